@@ -15,7 +15,12 @@ const { getCrateDependencies } = require("./utils.js");
  * @returns {string[]} Array of crate names
  */
 function getCoverageDependencies() {
-  const packages = ["oxc_coverage", "oxc_transform_conformance", "oxc_prettier_conformance"];
+  const packages = [
+    "oxc_coverage",
+    "oxc_transform_conformance",
+    "oxc_prettier_conformance",
+    "oxc_eslint_conformance",
+  ];
   const deps = getCrateDependencies(packages);
 
   console.error(`Conformance dependencies (${deps.length}):`);
@@ -32,6 +37,7 @@ const ALWAYS_RUN_PATHS = [
   "tasks/common/",
   "tasks/oxc_transform_conformance/",
   "tasks/oxc_prettier_conformance/",
+  "tasks/eslint_conformance/",
   "pnpm-lock.yaml",
 ];
 
@@ -1,8 +1,8 @@
 #!/bin/bash
 
 # Clone submodules in parallel for faster setup
-# Usage: ./clone-parallel.sh [test262] [babel] [typescript] [prettier] [acorn-test262] [node-compat-table]
-# Arguments: "true" or "false" for each submodule
+# Usage: ./clone-parallel.sh [test262] [babel] [typescript] [prettier] [acorn-test262] [node-compat-table] [eslint]
+# Arguments: "true" or "false" for each submodule, ESLint is only cloned when requested
 
 set -euo pipefail
 
@@ -13,6 +13,8 @@ TYPESCRIPT_SHA="669c25c091ad4d32298d0f33b0e4e681d46de3ea"
 PRETTIER_SHA="864bb7c042d9935344c155ad9292de3213e0085b"
 ACORN_TEST262_SHA="04357148679edc51c0a8e95047456d93e01da61b"
 NODE_COMPAT_TABLE_SHA="499beb6f1daa36f10c26b85a7f3ec3b3448ded23"
+# ESLint is pinned to a release, because rules are ported from released versions of ESLint
+ESLINT_TAG="v9.36.0"
 
 # Default values for which submodules to clone
 TEST262=${1:-true}
@@ -21,6 +23,7 @@ TYPESCRIPT=${3:-true}
 PRETTIER=${4:-true}
 ACORN_TEST262=${5:-true}
 NODE_COMPAT_TABLE=${6:-true}
+ESLINT=${7:-false}
 
 # Array to store background process PIDs
 declare -a PIDS=()
@@ -63,7 +66,7 @@ clone_repo() {
 
             # Fetch and checkout the specific commit
             git fetch --quiet --depth 1 origin "$ref"
-            git reset --hard "$ref"
+            git reset --hard FETCH_HEAD
             git clean -f -q
 
             echo "✓ Completed clone of $name"
@@ -83,6 +86,7 @@ clone_repo "$TYPESCRIPT" "microsoft/TypeScript" "tasks/coverage/typescript" "$TY
 clone_repo "$PRETTIER" "prettier/prettier" "tasks/prettier_conformance/prettier" "$PRETTIER_SHA" "prettier"
 clone_repo "$ACORN_TEST262" "oxc-project/acorn-test262" "tasks/coverage/acorn-test262" "$ACORN_TEST262_SHA" "acorn-test262"
 clone_repo "$NODE_COMPAT_TABLE" "williamkapke/node-compat-table" "tasks/coverage/node-compat-table" "$NODE_COMPAT_TABLE_SHA" "node-compat-table"
+clone_repo "$ESLINT" "eslint/eslint" "tasks/eslint_conformance/eslint" "$ESLINT_TAG" "eslint"
 
 # Wait for all background processes to complete
 echo "Waiting for all clone operations to complete..."
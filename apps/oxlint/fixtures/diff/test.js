@@ -0,0 +1,5 @@
+function foo() {
+  let a;
+  return a;
+}
+debugger;
@@ -0,0 +1,11 @@
+// eslint-disable-next-line no-debugger -- expires:2000-01-01
+debugger;
+
+// eslint-disable-next-line no-debugger -- expires:9999-12-31
+debugger;
+
+// eslint-disable-next-line no-debugger -- expires:2000-13-01
+debugger;
+
+// oxlint-disable-next-line no-debugger -- expires:2001-02-03 waiting for upstream fix
+debugger;
@@ -0,0 +1,7 @@
+debugger;
+debugger;
+function foo() {
+  let a = 1;
+  debugger;
+  debugger;
+}
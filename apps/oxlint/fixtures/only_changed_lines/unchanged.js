@@ -0,0 +1 @@
+debugger;
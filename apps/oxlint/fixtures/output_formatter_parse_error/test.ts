@@ -0,0 +1 @@
+class A { const x = 1; }
@@ -0,0 +1,5 @@
+var point = { x: 1 };
+
+with (point) {
+  console.log(x);
+}
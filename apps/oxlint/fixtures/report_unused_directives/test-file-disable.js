@@ -0,0 +1,4 @@
+/* eslint-disable */
+console.log("first");
+console.log("second");
+debugger;
@@ -0,0 +1,16 @@
+/* eslint-disable unknown-rule */
+
+// eslint-disable-next-line no-debugger, no-console
+debugger;
+
+// eslint-disable-next-line no-console
+console.log();
+
+// eslint-disable-next-line
+debugger;
+
+// eslint-disable-next-line no-debugger-statement
+console.log();
+
+// eslint-disable-next-line eslint/no-debugger
+debugger;
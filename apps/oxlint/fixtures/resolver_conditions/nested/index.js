@@ -0,0 +1,3 @@
+import { devtools } from "pkg";
+
+console.log(devtools);
@@ -0,0 +1,3 @@
+debugger;
+// eslint-disable-next-line no-console
+let a = 1;
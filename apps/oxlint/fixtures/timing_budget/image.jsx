@@ -0,0 +1 @@
+export const image = <img src="image.png" alt="An image" />;
@@ -0,0 +1,3 @@
+import { foo } from "@/util";
+
+foo();
@@ -8,6 +8,10 @@ export type JsLintFileCb =
 export type JsLoadPluginCb =
   ((arg0: string, arg1?: string | undefined | null) => Promise<string>)
 
+/** JS callback to extract JS/TS sections from a file with a processor of a JS plugin. */
+export type JsPreprocessCb =
+  ((arg0: number, arg1: string, arg2: string) => string)
+
 /**
  * NAPI entry point.
  *
@@ -15,7 +19,20 @@ export type JsLoadPluginCb =
  * 1. `args`: Command line arguments (process.argv.slice(2))
  * 2. `load_plugin`: Load a JS plugin from a file path.
  * 3. `lint_file`: Lint a file.
+ * 4. `preprocess`: Extract JS/TS sections from a file with a processor.
  *
  * Returns `true` if linting succeeded without errors, `false` otherwise.
  */
-export declare function lint(args: Array<string>, loadPlugin: JsLoadPluginCb, lintFile: JsLintFileCb): Promise<boolean>
+export declare function lint(args: Array<string>, loadPlugin: JsLoadPluginCb, lintFile: JsLintFileCb, preprocess: JsPreprocessCb): Promise<boolean>
+
+/**
+ * Request data for the file currently being linted, which is computed on demand.
+ *
+ * JS side passes in:
+ * 1. `buffer_id`: ID of the buffer containing the file's AST.
+ * 2. `kind`: Kind of data requested (`ExternalFileData`).
+ *
+ * The data is written into the buffer. Returns `[offset, length]` of the data in the buffer,
+ * as a `Uint32Array` offset and number of `u32`s, or `null` if the data is not available.
+ */
+export declare function requestFileData(bufferId: number, kind: number): Array<number> | null
@@ -575,5 +575,6 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { lint } = nativeBinding
+const { lint, requestFileData } = nativeBinding
 export { lint }
+export { requestFileData }
@@ -3,9 +3,10 @@ import { debugAssertIsNonNull } from "./utils/asserts.js";
 
 // Lazy-loaded JS plugin-related functions.
 // Using `typeof wrapper` here makes TS check that the function signatures of `loadPlugin` and `loadPluginWrapper`
-// are identical. Ditto `lintFile` and `lintFileWrapper`.
+// are identical. Ditto `lintFile` and `lintFileWrapper`, and `preprocess` and `preprocessWrapper`.
 let loadPlugin: typeof loadPluginWrapper | null = null;
 let lintFile: typeof lintFileWrapper | null = null;
+let preprocess: typeof preprocessWrapper | null = null;
 
 /**
  * Load a plugin.
@@ -21,7 +22,7 @@ function loadPluginWrapper(path: string, packageName: string | null): Promise<st
     // Use promises here instead of making `loadPluginWrapper` an async function,
     // to avoid a micro-tick and extra wrapper `Promise` in all later calls to `loadPluginWrapper`
     return import("./plugins/index.js").then((mod) => {
-      ({ loadPlugin, lintFile } = mod);
+      ({ loadPlugin, lintFile, preprocess } = mod);
       return loadPlugin(path, packageName);
     });
   }
@@ -54,11 +55,27 @@ function lintFileWrapper(
   return lintFile(filePath, bufferId, buffer, ruleIds, settingsJSON);
 }
 
+/**
+ * Extract JS/TS sections from a file with a processor.
+ *
+ * Delegates to `preprocess`, which was lazy-loaded by `loadPluginWrapper`.
+ *
+ * @param processorId - ID of processor
+ * @param filePath - Absolute path of file
+ * @param sourceText - Source text of file
+ * @returns Sections or error serialized to JSON string
+ */
+function preprocessWrapper(processorId: number, filePath: string, sourceText: string): string {
+  // Processors are registered by plugins, so `preprocess` must be defined here
+  debugAssertIsNonNull(preprocess);
+  return preprocess(processorId, filePath, sourceText);
+}
+
 // Get command line arguments, skipping first 2 (node binary and script path)
 const args = process.argv.slice(2);
 
-// Call Rust, passing `loadPlugin` and `lintFile` as callbacks, and CLI arguments
-const success = await lint(args, loadPluginWrapper, lintFileWrapper);
+// Call Rust, passing `loadPlugin`, `lintFile` and `preprocess` as callbacks, and CLI arguments
+const success = await lint(args, loadPluginWrapper, lintFileWrapper, preprocessWrapper);
 
 // Note: It's recommended to set `process.exitCode` instead of calling `process.exit()`.
 // `process.exit()` kills the process immediately and `stdout` may not be flushed before process dies.
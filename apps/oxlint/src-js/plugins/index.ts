@@ -1,4 +1,5 @@
 import { lintFile } from "./lint.js";
 import { loadPlugin } from "./load.js";
+import { preprocess } from "./preprocess.js";
 
-export { lintFile, loadPlugin };
+export { lintFile, loadPlugin, preprocess };
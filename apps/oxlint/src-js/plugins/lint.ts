@@ -126,7 +126,7 @@ function lintFileImpl(
   // So we pass the buffer to source code module here, so it can decode source text / deserialize AST on demand.
   const hasBOM = false; // TODO: Set this correctly
   const parserServices = PARSER_SERVICES_DEFAULT; // TODO: Set this correctly
-  setupSourceForFile(buffer, hasBOM, parserServices);
+  setupSourceForFile(buffer, bufferId, hasBOM, parserServices);
 
   // Pass settings JSON to context module
   setSettingsForFile(settingsJSON);
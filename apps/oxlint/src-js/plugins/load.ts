@@ -22,6 +22,38 @@ export interface Plugin {
   rules: {
     [key: string]: Rule;
   };
+  processors?: {
+    [key: string]: Processor;
+  };
+}
+
+/**
+ * Processor, which extracts JS/TS code from files which oxlint cannot parse itself (e.g. Markdown).
+ *
+ * Unlike ESLint processors, sections are not copied out of the file, but are ranges of the original
+ * source text. So diagnostics and fixes in the sections map back to the original file without
+ * a `postprocess` step.
+ */
+export interface Processor {
+  /**
+   * Extract JS/TS sections from a file.
+   * @param text - Source text of the file
+   * @param filename - Absolute path of the file
+   * @returns Sections of JS/TS code in the file
+   */
+  preprocess: (text: string, filename: string) => ProcessorSection[];
+}
+
+/**
+ * Section of JS/TS code extracted by a processor.
+ */
+export interface ProcessorSection {
+  // Start offset of the section in source text of the file
+  start: number;
+  // End offset of the section in source text of the file
+  end: number;
+  // Extension which determines how the section is parsed e.g. `ts`, `jsx`. Defaults to `js`.
+  extension?: string;
 }
 
 /**
@@ -88,6 +120,10 @@ const registeredPluginPaths = new Set<string>();
 // Indexed by `ruleId`, which is passed to `lintFile`.
 export const registeredRules: RuleDetails[] = [];
 
+// Processors of loaded plugins.
+// Indexed by `processorId`, which is passed to `preprocess`.
+export const registeredProcessors: Processor[] = [];
+
 // `before` hook which makes rule never run.
 const neverRunBeforeHook: BeforeHook = () => false;
 
@@ -102,6 +138,10 @@ interface PluginDetails {
   offset: number;
   // Names of rules within this plugin, in same order as in `registeredRules`
   ruleNames: string[];
+  // Index of first processor of this plugin within `registeredProcessors`
+  processorOffset: number;
+  // Names of processors within this plugin, in same order as in `registeredProcessors`
+  processorNames: string[];
 }
 
 /**
@@ -131,6 +171,7 @@ export async function loadPlugin(path: string, packageName: string | null): Prom
  * @throws {Error} If plugin has already been registered
  * @throws {Error} If plugin has no name
  * @throws {TypeError} If one of plugin's rules is malformed, or its `createOnce` method returns invalid visitor
+ * @throws {TypeError} If one of plugin's processors has no `preprocess` method
  * @throws {TypeError} if `plugin.meta.name` is not a string
  * @throws {*} If plugin throws an error during import
  */
@@ -140,7 +181,10 @@ async function loadPluginImpl(path: string, packageName: string | null): Promise
     registeredPluginPaths.add(path);
   }
 
-  const { default: plugin } = (await import(pathToFileURL(path).href)) as { default: Plugin };
+  const { default: pluginExport } = (await import(pathToFileURL(path).href)) as {
+    default: unknown;
+  };
+  const plugin = unwrapCommonJsDefaultExport(pluginExport) as Plugin;
 
   // TODO: Use a validation library to assert the shape of the plugin, and of rules
 
@@ -231,7 +275,25 @@ async function loadPluginImpl(path: string, packageName: string | null): Promise
     registeredRules.push(ruleDetails);
   }
 
-  return { name: pluginName, offset, ruleNames };
+  const processorOffset = registeredProcessors.length;
+  const processorNames: string[] = [];
+  const { processors } = plugin;
+  if (processors != null) {
+    for (const processorName of ObjectKeys(processors)) {
+      const processor = processors[processorName];
+      if (
+        typeof processor !== "object" ||
+        processor === null ||
+        typeof processor.preprocess !== "function"
+      ) {
+        throw new TypeError(`Processor \`${processorName}\` must have a \`preprocess\` method`);
+      }
+      registeredProcessors.push(processor);
+      processorNames.push(processorName);
+    }
+  }
+
+  return { name: pluginName, offset, ruleNames, processorOffset, processorNames };
 }
 
 /**
@@ -264,6 +326,27 @@ function getPluginName(plugin: Plugin, packageName: string | null): string {
   );
 }
 
+/**
+ * Unwrap the plugin from the default export of a CommonJS module which was compiled from ESM.
+ *
+ * Such modules set `exports.default = plugin`, and importing a CommonJS module gives `module.exports`
+ * as the default export, so the plugin is at `default.default`.
+ *
+ * @param pluginExport - Default export of plugin module
+ * @returns Plugin
+ */
+function unwrapCommonJsDefaultExport(pluginExport: unknown): unknown {
+  if (
+    typeof pluginExport === "object" &&
+    pluginExport !== null &&
+    !("rules" in pluginExport) &&
+    "default" in pluginExport
+  ) {
+    return pluginExport.default;
+  }
+  return pluginExport;
+}
+
 /**
  * Validate and conform `before` / `after` hook function.
  * @param hookFn - Hook function, or `null` / `undefined`
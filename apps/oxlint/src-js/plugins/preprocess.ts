@@ -0,0 +1,64 @@
+import { registeredProcessors } from "./load.js";
+import { debugAssertIsNonNull } from "../utils/asserts.js";
+import { getErrorMessage } from "../utils/utils.js";
+
+import type { ProcessorSection } from "./load.ts";
+
+/**
+ * Extract JS/TS sections from a file with a processor.
+ *
+ * Main logic is in separate function `preprocessImpl`, because V8 cannot optimize functions containing try/catch.
+ *
+ * @param processorId - ID of processor
+ * @param filePath - Absolute path of file
+ * @param sourceText - Source text of file
+ * @returns Sections or error serialized to JSON string
+ */
+export function preprocess(processorId: number, filePath: string, sourceText: string): string {
+  try {
+    return JSON.stringify({ Success: preprocessImpl(processorId, filePath, sourceText) });
+  } catch (err) {
+    return JSON.stringify({ Failure: getErrorMessage(err) });
+  }
+}
+
+/**
+ * Extract JS/TS sections from a file with a processor.
+ *
+ * @param processorId - ID of processor
+ * @param filePath - Absolute path of file
+ * @param sourceText - Source text of file
+ * @returns Sections of the file
+ * @throws {TypeError} If processor returns malformed sections
+ * @throws {*} If processor throws
+ */
+function preprocessImpl(
+  processorId: number,
+  filePath: string,
+  sourceText: string,
+): ProcessorSection[] {
+  const processor = registeredProcessors[processorId];
+  debugAssertIsNonNull(processor);
+
+  const sections = processor.preprocess(sourceText, filePath);
+  if (!Array.isArray(sections)) throw new TypeError("`preprocess` must return an array");
+
+  const sourceLen = sourceText.length;
+  return sections.map((section) => {
+    const { start, end, extension } = section;
+    if (
+      !Number.isInteger(start) ||
+      !Number.isInteger(end) ||
+      start < 0 ||
+      start > end ||
+      end > sourceLen
+    ) {
+      throw new TypeError(`\`preprocess\` returned invalid section range ${start}..${end}`);
+    }
+    if (extension != null && typeof extension !== "string") {
+      throw new TypeError("`extension` of section must be a string if provided");
+    }
+    // Only send the fields Rust needs
+    return { start, end, extension: extension ?? null };
+  });
+}
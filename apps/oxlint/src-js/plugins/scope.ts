@@ -7,7 +7,7 @@ import {
   type AnalyzeOptions,
   type ScopeManager as TSESLintScopeManager,
 } from "@typescript-eslint/scope-manager";
-import { ast, initAst } from "./source_code.js";
+import { ast, getFileData, initAst } from "./source_code.js";
 import { typeAssertIs, debugAssertIsNonNull } from "../utils/asserts.js";
 
 import type * as ESTree from "../generated/types.d.ts";
@@ -51,6 +51,7 @@ export interface Variable {
   identifiers: Identifier[];
   references: Reference[];
   defs: Definition[];
+  eslintUsed: boolean;
 }
 
 export interface Reference {
@@ -104,6 +105,27 @@ const analyzeOptions: SetNullable<AnalyzeOptions, "sourceType"> = {
   sourceType: null,
 };
 
+// Kind of file data requested from Rust, containing start offsets of unresolved references.
+// Must match `ExternalFileData::UnresolvedReferences` in `oxc_linter`.
+const FILE_DATA_UNRESOLVED_REFERENCES = 0;
+
+// Start offsets of all identifier references in current file which Oxc's semantic analysis
+// could not resolve to a declared symbol. Requested from Rust lazily only when needed.
+let unresolvedReferenceStarts: Set<number> | null = null;
+
+/**
+ * Get start offsets of identifier references in current file which don't resolve to a declared symbol.
+ * @returns Set of start offsets, or `null` if Rust could not provide them
+ */
+function getUnresolvedReferenceStarts(): Set<number> | null {
+  if (unresolvedReferenceStarts === null) {
+    const starts = getFileData(FILE_DATA_UNRESOLVED_REFERENCES);
+    if (starts === null) return null;
+    unresolvedReferenceStarts = new Set(starts);
+  }
+  return unresolvedReferenceStarts;
+}
+
 /**
  * Initialize TS-ESLint `ScopeManager` for current file.
  */
@@ -124,6 +146,7 @@ function initTsScopeManager() {
  */
 export function resetScopeManager() {
   tsScopeManager = null;
+  unresolvedReferenceStarts = null;
 }
 
 /**
@@ -199,6 +222,16 @@ export function isGlobalReference(node: ESTree.Node): boolean {
   if (!node) throw new TypeError("Missing required argument: `node`");
   if (node.type !== "Identifier") return false;
 
+  // A reference to a global variable is not resolved to any declaration in the file.
+  // Rust has already computed which references are unresolved, so use that to rule out most identifiers
+  // without building the TS-ESLint `ScopeManager`, which is expensive.
+  // Identifiers which are unresolved still need the check below, as TS-ESLint's global scope
+  // doesn't contain every name which is not declared in the file.
+  if (tsScopeManager === null) {
+    const starts = getUnresolvedReferenceStarts();
+    if (starts !== null && !starts.has(node.start)) return false;
+  }
+
   if (tsScopeManager === null) initTsScopeManager();
   debugAssertIsNonNull(tsScopeManager);
 
@@ -275,9 +308,27 @@ export function getScope(node: ESTree.Node): Scope {
  * @param refNode - Reference node
  * @returns `true` if a variable with the given name was found and marked as used, otherwise `false`
  */
-/* oxlint-disable no-unused-vars */
 export function markVariableAsUsed(name: string, refNode: ESTree.Node): boolean {
-  // TODO: Implement
-  throw new Error("`context.markVariableAsUsed` not implemented yet");
+  // ref: https://github.com/eslint/eslint/blob/e7cda3bdf1bdd664e6033503a3315ad81736b200/lib/languages/js/source-code/source-code.js
+  if (!refNode) throw new TypeError("Missing required argument: `refNode`");
+
+  const currentScope = getScope(refNode);
+  let initialScope = currentScope;
+
+  // When a reference is in the top level of a module or a CommonJS file, variables are declared
+  // in the `module` scope rather than the `global` scope, so start looking from there
+  if (currentScope.type === "global" && currentScope.childScopes.length > 0) {
+    const childScope = currentScope.childScopes[0];
+    if (childScope.block === ast) initialScope = childScope;
+  }
+
+  for (let scope: Scope | null = initialScope; scope !== null; scope = scope.upper) {
+    const variable = scope.set.get(name);
+    if (variable !== undefined) {
+      variable.eslintUsed = true;
+      return true;
+    }
+  }
+
+  return false;
 }
-/* oxlint-enable no-unused-vars */
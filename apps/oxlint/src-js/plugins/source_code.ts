@@ -1,3 +1,4 @@
+import { requestFileData } from "../bindings.js";
 import { DATA_POINTER_POS_32, SOURCE_LEN_OFFSET } from "../generated/constants.js";
 
 // We use the deserializer which removes `ParenthesizedExpression`s from AST,
@@ -31,8 +32,9 @@ const { max } = Math;
 // Text decoder, for decoding source text from buffer
 const textDecoder = new TextDecoder("utf-8", { ignoreBOM: true });
 
-// Buffer containing AST. Set before linting a file by `setupSourceForFile`.
+// Buffer containing AST, and its ID. Set before linting a file by `setupSourceForFile`.
 let buffer: BufferWithArrays | null = null;
+let bufferId = 0;
 
 // Indicates if the original source text has a BOM. Set before linting a file by `setupSourceForFile`.
 let hasBOM = false;
@@ -49,15 +51,18 @@ let parserServices: Record<string, unknown> | null = null;
 /**
  * Set up source for the file about to be linted.
  * @param bufferInput - Buffer containing AST
+ * @param bufferIdInput - ID of buffer containing AST
  * @param hasBOMInput - `true` if file's original source text has Unicode BOM
  * @param parserServicesInput - Parser services object for the file
  */
 export function setupSourceForFile(
   bufferInput: BufferWithArrays,
+  bufferIdInput: number,
   hasBOMInput: boolean,
   parserServicesInput: Record<string, unknown>,
 ): void {
   buffer = bufferInput;
+  bufferId = bufferIdInput;
   hasBOM = hasBOMInput;
   parserServices = parserServicesInput;
 }
@@ -81,6 +86,23 @@ export function initAst(): void {
   ast = deserializeProgramOnly(buffer, sourceText, sourceByteLen, getNodeLoc);
 }
 
+/**
+ * Request data about the file being linted, which Rust only computes on demand.
+ *
+ * Rust writes the data into the buffer, so it's not copied.
+ * The returned `Uint32Array` is only valid until linting of the current file completes.
+ *
+ * @param kind - Kind of data to request (one of the `FILE_DATA_*` constants)
+ * @returns Data, or `null` if it's not available
+ */
+export function getFileData(kind: number): Uint32Array | null {
+  debugAssertIsNonNull(buffer);
+  const location = requestFileData(bufferId, kind);
+  if (location === null) return null;
+  const pos32 = location[0];
+  return buffer.uint32.subarray(pos32, pos32 + location[1]);
+}
+
 /**
  * Reset source and AST after file has been linted, to free memory.
  *
@@ -0,0 +1,340 @@
+use std::str::FromStr;
+
+use crate::stats::{AllocationStats, MimallocStats};
+
+/// An allocator which new memory can be allocated with, see `--allocator`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AllocatorBackend {
+    /// Mimalloc, the default of builds with the `allocator` feature.
+    Mimalloc,
+    /// The allocator of the C library, e.g. musl's on Alpine.
+    System,
+}
+
+impl AllocatorBackend {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Mimalloc => "mimalloc",
+            Self::System => "system",
+        }
+    }
+}
+
+impl FromStr for AllocatorBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mimalloc" => Ok(Self::Mimalloc),
+            "system" => Ok(Self::System),
+            _ => Err(format!("'{s}' is not a known allocator")),
+        }
+    }
+}
+
+/// The allocator which new memory is allocated with.
+pub fn current_allocator() -> AllocatorBackend {
+    #[cfg(all(feature = "allocator", not(miri), not(target_family = "wasm")))]
+    if !selectable::uses_system() {
+        return AllocatorBackend::Mimalloc;
+    }
+    AllocatorBackend::System
+}
+
+/// Allocate new memory with `backend` from now on.
+///
+/// Builds with the `allocator` feature allocate with mimalloc, unless the system allocator is
+/// selected. Memory allocated before is still freed by the allocator which allocated it, see
+/// `selectable::SelectableAllocator`. Other builds can only use the system allocator.
+///
+/// # Errors
+///
+/// If `backend` is mimalloc, but this build of oxlint does not include it.
+#[cfg_attr(
+    all(feature = "allocator", not(miri), not(target_family = "wasm")),
+    expect(clippy::unnecessary_wraps)
+)]
+pub fn select_allocator(backend: AllocatorBackend) -> Result<(), String> {
+    match backend {
+        #[cfg(all(feature = "allocator", not(miri), not(target_family = "wasm")))]
+        AllocatorBackend::System => selectable::use_system(),
+        #[cfg(not(all(feature = "allocator", not(miri), not(target_family = "wasm"))))]
+        AllocatorBackend::Mimalloc => {
+            return Err(
+                "The `--allocator mimalloc` option is not supported, as this build of oxlint does not include mimalloc.\n"
+                    .to_string(),
+            );
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Statistics of mimalloc, for `--stats`, or `None` if new memory is not allocated with it.
+pub fn mimalloc_stats() -> Option<MimallocStats> {
+    match current_allocator() {
+        #[cfg(all(feature = "allocator", not(miri), not(target_family = "wasm")))]
+        AllocatorBackend::Mimalloc => Some(selectable::mimalloc_stats()),
+        _ => None,
+    }
+}
+
+/// Global allocator of builds with the `allocator` feature, which allocates with mimalloc or,
+/// once selected with `--allocator system`, with the system allocator.
+#[cfg(all(feature = "allocator", not(miri), not(target_family = "wasm")))]
+pub mod selectable {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        sync::atomic::{AtomicBool, Ordering},
+    };
+
+    use mimalloc_safe::MiMalloc;
+
+    use crate::stats::MimallocStats;
+
+    /// Whether new memory is allocated with the system allocator. It is selected before linting
+    /// starts, and never unselected.
+    static USE_SYSTEM: AtomicBool = AtomicBool::new(false);
+
+    pub fn use_system() {
+        USE_SYSTEM.store(true, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn uses_system() -> bool {
+        USE_SYSTEM.load(Ordering::Relaxed)
+    }
+
+    /// Whether `ptr` was allocated by mimalloc. Memory allocated before the system allocator was
+    /// selected, e.g. while parsing the command line, is still freed by mimalloc.
+    #[inline]
+    fn is_mimalloc_block(ptr: *mut u8) -> bool {
+        // SAFETY: `mi_is_in_heap_region` only looks up the address in the segment map of mimalloc.
+        !uses_system() || unsafe { libmimalloc_sys2::mi_is_in_heap_region(ptr.cast()) }
+    }
+
+    pub fn mimalloc_stats() -> MimallocStats {
+        let mut stats = MimallocStats::default();
+        let mut ignored = 0;
+        // SAFETY: All pointers are valid for writes.
+        unsafe {
+            libmimalloc_sys2::mi_process_info(
+                &raw mut ignored,
+                &raw mut ignored,
+                &raw mut ignored,
+                &raw mut stats.current_rss,
+                &raw mut stats.peak_rss,
+                &raw mut stats.current_commit,
+                &raw mut stats.peak_commit,
+                &raw mut stats.page_faults,
+            );
+        }
+        stats
+    }
+
+    pub struct SelectableAllocator;
+
+    // SAFETY: Memory is always freed and reallocated by the allocator which allocated it.
+    unsafe impl GlobalAlloc for SelectableAllocator {
+        #[inline]
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            // SAFETY: Same contract as `GlobalAlloc::alloc`.
+            unsafe { if uses_system() { System.alloc(layout) } else { MiMalloc.alloc(layout) } }
+        }
+
+        #[inline]
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            // SAFETY: Same contract as `GlobalAlloc::alloc_zeroed`.
+            unsafe {
+                if uses_system() {
+                    System.alloc_zeroed(layout)
+                } else {
+                    MiMalloc.alloc_zeroed(layout)
+                }
+            }
+        }
+
+        #[inline]
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            // SAFETY: Same contract as `GlobalAlloc::dealloc`, and `ptr` is freed by the allocator
+            // which allocated it.
+            unsafe {
+                if is_mimalloc_block(ptr) {
+                    MiMalloc.dealloc(ptr, layout);
+                } else {
+                    System.dealloc(ptr, layout);
+                }
+            }
+        }
+
+        #[inline]
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            // SAFETY: Same contract as `GlobalAlloc::realloc`, and `ptr` is reallocated by the
+            // allocator which allocated it.
+            unsafe {
+                if is_mimalloc_block(ptr) {
+                    MiMalloc.realloc(ptr, layout, new_size)
+                } else {
+                    System.realloc(ptr, layout, new_size)
+                }
+            }
+        }
+    }
+}
+
+/// Start counting allocations, for [`allocation_stats`].
+pub fn start_counting_allocations() {
+    #[cfg(all(feature = "allocation_stats", not(miri), not(target_family = "wasm")))]
+    counting::start();
+}
+
+/// Allocations made since [`start_counting_allocations`], or `None` if oxlint was built without
+/// the `allocation_stats` feature, with which its global allocator counts them.
+#[cfg_attr(
+    all(feature = "allocation_stats", not(miri), not(target_family = "wasm")),
+    expect(clippy::unnecessary_wraps)
+)]
+pub fn allocation_stats() -> Option<AllocationStats> {
+    #[cfg(all(feature = "allocation_stats", not(miri), not(target_family = "wasm")))]
+    return Some(counting::stats());
+    #[cfg(not(all(feature = "allocation_stats", not(miri), not(target_family = "wasm"))))]
+    None
+}
+
+/// Global allocator of builds with the `allocation_stats` feature, which counts the allocations
+/// for `--stats`.
+///
+/// Counting costs atomic operations on every allocation, so release builds do not enable it.
+#[cfg(all(feature = "allocation_stats", not(miri), not(target_family = "wasm")))]
+pub mod counting {
+    use std::{
+        alloc::{GlobalAlloc, Layout},
+        sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering},
+    };
+
+    use super::current_allocator;
+    use crate::stats::AllocationStats;
+
+    #[cfg(feature = "allocator")]
+    const BACKEND: super::selectable::SelectableAllocator = super::selectable::SelectableAllocator;
+    #[cfg(not(feature = "allocator"))]
+    const BACKEND: std::alloc::System = std::alloc::System;
+
+    static COUNTING: AtomicBool = AtomicBool::new(false);
+    static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+    static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+    /// Bytes allocated minus bytes freed since counting started. Negative when more memory
+    /// allocated before is freed than allocated since.
+    static LIVE_BYTES: AtomicIsize = AtomicIsize::new(0);
+    static PEAK_LIVE_BYTES: AtomicIsize = AtomicIsize::new(0);
+
+    pub fn start() {
+        ALLOCATIONS.store(0, Ordering::Relaxed);
+        ALLOCATED_BYTES.store(0, Ordering::Relaxed);
+        LIVE_BYTES.store(0, Ordering::Relaxed);
+        PEAK_LIVE_BYTES.store(0, Ordering::Relaxed);
+        COUNTING.store(true, Ordering::Relaxed);
+    }
+
+    pub fn stats() -> AllocationStats {
+        AllocationStats {
+            backend: current_allocator().as_str(),
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+            peak_live_bytes: PEAK_LIVE_BYTES.load(Ordering::Relaxed).max(0).cast_unsigned(),
+        }
+    }
+
+    #[inline]
+    fn count_allocation(size: usize) {
+        if COUNTING.load(Ordering::Relaxed) {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+            count_live_bytes(size.cast_signed());
+        }
+    }
+
+    #[inline]
+    fn count_live_bytes(delta: isize) {
+        if COUNTING.load(Ordering::Relaxed) {
+            let live = LIVE_BYTES.fetch_add(delta, Ordering::Relaxed) + delta;
+            PEAK_LIVE_BYTES.fetch_max(live, Ordering::Relaxed);
+        }
+    }
+
+    pub struct CountingAllocator;
+
+    // SAFETY: All calls are forwarded to the same allocator.
+    unsafe impl GlobalAlloc for CountingAllocator {
+        #[inline]
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            count_allocation(layout.size());
+            // SAFETY: Same contract as `GlobalAlloc::alloc`.
+            unsafe { BACKEND.alloc(layout) }
+        }
+
+        #[inline]
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            count_allocation(layout.size());
+            // SAFETY: Same contract as `GlobalAlloc::alloc_zeroed`.
+            unsafe { BACKEND.alloc_zeroed(layout) }
+        }
+
+        #[inline]
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            count_live_bytes(-layout.size().cast_signed());
+            // SAFETY: Same contract as `GlobalAlloc::dealloc`.
+            unsafe { BACKEND.dealloc(ptr, layout) }
+        }
+
+        #[inline]
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            count_allocation(new_size);
+            count_live_bytes(-layout.size().cast_signed());
+            // SAFETY: Same contract as `GlobalAlloc::realloc`.
+            unsafe { BACKEND.realloc(ptr, layout, new_size) }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AllocatorBackend, current_allocator, mimalloc_stats, select_allocator};
+
+    #[test]
+    fn parse_backend() {
+        assert_eq!("mimalloc".parse(), Ok(AllocatorBackend::Mimalloc));
+        assert_eq!("system".parse(), Ok(AllocatorBackend::System));
+        assert!("jemalloc".parse::<AllocatorBackend>().is_err());
+    }
+
+    #[test]
+    #[cfg(not(feature = "allocator"))]
+    fn select_allocator_without_mimalloc() {
+        assert!(select_allocator(AllocatorBackend::System).is_ok());
+        assert!(select_allocator(AllocatorBackend::Mimalloc).is_err());
+        assert_eq!(current_allocator(), AllocatorBackend::System);
+        assert!(mimalloc_stats().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "allocator")]
+    fn select_allocator_with_mimalloc() {
+        assert!(select_allocator(AllocatorBackend::Mimalloc).is_ok());
+        let before = vec![1u8; 1024];
+        assert!(mimalloc_stats().is_some_and(|stats| stats.peak_commit > 0));
+
+        assert!(select_allocator(AllocatorBackend::System).is_ok());
+        assert_eq!(current_allocator(), AllocatorBackend::System);
+        assert!(mimalloc_stats().is_none());
+        let after = vec![2u8; 1024];
+        // SAFETY: Only looks up the addresses.
+        unsafe {
+            assert!(libmimalloc_sys2::mi_is_in_heap_region(before.as_ptr().cast()));
+            assert!(!libmimalloc_sys2::mi_is_in_heap_region(after.as_ptr().cast()));
+        }
+        // Each is freed by the allocator which allocated it
+        drop(before);
+        drop(after);
+    }
+}
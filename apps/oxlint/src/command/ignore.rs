@@ -1,12 +1,14 @@
-use std::ffi::OsString;
+use std::{ffi::OsString, path::PathBuf};
 
 use bpaf::{Bpaf, doc::Style};
 
 pub const NO_IGNORE_HELP: &[(&str, Style)] = &[
-    ("Disable excluding files from `.eslintignore` files, ", Style::Text),
+    ("Disable excluding files from `.eslintignore` files, `.oxlintignore` files, ", Style::Text),
     ("--ignore-path", Style::Literal),
-    (" flags and ", Style::Text),
+    (" flags, ", Style::Text),
     ("--ignore-pattern", Style::Literal),
+    (" flags and ", Style::Text),
+    ("--ignore-pattern-file", Style::Literal),
     (" flags", Style::Text),
 ];
 
@@ -14,6 +16,12 @@ pub const NO_IGNORE_HELP: &[(&str, Style)] = &[
 #[derive(Debug, Clone, Bpaf)]
 pub struct IgnoreOptions {
     /// Specify the file to use as your `.eslintignore`
+    ///
+    /// `.oxlintignore` files are read in every linted directory, like nested `.gitignore` files.
+    /// Their patterns are relative to the directory of the file, and the file of a subdirectory
+    /// takes precedence over the files of its parents, e.g. to re-include files with `!`.
+    /// A file is linted only if it is not ignored by any of these files, `--ignore-pattern`
+    /// or the `ignorePatterns` of the configuration.
     #[bpaf(argument("PATH"), fallback(".eslintignore".into()), hide_usage)]
     pub ignore_path: OsString,
 
@@ -24,6 +32,13 @@ pub struct IgnoreOptions {
     #[bpaf(argument("PAT"), many, hide_usage)]
     pub ignore_pattern: Vec<String>,
 
+    /// Specify a file containing patterns of files to ignore, one per line
+    ///
+    /// Patterns are added to those given with `--ignore-pattern`. Empty lines and lines
+    /// starting with `#` are skipped. Useful when the ignore list is generated by other tools.
+    #[bpaf(argument("PATH"), optional, hide_usage)]
+    pub ignore_pattern_file: Option<PathBuf>,
+
     #[bpaf(switch, hide_usage, help(NO_IGNORE_HELP))]
     pub no_ignore: bool,
 }
@@ -45,6 +60,7 @@ mod ignore_options {
         assert_eq!(options.ignore_path, OsString::from(".eslintignore"));
         assert!(!options.no_ignore);
         assert!(options.ignore_pattern.is_empty());
+        assert!(options.ignore_pattern_file.is_none());
     }
 
     #[test]
@@ -70,4 +86,10 @@ mod ignore_options {
         let options = get_ignore_options("--ignore-pattern ./test --ignore-pattern bar.js foo.js");
         assert_eq!(options.ignore_pattern, vec![String::from("./test"), String::from("bar.js")]);
     }
+
+    #[test]
+    fn ignore_pattern_file() {
+        let options = get_ignore_options("--ignore-pattern-file .patterns foo.js");
+        assert_eq!(options.ignore_pattern_file, Some(PathBuf::from(".patterns")));
+    }
 }
@@ -3,12 +3,17 @@ use std::path::PathBuf;
 use bpaf::Bpaf;
 use oxc_linter::{AllowWarnDeny, FixKind, LintPlugins};
 
-use crate::output_formatter::OutputFormat;
+use crate::{
+    output_formatter::{ColorChoice, FixReport, OutputFormat, SortBy, SummaryBy},
+    shard::Shard,
+};
 
 use super::{
     MiscOptions, PATHS_ERROR_MESSAGE, VERSION,
     ignore::{IgnoreOptions, ignore_options},
-    misc_options, validate_paths,
+    misc_options,
+    report::{ReportCommand, report_command},
+    validate_paths,
 };
 
 #[derive(Debug, Clone, Bpaf)]
@@ -39,6 +44,18 @@ pub struct LintCommand {
     #[bpaf(long("rules"), switch, hide_usage)]
     pub list_rules: bool,
 
+    /// Print, for each enabled plugin, how many of its rules are enabled, disabled and unset in
+    /// the configuration, per directory when nested configuration files are found.
+    /// No linting is performed
+    #[bpaf(switch, hide_usage)]
+    pub coverage_report: bool,
+
+    /// Print the files which would be linted, after applying ignore files, ignore patterns and
+    /// the supported extensions, one per line or as a JSON array with `--format json`.
+    /// No linting is performed
+    #[bpaf(switch, hide_usage)]
+    pub list_files: bool,
+
     /// Start the language server
     #[bpaf(long("lsp"), switch, hide_usage)]
     pub lsp: bool,
@@ -58,10 +75,27 @@ pub struct LintCommand {
     #[bpaf(switch, hide_usage)]
     pub type_check: bool,
 
+    /// Only report syntax errors, without running any rules.
+    /// Much faster than linting, e.g. to validate files in pre-commit hooks
+    #[bpaf(switch, hide_usage)]
+    pub syntax_only: bool,
+
+    /// Only lint shard I of N of the files, e.g. `--shard 2/8`, to split a run across CI jobs.
+    /// Files are assigned to shards by a stable hash of their path
+    #[bpaf(argument("I/N"), optional, hide_usage)]
+    pub shard: Option<Shard>,
+
     #[bpaf(external)]
     pub inline_config_options: InlineConfigOptions,
 
-    /// Single file, single path or list of paths
+    #[bpaf(external)]
+    pub stdin_options: StdinOptions,
+
+    #[bpaf(external(report_command), optional, hide_usage)]
+    pub report_command: Option<ReportCommand>,
+
+    /// Single file, single path or list of paths. `@name` lints the path set `name` of the `paths`
+    /// in the configuration file
     #[bpaf(positional("PATH"), many, guard(validate_paths, PATHS_ERROR_MESSAGE))]
     pub paths: Vec<PathBuf>,
 }
@@ -122,14 +156,32 @@ pub struct BasicOptions {
     #[bpaf(long, short, argument("./.oxlintrc.json"))]
     pub config: Option<PathBuf>,
 
+    /// Oxlint configuration as a JSON string, used instead of a configuration file,
+    /// e.g. `--config-inline '{ "rules": { "no-debugger": "error" } }'`.
+    /// Relative paths in it are resolved from the current working directory.
+    #[bpaf(long, env("OXLINT_CONFIG"), argument("JSON"), hide_usage)]
+    pub config_inline: Option<String>,
+
     /// TypeScript `tsconfig.json` path for reading path alias and project references for import plugin.
     /// If not provided, will look for `tsconfig.json` in the current working directory.
+    /// Use `auto` to find the nearest `tsconfig.json` of each linted file, e.g. in monorepos.
     #[bpaf(argument("./tsconfig.json"), hide_usage)]
     pub tsconfig: Option<PathBuf>,
 
     /// Initialize oxlint configuration with default values
     #[bpaf(switch, hide_usage)]
     pub init: bool,
+
+    /// Replace deprecated names in the configuration file, e.g. the `import-x` plugin with `import`.
+    /// No linting is performed. Comments in the configuration file are not kept.
+    #[bpaf(switch, hide_usage)]
+    pub fix_config: bool,
+
+    /// Migrate the configuration file to the current version of its format, moving keys, replacing
+    /// deprecated names and setting `$version`. No linting is performed. Comments in the
+    /// configuration file are not kept.
+    #[bpaf(switch, hide_usage)]
+    pub migrate_config: bool,
 }
 
 // This is formatted according to
@@ -139,6 +191,7 @@ pub struct BasicOptions {
 ///
 /// Accumulate rules and categories from left to right on the command-line.
 ///   For example `-D correctness -A no-debugger` or `-A all -D no-debugger`.
+///   Plugin names, e.g. `-D unicorn`, enable all rules of the plugin except `nursery`.
 ///   The categories are:
 ///   * `correctness` - Code that is outright wrong or useless (default)
 ///   * `suspicious`  - Code that is most likely wrong or useless
@@ -193,6 +246,32 @@ pub struct FixOptions {
     /// Apply dangerous fixes and suggestions
     #[bpaf(switch, hide_usage)]
     pub fix_dangerously: bool,
+
+    /// Only fix diagnostics with at least the given severity, e.g. `error` fixes errors
+    /// and leaves warnings as they are. Possible values: `warn`, `error`
+    #[bpaf(
+        argument::<String>("SEVERITY"),
+        guard(|s| parse_fix_level(s).is_some(), "Invalid fix level, expected `warn` or `error`"),
+        map(|s| parse_fix_level(&s).unwrap()), // guard ensures parsing succeeds
+        optional,
+        hide_usage
+    )]
+    pub fix_level: Option<AllowWarnDeny>,
+
+    /// Print the fixed source text to stdout instead of the diagnostics, which are printed to stderr.
+    /// Only valid in combination with `--stdin`. Implies `--fix` unless another fix option is given.
+    #[bpaf(switch, hide_usage)]
+    pub fix_to_stdout: bool,
+
+    /// Print the files written by the fix options, with the number of applied fixes by rule,
+    /// to stderr. Cannot be used with `--fix-to-stdout`. Possible values: `json`
+    #[bpaf(argument("FORMAT"), optional, hide_usage)]
+    pub fix_report: Option<FixReport>,
+}
+
+/// Parse the value of `--fix-level`. `allow` / `off` are not valid, as they would never be reported.
+fn parse_fix_level(s: &str) -> Option<AllowWarnDeny> {
+    AllowWarnDeny::try_from(s).ok().filter(|severity| severity.is_warn_deny())
 }
 
 impl FixOptions {
@@ -214,11 +293,15 @@ impl FixOptions {
             kind.set(FixKind::Dangerous, true);
         }
 
+        if self.fix_to_stdout && kind.is_none() {
+            kind.set(FixKind::SafeFix, true);
+        }
+
         kind
     }
 
     pub fn is_enabled(&self) -> bool {
-        self.fix || self.fix_suggestions || self.fix_dangerously
+        self.fix || self.fix_suggestions || self.fix_dangerously || self.fix_to_stdout
     }
 }
 
@@ -229,6 +312,34 @@ pub struct WarningOptions {
     #[bpaf(switch, hide_usage)]
     pub quiet: bool,
 
+    /// Do not report diagnostics of these rules, but still count them towards the totals and exit code.
+    /// Accepts a comma-separated list of rules, e.g. `--quiet-rules no-debugger,react/jsx-key`
+    #[bpaf(argument::<String>("RULES"), many, map(split_rule_list), hide_usage)]
+    pub quiet_rules: Vec<String>,
+
+    /// Only report diagnostics on lines changed by a unified diff read from stdin,
+    /// but still count all diagnostics towards the totals and exit code.
+    /// Paths in the diff are relative to the current working directory.
+    #[bpaf(switch, hide_usage)]
+    pub only_changed_lines: bool,
+
+    /// Read the changed lines of `--only-changed-lines` from `git diff REV` instead of stdin,
+    /// e.g. `--only-changed-lines --changed origin/main`
+    #[bpaf(argument("REV"), hide_usage)]
+    pub changed: Option<String>,
+
+    /// Report diagnostics in files owned by TEAM in the `CODEOWNERS` file as errors,
+    /// and all other diagnostics as warnings. Can be repeated for the handles of a team,
+    /// e.g. `--owned-by @org/web --owned-by web@example.com`
+    #[bpaf(argument::<String>("TEAM"), many, hide_usage)]
+    pub owned_by: Vec<String>,
+
+    /// Path of the `CODEOWNERS` file of `--owned-by`, relative to the current working directory.
+    /// Defaults to `.github/CODEOWNERS`, `CODEOWNERS` or `docs/CODEOWNERS` in the root of the
+    /// git repository, which the patterns are relative to.
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub codeowners: Option<PathBuf>,
+
     /// Ensure warnings produce a non-zero exit code
     #[bpaf(switch, hide_usage)]
     pub deny_warnings: bool,
@@ -239,13 +350,91 @@ pub struct WarningOptions {
     pub max_warnings: Option<usize>,
 }
 
+#[derive(Debug, Clone, Bpaf)]
+struct SortOptions {
+    /// Alias for `--sort-by path,rule`
+    #[bpaf(switch, hide_usage)]
+    sort_output: bool,
+
+    /// Sort the diagnostics by a comma-separated list of keys before printing them, so the output
+    /// is the same on every run. Diagnostics are printed once all files have been linted.
+    /// Possible keys: `severity` (errors first), `path` (file and position) and `rule`,
+    /// e.g. `--sort-by severity,path,rule`. Ties are sorted by path, rule and message
+    #[bpaf(argument("KEYS"), optional, hide_usage)]
+    sort_by: Option<SortBy>,
+}
+
+impl SortOptions {
+    fn into_sort_by(self) -> Option<SortBy> {
+        self.sort_by.or_else(|| self.sort_output.then(SortBy::default))
+    }
+}
+
+fn split_rule_list(lists: Vec<String>) -> Vec<String> {
+    let mut rules = Vec::with_capacity(lists.len());
+    for list in lists {
+        rules.extend(
+            list.split(',').map(str::trim).filter(|rule| !rule.is_empty()).map(ToString::to_string),
+        );
+    }
+    rules
+}
+
 /// Output
 #[derive(Debug, Clone, Bpaf)]
 pub struct OutputOptions {
     /// Use a specific output format. Possible values:
-    /// `checkstyle`, `default`, `github`, `gitlab`, `json`, `junit`, `stylish`, `unix`
+    /// `checkstyle`, `compact-stable`, `default`, `github`, `gitlab`, `json`, `junit`, `ndjson`,
+    /// `stylish`, `unix`
     #[bpaf(long, short, fallback(OutputFormat::Default), hide_usage)]
     pub format: OutputFormat,
+
+    /// When to color the `default` and `stylish` output. Possible values: `auto`, `always`, `never`.
+    /// `auto` colors the output when stdout is a terminal, unless `NO_COLOR` is set.
+    /// `FORCE_COLOR` enables colors in `auto` mode, e.g. when the output is piped
+    #[bpaf(argument("WHEN"), fallback(ColorChoice::Auto), hide_usage)]
+    pub color: ColorChoice,
+
+    /// Display file paths with the path separator of the platform, i.e. `\` on Windows.
+    /// Paths are displayed with `/` on every platform by default.
+    #[bpaf(switch, hide_usage)]
+    pub native_path_separators: bool,
+
+    /// Keys to sort the diagnostics by, from `--sort-by` or `--sort-output`
+    #[bpaf(external(sort_options), map(SortOptions::into_sort_by), hide_usage)]
+    pub sort_by: Option<SortBy>,
+
+    /// Print a table of the diagnostic counts by severity and rule at the end of the run.
+    /// Possible values: `dir`, which groups diagnostics by package (a directory containing
+    /// a `package.json`) or by top-level directory.
+    /// Only printed for the `default`, `stylish` and `unix` formats
+    #[bpaf(argument("MODE"), optional, hide_usage)]
+    pub summary_by: Option<SummaryBy>,
+
+    /// Add the frameworks and source types detected for each linted file to the `json` and
+    /// `ndjson` output, e.g. to find out why test rules did or did not run on a file
+    #[bpaf(switch, hide_usage)]
+    pub file_metadata: bool,
+
+    /// Add the configuration which decided the severity of the rule to each diagnostic of the
+    /// `json` output, e.g. to find out which nested configuration file enabled a rule
+    #[bpaf(switch, hide_usage)]
+    pub rule_origins: bool,
+
+    /// Add the status of each file, e.g. `linted`, `skipped-ignored` or `parse-failed`, to the
+    /// `json` output, e.g. for wrappers to verify that every file was linted
+    #[bpaf(switch, hide_usage)]
+    pub file_statuses: bool,
+
+    /// Write the output to a file instead of stdout, e.g. with `--format compact-stable` to
+    /// commit the diagnostics as an approval snapshot
+    #[bpaf(argument("PATH"), optional, hide_usage)]
+    pub output_file: Option<PathBuf>,
+
+    /// Compare the output with the file of `--output-file` instead of writing it.
+    /// Fails if they differ, and succeeds otherwise, even if errors are found
+    #[bpaf(switch, hide_usage)]
+    pub check_snapshot: bool,
 }
 
 /// Enable/Disable Plugins
@@ -438,6 +627,29 @@ pub struct InlineConfigOptions {
     pub report_unused_directives: ReportUnusedDirectives,
 }
 
+/// Stdin
+#[derive(Debug, Clone, Bpaf)]
+pub struct StdinOptions {
+    /// Lint source text read from stdin instead of files
+    #[bpaf(switch, hide_usage)]
+    pub stdin: bool,
+
+    /// File name used for the source text read from stdin.
+    /// It determines the source type, and which configuration files and overrides apply.
+    #[bpaf(argument("PATH"), fallback("stdin.js".into()), hide_usage)]
+    pub stdin_filename: PathBuf,
+
+    /// Lint the files changed by a unified diff read from stdin, as they are after applying the diff
+    /// to the working tree. Only diagnostics on added lines are reported.
+    #[bpaf(switch, hide_usage)]
+    pub diff: bool,
+
+    /// Lint the files staged in the git index, as they will be committed, instead of the working
+    /// tree. Paths restrict the staged files which are linted.
+    #[bpaf(switch, hide_usage)]
+    pub staged: bool,
+}
+
 #[cfg(test)]
 mod plugins {
     use oxc_linter::LintPlugins;
@@ -482,6 +694,8 @@ mod plugins {
 
 #[cfg(test)]
 mod warning_options {
+    use std::path::PathBuf;
+
     use super::{WarningOptions, lint_command};
 
     fn get_warning_options(arg: &str) -> WarningOptions {
@@ -493,6 +707,7 @@ mod warning_options {
     fn default() {
         let options = get_warning_options(".");
         assert!(!options.quiet);
+        assert!(options.quiet_rules.is_empty());
         assert_eq!(options.max_warnings, None);
     }
 
@@ -502,6 +717,37 @@ mod warning_options {
         assert!(options.quiet);
     }
 
+    #[test]
+    fn quiet_rules() {
+        let options =
+            get_warning_options("--quiet-rules no-debugger,react/jsx-key --quiet-rules eqeqeq .");
+        assert_eq!(options.quiet_rules, vec!["no-debugger", "react/jsx-key", "eqeqeq"]);
+    }
+
+    #[test]
+    fn only_changed_lines() {
+        let options = get_warning_options(".");
+        assert!(!options.only_changed_lines);
+        assert_eq!(options.changed, None);
+
+        let options = get_warning_options("--only-changed-lines --changed origin/main .");
+        assert!(options.only_changed_lines);
+        assert_eq!(options.changed, Some("origin/main".to_string()));
+    }
+
+    #[test]
+    fn owned_by() {
+        let options = get_warning_options(".");
+        assert!(options.owned_by.is_empty());
+        assert_eq!(options.codeowners, None);
+
+        let options = get_warning_options(
+            "--owned-by @org/web --owned-by web@example.com --codeowners OWNERS .",
+        );
+        assert_eq!(options.owned_by, ["@org/web", "web@example.com"]);
+        assert_eq!(options.codeowners, Some(PathBuf::from("OWNERS")));
+    }
+
     #[test]
     fn max_warnings() {
         let options = get_warning_options("--max-warnings 10 .");
@@ -515,7 +761,12 @@ mod lint_options {
 
     use oxc_linter::AllowWarnDeny;
 
-    use super::{LintCommand, OutputFormat, lint_command};
+    use oxc_diagnostics::SortKey;
+
+    use super::{
+        ColorChoice, FixReport, LintCommand, OutputFormat, ReportCommand, Shard, SortBy, SummaryBy,
+        lint_command,
+    };
 
     fn get_lint_options(arg: &str) -> LintCommand {
         let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
@@ -531,6 +782,18 @@ mod lint_options {
         assert_eq!(options.output_options.format, OutputFormat::Default);
     }
 
+    #[test]
+    fn config_inline() {
+        let options = get_lint_options(".");
+        assert_eq!(options.basic_options.config_inline, None);
+
+        let options = get_lint_options(r#"--config-inline {"rules":{"no-debugger":"error"}} ."#);
+        assert_eq!(
+            options.basic_options.config_inline.as_deref(),
+            Some(r#"{"rules":{"no-debugger":"error"}}"#)
+        );
+    }
+
     #[test]
     fn multiple_paths() {
         let temp_dir = tempfile::tempdir().expect("Could not create a temp dir");
@@ -570,6 +833,31 @@ mod lint_options {
         assert!(options.fix_options.fix);
     }
 
+    #[test]
+    fn fix_report() {
+        let options = get_lint_options("--fix test.js");
+        assert_eq!(options.fix_options.fix_report, None);
+        let options = get_lint_options("--fix --fix-report json test.js");
+        assert_eq!(options.fix_options.fix_report, Some(FixReport::Json));
+        let result = lint_command().run_inner(&["--fix-report", "yaml"]);
+        assert!(result.is_err_and(|err| err.unwrap_stderr()
+            == "couldn't parse `yaml`: 'yaml' is not a known fix report format"));
+    }
+
+    #[test]
+    fn fix_level() {
+        let options = get_lint_options("--fix test.js");
+        assert_eq!(options.fix_options.fix_level, None);
+        let options = get_lint_options("--fix --fix-level error test.js");
+        assert_eq!(options.fix_options.fix_level, Some(AllowWarnDeny::Deny));
+        let options = get_lint_options("--fix --fix-level warn test.js");
+        assert_eq!(options.fix_options.fix_level, Some(AllowWarnDeny::Warn));
+        let result = lint_command().run_inner(&["--fix-level", "off"]);
+        assert!(result.is_err_and(
+            |err| err.unwrap_stderr() == "`off`: Invalid fix level, expected `warn` or `error`"
+        ));
+    }
+
     #[test]
     fn filter() {
         let options =
@@ -590,6 +878,130 @@ mod lint_options {
         let options = get_lint_options("-f json");
         assert_eq!(options.output_options.format, OutputFormat::Json);
         assert!(options.paths.is_empty());
+        let options = get_lint_options("-f ndjson");
+        assert_eq!(options.output_options.format, OutputFormat::Ndjson);
+    }
+
+    #[test]
+    fn rule_origins() {
+        let options = get_lint_options("-f json .");
+        assert!(!options.output_options.rule_origins);
+        let options = get_lint_options("-f json --rule-origins .");
+        assert!(options.output_options.rule_origins);
+    }
+
+    #[test]
+    fn file_statuses() {
+        let options = get_lint_options("-f json .");
+        assert!(!options.output_options.file_statuses);
+        let options = get_lint_options("-f json --file-statuses .");
+        assert!(options.output_options.file_statuses);
+    }
+
+    #[test]
+    fn file_metadata() {
+        let options = get_lint_options("-f json .");
+        assert!(!options.output_options.file_metadata);
+        let options = get_lint_options("-f json --file-metadata .");
+        assert!(options.output_options.file_metadata);
+    }
+
+    #[test]
+    fn output_file() {
+        let options = get_lint_options("-f compact-stable --output-file lint.snap .");
+        assert_eq!(options.output_options.format, OutputFormat::CompactStable);
+        assert_eq!(options.output_options.output_file, Some(PathBuf::from("lint.snap")));
+        assert!(!options.output_options.check_snapshot);
+        let options = get_lint_options("--output-file lint.snap --check-snapshot .");
+        assert!(options.output_options.check_snapshot);
+    }
+
+    #[test]
+    fn sort_output() {
+        let options = get_lint_options("--sort-output .");
+        assert_eq!(
+            options.output_options.sort_by,
+            Some(SortBy(vec![SortKey::Path, SortKey::Rule]))
+        );
+        let options = get_lint_options("--sort-output --sort-by severity .");
+        assert_eq!(options.output_options.sort_by, Some(SortBy(vec![SortKey::Severity])));
+    }
+
+    #[test]
+    fn sort_by() {
+        let options = get_lint_options(".");
+        assert_eq!(options.output_options.sort_by, None);
+        let options = get_lint_options("--sort-by severity,path,rule .");
+        assert_eq!(
+            options.output_options.sort_by,
+            Some(SortBy(vec![SortKey::Severity, SortKey::Path, SortKey::Rule]))
+        );
+        let result = lint_command().run_inner(&["--sort-by", "severity,size"]);
+        assert!(result.is_err_and(|err| err.unwrap_stderr()
+            == "couldn't parse `severity,size`: 'size' is not a known sort key"));
+    }
+
+    #[test]
+    fn native_path_separators() {
+        let options = get_lint_options(".");
+        assert!(!options.output_options.native_path_separators);
+        let options = get_lint_options("--native-path-separators .");
+        assert!(options.output_options.native_path_separators);
+    }
+
+    #[test]
+    fn color() {
+        let options = get_lint_options(".");
+        assert_eq!(options.output_options.color, ColorChoice::Auto);
+        let options = get_lint_options("--color always .");
+        assert_eq!(options.output_options.color, ColorChoice::Always);
+        let options = get_lint_options("--color never .");
+        assert_eq!(options.output_options.color, ColorChoice::Never);
+        let result = lint_command().run_inner(&["--color", "sometimes"]);
+        assert!(result.is_err_and(|err| err.unwrap_stderr()
+            == "couldn't parse `sometimes`: 'sometimes' is not a known color choice"));
+    }
+
+    #[test]
+    fn summary_by() {
+        let options = get_lint_options(".");
+        assert_eq!(options.output_options.summary_by, None);
+        let options = get_lint_options("--summary-by dir .");
+        assert_eq!(options.output_options.summary_by, Some(SummaryBy::Dir));
+        let result = lint_command().run_inner(&["--summary-by", "file"]);
+        assert!(result.is_err_and(|err| err.unwrap_stderr()
+            == "couldn't parse `file`: 'file' is not a known summary mode"));
+    }
+
+    #[test]
+    fn shard() {
+        let options = get_lint_options(".");
+        assert_eq!(options.shard, None);
+        assert!(options.report_command.is_none());
+        let options = get_lint_options("--shard 2/8 .");
+        assert_eq!(options.shard, Some("2/8".parse::<Shard>().unwrap()));
+        let result = lint_command().run_inner(&["--shard", "9/8"]);
+        assert!(result.is_err());
+        let options = get_lint_options("merge-reports --max-warnings 2 shard-1.json shard-2.json");
+        assert_eq!(options.warning_options.max_warnings, Some(2));
+        let Some(ReportCommand::MergeReports { paths }) = options.report_command else {
+            panic!("expected the merge-reports command");
+        };
+        assert_eq!(paths, vec![PathBuf::from("shard-1.json"), PathBuf::from("shard-2.json")]);
+        let result = lint_command().run_inner(&["merge-reports"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn compare() {
+        let options = get_lint_options("compare old.json new.json");
+        let Some(ReportCommand::Compare { old, new }) = options.report_command else {
+            panic!("expected the compare command");
+        };
+        assert_eq!(old, PathBuf::from("old.json"));
+        assert_eq!(new, PathBuf::from("new.json"));
+        let result = lint_command().run_inner(&["compare", "old.json"]);
+        assert!(result.is_err());
     }
 
     #[test]
@@ -607,6 +1019,22 @@ mod lint_options {
         assert!(options.list_rules);
     }
 
+    #[test]
+    fn coverage_report() {
+        let options = get_lint_options("--coverage-report");
+        assert!(options.coverage_report);
+        let options = get_lint_options(".");
+        assert!(!options.coverage_report);
+    }
+
+    #[test]
+    fn list_files() {
+        let options = get_lint_options("--list-files");
+        assert!(options.list_files);
+        let options = get_lint_options(".");
+        assert!(!options.list_files);
+    }
+
     #[test]
     fn disable_nested_config() {
         let options = get_lint_options("--disable-nested-config");
@@ -630,6 +1058,37 @@ mod lint_options {
         let options = get_lint_options(".");
         assert!(!options.type_check);
     }
+
+    #[test]
+    fn stdin() {
+        let options = get_lint_options("--stdin");
+        assert!(options.stdin_options.stdin);
+        assert_eq!(options.stdin_options.stdin_filename, PathBuf::from("stdin.js"));
+        assert!(options.paths.is_empty());
+
+        let options = get_lint_options("--stdin --stdin-filename src/foo.ts --fix-to-stdout");
+        assert!(options.stdin_options.stdin);
+        assert!(options.fix_options.fix_to_stdout);
+        assert!(options.fix_options.fix_kind().is_some());
+        assert_eq!(options.stdin_options.stdin_filename, PathBuf::from("src/foo.ts"));
+    }
+
+    #[test]
+    fn diff() {
+        let options = get_lint_options("--diff");
+        assert!(options.stdin_options.diff);
+        assert!(!options.stdin_options.stdin);
+        assert!(!get_lint_options(".").stdin_options.diff);
+    }
+
+    #[test]
+    fn staged() {
+        let options = get_lint_options("--staged src");
+        assert!(options.stdin_options.staged);
+        assert!(!options.stdin_options.diff);
+        assert_eq!(options.paths, vec![PathBuf::from("src")]);
+        assert!(!get_lint_options(".").stdin_options.staged);
+    }
 }
 
 #[cfg(test)]
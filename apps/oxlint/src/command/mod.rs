@@ -1,16 +1,20 @@
 mod ignore;
 mod lint;
+mod report;
 
 use std::path::PathBuf;
 
 use bpaf::Bpaf;
 
+use crate::allocator::AllocatorBackend;
+
 pub use self::{
     ignore::IgnoreOptions,
     lint::{LintCommand, OutputOptions, ReportUnusedDirectives, WarningOptions, lint_command},
+    report::ReportCommand,
 };
 
-const VERSION: &str = match option_env!("OXC_VERSION") {
+pub const VERSION: &str = match option_env!("OXC_VERSION") {
     Some(v) => v,
     None => "dev",
 };
@@ -26,6 +30,85 @@ pub struct MiscOptions {
     #[bpaf(argument("INT"), hide_usage)]
     pub threads: Option<usize>,
 
+    /// Skip files larger than this many bytes without parsing them,
+    /// and report them with a single warning.
+    #[bpaf(argument("BYTES"), hide_usage)]
+    pub max_file_size: Option<usize>,
+
+    /// Skip files with more lines than this without parsing them,
+    /// and report them with a single warning.
+    #[bpaf(argument("INT"), hide_usage)]
+    pub max_file_lines: Option<usize>,
+
+    /// Skip files with more AST nodes than this, e.g. minified or generated files,
+    /// and report them with a single warning.
+    #[bpaf(argument("INT"), hide_usage)]
+    pub max_file_nodes: Option<usize>,
+
+    /// Skip files whose AST is nested deeper than this, and report them with a single warning.
+    #[bpaf(argument("INT"), hide_usage)]
+    pub max_file_depth: Option<usize>,
+
+    /// Stop linting a file after this many milliseconds, and report it with a single warning.
+    #[bpaf(argument("MS"), hide_usage)]
+    pub max_file_lint_time: Option<u64>,
+
+    /// Limit the memory held by files waiting to be linted, in megabytes.
+    /// Files are processed more serially to stay within it, instead of running out of memory.
+    #[bpaf(argument("MB"), hide_usage)]
+    pub max_memory: Option<usize>,
+
+    /// Read files on dedicated I/O threads ahead of parsing them.
+    /// Speeds up runs on a cold disk cache, e.g. on CI or network file systems.
+    #[bpaf(switch, hide_usage)]
+    pub prefetch: bool,
+
+    /// Print the time spent running the slowest rules to stderr at the end of the run.
+    /// Times are summed over all threads
+    #[bpaf(switch, hide_usage)]
+    pub timing: bool,
+
+    /// Reuse the diagnostics of rules on files which did not change since the last run with
+    /// `--cache`. Results are invalidated when the configuration or options of a rule change
+    #[bpaf(switch, hide_usage)]
+    pub cache: bool,
+
+    /// Path of the file used by `--cache`, `.oxlintcache` in the working directory by default
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub cache_location: Option<PathBuf>,
+
+    /// Fail if a rule is slower than allowed by PATH, a JSON file mapping rules to the maximum
+    /// milliseconds per 1000 AST nodes, e.g. `{ "import/no-cycle": 2 }`. Implies `--timing`
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub timing_budget: Option<PathBuf>,
+
+    /// Percentage by which a rule may exceed its `--timing-budget`, 10 by default
+    #[bpaf(argument("PERCENT"), fallback(10.0), hide_usage)]
+    pub timing_tolerance: f64,
+
+    /// Print statistics of the memory arenas used to parse files at the end of the run,
+    /// e.g. how many were created and the most memory used for a single file,
+    /// of the allocations made by the global allocator, in builds with the `allocation_stats`
+    /// feature, of the memory committed by mimalloc, when it is used,
+    /// and the number of files by status, e.g. `linted` or `parse-failed`
+    #[bpaf(switch, hide_usage)]
+    pub stats: bool,
+
+    /// Allocator to use: `mimalloc`, the default of release builds, or `system`, the allocator of
+    /// the C library, e.g. to use less memory in small containers. Other builds only include the
+    /// system allocator
+    #[bpaf(argument("NAME"), optional, hide_usage)]
+    pub allocator: Option<AllocatorBackend>,
+
+    /// Do not write a crash report to a temporary directory when oxlint crashes
+    #[bpaf(long, env("OXLINT_NO_CRASH_REPORT"), switch, hide_usage)]
+    pub no_crash_report: bool,
+
+    /// Include the source text of the file being linted in crash reports.
+    /// Only share such a report if the source text is not confidential
+    #[bpaf(switch, hide_usage)]
+    pub crash_report_source: bool,
+
     /// This option outputs the configuration to be used.
     /// When present, no linting is performed and only config-related options are valid.
     #[bpaf(switch, hide_usage)]
@@ -45,7 +128,9 @@ const PATHS_ERROR_MESSAGE: &str = "PATH must not contain \"..\"";
 
 #[cfg(test)]
 mod misc_options {
-    use super::{MiscOptions, lint::lint_command};
+    use std::path::PathBuf;
+
+    use super::{AllocatorBackend, MiscOptions, lint::lint_command};
 
     fn get_misc_options(arg: &str) -> MiscOptions {
         let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
@@ -63,4 +148,84 @@ mod misc_options {
         let options = get_misc_options("--threads 4 .");
         assert_eq!(options.threads, Some(4));
     }
+
+    #[test]
+    fn file_limits() {
+        let options = get_misc_options(".");
+        assert!(options.max_file_size.is_none());
+        assert!(options.max_file_lines.is_none());
+        assert!(options.max_file_nodes.is_none());
+        assert!(options.max_file_depth.is_none());
+        assert!(options.max_file_lint_time.is_none());
+
+        let options = get_misc_options(
+            "--max-file-size 1000000 --max-file-lines 20000 --max-file-nodes 100000 --max-file-depth 500 --max-file-lint-time 2000 .",
+        );
+        assert_eq!(options.max_file_size, Some(1_000_000));
+        assert_eq!(options.max_file_lines, Some(20_000));
+        assert_eq!(options.max_file_nodes, Some(100_000));
+        assert_eq!(options.max_file_depth, Some(500));
+        assert_eq!(options.max_file_lint_time, Some(2000));
+    }
+
+    #[test]
+    fn allocator() {
+        let options = get_misc_options(".");
+        assert!(options.allocator.is_none());
+
+        let options = get_misc_options("--allocator system .");
+        assert_eq!(options.allocator, Some(AllocatorBackend::System));
+    }
+
+    #[test]
+    fn cache() {
+        let options = get_misc_options(".");
+        assert!(!options.cache);
+        assert!(options.cache_location.is_none());
+
+        let options = get_misc_options("--cache --cache-location tmp/cache .");
+        assert!(options.cache);
+        assert_eq!(options.cache_location, Some(PathBuf::from("tmp/cache")));
+    }
+
+    #[test]
+    fn max_memory() {
+        let options = get_misc_options(".");
+        assert!(options.max_memory.is_none());
+
+        let options = get_misc_options("--max-memory 512 .");
+        assert_eq!(options.max_memory, Some(512));
+    }
+
+    #[test]
+    fn timing() {
+        let options = get_misc_options(".");
+        assert!(!options.timing);
+        assert!(options.timing_budget.is_none());
+        assert!((options.timing_tolerance - 10.0).abs() < f64::EPSILON);
+
+        let options =
+            get_misc_options("--timing --timing-budget budget.json --timing-tolerance 25 .");
+        assert!(options.timing);
+        assert_eq!(options.timing_budget, Some(std::path::PathBuf::from("budget.json")));
+        assert!((options.timing_tolerance - 25.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn stats() {
+        let options = get_misc_options(".");
+        assert!(!options.stats);
+
+        let options = get_misc_options("--stats .");
+        assert!(options.stats);
+    }
+
+    #[test]
+    fn prefetch() {
+        let options = get_misc_options(".");
+        assert!(!options.prefetch);
+
+        let options = get_misc_options("--prefetch .");
+        assert!(options.prefetch);
+    }
 }
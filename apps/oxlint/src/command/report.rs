@@ -0,0 +1,31 @@
+use std::path::PathBuf;
+
+use bpaf::Bpaf;
+
+/// Commands on reports written with `--format json`
+#[derive(Debug, Clone, Bpaf)]
+pub enum ReportCommand {
+    /// Combine the reports of `--shard` runs into one report
+    ///
+    /// Prints one list of diagnostics and exits with one exit code,
+    /// which respects `--deny-warnings` and `--max-warnings`
+    #[bpaf(command("merge-reports"))]
+    MergeReports {
+        /// Paths of the JSON reports
+        #[bpaf(positional("PATH"), some("Requires the paths of the JSON reports"))]
+        paths: Vec<PathBuf>,
+    },
+    /// Compare the report of an old run with the report of a new run
+    ///
+    /// Prints the added, removed and unchanged diagnostics,
+    /// and fails if the new report has diagnostics which are not in the old one
+    #[bpaf(command("compare"))]
+    Compare {
+        /// Path of the report of the old run
+        #[bpaf(positional("OLD"))]
+        old: PathBuf,
+        /// Path of the report of the new run
+        #[bpaf(positional("NEW"))]
+        new: PathBuf,
+    },
+}
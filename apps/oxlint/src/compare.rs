@@ -0,0 +1,88 @@
+use std::{fmt::Write, path::Path};
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    result::CliRunResult,
+    shard::{JsonDiagnostic, JsonReport, fnv1a},
+};
+
+/// Fingerprint of a diagnostic which does not change when lines are added or removed above it:
+/// a hash of its path, rule and message.
+fn fingerprint(diagnostic: &JsonDiagnostic) -> u64 {
+    let code = diagnostic.code.as_deref().unwrap_or_default();
+    let key = format!("{}\0{code}\0{}", diagnostic.filename, diagnostic.message);
+    fnv1a(key.as_bytes())
+}
+
+/// Compare the JSON report of an old run with the report of a new run (both relative to `cwd`),
+/// and print the added, removed and unchanged diagnostics.
+///
+/// Diagnostics are matched by [`fingerprint`]. When a fingerprint occurs more often in one
+/// report, the occurrences furthest down the file are the added or removed ones.
+/// The run fails if the new report has diagnostics which are not in the old report.
+pub fn compare_reports(cwd: &Path, old_path: &Path, new_path: &Path) -> (String, CliRunResult) {
+    let (old_report, new_report) =
+        match (JsonReport::read(cwd, old_path), JsonReport::read(cwd, new_path)) {
+            (Ok(old_report), Ok(new_report)) => (old_report, new_report),
+            (Err(err), _) | (_, Err(err)) => return (err, CliRunResult::InvalidOptionConfig),
+        };
+
+    let mut old_diagnostics = old_report.diagnostics;
+    old_diagnostics.sort_by(JsonDiagnostic::cmp_by_location);
+    let mut old_counts = FxHashMap::<u64, usize>::default();
+    for diagnostic in &old_diagnostics {
+        *old_counts.entry(fingerprint(diagnostic)).or_default() += 1;
+    }
+
+    let mut new_diagnostics = new_report.diagnostics;
+    new_diagnostics.sort_by(JsonDiagnostic::cmp_by_location);
+    let mut new_counts = FxHashMap::<u64, usize>::default();
+    let mut lines = vec![];
+    let mut added = 0;
+    let mut unchanged = 0;
+    for diagnostic in &new_diagnostics {
+        let fingerprint = fingerprint(diagnostic);
+        let count = new_counts.entry(fingerprint).or_default();
+        *count += 1;
+        let is_added = *count > old_counts.get(&fingerprint).copied().unwrap_or_default();
+        if is_added {
+            added += 1;
+        } else {
+            unchanged += 1;
+        }
+        lines.push((diagnostic, if is_added { '+' } else { ' ' }));
+    }
+
+    let mut removed = 0;
+    let mut seen_counts = FxHashMap::<u64, usize>::default();
+    for diagnostic in &old_diagnostics {
+        let fingerprint = fingerprint(diagnostic);
+        let count = seen_counts.entry(fingerprint).or_default();
+        *count += 1;
+        if *count > new_counts.get(&fingerprint).copied().unwrap_or_default() {
+            removed += 1;
+            lines.push((diagnostic, '-'));
+        }
+    }
+    lines.sort_by(|(a, _), (b, _)| a.cmp_by_location(b));
+
+    let mut output = String::new();
+    for (diagnostic, prefix) in &lines {
+        let _ = writeln!(output, "{prefix} {}", diagnostic.to_line());
+    }
+    if !lines.is_empty() {
+        output.push('\n');
+    }
+    let _ = writeln!(
+        output,
+        "Compared {} with {}: {added} diagnostic{} added, {removed} removed and {unchanged} unchanged.",
+        old_path.display(),
+        new_path.display(),
+        if added == 1 { "" } else { "s" },
+    );
+
+    let result =
+        if added > 0 { CliRunResult::LintNewDiagnosticsFound } else { CliRunResult::LintSucceeded };
+    (output, result)
+}
@@ -0,0 +1,87 @@
+use std::{fmt::Write, path::Path};
+
+use oxc_linter::{AllowWarnDeny, Config, LintPlugins, rules::RULES};
+use rustc_hash::FxHashMap;
+
+/// Number of rules of a plugin by their state in a configuration.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct RuleCoverage {
+    /// Set to `"warn"` or `"error"`.
+    enabled: usize,
+    /// Turned off with `"off"` in `rules`.
+    disabled: usize,
+    /// Neither enabled nor turned off, so the rule does not run.
+    unset: usize,
+}
+
+/// For each plugin enabled in `config`, the number of its rules which are enabled, disabled and
+/// unset, in the order of [`RULES`].
+fn rule_coverage(config: &Config) -> Vec<(&'static str, RuleCoverage)> {
+    let plugins = config.plugins();
+    let severities = config
+        .configured_rules()
+        .iter()
+        .map(|(rule, severity)| (rule.id(), *severity))
+        .collect::<FxHashMap<_, _>>();
+    let mut coverage = Vec::<(&'static str, RuleCoverage)>::new();
+    for rule in RULES.iter() {
+        let Ok(plugin) = LintPlugins::try_from(rule.plugin_name()) else { continue };
+        // The rules of ESLint itself have no plugin flag, and are always available.
+        if !plugin.is_empty() && !plugins.contains(plugin) {
+            continue;
+        }
+        let name = if plugin.is_empty() { "eslint" } else { <&'static str>::from(plugin) };
+        let index = coverage.iter().position(|(n, _)| *n == name).unwrap_or_else(|| {
+            coverage.push((name, RuleCoverage::default()));
+            coverage.len() - 1
+        });
+        let counts = &mut coverage[index].1;
+        match severities.get(&rule.id()) {
+            Some(AllowWarnDeny::Allow) => counts.disabled += 1,
+            Some(_) => counts.enabled += 1,
+            None => counts.unset += 1,
+        }
+    }
+    coverage
+}
+
+/// The rule coverage report printed by `--coverage-report`: for each enabled plugin, the number
+/// of its rules which are enabled, disabled and unset in the root configuration and in each of
+/// the `nested_configs`, listed by their directory relative to `cwd`.
+pub fn render_coverage_report(
+    cwd: &Path,
+    root_config: &Config,
+    nested_configs: &[(&Path, &Config)],
+) -> String {
+    let mut configs = vec![];
+    // A nested config in `cwd` is the same file as the root config.
+    if !nested_configs.iter().any(|(dir, _)| *dir == cwd) {
+        configs.push((cwd, root_config));
+    }
+    configs.extend(nested_configs.iter().copied());
+    configs.sort_unstable_by_key(|(dir, _)| *dir);
+
+    let mut output = String::new();
+    for (dir, config) in configs {
+        let dir = dir.strip_prefix(cwd).unwrap_or(dir);
+        let dir = if dir.as_os_str().is_empty() { Path::new(".") } else { dir };
+        if !output.is_empty() {
+            output.push('\n');
+        }
+        let _ = writeln!(output, "Rule coverage of the configuration in {}:", dir.display());
+        let _ = writeln!(
+            output,
+            "{:<12}{:>9}{:>10}{:>7}{:>7}",
+            "Plugin", "Enabled", "Disabled", "Unset", "Total"
+        );
+        for (plugin, counts) in rule_coverage(config) {
+            let total = counts.enabled + counts.disabled + counts.unset;
+            let _ = writeln!(
+                output,
+                "{plugin:<12}{:>9}{:>10}{:>7}{total:>7}",
+                counts.enabled, counts.disabled, counts.unset
+            );
+        }
+    }
+    output
+}
@@ -0,0 +1,328 @@
+use std::{
+    any::Any,
+    fs, io,
+    panic::PanicHookInfo,
+    path::{Path, PathBuf},
+    sync::{Mutex, PoisonError},
+};
+
+use serde::Serialize;
+
+use oxc_linter::{Oxlintrc, current_file};
+
+use crate::{
+    cli::{LintCommand, VERSION},
+    shard::fnv1a,
+};
+
+/// What is known about the run when it crashes, set before linting starts.
+struct CrashContext {
+    args: Vec<String>,
+    cwd: Option<PathBuf>,
+    config_path: Option<PathBuf>,
+    config_hash: Option<String>,
+    include_source: bool,
+}
+
+static CRASH_CONTEXT: Mutex<Option<CrashContext>> = Mutex::new(None);
+
+/// What is known about a panic where it happens, recorded by the panic hook.
+struct PanicDetails {
+    /// The file being parsed or linted by the panicking thread
+    file: Option<PathBuf>,
+    message: String,
+    location: Option<String>,
+    thread: Option<String>,
+    backtrace: String,
+}
+
+/// The content of `crash.json` in a crash report directory.
+///
+/// Like the arguments, paths are redacted: the working directory is not reported, and paths are
+/// relative to it, see [`redact_path`].
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CrashReport {
+    version: &'static str,
+    args: Vec<String>,
+    config_path: Option<String>,
+    /// Hash of the root configuration, to tell whether two reports were produced with the same
+    /// configuration without sharing it
+    config_hash: Option<String>,
+    /// The file being parsed or linted by the panicking thread
+    file: Option<String>,
+    /// Name of the copy of `file` in the crash report directory, with `--crash-report-source`
+    source: Option<String>,
+    message: String,
+    location: Option<String>,
+    thread: Option<String>,
+    backtrace: String,
+}
+
+/// Install a panic hook which writes a crash report to a temporary directory and prints its
+/// location, unless disabled with `--no-crash-report`.
+///
+/// The report is written by the hook itself, because release builds abort right after the hook
+/// returns without unwinding. Only the first panic of a run is reported.
+///
+/// `args` are the command line arguments, without the name of the program.
+pub fn init_crash_report(command: &LintCommand, args: &[String]) {
+    if command.misc_options.no_crash_report {
+        return;
+    }
+
+    install_crash_report_hook(CrashContext {
+        args: redact_args(args),
+        cwd: std::env::current_dir().ok(),
+        config_path: None,
+        config_hash: None,
+        include_source: command.misc_options.crash_report_source,
+    });
+}
+
+fn install_crash_report_hook(context: CrashContext) {
+    *CRASH_CONTEXT.lock().unwrap_or_else(PoisonError::into_inner) = Some(context);
+
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        default_hook(info);
+        report_crash(info);
+    }));
+}
+
+/// Replace the values of command line arguments, which may contain paths, patterns and rule
+/// options, so that only the names of the options used are reported.
+fn redact_args(args: &[String]) -> Vec<String> {
+    args.iter()
+        .map(|arg| match arg.split_once('=') {
+            Some((name, _)) if name.starts_with('-') => format!("{name}=<redacted>"),
+            None if arg.starts_with('-') => arg.clone(),
+            _ => "<redacted>".to_string(),
+        })
+        .collect()
+}
+
+/// `path` relative to the working directory `cwd`, with `/` separators, or only the name of the
+/// file as `<redacted>/name` if it is outside of `cwd`, so that reports do not reveal the
+/// directories of users.
+fn redact_path(path: &Path, cwd: Option<&Path>) -> String {
+    match cwd.and_then(|cwd| path.strip_prefix(cwd).ok()) {
+        Some(relative) => relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/"),
+        None => format!(
+            "<redacted>/{}",
+            path.file_name().map_or_else(String::new, |name| name.to_string_lossy().into_owned())
+        ),
+    }
+}
+
+/// Record the root configuration of the run in crash reports.
+pub fn set_crash_report_config(oxlintrc: &Oxlintrc) {
+    if let Some(context) = CRASH_CONTEXT.lock().unwrap_or_else(PoisonError::into_inner).as_mut() {
+        context.config_path =
+            (!oxlintrc.path.as_os_str().is_empty()).then(|| oxlintrc.path.clone());
+        context.config_hash = serde_json::to_string(oxlintrc)
+            .ok()
+            .map(|json| format!("{:016x}", fnv1a(json.as_bytes())));
+    }
+}
+
+#[expect(clippy::print_stderr)]
+fn report_crash(info: &PanicHookInfo) {
+    let context = CRASH_CONTEXT.lock().unwrap_or_else(PoisonError::into_inner).take();
+    let Some(context) = context else {
+        return;
+    };
+    let details = PanicDetails::new(info);
+    let result = tempfile::Builder::new()
+        .prefix("oxlint-crash-")
+        .tempdir()
+        .map(tempfile::TempDir::keep)
+        .and_then(|dir| {
+            let source_file = details.file.clone();
+            let report = CrashReport::new(context, details);
+            write_crash_report(&dir, &report, source_file.as_deref()).map(|()| dir)
+        });
+    match result {
+        Ok(dir) => eprintln!(
+            "\noxlint crashed. A crash report was written to {}\nPlease attach it to a bug report at https://github.com/oxc-project/oxc/issues/new",
+            dir.display()
+        ),
+        Err(err) => eprintln!("\noxlint crashed. Failed to write a crash report: {err}"),
+    }
+}
+
+impl PanicDetails {
+    fn new(info: &PanicHookInfo) -> Self {
+        Self {
+            file: current_file(),
+            message: panic_message(info.payload()),
+            location: info
+                .location()
+                .map(|location| format!("{}:{}", location.file(), location.line())),
+            thread: std::thread::current().name().map(ToString::to_string),
+            backtrace: std::backtrace::Backtrace::force_capture().to_string(),
+        }
+    }
+}
+
+impl CrashReport {
+    fn new(context: CrashContext, details: PanicDetails) -> Self {
+        let source = details
+            .file
+            .as_deref()
+            .filter(|_| context.include_source)
+            .and_then(Path::file_name)
+            .map(|name| name.to_string_lossy().into_owned());
+        let cwd = context.cwd.as_deref();
+        Self {
+            version: VERSION,
+            args: context.args,
+            config_path: context.config_path.as_deref().map(|path| redact_path(path, cwd)),
+            config_hash: context.config_hash,
+            file: details.file.as_deref().map(|path| redact_path(path, cwd)),
+            source,
+            message: details.message,
+            location: details.location,
+            thread: details.thread,
+            backtrace: details.backtrace,
+        }
+    }
+}
+
+/// Write `report` as `crash.json` into `dir`, along with a copy of `source_file`, the crashing
+/// file, if the report references one.
+fn write_crash_report(
+    dir: &Path,
+    report: &CrashReport,
+    source_file: Option<&Path>,
+) -> io::Result<()> {
+    if let (Some(file), Some(source)) = (source_file, &report.source) {
+        fs::copy(file, dir.join(source))?;
+    }
+    let json = serde_json::to_string_pretty(&report).map_err(io::Error::other)?;
+    fs::write(dir.join("crash.json"), json)
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path, process::Command};
+
+    use super::{
+        CrashContext, CrashReport, install_crash_report_hook, panic_message, redact_args,
+        redact_path, write_crash_report,
+    };
+
+    #[test]
+    fn crash_report_bundle() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("input.js");
+        fs::write(&file, "debugger;").unwrap();
+        let bundle = dir.path().join("bundle");
+        fs::create_dir(&bundle).unwrap();
+
+        let report = CrashReport {
+            version: "dev",
+            args: vec!["input.js".to_string()],
+            config_path: None,
+            config_hash: Some("0123456789abcdef".to_string()),
+            file: Some(redact_path(&file, Some(dir.path()))),
+            source: Some("input.js".to_string()),
+            message: panic_message(&"index out of bounds"),
+            location: Some("crates/oxc_linter/src/rules/foo.rs:1".to_string()),
+            thread: None,
+            backtrace: String::new(),
+        };
+        write_crash_report(&bundle, &report, Some(&file)).unwrap();
+
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(bundle.join("crash.json")).unwrap()).unwrap();
+        assert_eq!(json["message"], "index out of bounds");
+        assert_eq!(json["configHash"], "0123456789abcdef");
+        assert_eq!(json["file"], "input.js");
+        assert_eq!(json["source"], "input.js");
+        assert_eq!(fs::read_to_string(bundle.join("input.js")).unwrap(), "debugger;");
+    }
+
+    #[test]
+    fn crash_report_redacts_args() {
+        let args = ["-c", "secret.json", "--tsconfig=./secret/tsconfig.json", "--fix", "src"]
+            .map(String::from);
+        assert_eq!(
+            redact_args(&args),
+            ["-c", "<redacted>", "--tsconfig=<redacted>", "--fix", "<redacted>"]
+        );
+    }
+
+    #[test]
+    fn crash_report_redacts_paths() {
+        let cwd = Path::new("/home/user/project");
+        assert_eq!(redact_path(&cwd.join("src/index.js"), Some(cwd)), "src/index.js");
+        assert_eq!(
+            redact_path(Path::new("/home/user/.oxlintrc.json"), Some(cwd)),
+            "<redacted>/.oxlintrc.json"
+        );
+        assert_eq!(redact_path(&cwd.join("a.js"), None), "<redacted>/a.js");
+    }
+
+    /// Release builds are compiled with `panic = "abort"`, so the report must be written by the
+    /// panic hook before the process aborts. Tests always unwind, so the test binary runs itself
+    /// in a child process which aborts right after the hooks, as the abort strategy does.
+    #[test]
+    fn crash_report_written_before_abort() {
+        const CHILD: &str = "OXLINT_TEST_CRASH_REPORT_CHILD";
+
+        if std::env::var_os(CHILD).is_some() {
+            install_crash_report_hook(CrashContext {
+                args: vec!["--fix".to_string()],
+                cwd: None,
+                config_path: None,
+                config_hash: None,
+                include_source: false,
+            });
+            let hook = std::panic::take_hook();
+            std::panic::set_hook(Box::new(move |info| {
+                hook(info);
+                std::process::abort();
+            }));
+            panic!("crash before abort");
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let output = Command::new(std::env::current_exe().unwrap())
+            .args([
+                "crash_report::test::crash_report_written_before_abort",
+                "--exact",
+                "--nocapture",
+            ])
+            .env(CHILD, "1")
+            .env("TMPDIR", dir.path())
+            .output()
+            .unwrap();
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).contains("A crash report was written to"));
+
+        let reports = fs::read_dir(dir.path()).unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(reports.len(), 1);
+        assert!(reports[0].file_name().to_string_lossy().starts_with("oxlint-crash-"));
+        let json: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string(reports[0].path().join("crash.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(json["message"], "crash before abort");
+        assert_eq!(json["args"][0], "--fix");
+    }
+}
@@ -0,0 +1,331 @@
+use std::{
+    borrow::Cow,
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::Allocator;
+use oxc_linter::{RuntimeFileSystem, read_to_arena_str};
+
+/// File system used when linting the files changed by a unified diff read from stdin (`--diff`),
+/// or the files staged in the git index (`--staged`).
+///
+/// The new content of each changed file is reconstructed by applying the diff to the working tree,
+/// or read from the index, and reads of these files are served from memory. All other paths (e.g. dependencies resolved by
+/// the import plugin) are read from disk as usual.
+pub struct DiffFileSystem {
+    /// Paths of the changed files in the order of the diff.
+    paths: Vec<PathBuf>,
+    files: FxHashMap<PathBuf, String>,
+}
+
+/// Changes to a single file in a unified diff.
+struct FilePatch<'d> {
+    /// Path of the file before the change, `None` for new files.
+    old_path: Option<&'d str>,
+    /// Path of the file after the change.
+    new_path: &'d str,
+    hunks: Vec<Hunk<'d>>,
+}
+
+struct Hunk<'d> {
+    /// 1-based line of the old file the hunk starts at.
+    /// For hunks without old lines, the line after which the new lines are inserted.
+    old_start: usize,
+    old_count: usize,
+    /// Lines of the hunk, starting with ` `, `-`, `+` or `\`.
+    lines: Vec<&'d str>,
+}
+
+impl DiffFileSystem {
+    /// Apply `diff` to the files in `cwd`.
+    ///
+    /// Deleted files, and files for which `is_wanted` returns `false`, are skipped.
+    /// Paths in the diff are relative to `cwd`, with the `a/` and `b/` prefixes of git removed.
+    ///
+    /// # Errors
+    /// When a changed file cannot be read, or the diff does not apply to it.
+    pub fn from_unified_diff(
+        cwd: &Path,
+        diff: &str,
+        mut is_wanted: impl FnMut(&Path) -> bool,
+    ) -> Result<Self, String> {
+        let mut paths = Vec::new();
+        let mut files = FxHashMap::default();
+
+        for patch in parse_unified_diff(diff) {
+            let path = cwd.join(patch.new_path);
+            if !is_wanted(&path) {
+                continue;
+            }
+            let old_text = match patch.old_path {
+                Some(old_path) => fs::read_to_string(cwd.join(old_path))
+                    .map_err(|err| format!("Failed to read {old_path}: {err}"))?,
+                None => String::new(),
+            };
+            let new_text = patch.apply(&old_text).map_err(|line| {
+                format!("The diff does not apply to {}: line {line} differs.", patch.new_path)
+            })?;
+            if files.insert(path.clone(), new_text).is_none() {
+                paths.push(path);
+            }
+        }
+
+        Ok(Self { paths, files })
+    }
+
+    /// Read the files staged in the git index of the repository containing `cwd`, as they will be
+    /// committed, regardless of unstaged changes in the working tree.
+    ///
+    /// Deleted files, files for which `is_wanted` returns `false`, and files not matching
+    /// `pathspecs` (if any) are skipped. Pathspecs are relative to `cwd`.
+    ///
+    /// # Errors
+    /// When `git` fails, or a staged file is not valid UTF-8.
+    pub fn from_staged(
+        cwd: &Path,
+        pathspecs: &[PathBuf],
+        mut is_wanted: impl FnMut(&Path) -> bool,
+    ) -> Result<Self, String> {
+        let mut args =
+            ["diff", "--cached", "--name-only", "--diff-filter=ACMR", "--relative", "-z", "--"]
+                .into_iter()
+                .map(Cow::Borrowed)
+                .collect::<Vec<_>>();
+        args.extend(pathspecs.iter().map(|pathspec| pathspec.to_string_lossy()));
+        let names = git(cwd, &args)?;
+
+        let mut paths = Vec::new();
+        let mut files = FxHashMap::default();
+        for name in String::from_utf8_lossy(&names).split('\0').filter(|name| !name.is_empty()) {
+            let path = cwd.join(name);
+            if !is_wanted(&path) {
+                continue;
+            }
+            // `:./path` is the staged version of `path`, relative to `cwd`.
+            let source_text = git(cwd, &["show".into(), format!(":./{name}").into()])?;
+            let source_text = String::from_utf8(source_text).map_err(|_| {
+                format!("Failed to read {name}: the staged file is not valid UTF-8.")
+            })?;
+            files.insert(path.clone(), source_text);
+            paths.push(path);
+        }
+
+        Ok(Self { paths, files })
+    }
+
+    /// Paths of the changed files.
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+}
+
+impl RuntimeFileSystem for DiffFileSystem {
+    fn read_to_arena_str<'a>(
+        &'a self,
+        path: &Path,
+        allocator: &'a Allocator,
+    ) -> Result<&'a str, io::Error> {
+        if let Some(source_text) = self.files.get(path) {
+            return Ok(source_text);
+        }
+
+        read_to_arena_str(path, allocator)
+    }
+
+    fn write_file(&self, path: &Path, _content: &str) -> Result<(), io::Error> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("Cannot write {} when linting a diff", path.display()),
+        ))
+    }
+}
+
+/// Run `git` with `args` in `cwd`, and return its stdout.
+fn git(cwd: &Path, args: &[Cow<'_, str>]) -> Result<Vec<u8>, String> {
+    let command = || format!("git {}", args.join(" "));
+    match Command::new("git").args(args.iter().map(AsRef::as_ref)).current_dir(cwd).output() {
+        Ok(output) if output.status.success() => Ok(output.stdout),
+        Ok(output) => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(format!("Failed to run `{}`: {}", command(), stderr.trim()))
+        }
+        Err(err) => Err(format!("Failed to run `{}`: {err}", command())),
+    }
+}
+
+fn parse_unified_diff(diff: &str) -> Vec<FilePatch<'_>> {
+    let mut patches: Vec<FilePatch> = Vec::new();
+    let mut old_path = None;
+    // Hunks of deleted files are skipped.
+    let mut is_deleted = false;
+    // Number of lines of the old and new file left in the current hunk.
+    let mut old_remaining = 0_usize;
+    let mut new_remaining = 0_usize;
+
+    for diff_line in diff.lines() {
+        if old_remaining > 0 || new_remaining > 0 || diff_line.starts_with('\\') {
+            if diff_line.starts_with('+') {
+                new_remaining = new_remaining.saturating_sub(1);
+            } else if diff_line.starts_with('-') {
+                old_remaining = old_remaining.saturating_sub(1);
+            } else if !diff_line.starts_with('\\') {
+                old_remaining = old_remaining.saturating_sub(1);
+                new_remaining = new_remaining.saturating_sub(1);
+            }
+            if !is_deleted
+                && let Some(hunk) = patches.last_mut().and_then(|patch| patch.hunks.last_mut())
+            {
+                hunk.lines.push(diff_line);
+            }
+        } else if let Some(path) = diff_line.strip_prefix("--- ") {
+            old_path = Some(parse_path(path, "a/"));
+        } else if let Some(path) = diff_line.strip_prefix("+++ ") {
+            let new_path = parse_path(path, "b/");
+            let old_path = old_path.take().filter(|path| *path != "/dev/null");
+            is_deleted = new_path == "/dev/null";
+            if !is_deleted {
+                patches.push(FilePatch { old_path, new_path, hunks: vec![] });
+            }
+        } else if let Some(hunk) = diff_line.strip_prefix("@@ ") {
+            // `@@ -old_start,old_count +new_start,new_count @@`, counts default to 1.
+            let mut ranges = hunk.split(' ').take(2).map(|range| {
+                let range = range.get(1..).unwrap_or_default();
+                let mut parts = range.split(',').map(|n| n.parse::<usize>().unwrap_or(0));
+                (parts.next().unwrap_or(0), parts.next().unwrap_or(1))
+            });
+            let (old_start, old_count) = ranges.next().unwrap_or_default();
+            let (_, new_count) = ranges.next().unwrap_or_default();
+            old_remaining = old_count;
+            new_remaining = new_count;
+            if !is_deleted && let Some(patch) = patches.last_mut() {
+                patch.hunks.push(Hunk { old_start, old_count, lines: vec![] });
+            }
+        }
+    }
+
+    patches
+}
+
+/// Path of a `---` or `+++` line, which may be followed by a timestamp.
+fn parse_path<'d>(path: &'d str, prefix: &str) -> &'d str {
+    let path = path.split('\t').next().unwrap_or(path).trim_end();
+    path.strip_prefix(prefix).unwrap_or(path)
+}
+
+impl FilePatch<'_> {
+    /// Apply the hunks to `old_text`.
+    ///
+    /// # Errors
+    /// The 1-based line of `old_text` which does not match the diff.
+    fn apply(&self, old_text: &str) -> Result<String, usize> {
+        let old_lines = old_text.split_inclusive('\n').collect::<Vec<_>>();
+        let mut new_text = String::with_capacity(old_text.len());
+        // Number of old lines copied to `new_text` so far.
+        let mut copied = 0;
+
+        for hunk in &self.hunks {
+            // Hunks without old lines insert after `old_start`, others replace from `old_start`.
+            let hunk_start =
+                if hunk.old_count == 0 { hunk.old_start } else { hunk.old_start.saturating_sub(1) };
+            if hunk_start < copied || hunk_start > old_lines.len() {
+                return Err(hunk.old_start);
+            }
+            for line in &old_lines[copied..hunk_start] {
+                new_text.push_str(line);
+            }
+            copied = hunk_start;
+
+            let mut last_is_added = false;
+            for line in &hunk.lines {
+                let content = line.get(1..).unwrap_or_default();
+                match line.chars().next() {
+                    Some('+') => {
+                        new_text.push_str(content);
+                        new_text.push('\n');
+                        last_is_added = true;
+                    }
+                    Some('-' | ' ') | None => {
+                        let old_line = old_lines.get(copied).ok_or(copied + 1)?;
+                        if old_line.trim_end_matches(['\n', '\r']) != content {
+                            return Err(copied + 1);
+                        }
+                        if !line.starts_with('-') {
+                            new_text.push_str(old_line);
+                        }
+                        copied += 1;
+                        last_is_added = false;
+                    }
+                    // `\ No newline at end of file` of the previous line.
+                    _ => {
+                        if last_is_added {
+                            new_text.pop();
+                        }
+                    }
+                }
+            }
+        }
+
+        for line in &old_lines[copied..] {
+            new_text.push_str(line);
+        }
+        Ok(new_text)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_unified_diff;
+
+    fn apply(diff: &str, old_text: &str) -> Result<String, usize> {
+        parse_unified_diff(diff)[0].apply(old_text)
+    }
+
+    #[test]
+    fn test_apply() {
+        let diff = "\
+--- a/test.js
++++ b/test.js
+@@ -0,0 +1 @@
++debugger;
+@@ -2 +3,2 @@ function foo() {
+-  let a;
++  let a = 1;
++  debugger;
+";
+        let old_text = "function foo() {\n  let a;\n  return a;\n}\n";
+        assert_eq!(
+            apply(diff, old_text),
+            Ok("debugger;\nfunction foo() {\n  let a = 1;\n  debugger;\n  return a;\n}\n".into())
+        );
+        assert_eq!(apply(diff, "function foo() {\n  let b;\n}\n"), Err(2));
+    }
+
+    #[test]
+    fn test_new_and_deleted_files() {
+        let diff = "\
+diff --git a/old.js b/old.js
+deleted file mode 100644
+--- a/old.js
++++ /dev/null
+@@ -1 +0,0 @@
+-let a;
+diff --git a/new.js b/new.js
+new file mode 100644
+--- /dev/null
++++ b/new.js
+@@ -0,0 +1,2 @@
++let a;
++let b;
+\\ No newline at end of file
+";
+        let patches = parse_unified_diff(diff);
+        assert_eq!(patches.len(), 1);
+        assert_eq!(patches[0].old_path, None);
+        assert_eq!(patches[0].new_path, "new.js");
+        assert_eq!(patches[0].apply(""), Ok("let a;\nlet b;".into()));
+    }
+}
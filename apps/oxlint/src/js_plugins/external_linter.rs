@@ -1,32 +1,40 @@
-use std::sync::{atomic::Ordering, mpsc::channel};
+use std::sync::{
+    LazyLock, Mutex,
+    atomic::Ordering,
+    mpsc::{Sender, channel},
+};
 
 use napi::{
     Status,
     bindgen_prelude::{FnArgs, Uint8Array},
     threadsafe_function::ThreadsafeFunctionCallMode,
 };
+use rustc_hash::FxHashMap;
 use serde::Deserialize;
 
 use oxc_allocator::{Allocator, free_fixed_size_allocator};
 use oxc_linter::{
-    ExternalLinter, ExternalLinterLintFileCb, ExternalLinterLoadPluginCb, LintFileResult,
-    PluginLoadResult,
+    ExternalFileData, ExternalFileDataProvider, ExternalLinter, ExternalLinterLintFileCb,
+    ExternalLinterLoadPluginCb, ExternalLinterPreprocessCb, LintFileResult, PluginLoadResult,
+    PreprocessSection,
 };
 
 use crate::{
     generated::raw_transfer_constants::{BLOCK_ALIGN, BUFFER_SIZE},
-    run::{JsLintFileCb, JsLoadPluginCb},
+    run::{JsLintFileCb, JsLoadPluginCb, JsPreprocessCb},
 };
 
 /// Wrap JS callbacks as normal Rust functions, and create [`ExternalLinter`].
 pub fn create_external_linter(
     load_plugin: JsLoadPluginCb,
     lint_file: JsLintFileCb,
+    preprocess: JsPreprocessCb,
 ) -> ExternalLinter {
     let rust_load_plugin = wrap_load_plugin(load_plugin);
     let rust_lint_file = wrap_lint_file(lint_file);
+    let rust_preprocess = wrap_preprocess(preprocess);
 
-    ExternalLinter::new(rust_load_plugin, rust_lint_file)
+    ExternalLinter::new(rust_load_plugin, rust_lint_file, rust_preprocess)
 }
 
 /// Wrap `loadPlugin` JS callback as a normal Rust function.
@@ -59,6 +67,40 @@ pub enum LintFileReturnValue {
     Failure(String),
 }
 
+/// Message received by the thread which is linting a file, while it waits for `lintFile` to complete.
+enum LintFileMessage {
+    /// `lintFile` completed.
+    Result(Result<LintFileReturnValue, String>),
+    /// A JS plugin requested [`ExternalFileData`] for the file.
+    /// Reply is sent on the `Sender`.
+    FileData(ExternalFileData, Sender<FileDataReply>),
+}
+
+/// Location of [`ExternalFileData`] in the buffer: offset of the first `u32`, and number of `u32`s.
+type FileDataReply = (u32, u32);
+
+/// Senders for threads which are currently linting a file, keyed by ID of the file's buffer.
+///
+/// `requestFileData` uses these to ask the thread which owns the buffer to compute the data.
+static FILE_DATA_REQUESTS: LazyLock<Mutex<FxHashMap<u32, Sender<LintFileMessage>>>> =
+    LazyLock::new(|| Mutex::new(FxHashMap::default()));
+
+/// Request [`ExternalFileData`] for the file currently being linted in buffer `buffer_id`.
+///
+/// Called on main JS thread while `lintFile` is executing. Blocks until the thread linting the file
+/// has computed the data and written it into the buffer.
+///
+/// Returns `None` if no file is being linted in the buffer, or `kind` is not recognised.
+pub fn request_file_data(buffer_id: u32, kind: u32) -> Option<FileDataReply> {
+    let kind = ExternalFileData::from_raw(kind)?;
+    let (tx, rx) = channel();
+    {
+        let requests = FILE_DATA_REQUESTS.lock().unwrap();
+        requests.get(&buffer_id)?.send(LintFileMessage::FileData(kind, tx)).ok()?;
+    }
+    rx.recv().ok()
+}
+
 /// Wrap `lintFile` JS callback as a normal Rust function.
 ///
 /// The returned function creates a `Uint8Array` referencing the memory of the given `Allocator`,
@@ -68,12 +110,16 @@ pub enum LintFileReturnValue {
 /// on main JS thread, and therefore it may have to wait for a previous `lintFile` call to complete.
 /// Use an `mpsc::channel` to wait for the result from JS side, and block current thread until `lintFile`
 /// completes execution.
+///
+/// While waiting, the same channel receives requests from JS for [`ExternalFileData`].
+/// These are computed on this thread, as it's the one which owns the AST.
 fn wrap_lint_file(cb: JsLintFileCb) -> ExternalLinterLintFileCb {
     Box::new(
         move |file_path: String,
               rule_ids: Vec<u32>,
               settings_json: String,
-              allocator: &Allocator| {
+              allocator: &Allocator,
+              file_data: &ExternalFileDataProvider| {
             let (tx, rx) = channel();
 
             // SAFETY: This function is only called when an `ExternalLinter` exists.
@@ -85,20 +131,21 @@ fn wrap_lint_file(cb: JsLintFileCb) -> ExternalLinterLintFileCb {
             // TODO: Do that.
             let (buffer_id, buffer) = unsafe { get_buffer(allocator) };
 
+            FILE_DATA_REQUESTS.lock().unwrap().insert(buffer_id, tx.clone());
+
             // Send data to JS
             let status = cb.call_with_return_value(
                 FnArgs::from((file_path, buffer_id, buffer, rule_ids, settings_json)),
                 ThreadsafeFunctionCallMode::NonBlocking,
                 move |result, _env| {
-                    let _ = match &result {
+                    let message = match &result {
                         Ok(r) => match serde_json::from_str::<LintFileReturnValue>(r) {
-                            Ok(v) => tx.send(Ok(v)),
-                            Err(_e) => {
-                                tx.send(Err("Failed to deserialize lint result".to_string()))
-                            }
+                            Ok(v) => Ok(v),
+                            Err(_e) => Err("Failed to deserialize lint result".to_string()),
                         },
-                        Err(e) => tx.send(Err(e.to_string())),
+                        Err(e) => Err(e.to_string()),
                     };
+                    let _ = tx.send(LintFileMessage::Result(message));
 
                     result.map(|_| ())
                 },
@@ -106,18 +153,76 @@ fn wrap_lint_file(cb: JsLintFileCb) -> ExternalLinterLintFileCb {
 
             assert!(status == Status::Ok, "Failed to schedule callback: {status:?}");
 
-            match rx.recv() {
-                Ok(Ok(x)) => match x {
-                    LintFileReturnValue::Success(diagnostics) => Ok(diagnostics),
-                    LintFileReturnValue::Failure(err) => Err(err),
-                },
-                Ok(Err(err)) => panic!("Callback reported error: {err}"),
-                Err(err) => panic!("Callback did not respond: {err}"),
+            let result = loop {
+                match rx.recv() {
+                    Ok(LintFileMessage::FileData(kind, reply_tx)) => {
+                        let data = file_data(kind);
+                        // Offset of data within buffer (bottom 32 bits of pointer).
+                        // Data is within the buffer, so its length is less than `BUFFER_SIZE`.
+                        #[expect(clippy::cast_possible_truncation)]
+                        let reply = (data.as_ptr() as u32, data.len() as u32);
+                        let _ = reply_tx.send(reply);
+                    }
+                    Ok(LintFileMessage::Result(result)) => break result,
+                    Err(err) => panic!("Callback did not respond: {err}"),
+                }
+            };
+
+            FILE_DATA_REQUESTS.lock().unwrap().remove(&buffer_id);
+
+            match result {
+                Ok(LintFileReturnValue::Success(diagnostics)) => Ok(diagnostics),
+                Ok(LintFileReturnValue::Failure(err)) => Err(err),
+                Err(err) => panic!("Callback reported error: {err}"),
             }
         },
     )
 }
 
+/// Result returned by `preprocess` JS callback.
+#[derive(Clone, Debug, Deserialize)]
+pub enum PreprocessReturnValue {
+    Success(Vec<PreprocessSection>),
+    Failure(String),
+}
+
+/// Wrap `preprocess` JS callback as a normal Rust function.
+///
+/// Like `lintFile`, `preprocess` JS callback is not async, but runs on main JS thread.
+/// Use an `mpsc::channel` to wait for the result from JS side.
+fn wrap_preprocess(cb: JsPreprocessCb) -> ExternalLinterPreprocessCb {
+    Box::new(move |processor_id: u32, file_path: String, source_text: String| {
+        let (tx, rx) = channel();
+
+        let status = cb.call_with_return_value(
+            FnArgs::from((processor_id, file_path, source_text)),
+            ThreadsafeFunctionCallMode::NonBlocking,
+            move |result, _env| {
+                let _ = match &result {
+                    Ok(r) => match serde_json::from_str::<PreprocessReturnValue>(r) {
+                        Ok(v) => tx.send(Ok(v)),
+                        Err(_e) => {
+                            tx.send(Err("Failed to deserialize processor result".to_string()))
+                        }
+                    },
+                    Err(e) => tx.send(Err(e.to_string())),
+                };
+
+                result.map(|_| ())
+            },
+        );
+
+        assert!(status == Status::Ok, "Failed to schedule callback: {status:?}");
+
+        match rx.recv() {
+            Ok(Ok(PreprocessReturnValue::Success(sections))) => Ok(sections),
+            Ok(Ok(PreprocessReturnValue::Failure(err))) => Err(err),
+            Ok(Err(err)) => panic!("Callback reported error: {err}"),
+            Err(err) => panic!("Callback did not respond: {err}"),
+        }
+    })
+}
+
 /// Get buffer ID of the `Allocator` and, if it hasn't already been sent to JS,
 /// create a `Uint8Array` referencing the `Allocator`'s memory.
 ///
@@ -195,3 +300,62 @@ unsafe fn get_buffer(
 
     (buffer_id, Some(buffer))
 }
+
+#[cfg(test)]
+mod test {
+    use std::{sync::mpsc::channel, thread};
+
+    use oxc_linter::ExternalFileData;
+
+    use super::{FILE_DATA_REQUESTS, LintFileMessage, request_file_data};
+
+    const UNRESOLVED_REFERENCES: u32 = ExternalFileData::UnresolvedReferences as u32;
+
+    /// Handle requests for file data for `buffer_id` on another thread, as the thread linting
+    /// the file does, with `reply`.
+    fn with_linting_thread(
+        buffer_id: u32,
+        reply: impl FnOnce(ExternalFileData) -> Option<(u32, u32)> + Send + 'static,
+        f: impl FnOnce(),
+    ) {
+        let (tx, rx) = channel();
+        FILE_DATA_REQUESTS.lock().unwrap().insert(buffer_id, tx);
+        let linting_thread = thread::spawn(move || {
+            let Ok(LintFileMessage::FileData(kind, reply_tx)) = rx.recv() else {
+                panic!("expected a request for file data");
+            };
+            if let Some(location) = reply(kind) {
+                reply_tx.send(location).unwrap();
+            }
+        });
+        f();
+        linting_thread.join().unwrap();
+        FILE_DATA_REQUESTS.lock().unwrap().remove(&buffer_id);
+    }
+
+    #[test]
+    fn request_file_data_from_linting_thread() {
+        with_linting_thread(
+            u32::MAX,
+            |kind| {
+                assert_eq!(kind, ExternalFileData::UnresolvedReferences);
+                Some((64, 3))
+            },
+            || assert_eq!(request_file_data(u32::MAX, UNRESOLVED_REFERENCES), Some((64, 3))),
+        );
+    }
+
+    #[test]
+    fn request_file_data_errors() {
+        // Unknown kind of data
+        assert_eq!(request_file_data(u32::MAX - 1, u32::MAX), None);
+        // No file is being linted in the buffer
+        assert_eq!(request_file_data(u32::MAX - 1, UNRESOLVED_REFERENCES), None);
+        // The linting thread does not reply
+        with_linting_thread(
+            u32::MAX - 1,
+            |_| None,
+            || assert_eq!(request_file_data(u32::MAX - 1, UNRESOLVED_REFERENCES), None),
+        );
+    }
+}
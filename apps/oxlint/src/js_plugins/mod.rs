@@ -1,5 +1,5 @@
 mod external_linter;
 mod raw_fs;
 
-pub use external_linter::create_external_linter;
+pub use external_linter::{create_external_linter, request_file_data};
 pub use raw_fs::RawTransferFileSystem;
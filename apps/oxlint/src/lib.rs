@@ -1,12 +1,21 @@
 // Ignore dead code warnings when building `tasks/website`, which disables `napi` Cargo feature
 #![cfg_attr(not(feature = "napi"), allow(dead_code))]
 
+mod allocator;
 mod command;
+mod compare;
+mod coverage;
+mod crash_report;
+mod diff;
 mod init;
 mod lint;
 mod lsp;
 mod output_formatter;
 mod result;
+mod shard;
+mod stats;
+mod stdin;
+mod timing;
 mod walk;
 
 #[cfg(test)]
@@ -14,7 +23,10 @@ mod tester;
 
 /// Re-exported CLI-related items for use in `tasks/website`.
 pub mod cli {
-    pub use super::{command::*, init::*, lint::CliRunner, lsp::run_lsp, result::CliRunResult};
+    pub use super::{
+        command::*, crash_report::init_crash_report, init::*, lint::CliRunner, lsp::run_lsp,
+        result::CliRunResult,
+    };
 }
 
 // Only include code to run linter when the `napi` feature is enabled.
@@ -35,9 +47,21 @@ mod generated {
 #[cfg(all(feature = "napi", target_pointer_width = "64", target_endian = "little"))]
 mod js_plugins;
 
-// Use Mimalloc as the global allocator if `--features allocator` is enabled.
+// Use Mimalloc as the global allocator if `--features allocator` is enabled, unless
+// `--allocator system` selects the system allocator at runtime.
 // Mimalloc has better performance, but this is feature-gated because it's slow to compile.
 // `--features allocator` is only used in release builds.
-#[cfg(all(feature = "allocator", not(miri), not(target_family = "wasm")))]
+#[cfg(all(
+    feature = "allocator",
+    not(feature = "allocation_stats"),
+    not(miri),
+    not(target_family = "wasm")
+))]
 #[global_allocator]
-static GLOBAL: mimalloc_safe::MiMalloc = mimalloc_safe::MiMalloc;
+static GLOBAL: allocator::selectable::SelectableAllocator =
+    allocator::selectable::SelectableAllocator;
+
+// `--features allocation_stats` wraps the global allocator to count allocations for `--stats`.
+#[cfg(all(feature = "allocation_stats", not(miri), not(target_family = "wasm")))]
+#[global_allocator]
+static GLOBAL: allocator::counting::CountingAllocator = allocator::counting::CountingAllocator;
@@ -1,37 +1,74 @@
 use std::{
+    borrow::Cow,
+    collections::BTreeMap,
     env,
     ffi::OsStr,
     fs,
     io::{ErrorKind, Write},
     path::{Path, PathBuf, absolute},
-    sync::Arc,
-    time::Instant,
+    process::Command,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
 };
 
 use cow_utils::CowUtils;
-use ignore::{gitignore::Gitignore, overrides::OverrideBuilder};
+use ignore::overrides::OverrideBuilder;
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde_json::Value;
+use similar::TextDiff;
 
-use oxc_diagnostics::{DiagnosticSender, DiagnosticService, GraphicalReportHandler, OxcDiagnostic};
+use oxc_diagnostics::{
+    ChangedLines, CodeOwners, DiagnosticSender, DiagnosticService, GraphicalReportHandler,
+    NormalizedPath, Ownership, OxcDiagnostic, Severity,
+    reporter::{DiagnosticReporter, DiagnosticResult},
+};
 use oxc_linter::{
-    AllowWarnDeny, Config, ConfigStore, ConfigStoreBuilder, ExternalLinter, ExternalPluginStore,
-    InvalidFilterKind, LintFilter, LintOptions, LintRunner, LintServiceOptions, Linter, Oxlintrc,
+    AllowWarnDeny, CONFIG_VERSION, Config, ConfigMigration, ConfigStore, ConfigStoreBuilder,
+    ExternalLinter, ExternalPluginStore, FileLimits, FileMetadataMap, FileStatus, FileStatusMap,
+    FixedFile, FixedFilesMap, InvalidFilterKind, LintFilter, LintFilterKind, LintOptions,
+    LintPlugins, LintRunner, LintServiceOptions, Linter, Oxlintrc, ProjectFiles, RuleCategory,
+    RuleOrigin, RuleOriginsMap, RuleResultCache, RuleTimingsMap, diagnostic_codes,
     table::RuleTable,
 };
 
 use crate::{
-    cli::{CliRunResult, LintCommand, MiscOptions, ReportUnusedDirectives, WarningOptions},
-    output_formatter::{LintCommandInfo, OutputFormat, OutputFormatter},
-    walk::Walk,
+    allocator::{allocation_stats, mimalloc_stats, select_allocator, start_counting_allocations},
+    cli::{
+        CliRunResult, IgnoreOptions, LintCommand, MiscOptions, OutputOptions, ReportCommand,
+        ReportUnusedDirectives, WarningOptions,
+    },
+    coverage::render_coverage_report,
+    crash_report::set_crash_report_config,
+    diff::DiffFileSystem,
+    output_formatter::{
+        DirectorySummaryReporter, FixReport, LintCommandInfo, OutputFormat, OutputFormatter,
+        SummaryBy, graphical_theme,
+    },
+    stats::{
+        render_allocation_stats, render_allocator_pool_stats, render_file_status_stats,
+        render_mimalloc_stats,
+    },
+    stdin::StdinFileSystem,
+    timing::{TimingBudget, render_regressions, render_timings},
+    walk::{Extensions, PathIgnores, Walk},
 };
 use oxc_linter::LintIgnoreMatcher;
 
+/// How `--fix-config` and `--migrate-config` rewrite the configuration file.
+#[derive(Debug, Clone, Copy)]
+enum ConfigRewrite {
+    FixDeprecatedNames,
+    Migrate,
+}
+
 #[derive(Debug)]
 pub struct CliRunner {
     options: LintCommand,
     cwd: PathBuf,
     external_linter: Option<ExternalLinter>,
+    /// Source text to lint when `--stdin` is passed, or the diff of `--only-changed-lines`.
+    /// Read from stdin if not provided.
+    stdin: Option<String>,
 }
 
 impl CliRunner {
@@ -41,13 +78,18 @@ impl CliRunner {
             options,
             cwd: env::current_dir().expect("Failed to get current working directory"),
             external_linter,
+            stdin: None,
         }
     }
 
+    /// Lint with the options of the command, writing the diagnostics to `stdout`, and warnings
+    /// about the configuration to `stderr`.
+    ///
     /// # Panics
-    pub fn run(self, stdout: &mut dyn Write) -> CliRunResult {
+    pub fn run(self, stdout: &mut dyn Write, stderr: &mut dyn Write) -> CliRunResult {
         let format_str = self.options.output_options.format;
-        let output_formatter = OutputFormatter::new(format_str);
+        let use_colors = self.options.output_options.color.use_colors();
+        let output_formatter = OutputFormatter::new(format_str, use_colors);
 
         let LintCommand {
             paths,
@@ -60,9 +102,86 @@ impl CliRunner {
             misc_options,
             disable_nested_config,
             inline_config_options,
+            stdin_options,
+            shard,
+            report_command,
             ..
         } = self.options;
 
+        if let Some(allocator) = misc_options.allocator
+            && let Err(message) = select_allocator(allocator)
+        {
+            print_and_flush_stdout(stdout, &message);
+            return CliRunResult::InvalidOptionConfig;
+        }
+        if misc_options.stats {
+            start_counting_allocations();
+        }
+
+        if let Some(report_command) = report_command {
+            let (output, result) = match report_command {
+                ReportCommand::MergeReports { paths } => {
+                    crate::shard::merge_reports(&self.cwd, &paths, &warning_options)
+                }
+                ReportCommand::Compare { old, new } => {
+                    crate::compare::compare_reports(&self.cwd, &old, &new)
+                }
+            };
+            print_and_flush_stdout(stdout, &output);
+            return result;
+        }
+
+        if basic_options.config_inline.is_some() {
+            let conflicting_option = if basic_options.config.is_some() {
+                Some("--config")
+            } else if basic_options.fix_config {
+                Some("--fix-config")
+            } else if basic_options.migrate_config {
+                Some("--migrate-config")
+            } else {
+                None
+            };
+            if let Some(option) = conflicting_option {
+                print_and_flush_stdout(
+                    stdout,
+                    &format!(
+                        "The `--config-inline` option (or `OXLINT_CONFIG`) cannot be used with `{option}`.\n"
+                    ),
+                );
+                return CliRunResult::InvalidOptionConfig;
+            }
+        }
+
+        if basic_options.fix_config {
+            return Self::fix_config(stdout, &self.cwd, basic_options.config.as_ref());
+        }
+
+        if basic_options.migrate_config {
+            return Self::migrate_config(stdout, &self.cwd, basic_options.config.as_ref());
+        }
+
+        let output_options = &self.options.output_options;
+        if output_options.check_snapshot && output_options.output_file.is_none() {
+            print_and_flush_stdout(
+                stdout,
+                "The `--check-snapshot` option requires `--output-file`.\n",
+            );
+            return CliRunResult::InvalidOptionConfig;
+        }
+
+        let timing_budget = match misc_options.timing_budget.as_ref() {
+            Some(path) => match TimingBudget::from_file(&self.cwd, path) {
+                Ok(budget) => Some(budget),
+                Err(err) => {
+                    print_and_flush_stdout(stdout, &format!("{err}\n"));
+                    return CliRunResult::InvalidOptionConfig;
+                }
+            },
+            None => None,
+        };
+        let rule_timings_map =
+            (misc_options.timing || timing_budget.is_some()).then(RuleTimingsMap::default);
+
         let external_linter = self.external_linter.as_ref();
 
         let mut paths = paths;
@@ -77,14 +196,145 @@ impl CliRunner {
             }
         };
 
+        let quiet_codes = match Self::get_quiet_codes(&warning_options.quiet_rules) {
+            Ok(quiet_codes) => quiet_codes,
+            Err((result, message)) => {
+                print_and_flush_stdout(stdout, &message);
+                return result;
+            }
+        };
+
         let handler = if cfg!(any(test, feature = "force_test_reporter")) {
             GraphicalReportHandler::new_themed(miette::GraphicalTheme::none())
         } else {
-            GraphicalReportHandler::new()
+            GraphicalReportHandler::new_themed(graphical_theme(use_colors))
+        };
+
+        let mut stdin = self.stdin;
+
+        // `--diff` and `--staged` lint files from a virtual file system.
+        let virtual_files_option = if stdin_options.diff {
+            Some("--diff")
+        } else if stdin_options.staged {
+            Some("--staged")
+        } else {
+            None
+        };
+        if let Some(virtual_files_option) = virtual_files_option {
+            let conflicting_option = if stdin_options.stdin {
+                Some("--stdin")
+            } else if stdin_options.diff && stdin_options.staged {
+                Some("--staged")
+            } else if warning_options.only_changed_lines {
+                Some("--only-changed-lines")
+            } else if fix_options.is_enabled() {
+                Some("--fix")
+            } else if self.options.type_aware {
+                // `tsgolint` reads the files from disk, not the content of the virtual file system.
+                Some("--type-aware")
+            } else {
+                None
+            };
+            if let Some(option) = conflicting_option {
+                print_and_flush_stdout(
+                    stdout,
+                    &format!(
+                        "The `{virtual_files_option}` option cannot be used with `{option}`.\n"
+                    ),
+                );
+                return CliRunResult::InvalidOptionConfig;
+            }
+        }
+
+        let diff = if stdin_options.diff {
+            match stdin.take().map_or_else(StdinFileSystem::read_stdin, Ok) {
+                Ok(diff) => Some(diff),
+                Err(err) => {
+                    print_and_flush_stdout(
+                        stdout,
+                        &format!("Failed to read the diff from stdin: {err}\n"),
+                    );
+                    return CliRunResult::InvalidOptionConfig;
+                }
+            }
+        } else {
+            None
+        };
+
+        let changed_lines = if let Some(diff) = &diff {
+            Some(ChangedLines::from_unified_diff(diff))
+        } else {
+            match Self::get_changed_lines(
+                &self.cwd,
+                &warning_options,
+                stdin_options.stdin,
+                &mut stdin,
+            ) {
+                Ok(changed_lines) => changed_lines,
+                Err((result, message)) => {
+                    print_and_flush_stdout(stdout, &message);
+                    return result;
+                }
+            }
+        };
+
+        let ownership = match Self::get_ownership(&self.cwd, &warning_options) {
+            Ok(ownership) => ownership,
+            Err((result, message)) => {
+                print_and_flush_stdout(stdout, &message);
+                return result;
+            }
+        };
+
+        if fix_options.fix_to_stdout && !stdin_options.stdin {
+            print_and_flush_stdout(stdout, "The `--fix-to-stdout` option requires `--stdin`.\n");
+            return CliRunResult::InvalidOptionConfig;
+        }
+
+        if fix_options.fix_to_stdout && fix_options.fix_report.is_some() {
+            print_and_flush_stdout(
+                stdout,
+                "The `--fix-report` option cannot be used with `--fix-to-stdout`.\n",
+            );
+            return CliRunResult::InvalidOptionConfig;
+        }
+
+        if fix_options.fix_level.is_some() && !fix_options.is_enabled() {
+            print_and_flush_stdout(
+                stdout,
+                "The `--fix-level` option requires a fix option such as `--fix`.\n",
+            );
+            return CliRunResult::InvalidOptionConfig;
+        }
+
+        let stdin_file_system = if stdin_options.stdin {
+            if self.external_linter.is_some() {
+                print_and_flush_stdout(
+                    stdout,
+                    "The `--stdin` option is not supported in combination with JS plugins.\n",
+                );
+                return CliRunResult::InvalidOptionConfig;
+            }
+            let source_text = match stdin.take().map_or_else(StdinFileSystem::read_stdin, Ok) {
+                Ok(source_text) => source_text,
+                Err(err) => {
+                    print_and_flush_stdout(stdout, &format!("Failed to read from stdin: {err}\n"));
+                    return CliRunResult::InvalidOptionConfig;
+                }
+            };
+            let Ok(path) = absolute(self.cwd.join(&stdin_options.stdin_filename)) else {
+                print_and_flush_stdout(stdout, "Invalid `--stdin-filename` path.\n");
+                return CliRunResult::InvalidOptionConfig;
+            };
+            Some(StdinFileSystem::new(path, source_text))
+        } else {
+            None
         };
 
-        let config_search_result =
-            Self::find_oxlint_config(&self.cwd, basic_options.config.as_ref());
+        let config_search_result = match &basic_options.config_inline {
+            Some(json) => Oxlintrc::from_inline(json, &self.cwd.join(Self::INLINE_CONFIG_NAME)),
+            None => Self::find_oxlint_config(&self.cwd, basic_options.config.as_ref()),
+        };
 
         let mut oxlintrc = match config_search_result {
             Ok(config) => config,
@@ -100,22 +350,68 @@ impl CliRunner {
                 return CliRunResult::InvalidOptionConfig;
             }
         };
+        set_crash_report_config(&oxlintrc);
+
+        if let Err(message) =
+            Self::expand_path_sets(&self.cwd, &oxlintrc, &ignore_options, &mut paths)
+        {
+            print_and_flush_stdout(stdout, &message);
+            return CliRunResult::InvalidOptionConfig;
+        }
+
+        // Statuses of files are reported by `--stats`, and in the JSON output with `--file-statuses`
+        let output_file_statuses = self.options.output_options.file_statuses
+            && self.options.output_options.format == OutputFormat::Json;
+        let file_status_map =
+            (misc_options.stats || output_file_statuses).then(FileStatusMap::default);
 
         let mut override_builder = None;
+        let mut path_ignores = None;
 
         if !ignore_options.no_ignore {
             let mut builder = OverrideBuilder::new(&self.cwd);
 
-            if !ignore_options.ignore_pattern.is_empty() {
-                for pattern in &ignore_options.ignore_pattern {
-                    // Meaning of ignore pattern is reversed
-                    // <https://docs.rs/ignore/latest/ignore/overrides/struct.OverrideBuilder.html#method.add>
-                    let pattern = format!("!{pattern}");
-                    builder.add(&pattern).unwrap();
+            let mut ignore_patterns = ignore_options.ignore_pattern.clone();
+            if let Some(pattern_file) = &ignore_options.ignore_pattern_file {
+                match fs::read_to_string(self.cwd.join(pattern_file)) {
+                    Ok(contents) => ignore_patterns.extend(
+                        contents
+                            .lines()
+                            .map(str::trim)
+                            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                            .map(String::from),
+                    ),
+                    Err(err) => {
+                        print_and_flush_stdout(
+                            stdout,
+                            &format!(
+                                "Failed to read ignore pattern file {}: {err}\n",
+                                pattern_file.display()
+                            ),
+                        );
+                        return CliRunResult::InvalidOptionConfig;
+                    }
+                }
+            }
+
+            for pattern in &ignore_patterns {
+                // Meaning of ignore pattern is reversed
+                // <https://docs.rs/ignore/latest/ignore/overrides/struct.OverrideBuilder.html#method.add>
+                if let Err(err) = builder.add(&format!("!{pattern}")) {
+                    print_and_flush_stdout(
+                        stdout,
+                        &format!("Invalid ignore pattern `{pattern}`: {err}\n"),
+                    );
+                    return CliRunResult::InvalidOptionConfig;
                 }
             }
 
             let builder = builder.build().unwrap();
+            let mut ignores = PathIgnores::new(
+                builder.clone(),
+                &self.cwd.join(&ignore_options.ignore_path),
+                &self.cwd,
+            );
 
             // The ignore crate whitelists explicit paths, but priority
             // should be given to the ignore file. Many users lint
@@ -123,8 +419,6 @@ impl CliRunner {
             // To accommodate this, unless `--no-ignore` is passed,
             // pre-filter the paths.
             if !paths.is_empty() {
-                let (ignore, _err) = Gitignore::new(&ignore_options.ignore_path);
-
                 paths.retain_mut(|p| {
                     // Try to prepend cwd to all paths
                     let Ok(mut path) = absolute(self.cwd.join(&p)) else {
@@ -134,17 +428,57 @@ impl CliRunner {
                     std::mem::swap(p, &mut path);
 
                     if path.is_dir() {
-                        true
-                    } else {
-                        !(builder.matched(p, false).is_ignore()
-                            || ignore.matched(path, false).is_ignore())
+                        return true;
                     }
+                    let ignored = ignores.is_ignored(p);
+                    if ignored && let Some(map) = &file_status_map {
+                        map.lock()
+                            .expect("file_status_map mutex poisoned")
+                            .insert(p.clone(), FileStatus::SkippedIgnored);
+                    }
+                    !ignored
                 });
             }
 
             override_builder = Some(builder);
+            path_ignores = Some(ignores);
         }
 
+        let diff_file_system = if diff.is_some() || stdin_options.staged {
+            let extensions = Extensions::for_config(&oxlintrc);
+            // The changed files are not walked, so they are checked against the ignore files here.
+            let mut path_ignores = path_ignores.map(PathIgnores::with_gitignore);
+            let is_wanted = |path: &Path| {
+                if !path
+                    .extension()
+                    .and_then(OsStr::to_str)
+                    .is_some_and(|ext| extensions.0.iter().any(|e| e == ext))
+                {
+                    return false;
+                }
+                let ignored = path_ignores.as_mut().is_some_and(|ignores| ignores.is_ignored(path));
+                if ignored && let Some(map) = &file_status_map {
+                    map.lock()
+                        .expect("file_status_map mutex poisoned")
+                        .insert(path.to_path_buf(), FileStatus::SkippedIgnored);
+                }
+                !ignored
+            };
+            let diff_file_system = match &diff {
+                Some(diff) => DiffFileSystem::from_unified_diff(&self.cwd, diff, is_wanted),
+                None => DiffFileSystem::from_staged(&self.cwd, &paths, is_wanted),
+            };
+            match diff_file_system {
+                Ok(diff_file_system) => Some(diff_file_system),
+                Err(err) => {
+                    print_and_flush_stdout(stdout, &format!("{err}\n"));
+                    return CliRunResult::InvalidOptionConfig;
+                }
+            }
+        } else {
+            None
+        };
+
         if paths.is_empty() {
             // If explicit paths were provided, but all have been
             // filtered, return early.
@@ -154,6 +488,18 @@ impl CliRunner {
                     number_of_rules: None,
                     threads_count: rayon::current_num_threads(),
                     start_time: now.elapsed(),
+                    file_metadata: None,
+                    rule_origins: None,
+                    file_statuses: file_status_map.as_ref().filter(|_| output_file_statuses).map(
+                        |map| {
+                            Self::by_displayed_path(
+                                map,
+                                &self.cwd,
+                                self.options.output_options.native_path_separators,
+                            )
+                        },
+                    ),
+                    code_counts: BTreeMap::new(),
                 }) {
                     print_and_flush_stdout(stdout, &end);
                 }
@@ -164,21 +510,49 @@ impl CliRunner {
             paths.push(self.cwd.clone());
         }
 
-        let walker = Walk::new(&paths, &ignore_options, override_builder);
-        let paths = walker.paths();
+        let paths = if let Some(stdin_file_system) = &stdin_file_system {
+            vec![Arc::<OsStr>::from(stdin_file_system.path().as_os_str())]
+        } else if let Some(diff_file_system) = &diff_file_system {
+            diff_file_system
+                .paths()
+                .iter()
+                .filter_map(|path| absolute(path).ok())
+                .map(|path| Arc::<OsStr>::from(path.as_os_str()))
+                .collect()
+        } else {
+            let extensions = Extensions::for_config(&oxlintrc);
+            // Files passed explicitly are skipped by the walk if they cannot be linted
+            if let Some(map) = &file_status_map {
+                let mut map = map.lock().expect("file_status_map mutex poisoned");
+                for path in &paths {
+                    let path = self.cwd.join(path);
+                    if path.is_file()
+                        && !Walk::is_wanted_file(&path, &extensions)
+                        && let Ok(path) = absolute(path)
+                    {
+                        map.insert(path, FileStatus::SkippedUnsupported);
+                    }
+                }
+            }
+            let walker =
+                Walk::new(&paths, &ignore_options, override_builder).with_extensions(extensions);
+            walker.paths()
+        };
 
         let mut external_plugin_store = ExternalPluginStore::default();
 
         let search_for_nested_configs = !disable_nested_config &&
             // If the `--config` option is explicitly passed, we should not search for nested config files
-            // as the passed config file takes absolute precedence.
-            basic_options.config.is_none();
+            // as the passed config file takes absolute precedence. The same goes for `--config-inline`.
+            basic_options.config.is_none() &&
+            basic_options.config_inline.is_none();
 
         let mut nested_ignore_patterns = Vec::new();
 
         let nested_configs = if search_for_nested_configs {
             match Self::get_nested_configs(
                 stdout,
+                stderr,
                 &handler,
                 &filters,
                 &paths,
@@ -197,12 +571,24 @@ impl CliRunner {
             LintIgnoreMatcher::new(&oxlintrc.ignore_patterns, &self.cwd, nested_ignore_patterns)
         };
 
+        // Plugins detected from `package.json`, only when creating a configuration with `--init`
+        // and no plugins are configured yet.
+        let detected_plugins = if basic_options.init && oxlintrc.plugins.is_none() {
+            Self::detect_plugins(&self.cwd)
+        } else {
+            LintPlugins::empty()
+        };
+
         {
-            let mut plugins = oxlintrc.plugins.unwrap_or_default();
+            let mut plugins = oxlintrc.plugins.unwrap_or_default() | detected_plugins;
             enable_plugins.apply_overrides(&mut plugins);
             oxlintrc.plugins = Some(plugins);
         }
 
+        if basic_options.init && oxlintrc.categories.is_empty() {
+            oxlintrc.categories.insert(RuleCategory::Correctness, AllowWarnDeny::Warn);
+        }
+
         let oxlintrc_for_print = if misc_options.print_config || basic_options.init {
             Some(oxlintrc.clone())
         } else {
@@ -229,14 +615,31 @@ impl CliRunner {
         }
         .with_filters(&filters);
 
+        for warning in &config_builder.warnings {
+            print_and_flush_stdout(stderr, &render_report(&handler, warning));
+        }
+
         // If no external rules, discard `ExternalLinter`
         let mut external_linter = self.external_linter;
         if external_plugin_store.is_empty() {
             external_linter = None;
         }
 
+        if let Some(virtual_files_option) = virtual_files_option
+            && external_linter.is_some()
+        {
+            print_and_flush_stdout(
+                stdout,
+                &format!(
+                    "The `{virtual_files_option}` option is not supported in combination with JS plugins.\n"
+                ),
+            );
+            return CliRunResult::InvalidOptionConfig;
+        }
+
         if let Some(basic_config_file) = oxlintrc_for_print {
-            let config_file = config_builder.resolve_final_config_file(basic_config_file);
+            let config_file = config_builder
+                .resolve_final_config_file(basic_config_file, misc_options.print_config);
             if misc_options.print_config {
                 print_and_flush_stdout(stdout, &config_file);
                 print_and_flush_stdout(stdout, "\n");
@@ -262,6 +665,19 @@ impl CliRunner {
 
                 if fs::write(Self::DEFAULT_OXLINTRC, configuration).is_ok() {
                     print_and_flush_stdout(stdout, "Configuration file created\n");
+                    if !detected_plugins.is_empty() {
+                        let names = detected_plugins
+                            .iter()
+                            .map(<&'static str>::from)
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        print_and_flush_stdout(
+                            stdout,
+                            &format!(
+                                "Enabled plugins for the dependencies in package.json: {names}\n"
+                            ),
+                        );
+                    }
                     return CliRunResult::ConfigFileInitSucceeded;
                 }
 
@@ -273,9 +689,22 @@ impl CliRunner {
 
         // TODO(refactor): pull this into a shared function, so that the language server can use
         // the same functionality.
-        let use_cross_module = config_builder.plugins().has_import()
-            || nested_configs.values().any(|config| config.plugins().has_import());
-        let mut options = LintServiceOptions::new(self.cwd).with_cross_module(use_cross_module);
+        // `--syntax-only` runs no rules, so neither imports nor type information are needed.
+        let syntax_only = self.options.syntax_only;
+        let type_aware = self.options.type_aware && !syntax_only;
+        let type_check = self.options.type_check && !syntax_only;
+        let mut options = LintServiceOptions::new(self.cwd)
+            .with_prefetch(misc_options.prefetch)
+            .with_file_limits(FileLimits {
+                size: misc_options.max_file_size,
+                lines: misc_options.max_file_lines,
+                nodes: misc_options.max_file_nodes,
+                depth: misc_options.max_file_depth,
+                lint_time: misc_options.max_file_lint_time.map(Duration::from_millis),
+            });
+        if let Some(max_memory) = misc_options.max_memory {
+            options = options.with_max_memory(max_memory.saturating_mul(1024 * 1024));
+        }
 
         let lint_config = match config_builder.build(&external_plugin_store) {
             Ok(config) => config,
@@ -291,13 +720,42 @@ impl CliRunner {
             }
         };
 
+        // Imports are resolved if the import plugin is enabled for some files, even if only by an
+        // override. Files it is not enabled for skip resolving their imports.
+        let use_cross_module = !syntax_only
+            && (lint_config.all_plugins().has_import()
+                || nested_configs.values().any(|config| config.all_plugins().has_import()));
+        options = options.with_cross_module(use_cross_module);
+
+        if self.options.coverage_report {
+            let nested_configs = nested_configs
+                .iter()
+                .map(|(dir, config)| (dir.as_path(), config))
+                .collect::<Vec<_>>();
+            let report = render_coverage_report(options.cwd(), &lint_config, &nested_configs);
+            print_and_flush_stdout(stdout, &report);
+            return CliRunResult::None;
+        }
+
         let report_unused_directives = match inline_config_options.report_unused_directives {
             ReportUnusedDirectives::WithoutSeverity(true) => Some(AllowWarnDeny::Warn),
             ReportUnusedDirectives::WithSeverity(Some(severity)) => Some(severity),
             _ => None,
-        };
-        let (mut diagnostic_service, tx_error) =
-            Self::get_diagnostic_service(&output_formatter, &warning_options, &misc_options);
+        }
+        // Without rules, every disable directive would be reported as unused.
+        .filter(|_| !syntax_only);
+        let (mut diagnostic_service, tx_error) = Self::get_diagnostic_service(
+            &output_formatter,
+            options.cwd(),
+            &self.options.output_options,
+            &warning_options,
+            &misc_options,
+            quiet_codes,
+            changed_lines,
+        );
+        // Ownership may turn warnings into errors after linting, so they may still be displayed
+        let quiet = warning_options.quiet && ownership.is_none();
+        diagnostic_service = diagnostic_service.with_ownership(ownership);
 
         let config_store = ConfigStore::new(lint_config, nested_configs, external_plugin_store);
 
@@ -331,16 +789,58 @@ impl CliRunner {
 
         let files_to_lint = paths
             .into_iter()
-            .filter(|path| !ignore_matcher.should_ignore(Path::new(path)))
+            .filter(|path| {
+                let ignored = ignore_matcher.should_ignore(Path::new(path));
+                if ignored && let Some(map) = &file_status_map {
+                    map.lock()
+                        .expect("file_status_map mutex poisoned")
+                        .insert(PathBuf::from(&**path), FileStatus::SkippedIgnored);
+                }
+                !ignored
+            })
+            .filter(|path| {
+                shard.is_none_or(|shard| {
+                    shard.contains(
+                        NormalizedPath::relative_to(Path::new(path), options.cwd()).as_str(),
+                    )
+                })
+            })
             .collect::<Vec<Arc<OsStr>>>();
 
+        if self.options.list_files {
+            let json = self.options.output_options.format == OutputFormat::Json;
+            print_and_flush_stdout(stdout, &render_file_list(options.cwd(), &files_to_lint, json));
+            return CliRunResult::None;
+        }
+
         let has_external_linter = external_linter.is_some();
-        let linter = Linter::new(LintOptions::default(), config_store, external_linter)
+        let rule_origins = self.options.output_options.rule_origins
+            && self.options.output_options.format == OutputFormat::Json;
+        let mut linter = Linter::new(LintOptions::default(), config_store, external_linter)
             .with_fix(fix_options.fix_kind())
-            .with_report_unused_directives(report_unused_directives);
+            .with_fix_min_severity(fix_options.fix_level.map(Severity::from))
+            .with_report_unused_directives(report_unused_directives)
+            // Origins are only shown by the JSON output
+            .with_rule_origins(rule_origins)
+            .with_syntax_only(syntax_only)
+            .with_project_files(Arc::new(ProjectFiles::new(options.cwd())));
+        if let Some(map) = &rule_timings_map {
+            linter = linter.with_rule_timings(Arc::clone(map));
+        }
 
         let number_of_files = files_to_lint.len();
 
+        let cache_path = misc_options.cache.then(|| {
+            let path = misc_options.cache_location.as_deref().unwrap_or(Path::new(".oxlintcache"));
+            options.cwd().join(path)
+        });
+        let rule_result_cache = cache_path
+            .as_ref()
+            .map(|path| Arc::new(RuleResultCache::read(path, number_of_files.max(1))));
+        if let Some(cache) = &rule_result_cache {
+            linter = linter.with_rule_result_cache(Arc::clone(cache));
+        }
+
         // Due to the architecture of the import plugin and JS plugins,
         // linting a large number of files with both enabled can cause resource exhaustion.
         // See: https://github.com/oxc-project/oxc/issues/15863
@@ -357,7 +857,9 @@ impl CliRunner {
 
         let tsconfig = basic_options.tsconfig;
         if let Some(path) = tsconfig.as_ref() {
-            if path.is_file() {
+            if path.as_os_str() == "auto" {
+                options = options.with_tsconfig_discovery(true);
+            } else if path.is_file() {
                 options = options.with_tsconfig(path);
             } else {
                 let path = if path.is_relative() { options.cwd().join(path) } else { path.clone() };
@@ -374,14 +876,26 @@ impl CliRunner {
             }
         }
 
-        let number_of_rules = linter.number_of_rules(self.options.type_aware);
+        let number_of_rules =
+            if syntax_only { Some(0) } else { linter.number_of_rules(type_aware) };
+
+        let file_metadata_map =
+            self.options.output_options.file_metadata.then(FileMetadataMap::default);
+        let fixed_files_map = fix_options.fix_report.is_some().then(FixedFilesMap::default);
+        let rule_origins_map = rule_origins.then(RuleOriginsMap::default);
+        let cwd = options.cwd().to_path_buf();
 
         // Create the LintRunner
         // TODO: Add a warning message if `tsgolint` cannot be found, but type-aware rules are enabled
         let lint_runner = match LintRunner::builder(options, linter)
-            .with_type_aware(self.options.type_aware)
-            .with_type_check(self.options.type_check)
+            .with_type_aware(type_aware)
+            .with_type_check(type_check)
+            .with_file_metadata_map(file_metadata_map.clone())
+            .with_fixed_files_map(fixed_files_map.clone())
+            .with_rule_origins_map(rule_origins_map.clone())
+            .with_file_status_map(file_status_map.clone())
             .with_silent(misc_options.silent)
+            .with_quiet(quiet)
             .with_fix_kind(fix_options.fix_kind())
             .build()
         {
@@ -392,8 +906,12 @@ impl CliRunner {
             }
         };
 
-        // Configure the file system for external linter if needed
-        let file_system = if has_external_linter {
+        // Configure the file system for stdin, `--diff`, `--staged` or external linter if needed
+        let file_system = if let Some(stdin_file_system) = &stdin_file_system {
+            Some(stdin_file_system as &(dyn oxc_linter::RuntimeFileSystem + Sync + Send))
+        } else if let Some(diff_file_system) = &diff_file_system {
+            Some(diff_file_system as &(dyn oxc_linter::RuntimeFileSystem + Sync + Send))
+        } else if has_external_linter {
             #[cfg(all(feature = "napi", target_pointer_width = "64", target_endian = "little"))]
             {
                 Some(
@@ -414,9 +932,22 @@ impl CliRunner {
             None
         };
 
+        let mut allocator_pool_stats = None;
         match lint_runner.lint_files(&files_to_lint, tx_error.clone(), file_system) {
             Ok(lint_runner) => {
                 lint_runner.report_unused_directives(report_unused_directives, &tx_error);
+                if let (Some(cache), Some(path)) = (&rule_result_cache, &cache_path)
+                    && let Err(err) = cache.write(path)
+                {
+                    let warning = OxcDiagnostic::warn(format!(
+                        "Failed to write the cache file {}: {err}",
+                        path.display()
+                    ));
+                    print_and_flush_stdout(stderr, &render_report(&handler, &warning));
+                }
+                if misc_options.stats {
+                    allocator_pool_stats = Some(lint_runner.allocator_pool_stats());
+                }
             }
             Err(err) => {
                 print_and_flush_stdout(stdout, &err);
@@ -426,19 +957,137 @@ impl CliRunner {
 
         drop(tx_error);
 
-        let diagnostic_result = diagnostic_service.run(stdout);
+        let lint_command_info =
+            |diagnostic_result: &DiagnosticResult, file_statuses| LintCommandInfo {
+                number_of_files,
+                number_of_rules,
+                threads_count: rayon::current_num_threads(),
+                start_time: now.elapsed(),
+                file_metadata: file_metadata_map.as_ref().map(|map| {
+                    Self::by_displayed_path(
+                        map,
+                        &cwd,
+                        self.options.output_options.native_path_separators,
+                    )
+                }),
+                rule_origins: rule_origins_map.as_ref().map(|map| {
+                    Self::rule_origins(
+                        map,
+                        &cwd,
+                        self.options.output_options.native_path_separators,
+                    )
+                }),
+                file_statuses,
+                code_counts: diagnostic_result.code_counts().clone(),
+            };
 
-        if let Some(end) = output_formatter.lint_command_info(&LintCommandInfo {
-            number_of_files,
-            number_of_rules,
-            threads_count: rayon::current_num_threads(),
-            start_time: now.elapsed(),
-        }) {
-            print_and_flush_stdout(stdout, &end);
-        }
+        let mut timing_budget_exceeded = false;
+        let diagnostic_result = if fix_options.fix_to_stdout
+            && let Some(stdin_file_system) = stdin_file_system
+        {
+            // Diagnostics and their summary go to stderr, so that stdout only contains the fixed
+            // source text.
+            let diagnostic_result = diagnostic_service.run(stderr);
+            print_and_flush_stdout(stdout, &stdin_file_system.into_output());
+            if let Some(end) =
+                output_formatter.lint_command_info(&lint_command_info(&diagnostic_result, None))
+            {
+                print_and_flush_stdout(stderr, &end);
+            }
+            diagnostic_result
+        } else {
+            // Output is collected to write it to `--output-file` at once
+            let mut file_output = Vec::new();
+            let output_file = self.options.output_options.output_file.as_ref();
+            let output: &mut dyn Write =
+                if output_file.is_some() { &mut file_output } else { stdout };
+            let diagnostic_result = diagnostic_service.run(output);
+
+            // Timings go to stderr, so that they are not mixed with the diagnostics.
+            if let Some(map) = &rule_timings_map {
+                let rule_timings = map.lock().expect("rule_timings mutex poisoned");
+                print_and_flush_stdout(stderr, &render_timings(&rule_timings));
+                if let Some(timing_budget) = &timing_budget {
+                    let regressions =
+                        timing_budget.regressions(&rule_timings, misc_options.timing_tolerance);
+                    if !regressions.is_empty() {
+                        print_and_flush_stdout(
+                            stderr,
+                            &render_regressions(&regressions, misc_options.timing_tolerance),
+                        );
+                        timing_budget_exceeded = true;
+                    }
+                }
+            }
+
+            let file_statuses = file_status_map.as_ref().map(|map| {
+                Self::by_displayed_path(
+                    map,
+                    &cwd,
+                    self.options.output_options.native_path_separators,
+                )
+            });
+
+            // Statistics go to stderr, so that they are not mixed with the diagnostics.
+            if let Some(stats) = &allocator_pool_stats {
+                print_and_flush_stdout(stderr, &render_allocator_pool_stats(stats));
+            }
+            if misc_options.stats
+                && let Some(file_statuses) = &file_statuses
+            {
+                print_and_flush_stdout(stderr, &render_file_status_stats(file_statuses));
+            }
+            if misc_options.stats
+                && let Some(stats) = allocation_stats()
+            {
+                print_and_flush_stdout(stderr, &render_allocation_stats(&stats));
+            }
+            if misc_options.stats
+                && let Some(stats) = mimalloc_stats()
+            {
+                print_and_flush_stdout(stderr, &render_mimalloc_stats(&stats));
+            }
+
+            if let Some(end) = output_formatter.lint_command_info(&lint_command_info(
+                &diagnostic_result,
+                file_statuses.filter(|_| output_file_statuses),
+            )) {
+                print_and_flush_stdout(output, &end);
+            }
+
+            if let Some(fix_report) = fix_options.fix_report
+                && let Some(map) = &fixed_files_map
+            {
+                let fixed_files = Self::by_displayed_path(
+                    map,
+                    &cwd,
+                    self.options.output_options.native_path_separators,
+                );
+                // The report goes to stderr, so that it is not mixed with the diagnostics.
+                print_and_flush_stdout(stderr, &Self::fix_report(fix_report, fixed_files));
+            }
+
+            if let Some(output_file) = output_file {
+                let file_output = String::from_utf8_lossy(&file_output);
+                if self.options.output_options.check_snapshot {
+                    return Self::check_snapshot(stdout, &cwd, output_file, &file_output);
+                }
+                if let Err(err) = fs::write(cwd.join(output_file), file_output.as_bytes()) {
+                    print_and_flush_stdout(
+                        stdout,
+                        &format!("Failed to write output file {}: {err}\n", output_file.display()),
+                    );
+                    return CliRunResult::InvalidOptionConfig;
+                }
+            }
+
+            diagnostic_result
+        };
 
         if diagnostic_result.errors_count() > 0 {
             CliRunResult::LintFoundErrors
+        } else if timing_budget_exceeded {
+            CliRunResult::LintTimingBudgetExceeded
         } else if warning_options.deny_warnings && diagnostic_result.warnings_count() > 0 {
             CliRunResult::LintNoWarningsAllowed
         } else if diagnostic_result.max_warnings_exceeded() {
@@ -451,6 +1100,9 @@ impl CliRunner {
 
 impl CliRunner {
     const DEFAULT_OXLINTRC: &'static str = ".oxlintrc.json";
+    /// Name of the configuration passed with `--config-inline` in diagnostics, as if it was a file
+    /// in the current working directory.
+    const INLINE_CONFIG_NAME: &'static str = "<config-inline>";
 
     #[must_use]
     pub fn with_cwd(mut self, cwd: PathBuf) -> Self {
@@ -458,21 +1110,280 @@ impl CliRunner {
         self
     }
 
+    /// Provide the source text used by `--stdin` or the diff used by `--only-changed-lines`,
+    /// instead of reading it from the process' stdin.
+    #[must_use]
+    pub fn with_stdin(mut self, stdin: String) -> Self {
+        self.stdin = Some(stdin);
+        self
+    }
+
     fn get_diagnostic_service(
         reporter: &OutputFormatter,
+        cwd: &Path,
+        output_options: &OutputOptions,
         warning_options: &WarningOptions,
         misc_options: &MiscOptions,
+        quiet_codes: Vec<String>,
+        changed_lines: Option<ChangedLines>,
     ) -> (DiagnosticService, DiagnosticSender) {
-        let (service, sender) = DiagnosticService::new(reporter.get_diagnostic_reporter());
+        let reporter: Box<dyn DiagnosticReporter> = match output_options.summary_by {
+            // The table would make the output of machine-readable formats invalid
+            Some(SummaryBy::Dir) if output_options.format.is_text() => {
+                Box::new(DirectorySummaryReporter::new(
+                    reporter.get_diagnostic_reporter(),
+                    cwd.to_path_buf(),
+                ))
+            }
+            _ => reporter.get_diagnostic_reporter(),
+        };
+        let (service, sender) = DiagnosticService::new(reporter);
         (
             service
                 .with_quiet(warning_options.quiet)
                 .with_silent(misc_options.silent)
-                .with_max_warnings(warning_options.max_warnings),
+                .with_quiet_codes(quiet_codes)
+                .with_changed_lines(changed_lines)
+                .with_max_warnings(warning_options.max_warnings)
+                .with_native_path_separators(output_options.native_path_separators)
+                .with_sort_by(
+                    output_options.sort_by.as_ref().map_or_else(Vec::new, |keys| keys.0.clone()),
+                ),
             sender,
         )
     }
 
+    /// The data collected by path for `--file-metadata` or `--fix-report`, keyed by the path as
+    /// displayed in diagnostics.
+    fn by_displayed_path<T>(
+        map: &Mutex<FxHashMap<PathBuf, T>>,
+        cwd: &Path,
+        native_path_separators: bool,
+    ) -> BTreeMap<String, T> {
+        let map = std::mem::take(&mut *map.lock().expect("mutex poisoned"));
+        map.into_iter()
+            .map(|(path, value)| (Self::displayed_path(&path, cwd, native_path_separators), value))
+            .collect()
+    }
+
+    fn displayed_path(path: &Path, cwd: &Path, native_path_separators: bool) -> String {
+        let path = NormalizedPath::relative_to(path, cwd);
+        if native_path_separators { path.to_native().into_owned() } else { path.to_string() }
+    }
+
+    /// The origins of rules for the JSON output, with the paths of configuration files displayed
+    /// like the paths of linted files.
+    fn rule_origins(
+        map: &Mutex<FxHashMap<PathBuf, BTreeMap<String, RuleOrigin>>>,
+        cwd: &Path,
+        native_path_separators: bool,
+    ) -> BTreeMap<String, BTreeMap<String, RuleOrigin>> {
+        let mut rule_origins = Self::by_displayed_path(map, cwd, native_path_separators);
+        for origin in rule_origins.values_mut().flat_map(BTreeMap::values_mut) {
+            if let Some(config_path) = &mut origin.config_path {
+                *config_path =
+                    PathBuf::from(Self::displayed_path(config_path, cwd, native_path_separators));
+            }
+        }
+        rule_origins
+    }
+
+    /// The report of `--fix-report`, listing the files written by the fix options in order of
+    /// their paths, e.g. `{"fixedFiles":[{"path":"src/a.js","fixes":1,"rules":{"eslint(no-var)":1}}]}`.
+    fn fix_report(format: FixReport, fixed_files: BTreeMap<String, FixedFile>) -> String {
+        #[derive(serde::Serialize)]
+        struct FixedFileEntry {
+            path: String,
+            #[serde(flatten)]
+            fixed_file: FixedFile,
+        }
+
+        match format {
+            FixReport::Json => {
+                let fixed_files = fixed_files
+                    .into_iter()
+                    .map(|(path, fixed_file)| FixedFileEntry { path, fixed_file })
+                    .collect::<Vec<_>>();
+                let mut report = serde_json::json!({ "fixedFiles": fixed_files }).to_string();
+                report.push('\n');
+                report
+            }
+        }
+    }
+
+    /// Compare `output` with the snapshot committed at `path` for `--check-snapshot`, and print
+    /// the differences.
+    fn check_snapshot(
+        stdout: &mut dyn Write,
+        cwd: &Path,
+        path: &Path,
+        output: &str,
+    ) -> CliRunResult {
+        let Ok(snapshot) = oxc_linter::read_to_string(&cwd.join(path)) else {
+            print_and_flush_stdout(
+                stdout,
+                &format!(
+                    "Snapshot {} does not exist. Run without `--check-snapshot` to create it.\n",
+                    path.display()
+                ),
+            );
+            return CliRunResult::LintSnapshotMismatch;
+        };
+        // Git may check out the snapshot with `\r\n` line endings
+        let snapshot = snapshot.cow_replace("\r\n", "\n");
+        if snapshot == output {
+            print_and_flush_stdout(
+                stdout,
+                &format!("Snapshot {} is up to date.\n", path.display()),
+            );
+            return CliRunResult::LintSucceeded;
+        }
+
+        let diff = TextDiff::from_lines(snapshot.as_ref(), output);
+        let diff = diff.unified_diff().header("snapshot", "current").to_string();
+        print_and_flush_stdout(
+            stdout,
+            &format!(
+                "{diff}\nSnapshot {} does not match the diagnostics. Run without `--check-snapshot` to update it.\n",
+                path.display()
+            ),
+        );
+        CliRunResult::LintSnapshotMismatch
+    }
+
+    /// Lines changed by the diff of `--only-changed-lines`, read from stdin or `git diff`.
+    fn get_changed_lines(
+        cwd: &Path,
+        warning_options: &WarningOptions,
+        is_stdin: bool,
+        stdin: &mut Option<String>,
+    ) -> Result<Option<ChangedLines>, (CliRunResult, String)> {
+        let invalid =
+            |message: &str| Err((CliRunResult::InvalidOptionConfig, format!("{message}\n")));
+        if !warning_options.only_changed_lines {
+            if warning_options.changed.is_some() {
+                return invalid("The `--changed` option requires `--only-changed-lines`.");
+            }
+            return Ok(None);
+        }
+
+        let diff = if let Some(rev) = &warning_options.changed {
+            let output = Command::new("git")
+                .args([
+                    "diff",
+                    "--relative",
+                    "--no-color",
+                    "--no-ext-diff",
+                    "--unified=0",
+                    rev,
+                    "--",
+                ])
+                .current_dir(cwd)
+                .output();
+            match output {
+                Ok(output) if output.status.success() => {
+                    String::from_utf8_lossy(&output.stdout).into_owned()
+                }
+                Ok(output) => {
+                    let stderr = String::from_utf8_lossy(&output.stderr);
+                    return invalid(&format!("Failed to run `git diff {rev}`: {}", stderr.trim()));
+                }
+                Err(err) => return invalid(&format!("Failed to run `git diff {rev}`: {err}")),
+            }
+        } else if is_stdin {
+            return invalid(
+                "The `--only-changed-lines` option requires `--changed` in combination with `--stdin`.",
+            );
+        } else {
+            match stdin.take().map_or_else(StdinFileSystem::read_stdin, Ok) {
+                Ok(diff) => diff,
+                Err(err) => return invalid(&format!("Failed to read the diff from stdin: {err}")),
+            }
+        };
+
+        Ok(Some(ChangedLines::from_unified_diff(&diff)))
+    }
+
+    /// Owners of files read from the `CODEOWNERS` file for `--owned-by`.
+    ///
+    /// Patterns are relative to the root of the git repository of `cwd`, or to `cwd` if it is not
+    /// inside of a repository.
+    fn get_ownership(
+        cwd: &Path,
+        warning_options: &WarningOptions,
+    ) -> Result<Option<Ownership>, (CliRunResult, String)> {
+        let invalid =
+            |message: &str| Err((CliRunResult::InvalidOptionConfig, format!("{message}\n")));
+        if warning_options.owned_by.is_empty() {
+            if warning_options.codeowners.is_some() {
+                return invalid("The `--codeowners` option requires `--owned-by`.");
+            }
+            return Ok(None);
+        }
+
+        let root = cwd.ancestors().find(|dir| dir.join(".git").exists()).unwrap_or(cwd);
+        let path = match &warning_options.codeowners {
+            Some(path) if !cwd.join(path).is_file() => {
+                return invalid(&format!(
+                    "The `CODEOWNERS` file {} does not exist.",
+                    path.display()
+                ));
+            }
+            Some(path) => cwd.join(path),
+            // Same locations as GitHub, in the same order
+            None => {
+                let Some(path) = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"]
+                    .into_iter()
+                    .map(|path| root.join(path))
+                    .find(|path| path.is_file())
+                else {
+                    return invalid(
+                        "No `CODEOWNERS` file found for `--owned-by`. Use `--codeowners` to specify its path.",
+                    );
+                };
+                path
+            }
+        };
+        match oxc_linter::read_to_string(&path) {
+            Ok(text) => Ok(Some(Ownership::new(
+                CodeOwners::parse(&text),
+                warning_options.owned_by.clone(),
+                root.to_path_buf(),
+                cwd,
+                |code| oxc_linter::rule_of_diagnostic_code(code).is_some(),
+            ))),
+            Err(err) => invalid(&format!("Failed to read {}: {err}", path.display())),
+        }
+    }
+
+    /// Diagnostic codes of the rules passed to `--quiet-rules`.
+    fn get_quiet_codes(quiet_rules: &[String]) -> Result<Vec<String>, (CliRunResult, String)> {
+        let mut codes = vec![];
+        for rule in quiet_rules {
+            match LintFilterKind::parse(Cow::Owned(rule.clone())) {
+                Ok(filter) => codes.extend(diagnostic_codes(&filter)),
+                Err(InvalidFilterKind::Empty) => {}
+                Err(InvalidFilterKind::PluginMissing(filter)) => {
+                    return Err((
+                        CliRunResult::InvalidOptionConfig,
+                        format!(
+                            "Failed to parse `--quiet-rules` rule {filter}: Plugin name is missing. Expected <plugin>/<rule>\n"
+                        ),
+                    ));
+                }
+                Err(InvalidFilterKind::RuleMissing(filter)) => {
+                    return Err((
+                        CliRunResult::InvalidOptionConfig,
+                        format!(
+                            "Failed to parse `--quiet-rules` rule {filter}: Rule name is missing. Expected <plugin>/<rule>\n"
+                        ),
+                    ));
+                }
+            }
+        }
+        Ok(codes)
+    }
+
     // moved into a separate function for readability, but it's only ever used
     // in one place.
     fn get_filters(
@@ -515,6 +1426,7 @@ impl CliRunner {
 
     fn get_nested_configs(
         stdout: &mut dyn Write,
+        stderr: &mut dyn Write,
         handler: &GraphicalReportHandler,
         filters: &Vec<LintFilter>,
         paths: &Vec<Arc<OsStr>>,
@@ -565,8 +1477,9 @@ impl CliRunner {
                 oxlintrc.ignore_patterns.clone(),
                 oxlintrc.path.parent().unwrap().to_path_buf(),
             ));
+            let ignore_command_line_filters = oxlintrc.ignore_command_line_filters;
             // TODO(refactor): clean up all of the error handling in this function
-            let builder = match ConfigStoreBuilder::from_oxlintrc(
+            let mut builder = match ConfigStoreBuilder::from_oxlintrc(
                 false,
                 oxlintrc,
                 external_linter,
@@ -583,8 +1496,14 @@ impl CliRunner {
                     );
                     return Err(CliRunResult::InvalidOptionConfig);
                 }
+            };
+            if !ignore_command_line_filters {
+                builder = builder.with_filters(filters);
+            }
+
+            for warning in &builder.warnings {
+                print_and_flush_stdout(stderr, &render_report(handler, warning));
             }
-            .with_filters(filters);
 
             let config = match builder.build(external_plugin_store) {
                 Ok(config) => config,
@@ -605,6 +1524,79 @@ impl CliRunner {
         Ok(nested_configs)
     }
 
+    /// Replace the `@name` paths which name a path set of the configuration file, e.g. `@app` for
+    /// `"paths": { "app": ["src/**"] }`, by the paths of the set and the files matching its globs.
+    /// Paths and globs of path sets are resolved from the directory of the configuration file.
+    fn expand_path_sets(
+        cwd: &Path,
+        oxlintrc: &Oxlintrc,
+        ignore_options: &IgnoreOptions,
+        paths: &mut Vec<PathBuf>,
+    ) -> Result<(), String> {
+        let config_dir = oxlintrc.path.parent().unwrap_or(cwd);
+        let mut globs = OverrideBuilder::new(config_dir);
+        let mut glob_roots = Vec::new();
+        let mut expanded = Vec::with_capacity(paths.len());
+
+        for path in paths.drain(..) {
+            let Some(name) = path.to_str().and_then(|path| path.strip_prefix('@')) else {
+                expanded.push(path);
+                continue;
+            };
+            let Some(path_set) = oxlintrc.paths.get(name) else {
+                // Paths such as `@types` may be directories rather than path sets.
+                if cwd.join(&path).exists() {
+                    expanded.push(path);
+                    continue;
+                }
+                let defined =
+                    oxlintrc.paths.keys().map(|name| format!("`@{name}`")).collect::<Vec<_>>();
+                let defined = if defined.is_empty() {
+                    "No path sets are defined".to_string()
+                } else {
+                    format!("Defined path sets: {}", defined.join(", "))
+                };
+                return Err(format!(
+                    "The path set `@{name}` is not defined in the `paths` of the configuration file. {defined}.\n"
+                ));
+            };
+
+            for pattern in path_set {
+                let pattern = pattern.strip_prefix("./").unwrap_or(pattern);
+                let wildcard = pattern.find(['*', '?', '[', '{']);
+                if wildcard.is_none() && !pattern.starts_with('!') {
+                    expanded.push(config_dir.join(pattern));
+                    continue;
+                }
+                globs.add(pattern).map_err(|err| {
+                    format!("Invalid glob `{pattern}` in the path set `@{name}`: {err}\n")
+                })?;
+                // Walk the directory before the first component with a wildcard.
+                // Negated globs only exclude files found by the other globs.
+                if let Some(wildcard) = wildcard.filter(|_| !pattern.starts_with('!')) {
+                    let root = pattern[..wildcard].rfind('/').map_or("", |end| &pattern[..end]);
+                    glob_roots.push(config_dir.join(root));
+                }
+            }
+        }
+
+        if !glob_roots.is_empty() {
+            glob_roots.sort_unstable();
+            glob_roots.dedup();
+            let globs = globs.build().map_err(|err| format!("Invalid path set: {err}\n"))?;
+            let files = Walk::new(&glob_roots, ignore_options, None)
+                .with_extensions(Extensions::for_config(oxlintrc))
+                .with_globs(globs)
+                .paths();
+            expanded.extend(files.iter().map(PathBuf::from));
+            expanded.sort_unstable();
+            expanded.dedup();
+        }
+
+        *paths = expanded;
+        Ok(())
+    }
+
     // finds the oxlint config
     // when config is provided, but not found, an String with the formatted error is returned, else the oxlintrc config file is returned
     // when no config is provided, it will search for the default file names in the current working directory
@@ -619,6 +1611,101 @@ impl CliRunner {
         Ok(Oxlintrc::default())
     }
 
+    /// Replace deprecated names in the configuration file, see `--fix-config`.
+    fn fix_config(stdout: &mut dyn Write, cwd: &Path, config: Option<&PathBuf>) -> CliRunResult {
+        Self::rewrite_config(stdout, cwd, config, ConfigRewrite::FixDeprecatedNames)
+    }
+
+    /// Migrate the configuration file to the current version, see `--migrate-config`.
+    fn migrate_config(
+        stdout: &mut dyn Write,
+        cwd: &Path,
+        config: Option<&PathBuf>,
+    ) -> CliRunResult {
+        Self::rewrite_config(stdout, cwd, config, ConfigRewrite::Migrate)
+    }
+
+    /// Rewrite the configuration file in place, and print each change followed by a summary.
+    fn rewrite_config(
+        stdout: &mut dyn Write,
+        cwd: &Path,
+        config: Option<&PathBuf>,
+        rewrite: ConfigRewrite,
+    ) -> CliRunResult {
+        use std::fmt::Write as _;
+
+        let path = cwd.join(config.map_or(Self::DEFAULT_OXLINTRC.as_ref(), PathBuf::as_path));
+        if !path.is_file() {
+            print_and_flush_stdout(
+                stdout,
+                &format!("No configuration file found at {}.\n", path.display()),
+            );
+            return CliRunResult::InvalidOptionConfig;
+        }
+
+        let changes = match rewrite {
+            ConfigRewrite::FixDeprecatedNames => Oxlintrc::fix_deprecated_names(&path)
+                .map(|names| names.into_iter().map(ConfigMigration::Renamed).collect::<Vec<_>>()),
+            ConfigRewrite::Migrate => Oxlintrc::migrate(&path),
+        };
+        let changes = match changes {
+            Ok(changes) => changes,
+            Err(err) => {
+                let action = match rewrite {
+                    ConfigRewrite::FixDeprecatedNames => "fix",
+                    ConfigRewrite::Migrate => "migrate",
+                };
+                print_and_flush_stdout(
+                    stdout,
+                    &format!("Failed to {action} configuration file.\n{}\n", err.message),
+                );
+                return CliRunResult::InvalidOptionConfig;
+            }
+        };
+
+        let mut output = String::new();
+        for change in &changes {
+            let _ = writeln!(output, "{change}");
+        }
+        let _ = match (rewrite, changes.len()) {
+            (ConfigRewrite::FixDeprecatedNames, 0) => {
+                writeln!(output, "No deprecated names found in configuration file.")
+            }
+            (ConfigRewrite::FixDeprecatedNames, count) => writeln!(
+                output,
+                "Fixed {count} deprecated name{} in configuration file.",
+                if count == 1 { "" } else { "s" }
+            ),
+            (ConfigRewrite::Migrate, 0) => {
+                writeln!(output, "Configuration file is already at version {CONFIG_VERSION}.")
+            }
+            (ConfigRewrite::Migrate, _) => {
+                writeln!(output, "Migrated configuration file to version {CONFIG_VERSION}.")
+            }
+        };
+        print_and_flush_stdout(stdout, &output);
+
+        match rewrite {
+            ConfigRewrite::FixDeprecatedNames => CliRunResult::ConfigFileFixSucceeded,
+            ConfigRewrite::Migrate => CliRunResult::ConfigFileMigrateSucceeded,
+        }
+    }
+
+    /// Plugins for the frameworks and test runners which the `package.json` in `cwd` depends on.
+    fn detect_plugins(cwd: &Path) -> LintPlugins {
+        let Ok(content) = fs::read_to_string(cwd.join("package.json")) else {
+            return LintPlugins::empty();
+        };
+        let Ok(package_json) = serde_json::from_str::<Value>(&content) else {
+            return LintPlugins::empty();
+        };
+        ["dependencies", "devDependencies", "peerDependencies"]
+            .iter()
+            .filter_map(|field| package_json.get(field).and_then(Value::as_object))
+            .flat_map(serde_json::Map::keys)
+            .fold(LintPlugins::empty(), |plugins, name| plugins | LintPlugins::for_dependency(name))
+    }
+
     /// Looks in a directory for an oxlint config file, returns the oxlint config if it exists
     /// and returns `Err` if none exists or the file is invalid. Does not apply the default
     /// config file.
@@ -646,6 +1733,22 @@ fn check_for_writer_error(error: std::io::Error) -> Result<(), std::io::Error> {
     }
 }
 
+/// The paths of `files` relative to `cwd` in sorted order, one per line or as a JSON array.
+fn render_file_list(cwd: &Path, files: &[Arc<OsStr>], json: bool) -> String {
+    let mut paths = files
+        .iter()
+        .map(|path| NormalizedPath::relative_to(Path::new(path), cwd).as_str().to_string())
+        .collect::<Vec<_>>();
+    paths.sort_unstable();
+    if json {
+        let mut output = serde_json::to_string_pretty(&paths).unwrap();
+        output.push('\n');
+        output
+    } else {
+        paths.into_iter().map(|path| path + "\n").collect()
+    }
+}
+
 fn render_report(handler: &GraphicalReportHandler, diagnostic: &OxcDiagnostic) -> String {
     let mut err = String::new();
     handler.render_report(&mut err, diagnostic).unwrap();
@@ -654,7 +1757,12 @@ fn render_report(handler: &GraphicalReportHandler, diagnostic: &OxcDiagnostic) -
 
 #[cfg(test)]
 mod test {
-    use std::{fs, path::PathBuf};
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use oxc_linter::LintPlugins;
 
     use super::CliRunner;
     use crate::tester::Tester;
@@ -691,6 +1799,27 @@ mod test {
         Tester::new().test_and_snapshot(args);
     }
 
+    #[test]
+    fn quiet_rules() {
+        // `no-debugger` is counted, but not printed
+        let args = &[
+            "--quiet-rules",
+            "no-debugger",
+            "fixtures/linter/debugger.js",
+            "fixtures/linter/nan.js",
+        ];
+        Tester::new().test_and_snapshot(args);
+
+        // hidden warnings still count towards `--deny-warnings`
+        let args = &[
+            "--quiet-rules",
+            "eslint/no-debugger",
+            "--deny-warnings",
+            "fixtures/linter/debugger.js",
+        ];
+        Tester::new().test_and_snapshot(args);
+    }
+
     #[test]
     fn wrong_extension() {
         let args = &["foo.asdf"];
@@ -704,6 +1833,27 @@ mod test {
         Tester::new().test_and_snapshot(args);
     }
 
+    #[test]
+    fn ignore_pattern_file() {
+        let args =
+            &["--ignore-pattern-file", "fixtures/linter/.ignore-patterns", "fixtures/linter"];
+        Tester::new().test_and_snapshot(args);
+    }
+
+    #[test]
+    fn ignore_pattern_file_missing() {
+        let args = &["--ignore-pattern-file", "fixtures/linter/.does-not-exist", "fixtures/linter"];
+        let output = Tester::new().test_output(args);
+        assert!(output.starts_with("Failed to read ignore pattern file"));
+    }
+
+    #[test]
+    fn ignore_pattern_invalid() {
+        let args = &["--ignore-pattern", "**/*.{js", "fixtures/linter"];
+        let output = Tester::new().test_output(args);
+        assert!(output.starts_with("Invalid ignore pattern `**/*.{js`"), "{output}");
+    }
+
     /// When a file is explicitly passed as a path and `--no-ignore`
     /// is not present, the ignore file should take precedence.
     /// See https://github.com/oxc-project/oxc/issues/1124
@@ -969,6 +2119,455 @@ mod test {
             .test_and_snapshot(&["--tsconfig", "oxc/tsconfig.json"]);
     }
 
+    #[test]
+    fn test_only_changed_lines() {
+        let diff = "\
+--- a/test.js
++++ b/test.js
+@@ -1,0 +2 @@
++debugger;
+@@ -3 +4,2 @@ function foo() {
+-  let a;
++  let a = 1;
++  debugger;
+";
+        let args = &["-A", "all", "-D", "no-debugger", "--only-changed-lines"];
+        let invalid_args = &["--changed", "HEAD"];
+        Tester::new()
+            .with_cwd("fixtures/only_changed_lines".into())
+            .with_stdin(diff)
+            .test_and_snapshot_multiple(&[args, invalid_args]);
+    }
+
+    #[test]
+    fn test_diff() {
+        let diff = "\
+--- a/test.js
++++ b/test.js
+@@ -0,0 +1 @@
++debugger;
+@@ -2 +3,2 @@ function foo() {
+-  let a;
++  let a = 1;
++  debugger;
+--- /dev/null
++++ b/new.js
+@@ -0,0 +1 @@
++let b = 1;
+--- /dev/null
++++ b/README.md
+@@ -0,0 +1 @@
++# Diff
+";
+        let args = &["-A", "all", "-D", "no-debugger", "--diff"];
+        let conflicting_args = &["--diff", "--stdin"];
+        Tester::new()
+            .with_cwd("fixtures/diff".into())
+            .with_stdin(diff)
+            .test_and_snapshot_multiple(&[args, conflicting_args]);
+    }
+
+    #[test]
+    fn test_diff_ignored_files() {
+        let diff = "\
+--- /dev/null
++++ b/index.js
+@@ -0,0 +1 @@
++debugger;
+--- /dev/null
++++ b/generated.js
+@@ -0,0 +1 @@
++debugger;
+--- /dev/null
++++ b/vendor.js
+@@ -0,0 +1 @@
++debugger;
+--- /dev/null
++++ b/legacy.js
+@@ -0,0 +1 @@
++debugger;
+";
+        // `generated.js` is ignored by `.gitignore`, `vendor.js` by `.eslintignore`
+        let args = &["-A", "all", "-D", "no-debugger", "--diff", "--ignore-pattern", "legacy.js"];
+        Tester::new()
+            .with_cwd("fixtures/diff_ignore".into())
+            .with_stdin(diff)
+            .test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_staged() {
+        let dir = tempfile::tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .args([
+                    "-c",
+                    "user.name=oxlint",
+                    "-c",
+                    "user.email=oxlint@example.com",
+                    "-c",
+                    "commit.gpgsign=false",
+                ])
+                .args(args)
+                .current_dir(dir.path())
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {args:?} failed");
+        };
+        git(&["init", "-q"]);
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::write(dir.path().join("src/committed.js"), "debugger;\n").unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "init"]);
+
+        // Only the staged content of `staged.js` is linted, not its unstaged fix
+        fs::write(dir.path().join("src/staged.js"), "debugger;\n").unwrap();
+        git(&["add", "src/staged.js"]);
+        fs::write(dir.path().join("src/staged.js"), "let a = 1;\n").unwrap();
+        fs::write(dir.path().join("src/unstaged.js"), "debugger;\n").unwrap();
+        fs::write(dir.path().join("other.js"), "debugger;\n").unwrap();
+        git(&["add", "other.js"]);
+
+        let tester = Tester::new().with_cwd(dir.path().to_path_buf());
+        let args = &["-A", "all", "-D", "no-debugger", "--staged"];
+        let output = tester.test_diagnostics_output(args);
+        assert!(output.contains("src/staged.js:1:1"), "{output}");
+        assert!(output.contains("other.js:1:1"), "{output}");
+        assert!(!output.contains("committed.js"), "{output}");
+        assert!(!output.contains("unstaged.js"), "{output}");
+        assert!(output.contains("Found 0 warnings and 2 errors."), "{output}");
+
+        // Paths restrict the staged files which are linted
+        let output =
+            tester.test_diagnostics_output(&["-A", "all", "-D", "no-debugger", "--staged", "src"]);
+        assert!(output.contains("Found 0 warnings and 1 error."), "{output}");
+
+        let output = tester.test_diagnostics_output(&["--staged", "--fix"]);
+        assert_eq!(output, "The `--staged` option cannot be used with `--fix`.\n");
+    }
+
+    #[test]
+    fn test_config_inline() {
+        let args = &["--config-inline", r#"{ "rules": { "no-debugger": "error" } }"#];
+        let conflicting_args =
+            &["--config-inline", "{}", "-c", "fixtures/linter/eslintrc.json", "debugger.js"];
+        let invalid_args = &["--config-inline", r#"{ "rules": "#];
+        Tester::new().with_cwd("fixtures/linter".into()).test_and_snapshot_multiple(&[
+            args,
+            conflicting_args,
+            invalid_args,
+        ]);
+    }
+
+    #[test]
+    fn test_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("index.js"), "debugger;\n").unwrap();
+        let cache_path = dir.path().join("tmp/cache.json");
+
+        let tester = Tester::new().with_cwd(dir.path().to_path_buf());
+        let args =
+            &["-A", "all", "-W", "no-debugger", "--cache", "--cache-location", "tmp/cache.json"];
+        let output = tester.test_diagnostics_output(args);
+        assert!(output.contains("Found 1 warning and 0 errors."), "{output}");
+        let cache = fs::read_to_string(&cache_path).unwrap();
+        assert!(cache.contains("index.js") && cache.contains("no-debugger"), "{cache}");
+
+        // Results are reused from the cache file
+        let diagnostics = |output: &str| output.split("Finished in").next().unwrap().to_string();
+        assert_eq!(diagnostics(&tester.test_diagnostics_output(args)), diagnostics(&output));
+
+        // and invalidated when the configuration of the rule changes.
+        let args =
+            &["-A", "all", "-D", "no-debugger", "--cache", "--cache-location", "tmp/cache.json"];
+        let output = tester.test_diagnostics_output(args);
+        assert!(output.contains("Found 0 warnings and 1 error."), "{output}");
+    }
+
+    #[test]
+    fn test_owned_by() {
+        // Patterns of `CODEOWNERS` are relative to the root of the repository, not to the cwd
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join(".git")).unwrap();
+        fs::create_dir_all(dir.path().join(".github")).unwrap();
+        fs::write(dir.path().join(".github/CODEOWNERS"), "*  @org/core\n/app/web/  @org/web\n")
+            .unwrap();
+        for file in ["app/web/index.js", "app/api/index.js"] {
+            fs::create_dir_all(dir.path().join(file).parent().unwrap()).unwrap();
+            fs::write(dir.path().join(file), "debugger;\nif (true) {}\n").unwrap();
+        }
+        fs::write(dir.path().join("app/api/syntax.js"), "let a = ;\n").unwrap();
+
+        let tester = Tester::new().with_cwd(dir.path().join("app"));
+        let args = &["-A", "all", "-W", "no-debugger", "-D", "no-empty", "--owned-by", "@org/web"];
+        let output = tester.test_diagnostics_output(args);
+        // warnings of owned files become errors, errors of other files become warnings
+        assert!(output.contains("Found 2 warnings and 3 errors."), "{output}");
+        // syntax errors are never turned into warnings
+        assert!(output.contains("api/syntax.js"), "{output}");
+
+        let output = tester.test_diagnostics_output(&[
+            "--codeowners",
+            "missing/CODEOWNERS",
+            "--owned-by",
+            "@org/web",
+        ]);
+        assert_eq!(output, "The `CODEOWNERS` file missing/CODEOWNERS does not exist.\n");
+    }
+
+    #[test]
+    fn test_hidden_diagnostics_are_counted() {
+        // warnings are counted without their source text, errors are still displayed with it
+        let quiet_args = &["-W", "no-debugger", "-D", "no-empty", "--quiet", "--max-warnings", "1"];
+        let silent_args = &["-W", "no-debugger", "--silent", "--max-warnings", "1"];
+        Tester::new()
+            .with_cwd("fixtures/hidden_diagnostics".into())
+            .test_and_snapshot_multiple(&[quiet_args, silent_args]);
+    }
+
+    #[test]
+    fn test_syntax_only() {
+        // `debugger` and the unused disable directive in `valid.js` are not reported
+        let args = &["-D", "no-debugger", "--report-unused-disable-directives", "--syntax-only"];
+        Tester::new().with_cwd("fixtures/syntax_only".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_shard() {
+        // every file is linted by exactly one of the shards
+        let args_1 = &["-W", "no-debugger", "-f", "unix", "--shard", "1/2"];
+        let args_2 = &["-W", "no-debugger", "-f", "unix", "--shard", "2/2"];
+        Tester::new()
+            .with_cwd("fixtures/shard".into())
+            .test_and_snapshot_multiple(&[args_1, args_2]);
+    }
+
+    #[test]
+    fn test_file_limits() {
+        // `nested.js` is skipped with a single warning, `small.js` is linted as usual
+        let depth_args = &["-W", "no-debugger", "--max-file-depth", "8"];
+        let nodes_args = &["-W", "no-debugger", "--max-file-nodes", "10"];
+        let size_args = &["-W", "no-debugger", "--max-file-size", "20"];
+        let lines_args = &["-W", "no-debugger", "--max-file-lines", "1"];
+        Tester::new()
+            .with_cwd("fixtures/file_limits".into())
+            .test_and_snapshot_multiple(&[depth_args, nodes_args, size_args, lines_args]);
+    }
+
+    #[test]
+    fn test_prefetch() {
+        // Same diagnostics as without `--prefetch`, with and without resolving imports
+        let args = &["--prefetch", "-D", "no-debugger"];
+        Tester::new().with_cwd("fixtures/linter".into()).test_and_snapshot(args);
+        let args = &["--prefetch", "--import-plugin", "-D", "import/no-cycle"];
+        Tester::new().with_cwd("fixtures/import-cycle".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_max_memory() {
+        // Modules are linted one at a time, but the module graph is still complete
+        let args = &["--import-plugin", "-D", "import/no-cycle", "--max-memory", "0"];
+        Tester::new().with_cwd("fixtures/import-cycle".into()).test_and_snapshot(args);
+        // Without resolving imports, files are parsed one at a time
+        let args = &["-D", "no-debugger", "--max-memory", "0"];
+        Tester::new().with_cwd("fixtures/linter".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_file_metadata() {
+        // `add.spec.ts` imports `vitest`, but is named like a Jest test file
+        let args = &["-f", "json", "--file-metadata"];
+        Tester::new().with_cwd("fixtures/file_metadata".into()).test_and_snapshot(args);
+        let args = &["-f", "ndjson", "--file-metadata"];
+        Tester::new().with_cwd("fixtures/file_metadata".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_ndjson() {
+        let args = &["-f", "ndjson", "-W", "no-debugger"];
+        Tester::new().with_cwd("fixtures/linter".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_check_snapshot() {
+        let args_1 = &["-f", "compact-stable", "--output-file", "lint.snap", "--check-snapshot"];
+        let args_2 =
+            &["-f", "compact-stable", "--output-file", "outdated.snap", "--check-snapshot"];
+        Tester::new()
+            .with_cwd("fixtures/check_snapshot".into())
+            .test_and_snapshot_multiple(&[args_1, args_2]);
+    }
+
+    #[test]
+    fn test_stats() {
+        let tester = Tester::new().with_cwd("fixtures/linter".into());
+        let stderr = tester.test_stderr(&["--stats"]);
+        assert!(stderr.contains("Memory arenas:"));
+        assert!(stderr.contains("Largest file"));
+        assert!(!tester.test_stderr(&[]).contains("Memory arenas:"));
+        assert!(!tester.test_output(&["--stats"]).contains("Memory arenas:"));
+    }
+
+    #[test]
+    fn test_file_statuses() {
+        let tester = Tester::new().with_cwd("fixtures/file_statuses".into());
+        assert!(!tester.test_output(&["-f", "json", "--stats", "."]).contains("file_statuses"));
+        let output = tester.test_output(&["-f", "json", "--file-statuses", ".", "readme.md"]);
+        assert!(output.contains(
+            r#""file_statuses": {"flow.js":"skipped-unsupported","ignored.js":"skipped-ignored","linted.js":"linted","parse_failed.js":"parse-failed","readme.md":"skipped-unsupported"}"#
+        ), "{output}");
+
+        let output = tester.test_stderr(&["--stats", "."]);
+        assert!(output.contains("\nFiles:\nlinted               1\nskipped-ignored      1\nskipped-unsupported  1\nparse-failed         1\nfixed                0\n"), "{output}");
+        assert!(!tester.test_output(&["."]).contains("Files:"));
+
+        let args = &["--stdin", "--stdin-filename", "fixed.js", "--fix", "--stats"];
+        let output = tester.test_stderr_with_stdin(args, "debugger\n");
+        assert!(output.contains("\nlinted               0\n"), "{output}");
+        assert!(output.contains("\nfixed                1\n"), "{output}");
+    }
+
+    #[test]
+    fn test_timing_budget() {
+        let tester = Tester::new().with_cwd("fixtures/timing_budget".into());
+        let args = &[
+            "-A",
+            "all",
+            "-D",
+            "no-debugger",
+            "--jsx-a11y-plugin",
+            "-D",
+            "jsx-a11y/alt-text",
+            "--timing",
+        ];
+        assert!(!tester.test_output(args).contains("Rule timings:"));
+        let output = tester.test_stderr(args);
+        assert!(output.contains("Rule timings:"));
+        assert!(output.contains("eslint/no-debugger"));
+        assert!(output.contains("jsx-a11y/alt-text"));
+
+        let output = tester.test_stderr(&[
+            "-A",
+            "all",
+            "-D",
+            "no-debugger",
+            "--timing-budget",
+            "budget.json",
+        ]);
+        assert!(output.contains("eslint/no-debugger"));
+        assert!(!output.contains("timing budget"));
+
+        // Timings are not deterministic, only snapshot the errors
+        tester.test_and_snapshot_multiple(&[
+            &["--timing-budget", "invalid.json"],
+            &["--timing-budget", "unknown-rule.json"],
+        ]);
+    }
+
+    #[test]
+    fn test_check_snapshot_without_file() {
+        let args_1 = &["--output-file", "missing.snap", "--check-snapshot"];
+        let args_2 = &["--check-snapshot"];
+        Tester::new()
+            .with_cwd("fixtures/check_snapshot".into())
+            .test_and_snapshot_multiple(&[args_1, args_2]);
+    }
+
+    #[test]
+    fn test_output_file() {
+        let cwd = Path::new("fixtures/check_snapshot");
+        let dir = tempfile::tempdir().unwrap();
+        let output_file = dir.path().join("output.snap");
+        let args = &["-f", "compact-stable", "--output-file", output_file.to_str().unwrap()];
+        // Nothing is printed to stdout
+        let output = Tester::new().with_cwd(cwd.into()).test_diagnostics_output(args);
+        assert_eq!(output, "");
+
+        let written = fs::read_to_string(output_file).unwrap();
+        let snapshot = fs::read_to_string(cwd.join("lint.snap")).unwrap();
+        assert_eq!(written, snapshot);
+    }
+
+    #[test]
+    fn test_merge_reports() {
+        let args = &["merge-reports", "shard-1.json", "shard-2.json"];
+        let deny_warnings_args = &["merge-reports", "--deny-warnings", "shard-1.json"];
+        let invalid_args = &["merge-reports", "invalid.json"];
+        Tester::new().with_cwd("fixtures/merge_reports".into()).test_and_snapshot_multiple(&[
+            args,
+            deny_warnings_args,
+            invalid_args,
+        ]);
+    }
+
+    #[test]
+    fn test_compare_reports() {
+        // the first `debugger` of `new.json` moved down two lines, the second one was added
+        let args = &["compare", "old.json", "new.json"];
+        let unchanged_args = &["compare", "new.json", "new.json"];
+        let invalid_args = &["compare", "old.json", "invalid.json"];
+        Tester::new().with_cwd("fixtures/compare_reports".into()).test_and_snapshot_multiple(&[
+            args,
+            unchanged_args,
+            invalid_args,
+        ]);
+    }
+
+    #[test]
+    fn test_summary_by_dir() {
+        // `packages/app` and `packages/utils` contain a `package.json`, `scripts` does not
+        let args = &["-A", "all", "-W", "no-debugger", "-D", "use-isnan", "--summary-by", "dir"];
+        Tester::new().with_cwd("fixtures/summary_by_dir".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_summary_by_dir_machine_readable() {
+        // the summary is not appended to machine-readable formats
+        let args = &[
+            "-A",
+            "all",
+            "-D",
+            "no-debugger",
+            "--summary-by",
+            "dir",
+            "-f",
+            "checkstyle",
+            "root.js",
+        ];
+        Tester::new().with_cwd("fixtures/summary_by_dir".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_tsconfig_auto() {
+        // `packages/b` reads its path aliases from a project reference
+        let args = &["--import-plugin", "-A", "all", "-D", "import/named", "--tsconfig", "auto"];
+        Tester::new().with_cwd("fixtures/tsconfig_auto".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_json_files() {
+        // JSON files are walked when the `json` plugin is enabled, comments are only allowed in
+        // JSONC files
+        let args_1 = &[];
+        let args_2 = &["data.json"];
+        Tester::new()
+            .with_cwd("fixtures/json".into())
+            .test_and_snapshot_multiple(&[args_1, args_2]);
+    }
+
+    #[test]
+    fn test_resolver_conditions() {
+        // `settings["import/resolver"].conditionNames` resolves `pkg` to its development build
+        let args_1 = &["index.js"];
+        let args_2 = &["-c", "no-settings.json", "index.js"];
+        // The settings of the configuration of each file are used, the nested one has none
+        let args_3 = &["index.js", "nested/index.js"];
+        Tester::new()
+            .with_cwd("fixtures/resolver_conditions".into())
+            .test_and_snapshot_multiple(&[args_1, args_2, args_3]);
+    }
+
     #[test]
     fn test_enable_vitest_rule_without_plugin() {
         let args = &[
@@ -1008,6 +2607,72 @@ mod test {
             "<script>debugger;</script>\n<script>debugger;</script>\n",
             "<script></script>\n<script></script>\n",
         );
+        // `no-unused-labels` and `no-debugger` fixes overlap, so they are applied in separate passes
+        Tester::test_fix("fixtures/fix_argument/multi_pass.js", "A: debugger;\n", "\n");
+        // Fixes in every section of a file are applied together
+        Tester::test_fix(
+            "fixtures/fix_argument/fix.astro",
+            "---\ndebugger;\n---\n<div />\n<script>debugger; debugger;</script>\n<script>A: debugger;</script>\n",
+            "---\n\n---\n<div />\n<script> </script>\n<script></script>\n",
+        );
+        Tester::test_fix(
+            "fixtures/fix_argument/sections.vue",
+            "<template><div /></template>\n<script>debugger; A: debugger;</script>\n<script setup lang=\"ts\">debugger;</script>\n",
+            "<template><div /></template>\n<script> </script>\n<script setup lang=\"ts\"></script>\n",
+        );
+    }
+
+    #[test]
+    fn test_fix_to_stdout() {
+        let args = &["--stdin", "--stdin-filename", "fix.js", "--fix-to-stdout"];
+        let tester = Tester::new().with_cwd("fixtures/fix_argument".into());
+        assert_eq!(tester.test_output_with_stdin(args, "debugger\n"), "\n");
+        // Source text is printed unchanged when there is nothing to fix.
+        assert_eq!(tester.test_output_with_stdin(args, "let a = 1;\n"), "let a = 1;\n");
+        // The summary goes to stderr.
+        let stderr = tester.test_stderr_with_stdin(args, "debugger\n");
+        assert!(stderr.starts_with("\nFound 1 warning and 0 errors.\n"), "{stderr}");
+    }
+
+    #[test]
+    fn test_fix_level() {
+        let tester = Tester::new().with_cwd("fixtures/fix_argument".into());
+        let args = &[
+            "--stdin",
+            "--stdin-filename",
+            "multi_pass.js",
+            "--fix-to-stdout",
+            "-D",
+            "no-debugger",
+            "-W",
+            "no-unused-labels",
+        ];
+        // Only the error is fixed, the warning is left for a human.
+        let error_args = [args.as_slice(), &["--fix-level", "error"]].concat();
+        assert_eq!(tester.test_output_with_stdin(&error_args, "A: { debugger; }\n"), "A: {  }\n");
+        let warn_args = [args.as_slice(), &["--fix-level", "warn"]].concat();
+        assert_eq!(tester.test_output_with_stdin(&warn_args, "A: { debugger; }\n"), "{  }\n");
+    }
+
+    #[test]
+    fn test_fix_report() {
+        let args =
+            &["--stdin", "--stdin-filename", "multi_pass.js", "--fix", "--fix-report", "json"];
+        let tester = Tester::new().with_cwd("fixtures/fix_argument".into());
+        let output = tester.test_stderr_with_stdin(args, "A: debugger;\n");
+        assert_eq!(
+            output,
+            r#"{"fixedFiles":[{"path":"multi_pass.js","fixes":2,"rules":{"eslint(no-debugger)":1,"eslint(no-unused-labels)":1}}]}
+"#
+        );
+        // Files without applied fixes are not reported.
+        let output = tester.test_stderr_with_stdin(args, "let a = 1;\n");
+        assert_eq!(output, "{\"fixedFiles\":[]}\n");
+
+        let args =
+            &["--stdin", "--stdin-filename", "a.js", "--fix-to-stdout", "--fix-report", "json"];
+        let output = tester.test_output_with_stdin(args, "debugger;\n");
+        assert_eq!(output, "The `--fix-report` option cannot be used with `--fix-to-stdout`.\n");
     }
 
     #[test]
@@ -1030,6 +2695,12 @@ mod test {
         Tester::new().test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_print_config_default_rule_configs() {
+        let args = &["-A", "all", "-D", "no-cond-assign", "-D", "eqeqeq", "--print-config"];
+        Tester::new().with_cwd("fixtures".into()).test_and_snapshot(args);
+    }
+
     #[test]
     fn test_init_config() {
         assert!(!fs::exists(CliRunner::DEFAULT_OXLINTRC).unwrap());
@@ -1042,6 +2713,16 @@ mod test {
         fs::remove_file(CliRunner::DEFAULT_OXLINTRC).unwrap();
     }
 
+    #[test]
+    fn test_init_detect_plugins() {
+        let plugins = CliRunner::detect_plugins(Path::new("fixtures/init_detect_plugins"));
+        assert_eq!(
+            plugins,
+            LintPlugins::REACT | LintPlugins::NEXTJS | LintPlugins::VITEST | LintPlugins::VUE
+        );
+        assert!(CliRunner::detect_plugins(Path::new("fixtures/linter")).is_empty());
+    }
+
     #[test]
     fn test_overrides() {
         let args_1 = &["-c", "fixtures/overrides/.oxlintrc.json", "fixtures/overrides/test.js"];
@@ -1113,6 +2794,27 @@ mod test {
         Tester::new().test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_nested_oxlintignore() {
+        let args = &[];
+        Tester::new().with_cwd("fixtures/nested_oxlintignore".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_nested_oxlintignore_explicit_paths() {
+        let args = &["main.js", "legacy.js", "sub/generated.js", "sub/legacy.js"];
+        Tester::new().with_cwd("fixtures/nested_oxlintignore".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_list_files() {
+        let args = &["--list-files"];
+        Tester::new().with_cwd("fixtures/nested_oxlintignore".into()).test_and_snapshot(args);
+        // `ignorePatterns` of nested configuration files apply too.
+        let args = &["--list-files", "--format", "json"];
+        Tester::new().with_cwd("fixtures/ignore_patterns_mixed".into()).test_and_snapshot(args);
+    }
+
     #[test]
     fn test_jest_and_vitest_alias_rules() {
         let args_1 = &["-c", "oxlint-jest.json", "test.js"];
@@ -1156,6 +2858,158 @@ mod test {
         Tester::new().with_cwd("fixtures/report_unused_directives".into()).test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_expired_directives() {
+        let args = &["-A", "all", "-D", "no-debugger"];
+
+        Tester::new().with_cwd("fixtures/expired_directives".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_report_unused_inline_configs() {
+        let args = &["-c", ".oxlintrc.json"];
+
+        Tester::new()
+            .with_cwd("fixtures/report_unused_inline_configs".into())
+            .test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_vendor_paths() {
+        let args = &["-c", ".oxlintrc.json"];
+        Tester::new().with_cwd("fixtures/vendor_paths".into()).test_and_snapshot(args);
+
+        let args = &["-c", "vendor-severity-off.json"];
+        Tester::new().with_cwd("fixtures/vendor_paths".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_path_sets() {
+        let args1 = &["@app"];
+        let args2 = &["@scripts"];
+        let args3 = &["@app", "other.js"];
+        let args4 = &["@missing"];
+        Tester::new()
+            .with_cwd("fixtures/path_sets".into())
+            .test_and_snapshot_multiple(&[args1, args2, args3, args4]);
+    }
+
+    #[test]
+    fn test_per_file_thresholds() {
+        let args = &["-c", ".oxlintrc.json"];
+        Tester::new().with_cwd("fixtures/per_file_thresholds".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_parser_options() {
+        let args = &["-c", ".oxlintrc.json"];
+        Tester::new().with_cwd("fixtures/parser_options".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_unknown_config_names() {
+        let args = &["-c", "config.json"];
+        Tester::new().with_cwd("fixtures/unknown_config_names".into()).test_and_snapshot(args);
+
+        let args = &["-c", "strict.json"];
+        Tester::new().with_cwd("fixtures/unknown_config_names".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_deprecated_config_names() {
+        let args = &["-c", "config.json"];
+        Tester::new().with_cwd("fixtures/deprecated_config_names".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_fix_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let cwd = dir.path();
+        fs::copy("fixtures/deprecated_config_names/config.json", cwd.join("fix.json")).unwrap();
+
+        let tester = Tester::new().with_cwd(cwd.into());
+        let output = tester.test_output(&["--fix-config", "-c", "fix.json"]);
+        assert_eq!(
+            output,
+            "Replaced plugin name `import-x` with `import` (deprecated-plugin-name).
+Replaced rule name `import-x/no-default-export` with `import/no-default-export` (deprecated-rule-name).
+Replaced rule name `deepscan/bad-array-method-on-arguments` with `oxc/bad-array-method-on-arguments` (deprecated-rule-name).
+Replaced global value `readable` with `readonly` (deprecated-global-value).
+Fixed 4 deprecated names in configuration file.
+"
+        );
+
+        let fixed = fs::read_to_string(cwd.join("fix.json")).unwrap();
+        assert_eq!(
+            fixed,
+            r#"{
+  "plugins": [
+    "import"
+  ],
+  "rules": {
+    "import/no-default-export": "error",
+    "oxc/bad-array-method-on-arguments": "off"
+  },
+  "globals": {
+    "foo": "readonly"
+  }
+}
+"#
+        );
+
+        let output = tester.test_output(&["--fix-config", "-c", "fix.json"]);
+        assert_eq!(output, "No deprecated names found in configuration file.\n");
+    }
+
+    #[test]
+    fn test_migrate_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let cwd = dir.path();
+        fs::write(
+            cwd.join("migrate.json"),
+            r#"{
+  // configuration without `$version`
+  "$schema": "./node_modules/oxlint/configuration_schema.json",
+  "plugins": ["import-x"],
+  "rules": {
+    "import-x/no-default-export": "error"
+  }
+}
+"#,
+        )
+        .unwrap();
+
+        let tester = Tester::new().with_cwd(cwd.into());
+        let output = tester.test_output(&["--migrate-config", "-c", "migrate.json"]);
+        assert_eq!(
+            output,
+            "Replaced plugin name `import-x` with `import` (deprecated-plugin-name).
+Replaced rule name `import-x/no-default-export` with `import/no-default-export` (deprecated-rule-name).
+Updated `$version` from 0 to 1.
+Migrated configuration file to version 1.
+"
+        );
+
+        let migrated = fs::read_to_string(cwd.join("migrate.json")).unwrap();
+        assert_eq!(
+            migrated,
+            r#"{
+  "$schema": "./node_modules/oxlint/configuration_schema.json",
+  "$version": 1,
+  "plugins": [
+    "import"
+  ],
+  "rules": {
+    "import/no-default-export": "error"
+  }
+}
+"#
+        );
+
+        let output = tester.test_output(&["--migrate-config", "-c", "migrate.json"]);
+        assert_eq!(output, "Configuration file is already at version 1.\n");
+    }
+
     #[test]
     fn test_nested_config() {
         let args = &[];
@@ -1189,6 +3043,25 @@ mod test {
         Tester::new().with_cwd("fixtures/nested_config".into()).test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_coverage_report() {
+        let args = &["--coverage-report"];
+        Tester::new().with_cwd("fixtures/nested_config".into()).test_and_snapshot(args);
+
+        let args = &["--coverage-report", "-c", "fixtures/eslintrc_off/eslintrc.json"];
+        Tester::new().test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_nested_config_ignore_command_line_filters() {
+        // Nested configs with `ignoreCommandLineFilters` are not changed by CLI filters, while the
+        // other configs still are.
+        let args = &["-D", "no-debugger"];
+        Tester::new()
+            .with_cwd("fixtures/nested_config_ignore_command_line_filters".into())
+            .test_and_snapshot(args);
+    }
+
     #[test]
     fn test_nested_config_explicit_config_and_filter_precedence() {
         // Combining `--config` and CLI filters should make the passed config file be
@@ -1292,6 +3165,15 @@ mod test {
         Tester::new().with_cwd("fixtures/overrides_with_plugin".into()).test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_import_plugin_enabled_by_override() {
+        // Only the imports of `src` are resolved, so only its cycle is reported.
+        let args = &["-c", ".oxlintrc.json"];
+        Tester::new()
+            .with_cwd("fixtures/overrides_with_import_plugin".into())
+            .test_and_snapshot(args);
+    }
+
     #[test]
     fn test_plugins_inside_overrides_categories_enabled_correctly() {
         let args = &["-c", ".oxlintrc.json"];
@@ -1304,6 +3186,48 @@ mod test {
         Tester::new().with_cwd("fixtures/issue_11644".into()).test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_sort_output() {
+        let tester = Tester::new().with_cwd("fixtures/linter".into());
+        let args = &["--sort-output", "-f", "unix", "-W", "no-debugger", "-W", "use-isnan"];
+        let output = tester.test_diagnostics_output(args);
+        assert_eq!(output, tester.test_diagnostics_output(args));
+
+        let positions = output
+            .lines()
+            .filter_map(|line| line.split_once(": ").map(|(position, _)| position))
+            .collect::<Vec<_>>();
+        assert!(positions.len() > 1);
+        assert!(positions.is_sorted_by_key(|position| {
+            let mut position = position.split(':');
+            let path = position.next().unwrap();
+            let mut number = || position.next().unwrap().parse::<usize>().unwrap();
+            (path, number(), number())
+        }));
+    }
+
+    #[test]
+    fn test_sort_by() {
+        let tester = Tester::new().with_cwd("fixtures/linter".into());
+        let args =
+            &["--sort-by", "severity,rule", "-f", "unix", "-W", "no-debugger", "-D", "use-isnan"];
+        let output = tester.test_diagnostics_output(args);
+
+        // Lines look like `path:line:column: message [Severity/rule]`.
+        let keys = output
+            .lines()
+            .filter_map(|line| {
+                let (path, rest) = line.split_once(':')?;
+                let (severity, rule) =
+                    rest.rsplit_once(" [")?.1.trim_end_matches(']').split_once('/')?;
+                Some((severity != "Error", rule.to_string(), path.to_string()))
+            })
+            .collect::<Vec<_>>();
+        assert!(keys.iter().any(|(is_warning, ..)| *is_warning));
+        assert!(keys.iter().any(|(is_warning, ..)| !*is_warning));
+        assert!(keys.is_sorted());
+    }
+
     #[test]
     fn test_dot_folder() {
         Tester::new().with_cwd("fixtures/dot_folder".into()).test_and_snapshot(&[]);
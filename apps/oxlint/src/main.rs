@@ -1,6 +1,8 @@
 use std::io::BufWriter;
 
-use oxlint::cli::{CliRunResult, CliRunner, init_miette, init_tracing, lint_command, run_lsp};
+use oxlint::cli::{
+    CliRunResult, CliRunner, init_crash_report, init_miette, init_tracing, lint_command, run_lsp,
+};
 
 #[tokio::main]
 async fn main() -> CliRunResult {
@@ -15,13 +17,17 @@ async fn main() -> CliRunResult {
 
     init_tracing();
     init_miette();
+    let args: Vec<String> =
+        std::env::args_os().skip(1).map(|arg| arg.to_string_lossy().into_owned()).collect();
+    init_crash_report(&command, &args);
 
     command.handle_threads();
 
     // stdio is blocked by LineWriter, use a BufWriter to reduce syscalls.
     // See `https://github.com/rust-lang/rust/issues/60673`.
     let mut stdout = BufWriter::new(std::io::stdout());
+    let mut stderr = BufWriter::new(std::io::stderr());
 
     // Run without external linter (no JS plugins)
-    CliRunner::new(command, None).run(&mut stdout)
+    CliRunner::new(command, None).run(&mut stdout, &mut stderr)
 }
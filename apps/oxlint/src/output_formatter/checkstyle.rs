@@ -1,13 +1,14 @@
 use std::borrow::Cow;
 
-use rustc_hash::FxHashMap;
+use indexmap::IndexMap;
+use rustc_hash::FxBuildHasher;
 
 use oxc_diagnostics::{
     Error, Severity,
     reporter::{DiagnosticReporter, DiagnosticResult, Info},
 };
 
-use crate::output_formatter::{InternalFormatter, xml_utils::xml_escape};
+use crate::output_formatter::{InternalFormatter, escape::xml_escape_attribute};
 
 #[derive(Debug, Default)]
 pub struct CheckStyleOutputFormatter;
@@ -39,7 +40,8 @@ impl DiagnosticReporter for CheckstyleReporter {
 
 fn format_checkstyle(diagnostics: &[Error]) -> String {
     let infos = diagnostics.iter().map(Info::new).collect::<Vec<_>>();
-    let mut grouped: FxHashMap<String, Vec<Info>> = FxHashMap::default();
+    // Files are listed in the order of their first diagnostic, see `--sort-by`.
+    let mut grouped: IndexMap<String, Vec<Info>, FxBuildHasher> = IndexMap::default();
     for info in infos {
         grouped.entry(info.filename.clone()).or_default().push(info);
     }
@@ -47,18 +49,21 @@ fn format_checkstyle(diagnostics: &[Error]) -> String {
          let messages = infos
              .iter()
              .fold(String::new(), |mut acc, info| {
-                 let Info { start, message, severity, rule_id, .. } = info;
+                 let Info { start, message, severity, rule_id, help, .. } = info;
                  let severity = match severity {
                      Severity::Error => "error",
                      _ => "warning",
                  };
-                 let message =  xml_escape(message);
-                 let source = rule_id.as_ref().map_or(Cow::Borrowed(""), |v| xml_escape(v));
+                 let message = match help {
+                     Some(help) => format!("{}&#10;help: {}", xml_escape_attribute(message), xml_escape_attribute(help)),
+                     None => xml_escape_attribute(message).into_owned(),
+                 };
+                 let source = rule_id.as_ref().map_or(Cow::Borrowed(""), |v| xml_escape_attribute(v));
                  let line = format!(r#"<error line="{}" column="{}" severity="{severity}" message="{message}" source="{source}" />"#, start.line, start.column);
                  acc.push_str(&line);
                  acc
              });
-         let filename = &infos[0].filename;
+         let filename = xml_escape_attribute(&infos[0].filename);
          format!(r#"<file name="{filename}">{messages}</file>"#)
      }).collect::<Vec<_>>().join(" ");
     format!(
@@ -73,8 +78,10 @@ mod test {
         reporter::{DiagnosticReporter, DiagnosticResult},
     };
     use oxc_span::Span;
+    use quickcheck::quickcheck;
 
     use super::CheckstyleReporter;
+    use crate::output_formatter::escape::test::HostileText;
 
     #[test]
     fn reporter() {
@@ -98,4 +105,40 @@ mod test {
             "<?xml version=\"1.0\" encoding=\"utf-8\"?><checkstyle version=\"4.3\"><file name=\"file://test.ts\"><error line=\"1\" column=\"1\" severity=\"warning\" message=\"error message\" source=\"\" /></file></checkstyle>\n"
         );
     }
+
+    #[test]
+    fn reporter_parse_error() {
+        let mut reporter = CheckstyleReporter::default();
+
+        let error = OxcDiagnostic::error(
+            "Expected a semicolon or an implicit semicolon after a statement, but found none",
+        )
+        .with_help("Try inserting a semicolon here")
+        .with_error_code("oxc", "parse-error")
+        .with_label(Span::new(5, 5))
+        .with_source_code(NamedSource::new("file.js", "let a b;"));
+        reporter.render_error(error);
+
+        // Diagnostics without labels are reported at the start of the file.
+        let error = OxcDiagnostic::error("Unexpected end of file")
+            .with_error_code("oxc", "parse-error")
+            .with_source_code(NamedSource::new("file.js", "let a b;"));
+        reporter.render_error(error);
+
+        assert_eq!(
+            reporter.finish(&DiagnosticResult::default()).unwrap(),
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?><checkstyle version=\"4.3\"><file name=\"file.js\"><error line=\"1\" column=\"6\" severity=\"error\" message=\"Expected a semicolon or an implicit semicolon after a statement, but found none&#10;help: Try inserting a semicolon here\" source=\"oxc(parse-error)\" /><error line=\"1\" column=\"1\" severity=\"error\" message=\"Unexpected end of file\" source=\"oxc(parse-error)\" /></file></checkstyle>\n"
+        );
+    }
+
+    #[test]
+    fn reporter_hostile_text() {
+        fn prop(text: HostileText) -> bool {
+            let mut reporter = CheckstyleReporter::default();
+            reporter.render_error(text.into_diagnostic());
+            let output = reporter.finish(&DiagnosticResult::default()).unwrap();
+            roxmltree::Document::parse(&output).is_ok()
+        }
+        quickcheck(prop as fn(HostileText) -> bool);
+    }
 }
@@ -0,0 +1,113 @@
+use cow_utils::CowUtils;
+
+use oxc_diagnostics::{
+    Error, Severity,
+    reporter::{DiagnosticReporter, DiagnosticResult, Info},
+};
+
+use crate::output_formatter::InternalFormatter;
+
+#[derive(Debug, Default)]
+pub struct CompactStableOutputFormatter;
+
+impl InternalFormatter for CompactStableOutputFormatter {
+    fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
+        Box::new(CompactStableReporter::default())
+    }
+}
+
+/// Reporter to output diagnostics in one line each, meant to be committed as an approval
+/// snapshot (see `--output-file` and `--check-snapshot`).
+///
+/// Diagnostics are sorted by path, position, rule and message, so the output does not depend on
+/// the order files are linted in. Nothing which changes between runs, like timings, is printed.
+#[derive(Default)]
+struct CompactStableReporter {
+    diagnostics: Vec<(Info, String)>,
+}
+
+impl DiagnosticReporter for CompactStableReporter {
+    fn finish(&mut self, _: &DiagnosticResult) -> Option<String> {
+        if self.diagnostics.is_empty() {
+            return None;
+        }
+        self.diagnostics.sort_unstable_by(|(a, a_line), (b, b_line)| {
+            a.filename
+                .cmp(&b.filename)
+                .then_with(|| a.start.cmp(&b.start))
+                .then_with(|| a.rule_id.cmp(&b.rule_id))
+                .then_with(|| a_line.cmp(b_line))
+        });
+        Some(self.diagnostics.drain(..).map(|(_, line)| line).collect())
+    }
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        let info = Info::new(&error);
+        let line = format_compact_stable(&info, &error);
+        self.diagnostics.push((info, line));
+        None
+    }
+}
+
+fn format_compact_stable(info: &Info, error: &Error) -> String {
+    let severity = match info.severity {
+        Severity::Error => "error",
+        _ => "warning",
+    };
+    // Diagnostics without labels, e.g. about configuration files, have no position.
+    let message = if info.message.is_empty() { error.to_string() } else { info.message.clone() };
+    let message = message.cow_replace('\n', " ");
+    let rule_id = info.rule_id.as_deref().map_or_else(String::new, |rule_id| format!(" {rule_id}"));
+    let Info { start, filename, .. } = info;
+    format!("{filename}:{}:{}: {severity}{rule_id}: {message}\n", start.line, start.column)
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::{
+        NamedSource, OxcDiagnostic,
+        reporter::{DiagnosticReporter, DiagnosticResult},
+    };
+    use oxc_span::Span;
+
+    use super::CompactStableReporter;
+
+    #[test]
+    fn reporter_finish_empty() {
+        let mut reporter = CompactStableReporter::default();
+
+        let result = reporter.finish(&DiagnosticResult::default());
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn reporter_sorts_diagnostics() {
+        let mut reporter = CompactStableReporter::default();
+        let source_text = "debugger;\ndebugger;";
+        let errors = [
+            OxcDiagnostic::warn("second line")
+                .with_label(Span::new(10, 18))
+                .with_source_code(NamedSource::new("b.ts", source_text)),
+            OxcDiagnostic::error("first line")
+                .with_error_code("eslint", "no-debugger")
+                .with_label(Span::new(0, 8))
+                .with_source_code(NamedSource::new("b.ts", source_text)),
+            OxcDiagnostic::warn("other file")
+                .with_label(Span::new(0, 8))
+                .with_source_code(NamedSource::new("a.ts", source_text)),
+        ];
+
+        for error in errors {
+            assert!(reporter.render_error(error).is_none());
+        }
+        let result = reporter.finish(&DiagnosticResult::default());
+
+        assert_eq!(
+            result.unwrap(),
+            "a.ts:1:1: warning: other file\n\
+             b.ts:1:1: error eslint(no-debugger): first line\n\
+             b.ts:2:1: warning: second line\n"
+        );
+    }
+}
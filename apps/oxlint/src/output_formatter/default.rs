@@ -1,14 +1,25 @@
-use std::time::Duration;
+use std::{borrow::Cow, collections::BTreeMap, fmt::Write, time::Duration};
 
-use crate::output_formatter::InternalFormatter;
+use cow_utils::CowUtils;
+use rustc_hash::FxHashMap;
+
+use crate::output_formatter::{InternalFormatter, graphical_theme};
 use oxc_diagnostics::{
     Error, GraphicalReportHandler,
     reporter::{DiagnosticReporter, DiagnosticResult},
 };
-use oxc_linter::table::RuleTable;
+use oxc_linter::{rule_of_diagnostic_code, table::RuleTable};
 
 #[derive(Debug)]
-pub struct DefaultOutputFormatter;
+pub struct DefaultOutputFormatter {
+    use_colors: bool,
+}
+
+impl DefaultOutputFormatter {
+    pub fn new(use_colors: bool) -> Self {
+        Self { use_colors }
+    }
+}
 
 impl InternalFormatter for DefaultOutputFormatter {
     fn all_rules(&self) -> Option<String> {
@@ -26,30 +37,34 @@ impl InternalFormatter for DefaultOutputFormatter {
     fn lint_command_info(&self, lint_command_info: &super::LintCommandInfo) -> Option<String> {
         let time = Self::get_execution_time(&lint_command_info.start_time);
         let s = if lint_command_info.number_of_files == 1 { "" } else { "s" };
+        let mut output = Self::get_counts_by_group(&lint_command_info.code_counts);
 
         if let Some(number_of_rules) = lint_command_info.number_of_rules {
-            Some(format!(
-                "Finished in {time} on {} file{s} with {} rules using {} threads.\n",
+            let _ = writeln!(
+                output,
+                "Finished in {time} on {} file{s} with {} rules using {} threads.",
                 lint_command_info.number_of_files, number_of_rules, lint_command_info.threads_count
-            ))
+            );
         } else {
-            Some(format!(
-                "Finished in {time} on {} file{s} using {} threads.\n",
+            let _ = writeln!(
+                output,
+                "Finished in {time} on {} file{s} using {} threads.",
                 lint_command_info.number_of_files, lint_command_info.threads_count
-            ))
+            );
         }
+        Some(output)
     }
 
     #[cfg(not(any(test, feature = "force_test_reporter")))]
     fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
-        Box::new(GraphicalReporter::default())
+        Box::new(GraphicalReporter::new(self.use_colors))
     }
 
     #[cfg(any(test, feature = "force_test_reporter"))]
     fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
         use crate::output_formatter::default::test_implementation::GraphicalReporterTester;
 
-        Box::new(GraphicalReporterTester::default())
+        Box::new(GraphicalReporterTester::new(self.use_colors))
     }
 }
 
@@ -58,19 +73,49 @@ impl DefaultOutputFormatter {
         let ms = duration.as_millis();
         if ms < 1000 { format!("{ms}ms") } else { format!("{:.1}s", duration.as_secs_f64()) }
     }
+
+    /// The number of diagnostics of built-in rules per category and per plugin, most frequent
+    /// first, e.g. `Diagnostics by category: correctness 3, style 1`.
+    fn get_counts_by_group(code_counts: &BTreeMap<String, usize>) -> String {
+        let mut categories = FxHashMap::<Cow<'static, str>, usize>::default();
+        let mut plugins = FxHashMap::<Cow<'static, str>, usize>::default();
+        for (code, count) in code_counts {
+            let Some(rule) = rule_of_diagnostic_code(code) else { continue };
+            *categories.entry(Cow::Borrowed(rule.category().as_str())).or_default() += count;
+            *plugins.entry(rule.plugin_name().cow_replace('_', "-")).or_default() += count;
+        }
+
+        let mut output = String::new();
+        for (group, counts) in [("category", categories), ("plugin", plugins)] {
+            if counts.is_empty() {
+                continue;
+            }
+            let mut counts = counts.into_iter().collect::<Vec<_>>();
+            counts.sort_unstable_by(|(a_name, a_count), (b_name, b_count)| {
+                b_count.cmp(a_count).then_with(|| a_name.cmp(b_name))
+            });
+            let counts = counts
+                .iter()
+                .map(|(name, count)| format!("{name} {count}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let _ = writeln!(output, "Diagnostics by {group}: {counts}");
+        }
+        output
+    }
 }
 
 /// Pretty-prints diagnostics. Primarily meant for human-readable output in a terminal.
 ///
 /// See [`GraphicalReportHandler`] for how to configure colors, context lines, etc.
-#[cfg_attr(all(not(test), feature = "force_test_reporter"), expect(dead_code))]
 struct GraphicalReporter {
     handler: GraphicalReportHandler,
 }
 
-impl Default for GraphicalReporter {
-    fn default() -> Self {
-        Self { handler: GraphicalReportHandler::new() }
+impl GraphicalReporter {
+    #[cfg_attr(all(not(test), feature = "force_test_reporter"), expect(dead_code))]
+    fn new(use_colors: bool) -> Self {
+        Self { handler: GraphicalReportHandler::new_themed(graphical_theme(use_colors)) }
     }
 }
 
@@ -123,14 +168,22 @@ mod test_implementation {
 
     use crate::output_formatter::default::get_diagnostic_result_output;
 
-    #[derive(Default)]
     pub struct GraphicalReporterTester {
         diagnostics: Vec<Error>,
+        use_colors: bool,
+    }
+
+    impl GraphicalReporterTester {
+        pub fn new(use_colors: bool) -> Self {
+            Self { diagnostics: Vec::new(), use_colors }
+        }
     }
 
     impl DiagnosticReporter for GraphicalReporterTester {
         fn finish(&mut self, result: &DiagnosticResult) -> Option<String> {
-            let handler = GraphicalReportHandler::new_themed(GraphicalTheme::none());
+            let theme =
+                if self.use_colors { GraphicalTheme::unicode() } else { GraphicalTheme::none() };
+            let handler = GraphicalReportHandler::new_themed(theme);
             let mut output = String::new();
 
             self.diagnostics.sort_by_cached_key(|diagnostic| {
@@ -156,7 +209,7 @@ mod test_implementation {
 
 #[cfg(test)]
 mod test {
-    use std::time::Duration;
+    use std::{collections::BTreeMap, time::Duration};
 
     use crate::output_formatter::{
         InternalFormatter, LintCommandInfo,
@@ -166,7 +219,7 @@ mod test {
 
     #[test]
     fn all_rules() {
-        let formatter = DefaultOutputFormatter;
+        let formatter = DefaultOutputFormatter::new(false);
         let result = formatter.all_rules();
 
         assert!(result.is_some());
@@ -174,12 +227,16 @@ mod test {
 
     #[test]
     fn lint_command_info() {
-        let formatter = DefaultOutputFormatter;
+        let formatter = DefaultOutputFormatter::new(false);
         let result = formatter.lint_command_info(&LintCommandInfo {
             number_of_files: 5,
             number_of_rules: Some(10),
             threads_count: 12,
             start_time: Duration::new(1, 0),
+            file_metadata: None,
+            rule_origins: None,
+            file_statuses: None,
+            code_counts: BTreeMap::new(),
         });
 
         assert!(result.is_some());
@@ -189,14 +246,49 @@ mod test {
         );
     }
 
+    #[test]
+    fn lint_command_info_counts_by_group() {
+        let formatter = DefaultOutputFormatter::new(false);
+        let code_counts = [
+            ("eslint(no-debugger)", 2),
+            ("eslint(eqeqeq)", 1),
+            ("eslint-plugin-jsx-a11y(alt-text)", 2),
+            ("oxc(parse-error)", 1),
+        ]
+        .into_iter()
+        .map(|(code, count)| (code.to_string(), count))
+        .collect();
+        let result = formatter.lint_command_info(&LintCommandInfo {
+            number_of_files: 5,
+            number_of_rules: Some(10),
+            threads_count: 12,
+            start_time: Duration::new(1, 0),
+            file_metadata: None,
+            rule_origins: None,
+            file_statuses: None,
+            code_counts,
+        });
+
+        assert_eq!(
+            result.unwrap(),
+            "Diagnostics by category: correctness 4, pedantic 1\n\
+             Diagnostics by plugin: eslint 3, jsx-a11y 2\n\
+             Finished in 1.0s on 5 files with 10 rules using 12 threads.\n"
+        );
+    }
+
     #[test]
     fn lint_command_info_unknown_rules() {
-        let formatter = DefaultOutputFormatter;
+        let formatter = DefaultOutputFormatter::new(false);
         let result = formatter.lint_command_info(&LintCommandInfo {
             number_of_files: 5,
             number_of_rules: None,
             threads_count: 12,
             start_time: Duration::new(1, 0),
+            file_metadata: None,
+            rule_origins: None,
+            file_statuses: None,
+            code_counts: BTreeMap::new(),
         });
 
         assert!(result.is_some());
@@ -205,7 +297,7 @@ mod test {
 
     #[test]
     fn reporter_finish_no_results() {
-        let mut reporter = GraphicalReporter::default();
+        let mut reporter = GraphicalReporter::new(false);
 
         let result = reporter.finish(&DiagnosticResult::default());
 
@@ -215,7 +307,7 @@ mod test {
 
     #[test]
     fn reporter_finish_one_warning_and_one_error() {
-        let mut reporter = GraphicalReporter::default();
+        let mut reporter = GraphicalReporter::new(false);
 
         let result = reporter.finish(&DiagnosticResult::new(1, 1, false));
 
@@ -225,7 +317,7 @@ mod test {
 
     #[test]
     fn reporter_finish_multiple_warning_and_errors() {
-        let mut reporter = GraphicalReporter::default();
+        let mut reporter = GraphicalReporter::new(false);
 
         let result = reporter.finish(&DiagnosticResult::new(6, 4, false));
 
@@ -235,7 +327,7 @@ mod test {
 
     #[test]
     fn reporter_finish_exceeded_warnings() {
-        let mut reporter = GraphicalReporter::default();
+        let mut reporter = GraphicalReporter::new(false);
 
         let result = reporter.finish(&DiagnosticResult::new(6, 4, true));
 
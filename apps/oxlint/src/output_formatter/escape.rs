@@ -0,0 +1,346 @@
+//! Escaping of the values written in structured output formats.
+//!
+//! Messages, help texts, file names and rule names can contain any text, e.g. ANSI escape
+//! sequences of a JS plugin or newlines. Every formatter writing them in XML, JSON or GitHub
+//! workflow commands escapes them with these functions, so its output stays valid.
+
+use std::{borrow::Cow, fmt::Write};
+
+/// Remove ANSI escape sequences, e.g. the colors of `\x1b[31mred\x1b[0m`, from `raw`.
+pub fn strip_ansi(raw: &str) -> Cow<'_, str> {
+    if !raw.contains('\u{1b}') {
+        return Cow::Borrowed(raw);
+    }
+    let mut result = String::with_capacity(raw.len());
+    let mut chars = raw.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            result.push(c);
+            continue;
+        }
+        match chars.peek() {
+            // Control sequences, e.g. colors: parameters and intermediates, then a final character.
+            Some('[') => {
+                chars.next();
+                while chars.next_if(|c| (' '..='?').contains(c)).is_some() {}
+                chars.next_if(|c| ('@'..='~').contains(c));
+            }
+            // Operating system commands, e.g. hyperlinks, end with BEL or `ESC \`.
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\u{7}' {
+                        break;
+                    }
+                    if c == '\u{1b}' {
+                        chars.next_if_eq(&'\\');
+                        break;
+                    }
+                }
+            }
+            Some(c) if ('@'..='~').contains(c) => {
+                chars.next();
+            }
+            _ => {}
+        }
+    }
+    Cow::Owned(result)
+}
+
+/// Escape `raw` for the text of an XML element. ANSI escape sequences are removed, and characters
+/// XML 1.0 cannot represent, even as character references, are replaced with U+FFFD.
+pub fn xml_escape(raw: &str) -> Cow<'_, str> {
+    xml_escape_with(raw, |ch| matches!(ch, b'<' | b'>' | b'&' | b'\'' | b'\"'))
+}
+
+/// Escape `raw` for the value of an XML attribute, like [`xml_escape`]. Tabs and line breaks are
+/// escaped too, so parsers do not normalize them to spaces.
+pub fn xml_escape_attribute(raw: &str) -> Cow<'_, str> {
+    xml_escape_with(raw, |ch| {
+        matches!(ch, b'<' | b'>' | b'&' | b'\'' | b'\"' | b'\t' | b'\n' | b'\r')
+    })
+}
+
+fn xml_escape_with<F: Fn(u8) -> bool>(raw: &str, escape_chars: F) -> Cow<'_, str> {
+    match xml_sanitize(raw) {
+        Cow::Borrowed(raw) => xml_escape_impl(raw, escape_chars),
+        Cow::Owned(raw) => Cow::Owned(xml_escape_impl(&raw, escape_chars).into_owned()),
+    }
+}
+
+/// `raw` without ANSI escape sequences, and with the characters XML 1.0 cannot represent replaced
+/// with U+FFFD.
+fn xml_sanitize(raw: &str) -> Cow<'_, str> {
+    // <https://www.w3.org/TR/xml/#charsets>
+    fn is_xml_char(c: char) -> bool {
+        matches!(c, '\t' | '\n' | '\r' | ' '..='\u{D7FF}' | '\u{E000}'..='\u{FFFD}' | '\u{10000}'..)
+    }
+
+    let raw = strip_ansi(raw);
+    if raw.chars().all(is_xml_char) {
+        return raw;
+    }
+    Cow::Owned(
+        raw.chars().map(|c| if is_xml_char(c) { c } else { char::REPLACEMENT_CHARACTER }).collect(),
+    )
+}
+
+/// <https://github.com/tafia/quick-xml/blob/6e34a730853fe295d68dc28460153f08a5a12955/src/escapei.rs#L84-L86>
+fn xml_escape_impl<F: Fn(u8) -> bool>(raw: &str, escape_chars: F) -> Cow<'_, str> {
+    let bytes = raw.as_bytes();
+    let mut escaped = None;
+    let mut iter = bytes.iter();
+    let mut pos = 0;
+    while let Some(i) = iter.position(|&b| escape_chars(b)) {
+        if escaped.is_none() {
+            escaped = Some(Vec::with_capacity(raw.len()));
+        }
+        let escaped = escaped.as_mut().expect("initialized");
+        let new_pos = pos + i;
+        escaped.extend_from_slice(&bytes[pos..new_pos]);
+        match bytes[new_pos] {
+            b'<' => escaped.extend_from_slice(b"&lt;"),
+            b'>' => escaped.extend_from_slice(b"&gt;"),
+            b'\'' => escaped.extend_from_slice(b"&apos;"),
+            b'&' => escaped.extend_from_slice(b"&amp;"),
+            b'"' => escaped.extend_from_slice(b"&quot;"),
+
+            // This set of escapes handles characters that should be escaped
+            // in elements of xs:lists, because those characters works as
+            // delimiters of list elements
+            b'\t' => escaped.extend_from_slice(b"&#9;"),
+            b'\n' => escaped.extend_from_slice(b"&#10;"),
+            b'\r' => escaped.extend_from_slice(b"&#13;"),
+            b' ' => escaped.extend_from_slice(b"&#32;"),
+            _ => unreachable!(
+                "Only '<', '>','\', '&', '\"', '\\t', '\\r', '\\n', and ' ' are escaped"
+            ),
+        }
+        pos = new_pos + 1;
+    }
+
+    if let Some(mut escaped) = escaped {
+        if let Some(raw) = bytes.get(pos..) {
+            escaped.extend_from_slice(raw);
+        }
+
+        // SAFETY: we operate on UTF-8 input and search for an one byte chars only,
+        // so all slices that was put to the `escaped` is a valid UTF-8 encoded strings
+        Cow::Owned(unsafe { String::from_utf8_unchecked(escaped) })
+    } else {
+        Cow::Borrowed(raw)
+    }
+}
+
+/// Escape the control characters of `json`, rendered by miette's `JSONReportHandler`, which only
+/// escapes `\n`, `\r`, `\t`, `\x08` and `\x0c` in strings. Other control characters are only
+/// valid in JSON as `\u` escapes. They can only occur in strings, since the handler writes no
+/// whitespace between values.
+pub fn json_escape_control_chars(json: String) -> String {
+    let is_control = |c: char| c < ' ';
+    if !json.contains(is_control) {
+        return json;
+    }
+    let mut result = String::with_capacity(json.len());
+    for c in json.chars() {
+        if is_control(c) {
+            let _ = write!(result, "\\u{:04x}", u32::from(c));
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Escape `value` for the message of a GitHub workflow command, e.g. `::error::{value}`.
+/// ANSI escape sequences are removed.
+pub fn github_escape_data(value: &str) -> String {
+    // Refs:
+    // - https://github.com/actions/runner/blob/a4c57f27477077e57545af79851551ff7f5632bd/src/Runner.Common/ActionCommand.cs#L18-L22
+    // - https://github.com/actions/toolkit/blob/fe3e7ce9a7f995d29d1fcfd226a32bca407f9dc8/packages/core/src/command.ts#L80-L94
+    let value = strip_ansi(value);
+    let mut result = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\r' => result.push_str("%0D"),
+            '\n' => result.push_str("%0A"),
+            '%' => result.push_str("%25"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Escape `value` for a property of a GitHub workflow command, e.g. `::error file={value}::`.
+/// ANSI escape sequences are removed.
+pub fn github_escape_property(value: &str) -> String {
+    // Refs:
+    // - https://github.com/actions/runner/blob/a4c57f27477077e57545af79851551ff7f5632bd/src/Runner.Common/ActionCommand.cs#L25-L32
+    // - https://github.com/actions/toolkit/blob/fe3e7ce9a7f995d29d1fcfd226a32bca407f9dc8/packages/core/src/command.ts#L80-L94
+    let value = strip_ansi(value);
+    let mut result = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\r' => result.push_str("%0D"),
+            '\n' => result.push_str("%0A"),
+            ':' => result.push_str("%3A"),
+            ',' => result.push_str("%2C"),
+            '%' => result.push_str("%25"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+pub mod test {
+    use std::borrow::Cow;
+
+    use cow_utils::CowUtils;
+    use quickcheck::{Arbitrary, Gen, quickcheck};
+
+    use oxc_diagnostics::{Error, NamedSource, OxcDiagnostic};
+    use oxc_span::Span;
+
+    use super::{
+        github_escape_data, github_escape_property, strip_ansi, xml_escape, xml_escape_attribute,
+        xml_sanitize,
+    };
+
+    /// Text made of characters which are special in the output formats, e.g. `<`, `"`, line
+    /// breaks, control characters and ANSI escape sequences, and of arbitrary strings.
+    #[derive(Debug, Clone)]
+    pub struct HostileText(pub String);
+
+    const FRAGMENTS: &[&str] = &[
+        "<",
+        ">",
+        "&",
+        "\"",
+        "'",
+        "\\",
+        "\n",
+        "\r",
+        "\r\n",
+        "\t",
+        " ",
+        "%",
+        "%25",
+        ":",
+        ",",
+        "::error file=a.js::",
+        "]]>",
+        "<!--",
+        "&amp;",
+        "&#10;",
+        "\u{0}",
+        "\u{8}",
+        "\u{b}",
+        "\u{c}",
+        "\u{1f}",
+        "\u{7f}",
+        "\u{85}",
+        "\u{2028}",
+        "\u{fffe}",
+        "\u{ffff}",
+        "\u{1b}",
+        "\u{1b}[31m",
+        "\u{1b}[0m",
+        "\u{1b}[1;4;38;5;196m",
+        "\u{1b}]8;;https://oxc.rs\u{7}",
+        "\u{1b}]8;;\u{1b}\\",
+        "\u{1b}c",
+        "é",
+        "😀",
+    ];
+
+    impl Arbitrary for HostileText {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let len = usize::arbitrary(g) % 16;
+            let text = std::iter::repeat_with(|| {
+                if bool::arbitrary(g) {
+                    Cow::Borrowed(*g.choose(FRAGMENTS).unwrap())
+                } else {
+                    Cow::Owned(String::arbitrary(g))
+                }
+            })
+            .take(len)
+            .collect();
+            Self(text)
+        }
+    }
+
+    impl HostileText {
+        /// A diagnostic with this text as its message, help, file name and rule name.
+        pub fn into_diagnostic(self) -> Error {
+            OxcDiagnostic::warn(self.0.clone())
+                .with_help(self.0.clone())
+                .with_error_code("hostile", format!("rule{}", self.0))
+                .with_label(Span::new(0, 8))
+                .with_source_code(NamedSource::new(self.0, "debugger;"))
+        }
+    }
+
+    #[test]
+    fn test_strip_ansi() {
+        fn prop(text: HostileText) -> bool {
+            let text = text.0;
+            let stripped = strip_ansi(&text);
+            !stripped.contains('\u{1b}')
+                && (text.contains('\u{1b}') || matches!(stripped, Cow::Borrowed(_)))
+        }
+
+        assert_eq!(strip_ansi("\u{1b}[1;31merror\u{1b}[0m: a"), "error: a");
+        assert_eq!(strip_ansi("\u{1b}]8;;https://oxc.rs\u{7}oxc\u{1b}]8;;\u{1b}\\"), "oxc");
+        assert_eq!(strip_ansi("a\u{1b}\nb\u{1b}"), "a\nb");
+        quickcheck(prop as fn(HostileText) -> bool);
+    }
+
+    #[test]
+    fn test_xml_escape() {
+        fn prop(text: HostileText) -> bool {
+            let text = text.0;
+            let xml = format!("<a b=\"{}\">{}</a>", xml_escape_attribute(&text), xml_escape(&text));
+            let document = roxmltree::Document::parse(&xml).unwrap();
+            let element = document.root_element();
+            let expected = xml_sanitize(&text);
+            // Parsers may normalize line breaks in the text of elements.
+            let normalize =
+                |text: &str| text.cow_replace("\r\n", "\n").cow_replace('\r', "\n").into_owned();
+            element.attribute("b") == Some(&*expected)
+                && normalize(element.text().unwrap_or_default()) == normalize(&expected)
+        }
+
+        assert_eq!(xml_escape("a < b\n\u{1b}[31m\u{0}"), "a &lt; b\n\u{fffd}");
+        assert_eq!(xml_escape_attribute("\"a\"\r\n"), "&quot;a&quot;&#13;&#10;");
+        quickcheck(prop as fn(HostileText) -> bool);
+    }
+
+    #[test]
+    fn test_github_escape() {
+        fn unescape(value: &str) -> String {
+            let mut result = String::new();
+            let mut chars = value.chars();
+            while let Some(c) = chars.next() {
+                if c == '%' {
+                    let code = chars.by_ref().take(2).collect::<String>();
+                    result.push(char::from(u8::from_str_radix(&code, 16).unwrap()));
+                } else {
+                    result.push(c);
+                }
+            }
+            result
+        }
+
+        fn prop(text: HostileText) -> bool {
+            let text = text.0;
+            let data = github_escape_data(&text);
+            let property = github_escape_property(&text);
+            let stripped = strip_ansi(&text);
+            !data.contains(['\r', '\n', '\u{1b}'])
+                && !property.contains(['\r', '\n', '\u{1b}', ':', ','])
+                && unescape(&data) == stripped
+                && unescape(&property) == stripped
+        }
+        quickcheck(prop as fn(HostileText) -> bool);
+    }
+}
@@ -5,7 +5,10 @@ use oxc_diagnostics::{
     reporter::{DiagnosticReporter, DiagnosticResult, Info},
 };
 
-use crate::output_formatter::InternalFormatter;
+use crate::output_formatter::{
+    InternalFormatter,
+    escape::{github_escape_data, github_escape_property},
+};
 
 #[derive(Debug)]
 pub struct GithubOutputFormatter;
@@ -31,54 +34,21 @@ impl DiagnosticReporter for GithubReporter {
 }
 
 fn format_github(diagnostic: &Error) -> String {
-    let Info { start, end, filename, message, severity, rule_id } = Info::new(diagnostic);
+    let Info { start, end, filename, message, severity, rule_id, .. } = Info::new(diagnostic);
     let severity = match severity {
         Severity::Error => "error",
         Severity::Warning | miette::Severity::Advice => "warning",
     };
-    let title = rule_id.map_or(Cow::Borrowed("oxlint"), Cow::Owned);
-    let filename = escape_property(&filename);
-    let message = escape_data(&message);
+    let title = rule_id
+        .map_or(Cow::Borrowed("oxlint"), |rule_id| Cow::Owned(github_escape_property(&rule_id)));
+    let filename = github_escape_property(&filename);
+    let message = github_escape_data(&message);
     format!(
         "::{severity} file={filename},line={},endLine={},col={},endColumn={},title={title}::{message}\n",
         start.line, end.line, start.column, end.column
     )
 }
 
-fn escape_data(value: &str) -> String {
-    // Refs:
-    // - https://github.com/actions/runner/blob/a4c57f27477077e57545af79851551ff7f5632bd/src/Runner.Common/ActionCommand.cs#L18-L22
-    // - https://github.com/actions/toolkit/blob/fe3e7ce9a7f995d29d1fcfd226a32bca407f9dc8/packages/core/src/command.ts#L80-L94
-    let mut result = String::with_capacity(value.len());
-    for c in value.chars() {
-        match c {
-            '\r' => result.push_str("%0D"),
-            '\n' => result.push_str("%0A"),
-            '%' => result.push_str("%25"),
-            _ => result.push(c),
-        }
-    }
-    result
-}
-
-fn escape_property(value: &str) -> String {
-    // Refs:
-    // - https://github.com/actions/runner/blob/a4c57f27477077e57545af79851551ff7f5632bd/src/Runner.Common/ActionCommand.cs#L25-L32
-    // - https://github.com/actions/toolkit/blob/fe3e7ce9a7f995d29d1fcfd226a32bca407f9dc8/packages/core/src/command.ts#L80-L94
-    let mut result = String::with_capacity(value.len());
-    for c in value.chars() {
-        match c {
-            '\r' => result.push_str("%0D"),
-            '\n' => result.push_str("%0A"),
-            ':' => result.push_str("%3A"),
-            ',' => result.push_str("%2C"),
-            '%' => result.push_str("%25"),
-            _ => result.push(c),
-        }
-    }
-    result
-}
-
 #[cfg(test)]
 mod test {
     use oxc_diagnostics::{
@@ -86,8 +56,10 @@ mod test {
         reporter::{DiagnosticReporter, DiagnosticResult},
     };
     use oxc_span::Span;
+    use quickcheck::quickcheck;
 
     use super::GithubReporter;
+    use crate::output_formatter::escape::test::HostileText;
 
     #[test]
     fn reporter_finish() {
@@ -113,4 +85,22 @@ mod test {
             "::warning file=file%3A//test.ts,line=1,endLine=1,col=1,endColumn=9,title=oxlint::error message\n"
         );
     }
+
+    #[test]
+    fn reporter_hostile_text() {
+        fn prop(text: HostileText) -> bool {
+            let output = GithubReporter.render_error(text.into_diagnostic()).unwrap();
+            // Each diagnostic is a single workflow command: `::warning {properties}::{message}`.
+            let Some((properties, message)) =
+                output.strip_prefix("::warning ").and_then(|command| command.split_once("::"))
+            else {
+                return false;
+            };
+            properties.split(',').count() == 6
+                && message
+                    .strip_suffix('\n')
+                    .is_some_and(|message| !message.contains(['\r', '\n', '\u{1b}']))
+        }
+        quickcheck(prop as fn(HostileText) -> bool);
+    }
 }
@@ -63,7 +63,7 @@ impl DiagnosticReporter for GitlabReporter {
 
 fn format_gitlab(diagnostics: &mut Vec<Error>) -> String {
     let errors = diagnostics.drain(..).map(|error| {
-        let Info { start, end, filename, message, severity, rule_id } = Info::new(&error);
+        let Info { start, end, filename, message, severity, rule_id, .. } = Info::new(&error);
         let severity = match severity {
             Severity::Error => "critical".to_string(),
             Severity::Warning => "major".to_string(),
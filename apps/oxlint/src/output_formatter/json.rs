@@ -1,15 +1,15 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{borrow::Cow, cell::RefCell, collections::BTreeMap, rc::Rc};
 
 use miette::JSONReportHandler;
 use serde::Serialize;
 
 use oxc_diagnostics::{
-    Error,
+    DiagnosticValue, Error, diagnostic_data,
     reporter::{DiagnosticReporter, DiagnosticResult},
 };
-use oxc_linter::{RuleCategory, rules::RULES};
+use oxc_linter::{RuleCategory, RuleOrigin, rules::RULES};
 
-use crate::output_formatter::InternalFormatter;
+use crate::output_formatter::{InternalFormatter, escape::json_escape_control_chars};
 
 #[derive(Debug, Default)]
 pub struct JsonOutputFormatter {
@@ -38,17 +38,31 @@ impl InternalFormatter for JsonOutputFormatter {
     }
 
     fn lint_command_info(&self, lint_command_info: &super::LintCommandInfo) -> Option<String> {
-        let diagnostics = self.reporter.0.borrow_mut().render();
+        let diagnostics =
+            self.reporter.0.borrow_mut().render(lint_command_info.rule_origins.as_ref());
         let number_of_rules =
             lint_command_info.number_of_rules.map_or("null".to_string(), |x| x.to_string());
         let start_time = lint_command_info.start_time.as_secs_f64();
+        let files = lint_command_info.file_metadata.as_ref().map_or(String::new(), |files| {
+            format!(
+                ",\n              \"files\": {}",
+                serde_json::to_string(files).expect("Failed to serialize")
+            )
+        });
+        let file_statuses =
+            lint_command_info.file_statuses.as_ref().map_or(String::new(), |statuses| {
+                format!(
+                    ",\n              \"file_statuses\": {}",
+                    serde_json::to_string(statuses).expect("Failed to serialize")
+                )
+            });
 
         Some(format!(
             r#"{{ "diagnostics": {},
               "number_of_files": {},
               "number_of_rules": {},
               "threads_count": {},
-              "start_time": {}
+              "start_time": {}{}{}
             }}
             "#,
             diagnostics,
@@ -56,6 +70,8 @@ impl InternalFormatter for JsonOutputFormatter {
             number_of_rules,
             lint_command_info.threads_count,
             start_time,
+            files,
+            file_statuses,
         ))
     }
 
@@ -98,34 +114,102 @@ impl DiagnosticReporter for JsonReporter {
 }
 
 impl JsonReporter {
-    pub(super) fn render(&mut self) -> String {
-        format_json(&mut self.diagnostics)
+    pub(super) fn render(
+        &mut self,
+        rule_origins: Option<&BTreeMap<String, BTreeMap<String, RuleOrigin>>>,
+    ) -> String {
+        format_json(&mut self.diagnostics, rule_origins)
     }
 }
 
 /// <https://github.com/fregante/eslint-formatters/tree/ae1fd9748596447d1fd09625c33d9e7ba9a3d06d/packages/eslint-formatter-json>
-fn format_json(diagnostics: &mut Vec<Error>) -> String {
+///
+/// With `--rule-origins`, diagnostics of rules get an `origin` field with the configuration which
+/// decided the severity of the rule, see [`RuleOrigin`]. Diagnostics with structured data a `data` field, see
+/// [`OxcDiagnostic::with_data`](oxc_diagnostics::OxcDiagnostic::with_data).
+fn format_json(
+    diagnostics: &mut Vec<Error>,
+    rule_origins: Option<&BTreeMap<String, BTreeMap<String, RuleOrigin>>>,
+) -> String {
     let handler = JSONReportHandler::new();
     let messages = diagnostics
         .drain(..)
         .map(|error| {
-            let mut output = String::new();
-            handler.render_report(&mut output, error.as_ref()).unwrap();
-            output
+            let origin = rule_origins.and_then(|origins| rule_origin(origins, &error));
+            format_diagnostic(&handler, &error, origin)
         })
         .collect::<Vec<_>>()
         .join(",\n");
     format!("[{messages}]")
 }
 
+/// One diagnostic of [`format_json`] as a JSON object on a single line.
+///
+/// Every diagnostic is serialized by `serde_json`, so that diagnostics with and without the
+/// fields added to the report of miette have the same style.
+pub(super) fn format_diagnostic(
+    handler: &JSONReportHandler,
+    error: &Error,
+    origin: Option<&RuleOrigin>,
+) -> String {
+    /// The report of miette's [`JSONReportHandler`] with the fields it does not know about.
+    #[derive(Serialize)]
+    struct DiagnosticJson<'a> {
+        #[serde(flatten)]
+        report: serde_json::Map<String, serde_json::Value>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        origin: Option<&'a RuleOrigin>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        data: Option<serde_json::Map<String, serde_json::Value>>,
+    }
+
+    let mut output = String::new();
+    handler.render_report(&mut output, error.as_ref()).unwrap();
+    let output = json_escape_control_chars(output);
+    let data = diagnostic_data(error);
+    let Ok(report) = serde_json::from_str(&output) else {
+        return output;
+    };
+    let diagnostic =
+        DiagnosticJson { report, origin, data: (!data.is_empty()).then(|| data_json(data)) };
+    serde_json::to_string(&diagnostic).expect("Failed to serialize")
+}
+
+/// The structured data of a diagnostic as a JSON object, e.g. `{"identifier":"foo","max":3}`.
+fn data_json(
+    data: &[(Cow<'static, str>, DiagnosticValue)],
+) -> serde_json::Map<String, serde_json::Value> {
+    data.iter()
+        .map(|(key, value)| {
+            let value = match value {
+                DiagnosticValue::String(value) => serde_json::Value::from(value.as_ref()),
+                DiagnosticValue::Number(value) => serde_json::Value::from(*value),
+            };
+            (key.to_string(), value)
+        })
+        .collect()
+}
+
+/// The origin of the rule which reported `error`, by the path of its file and its code.
+fn rule_origin<'o>(
+    rule_origins: &'o BTreeMap<String, BTreeMap<String, RuleOrigin>>,
+    error: &Error,
+) -> Option<&'o RuleOrigin> {
+    let code = error.code()?.to_string();
+    rule_origins.get(error.source_code()?.name()?)?.get(&code)
+}
+
 #[cfg(test)]
 mod test {
-    use std::time::Duration;
+    use std::{collections::BTreeMap, time::Duration};
 
     use oxc_diagnostics::{NamedSource, OxcDiagnostic, reporter::DiagnosticResult};
     use oxc_span::Span;
+    use quickcheck::quickcheck;
 
-    use crate::output_formatter::{InternalFormatter, LintCommandInfo, json::JsonOutputFormatter};
+    use crate::output_formatter::{
+        InternalFormatter, LintCommandInfo, escape::test::HostileText, json::JsonOutputFormatter,
+    };
 
     #[test]
     fn reporter() {
@@ -151,11 +235,68 @@ mod test {
                 number_of_rules: Some(0),
                 start_time: Duration::new(0, 0),
                 threads_count: 1,
+                file_metadata: None,
+                rule_origins: None,
+                file_statuses: None,
+                code_counts: BTreeMap::new(),
             })
             .unwrap();
         assert_eq!(
             &output,
-            "{ \"diagnostics\": [{\"message\": \"error message\",\"severity\": \"warning\",\"causes\": [],\"filename\": \"file://test.ts\",\"labels\": [{\"span\": {\"offset\": 0,\"length\": 8,\"line\": 1,\"column\": 1}}],\"related\": []}],\n              \"number_of_files\": 0,\n              \"number_of_rules\": 0,\n              \"threads_count\": 1,\n              \"start_time\": 0\n            }\n            "
+            "{ \"diagnostics\": [{\"message\":\"error message\",\"severity\":\"warning\",\"causes\":[],\"filename\":\"file://test.ts\",\"labels\":[{\"span\":{\"offset\":0,\"length\":8,\"line\":1,\"column\":1}}],\"related\":[]}],\n              \"number_of_files\": 0,\n              \"number_of_rules\": 0,\n              \"threads_count\": 1,\n              \"start_time\": 0\n            }\n            "
         );
     }
+
+    #[test]
+    fn reporter_with_data() {
+        let formatter = JsonOutputFormatter::default();
+
+        let error = OxcDiagnostic::warn("'foo' has too many lines")
+            .with_data("identifier", "foo")
+            .with_data("max", 3_usize)
+            .with_label(Span::new(0, 3))
+            .with_source_code(NamedSource::new("test.ts", "foo"));
+
+        let mut diagnostic_reporter = formatter.get_diagnostic_reporter();
+        diagnostic_reporter.render_error(error);
+        let output = formatter
+            .lint_command_info(&LintCommandInfo {
+                number_of_files: 1,
+                number_of_rules: Some(1),
+                start_time: Duration::new(0, 0),
+                threads_count: 1,
+                file_metadata: None,
+                rule_origins: None,
+                file_statuses: None,
+                code_counts: BTreeMap::new(),
+            })
+            .unwrap();
+        assert!(output.contains(",\"related\":[],\"data\":{\"identifier\":\"foo\",\"max\":3}}]"));
+    }
+
+    #[test]
+    fn reporter_hostile_text() {
+        fn prop(text: HostileText) -> bool {
+            let expected = text.0.clone();
+            let formatter = JsonOutputFormatter::default();
+            formatter.get_diagnostic_reporter().render_error(text.into_diagnostic());
+            let output = formatter
+                .lint_command_info(&LintCommandInfo {
+                    number_of_files: 1,
+                    number_of_rules: Some(1),
+                    start_time: Duration::new(0, 0),
+                    threads_count: 1,
+                    file_metadata: None,
+                    rule_origins: None,
+                    file_statuses: None,
+                    code_counts: BTreeMap::new(),
+                })
+                .unwrap();
+            let output = serde_json::from_str::<serde_json::Value>(&output).unwrap();
+            let diagnostic = &output["diagnostics"][0];
+            diagnostic["message"] == expected.as_str()
+                && diagnostic["filename"] == expected.as_str()
+        }
+        quickcheck(prop as fn(HostileText) -> bool);
+    }
 }
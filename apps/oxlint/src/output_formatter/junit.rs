@@ -1,10 +1,14 @@
+use indexmap::IndexMap;
 use oxc_diagnostics::{
     Error, Severity,
     reporter::{DiagnosticReporter, DiagnosticResult, Info},
 };
-use rustc_hash::FxHashMap;
+use rustc_hash::FxBuildHasher;
 
-use super::{InternalFormatter, xml_utils::xml_escape};
+use super::{
+    InternalFormatter,
+    escape::{xml_escape, xml_escape_attribute},
+};
 
 #[derive(Default)]
 pub struct JUnitOutputFormatter;
@@ -32,7 +36,8 @@ impl DiagnosticReporter for JUnitReporter {
 }
 
 fn format_junit(diagnostics: &[Error]) -> String {
-    let mut grouped: FxHashMap<String, Vec<&Error>> = FxHashMap::default();
+    // Files are listed in the order of their first diagnostic, see `--sort-by`.
+    let mut grouped: IndexMap<String, Vec<&Error>, FxBuildHasher> = IndexMap::default();
     let mut total_errors = 0;
     let mut total_warnings = 0;
 
@@ -51,7 +56,7 @@ fn format_junit(diagnostics: &[Error]) -> String {
 
         for diagnostic in diagnostics {
             let rule = diagnostic.code().map_or_else(String::new, |code| code.to_string());
-            let Info { message, start, .. } = Info::new(diagnostic);
+            let Info { message, start, help, .. } = Info::new(diagnostic);
 
             let severity = if diagnostic.severity() == Some(Severity::Error) {
                 total_errors += 1;
@@ -62,23 +67,29 @@ fn format_junit(diagnostics: &[Error]) -> String {
                 warning += 1;
                 "failure"
             };
-            let description =
+            let mut description =
                 format!("line {}, column {}, {}", start.line, start.column, xml_escape(&message));
+            if let Some(help) = help {
+                description.push_str("\nhelp: ");
+                description.push_str(&xml_escape(&help));
+            }
 
             let status = format!(
                 "            <{} message=\"{}\">{}</{}>",
                 severity,
-                xml_escape(&message),
+                xml_escape_attribute(&message),
                 description,
                 severity
             );
-            let test_case =
-                format!("\n        <testcase name=\"{rule}\">\n{status}\n        </testcase>");
+            let test_case = format!(
+                "\n        <testcase name=\"{}\">\n{status}\n        </testcase>",
+                xml_escape_attribute(&rule)
+            );
             test_cases = format!("{test_cases}{test_case}");
         }
         test_suite = format!(
             "    <testsuite name=\"{}\" tests=\"{}\" disabled=\"0\" errors=\"{}\" failures=\"{}\">{}\n    </testsuite>",
-            filename,
+            xml_escape_attribute(&filename),
             diagnostics.len(),
             error,
             warning,
@@ -101,6 +112,9 @@ mod test {
     use super::*;
     use oxc_diagnostics::{NamedSource, OxcDiagnostic, reporter::DiagnosticResult};
     use oxc_span::Span;
+    use quickcheck::quickcheck;
+
+    use crate::output_formatter::escape::test::HostileText;
 
     #[test]
     fn test_junit_reporter() {
@@ -132,4 +146,15 @@ mod test {
         let output = reporter.finish(&DiagnosticResult::default()).unwrap();
         assert_eq!(output, EXPECTED_REPORT);
     }
+
+    #[test]
+    fn test_junit_reporter_hostile_text() {
+        fn prop(text: HostileText) -> bool {
+            let mut reporter = JUnitReporter::default();
+            reporter.render_error(text.into_diagnostic());
+            let output = reporter.finish(&DiagnosticResult::default()).unwrap();
+            roxmltree::Document::parse(&output).is_ok()
+        }
+        quickcheck(prop as fn(HostileText) -> bool);
+    }
 }
@@ -1,24 +1,34 @@
 mod checkstyle;
+mod compact_stable;
 mod default;
+mod escape;
 mod github;
 mod gitlab;
 mod json;
 mod junit;
+mod ndjson;
 mod stylish;
+mod summary;
 mod unix;
-mod xml_utils;
 
+use std::collections::BTreeMap;
+use std::io::IsTerminal;
 use std::str::FromStr;
 use std::time::Duration;
 
 use checkstyle::CheckStyleOutputFormatter;
+use compact_stable::CompactStableOutputFormatter;
 use github::GithubOutputFormatter;
 use gitlab::GitlabOutputFormatter;
 use junit::JUnitOutputFormatter;
+use ndjson::NdjsonOutputFormatter;
 use stylish::StylishOutputFormatter;
 use unix::UnixOutputFormatter;
 
-use oxc_diagnostics::reporter::DiagnosticReporter;
+pub use summary::DirectorySummaryReporter;
+
+use oxc_diagnostics::{GraphicalTheme, SortKey, reporter::DiagnosticReporter};
+use oxc_linter::{FileMetadata, FileStatus, RuleOrigin};
 
 use crate::output_formatter::{default::DefaultOutputFormatter, json::JsonOutputFormatter};
 
@@ -30,10 +40,21 @@ pub enum OutputFormat {
     Github,
     Gitlab,
     Json,
+    /// One JSON object per line, written as soon as each diagnostic is reported
+    Ndjson,
     Unix,
     Checkstyle,
     Stylish,
     JUnit,
+    /// One line per diagnostic in a stable order, to be committed as an approval snapshot
+    CompactStable,
+}
+
+impl OutputFormat {
+    /// Whether the format is meant to be read by humans, so extra reports can be appended to it.
+    pub fn is_text(self) -> bool {
+        matches!(self, Self::Default | Self::Stylish | Self::Unix)
+    }
 }
 
 impl FromStr for OutputFormat {
@@ -42,6 +63,7 @@ impl FromStr for OutputFormat {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "json" => Ok(Self::Json),
+            "ndjson" => Ok(Self::Ndjson),
             "default" => Ok(Self::Default),
             "unix" => Ok(Self::Unix),
             "checkstyle" => Ok(Self::Checkstyle),
@@ -49,11 +71,139 @@ impl FromStr for OutputFormat {
             "gitlab" => Ok(Self::Gitlab),
             "stylish" => Ok(Self::Stylish),
             "junit" => Ok(Self::JUnit),
+            "compact-stable" => Ok(Self::CompactStable),
             _ => Err(format!("'{s}' is not a known format")),
         }
     }
 }
 
+/// Aggregated report rendered at the end of the run, see `--summary-by`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SummaryBy {
+    /// Diagnostic counts per package or top-level directory.
+    Dir,
+}
+
+impl FromStr for SummaryBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dir" => Ok(Self::Dir),
+            _ => Err(format!("'{s}' is not a known summary mode")),
+        }
+    }
+}
+
+/// Keys to sort the diagnostics by, see `--sort-by`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SortBy(pub Vec<SortKey>);
+
+impl Default for SortBy {
+    /// The keys of `--sort-output`.
+    fn default() -> Self {
+        Self(vec![SortKey::Path, SortKey::Rule])
+    }
+}
+
+impl FromStr for SortBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(str::trim)
+            .map(|key| match key {
+                "severity" => Ok(SortKey::Severity),
+                "path" => Ok(SortKey::Path),
+                "rule" => Ok(SortKey::Rule),
+                _ => Err(format!("'{key}' is not a known sort key")),
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// When to emit ANSI colors in the human-readable formats, see `--color`.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum ColorChoice {
+    /// Colors when stdout is a terminal, unless overridden by `NO_COLOR` or `FORCE_COLOR`.
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl FromStr for ColorChoice {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            _ => Err(format!("'{s}' is not a known color choice")),
+        }
+    }
+}
+
+impl ColorChoice {
+    /// Whether the output should contain ANSI colors.
+    pub fn use_colors(self) -> bool {
+        match self {
+            Self::Auto => auto_colors(),
+            Self::Always => true,
+            Self::Never => false,
+        }
+    }
+}
+
+/// Snapshots must not depend on the terminal the tests are run in.
+#[cfg(any(test, feature = "force_test_reporter"))]
+fn auto_colors() -> bool {
+    false
+}
+
+#[cfg(not(any(test, feature = "force_test_reporter")))]
+fn auto_colors() -> bool {
+    if let Ok(value) = std::env::var("FORCE_COLOR") {
+        return value != "0";
+    }
+    if std::env::var("NO_COLOR").is_ok_and(|value| !value.is_empty()) {
+        return false;
+    }
+    // Assume CI can always print colors.
+    std::env::var("CI").is_ok() || std::io::stdout().is_terminal()
+}
+
+/// Theme of the graphical reports: unicode drawing characters are kept on a terminal even
+/// when colors are disabled, plain ASCII is used when piped.
+pub fn graphical_theme(use_colors: bool) -> GraphicalTheme {
+    if use_colors {
+        GraphicalTheme::unicode()
+    } else if std::io::stdout().is_terminal() {
+        GraphicalTheme::unicode_nocolor()
+    } else {
+        GraphicalTheme::none()
+    }
+}
+
+/// Format of the report of the files written by `--fix`, see `--fix-report`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FixReport {
+    Json,
+}
+
+impl FromStr for FixReport {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            _ => Err(format!("'{s}' is not a known fix report format")),
+        }
+    }
+}
+
 /// Some extra lint information, which can be outputted
 /// at the end of the command
 pub struct LintCommandInfo {
@@ -66,6 +216,17 @@ pub struct LintCommandInfo {
     pub threads_count: usize,
     /// Some reporters want to output the duration it took to finished the task
     pub start_time: Duration,
+    /// Frameworks and source types detected for each linted file, keyed by the displayed path.
+    /// Only collected with `--file-metadata`.
+    pub file_metadata: Option<BTreeMap<String, FileMetadata>>,
+    /// Origins of the rules which reported diagnostics, keyed by the displayed path and the code
+    /// of the rule. Only collected for the JSON output.
+    pub rule_origins: Option<BTreeMap<String, BTreeMap<String, RuleOrigin>>>,
+    /// Status of each file passed to the linter, keyed by the displayed path. Only collected
+    /// for `--stats` and the JSON output.
+    pub file_statuses: Option<BTreeMap<String, FileStatus>>,
+    /// Number of warnings and errors reported per diagnostic code, e.g. `eslint(no-debugger)`.
+    pub code_counts: BTreeMap<String, usize>,
 }
 
 /// An Interface for the different output formats.
@@ -91,20 +252,25 @@ pub struct OutputFormatter {
 }
 
 impl OutputFormatter {
-    pub fn new(format: OutputFormat) -> Self {
-        Self { internal: Self::get_internal_formatter(format) }
+    pub fn new(format: OutputFormat, use_colors: bool) -> Self {
+        Self { internal: Self::get_internal_formatter(format, use_colors) }
     }
 
-    fn get_internal_formatter(format: OutputFormat) -> Box<dyn InternalFormatter> {
+    fn get_internal_formatter(
+        format: OutputFormat,
+        use_colors: bool,
+    ) -> Box<dyn InternalFormatter> {
         match format {
             OutputFormat::Json => Box::<JsonOutputFormatter>::default(),
+            OutputFormat::Ndjson => Box::new(NdjsonOutputFormatter),
             OutputFormat::Checkstyle => Box::<CheckStyleOutputFormatter>::default(),
             OutputFormat::Github => Box::new(GithubOutputFormatter),
             OutputFormat::Gitlab => Box::<GitlabOutputFormatter>::default(),
             OutputFormat::Unix => Box::<UnixOutputFormatter>::default(),
-            OutputFormat::Default => Box::new(DefaultOutputFormatter),
-            OutputFormat::Stylish => Box::<StylishOutputFormatter>::default(),
+            OutputFormat::Default => Box::new(DefaultOutputFormatter::new(use_colors)),
+            OutputFormat::Stylish => Box::new(StylishOutputFormatter::new(use_colors)),
             OutputFormat::JUnit => Box::<JUnitOutputFormatter>::default(),
+            OutputFormat::CompactStable => Box::<CompactStableOutputFormatter>::default(),
         }
     }
 
@@ -150,6 +316,14 @@ mod test {
         Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
     }
 
+    #[cfg(all(test, not(target_os = "windows")))]
+    #[test]
+    fn test_output_formatter_diagnostic_json_rule_origins() {
+        let args = &["--format=json", "--rule-origins", "test.js"];
+
+        Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
+    }
+
     #[test]
     fn test_output_formatter_diagnostic_checkstyle() {
         let args = &["--format=checkstyle", "test.js"];
@@ -171,10 +345,38 @@ mod test {
         Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_output_formatter_diagnostic_stylish_color_always() {
+        let args = &["--format=stylish", "--color", "always", "test.js"];
+
+        Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
+    }
+
     #[test]
     fn test_output_formatter_diagnostic_junit() {
         let args = &["--format=junit", "test.js"];
 
         Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
     }
+
+    #[test]
+    fn test_output_formatter_diagnostic_compact_stable() {
+        let args = &["--format=compact-stable", "test.js"];
+
+        Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
+    }
+
+    /// Syntax errors have the `oxc(parse-error)` rule id in every format, the code of the parser
+    /// is kept as `parserCode` data.
+    #[cfg(all(test, not(target_os = "windows")))]
+    #[test]
+    fn test_output_formatter_parse_error() {
+        let json_args = &["--format=json", "test.ts"];
+        let checkstyle_args = &["--format=checkstyle", "test.ts"];
+        let junit_args = &["--format=junit", "test.ts"];
+
+        Tester::new()
+            .with_cwd("fixtures/output_formatter_parse_error".into())
+            .test_and_snapshot_multiple(&[json_args, checkstyle_args, junit_args]);
+    }
 }
@@ -0,0 +1,82 @@
+use std::fmt::Write;
+
+use miette::JSONReportHandler;
+use serde::Serialize;
+
+use oxc_diagnostics::{
+    Error,
+    reporter::{DiagnosticReporter, DiagnosticResult},
+};
+use oxc_linter::FileMetadata;
+
+use crate::output_formatter::{InternalFormatter, LintCommandInfo, json::format_diagnostic};
+
+/// Renders each diagnostic as soon as it is reported, as one JSON object per line, in the same
+/// shape as the diagnostics of the `json` format. With `--file-metadata`, one more line per
+/// linted file is written at the end, as `{"file": ..., "metadata": ...}`.
+#[derive(Debug, Default)]
+pub struct NdjsonOutputFormatter;
+
+impl InternalFormatter for NdjsonOutputFormatter {
+    fn lint_command_info(&self, lint_command_info: &LintCommandInfo) -> Option<String> {
+        #[derive(Serialize)]
+        struct FileMetadataLine<'a> {
+            file: &'a str,
+            metadata: &'a FileMetadata,
+        }
+
+        let files = lint_command_info.file_metadata.as_ref()?;
+        let mut output = String::new();
+        for (file, metadata) in files {
+            let line = FileMetadataLine { file, metadata };
+            let _ =
+                writeln!(output, "{}", serde_json::to_string(&line).expect("Failed to serialize"));
+        }
+        Some(output)
+    }
+
+    fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
+        Box::new(NdjsonReporter { handler: JSONReportHandler::new() })
+    }
+}
+
+struct NdjsonReporter {
+    handler: JSONReportHandler,
+}
+
+impl DiagnosticReporter for NdjsonReporter {
+    fn finish(&mut self, _: &DiagnosticResult) -> Option<String> {
+        None
+    }
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        let mut output = format_diagnostic(&self.handler, &error, None);
+        output.push('\n');
+        Some(output)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::{NamedSource, OxcDiagnostic, reporter::DiagnosticResult};
+    use oxc_span::Span;
+
+    use super::NdjsonOutputFormatter;
+    use crate::output_formatter::InternalFormatter;
+
+    #[test]
+    fn reporter() {
+        let mut reporter = NdjsonOutputFormatter.get_diagnostic_reporter();
+
+        let error = OxcDiagnostic::warn("error message\nsecond line")
+            .with_label(Span::new(0, 8))
+            .with_source_code(NamedSource::new("test.ts", "debugger;"));
+        let result = reporter.render_error(error);
+
+        assert_eq!(
+            result.unwrap(),
+            "{\"message\":\"error message\\nsecond line\",\"severity\":\"warning\",\"causes\":[],\"filename\":\"test.ts\",\"labels\":[{\"span\":{\"offset\":0,\"length\":8,\"line\":1,\"column\":1}}],\"related\":[]}\n"
+        );
+        assert!(reporter.finish(&DiagnosticResult::default()).is_none());
+    }
+}
@@ -9,22 +9,31 @@ use rustc_hash::FxHashMap;
 use crate::output_formatter::InternalFormatter;
 
 #[derive(Debug, Default)]
-pub struct StylishOutputFormatter;
+pub struct StylishOutputFormatter {
+    use_colors: bool,
+}
+
+impl StylishOutputFormatter {
+    pub fn new(use_colors: bool) -> Self {
+        Self { use_colors }
+    }
+}
 
 impl InternalFormatter for StylishOutputFormatter {
     fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
-        Box::new(StylishReporter::default())
+        Box::new(StylishReporter { diagnostics: Vec::new(), use_colors: self.use_colors })
     }
 }
 
 #[derive(Default)]
 struct StylishReporter {
     diagnostics: Vec<Error>,
+    use_colors: bool,
 }
 
 impl DiagnosticReporter for StylishReporter {
     fn finish(&mut self, _: &DiagnosticResult) -> Option<String> {
-        Some(format_stylish(&self.diagnostics))
+        Some(format_stylish(&self.diagnostics, self.use_colors))
     }
 
     fn render_error(&mut self, error: Error) -> Option<String> {
@@ -33,11 +42,20 @@ impl DiagnosticReporter for StylishReporter {
     }
 }
 
-fn format_stylish(diagnostics: &[Error]) -> String {
+fn format_stylish(diagnostics: &[Error], use_colors: bool) -> String {
     if diagnostics.is_empty() {
         return String::new();
     }
 
+    let style = |code: &'static str| if use_colors { code } else { "" };
+    let (underline, red, yellow, dim, reset) = (
+        style("\u{1b}[4m"),
+        style("\u{1b}[31m"),
+        style("\u{1b}[33m"),
+        style("\u{1b}[2m"),
+        style("\u{1b}[0m"),
+    );
+
     let mut output = String::new();
     let mut total_errors = 0;
     let mut total_warnings = 0;
@@ -72,7 +90,7 @@ fn format_stylish(diagnostics: &[Error]) -> String {
             .max()
             .unwrap_or(0);
 
-        writeln!(output, "\n\u{1b}[4m{filename}\u{1b}[0m").unwrap();
+        writeln!(output, "\n{underline}{filename}{reset}").unwrap();
 
         for diagnostic in diagnostics {
             match diagnostic.severity() {
@@ -81,9 +99,9 @@ fn format_stylish(diagnostics: &[Error]) -> String {
             }
 
             let severity_str = if diagnostic.severity() == Some(Severity::Error) {
-                "\u{1b}[31merror\u{1b}[0m"
+                format!("{red}error{reset}")
             } else {
-                "\u{1b}[33mwarning\u{1b}[0m"
+                format!("{yellow}warning{reset}")
             };
 
             let info = Info::new(diagnostic);
@@ -91,17 +109,18 @@ fn format_stylish(diagnostics: &[Error]) -> String {
             let position = format!("{}:{}", info.start.line, info.start.column);
             writeln!(
                 output,
-                "  \u{1b}[2m{position:max_len_width$}\u{1b}[0m  {severity_str}  {diagnostic}  \u{1b}[2m{rule}\u{1b}[0m"
-            ).unwrap();
+                "  {dim}{position:max_len_width$}{reset}  {severity_str}  {diagnostic}  {dim}{rule}{reset}"
+            )
+            .unwrap();
         }
     }
 
     let total = total_errors + total_warnings;
     if total > 0 {
-        let summary_color = if total_errors > 0 { "\u{1b}[31m" } else { "\u{1b}[33m" };
+        let summary_color = if total_errors > 0 { red } else { yellow };
         writeln!(
             output,
-            "\n{summary_color}✖ {total} problem{} ({total_errors} error{}, {total_warnings} warning{})\u{1b}[0m",
+            "\n{summary_color}✖ {total} problem{} ({total_errors} error{}, {total_warnings} warning{}){reset}",
             if total == 1 { "" } else { "s" },
             if total_errors == 1 { "" } else { "s" },
             if total_warnings == 1 { "" } else { "s" }
@@ -141,4 +160,22 @@ mod test {
         assert!(output.contains("1 error"), "Output should mention error count");
         assert!(output.contains("1 warning"), "Output should mention warning count");
     }
+
+    #[test]
+    fn test_stylish_reporter_colors() {
+        let diagnostic = || {
+            vec![
+                OxcDiagnostic::error("error message")
+                    .with_label(Span::new(0, 4))
+                    .with_source_code(NamedSource::new("file.js", "code")),
+            ]
+        };
+
+        let output = format_stylish(&diagnostic(), false);
+        assert!(!output.contains('\u{1b}'), "Output should not contain ANSI escape codes");
+        assert!(output.contains("  error  "), "Output should contain the severity");
+
+        let output = format_stylish(&diagnostic(), true);
+        assert!(output.contains("\u{1b}[31merror\u{1b}[0m"), "Output should color the severity");
+    }
 }
@@ -0,0 +1,139 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    path::{Component, Path, PathBuf},
+};
+
+use rustc_hash::FxHashMap;
+
+use oxc_diagnostics::{
+    Error, Severity,
+    reporter::{DiagnosticReporter, DiagnosticResult, Info},
+};
+
+/// Diagnostics without a rule, e.g. syntax errors.
+const NO_RULE: &str = "(no rule)";
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Counts {
+    errors: usize,
+    warnings: usize,
+}
+
+impl Counts {
+    fn add(&mut self, severity: Severity) {
+        if severity == Severity::Error {
+            self.errors += 1;
+        } else {
+            self.warnings += 1;
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct DirectorySummary {
+    total: Counts,
+    rules: BTreeMap<String, Counts>,
+}
+
+/// Wraps the reporter of the output format and counts its diagnostics per directory, which are
+/// rendered as a table after the output of the wrapped reporter.
+///
+/// Diagnostics are grouped by the closest package (a directory with a `package.json` below the
+/// working directory), or by the top-level directory for files outside of a package.
+pub struct DirectorySummaryReporter {
+    inner: Box<dyn DiagnosticReporter>,
+    cwd: PathBuf,
+    directories: BTreeMap<String, DirectorySummary>,
+    /// Whether a directory (relative to `cwd`) contains a `package.json`.
+    package_roots: FxHashMap<PathBuf, bool>,
+}
+
+impl DirectorySummaryReporter {
+    pub fn new(inner: Box<dyn DiagnosticReporter>, cwd: PathBuf) -> Self {
+        Self { inner, cwd, directories: BTreeMap::new(), package_roots: FxHashMap::default() }
+    }
+
+    /// The directory `filename` (relative to `cwd`) is counted for.
+    fn directory_of(&mut self, filename: &str) -> String {
+        let path = Path::new(filename);
+        let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) else {
+            return ".".to_string();
+        };
+
+        for directory in parent.ancestors().filter(|dir| !dir.as_os_str().is_empty()) {
+            let is_package_root = *self
+                .package_roots
+                .entry(directory.to_path_buf())
+                .or_insert_with(|| self.cwd.join(directory).join("package.json").is_file());
+            if is_package_root {
+                return display_directory(directory);
+            }
+        }
+
+        match parent.components().next() {
+            Some(Component::Normal(first)) => first.to_string_lossy().into_owned(),
+            _ => display_directory(parent),
+        }
+    }
+
+    fn render_summary(&self) -> String {
+        let rows = self
+            .directories
+            .iter()
+            .flat_map(|(directory, summary)| {
+                std::iter::once((directory.clone(), summary.total)).chain(
+                    summary.rules.iter().map(|(rule, counts)| (format!("  {rule}"), *counts)),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let header = ("Directory / Rule", "Errors", "Warnings");
+        let name_width =
+            rows.iter().map(|(name, _)| name.len()).max().unwrap_or(0).max(header.0.len());
+
+        let mut output = format!(
+            "\nSummary by directory:\n{:<name_width$}  {:>6}  {:>8}\n",
+            header.0, header.1, header.2
+        );
+        for (name, counts) in rows {
+            let _ = writeln!(
+                output,
+                "{name:<name_width$}  {:>6}  {:>8}",
+                counts.errors, counts.warnings
+            );
+        }
+        output
+    }
+}
+
+fn display_directory(directory: &Path) -> String {
+    directory.components().map(|c| c.as_os_str().to_string_lossy()).collect::<Vec<_>>().join("/")
+}
+
+impl DiagnosticReporter for DirectorySummaryReporter {
+    fn finish(&mut self, result: &DiagnosticResult) -> Option<String> {
+        let output = self.inner.finish(result);
+        if self.directories.is_empty() {
+            return output;
+        }
+        let mut output = output.unwrap_or_default();
+        output.push_str(&self.render_summary());
+        Some(output)
+    }
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        let info = Info::new(&error);
+        if !info.filename.is_empty() {
+            let directory = self.directory_of(&info.filename);
+            let summary = self.directories.entry(directory).or_default();
+            summary.total.add(info.severity);
+            summary
+                .rules
+                .entry(info.rule_id.unwrap_or_else(|| NO_RULE.to_string()))
+                .or_default()
+                .add(info.severity);
+        }
+        self.inner.render_error(error)
+    }
+}
@@ -41,7 +41,7 @@ impl DiagnosticReporter for UnixReporter {
 
 /// <https://github.com/fregante/eslint-formatters/tree/ae1fd9748596447d1fd09625c33d9e7ba9a3d06d/packages/eslint-formatter-unix>
 fn format_unix(diagnostic: &Error) -> String {
-    let Info { start, end: _, filename, message, severity, rule_id } = Info::new(diagnostic);
+    let Info { start, filename, message, severity, rule_id, .. } = Info::new(diagnostic);
     let severity = match severity {
         Severity::Error => "Error",
         _ => "Warning",
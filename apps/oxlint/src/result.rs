@@ -13,9 +13,14 @@ pub enum CliRunResult {
     LintMaxWarningsExceeded,
     LintNoWarningsAllowed,
     LintNoFilesFound,
+    LintSnapshotMismatch,
+    LintTimingBudgetExceeded,
+    LintNewDiagnosticsFound,
     PrintConfigResult,
     ConfigFileInitFailed,
     ConfigFileInitSucceeded,
+    ConfigFileFixSucceeded,
+    ConfigFileMigrateSucceeded,
     TsGoLintError,
     TooManyFilesWithImportAndJsPlugins,
 }
@@ -26,6 +31,8 @@ impl Termination for CliRunResult {
             Self::None
             | Self::PrintConfigResult
             | Self::ConfigFileInitSucceeded
+            | Self::ConfigFileFixSucceeded
+            | Self::ConfigFileMigrateSucceeded
             | Self::LintSucceeded
             // ToDo: when oxc_linter (config) validates the configuration, we can use exit_code = 1 to fail
             | Self::LintNoFilesFound => ExitCode::SUCCESS,
@@ -33,6 +40,9 @@ impl Termination for CliRunResult {
             | Self::LintFoundErrors
             | Self::LintNoWarningsAllowed
             | Self::LintMaxWarningsExceeded
+            | Self::LintSnapshotMismatch
+            | Self::LintTimingBudgetExceeded
+            | Self::LintNewDiagnosticsFound
             | Self::InvalidOptionConfig
             | Self::InvalidOptionTsConfig
             | Self::InvalidOptionSeverityWithoutFilter
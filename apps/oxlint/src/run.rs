@@ -11,6 +11,7 @@ use napi::{
 use napi_derive::napi;
 
 use crate::{
+    crash_report::init_crash_report,
     init::{init_miette, init_tracing},
     lint::CliRunner,
     result::CliRunResult,
@@ -52,19 +53,65 @@ pub type JsLintFileCb = ThreadsafeFunction<
     false,
 >;
 
+/// JS callback to extract JS/TS sections from a file with a processor of a JS plugin.
+#[napi]
+pub type JsPreprocessCb = ThreadsafeFunction<
+    // Arguments
+    FnArgs<(
+        u32,    // Processor ID
+        String, // Absolute path of file
+        String, // Source text of file
+    )>,
+    // Return value
+    String, // `Vec<PreprocessSection>`, serialized to JSON
+    // Arguments (repeated)
+    FnArgs<(u32, String, String)>,
+    // Error status
+    Status,
+    // CalleeHandled
+    false,
+>;
+
 /// NAPI entry point.
 ///
 /// JS side passes in:
 /// 1. `args`: Command line arguments (process.argv.slice(2))
 /// 2. `load_plugin`: Load a JS plugin from a file path.
 /// 3. `lint_file`: Lint a file.
+/// 4. `preprocess`: Extract JS/TS sections from a file with a processor.
 ///
 /// Returns `true` if linting succeeded without errors, `false` otherwise.
 #[expect(clippy::allow_attributes)]
 #[allow(clippy::trailing_empty_array, clippy::unused_async)] // https://github.com/napi-rs/napi-rs/issues/2758
 #[napi]
-pub async fn lint(args: Vec<String>, load_plugin: JsLoadPluginCb, lint_file: JsLintFileCb) -> bool {
-    lint_impl(args, load_plugin, lint_file).await.report() == ExitCode::SUCCESS
+pub async fn lint(
+    args: Vec<String>,
+    load_plugin: JsLoadPluginCb,
+    lint_file: JsLintFileCb,
+    preprocess: JsPreprocessCb,
+) -> bool {
+    lint_impl(args, load_plugin, lint_file, preprocess).await.report() == ExitCode::SUCCESS
+}
+
+/// Request data for the file currently being linted, which is computed on demand.
+///
+/// JS side passes in:
+/// 1. `buffer_id`: ID of the buffer containing the file's AST.
+/// 2. `kind`: Kind of data requested (`ExternalFileData`).
+///
+/// The data is written into the buffer. Returns `[offset, length]` of the data in the buffer,
+/// as a `Uint32Array` offset and number of `u32`s, or `null` if the data is not available.
+#[napi]
+pub fn request_file_data(buffer_id: u32, kind: u32) -> Option<Vec<u32>> {
+    #[cfg(all(target_pointer_width = "64", target_endian = "little"))]
+    let location = super::js_plugins::request_file_data(buffer_id, kind);
+    #[cfg(not(all(target_pointer_width = "64", target_endian = "little")))]
+    let location = {
+        let (_, _) = (buffer_id, kind);
+        None
+    };
+
+    location.map(|(offset, len)| vec![offset >> 2, len])
 }
 
 /// Run the linter.
@@ -72,13 +119,15 @@ async fn lint_impl(
     args: Vec<String>,
     load_plugin: JsLoadPluginCb,
     lint_file: JsLintFileCb,
+    preprocess: JsPreprocessCb,
 ) -> CliRunResult {
     // Convert String args to OsString for compatibility with bpaf
-    let args: Vec<std::ffi::OsString> = args.into_iter().map(std::ffi::OsString::from).collect();
+    let os_args: Vec<std::ffi::OsString> =
+        args.iter().cloned().map(std::ffi::OsString::from).collect();
 
     let command = {
         let cmd = crate::cli::lint_command();
-        match cmd.run_inner(&*args) {
+        match cmd.run_inner(&*os_args) {
             Ok(cmd) => cmd,
             Err(e) => {
                 e.print_message(100);
@@ -99,21 +148,24 @@ async fn lint_impl(
 
     init_tracing();
     init_miette();
+    init_crash_report(&command, &args);
 
     command.handle_threads();
 
     // JS plugins are only supported on 64-bit little-endian platforms at present
     #[cfg(all(target_pointer_width = "64", target_endian = "little"))]
-    let external_linter = Some(super::js_plugins::create_external_linter(load_plugin, lint_file));
+    let external_linter =
+        Some(super::js_plugins::create_external_linter(load_plugin, lint_file, preprocess));
     #[cfg(not(all(target_pointer_width = "64", target_endian = "little")))]
     let external_linter = {
-        let (_, _) = (load_plugin, lint_file);
+        let (_, _, _) = (load_plugin, lint_file, preprocess);
         None
     };
 
     // stdio is blocked by LineWriter, use a BufWriter to reduce syscalls.
     // See `https://github.com/rust-lang/rust/issues/60673`.
     let mut stdout = BufWriter::new(std::io::stdout());
+    let mut stderr = BufWriter::new(std::io::stderr());
 
-    CliRunner::new(command, external_linter).run(&mut stdout)
+    CliRunner::new(command, external_linter).run(&mut stdout, &mut stderr)
 }
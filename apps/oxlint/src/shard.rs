@@ -0,0 +1,220 @@
+use std::{
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use serde::Deserialize;
+
+use crate::{cli::WarningOptions, result::CliRunResult};
+
+/// A part of the linted files, selected with `--shard I/N`.
+///
+/// Files are assigned to shards by a stable hash of their path relative to the working directory,
+/// so every job of a CI matrix lints a disjoint set of files, independent of where the
+/// repository is checked out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shard {
+    /// 1-based index of the shard
+    index: u64,
+    count: u64,
+}
+
+impl FromStr for Shard {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("'{s}' is not a valid shard, expected `I/N` with 1 <= I <= N");
+        let (index, count) = s.split_once('/').ok_or_else(invalid)?;
+        let index = index.trim().parse::<u64>().map_err(|_| invalid())?;
+        let count = count.trim().parse::<u64>().map_err(|_| invalid())?;
+        if index == 0 || index > count {
+            return Err(invalid());
+        }
+        Ok(Self { index, count })
+    }
+}
+
+impl Shard {
+    /// Whether the file at `path`, relative to the working directory and normalized to `/`
+    /// separators, belongs to this shard.
+    pub fn contains(self, path: &str) -> bool {
+        fnv1a(path.as_bytes()) % self.count == self.index - 1
+    }
+}
+
+/// 64-bit FNV-1a, which is stable across platforms and releases, unlike the hashers of `std`.
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(PRIME))
+}
+
+/// The parts of a report written with `--format json` which are needed to merge or compare it.
+#[derive(Debug, Deserialize)]
+pub struct JsonReport {
+    pub diagnostics: Vec<JsonDiagnostic>,
+    pub number_of_files: usize,
+}
+
+impl JsonReport {
+    /// Read the report at `path`, relative to `cwd`.
+    pub fn read(cwd: &Path, path: &Path) -> Result<Self, String> {
+        fs::read_to_string(cwd.join(path))
+            .map_err(|err| err.to_string())
+            .and_then(|content| serde_json::from_str(&content).map_err(|err| err.to_string()))
+            .map_err(|err| format!("Failed to read report {}: {err}\n", path.display()))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JsonDiagnostic {
+    pub message: String,
+    pub code: Option<String>,
+    pub severity: String,
+    #[serde(default)]
+    pub filename: String,
+    #[serde(default)]
+    labels: Vec<JsonLabel>,
+}
+
+impl JsonDiagnostic {
+    /// Line and column of the first label, `(0, 0)` for diagnostics without labels.
+    pub fn position(&self) -> (usize, usize) {
+        self.labels.first().map_or((0, 0), |label| (label.span.line, label.span.column))
+    }
+
+    /// Order by path, position, rule and message.
+    pub fn cmp_by_location(&self, other: &Self) -> std::cmp::Ordering {
+        (&self.filename, self.position(), &self.code, &self.message).cmp(&(
+            &other.filename,
+            other.position(),
+            &other.code,
+            &other.message,
+        ))
+    }
+
+    /// Print the diagnostic on a single line, like `--format unix`.
+    pub fn to_line(&self) -> String {
+        let severity = if self.is_error() { "Error" } else { "Warning" };
+        let (line, column) = self.position();
+        let rule_id = self.code.as_ref().map(|code| format!("/{code}")).unwrap_or_default();
+        format!("{}:{line}:{column}: {} [{severity}{rule_id}]", self.filename, self.message)
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == "error"
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonLabel {
+    span: JsonSpan,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonSpan {
+    line: usize,
+    column: usize,
+}
+
+/// Combine the JSON reports of `--shard` runs at `paths` (relative to `cwd`) into one list of
+/// diagnostics, one summary and one exit code, as if all files were linted by a single run.
+pub fn merge_reports(
+    cwd: &Path,
+    paths: &[PathBuf],
+    warning_options: &WarningOptions,
+) -> (String, CliRunResult) {
+    let mut diagnostics = vec![];
+    let mut number_of_files = 0;
+    for path in paths {
+        match JsonReport::read(cwd, path) {
+            Ok(report) => {
+                diagnostics.extend(report.diagnostics);
+                number_of_files += report.number_of_files;
+            }
+            Err(err) => return (err, CliRunResult::InvalidOptionConfig),
+        }
+    }
+
+    diagnostics.sort_by(JsonDiagnostic::cmp_by_location);
+
+    let mut output = String::new();
+    let (mut warnings_count, mut errors_count) = (0, 0);
+    for diagnostic in &diagnostics {
+        if diagnostic.is_error() {
+            errors_count += 1;
+        } else {
+            warnings_count += 1;
+        }
+        let _ = writeln!(output, "{}", diagnostic.to_line());
+    }
+
+    if !diagnostics.is_empty() {
+        output.push('\n');
+    }
+    let _ = writeln!(
+        output,
+        "Found {warnings_count} warning{} and {errors_count} error{}.",
+        if warnings_count == 1 { "" } else { "s" },
+        if errors_count == 1 { "" } else { "s" },
+    );
+    let max_warnings_exceeded =
+        warning_options.max_warnings.is_some_and(|max_warnings| warnings_count > max_warnings);
+    if max_warnings_exceeded {
+        let _ = writeln!(output, "Exceeded maximum number of warnings. Found {warnings_count}.");
+    }
+    let _ = writeln!(
+        output,
+        "Merged {} report{} of {number_of_files} file{}.",
+        paths.len(),
+        if paths.len() == 1 { "" } else { "s" },
+        if number_of_files == 1 { "" } else { "s" },
+    );
+
+    let result = if errors_count > 0 {
+        CliRunResult::LintFoundErrors
+    } else if warning_options.deny_warnings && warnings_count > 0 {
+        CliRunResult::LintNoWarningsAllowed
+    } else if max_warnings_exceeded {
+        CliRunResult::LintMaxWarningsExceeded
+    } else {
+        CliRunResult::LintSucceeded
+    };
+    (output, result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::Shard;
+
+    #[test]
+    fn parse_shard() {
+        assert_eq!("2/8".parse::<Shard>(), Ok(Shard { index: 2, count: 8 }));
+        assert_eq!("1/1".parse::<Shard>(), Ok(Shard { index: 1, count: 1 }));
+        assert!("0/8".parse::<Shard>().is_err());
+        assert!("9/8".parse::<Shard>().is_err());
+        assert!("2".parse::<Shard>().is_err());
+        assert!("a/b".parse::<Shard>().is_err());
+    }
+
+    #[test]
+    fn shards_partition_files() {
+        let paths = (0..100).map(|i| format!("src/dir{}/file{i}.js", i % 7)).collect::<Vec<_>>();
+        let shards = (1..=4).map(|index| Shard { index, count: 4 }).collect::<Vec<_>>();
+        for path in &paths {
+            assert_eq!(shards.iter().filter(|shard| shard.contains(path)).count(), 1, "{path}");
+        }
+        // every shard gets some of the files
+        for shard in &shards {
+            assert!(paths.iter().any(|path| shard.contains(path)));
+        }
+        // the assignment does not change between runs
+        assert!(Shard { index: 1, count: 1 }.contains("src/index.js"));
+        assert_eq!(
+            (1..=4).find(|&index| Shard { index, count: 4 }.contains("src/index.js")),
+            Some(4)
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use oxc_allocator::AllocatorPoolStats;
+use oxc_linter::FileStatus;
+
+/// Statistics of the memory arenas used to parse and lint files, printed by `--stats`.
+pub fn render_allocator_pool_stats(stats: &AllocatorPoolStats) -> String {
+    #[expect(clippy::cast_precision_loss)]
+    let largest_file_mib = stats.peak_bytes as f64 / (1024.0 * 1024.0);
+    let mut output = String::from("\nMemory arenas:\n");
+    let _ = writeln!(output, "{:<18}{}", "Created", stats.allocators_created);
+    let _ = writeln!(output, "{:<18}{}", "Reused", stats.resets);
+    let _ = writeln!(output, "{:<18}{}", "Freed when large", stats.shrinks);
+    // Memory used to parse and lint the largest file, not the total memory used by all arenas
+    let _ = writeln!(output, "{:<18}{largest_file_mib:.1} MiB", "Largest file");
+    let _ = writeln!(output, "{:<18}{}", "Contention waits", stats.contention_waits);
+    output
+}
+
+/// Allocations of the global allocator during a run, see [`crate::allocator::allocation_stats`].
+#[derive(Debug, Clone, Copy)]
+pub struct AllocationStats {
+    /// Name of the allocator which new memory is allocated with.
+    pub backend: &'static str,
+    pub allocations: usize,
+    pub allocated_bytes: usize,
+    /// Most memory allocated during the run which was not yet freed.
+    pub peak_live_bytes: usize,
+}
+
+/// Statistics of the global allocator, printed by `--stats`.
+pub fn render_allocation_stats(stats: &AllocationStats) -> String {
+    #[expect(clippy::cast_precision_loss)]
+    let mib = |bytes: usize| bytes as f64 / (1024.0 * 1024.0);
+    let mut output = String::from("\nAllocations:\n");
+    let _ = writeln!(output, "{:<18}{}", "Allocator", stats.backend);
+    let _ = writeln!(output, "{:<18}{}", "Count", stats.allocations);
+    let _ = writeln!(output, "{:<18}{:.1} MiB", "Allocated", mib(stats.allocated_bytes));
+    let _ = writeln!(output, "{:<18}{:.1} MiB", "Peak in use", mib(stats.peak_live_bytes));
+    output
+}
+
+/// Memory of the process, as tracked by mimalloc, see [`crate::allocator::mimalloc_stats`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MimallocStats {
+    pub current_rss: usize,
+    pub peak_rss: usize,
+    /// Memory reserved by mimalloc which is backed by the OS.
+    pub current_commit: usize,
+    pub peak_commit: usize,
+    pub page_faults: usize,
+}
+
+/// Statistics of mimalloc, printed by `--stats`.
+pub fn render_mimalloc_stats(stats: &MimallocStats) -> String {
+    #[expect(clippy::cast_precision_loss)]
+    let mib = |bytes: usize| bytes as f64 / (1024.0 * 1024.0);
+    let mut output = String::from("\nMimalloc:\n");
+    let _ = writeln!(output, "{:<18}{:.1} MiB", "Committed", mib(stats.current_commit));
+    let _ = writeln!(output, "{:<18}{:.1} MiB", "Peak committed", mib(stats.peak_commit));
+    let _ = writeln!(output, "{:<18}{:.1} MiB", "Resident", mib(stats.current_rss));
+    let _ = writeln!(output, "{:<18}{:.1} MiB", "Peak resident", mib(stats.peak_rss));
+    let _ = writeln!(output, "{:<18}{}", "Page faults", stats.page_faults);
+    output
+}
+
+/// Number of files with each [`FileStatus`], printed by `--stats`.
+pub fn render_file_status_stats(file_statuses: &BTreeMap<String, FileStatus>) -> String {
+    let mut output = String::from("\nFiles:\n");
+    for status in FileStatus::ALL {
+        let count = file_statuses.values().filter(|file_status| **file_status == status).count();
+        let _ = writeln!(output, "{:<21}{count}", status.as_str());
+    }
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use oxc_allocator::AllocatorPoolStats;
+    use oxc_linter::FileStatus;
+
+    use super::{
+        AllocationStats, MimallocStats, render_allocation_stats, render_allocator_pool_stats,
+        render_file_status_stats, render_mimalloc_stats,
+    };
+
+    #[test]
+    fn render() {
+        let stats = AllocatorPoolStats {
+            allocators_created: 4,
+            resets: 120,
+            shrinks: 1,
+            peak_bytes: 3 * 1024 * 1024 / 2,
+            contention_waits: 2,
+        };
+        assert_eq!(
+            render_allocator_pool_stats(&stats),
+            "\nMemory arenas:\nCreated           4\nReused            120\nFreed when large  1\nLargest file      1.5 MiB\nContention waits  2\n"
+        );
+    }
+
+    #[test]
+    fn render_allocations() {
+        let stats = AllocationStats {
+            backend: "system",
+            allocations: 1500,
+            allocated_bytes: 5 * 1024 * 1024,
+            peak_live_bytes: 3 * 1024 * 1024 / 2,
+        };
+        assert_eq!(
+            render_allocation_stats(&stats),
+            "\nAllocations:\nAllocator         system\nCount             1500\nAllocated         5.0 MiB\nPeak in use       1.5 MiB\n"
+        );
+    }
+
+    #[test]
+    fn render_mimalloc() {
+        let stats = MimallocStats {
+            current_rss: 40 * 1024 * 1024,
+            peak_rss: 64 * 1024 * 1024,
+            current_commit: 32 * 1024 * 1024,
+            peak_commit: 48 * 1024 * 1024,
+            page_faults: 3,
+        };
+        assert_eq!(
+            render_mimalloc_stats(&stats),
+            "\nMimalloc:\nCommitted         32.0 MiB\nPeak committed    48.0 MiB\nResident          40.0 MiB\nPeak resident     64.0 MiB\nPage faults       3\n"
+        );
+    }
+
+    #[test]
+    fn render_file_statuses() {
+        let file_statuses = BTreeMap::from([
+            ("a.js".to_string(), FileStatus::Linted),
+            ("b.js".to_string(), FileStatus::Linted),
+            ("c.js".to_string(), FileStatus::ParseFailed),
+            ("d.md".to_string(), FileStatus::SkippedUnsupported),
+        ]);
+        assert_eq!(
+            render_file_status_stats(&file_statuses),
+            "\nFiles:\nlinted               2\nskipped-ignored      0\nskipped-unsupported  1\nparse-failed         1\nfixed                0\n"
+        );
+    }
+}
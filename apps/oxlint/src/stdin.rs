@@ -0,0 +1,67 @@
+use std::{
+    io::{self, Read},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use oxc_allocator::Allocator;
+use oxc_linter::{RuntimeFileSystem, read_to_arena_str};
+
+/// File system used when linting source text piped through stdin.
+///
+/// Reads of `path` are served from the in-memory `source_text`, and writes to `path` (from `--fix`)
+/// are captured in memory instead of touching the disk, so the fixed source can be printed afterwards.
+/// All other paths (e.g. dependencies resolved by the import plugin) are read from disk as usual.
+pub struct StdinFileSystem {
+    path: PathBuf,
+    source_text: String,
+    fixed_source_text: Mutex<Option<String>>,
+}
+
+impl StdinFileSystem {
+    pub fn new(path: PathBuf, source_text: String) -> Self {
+        Self { path, source_text, fixed_source_text: Mutex::new(None) }
+    }
+
+    /// Read all of stdin into a `String`.
+    ///
+    /// # Errors
+    /// When stdin cannot be read or is not valid UTF-8.
+    pub fn read_stdin() -> io::Result<String> {
+        let mut source_text = String::new();
+        io::stdin().lock().read_to_string(&mut source_text)?;
+        Ok(source_text)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the fixed source text if any fix was applied, otherwise the original source text.
+    pub fn into_output(self) -> String {
+        self.fixed_source_text.into_inner().unwrap().unwrap_or(self.source_text)
+    }
+}
+
+impl RuntimeFileSystem for StdinFileSystem {
+    fn read_to_arena_str<'a>(
+        &'a self,
+        path: &Path,
+        allocator: &'a Allocator,
+    ) -> Result<&'a str, std::io::Error> {
+        if path == self.path {
+            return Ok(&self.source_text);
+        }
+
+        read_to_arena_str(path, allocator)
+    }
+
+    fn write_file(&self, path: &Path, content: &str) -> Result<(), std::io::Error> {
+        if path == self.path {
+            *self.fixed_source_text.lock().unwrap() = Some(content.to_string());
+            return Ok(());
+        }
+
+        std::fs::write(path, content)
+    }
+}
@@ -7,6 +7,8 @@ use crate::cli::{CliRunner, lint_command};
 
 pub struct Tester {
     cwd: PathBuf,
+    /// Text read from stdin by the snapshot tests
+    stdin: Option<String>,
 }
 
 impl Tester {
@@ -18,7 +20,7 @@ impl Tester {
         // do not unwrap because we can set it only one time.
         let _ = rayon::ThreadPoolBuilder::new().num_threads(1).build_global();
 
-        Self { cwd }
+        Self { cwd, stdin: None }
     }
 
     pub fn with_cwd(mut self, cwd: PathBuf) -> Self {
@@ -26,13 +28,20 @@ impl Tester {
         self
     }
 
+    pub fn with_stdin(mut self, stdin: &str) -> Self {
+        self.stdin = Some(stdin.to_string());
+        self
+    }
+
     pub fn test(&self, args: &[&str]) {
         let mut new_args = vec!["--silent"];
         new_args.extend(args);
 
         let options = lint_command().run_inner(new_args.as_slice()).unwrap();
         let mut output = Vec::new();
-        let _ = CliRunner::new(options, None).with_cwd(self.cwd.clone()).run(&mut output);
+        let _ = CliRunner::new(options, None)
+            .with_cwd(self.cwd.clone())
+            .run(&mut output, &mut Vec::new());
     }
 
     pub fn test_output(&self, args: &[&str]) -> String {
@@ -41,11 +50,68 @@ impl Tester {
 
         let options = lint_command().run_inner(new_args.as_slice()).unwrap();
         let mut output = Vec::new();
-        let _ = CliRunner::new(options, None).with_cwd(self.cwd.clone()).run(&mut output);
+        let _ = CliRunner::new(options, None)
+            .with_cwd(self.cwd.clone())
+            .run(&mut output, &mut Vec::new());
+
+        String::from_utf8(output).unwrap()
+    }
+
+    /// The output of running with `args`, including the diagnostics, unlike
+    /// [`test_output`](Tester::test_output).
+    pub fn test_diagnostics_output(&self, args: &[&str]) -> String {
+        let options = lint_command().run_inner(args).unwrap();
+        let mut output = Vec::new();
+        let _ = CliRunner::new(options, None)
+            .with_cwd(self.cwd.clone())
+            .run(&mut output, &mut Vec::new());
 
         String::from_utf8(output).unwrap()
     }
 
+    pub fn test_output_with_stdin(&self, args: &[&str], stdin: &str) -> String {
+        let mut new_args = vec!["--silent"];
+        new_args.extend(args);
+
+        let options = lint_command().run_inner(new_args.as_slice()).unwrap();
+        let mut output = Vec::new();
+        let _ = CliRunner::new(options, None)
+            .with_cwd(self.cwd.clone())
+            .with_stdin(stdin.to_string())
+            .run(&mut output, &mut Vec::new());
+
+        String::from_utf8(output).unwrap()
+    }
+
+    /// What running with `args` writes to stderr.
+    pub fn test_stderr(&self, args: &[&str]) -> String {
+        let mut new_args = vec!["--silent"];
+        new_args.extend(args);
+
+        let options = lint_command().run_inner(new_args.as_slice()).unwrap();
+        let mut stderr = Vec::new();
+        let _ = CliRunner::new(options, None)
+            .with_cwd(self.cwd.clone())
+            .run(&mut Vec::new(), &mut stderr);
+
+        String::from_utf8(stderr).unwrap()
+    }
+
+    /// What running with `args` and `stdin` writes to stderr.
+    pub fn test_stderr_with_stdin(&self, args: &[&str], stdin: &str) -> String {
+        let mut new_args = vec!["--silent"];
+        new_args.extend(args);
+
+        let options = lint_command().run_inner(new_args.as_slice()).unwrap();
+        let mut stderr = Vec::new();
+        let _ = CliRunner::new(options, None)
+            .with_cwd(self.cwd.clone())
+            .with_stdin(stdin.to_string())
+            .run(&mut Vec::new(), &mut stderr);
+
+        String::from_utf8(stderr).unwrap()
+    }
+
     pub fn test_fix(file: &str, before: &str, after: &str) {
         use std::fs;
         #[expect(clippy::disallowed_methods)]
@@ -89,7 +155,16 @@ impl Tester {
                 format!("working directory: {}\n", relative_dir.to_str().unwrap()).as_bytes(),
             );
             output.extend_from_slice(b"----------\n");
-            let result = CliRunner::new(options, None).with_cwd(self.cwd.clone()).run(&mut output);
+            let mut runner = CliRunner::new(options, None).with_cwd(self.cwd.clone());
+            if let Some(stdin) = &self.stdin {
+                runner = runner.with_stdin(stdin.clone());
+            }
+            let mut stderr = Vec::new();
+            let result = runner.run(&mut output, &mut stderr);
+            if !stderr.is_empty() {
+                output.extend_from_slice(b"---------- stderr\n");
+                output.extend_from_slice(&stderr);
+            }
 
             output.extend_from_slice(b"----------\n");
             output.extend_from_slice(format!("CLI result: {result:?}\n").as_bytes());
@@ -119,9 +194,10 @@ impl Tester {
 
         let snapshot_file_name = format!("{}_{}", relative_dir.to_str().unwrap(), full_args_list);
 
-        // windows can not handle filenames with *
+        // windows can not handle filenames with * or ", e.g. of JSON arguments
         // allow replace instead of cow_replace. It only test
-        let snapshot_file_name = snapshot_file_name.cow_replace('*', "_").to_string();
+        let snapshot_file_name =
+            snapshot_file_name.cow_replace(|c| c == '*' || c == '"', "_").to_string();
         settings.bind(|| {
             insta::assert_snapshot!(snapshot_file_name, output_string);
         });
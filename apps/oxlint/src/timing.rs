@@ -0,0 +1,188 @@
+use std::{fmt::Write, path::Path};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_linter::{LintPlugins, RuleTiming, rules::RULES};
+
+/// Number of rules listed by `--timing`, like the `TIMING` environment variable of ESLint.
+const LISTED_RULES: usize = 10;
+
+/// Maximum milliseconds per 1000 AST nodes of each rule, read from the file of `--timing-budget`,
+/// e.g. `{ "eslint/no-unused-vars": 0.5, "import/no-cycle": 2 }`.
+///
+/// Rules are named like in the configuration, e.g. `jsx-a11y/alt-text`, rules of the `eslint`
+/// plugin may omit the plugin.
+#[derive(Debug, Default)]
+pub struct TimingBudget(FxHashMap<String, f64>);
+
+/// A rule slower than its budget allows.
+#[derive(Debug)]
+pub struct TimingRegression {
+    pub rule: String,
+    pub budget: f64,
+    pub measured: f64,
+}
+
+impl TimingBudget {
+    /// Read the budget at `path`, relative to `cwd`. All rules must be built-in rules.
+    pub fn from_file(cwd: &Path, path: &Path) -> Result<Self, String> {
+        let json = oxc_linter::read_to_string(&cwd.join(path))
+            .map_err(|err| format!("Failed to read timing budget {}: {err}", path.display()))?;
+        let budget = serde_json::from_str::<FxHashMap<String, f64>>(&json)
+            .map_err(|err| format!("Failed to parse timing budget {}: {err}", path.display()))?;
+        let known_rules = RULES
+            .iter()
+            .map(|rule| display_rule_name(rule.plugin_name(), rule.name()))
+            .collect::<FxHashSet<_>>();
+        budget
+            .into_iter()
+            .map(|(rule, ms)| {
+                let name = match rule.split_once('/') {
+                    Some((plugin, name)) => display_rule_name(plugin, name),
+                    None => display_rule_name("eslint", &rule),
+                };
+                if known_rules.contains(&name) {
+                    Ok((name, ms))
+                } else {
+                    Err(format!("Unknown rule `{rule}` in timing budget {}", path.display()))
+                }
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
+    /// Rules whose time per 1000 nodes exceeds their budget by more than `tolerance` percent,
+    /// sorted by name. Rules which did not run are not checked.
+    pub fn regressions(
+        &self,
+        timings: &FxHashMap<(&'static str, &'static str), RuleTiming>,
+        tolerance: f64,
+    ) -> Vec<TimingRegression> {
+        let mut regressions = timings
+            .iter()
+            .filter_map(|((plugin, rule), timing)| {
+                let rule = display_rule_name(plugin, rule);
+                let budget = *self.0.get(&rule)?;
+                let measured = timing.ms_per_1k_nodes();
+                (measured > budget * (1.0 + tolerance / 100.0)).then_some(TimingRegression {
+                    rule,
+                    budget,
+                    measured,
+                })
+            })
+            .collect::<Vec<_>>();
+        regressions.sort_unstable_by(|a, b| a.rule.cmp(&b.rule));
+        regressions
+    }
+}
+
+/// The table of `--timing`, listing the slowest rules.
+pub fn render_timings(timings: &FxHashMap<(&'static str, &'static str), RuleTiming>) -> String {
+    let mut timings = timings
+        .iter()
+        .map(|((plugin, rule), timing)| (display_rule_name(plugin, rule), timing))
+        .collect::<Vec<_>>();
+    timings.sort_unstable_by(|(a_rule, a), (b_rule, b)| {
+        b.duration.cmp(&a.duration).then_with(|| a_rule.cmp(b_rule))
+    });
+    let total = timings.iter().map(|(_, timing)| timing.duration.as_secs_f64()).sum::<f64>();
+    let timings = &timings[..timings.len().min(LISTED_RULES)];
+
+    let width = timings.iter().map(|(rule, _)| rule.len()).max().unwrap_or(0).max("Rule".len());
+    let mut output = String::from("\nRule timings:\n");
+    let _ = writeln!(
+        output,
+        "{:<width$}  {:>10}  {:>12}  {:>8}",
+        "Rule", "Time (ms)", "ms/1k nodes", "Relative"
+    );
+    for (rule, timing) in timings {
+        let seconds = timing.duration.as_secs_f64();
+        let relative = if total > 0.0 { seconds / total * 100.0 } else { 0.0 };
+        let _ = writeln!(
+            output,
+            "{rule:<width$}  {:>10.3}  {:>12.3}  {:>7.1}%",
+            seconds * 1000.0,
+            timing.ms_per_1k_nodes(),
+            relative,
+        );
+    }
+    output
+}
+
+/// The name of a rule as in the configuration, e.g. `jsx-a11y/alt-text` for the rule `alt-text` of
+/// the `jsx_a11y` plugin.
+fn display_rule_name(plugin: &str, rule: &str) -> String {
+    let plugin = match LintPlugins::try_from(plugin).map(<&str>::from) {
+        Ok(name) if !name.is_empty() => name,
+        _ => plugin,
+    };
+    format!("{plugin}/{rule}")
+}
+
+/// The report of rules exceeding their `--timing-budget`.
+pub fn render_regressions(regressions: &[TimingRegression], tolerance: f64) -> String {
+    let mut output = format!("\nRules exceeding their timing budget by more than {tolerance}%:\n");
+    for TimingRegression { rule, budget, measured } in regressions {
+        let _ = writeln!(output, "  {rule}: {measured:.3} ms/1k nodes (budget {budget} ms)");
+    }
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use rustc_hash::FxHashMap;
+
+    use oxc_linter::RuleTiming;
+
+    use super::{TimingBudget, display_rule_name};
+
+    #[test]
+    fn regressions() {
+        let budget = TimingBudget(FxHashMap::from_iter([
+            ("eslint/no-debugger".to_string(), 1.0),
+            ("import/no-cycle".to_string(), 2.0),
+            ("eslint/no-unused-vars".to_string(), 1.0),
+        ]));
+        let timings = FxHashMap::from_iter([
+            // 1.05 ms per 1k nodes, within the tolerance
+            (
+                ("eslint", "no-debugger"),
+                RuleTiming { duration: Duration::from_micros(2100), nodes: 2000 },
+            ),
+            // 3 ms per 1k nodes
+            (
+                ("import", "no-cycle"),
+                RuleTiming { duration: Duration::from_millis(3), nodes: 1000 },
+            ),
+            // no budget
+            (("eslint", "eqeqeq"), RuleTiming { duration: Duration::from_secs(1), nodes: 1 }),
+        ]);
+
+        let rules = |tolerance| {
+            budget
+                .regressions(&timings, tolerance)
+                .into_iter()
+                .map(|regression| regression.rule)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(rules(10.0), vec!["import/no-cycle"]);
+        assert_eq!(rules(0.0), vec!["eslint/no-debugger", "import/no-cycle"]);
+        assert!(rules(50.0).is_empty());
+    }
+
+    #[test]
+    fn rule_names() {
+        assert_eq!(display_rule_name("eslint", "no-debugger"), "eslint/no-debugger");
+        assert_eq!(display_rule_name("jsx_a11y", "alt-text"), "jsx-a11y/alt-text");
+        assert_eq!(
+            display_rule_name("react_perf", "jsx-no-new-array-as-prop"),
+            "react-perf/jsx-no-new-array-as-prop"
+        );
+        assert_eq!(
+            display_rule_name("@typescript-eslint", "no-explicit-any"),
+            "typescript/no-explicit-any"
+        );
+    }
+}
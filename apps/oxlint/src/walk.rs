@@ -1,16 +1,45 @@
-use std::{ffi::OsStr, path::PathBuf, sync::Arc, sync::mpsc};
+use std::{
+    borrow::Cow,
+    ffi::OsStr,
+    path::{Path, PathBuf},
+    sync::Arc,
+    sync::mpsc,
+};
 
-use ignore::{DirEntry, overrides::Override};
-use oxc_linter::LINTABLE_EXTENSIONS;
+use ignore::{DirEntry, gitignore::Gitignore, overrides::Override};
+use oxc_diagnostics::NormalizedPath;
+use oxc_linter::{LINTABLE_EXTENSIONS, Oxlintrc, loader::JSON_EXTENSIONS};
+use rustc_hash::FxHashMap;
 
 use crate::cli::IgnoreOptions;
 
+/// Ignore file read in every visited directory, like nested `.gitignore` files.
+pub const OXLINTIGNORE_FILENAME: &str = ".oxlintignore";
+
 #[derive(Debug, Clone)]
-pub struct Extensions(pub Vec<&'static str>);
+pub struct Extensions(pub Vec<Cow<'static, str>>);
 
 impl Default for Extensions {
     fn default() -> Self {
-        Self(LINTABLE_EXTENSIONS.to_vec())
+        Self(LINTABLE_EXTENSIONS.iter().copied().map(Cow::Borrowed).collect())
+    }
+}
+
+impl Extensions {
+    /// Lintable extensions, plus the extensions of JSON files if `oxlintrc` enables the `json`
+    /// plugin, and of files which are linted with a processor of a JS plugin, see
+    /// [`Oxlintrc::processed_extensions`].
+    pub fn for_config(oxlintrc: &Oxlintrc) -> Self {
+        let mut extensions = Self::default();
+        if oxlintrc.lints_json() {
+            extensions.0.extend(JSON_EXTENSIONS.iter().copied().map(Cow::Borrowed));
+        }
+        for extension in oxlintrc.processed_extensions() {
+            if !extensions.0.iter().any(|e| *e == extension) {
+                extensions.0.push(Cow::Owned(extension));
+            }
+        }
+        extensions
     }
 }
 
@@ -18,11 +47,14 @@ pub struct Walk {
     inner: ignore::WalkParallel,
     /// The file extensions to include during the traversal.
     extensions: Extensions,
+    /// Only include files matching these globs, see [`Walk::with_globs`].
+    globs: Option<Override>,
 }
 
 struct WalkBuilder {
     sender: mpsc::Sender<Vec<Arc<OsStr>>>,
     extensions: Extensions,
+    globs: Option<Override>,
 }
 
 impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
@@ -31,6 +63,7 @@ impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
             paths: vec![],
             sender: self.sender.clone(),
             extensions: self.extensions.clone(),
+            globs: self.globs.clone(),
         })
     }
 }
@@ -39,6 +72,7 @@ struct WalkCollector {
     paths: Vec<Arc<OsStr>>,
     sender: mpsc::Sender<Vec<Arc<OsStr>>>,
     extensions: Extensions,
+    globs: Option<Override>,
 }
 
 impl Drop for WalkCollector {
@@ -57,8 +91,13 @@ impl ignore::ParallelVisitor for WalkCollector {
                 if entry.file_type().is_some_and(|ty| ty.is_dir()) && entry.file_name() == ".git" {
                     return ignore::WalkState::Skip;
                 }
-                if Walk::is_wanted_entry(&entry, &self.extensions) {
-                    self.paths.push(entry.path().as_os_str().into());
+                if Walk::is_wanted_entry(&entry, &self.extensions)
+                    && self
+                        .globs
+                        .as_ref()
+                        .is_none_or(|globs| globs.matched(entry.path(), false).is_whitelist())
+                {
+                    self.paths.push(Walk::normalize_path(entry.path()));
                 }
                 ignore::WalkState::Continue
             }
@@ -90,6 +129,9 @@ impl Walk {
         }
 
         if !options.no_ignore {
+            // Later names take precedence, so `--ignore-path` overrides `.oxlintignore`
+            // files of the same directory.
+            inner.add_custom_ignore_filename(OXLINTIGNORE_FILENAME);
             inner.add_custom_ignore_filename(&options.ignore_path);
 
             if let Some(override_builder) = override_builder {
@@ -99,45 +141,150 @@ impl Walk {
 
         let inner =
             inner.ignore(false).git_global(false).follow_links(true).hidden(false).build_parallel();
-        Self { inner, extensions: Extensions::default() }
+        Self { inner, extensions: Extensions::default(), globs: None }
     }
 
     pub fn paths(self) -> Vec<Arc<OsStr>> {
         let (sender, receiver) = mpsc::channel::<Vec<Arc<OsStr>>>();
-        let mut builder = WalkBuilder { sender, extensions: self.extensions };
+        let mut builder = WalkBuilder { sender, extensions: self.extensions, globs: self.globs };
         self.inner.visit(&mut builder);
         drop(builder);
         receiver.into_iter().flatten().collect()
     }
 
-    #[cfg_attr(not(test), expect(dead_code))]
     pub fn with_extensions(mut self, extensions: Extensions) -> Self {
         self.extensions = extensions;
         self
     }
 
+    /// Only include files which are whitelisted by `globs`, e.g. the globs of a path set of the
+    /// configuration file.
+    pub fn with_globs(mut self, globs: Override) -> Self {
+        self.globs = Some(globs);
+        self
+    }
+
     fn is_wanted_entry(dir_entry: &DirEntry, extensions: &Extensions) -> bool {
         let Some(file_type) = dir_entry.file_type() else { return false };
         if file_type.is_dir() {
             return false;
         }
-        let Some(file_name) = dir_entry.path().file_name() else { return false };
+        Self::is_wanted_file(dir_entry.path(), extensions)
+    }
+
+    /// Whether the file at `path` is linted when it is walked: it has one of `extensions`,
+    /// and is not minified.
+    pub fn is_wanted_file(path: &Path, extensions: &Extensions) -> bool {
+        let Some(file_name) = path.file_name() else { return false };
         if [".min.", "-min.", "_min."].iter().any(|e| file_name.to_string_lossy().contains(e)) {
             return false;
         }
-        let Some(extension) = dir_entry.path().extension() else { return false };
+        let Some(extension) = path.extension() else { return false };
         let extension = extension.to_string_lossy();
-        extensions.0.contains(&extension.as_ref())
+        extensions.0.contains(&extension)
+    }
+
+    /// Use `/` as path separator on every platform, so paths are displayed and matched against
+    /// globs the same way. Paths which are not valid UTF-8 are kept as is.
+    fn normalize_path(path: &Path) -> Arc<OsStr> {
+        if cfg!(windows) && path.to_str().is_some() {
+            OsStr::new(NormalizedPath::new(path).as_str()).into()
+        } else {
+            path.as_os_str().into()
+        }
+    }
+}
+
+/// Ignore files and `--ignore-pattern`s applied to paths which are not walked, like explicitly
+/// passed files or the files changed by `--diff` and `--staged`, as the walker would apply them.
+pub struct PathIgnores {
+    overrides: Override,
+    /// The file of `--ignore-path`.
+    ignore_file: Gitignore,
+    nested_ignore_files: NestedIgnoreFiles,
+    /// Nested `.gitignore` files, see [`PathIgnores::with_gitignore`].
+    nested_gitignore_files: Option<NestedIgnoreFiles>,
+    cwd: PathBuf,
+}
+
+impl PathIgnores {
+    /// `ignore_path` is the absolute path of the file of `--ignore-path`.
+    pub fn new(overrides: Override, ignore_path: &Path, cwd: &Path) -> Self {
+        Self {
+            overrides,
+            ignore_file: Gitignore::new(ignore_path).0,
+            nested_ignore_files: NestedIgnoreFiles::new(OXLINTIGNORE_FILENAME, cwd),
+            nested_gitignore_files: None,
+            cwd: cwd.to_path_buf(),
+        }
+    }
+
+    /// Also apply the `.gitignore` files of the ancestors, which the walker applies to the files
+    /// it visits, but not to explicitly passed paths.
+    #[must_use]
+    pub fn with_gitignore(mut self) -> Self {
+        self.nested_gitignore_files = Some(NestedIgnoreFiles::new(".gitignore", &self.cwd));
+        self
+    }
+
+    /// Whether the absolute `path` is ignored.
+    pub fn is_ignored(&mut self, path: &Path) -> bool {
+        self.overrides.matched(path, false).is_ignore()
+            || self.ignore_file.matched(path, false).is_ignore()
+            || self.nested_ignore_files.is_ignored(path)
+            || self.nested_gitignore_files.as_mut().is_some_and(|files| files.is_ignored(path))
+    }
+}
+
+/// Ignore files of the ancestors of explicitly passed paths, which the walker does not apply to
+/// the paths it is given.
+///
+/// Ancestors are visited up to the working directory, or the root of the git repository when
+/// the path is outside of the working directory.
+pub struct NestedIgnoreFiles {
+    file_name: &'static str,
+    cwd: PathBuf,
+    /// The ignore file of each visited directory, and whether the directory is the root of a git
+    /// repository.
+    cache: FxHashMap<PathBuf, (Gitignore, bool)>,
+}
+
+impl NestedIgnoreFiles {
+    pub fn new(file_name: &'static str, cwd: &Path) -> Self {
+        Self { file_name, cwd: cwd.to_path_buf(), cache: FxHashMap::default() }
+    }
+
+    /// Whether the absolute `path` is ignored by the ignore file of one of its ancestors.
+    /// The closest file with a matching pattern decides, so `!` patterns of a subdirectory can
+    /// re-include files ignored by a parent directory.
+    pub fn is_ignored(&mut self, path: &Path) -> bool {
+        for dir in path.ancestors().skip(1) {
+            let (ignore, is_git_root) = self.cache.entry(dir.to_path_buf()).or_insert_with(|| {
+                (Gitignore::new(dir.join(self.file_name)).0, dir.join(".git").exists())
+            });
+            let matched = ignore.matched_path_or_any_parents(path, false);
+            if matched.is_ignore() {
+                return true;
+            }
+            if matched.is_whitelist() {
+                return false;
+            }
+            if *is_git_root || dir == self.cwd {
+                break;
+            }
+        }
+        false
     }
 }
 
 #[cfg(test)]
 mod test {
-    use std::{env, ffi::OsString, path::Path};
+    use std::{env, ffi::OsString};
 
     use ignore::overrides::OverrideBuilder;
+    use oxc_diagnostics::NormalizedPath;
 
-    use super::{Extensions, Walk};
+    use super::{Extensions, NestedIgnoreFiles, OXLINTIGNORE_FILENAME, Walk};
     use crate::cli::IgnoreOptions;
 
     #[test]
@@ -148,20 +295,56 @@ mod test {
             no_ignore: false,
             ignore_path: OsString::from(".gitignore"),
             ignore_pattern: vec![],
+            ignore_pattern_file: None,
         };
 
         let override_builder = OverrideBuilder::new("/").build().unwrap();
 
         let mut paths = Walk::new(&fixtures, &ignore_options, Some(override_builder))
-            .with_extensions(Extensions(["js", "vue"].to_vec()))
+            .with_extensions(Extensions(vec!["js".into(), "vue".into()]))
             .paths()
             .into_iter()
-            .map(|path| {
-                Path::new(&path).strip_prefix(&fixture).unwrap().to_string_lossy().to_string()
-            })
+            .map(|path| NormalizedPath::relative_to(&*path, &fixture).to_string())
             .collect::<Vec<_>>();
         paths.sort();
 
         assert_eq!(paths, vec!["bar.vue", "foo.js"]);
     }
+
+    #[test]
+    fn test_walk_with_nested_oxlintignore() {
+        let fixture = env::current_dir().unwrap().join("fixtures/nested_oxlintignore");
+        let fixtures = vec![fixture.clone()];
+        let ignore_options = IgnoreOptions {
+            no_ignore: false,
+            ignore_path: OsString::from(".eslintignore"),
+            ignore_pattern: vec![],
+            ignore_pattern_file: None,
+        };
+
+        let mut paths = Walk::new(&fixtures, &ignore_options, None)
+            .paths()
+            .into_iter()
+            .map(|path| NormalizedPath::relative_to(&*path, &fixture).to_string())
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        assert_eq!(paths, vec!["main.js", "sub/config-ignored.js", "sub/legacy.js", "sub/main.js"]);
+    }
+
+    #[test]
+    fn test_nested_ignore_files() {
+        let fixture = env::current_dir().unwrap().join("fixtures/nested_oxlintignore");
+        let mut nested_ignore_files = NestedIgnoreFiles::new(OXLINTIGNORE_FILENAME, &fixture);
+
+        assert!(!nested_ignore_files.is_ignored(&fixture.join("main.js")));
+        assert!(nested_ignore_files.is_ignored(&fixture.join("legacy.js")));
+        assert!(nested_ignore_files.is_ignored(&fixture.join("sub/generated.js")));
+        assert!(!nested_ignore_files.is_ignored(&fixture.join("sub/legacy.js")));
+
+        // Ignore files above the working directory are not applied to the paths inside of it.
+        let cwd = fixture.join("other");
+        let mut nested_ignore_files = NestedIgnoreFiles::new(OXLINTIGNORE_FILENAME, &cwd);
+        assert!(!nested_ignore_files.is_ignored(&cwd.join("legacy.js")));
+    }
 }
@@ -0,0 +1,39 @@
+import type { Plugin, ProcessorSection } from "#oxlint";
+
+const FENCE_START = /^```(js|jsx|ts|tsx)\n/gm;
+
+const plugin: Plugin = {
+  meta: {
+    name: "markdown-plugin",
+  },
+  rules: {
+    "no-debugger": {
+      create(context) {
+        return {
+          DebuggerStatement(debuggerStatement) {
+            context.report({
+              message: "Unexpected Debugger Statement",
+              node: debuggerStatement,
+            });
+          },
+        };
+      },
+    },
+  },
+  processors: {
+    markdown: {
+      preprocess(text) {
+        const sections: ProcessorSection[] = [];
+        for (const match of text.matchAll(FENCE_START)) {
+          const start = match.index + match[0].length;
+          const end = text.indexOf("```", start);
+          if (end === -1) break;
+          sections.push({ start, end, extension: match[1] });
+        }
+        return sections;
+      },
+    },
+  },
+};
+
+export default plugin;
@@ -0,0 +1,6 @@
+const local = Array.from([]);
+local;
+undeclared;
+function f(param) {
+  return param + Math.max(1, 2);
+}
@@ -0,0 +1,26 @@
+import type { Plugin, Rule } from "#oxlint";
+
+// Only calls `isGlobalReference`, without using the scope manager first, so `isGlobalReference`
+// requests the start offsets of unresolved references from Rust. Identifiers which resolve to
+// a declaration, or are not references, are not global references, without analysing scopes in JS.
+const rule: Rule = {
+  create(context) {
+    const { sourceCode } = context;
+
+    return {
+      Identifier(node) {
+        context.report({
+          message: `isGlobalReference(${node.name}): ${sourceCode.isGlobalReference(node)}`,
+          node,
+        });
+      },
+    };
+  },
+};
+
+const plugin: Plugin = {
+  meta: { name: "file-data-plugin" },
+  rules: { "global-references": rule },
+};
+
+export default plugin;
@@ -0,0 +1,9 @@
+const used = 1, unused = 2, marked = 3, markedFromFunction = 4;
+markUsed("marked");
+function outer(param, unusedParam) {
+  markUsed("param");
+  markUsed("markedFromFunction");
+  markUsed("notDefined");
+  return used;
+}
+markUsed("outer");
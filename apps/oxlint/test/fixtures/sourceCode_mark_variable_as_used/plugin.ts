@@ -0,0 +1,54 @@
+import type { Plugin, Rule } from "#oxlint";
+
+// Marks the variable named by the argument of each `markUsed("name")` call as used.
+const markUsedRule: Rule = {
+  create(context) {
+    return {
+      CallExpression(node) {
+        if (node.callee.type !== "Identifier" || node.callee.name !== "markUsed") return;
+        const arg = node.arguments[0];
+        if (arg.type !== "Literal" || typeof arg.value !== "string") return;
+
+        const result = context.sourceCode.markVariableAsUsed(arg.value, node);
+        context.report({
+          message: `markVariableAsUsed("${arg.value}"): ${result}`,
+          node,
+        });
+      },
+    };
+  },
+};
+
+// Reports variables which are never read, unless a rule marked them as used.
+// Like ESLint's `no-unused-vars` rule, this relies on `eslintUsed`, so runs when the whole file has been visited.
+const noUnusedVarsRule: Rule = {
+  create(context) {
+    return {
+      "Program:exit"() {
+        for (const scope of context.sourceCode.scopeManager.scopes) {
+          for (const variable of scope.variables) {
+            // Skip implicit variables, e.g. `arguments` and globals from `lib`
+            if (variable.defs.length === 0) continue;
+            if (variable.eslintUsed) continue;
+            if (variable.references.some((reference) => reference.isRead())) continue;
+
+            context.report({
+              message: `'${variable.name}' is defined but never used.`,
+              node: variable.identifiers[0],
+            });
+          }
+        }
+      },
+    };
+  },
+};
+
+const plugin: Plugin = {
+  meta: { name: "unused-vars-plugin" },
+  rules: {
+    "mark-used": markUsedRule,
+    "no-unused-vars": noUnusedVarsRule,
+  },
+};
+
+export default plugin;
@@ -15,6 +15,8 @@ use crate::{
     generated::fixed_size_constants::{BLOCK_ALIGN, BLOCK_SIZE, RAW_METADATA_SIZE},
 };
 
+use super::stats::PoolCounters;
+
 const TWO_GIB: usize = 1 << 31;
 const FOUR_GIB: usize = 1 << 32;
 
@@ -40,13 +42,14 @@ impl FixedSizeAllocatorPool {
     ///
     /// # Panics
     /// Panics if the underlying mutex is poisoned.
-    pub fn get(&self) -> Allocator {
+    pub fn get(&self, counters: &PoolCounters) -> Allocator {
         let fixed_size_allocator = {
-            let mut allocators = self.allocators.lock().unwrap();
+            let mut allocators = counters.lock(&self.allocators);
             allocators.pop()
         };
 
         let fixed_size_allocator = fixed_size_allocator.unwrap_or_else(|| {
+            counters.allocator_created();
             // Each allocator needs to have a unique ID, but the order those IDs are assigned in
             // doesn't matter, so `Ordering::Relaxed` is fine
             let id = self.next_id.fetch_add(1, Ordering::Relaxed);
@@ -75,12 +78,13 @@ impl FixedSizeAllocatorPool {
     ///
     /// # Panics
     /// Panics if the underlying mutex is poisoned.
-    pub(super) unsafe fn add(&self, allocator: Allocator) {
+    pub(super) unsafe fn add(&self, allocator: Allocator, counters: &PoolCounters) {
+        counters.reset(allocator.used_bytes());
         let mut fixed_size_allocator =
             FixedSizeAllocator { allocator: ManuallyDrop::new(allocator) };
         fixed_size_allocator.reset();
 
-        let mut allocators = self.allocators.lock().unwrap();
+        let mut allocators = counters.lock(&self.allocators);
         allocators.push(fixed_size_allocator);
     }
 }
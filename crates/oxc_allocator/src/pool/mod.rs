@@ -3,7 +3,10 @@ use std::{mem::ManuallyDrop, ops::Deref};
 use crate::Allocator;
 
 mod standard;
+mod stats;
 use standard::StandardAllocatorPool;
+pub use stats::AllocatorPoolStats;
+use stats::PoolCounters;
 
 // Fixed size allocators are only supported on 64-bit little-endian platforms at present.
 // They are only enabled if `fixed_size` Cargo feature is enabled.
@@ -27,8 +30,10 @@ pub use fixed_size::{FixedSizeAllocatorMetadata, free_fixed_size_allocator};
 ///
 /// Fixed-size allocators are only supported on 64-bit little-endian platforms at present,
 /// and require the `fixed_size` Cargo feature to be enabled.
-#[repr(transparent)]
-pub struct AllocatorPool(AllocatorPoolInner);
+pub struct AllocatorPool {
+    inner: AllocatorPoolInner,
+    counters: PoolCounters,
+}
 
 /// Inner type of [`AllocatorPool`], holding either a standard or fixed-size allocator pool.
 enum AllocatorPoolInner {
@@ -40,8 +45,13 @@ enum AllocatorPoolInner {
 impl AllocatorPool {
     /// Create a new [`AllocatorPool`] for use across the specified number of threads,
     /// which uses standard allocators.
+    ///
+    /// Allocators holding more than 64 MiB after a file was processed are dropped instead of
+    /// being returned to the pool, see [`AllocatorPool::with_max_retained_capacity`].
     pub fn new(thread_count: usize) -> AllocatorPool {
-        Self(AllocatorPoolInner::Standard(StandardAllocatorPool::new(thread_count)))
+        let counters = PoolCounters::default();
+        let pool = StandardAllocatorPool::new(thread_count, &counters);
+        Self { inner: AllocatorPoolInner::Standard(pool), counters }
     }
 
     /// Create a new [`AllocatorPool`] for use across the specified number of threads,
@@ -50,7 +60,10 @@ impl AllocatorPool {
     pub fn new_fixed_size(thread_count: usize) -> AllocatorPool {
         #[cfg(all(target_pointer_width = "64", target_endian = "little"))]
         {
-            Self(AllocatorPoolInner::FixedSize(FixedSizeAllocatorPool::new(thread_count)))
+            Self {
+                inner: AllocatorPoolInner::FixedSize(FixedSizeAllocatorPool::new(thread_count)),
+                counters: PoolCounters::default(),
+            }
         }
 
         #[cfg(not(all(target_pointer_width = "64", target_endian = "little")))]
@@ -60,6 +73,32 @@ impl AllocatorPool {
         }
     }
 
+    /// Free the memory of [`Allocator`]s which hold more than `max_retained_capacity` bytes when
+    /// they are returned to the pool, instead of keeping it for re-use.
+    ///
+    /// Bounds the memory held by idle allocators after a large file was processed.
+    /// Has no effect on fixed-size allocators, whose memory is reserved upfront.
+    #[must_use]
+    pub fn with_max_retained_capacity(mut self, max_retained_capacity: usize) -> AllocatorPool {
+        match &mut self.inner {
+            AllocatorPoolInner::Standard(pool) => {
+                pool.set_max_retained_capacity(max_retained_capacity);
+            }
+            #[cfg(all(
+                feature = "fixed_size",
+                target_pointer_width = "64",
+                target_endian = "little"
+            ))]
+            AllocatorPoolInner::FixedSize(_) => {}
+        }
+        self
+    }
+
+    /// Statistics of the use of the pool so far, e.g. to tune the number of threads.
+    pub fn stats(&self) -> AllocatorPoolStats {
+        self.counters.stats()
+    }
+
     /// Retrieve an [`Allocator`] from the pool, or create a new one if the pool is empty.
     ///
     /// Returns an [`AllocatorGuard`] that gives access to the allocator.
@@ -68,14 +107,14 @@ impl AllocatorPool {
     ///
     /// Panics if the underlying mutex is poisoned.
     pub fn get(&self) -> AllocatorGuard<'_> {
-        let allocator = match &self.0 {
-            AllocatorPoolInner::Standard(pool) => pool.get(),
+        let allocator = match &self.inner {
+            AllocatorPoolInner::Standard(pool) => pool.get(&self.counters),
             #[cfg(all(
                 feature = "fixed_size",
                 target_pointer_width = "64",
                 target_endian = "little"
             ))]
-            AllocatorPoolInner::FixedSize(pool) => pool.get(),
+            AllocatorPoolInner::FixedSize(pool) => pool.get(&self.counters),
         };
 
         AllocatorGuard { allocator: ManuallyDrop::new(allocator), pool: self }
@@ -93,14 +132,14 @@ impl AllocatorPool {
         // `AllocatorGuard`s are only created by `AllocatorPool::get`, so the `Allocator` must have
         // been created by this pool. Therefore, it is the correct type for the pool.
         unsafe {
-            match &self.0 {
-                AllocatorPoolInner::Standard(pool) => pool.add(allocator),
+            match &self.inner {
+                AllocatorPoolInner::Standard(pool) => pool.add(allocator, &self.counters),
                 #[cfg(all(
                     feature = "fixed_size",
                     target_pointer_width = "64",
                     target_endian = "little"
                 ))]
-                AllocatorPoolInner::FixedSize(pool) => pool.add(allocator),
+                AllocatorPoolInner::FixedSize(pool) => pool.add(allocator, &self.counters),
             }
         }
     }
@@ -2,45 +2,119 @@ use std::{iter, sync::Mutex};
 
 use crate::Allocator;
 
+use super::stats::PoolCounters;
+
+/// Allocators holding more memory than this after being reset are dropped by default,
+/// so that the memory used for a very large file is not kept for the rest of the run.
+const DEFAULT_MAX_RETAINED_CAPACITY: usize = 64 * 1024 * 1024;
+
 /// A thread-safe pool for reusing [`Allocator`] instances, that uses standard allocators.
 ///
 /// Unlike `FixedSizeAllocatorPool`, the `Allocator`s used in this pool are suitable for general use,
 /// but not for raw transfer.
 pub struct StandardAllocatorPool {
     allocators: Mutex<Vec<Allocator>>,
+    /// Allocators holding more memory than this are dropped instead of being returned to the pool.
+    max_retained_capacity: usize,
 }
 
 impl StandardAllocatorPool {
     /// Create a new [`StandardAllocatorPool`] for use across the specified number of threads.
-    pub fn new(thread_count: usize) -> StandardAllocatorPool {
-        let allocators = iter::repeat_with(Allocator::new).take(thread_count).collect();
-        StandardAllocatorPool { allocators: Mutex::new(allocators) }
+    pub fn new(thread_count: usize, counters: &PoolCounters) -> StandardAllocatorPool {
+        let allocators = iter::repeat_with(|| {
+            counters.allocator_created();
+            Allocator::new()
+        })
+        .take(thread_count)
+        .collect();
+        StandardAllocatorPool {
+            allocators: Mutex::new(allocators),
+            max_retained_capacity: DEFAULT_MAX_RETAINED_CAPACITY,
+        }
+    }
+
+    /// Set the maximum number of bytes an [`Allocator`] may hold when it is returned to the pool.
+    pub fn set_max_retained_capacity(&mut self, max_retained_capacity: usize) {
+        self.max_retained_capacity = max_retained_capacity;
     }
 
     /// Retrieve an [`Allocator`] from the pool, or create a new one if the pool is empty.
     ///
     /// # Panics
     /// Panics if the underlying mutex is poisoned.
-    pub fn get(&self) -> Allocator {
+    pub fn get(&self, counters: &PoolCounters) -> Allocator {
         let allocator = {
-            let mut allocators = self.allocators.lock().unwrap();
+            let mut allocators = counters.lock(&self.allocators);
             allocators.pop()
         };
-        allocator.unwrap_or_else(Allocator::new)
+        allocator.unwrap_or_else(|| {
+            counters.allocator_created();
+            Allocator::new()
+        })
     }
 
     /// Add an [`Allocator`] to the pool.
     ///
     /// The `Allocator` is reset by this method, so it's ready to be re-used.
+    /// If it still holds more memory than the maximum retained capacity, it is dropped instead,
+    /// freeing its memory.
     ///
     /// # SAFETY
     /// The `Allocator` must have been created by a `StandardAllocatorPool` (not `FixedSizeAllocatorPool`).
     ///
     /// # Panics
     /// Panics if the underlying mutex is poisoned.
-    pub(super) unsafe fn add(&self, mut allocator: Allocator) {
+    pub(super) unsafe fn add(&self, mut allocator: Allocator, counters: &PoolCounters) {
+        counters.reset(allocator.used_bytes());
         allocator.reset();
-        let mut allocators = self.allocators.lock().unwrap();
+        if allocator.capacity() > self.max_retained_capacity {
+            counters.shrunk();
+            return;
+        }
+        let mut allocators = counters.lock(&self.allocators);
         allocators.push(allocator);
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{super::stats::PoolCounters, StandardAllocatorPool};
+
+    #[test]
+    fn drops_allocators_above_max_retained_capacity() {
+        let counters = PoolCounters::default();
+        let mut pool = StandardAllocatorPool::new(0, &counters);
+        pool.set_max_retained_capacity(64 * 1024);
+
+        let small = pool.get(&counters);
+        small.alloc_str("small");
+        // SAFETY: The allocator was created by this pool
+        unsafe { pool.add(small, &counters) };
+        assert_eq!(pool.allocators.lock().unwrap().len(), 1);
+
+        let large = pool.get(&counters);
+        large.alloc_str(&"x".repeat(1024 * 1024));
+        // SAFETY: The allocator was created by this pool
+        unsafe { pool.add(large, &counters) };
+        assert!(pool.allocators.lock().unwrap().is_empty());
+
+        let stats = counters.stats();
+        assert_eq!(stats.allocators_created, 1);
+        assert_eq!(stats.resets, 2);
+        assert_eq!(stats.shrinks, 1);
+        assert!(stats.peak_bytes >= 1024 * 1024);
+    }
+
+    #[test]
+    fn drops_very_large_allocators_by_default() {
+        let counters = PoolCounters::default();
+        let pool = StandardAllocatorPool::new(1, &counters);
+
+        let allocator = pool.get(&counters);
+        allocator.alloc_str(&"x".repeat(128 * 1024 * 1024));
+        // SAFETY: The allocator was created by this pool
+        unsafe { pool.add(allocator, &counters) };
+        assert!(pool.allocators.lock().unwrap().is_empty());
+        assert_eq!(counters.stats().shrinks, 1);
+    }
+}
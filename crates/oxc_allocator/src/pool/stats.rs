@@ -0,0 +1,72 @@
+use std::sync::{
+    Mutex, MutexGuard, TryLockError,
+    atomic::{AtomicUsize, Ordering},
+};
+
+/// Statistics of an [`AllocatorPool`](super::AllocatorPool), see
+/// [`AllocatorPool::stats`](super::AllocatorPool::stats).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AllocatorPoolStats {
+    /// Number of [`Allocator`](crate::Allocator)s created by the pool.
+    pub allocators_created: usize,
+    /// Number of times an `Allocator` was reset to be re-used.
+    pub resets: usize,
+    /// Number of `Allocator`s dropped to free their memory, instead of being returned to the pool,
+    /// because they held more than the maximum retained capacity.
+    pub shrinks: usize,
+    /// Largest number of bytes used by an `Allocator` before it was reset.
+    pub peak_bytes: usize,
+    /// Number of times a thread had to wait for another thread to access the pool.
+    pub contention_waits: usize,
+}
+
+/// Counters behind [`AllocatorPoolStats`], updated from all threads using the pool.
+#[derive(Default)]
+pub(super) struct PoolCounters {
+    allocators_created: AtomicUsize,
+    resets: AtomicUsize,
+    shrinks: AtomicUsize,
+    peak_bytes: AtomicUsize,
+    contention_waits: AtomicUsize,
+}
+
+// Counters are only read once all threads are done, so `Ordering::Relaxed` is fine.
+impl PoolCounters {
+    pub fn allocator_created(&self) {
+        self.allocators_created.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn reset(&self, used_bytes: usize) {
+        self.resets.fetch_add(1, Ordering::Relaxed);
+        self.peak_bytes.fetch_max(used_bytes, Ordering::Relaxed);
+    }
+
+    pub fn shrunk(&self) {
+        self.shrinks.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Lock `mutex`, counting a contention wait if another thread holds it.
+    ///
+    /// # Panics
+    /// Panics if the mutex is poisoned.
+    pub fn lock<'m, T>(&self, mutex: &'m Mutex<T>) -> MutexGuard<'m, T> {
+        match mutex.try_lock() {
+            Ok(guard) => guard,
+            Err(TryLockError::WouldBlock) => {
+                self.contention_waits.fetch_add(1, Ordering::Relaxed);
+                mutex.lock().unwrap()
+            }
+            Err(TryLockError::Poisoned(err)) => panic!("{err}"),
+        }
+    }
+
+    pub fn stats(&self) -> AllocatorPoolStats {
+        AllocatorPoolStats {
+            allocators_created: self.allocators_created.load(Ordering::Relaxed),
+            resets: self.resets.load(Ordering::Relaxed),
+            shrinks: self.shrinks.load(Ordering::Relaxed),
+            peak_bytes: self.peak_bytes.load(Ordering::Relaxed),
+            contention_waits: self.contention_waits.load(Ordering::Relaxed),
+        }
+    }
+}
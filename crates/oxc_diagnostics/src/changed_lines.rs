@@ -0,0 +1,140 @@
+use rustc_hash::FxHashMap;
+
+/// Lines added or modified by a unified diff, keyed by the path of the new file.
+///
+/// Used by [`DiagnosticService::with_changed_lines`](crate::DiagnosticService::with_changed_lines)
+/// to only report diagnostics on changed lines.
+#[derive(Debug, Default, Clone)]
+pub struct ChangedLines {
+    /// Sorted, 1-based line numbers of each changed file.
+    files: FxHashMap<String, Vec<usize>>,
+}
+
+impl ChangedLines {
+    /// Collect the changed lines of a unified diff, e.g. the output of `git diff`.
+    ///
+    /// Paths are taken from the `+++` lines, with the `b/` prefix of git removed.
+    /// Deleted files have no changed lines.
+    pub fn from_unified_diff(diff: &str) -> Self {
+        let mut files: FxHashMap<String, Vec<usize>> = FxHashMap::default();
+        let mut current_file: Option<&mut Vec<usize>> = None;
+        // Line number in the new file of the next line in the current hunk.
+        let mut line = 0;
+        // Number of lines of the old and new file left in the current hunk.
+        let mut old_remaining = 0_usize;
+        let mut new_remaining = 0_usize;
+
+        for diff_line in diff.lines() {
+            if old_remaining > 0 || new_remaining > 0 {
+                if diff_line.starts_with('+') {
+                    if let Some(lines) = current_file.as_deref_mut() {
+                        lines.push(line);
+                    }
+                    line += 1;
+                    new_remaining = new_remaining.saturating_sub(1);
+                } else if diff_line.starts_with('-') {
+                    old_remaining = old_remaining.saturating_sub(1);
+                } else if !diff_line.starts_with('\\') {
+                    // Context line. `\ No newline at end of file` is not a line of either file.
+                    line += 1;
+                    old_remaining = old_remaining.saturating_sub(1);
+                    new_remaining = new_remaining.saturating_sub(1);
+                }
+            } else if let Some(path) = diff_line.strip_prefix("+++ ") {
+                // The path may be followed by a timestamp.
+                let path = path.split('\t').next().unwrap_or(path).trim_end();
+                current_file = (path != "/dev/null").then(|| {
+                    let path = path.strip_prefix("b/").unwrap_or(path);
+                    files.entry(path.to_string()).or_default()
+                });
+            } else if let Some(hunk) = diff_line.strip_prefix("@@ ") {
+                // `@@ -old_start,old_count +new_start,new_count @@`, counts default to 1.
+                let mut ranges = hunk.split(' ').take(2).map(|range| {
+                    let range = range.get(1..).unwrap_or_default();
+                    let mut parts = range.split(',').map(|n| n.parse::<usize>().unwrap_or(0));
+                    (parts.next().unwrap_or(0), parts.next().unwrap_or(1))
+                });
+                let (_, old_count) = ranges.next().unwrap_or_default();
+                let (new_start, new_count) = ranges.next().unwrap_or_default();
+                line = new_start;
+                old_remaining = old_count;
+                new_remaining = new_count;
+            }
+        }
+
+        for lines in files.values_mut() {
+            lines.sort_unstable();
+            lines.dedup();
+        }
+        Self { files }
+    }
+
+    /// Check if `path` has any changed lines.
+    pub fn contains_file(&self, path: &str) -> bool {
+        self.files.get(path).is_some_and(|lines| !lines.is_empty())
+    }
+
+    /// Check if any line from `start_line` to `end_line` (1-based, inclusive) of `path` changed.
+    pub fn intersects(&self, path: &str, start_line: usize, end_line: usize) -> bool {
+        self.files.get(path).is_some_and(|lines| {
+            let index = lines.partition_point(|line| *line < start_line);
+            lines.get(index).is_some_and(|line| *line <= end_line)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChangedLines;
+
+    #[test]
+    fn parse_git_diff() {
+        let diff = "\
+diff --git a/src/a.js b/src/a.js
+index 1111111..2222222 100644
+--- a/src/a.js
++++ b/src/a.js
+@@ -1,4 +1,5 @@
+ const a = 1;
+-const b = 2;
++const b = 3;
++const c = 4;
+ const d = 5;
+ const e = 6;
+@@ -10,0 +12 @@ function foo() {
++  debugger;
+diff --git a/src/removed.js b/src/removed.js
+deleted file mode 100644
+--- a/src/removed.js
++++ /dev/null
+@@ -1 +0,0 @@
+-const a = 1;
+diff --git a/src/new.js b/src/new.js
+new file mode 100644
+--- /dev/null
++++ b/src/new.js
+@@ -0,0 +1,2 @@
++const a = 1;
++const b = 2;
+\\ No newline at end of file
+";
+        let changed_lines = ChangedLines::from_unified_diff(diff);
+
+        assert!(!changed_lines.intersects("src/a.js", 1, 1));
+        assert!(changed_lines.intersects("src/a.js", 2, 2));
+        assert!(changed_lines.intersects("src/a.js", 3, 3));
+        assert!(!changed_lines.intersects("src/a.js", 4, 11));
+        assert!(changed_lines.intersects("src/a.js", 4, 12));
+        assert!(!changed_lines.intersects("src/a.js", 13, 20));
+
+        assert!(!changed_lines.contains_file("src/removed.js"));
+        assert!(!changed_lines.contains_file("/dev/null"));
+
+        assert!(changed_lines.contains_file("src/new.js"));
+        assert!(changed_lines.intersects("src/new.js", 1, 2));
+        assert!(!changed_lines.intersects("src/new.js", 3, 3));
+
+        assert!(!changed_lines.contains_file("src/b.js"));
+        assert!(!changed_lines.intersects("src/b.js", 1, 100));
+    }
+}
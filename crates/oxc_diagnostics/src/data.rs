@@ -0,0 +1,160 @@
+use std::{
+    borrow::Cow,
+    error::Error as StdError,
+    fmt::{self, Debug, Display},
+};
+
+use miette::{Diagnostic, LabeledSpan, SourceCode};
+
+use crate::{Error, OxcDiagnostic, Severity, ownership::WithSeverity, path::WithFileName};
+
+/// A value of the structured data of a diagnostic, see [`OxcDiagnostic::with_data`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiagnosticValue {
+    String(Cow<'static, str>),
+    Number(i64),
+}
+
+impl From<&'static str> for DiagnosticValue {
+    fn from(value: &'static str) -> Self {
+        Self::String(Cow::Borrowed(value))
+    }
+}
+
+impl From<String> for DiagnosticValue {
+    fn from(value: String) -> Self {
+        Self::String(Cow::Owned(value))
+    }
+}
+
+impl From<Cow<'static, str>> for DiagnosticValue {
+    fn from(value: Cow<'static, str>) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<i64> for DiagnosticValue {
+    fn from(value: i64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<u32> for DiagnosticValue {
+    fn from(value: u32) -> Self {
+        Self::Number(i64::from(value))
+    }
+}
+
+impl From<usize> for DiagnosticValue {
+    fn from(value: usize) -> Self {
+        Self::Number(i64::try_from(value).unwrap_or(i64::MAX))
+    }
+}
+
+/// The structured data of `error`, if it is an [`OxcDiagnostic`], e.g. `identifier` and `limit`
+/// for a message like "'foo' has too many parameters (4). Maximum allowed is 3.".
+pub fn diagnostic_data(error: &Error) -> &[(Cow<'static, str>, DiagnosticValue)] {
+    if let Some(diagnostic) = error.downcast_ref::<WithSourceCode>() {
+        &diagnostic.diagnostic.data
+    } else if let Some(diagnostic) = error.downcast_ref::<OxcDiagnostic>() {
+        &diagnostic.data
+    } else if let Some(diagnostic) = error.downcast_ref::<WithSeverity>() {
+        diagnostic_data(&diagnostic.diagnostic)
+    } else if let Some(diagnostic) = error.downcast_ref::<WithFileName>() {
+        diagnostic_data(&diagnostic.diagnostic)
+    } else {
+        &[]
+    }
+}
+
+/// An [`OxcDiagnostic`] with its source code, see [`OxcDiagnostic::with_source_code`].
+///
+/// Unlike [`Error::with_source_code`], the diagnostic can still be downcast to, to read its
+/// structured data.
+pub struct WithSourceCode {
+    pub diagnostic: OxcDiagnostic,
+    pub source_code: Box<dyn SourceCode>,
+}
+
+impl Debug for WithSourceCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.diagnostic, f)
+    }
+}
+
+impl Display for WithSourceCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.diagnostic, f)
+    }
+}
+
+impl StdError for WithSourceCode {}
+
+impl Diagnostic for WithSourceCode {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.diagnostic.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&*self.source_code)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.diagnostic.labels()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        path::{Path, PathBuf},
+        sync::Arc,
+    };
+
+    use miette::NamedSource;
+
+    use crate::{CodeOwners, Error, Ownership, OxcDiagnostic, Severity};
+
+    use super::{DiagnosticValue, diagnostic_data};
+
+    #[test]
+    fn test_diagnostic_data() {
+        let diagnostic = OxcDiagnostic::warn("'foo' is too long")
+            .with_data("identifier", "foo")
+            .with_data("limit", 3_usize);
+        let data = vec![
+            ("identifier".into(), DiagnosticValue::String("foo".into())),
+            ("limit".into(), DiagnosticValue::Number(3)),
+        ];
+
+        assert_eq!(diagnostic_data(&Error::new(diagnostic.clone())), data);
+
+        let source = Arc::new(NamedSource::new("test.js", "foo".to_string()));
+        let error = diagnostic.with_source_code(source);
+        let source = error.source_code().unwrap();
+        assert_eq!(source.read_span(&(0, 0).into(), 0, 0).unwrap().name(), Some("test.js"));
+        assert_eq!(diagnostic_data(&error), data);
+
+        let root = PathBuf::from("/repo");
+        let team = vec!["@team".to_string()];
+        let ownership =
+            Ownership::new(CodeOwners::parse("* @team"), team, root, Path::new("/repo"), |_| true);
+        let error = ownership.apply("test.js", error);
+        assert_eq!(error.severity(), Some(Severity::Error));
+        assert_eq!(diagnostic_data(&error), data);
+
+        assert!(diagnostic_data(&Error::msg("no data")).is_empty());
+    }
+}
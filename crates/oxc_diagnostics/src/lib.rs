@@ -46,6 +46,10 @@
 //! service.run();
 //! ```
 
+mod changed_lines;
+mod data;
+mod ownership;
+mod path;
 mod service;
 
 use std::{
@@ -56,7 +60,13 @@ use std::{
 
 pub mod reporter;
 
-pub use crate::service::{DiagnosticSender, DiagnosticService};
+pub use crate::{
+    changed_lines::ChangedLines,
+    data::{DiagnosticValue, diagnostic_data},
+    ownership::{CodeOwners, Ownership},
+    path::NormalizedPath,
+    service::{DiagnosticSender, DiagnosticService, SortKey},
+};
 
 pub type Error = miette::Error;
 pub type Severity = miette::Severity;
@@ -122,6 +132,9 @@ pub struct OxcDiagnosticInner {
     pub severity: Severity,
     pub code: OxcCode,
     pub url: Option<Cow<'static, str>>,
+    /// Structured data of the message, e.g. the identifier it is about, for tools which read
+    /// diagnostics. See [`OxcDiagnostic::with_data`].
+    pub data: Vec<(Cow<'static, str>, DiagnosticValue)>,
 }
 
 impl Display for OxcDiagnostic {
@@ -178,6 +191,7 @@ impl OxcDiagnostic {
                 severity: Severity::Error,
                 code: OxcCode::default(),
                 url: None,
+                data: Vec::new(),
             }),
         }
     }
@@ -192,6 +206,7 @@ impl OxcDiagnostic {
                 severity: Severity::Warning,
                 code: OxcCode::default(),
                 url: None,
+                data: Vec::new(),
             }),
         }
     }
@@ -332,11 +347,25 @@ impl OxcDiagnostic {
         self
     }
 
+    /// Add a value of structured data to this diagnostic, e.g. `with_data("limit", 3)`.
+    ///
+    /// The data is not rendered with the message, but is available to tools which read
+    /// diagnostics, e.g. in the JSON output of oxlint. Use the same values as in the message, so
+    /// that diagnostics can be grouped by them.
+    pub fn with_data<K: Into<Cow<'static, str>>, V: Into<DiagnosticValue>>(
+        mut self,
+        key: K,
+        value: V,
+    ) -> Self {
+        self.inner.data.push((key.into(), value.into()));
+        self
+    }
+
     /// Add source code to this diagnostic and convert it into an [`Error`].
     ///
     /// You should use a [`NamedSource`] if you have a file name as well as the source code.
     pub fn with_source_code<T: SourceCode + Send + Sync + 'static>(self, code: T) -> Error {
-        Error::from(self).with_source_code(code)
+        Error::new(data::WithSourceCode { diagnostic: self, source_code: Box::new(code) })
     }
 
     /// Consumes the diagnostic and returns the inner owned data.
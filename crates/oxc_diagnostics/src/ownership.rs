@@ -0,0 +1,289 @@
+use std::{
+    borrow::Cow,
+    error::Error as StdError,
+    fmt::{self, Display},
+    path::{Path, PathBuf},
+};
+
+use miette::{Diagnostic, LabeledSpan, SourceCode};
+
+use crate::{Error, NormalizedPath, Severity};
+
+/// Owners of files, parsed from a `CODEOWNERS` file.
+///
+/// Patterns follow the syntax of GitHub, which is a subset of `.gitignore`: patterns without a
+/// slash match files and directories at any depth, other patterns are relative to the root, and
+/// patterns matching a directory match all files inside of it. The last matching pattern wins.
+///
+/// See <https://docs.github.com/en/repositories/managing-your-repositorys-settings-and-features/customizing-your-repository/about-code-owners>
+#[derive(Debug, Default, Clone)]
+pub struct CodeOwners {
+    rules: Vec<OwnershipRule>,
+}
+
+#[derive(Debug, Clone)]
+struct OwnershipRule {
+    /// Glob matching the files of the pattern
+    glob: String,
+    /// Glob matching the files inside of the directories of the pattern
+    dir_glob: String,
+    /// The pattern only matches directories, e.g. `docs/`
+    dir_only: bool,
+    /// May be empty, which makes matching files unowned
+    owners: Vec<String>,
+}
+
+impl CodeOwners {
+    /// Parse the contents of a `CODEOWNERS` file.
+    ///
+    /// Invalid lines, e.g. with negated patterns which are not supported by GitHub either,
+    /// are ignored.
+    pub fn parse(text: &str) -> Self {
+        let mut rules = vec![];
+        for line in text.lines() {
+            // Comments may follow owners, but `\#` escapes patterns starting with `#`.
+            let line = line.split(" #").next().unwrap_or_default().trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+                continue;
+            }
+            let mut parts = line.split_whitespace();
+            let Some(pattern) = parts.next() else { continue };
+            let pattern = pattern.strip_prefix('\\').unwrap_or(pattern);
+            let owners = parts.map(ToString::to_string).collect();
+
+            let dir_only = pattern.ends_with('/');
+            let pattern = pattern.trim_end_matches('/');
+            let glob = if pattern.contains('/') {
+                pattern.trim_start_matches('/').to_string()
+            } else {
+                format!("**/{pattern}")
+            };
+            let dir_glob = format!("{glob}/**");
+            rules.push(OwnershipRule { glob, dir_glob, dir_only, owners });
+        }
+        Self { rules }
+    }
+
+    /// Owners of `path`, which is relative to the root of the repository and separated by `/`.
+    /// Empty if the file has no owners.
+    pub fn owners(&self, path: &str) -> &[String] {
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| {
+                (!rule.dir_only && fast_glob::glob_match(&rule.glob, path))
+                    || fast_glob::glob_match(&rule.dir_glob, path)
+            })
+            .map_or(&[], |rule| rule.owners.as_slice())
+    }
+}
+
+/// Escalates diagnostics in files owned by a team to errors, and turns all other diagnostics into
+/// warnings, see [`DiagnosticService::with_ownership`](crate::DiagnosticService::with_ownership).
+#[derive(Debug, Clone)]
+pub struct Ownership {
+    code_owners: CodeOwners,
+    /// Handles of the team, e.g. `@org/team` or an email address
+    team: Vec<String>,
+    /// The root of the repository, which the patterns of `code_owners` are relative to
+    root: PathBuf,
+    /// The directory which the file names of diagnostics are relative to, relative to `root`
+    /// and separated by `/`. Empty if it is `root`.
+    cwd: String,
+    /// Check if a diagnostic code, e.g. `eslint(no-debugger)`, is the code of a lint rule.
+    /// Errors with other codes, e.g. syntax errors, are never downgraded.
+    is_rule_code: fn(&str) -> bool,
+}
+
+impl Ownership {
+    /// Ownership of the files in `root`, for diagnostics of files relative to `cwd`.
+    pub fn new(
+        code_owners: CodeOwners,
+        team: Vec<String>,
+        root: PathBuf,
+        cwd: &Path,
+        is_rule_code: fn(&str) -> bool,
+    ) -> Self {
+        let cwd = cwd.strip_prefix(&root).map(NormalizedPath::new).map(|cwd| cwd.to_string());
+        Self { code_owners, team, cwd: cwd.unwrap_or_default(), root, is_rule_code }
+    }
+
+    /// Check if `path` is owned by the team. `path` is relative to the root of the repository.
+    /// Handles are compared case-insensitively, like on GitHub.
+    pub fn is_owned(&self, path: &str) -> bool {
+        self.code_owners
+            .owners(path)
+            .iter()
+            .any(|owner| self.team.iter().any(|handle| handle.eq_ignore_ascii_case(owner)))
+    }
+
+    /// `file_name` of a diagnostic relative to the root of the repository, or `None` if the file
+    /// is outside of the repository.
+    fn path_in_root<'a>(&self, file_name: &'a str) -> Option<Cow<'a, str>> {
+        let path = Path::new(file_name);
+        if path.is_absolute() {
+            let path = path.strip_prefix(&self.root).ok()?;
+            return Some(Cow::Owned(NormalizedPath::new(path).to_string()));
+        }
+        if self.cwd.is_empty() {
+            Some(Cow::Borrowed(file_name))
+        } else {
+            Some(Cow::Owned(format!("{}/{file_name}", self.cwd)))
+        }
+    }
+
+    /// `diagnostic` of the file `file_name` with the severity of the file. Advice is left as is.
+    pub(crate) fn apply(&self, file_name: &str, diagnostic: Error) -> Error {
+        if diagnostic.severity() == Some(Severity::Advice) {
+            return diagnostic;
+        }
+        let is_owned = self.path_in_root(file_name).is_some_and(|path| self.is_owned(&path));
+        let severity = if is_owned { Severity::Error } else { Severity::Warning };
+        let current = diagnostic.severity().unwrap_or(Severity::Error);
+        if current == severity
+            || (current == Severity::Error
+                && !diagnostic.code().is_some_and(|code| (self.is_rule_code)(&code.to_string())))
+        {
+            return diagnostic;
+        }
+        Error::new(WithSeverity { diagnostic, severity })
+    }
+}
+
+/// A diagnostic with another severity. The severity of an [`Error`] cannot be changed once it
+/// is wrapped with its source code.
+#[derive(Debug)]
+pub struct WithSeverity {
+    pub diagnostic: Error,
+    severity: Severity,
+}
+
+impl Display for WithSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.diagnostic, f)
+    }
+}
+
+impl StdError for WithSeverity {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.diagnostic.source()
+    }
+}
+
+impl Diagnostic for WithSeverity {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        Some(self.severity)
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.diagnostic.source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.diagnostic.labels()
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.diagnostic.related()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        self.diagnostic.diagnostic_source()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{CodeOwners, Ownership};
+    use crate::{Error, OxcDiagnostic, Severity};
+
+    #[test]
+    fn parse_code_owners() {
+        let code_owners = CodeOwners::parse(
+            "\
+# Default owners
+*       @org/core
+
+*.md    @org/docs docs@example.com # inline comment
+/apps/  @org/apps
+web/    @org/web
+/crates/*/tests @org/qa
+**/generated
+\\#notes @org/core
+",
+        );
+
+        let owners = |path| code_owners.owners(path).to_vec();
+        assert_eq!(owners("src/index.js"), ["@org/core"]);
+        assert_eq!(owners("README.md"), ["@org/docs", "docs@example.com"]);
+        assert_eq!(owners("src/guide/README.md"), ["@org/docs", "docs@example.com"]);
+        assert_eq!(owners("apps/oxlint/src/main.rs"), ["@org/apps"]);
+        assert_eq!(owners("src/apps/main.rs"), ["@org/core"]);
+        // `web/` matches directories at any depth, but not files
+        assert_eq!(owners("apps/web/index.js"), ["@org/web"]);
+        assert_eq!(owners("web/index.js"), ["@org/web"]);
+        assert_eq!(owners("crates/oxc/tests/main.rs"), ["@org/qa"]);
+        assert_eq!(owners("crates/oxc/src/tests/main.rs"), ["@org/core"]);
+        // no owners
+        assert!(owners("src/generated/types.js").is_empty());
+        assert_eq!(owners("#notes"), ["@org/core"]);
+
+        assert!(CodeOwners::parse("").owners("index.js").is_empty());
+    }
+
+    #[test]
+    fn is_owned() {
+        let code_owners = CodeOwners::parse("* @org/core\n/web/ @Org/Web\n");
+        let root = PathBuf::from("/repo");
+        let ownership = Ownership::new(
+            code_owners,
+            vec!["@org/web".to_string()],
+            root,
+            Path::new("/repo"),
+            |_| true,
+        );
+
+        assert!(ownership.is_owned("web/index.js"));
+        assert!(!ownership.is_owned("index.js"));
+    }
+
+    #[test]
+    fn apply() {
+        let code_owners = CodeOwners::parse("/app/web/ @org/web\n");
+        let root = PathBuf::from("/repo");
+        let cwd = Path::new("/repo/app");
+        let ownership =
+            Ownership::new(code_owners, vec!["@org/web".to_string()], root, cwd, |code| {
+                code == "eslint(no-debugger)"
+            });
+        let severity = |file_name, diagnostic: OxcDiagnostic| {
+            ownership.apply(file_name, Error::new(diagnostic)).severity()
+        };
+        let rule = || OxcDiagnostic::warn("rule").with_error_code("eslint", "no-debugger");
+        let rule_error = || OxcDiagnostic::error("rule").with_error_code("eslint", "no-debugger");
+
+        // file names are relative to `cwd`, patterns to `root`
+        assert_eq!(severity("web/index.js", rule()), Some(Severity::Error));
+        assert_eq!(severity("/repo/app/web/index.js", rule()), Some(Severity::Error));
+        assert_eq!(severity("api/index.js", rule_error()), Some(Severity::Warning));
+        assert_eq!(severity("/other/web/index.js", rule_error()), Some(Severity::Warning));
+        // syntax errors are never downgraded
+        assert_eq!(severity("api/index.js", OxcDiagnostic::error("syntax")), Some(Severity::Error));
+        let ts_error = OxcDiagnostic::error("syntax").with_error_code("TS", "1109");
+        assert_eq!(severity("api/index.js", ts_error), Some(Severity::Error));
+    }
+}
@@ -0,0 +1,259 @@
+use std::{
+    borrow::Cow,
+    error::Error as StdError,
+    fmt::{self, Display},
+    path::{MAIN_SEPARATOR, Path},
+};
+
+use cow_utils::CowUtils;
+use miette::{
+    Diagnostic, LabeledSpan, MietteError, MietteSpanContents, SourceCode, SourceSpan, SpanContents,
+};
+
+use crate::{Error, Severity};
+
+/// A path which always uses `/` as separator, regardless of the platform.
+///
+/// Ignore patterns, override globs and the file names of diagnostics are all written with `/`,
+/// so paths are normalized before they are matched or displayed. On Windows, the verbatim
+/// prefix of canonicalized paths (`\\?\C:\`) is removed as well.
+///
+/// Use [`NormalizedPath::to_native`] to display the path with the separator of the platform.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NormalizedPath(String);
+
+impl NormalizedPath {
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self(normalize(&path.as_ref().to_string_lossy(), MAIN_SEPARATOR))
+    }
+
+    /// Normalize `path` relative to `base`. When `path` is not inside of `base`, it is
+    /// normalized as is.
+    pub fn relative_to<P: AsRef<Path>, B: AsRef<Path>>(path: P, base: B) -> Self {
+        let path = path.as_ref();
+        Self::new(path.strip_prefix(base).unwrap_or(path))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The path as [`Path`], which is valid on every platform, as Windows accepts `/` as
+    /// separator as well.
+    pub fn as_path(&self) -> &Path {
+        Path::new(&self.0)
+    }
+
+    /// The path with the separator of the platform, i.e. `\` on Windows.
+    pub fn to_native(&self) -> Cow<'_, str> {
+        to_separator(&self.0, MAIN_SEPARATOR)
+    }
+}
+
+impl Display for NormalizedPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for NormalizedPath {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<Path> for NormalizedPath {
+    fn as_ref(&self) -> &Path {
+        self.as_path()
+    }
+}
+
+/// Replace `separator` in `path` with `/`.
+///
+/// `separator` is a parameter, so the Windows behavior can be tested on every platform.
+fn normalize(path: &str, separator: char) -> String {
+    if separator == '/' {
+        return path.to_string();
+    }
+    // `\\?\C:\` is only written as `C:\` by users, other verbatim paths (`\\?\UNC\`) are kept.
+    let path = match path.strip_prefix(r"\\?\") {
+        Some(rest) if rest.as_bytes().get(1) == Some(&b':') => rest,
+        _ => path,
+    };
+    path.cow_replace(separator, "/").into_owned()
+}
+
+/// Replace `/` in a normalized `path` with `separator`.
+fn to_separator(path: &str, separator: char) -> Cow<'_, str> {
+    if separator == '/' {
+        return Cow::Borrowed(path);
+    }
+    let mut buf = [0; 4];
+    path.cow_replace('/', separator.encode_utf8(&mut buf))
+}
+
+/// A diagnostic whose file is displayed with another name, e.g. with the path separator of
+/// the platform. The source code of the diagnostic is not copied.
+#[derive(Debug)]
+pub struct WithFileName {
+    pub diagnostic: Error,
+    name: String,
+}
+
+impl WithFileName {
+    pub fn new(diagnostic: Error, name: String) -> Self {
+        Self { diagnostic, name }
+    }
+}
+
+impl Display for WithFileName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.diagnostic, f)
+    }
+}
+
+impl StdError for WithFileName {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.diagnostic.source()
+    }
+}
+
+impl Diagnostic for WithFileName {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.diagnostic.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.diagnostic.source_code().map(|_| self as &dyn SourceCode)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.diagnostic.labels()
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.diagnostic.related()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        self.diagnostic.diagnostic_source()
+    }
+}
+
+impl SourceCode for WithFileName {
+    fn read_span<'a>(
+        &'a self,
+        span: &SourceSpan,
+        context_lines_before: usize,
+        context_lines_after: usize,
+    ) -> Result<Box<dyn SpanContents<'a> + 'a>, MietteError> {
+        let source = self.diagnostic.source_code().ok_or(MietteError::OutOfBounds)?;
+        let contents = source.read_span(span, context_lines_before, context_lines_after)?;
+        Ok(Box::new(MietteSpanContents::new_named(
+            self.name.clone(),
+            contents.data(),
+            *contents.span(),
+            contents.line(),
+            contents.column(),
+            contents.line_count(),
+        )))
+    }
+
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use crate::{DiagnosticService, Error, LabeledSpan, OxcDiagnostic};
+
+    use super::{NormalizedPath, WithFileName, normalize, to_separator};
+
+    #[test]
+    fn test_normalize_windows_separators() {
+        let cases = [
+            (r"src\index.ts", "src/index.ts"),
+            (r"C:\repo\src\index.ts", "C:/repo/src/index.ts"),
+            (r"\\?\C:\repo\src\index.ts", "C:/repo/src/index.ts"),
+            (r"\\?\UNC\server\share\index.ts", "//?/UNC/server/share/index.ts"),
+            (r"\\server\share\index.ts", "//server/share/index.ts"),
+            ("src/already/normalized.ts", "src/already/normalized.ts"),
+            (r"mixed\separators/index.ts", "mixed/separators/index.ts"),
+        ];
+        for (path, expected) in cases {
+            assert_eq!(normalize(path, '\\'), expected, "{path}");
+        }
+    }
+
+    #[test]
+    fn test_normalize_posix_separators() {
+        // `\` is a valid character of file names on posix systems.
+        assert_eq!(normalize(r"src/weird\name.ts", '/'), r"src/weird\name.ts");
+        assert_eq!(normalize("src/index.ts", '/'), "src/index.ts");
+    }
+
+    #[test]
+    fn test_to_native_separators() {
+        assert_eq!(to_separator("C:/repo/src/index.ts", '\\'), r"C:\repo\src\index.ts");
+        assert_eq!(to_separator("src/index.ts", '/'), "src/index.ts");
+    }
+
+    #[test]
+    fn test_relative_to() {
+        let path = NormalizedPath::relative_to(Path::new("/repo/src/index.ts"), "/repo");
+        assert_eq!(path.as_str(), "src/index.ts");
+        let path = NormalizedPath::relative_to(Path::new("/other/index.ts"), "/repo");
+        assert_eq!(path.as_str(), "/other/index.ts");
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_windows_paths() {
+        let path = NormalizedPath::new(Path::new(r"C:\repo\src\index.ts"));
+        assert_eq!(path.as_str(), "C:/repo/src/index.ts");
+        assert_eq!(path.to_native(), r"C:\repo\src\index.ts");
+        assert_eq!(path.as_path(), Path::new(r"C:\repo\src\index.ts"));
+
+        let path = NormalizedPath::relative_to(Path::new(r"C:\repo\src\index.ts"), r"C:\repo");
+        assert_eq!(path.as_str(), "src/index.ts");
+        assert_eq!(path.to_native(), r"src\index.ts");
+
+        let path = NormalizedPath::new(Path::new(r"\\?\C:\repo\src\index.ts"));
+        assert_eq!(path.as_str(), "C:/repo/src/index.ts");
+    }
+
+    #[test]
+    fn test_with_file_name() {
+        let diagnostic = OxcDiagnostic::warn("debugger").with_label(LabeledSpan::underline((4, 8)));
+        let diagnostic = DiagnosticService::wrap_diagnostics(
+            "/repo",
+            "/repo/src/index.js",
+            "let debugger;",
+            vec![diagnostic],
+        )
+        .remove(0);
+        let error = Error::new(WithFileName::new(diagnostic, r"src\index.js".to_string()));
+
+        let source = error.source_code().unwrap();
+        assert_eq!(source.name(), Some(r"src\index.js"));
+        let label = error.labels().unwrap().next().unwrap();
+        let contents = source.read_span(label.inner(), 0, 0).unwrap();
+        assert_eq!(contents.name(), Some(r"src\index.js"));
+        assert_eq!(contents.data(), b"debugger");
+    }
+}
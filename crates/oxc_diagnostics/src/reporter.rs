@@ -1,5 +1,7 @@
 //! [Reporters](DiagnosticReporter) for rendering and writing diagnostics.
 
+use std::collections::BTreeMap;
+
 use miette::SourceSpan;
 
 use crate::{Error, Severity};
@@ -68,11 +70,21 @@ pub struct DiagnosticResult {
     /// Did the threshold for warnings exceeded the max_warnings?
     /// ToDo: We giving the input from outside, let the owner calculate the result
     max_warnings_exceeded: bool,
+
+    /// Number of warnings and errors received per code, e.g. `eslint(no-debugger)`.
+    /// Diagnostics without a code are not included.
+    code_counts: BTreeMap<String, usize>,
 }
 
 impl DiagnosticResult {
     pub fn new(warnings_count: usize, errors_count: usize, max_warnings_exceeded: bool) -> Self {
-        Self { warnings_count, errors_count, max_warnings_exceeded }
+        Self { warnings_count, errors_count, max_warnings_exceeded, code_counts: BTreeMap::new() }
+    }
+
+    #[must_use]
+    pub fn with_code_counts(mut self, code_counts: BTreeMap<String, usize>) -> Self {
+        self.code_counts = code_counts;
+        self
     }
 
     /// Get the number of warning-level diagnostics received.
@@ -89,6 +101,11 @@ impl DiagnosticResult {
     pub fn max_warnings_exceeded(&self) -> bool {
         self.max_warnings_exceeded
     }
+
+    /// Number of warnings and errors received per code, e.g. `eslint(no-debugger)`.
+    pub fn code_counts(&self) -> &BTreeMap<String, usize> {
+        &self.code_counts
+    }
 }
 
 #[derive(Debug)]
@@ -99,6 +116,8 @@ pub struct Info {
     pub message: String,
     pub severity: Severity,
     pub rule_id: Option<String>,
+    /// Help message of the diagnostic, e.g. how to recover from a syntax error.
+    pub help: Option<String>,
 }
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
@@ -115,6 +134,8 @@ impl Info {
         let mut message = String::new();
         let mut severity = Severity::Warning;
         let rule_id = diagnostic.code().map(|code| code.to_string());
+        let help = diagnostic.help().map(|help| help.to_string());
+        let mut has_source = false;
 
         if let Some(mut labels) = diagnostic.labels()
             && let Some(source) = diagnostic.source_code()
@@ -134,6 +155,20 @@ impl Info {
             if let Some(name) = span_content.name() {
                 filename = name.to_string();
             }
+            has_source = true;
+        } else if let Some(source) = diagnostic.source_code()
+            && let Ok(span_content) = source.read_span(&SourceSpan::from((0, 0)), 0, 0)
+        {
+            // Diagnostics without labels are reported for the whole file, e.g. some syntax errors.
+            start = InfoPosition { line: 1, column: 1 };
+            end = InfoPosition { line: 1, column: 1 };
+            if let Some(name) = span_content.name() {
+                filename = name.to_string();
+            }
+            has_source = true;
+        }
+
+        if has_source {
             if matches!(diagnostic.severity(), Some(Severity::Error)) {
                 severity = Severity::Error;
             }
@@ -150,6 +185,6 @@ impl Info {
             }
         }
 
-        Self { start, end, filename, message, severity, rule_id }
+        Self { start, end, filename, message, severity, rule_id, help }
     }
 }
@@ -1,23 +1,49 @@
 use std::{
     borrow::Cow,
+    cmp::Ordering,
+    collections::BTreeMap,
+    fmt::{self, Write as _},
     io::{ErrorKind, Write},
-    path::{Path, PathBuf},
-    sync::{Arc, mpsc},
+    path::{MAIN_SEPARATOR, Path, PathBuf},
+    sync::{Arc, LazyLock, mpsc},
 };
 
 use cow_utils::CowUtils;
+use miette::{MietteError, SourceCode, SourceSpan, SpanContents};
 use percent_encoding::AsciiSet;
 #[cfg(not(windows))]
 use std::fs::canonicalize as strict_canonicalize;
 
 use crate::{
-    Error, NamedSource, OxcDiagnostic, Severity,
-    reporter::{DiagnosticReporter, DiagnosticResult},
+    ChangedLines, Error, NamedSource, NormalizedPath, Ownership, OxcDiagnostic, Severity,
+    path::WithFileName,
+    reporter::{DiagnosticReporter, DiagnosticResult, Info},
 };
 
 pub type DiagnosticSender = mpsc::Sender<Vec<Error>>;
 pub type DiagnosticReceiver = mpsc::Receiver<Vec<Error>>;
 
+/// Key to sort the diagnostics by, see [`DiagnosticService::with_sort_by`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    /// Errors before warnings.
+    Severity,
+    /// File name, then position in the file.
+    Path,
+    /// Code of the rule, e.g. `eslint(no-debugger)`.
+    Rule,
+}
+
+impl SortKey {
+    fn compare(self, a: &Info, b: &Info) -> Ordering {
+        match self {
+            Self::Severity => b.severity.cmp(&a.severity),
+            Self::Path => (&a.filename, &a.start, &a.end).cmp(&(&b.filename, &b.start, &b.end)),
+            Self::Rule => a.rule_id.cmp(&b.rule_id),
+        }
+    }
+}
+
 /// Listens for diagnostics sent over a [channel](DiagnosticSender) by some job, and
 /// formats/reports them to the user.
 ///
@@ -56,10 +82,28 @@ pub struct DiagnosticService {
     /// Do not display any diagnostics
     silent: bool,
 
+    /// Do not display diagnostics with these codes, e.g. `eslint(no-debugger)`.
+    /// They are still counted towards the warning and error totals.
+    quiet_codes: Vec<String>,
+
+    /// Only display diagnostics on these lines.
+    /// Diagnostics on other lines are still counted towards the warning and error totals.
+    changed_lines: Option<ChangedLines>,
+
+    /// Report diagnostics in files owned by a team as errors, and all others as warnings.
+    ownership: Option<Ownership>,
+
     /// Specify a warning threshold,
     /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
     max_warnings: Option<usize>,
 
+    /// Display file names with the path separator of the platform instead of `/`.
+    native_path_separators: bool,
+
+    /// Report the diagnostics sorted by these keys, then by file, position, rule and message,
+    /// once all of them have been received, instead of in the order in which files are linted.
+    sort_by: Option<Vec<SortKey>>,
+
     receiver: DiagnosticReceiver,
 }
 
@@ -68,7 +112,21 @@ impl DiagnosticService {
     /// provided [`DiagnosticReporter`].
     pub fn new(reporter: Box<dyn DiagnosticReporter>) -> (Self, DiagnosticSender) {
         let (sender, receiver) = mpsc::channel();
-        (Self { reporter, quiet: false, silent: false, max_warnings: None, receiver }, sender)
+        (
+            Self {
+                reporter,
+                quiet: false,
+                silent: false,
+                quiet_codes: Vec::new(),
+                changed_lines: None,
+                ownership: None,
+                max_warnings: None,
+                native_path_separators: false,
+                sort_by: None,
+                receiver,
+            },
+            sender,
+        )
     }
 
     /// Set to `true` to only report errors and ignore warnings.
@@ -93,6 +151,88 @@ impl DiagnosticService {
         self
     }
 
+    /// Do not display diagnostics whose code (e.g. `eslint(no-debugger)`) is in `codes`.
+    ///
+    /// Unlike [`with_quiet`](DiagnosticService::with_quiet), hidden diagnostics are still
+    /// counted, so they still affect the exit code and the warning threshold.
+    ///
+    /// Default: no codes
+    #[must_use]
+    pub fn with_quiet_codes(mut self, codes: Vec<String>) -> Self {
+        self.quiet_codes = codes;
+        self
+    }
+
+    /// Check if `diagnostic` has one of the codes set with
+    /// [`with_quiet_codes`](DiagnosticService::with_quiet_codes).
+    fn is_quiet_code(&self, diagnostic: &Error) -> bool {
+        !self.quiet_codes.is_empty()
+            && diagnostic.code().is_some_and(|code| {
+                self.quiet_codes.iter().any(|quiet_code| display_eq(&*code, quiet_code))
+            })
+    }
+
+    /// Only display diagnostics whose labels intersect `changed_lines`, e.g. the lines changed
+    /// by a pull request. Diagnostics without labels are displayed if their file changed.
+    ///
+    /// Like [`with_quiet_codes`](DiagnosticService::with_quiet_codes), hidden diagnostics are
+    /// still counted.
+    ///
+    /// Default: [`None`], all diagnostics are displayed
+    #[must_use]
+    pub fn with_changed_lines(mut self, changed_lines: Option<ChangedLines>) -> Self {
+        self.changed_lines = changed_lines;
+        self
+    }
+
+    /// Check if `diagnostic` is outside of the lines set with
+    /// [`with_changed_lines`](DiagnosticService::with_changed_lines).
+    fn is_outside_changed_lines(&self, diagnostic: &Error) -> bool {
+        let Some(changed_lines) = &self.changed_lines else { return false };
+        let Some(source) = diagnostic.source_code() else { return false };
+        let Some(path) = Self::file_name(diagnostic) else { return false };
+        let Some(labels) = diagnostic.labels() else {
+            return !changed_lines.contains_file(path);
+        };
+        let mut has_labels = false;
+        for label in labels {
+            has_labels = true;
+            let Ok(contents) = source.read_span(label.inner(), 0, 0) else { continue };
+            let start_line = contents.line() + 1;
+            let end_line = start_line + contents.line_count().saturating_sub(1);
+            if changed_lines.intersects(path, start_line, end_line) {
+                return false;
+            }
+        }
+        has_labels || !changed_lines.contains_file(path)
+    }
+
+    /// Report diagnostics in files owned by the team of `ownership` as errors, and diagnostics in
+    /// all other files as warnings, regardless of the severity of their rules. Diagnostics
+    /// without a file, e.g. about configuration files, keep their severity, and errors which
+    /// are not reported by a rule, e.g. syntax errors, are never turned into warnings.
+    ///
+    /// Default: [`None`], diagnostics keep their severity
+    #[must_use]
+    pub fn with_ownership(mut self, ownership: Option<Ownership>) -> Self {
+        self.ownership = ownership;
+        self
+    }
+
+    /// `diagnostic` with its severity set by [`with_ownership`](DiagnosticService::with_ownership).
+    fn apply_ownership(&self, diagnostic: Error) -> Error {
+        let Some(ownership) = &self.ownership else { return diagnostic };
+        match Self::file_name(&diagnostic).map(ToString::to_string) {
+            Some(path) => ownership.apply(&path, diagnostic),
+            None => diagnostic,
+        }
+    }
+
+    /// The file name of the source code of `diagnostic`.
+    fn file_name(diagnostic: &Error) -> Option<&str> {
+        diagnostic.source_code()?.name()
+    }
+
     /// Specify a warning threshold, which can be used to force exit with an error status if there
     /// are too many warning-level rule violations in your project. Errors do not count towards the
     /// warning limit.
@@ -107,6 +247,75 @@ impl DiagnosticService {
         self
     }
 
+    /// Set to `true` to display file names with the path separator of the platform, i.e. `\` on
+    /// Windows. File names are displayed with `/` by default, so the output is the same on every
+    /// platform.
+    ///
+    /// Default: `false`
+    #[must_use]
+    pub fn with_native_path_separators(mut self, yes: bool) -> Self {
+        self.native_path_separators = yes;
+        self
+    }
+
+    /// Report the diagnostics sorted by `keys`, e.g. errors first with [`SortKey::Severity`], so
+    /// the output does not depend on the order in which files are linted in parallel. Ties are
+    /// sorted by file, position, rule and message. Diagnostics are only reported once all of them
+    /// have been received. Does nothing if `keys` is empty.
+    ///
+    /// Default: no keys
+    #[must_use]
+    pub fn with_sort_by(mut self, keys: Vec<SortKey>) -> Self {
+        if !keys.is_empty() {
+            self.sort_by = Some(keys);
+        }
+        self
+    }
+
+    /// Receive all diagnostics, sorted as described in
+    /// [`with_sort_by`](DiagnosticService::with_sort_by). Consecutive diagnostics of the same
+    /// file are grouped together.
+    fn receive_sorted(&self, keys: &[SortKey]) -> Vec<Vec<Error>> {
+        let mut diagnostics = self
+            .receiver
+            .iter()
+            .flatten()
+            // Owned files change the severity, which is a sort key.
+            .map(|diagnostic| {
+                let diagnostic = self.apply_ownership(diagnostic);
+                (Info::new(&diagnostic), diagnostic)
+            })
+            .collect::<Vec<_>>();
+        diagnostics.sort_by(|(a, _), (b, _)| {
+            keys.iter()
+                .map(|key| key.compare(a, b))
+                .chain([SortKey::Path.compare(a, b), SortKey::Rule.compare(a, b)])
+                .fold(Ordering::Equal, Ordering::then)
+                .then_with(|| a.message.cmp(&b.message))
+        });
+
+        let mut files: Vec<(String, Vec<Error>)> = vec![];
+        for (Info { filename, .. }, diagnostic) in diagnostics {
+            match files.last_mut() {
+                Some((last, file)) if *last == filename => file.push(diagnostic),
+                _ => files.push((filename, vec![diagnostic])),
+            }
+        }
+        files.into_iter().map(|(_, file)| file).collect()
+    }
+
+    /// `diagnostic` with its file named with the path separator of the platform.
+    fn with_native_path(diagnostic: Error) -> Error {
+        // File URLs, displayed for JetBrains terminals, always use `/`.
+        let Some(native_name) = Self::file_name(&diagnostic)
+            .filter(|name| !name.starts_with("file://"))
+            .map(|name| NormalizedPath::new(name).to_native().into_owned())
+        else {
+            return diagnostic;
+        };
+        Error::new(WithFileName::new(diagnostic, native_name))
+    }
+
     /// Check if the max warning threshold, as set by
     /// [`with_max_warnings`](DiagnosticService::with_max_warnings), has been exceeded.
     fn max_warnings_exceeded(&self, warnings_count: usize) -> bool {
@@ -123,22 +332,20 @@ impl DiagnosticService {
         diagnostics: Vec<OxcDiagnostic>,
     ) -> Vec<Error> {
         // TODO: This causes snapshots to fail when running tests through a JetBrains terminal.
-        let is_jetbrains =
-            std::env::var("TERMINAL_EMULATOR").is_ok_and(|x| x.eq("JetBrains-JediTerm"));
+        // Environment is only read once, instead of allocating a `String` for every file.
+        static IS_JETBRAINS: LazyLock<bool> = LazyLock::new(|| {
+            std::env::var_os("TERMINAL_EMULATOR").is_some_and(|x| x == "JetBrains-JediTerm")
+        });
+        let is_jetbrains = *IS_JETBRAINS;
 
         let path_ref = path.as_ref();
         let path_display = if is_jetbrains { from_file_path(path_ref) } else { None }
-            .unwrap_or_else(|| {
-                let relative_path =
-                    path_ref.strip_prefix(cwd).unwrap_or(path_ref).to_string_lossy();
-                let normalized_path = relative_path.cow_replace('\\', "/");
-                normalized_path.to_string()
-            });
-
-        let source = Arc::new(NamedSource::new(path_display, source_text.to_owned()));
+            .unwrap_or_else(|| NormalizedPath::relative_to(path_ref, cwd).to_string());
+
+        let source = FileSource(Arc::new(NamedSource::new(path_display, source_text.to_owned())));
         diagnostics
             .into_iter()
-            .map(|diagnostic| diagnostic.with_source_code(Arc::clone(&source)))
+            .map(|diagnostic| diagnostic.with_source_code(source.clone()))
             .collect()
     }
 
@@ -154,14 +361,37 @@ impl DiagnosticService {
     pub fn run(&mut self, writer: &mut dyn Write) -> DiagnosticResult {
         let mut warnings_count: usize = 0;
         let mut errors_count: usize = 0;
-
-        while let Ok(diagnostics) = self.receiver.recv() {
+        let mut code_counts = BTreeMap::<String, usize>::new();
+        let mut code_buffer = String::new();
+
+        let mut sorted = self.sort_by.as_ref().map(|keys| self.receive_sorted(keys).into_iter());
+        let is_sorted = sorted.is_some();
+        loop {
+            let diagnostics = match &mut sorted {
+                Some(sorted) => sorted.next(),
+                None => self.receiver.recv().ok(),
+            };
+            let Some(diagnostics) = diagnostics else { break };
             let mut is_minified = false;
             for diagnostic in diagnostics {
+                // Ownership is applied before sorting.
+                let diagnostic =
+                    if is_sorted { diagnostic } else { self.apply_ownership(diagnostic) };
                 let severity = diagnostic.severity();
                 let is_warning = severity == Some(Severity::Warning);
                 let is_error = severity == Some(Severity::Error) || severity.is_none();
                 if is_warning || is_error {
+                    if let Some(code) = diagnostic.code() {
+                        // Only allocate a string for the first diagnostic of each code.
+                        code_buffer.clear();
+                        let _ = write!(code_buffer, "{code}");
+                        match code_counts.get_mut(&code_buffer) {
+                            Some(count) => *count += 1,
+                            None => {
+                                code_counts.insert(code_buffer.clone(), 1);
+                            }
+                        }
+                    }
                     if is_warning {
                         warnings_count += 1;
                     }
@@ -175,10 +405,20 @@ impl DiagnosticService {
                     }
                 }
 
-                if self.silent || is_minified {
+                if self.silent
+                    || is_minified
+                    || self.is_quiet_code(&diagnostic)
+                    || self.is_outside_changed_lines(&diagnostic)
+                {
                     continue;
                 }
 
+                let diagnostic = if self.native_path_separators && MAIN_SEPARATOR != '/' {
+                    Self::with_native_path(diagnostic)
+                } else {
+                    diagnostic
+                };
+
                 let path = diagnostic
                     .source_code()
                     .and_then(|source| source.name())
@@ -219,7 +459,8 @@ impl DiagnosticService {
             warnings_count,
             errors_count,
             self.max_warnings_exceeded(warnings_count),
-        );
+        )
+        .with_code_counts(code_counts);
 
         if let Some(finish_output) = self.reporter.finish(&result) {
             writer
@@ -243,6 +484,27 @@ impl DiagnosticService {
     }
 }
 
+/// The source code of a file, shared by its diagnostics.
+///
+/// Unlike `Arc<NamedSource>`, the name of the file is returned by [`SourceCode::name`].
+#[derive(Clone)]
+struct FileSource(Arc<NamedSource<String>>);
+
+impl SourceCode for FileSource {
+    fn read_span<'a>(
+        &'a self,
+        span: &SourceSpan,
+        context_lines_before: usize,
+        context_lines_after: usize,
+    ) -> Result<Box<dyn SpanContents<'a> + 'a>, MietteError> {
+        self.0.read_span(span, context_lines_before, context_lines_after)
+    }
+
+    fn name(&self) -> Option<&str> {
+        SourceCode::name(&*self.0)
+    }
+}
+
 // The following from_file_path and strict_canonicalize implementations are from tower-lsp-community/tower-lsp-server
 // available under the MIT License or Apache 2.0 License.
 //
@@ -334,11 +596,36 @@ fn strict_canonicalize<P: AsRef<Path>>(path: P) -> std::io::Result<PathBuf> {
     impl_(&canon)
 }
 
+/// Whether `value` is displayed as `expected`, without allocating a string for it.
+fn display_eq(value: &dyn fmt::Display, expected: &str) -> bool {
+    struct Matcher<'a>(&'a str);
+
+    impl fmt::Write for Matcher<'_> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0 = self.0.strip_prefix(s).ok_or(fmt::Error)?;
+            Ok(())
+        }
+    }
+
+    let mut matcher = Matcher(expected);
+    fmt::write(&mut matcher, format_args!("{value}")).is_ok() && matcher.0.is_empty()
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::service::from_file_path;
+    use crate::OxcCode;
+    use crate::service::{display_eq, from_file_path};
     use std::path::PathBuf;
 
+    #[test]
+    fn test_display_eq() {
+        let code = OxcCode { scope: Some("eslint".into()), number: Some("no-console".into()) };
+        assert!(display_eq(&code, "eslint(no-console)"));
+        assert!(!display_eq(&code, "eslint(no-console-log)"));
+        assert!(!display_eq(&code, "eslint(no-con"));
+        assert!(!display_eq(&code, "eslint"));
+    }
+
     fn with_schema(path: &str) -> String {
         const EXPECTED_SCHEMA: &str = if cfg!(windows) { "file:///" } else { "file://" };
         format!("{EXPECTED_SCHEMA}{path}")
@@ -88,14 +88,16 @@ impl<'a> MayHaveSideEffects<'a> for Declaration<'a> {
     fn may_have_side_effects(&self, ctx: &impl MayHaveSideEffectsContext<'a>) -> bool {
         match self {
             Declaration::VariableDeclaration(var_decl) => var_decl.may_have_side_effects(ctx),
-            Declaration::FunctionDeclaration(_) => false,
             Declaration::ClassDeclaration(class_decl) => class_decl.may_have_side_effects(ctx),
-            Declaration::TSEnumDeclaration(_)
-            | Declaration::TSImportEqualsDeclaration(_)
-            | Declaration::TSModuleDeclaration(_)
+            // TypeScript declarations are usually stripped before this is called,
+            // but linters ask about untransformed code.
+            Declaration::FunctionDeclaration(_)
             | Declaration::TSGlobalDeclaration(_)
             | Declaration::TSInterfaceDeclaration(_)
-            | Declaration::TSTypeAliasDeclaration(_) => unreachable!(),
+            | Declaration::TSTypeAliasDeclaration(_) => false,
+            Declaration::TSEnumDeclaration(_)
+            | Declaration::TSImportEqualsDeclaration(_)
+            | Declaration::TSModuleDeclaration(_) => true,
         }
     }
 }
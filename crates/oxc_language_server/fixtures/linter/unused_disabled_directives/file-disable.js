@@ -0,0 +1,3 @@
+/* eslint-disable */
+console.log("first");
+debugger;
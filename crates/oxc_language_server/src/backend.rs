@@ -1,9 +1,16 @@
-use std::{str::FromStr, sync::Arc};
+use std::{
+    str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
 
 use futures::future::join_all;
 use log::{debug, info, warn};
 use rustc_hash::FxBuildHasher;
-use tokio::sync::{OnceCell, RwLock, SetError};
+use serde::Serialize;
+use tokio::sync::{Mutex, OnceCell, RwLock, SetError, mpsc};
 use tower_lsp_server::{
     Client, LanguageServer,
     jsonrpc::{Error, ErrorCode, Result},
@@ -59,6 +66,32 @@ pub struct Backend {
     // The client will send the content of in-memory files on `textDocument/didOpen` and `textDocument/didChange`.
     // This is only needed when the client supports `textDocument/formatting` request.
     file_system: Arc<RwLock<LSPFileSystem>>,
+    // Cancellation flag of the running `oxc/workspaceDiagnostic` request, if any.
+    workspace_diagnostic_run: Mutex<Option<Arc<AtomicBool>>>,
+}
+
+/// Custom request to lint all files of every workspace folder, not only the opened files.
+/// The diagnostics are published with `textDocument/publishDiagnostics` for each file as soon as it is linted.
+/// A new request, `workspace/didChangeConfiguration`, `workspace/didChangeWatchedFiles`,
+/// `workspace/didChangeWorkspaceFolders` and `shutdown` cancel a running request.
+pub const WORKSPACE_DIAGNOSTIC_METHOD: &str = "oxc/workspaceDiagnostic";
+
+/// Response of the [`WORKSPACE_DIAGNOSTIC_METHOD`] request.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceDiagnosticResult {
+    /// Number of files whose diagnostics were published.
+    pub file_count: usize,
+}
+
+/// Cancels the workspace diagnostic run when the request future is dropped,
+/// e.g. because the client sent `$/cancelRequest`.
+struct CancelOnDrop(Arc<AtomicBool>);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
 }
 
 impl LanguageServer for Backend {
@@ -219,6 +252,7 @@ impl LanguageServer for Backend {
     ///
     /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#shutdown>
     async fn shutdown(&self) -> Result<()> {
+        self.cancel_workspace_diagnostic().await;
         let mut clearing_diagnostics = Vec::new();
         let mut removed_registrations = Vec::new();
 
@@ -245,6 +279,7 @@ impl LanguageServer for Backend {
     ///
     /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#workspace_didChangeConfiguration>
     async fn did_change_configuration(&self, params: DidChangeConfigurationParams) {
+        self.cancel_workspace_diagnostic().await;
         let workers = self.workspace_workers.read().await;
         let mut new_diagnostics = Vec::new();
         let mut removing_registrations = vec![];
@@ -337,6 +372,7 @@ impl LanguageServer for Backend {
     ///
     /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#workspace_didChangeWatchedFiles>
     async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        self.cancel_workspace_diagnostic().await;
         let workers = self.workspace_workers.read().await;
         // ToDo: what if an empty changes flag is passed?
         debug!("watched file did change");
@@ -387,6 +423,7 @@ impl LanguageServer for Backend {
     ///
     /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#workspace_didChangeWorkspaceFolders>
     async fn did_change_workspace_folders(&self, params: DidChangeWorkspaceFoldersParams) {
+        self.cancel_workspace_diagnostic().await;
         let mut workers = self.workspace_workers.write().await;
         let mut cleared_diagnostics = vec![];
         let mut added_registrations = vec![];
@@ -607,6 +644,70 @@ impl Backend {
             workspace_workers: Arc::new(RwLock::new(vec![])),
             capabilities: OnceCell::new(),
             file_system: Arc::new(RwLock::new(LSPFileSystem::default())),
+            workspace_diagnostic_run: Mutex::new(None),
+        }
+    }
+
+    /// Lint all files of every workspace folder in a blocking task
+    /// and publish the diagnostics of each file as soon as it is linted.
+    /// See [`WORKSPACE_DIAGNOSTIC_METHOD`].
+    ///
+    /// # Errors
+    /// Returns a `RequestCancelled` error if the run was cancelled before all files were linted.
+    pub async fn workspace_diagnostic(&self) -> Result<WorkspaceDiagnosticResult> {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let previous = self.workspace_diagnostic_run.lock().await.replace(Arc::clone(&cancelled));
+        if let Some(previous) = previous {
+            previous.store(true, Ordering::Relaxed);
+        }
+        let _cancel_on_drop = CancelOnDrop(Arc::clone(&cancelled));
+
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let workers = Arc::clone(&self.workspace_workers);
+        let file_system = Arc::clone(&self.file_system);
+        let run_cancelled = Arc::clone(&cancelled);
+        let run = tokio::task::spawn_blocking(move || {
+            // do not hold the lock for the whole run, opening a file needs to write to it
+            let read_content = |uri: &Uri| file_system.blocking_read().get(uri);
+            for worker in workers.blocking_read().iter() {
+                worker.run_workspace_diagnostic(
+                    &read_content,
+                    &run_cancelled,
+                    &mut |uri, diagnostics| {
+                        // the receiver is only dropped when the request is cancelled
+                        let _ = sender.send((uri, diagnostics));
+                    },
+                );
+            }
+        });
+
+        let mut file_count = 0;
+        while let Some((uri, diagnostics)) = receiver.recv().await {
+            self.client.publish_diagnostics(uri, diagnostics, None).await;
+            file_count += 1;
+        }
+        if let Err(err) = run.await {
+            warn!("workspace diagnostic run failed: {err}");
+            return Err(Error::internal_error());
+        }
+
+        let mut current_run = self.workspace_diagnostic_run.lock().await;
+        if current_run.as_ref().is_some_and(|current| Arc::ptr_eq(current, &cancelled)) {
+            *current_run = None;
+        }
+        drop(current_run);
+
+        if cancelled.load(Ordering::Relaxed) {
+            return Err(Error::request_cancelled());
+        }
+        Ok(WorkspaceDiagnosticResult { file_count })
+    }
+
+    /// Cancel the running [`WORKSPACE_DIAGNOSTIC_METHOD`] request, if any.
+    async fn cancel_workspace_diagnostic(&self) {
+        let run = self.workspace_diagnostic_run.lock().await.take();
+        if let Some(run) = run {
+            run.store(true, Ordering::Relaxed);
         }
     }
 
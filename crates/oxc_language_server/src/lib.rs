@@ -13,7 +13,7 @@ mod tool;
 mod utils;
 mod worker;
 
-use crate::backend::Backend;
+use crate::backend::{Backend, WORKSPACE_DIAGNOSTIC_METHOD};
 #[cfg(feature = "formatter")]
 pub use crate::formatter::ServerFormatterBuilder;
 #[cfg(feature = "linter")]
@@ -29,7 +29,9 @@ pub async fn run_server(tools: Vec<Box<dyn ToolBuilder>>) {
     let stdin = tokio::io::stdin();
     let stdout = tokio::io::stdout();
 
-    let (service, socket) = LspService::build(|client| Backend::new(client, tools)).finish();
+    let (service, socket) = LspService::build(|client| Backend::new(client, tools))
+        .custom_method(WORKSPACE_DIAGNOSTIC_METHOD, Backend::workspace_diagnostic)
+        .finish();
 
     Server::new(stdin, stdout, socket).serve(service).await;
 }
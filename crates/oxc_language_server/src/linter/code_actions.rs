@@ -1,11 +1,38 @@
-use log::debug;
 use tower_lsp_server::lsp_types::{CodeAction, CodeActionKind, TextEdit, Uri, WorkspaceEdit};
 
-use crate::linter::error_with_position::{FixedContent, PossibleFixContent};
+use oxc_linter::FixKind;
+
+use crate::linter::error_with_position::{FixedContent, PossibleFixContent, SuppressionEdit};
 
 pub const CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC: CodeActionKind =
     CodeActionKind::new("source.fixAll.oxc");
 
+/// Suggestions may change the meaning of the code, so they are not offered as plain quick fixes.
+pub const CODE_ACTION_KIND_QUICKFIX_SUGGESTION_OXC: CodeActionKind =
+    CodeActionKind::new("quickfix.oxc.suggestion");
+
+/// Dangerous fixes and suggestions may break the code.
+pub const CODE_ACTION_KIND_QUICKFIX_DANGEROUS_OXC: CodeActionKind =
+    CodeActionKind::new("quickfix.oxc.dangerous");
+
+/// Whether the fix is safe to apply without review, i.e. it may be preferred and is part of "fix all".
+fn is_safe_fix(kind: FixKind) -> bool {
+    kind == FixKind::SafeFix
+}
+
+/// The code action kind and the title prefix for fixes of `kind`.
+fn code_action_kind_and_label(kind: FixKind) -> (CodeActionKind, Option<&'static str>) {
+    if kind.is_dangerous() {
+        let label =
+            if kind.contains(FixKind::Fix) { "Dangerous fix" } else { "Dangerous suggestion" };
+        (CODE_ACTION_KIND_QUICKFIX_DANGEROUS_OXC, Some(label))
+    } else if kind.contains(FixKind::Suggestion) {
+        (CODE_ACTION_KIND_QUICKFIX_SUGGESTION_OXC, Some("Suggestion"))
+    } else {
+        (CodeActionKind::QUICKFIX, None)
+    }
+}
+
 fn fix_content_to_code_action(
     fixed_content: &FixedContent,
     uri: &Uri,
@@ -26,10 +53,17 @@ fn fix_content_to_code_action(
         }
     };
 
+    // Suggestions and dangerous fixes are labeled, so they do not look as safe as a fix.
+    let (kind, label) = code_action_kind_and_label(fixed_content.kind);
+    let title = match label {
+        Some(label) => format!("{label}: {title}"),
+        None => title,
+    };
+
     CodeAction {
         title,
-        kind: Some(CodeActionKind::QUICKFIX),
-        is_preferred: Some(is_preferred),
+        kind: Some(kind),
+        is_preferred: Some(is_preferred && is_safe_fix(fixed_content.kind)),
         edit: Some(WorkspaceEdit {
             #[expect(clippy::disallowed_types)]
             changes: Some(std::collections::HashMap::from([(
@@ -77,6 +111,30 @@ pub fn apply_fix_code_actions(
     }
 }
 
+/// Code actions to suppress a diagnostic, offered after its fixes. They are never preferred.
+pub fn apply_suppression_code_actions(
+    suppressions: &[SuppressionEdit],
+    uri: &Uri,
+) -> impl Iterator<Item = CodeAction> {
+    suppressions.iter().map(|suppression| CodeAction {
+        title: suppression.title.clone(),
+        kind: Some(CodeActionKind::QUICKFIX),
+        is_preferred: Some(false),
+        edit: Some(WorkspaceEdit {
+            #[expect(clippy::disallowed_types)]
+            changes: Some(std::collections::HashMap::from([(
+                uri.clone(),
+                vec![suppression.edit.clone()],
+            )])),
+            ..WorkspaceEdit::default()
+        }),
+        disabled: None,
+        data: None,
+        diagnostics: None,
+        command: None,
+    })
+}
+
 pub fn apply_all_fix_code_action<'a>(
     reports: impl Iterator<Item = &'a PossibleFixContent>,
     uri: &Uri,
@@ -116,32 +174,12 @@ pub fn fix_all_text_edit<'a>(
             PossibleFixContent::Single(fixed_content) => Some(fixed_content),
             // For multiple fixes, we take the first one as a representative fix.
             // Applying all possible fixes at once is not possible in this context.
-            PossibleFixContent::Multiple(multi) => {
-                // for a real linter fix, we expect at least 3 fixes
-                if multi.len() > 2 {
-                    multi.first()
-                } else {
-                    debug!("Multiple fixes found, but only ignore fixes available");
-                    #[cfg(debug_assertions)]
-                    {
-                        if !multi.is_empty() {
-                            debug_assert!(multi[0].message.as_ref().is_some());
-                            debug_assert!(
-                                multi[0].message.as_ref().unwrap().starts_with("Disable")
-                            );
-                            debug_assert!(
-                                multi[0].message.as_ref().unwrap().ends_with("for this line")
-                            );
-                        }
-                    }
-
-                    // this fix is only for "ignore this line/file" fixes
-                    // do not apply them for "fix all" code action
-                    None
-                }
-            }
+            PossibleFixContent::Multiple(multi) => multi.first(),
         };
 
+        // Suggestions and dangerous fixes need a review, they are never applied with "fix all".
+        let fix = fix.filter(|fixed_content| is_safe_fix(fixed_content.kind));
+
         if let Some(fixed_content) = &fix {
             // when source.fixAll.oxc we collect all changes at ones
             // and return them as one workspace edit.
@@ -156,3 +194,47 @@ pub fn fix_all_text_edit<'a>(
 
     text_edits
 }
+
+#[cfg(test)]
+mod test {
+    use tower_lsp_server::lsp_types::{Position, Range};
+
+    use oxc_linter::FixKind;
+
+    use super::{fix_all_text_edit, is_safe_fix};
+    use crate::linter::error_with_position::{FixedContent, PossibleFixContent};
+
+    fn fixed_content(code: &str, kind: FixKind) -> FixedContent {
+        FixedContent {
+            message: None,
+            code: code.to_string(),
+            range: Range::new(Position::new(0, 0), Position::new(0, 1)),
+            kind,
+        }
+    }
+
+    #[test]
+    fn fix_all_skips_suggestions_and_dangerous_fixes() {
+        let reports = [
+            PossibleFixContent::Single(fixed_content("safe", FixKind::SafeFix)),
+            PossibleFixContent::Single(fixed_content("suggestion", FixKind::Suggestion)),
+            PossibleFixContent::Single(fixed_content("dangerous", FixKind::DangerousFix)),
+            PossibleFixContent::Multiple(vec![
+                fixed_content("suggestion", FixKind::Suggestion),
+                fixed_content("other suggestion", FixKind::Suggestion),
+            ]),
+        ];
+
+        let text_edits = fix_all_text_edit(reports.iter());
+        assert_eq!(text_edits.len(), 1);
+        assert_eq!(text_edits[0].new_text, "safe");
+    }
+
+    #[test]
+    fn safe_fix_kinds() {
+        assert!(is_safe_fix(FixKind::SafeFix));
+        assert!(!is_safe_fix(FixKind::Suggestion));
+        assert!(!is_safe_fix(FixKind::DangerousFix));
+        assert!(!is_safe_fix(FixKind::DangerousSuggestion));
+    }
+}
@@ -1,18 +1,51 @@
 use std::str::FromStr;
 
+use serde::{Deserialize, Serialize};
 use tower_lsp_server::lsp_types::{
     self, CodeDescription, Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity,
-    NumberOrString, Position, Range, Uri,
+    NumberOrString, Position, Range, TextEdit, Uri,
 };
 
 use oxc_data_structures::rope::{Rope, get_line_column};
 use oxc_diagnostics::{OxcCode, Severity};
-use oxc_linter::{Fix, Message, PossibleFixes};
+use oxc_linter::{Fix, FixKind, Message, PARSE_ERROR_RULE_NAME, PossibleFixes, RuleOrigin};
 
 #[derive(Debug, Clone, Default)]
 pub struct DiagnosticReport {
     pub diagnostic: Diagnostic,
     pub fixed_content: PossibleFixContent,
+    /// Edits which suppress the diagnostic, also sent to clients in [`Diagnostic::data`].
+    pub suppressions: Vec<SuppressionEdit>,
+}
+
+/// Data of a [`Diagnostic`] sent to clients, see [`Diagnostic::data`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiagnosticData {
+    /// Edits which suppress the diagnostic with a disable directive, so that clients can offer
+    /// them without knowing the syntax of directives.
+    pub suppressions: Vec<SuppressionEdit>,
+    /// The configuration which decided the severity of the rule reporting the diagnostic.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<RuleOrigin>,
+}
+
+/// An edit inserting a disable directive, e.g. `// oxlint-disable-next-line no-console`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuppressionEdit {
+    /// Title of the edit, e.g. `Disable no-console for this line`
+    pub title: String,
+    pub scope: SuppressionScope,
+    pub edit: TextEdit,
+}
+
+/// Where a [`SuppressionEdit`] disables the rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SuppressionScope {
+    /// The line of the diagnostic
+    Line,
+    /// The whole file, or the section of the file for frameworks like Vue
+    File,
 }
 
 #[derive(Debug, Clone)]
@@ -20,6 +53,8 @@ pub struct FixedContent {
     pub message: Option<String>,
     pub code: String,
     pub range: Range,
+    /// Kind of the fix, used to tell safe fixes apart from suggestions and dangerous fixes.
+    pub kind: FixKind,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -89,7 +124,7 @@ pub fn message_to_lsp_diagnostic(
         None => message.error.message.to_string(),
     };
 
-    let diagnostic = Diagnostic {
+    let mut diagnostic = Diagnostic {
         range,
         severity,
         code: Some(NumberOrString::String(code)),
@@ -112,27 +147,34 @@ pub fn message_to_lsp_diagnostic(
         ),
     };
 
-    // Add ignore fixes
     let error_offset = message.span.start;
     let section_offset = message.section_offset;
 
     // If the error is exactly at the section offset and has 0 span length, it means that the file is the problem
     // and attaching a ignore comment would not ignore the error.
     // This is because the ignore comment would need to be placed before the error offset, which is not possible.
-    if error_offset == section_offset && message.span.end == section_offset {
-        return DiagnosticReport { diagnostic, fixed_content };
-    }
+    let suppressions = if error_offset == section_offset && message.span.end == section_offset {
+        vec![]
+    } else {
+        suppression_edits(
+            &fixed_content,
+            &message.error.code,
+            error_offset,
+            section_offset,
+            rope,
+            source_text,
+        )
+    };
 
-    let fixed_content = add_ignore_fixes(
-        fixed_content,
-        &message.error.code,
-        error_offset,
-        section_offset,
-        rope,
-        source_text,
-    );
+    if !suppressions.is_empty() || message.origin.is_some() {
+        diagnostic.data = serde_json::to_value(DiagnosticData {
+            suppressions: suppressions.clone(),
+            origin: message.origin.clone(),
+        })
+        .ok();
+    }
 
-    DiagnosticReport { diagnostic, fixed_content }
+    DiagnosticReport { diagnostic, fixed_content, suppressions }
 }
 
 fn fix_to_fixed_content(fix: &Fix, rope: &Rope, source_text: &str) -> FixedContent {
@@ -143,6 +185,7 @@ fn fix_to_fixed_content(fix: &Fix, rope: &Rope, source_text: &str) -> FixedConte
         message: fix.message.as_ref().map(std::string::ToString::to_string),
         code: fix.content.to_string(),
         range: Range::new(start_position, end_position),
+        kind: fix.kind,
     }
 }
 
@@ -181,6 +224,7 @@ pub fn generate_inverted_diagnostics(
                     data: None,
                 },
                 fixed_content: PossibleFixContent::None,
+                suppressions: vec![],
             });
         }
     }
@@ -192,50 +236,35 @@ pub fn offset_to_position(rope: &Rope, offset: u32, source_text: &str) -> Positi
     Position::new(line, column)
 }
 
-/// Add "ignore this line" and "ignore this rule" fixes to the existing fixes.
-/// These fixes will be added to the end of the existing fixes.
-/// If the existing fixes already contain an "remove unused disable directive" fix,
-/// then no ignore fixes will be added.
-fn add_ignore_fixes(
-    fixes: PossibleFixContent,
+/// "Disable for this line" and "disable for this whole file" edits for the rule of a diagnostic.
+/// No edits are returned for diagnostics without a rule, for syntax errors which cannot be
+/// disabled, or if the fix of the diagnostic already is to "remove unused disable directive".
+fn suppression_edits(
+    fixes: &PossibleFixContent,
     code: &OxcCode,
     error_offset: u32,
     section_offset: u32,
     rope: &Rope,
     source_text: &str,
-) -> PossibleFixContent {
+) -> Vec<SuppressionEdit> {
     // do not append ignore code actions when the error is the ignore action
-    if matches!(fixes, PossibleFixContent::Single(ref fix) if fix.message.as_ref().is_some_and(|message| message.starts_with("remove unused disable directive")))
+    if matches!(fixes, PossibleFixContent::Single(fix) if fix.message.as_ref().is_some_and(|message| message.starts_with("remove unused disable directive")))
     {
-        return fixes;
-    }
-
-    let mut new_fixes: Vec<FixedContent> = vec![];
-    if let PossibleFixContent::Single(fix) = fixes {
-        new_fixes.push(fix);
-    } else if let PossibleFixContent::Multiple(existing_fixes) = fixes {
-        new_fixes.extend(existing_fixes);
+        return vec![];
     }
 
-    if let Some(rule_name) = code.number.as_ref() {
-        // TODO: doesn't support disabling multiple rules by name for a given line.
-        new_fixes.push(disable_for_this_line(
-            rule_name,
-            error_offset,
-            section_offset,
-            rope,
-            source_text,
-        ));
-        new_fixes.push(disable_for_this_section(rule_name, section_offset, rope, source_text));
+    let Some(rule_name) = code.number.as_ref() else {
+        return vec![];
+    };
+    if rule_name == PARSE_ERROR_RULE_NAME {
+        return vec![];
     }
 
-    if new_fixes.is_empty() {
-        PossibleFixContent::None
-    } else if new_fixes.len() == 1 {
-        PossibleFixContent::Single(new_fixes.remove(0))
-    } else {
-        PossibleFixContent::Multiple(new_fixes)
-    }
+    // TODO: doesn't support disabling multiple rules by name for a given line.
+    vec![
+        disable_for_this_line(rule_name, error_offset, section_offset, rope, source_text),
+        disable_for_this_section(rule_name, section_offset, rope, source_text),
+    ]
 }
 
 fn disable_for_this_line(
@@ -244,7 +273,7 @@ fn disable_for_this_line(
     section_offset: u32,
     rope: &Rope,
     source_text: &str,
-) -> FixedContent {
+) -> SuppressionEdit {
     let bytes = source_text.as_bytes();
     // Find the line break before the error
     let mut line_break_offset = error_offset;
@@ -276,33 +305,76 @@ fn disable_for_this_line(
     let whitespace_string = String::from_utf8_lossy(whitespace_range);
 
     let position = offset_to_position(rope, insert_offset, source_text);
-    FixedContent {
-        message: Some(format!("Disable {rule_name} for this line")),
-        code: format!(
-            "{content_prefix}{whitespace_string}// oxlint-disable-next-line {rule_name}\n"
-        ),
-        range: Range::new(position, position),
+    SuppressionEdit {
+        title: format!("Disable {rule_name} for this line"),
+        scope: SuppressionScope::Line,
+        edit: TextEdit {
+            range: Range::new(position, position),
+            new_text: format!(
+                "{content_prefix}{whitespace_string}// oxlint-disable-next-line {rule_name}\n"
+            ),
+        },
     }
 }
 
+/// Add the rule to the directive disabling rules at the start of the section, or insert a new
+/// directive if there is none.
 fn disable_for_this_section(
     rule_name: &str,
     section_offset: u32,
     rope: &Rope,
     source_text: &str,
-) -> FixedContent {
-    let comment = format!("// oxlint-disable {rule_name}\n");
-
-    let (content_prefix, insert_offset) =
-        get_section_insert_position(section_offset, section_offset, source_text.as_bytes());
+) -> SuppressionEdit {
+    let (content, insert_offset) =
+        if let Some(rules_end) = section_disable_directive_end(source_text, section_offset) {
+            (format!(", {rule_name}"), rules_end)
+        } else {
+            let (content_prefix, insert_offset) =
+                get_section_insert_position(section_offset, section_offset, source_text.as_bytes());
+            (format!("{content_prefix}// oxlint-disable {rule_name}\n"), insert_offset)
+        };
 
-    let content = format!("{content_prefix}{comment}");
     let position = offset_to_position(rope, insert_offset, source_text);
 
-    FixedContent {
-        message: Some(format!("Disable {rule_name} for this whole file")),
-        code: content,
-        range: Range::new(position, position),
+    SuppressionEdit {
+        title: format!("Disable {rule_name} for this whole file"),
+        scope: SuppressionScope::File,
+        edit: TextEdit { range: Range::new(position, position), new_text: content },
+    }
+}
+
+/// Find a directive disabling rules among the comments at the start of the section, such as
+/// `/* oxlint-disable no-console, no-debugger */`, and return the offset after its last rule.
+#[expect(clippy::cast_possible_truncation)]
+fn section_disable_directive_end(source_text: &str, section_offset: u32) -> Option<u32> {
+    let mut rest = &source_text[section_offset as usize..];
+    loop {
+        let trimmed = rest.trim_start();
+        let (comment, after) = if let Some(comment) = trimmed.strip_prefix("//") {
+            comment.split_at(comment.find(['\n', '\r']).unwrap_or(comment.len()))
+        } else if let Some(comment) = trimmed.strip_prefix("/*") {
+            let end = comment.find("*/")?;
+            (&comment[..end], &comment[end + 2..])
+        } else {
+            return None;
+        };
+
+        let directive = comment.trim_start();
+        // `oxlint-disable-next-line` and `oxlint-disable-line` are not followed by whitespace
+        if let Some(rules) = directive
+            .strip_prefix("oxlint-disable")
+            .or_else(|| directive.strip_prefix("eslint-disable"))
+            .filter(|rules| rules.starts_with(char::is_whitespace))
+        {
+            // ignore the description, e.g. `oxlint-disable no-console -- reason`
+            let rules = rules.split("--").next().unwrap_or(rules).trim_end();
+            // `oxlint-disable` without rules disables all rules
+            if !rules.trim_start().is_empty() {
+                let rules_start = rules.as_ptr() as usize - source_text.as_ptr() as usize;
+                return Some((rules_start + rules.len()) as u32);
+            }
+        }
+        rest = after;
     }
 }
 
@@ -349,9 +421,15 @@ fn get_section_insert_position(
 
 #[cfg(test)]
 mod test {
+    use std::{path::PathBuf, str::FromStr};
+
+    use tower_lsp_server::lsp_types::{Position, Uri};
+
     use oxc_data_structures::rope::Rope;
+    use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
+    use oxc_linter::{Message, PossibleFixes, RuleOrigin, RuleOriginKind};
 
-    use super::offset_to_position;
+    use super::{DiagnosticData, SuppressionScope, message_to_lsp_diagnostic, offset_to_position};
 
     #[test]
     fn single_line() {
@@ -394,9 +472,9 @@ mod test {
         let rope = Rope::from_str(source);
         let fix = super::disable_for_this_section("no-console", 0, &rope, source);
 
-        assert_eq!(fix.code, "// oxlint-disable no-console\n");
-        assert_eq!(fix.range.start.line, 0);
-        assert_eq!(fix.range.start.character, 0);
+        assert_eq!(fix.edit.new_text, "// oxlint-disable no-console\n");
+        assert_eq!(fix.edit.range.start.line, 0);
+        assert_eq!(fix.edit.range.start.character, 0);
     }
 
     #[test]
@@ -405,9 +483,9 @@ mod test {
         let rope = Rope::from_str(source);
         let fix = super::disable_for_this_section("no-console", 8, &rope, source);
 
-        assert_eq!(fix.code, "// oxlint-disable no-console\n");
-        assert_eq!(fix.range.start.line, 1);
-        assert_eq!(fix.range.start.character, 0);
+        assert_eq!(fix.edit.new_text, "// oxlint-disable no-console\n");
+        assert_eq!(fix.edit.range.start.line, 1);
+        assert_eq!(fix.edit.range.start.character, 0);
     }
 
     #[test]
@@ -416,9 +494,9 @@ mod test {
         let rope = Rope::from_str(source);
         let fix = super::disable_for_this_section("no-console", 8, &rope, source);
 
-        assert_eq!(fix.code, "// oxlint-disable no-console\n");
-        assert_eq!(fix.range.start.line, 1);
-        assert_eq!(fix.range.start.character, 0);
+        assert_eq!(fix.edit.new_text, "// oxlint-disable no-console\n");
+        assert_eq!(fix.edit.range.start.line, 1);
+        assert_eq!(fix.edit.range.start.character, 0);
     }
 
     #[test]
@@ -427,9 +505,9 @@ mod test {
         let rope = Rope::from_str(source);
         let fix = super::disable_for_this_section("no-unused-vars", 6, &rope, source);
 
-        assert_eq!(fix.code, "\n// oxlint-disable no-unused-vars\n");
-        assert_eq!(fix.range.start.line, 0);
-        assert_eq!(fix.range.start.character, 6);
+        assert_eq!(fix.edit.new_text, "\n// oxlint-disable no-unused-vars\n");
+        assert_eq!(fix.edit.range.start.line, 0);
+        assert_eq!(fix.edit.range.start.character, 6);
     }
 
     #[test]
@@ -438,9 +516,9 @@ mod test {
         let rope = Rope::from_str(source);
         let fix = super::disable_for_this_line("no-console", 0, 0, &rope, source);
 
-        assert_eq!(fix.code, "// oxlint-disable-next-line no-console\n");
-        assert_eq!(fix.range.start.line, 0);
-        assert_eq!(fix.range.start.character, 0);
+        assert_eq!(fix.edit.new_text, "// oxlint-disable-next-line no-console\n");
+        assert_eq!(fix.edit.range.start.line, 0);
+        assert_eq!(fix.edit.range.start.character, 0);
     }
 
     #[test]
@@ -449,9 +527,9 @@ mod test {
         let rope = Rope::from_str(source);
         let fix = super::disable_for_this_line("no-console", 10, 0, &rope, source);
 
-        assert_eq!(fix.code, "  // oxlint-disable-next-line no-console\n");
-        assert_eq!(fix.range.start.line, 0);
-        assert_eq!(fix.range.start.character, 0);
+        assert_eq!(fix.edit.new_text, "  // oxlint-disable-next-line no-console\n");
+        assert_eq!(fix.edit.range.start.line, 0);
+        assert_eq!(fix.edit.range.start.character, 0);
     }
 
     #[test]
@@ -460,9 +538,9 @@ mod test {
         let rope = Rope::from_str(source);
         let fix = super::disable_for_this_line("no-console", 10, 0, &rope, source);
 
-        assert_eq!(fix.code, "\t\t// oxlint-disable-next-line no-console\n");
-        assert_eq!(fix.range.start.line, 0);
-        assert_eq!(fix.range.start.character, 0);
+        assert_eq!(fix.edit.new_text, "\t\t// oxlint-disable-next-line no-console\n");
+        assert_eq!(fix.edit.range.start.line, 0);
+        assert_eq!(fix.edit.range.start.character, 0);
     }
 
     #[test]
@@ -471,9 +549,9 @@ mod test {
         let rope = Rope::from_str(source);
         let fix = super::disable_for_this_line("no-console", 12, 0, &rope, source);
 
-        assert_eq!(fix.code, "\t  \t// oxlint-disable-next-line no-console\n");
-        assert_eq!(fix.range.start.line, 0);
-        assert_eq!(fix.range.start.character, 0);
+        assert_eq!(fix.edit.new_text, "\t  \t// oxlint-disable-next-line no-console\n");
+        assert_eq!(fix.edit.range.start.line, 0);
+        assert_eq!(fix.edit.range.start.character, 0);
     }
 
     #[test]
@@ -482,9 +560,9 @@ mod test {
         let rope = Rope::from_str(source);
         let fix = super::disable_for_this_line("no-console", 27, 0, &rope, source);
 
-        assert_eq!(fix.code, "\t// oxlint-disable-next-line no-console\n");
-        assert_eq!(fix.range.start.line, 1);
-        assert_eq!(fix.range.start.character, 0);
+        assert_eq!(fix.edit.new_text, "\t// oxlint-disable-next-line no-console\n");
+        assert_eq!(fix.edit.range.start.line, 1);
+        assert_eq!(fix.edit.range.start.character, 0);
     }
 
     #[test]
@@ -493,9 +571,9 @@ mod test {
         let rope = Rope::from_str(source);
         let fix = super::disable_for_this_line("no-console", 30, 0, &rope, source);
 
-        assert_eq!(fix.code, "    // oxlint-disable-next-line no-console\n");
-        assert_eq!(fix.range.start.line, 1);
-        assert_eq!(fix.range.start.character, 0);
+        assert_eq!(fix.edit.new_text, "    // oxlint-disable-next-line no-console\n");
+        assert_eq!(fix.edit.range.start.line, 1);
+        assert_eq!(fix.edit.range.start.character, 0);
     }
 
     #[test]
@@ -504,9 +582,9 @@ mod test {
         let rope = Rope::from_str(source);
         let fix = super::disable_for_this_line("no-console", 33, 0, &rope, source);
 
-        assert_eq!(fix.code, "\t  \t  // oxlint-disable-next-line no-console\n");
-        assert_eq!(fix.range.start.line, 1);
-        assert_eq!(fix.range.start.character, 0);
+        assert_eq!(fix.edit.new_text, "\t  \t  // oxlint-disable-next-line no-console\n");
+        assert_eq!(fix.edit.range.start.line, 1);
+        assert_eq!(fix.edit.range.start.character, 0);
     }
 
     #[test]
@@ -515,9 +593,9 @@ mod test {
         let rope = Rope::from_str(source);
         let fix = super::disable_for_this_line("no-console", 26, 0, &rope, source);
 
-        assert_eq!(fix.code, "// oxlint-disable-next-line no-console\n");
-        assert_eq!(fix.range.start.line, 1);
-        assert_eq!(fix.range.start.character, 0);
+        assert_eq!(fix.edit.new_text, "// oxlint-disable-next-line no-console\n");
+        assert_eq!(fix.edit.range.start.line, 1);
+        assert_eq!(fix.edit.range.start.character, 0);
     }
 
     #[test]
@@ -526,9 +604,9 @@ mod test {
         let rope = Rope::from_str(source);
         let fix = super::disable_for_this_line("no-console", 28, 0, &rope, source);
 
-        assert_eq!(fix.code, "\t// oxlint-disable-next-line no-console\n");
-        assert_eq!(fix.range.start.line, 1);
-        assert_eq!(fix.range.start.character, 0);
+        assert_eq!(fix.edit.new_text, "\t// oxlint-disable-next-line no-console\n");
+        assert_eq!(fix.edit.range.start.line, 1);
+        assert_eq!(fix.edit.range.start.character, 0);
     }
 
     #[test]
@@ -537,9 +615,9 @@ mod test {
         let rope = Rope::from_str(source);
         let fix = super::disable_for_this_line("no-console", 40, 0, &rope, source);
 
-        assert_eq!(fix.code, "\t\t\t// oxlint-disable-next-line no-console\n");
-        assert_eq!(fix.range.start.line, 2);
-        assert_eq!(fix.range.start.character, 0);
+        assert_eq!(fix.edit.new_text, "\t\t\t// oxlint-disable-next-line no-console\n");
+        assert_eq!(fix.edit.range.start.line, 2);
+        assert_eq!(fix.edit.range.start.character, 0);
     }
 
     #[test]
@@ -548,9 +626,9 @@ mod test {
         let rope = Rope::from_str(source);
         let fix = super::disable_for_this_line("no-console", 0, 0, &rope, source);
 
-        assert_eq!(fix.code, "// oxlint-disable-next-line no-console\n");
-        assert_eq!(fix.range.start.line, 0);
-        assert_eq!(fix.range.start.character, 0);
+        assert_eq!(fix.edit.new_text, "// oxlint-disable-next-line no-console\n");
+        assert_eq!(fix.edit.range.start.line, 0);
+        assert_eq!(fix.edit.range.start.character, 0);
     }
 
     #[test]
@@ -562,9 +640,9 @@ mod test {
         let fix = super::disable_for_this_line("no-console", 21, 0, &rope, source);
 
         // Should only capture "  \t" at the beginning, not the spaces around "here"
-        assert_eq!(fix.code, "  \t// oxlint-disable-next-line no-console\n");
-        assert_eq!(fix.range.start.line, 1);
-        assert_eq!(fix.range.start.character, 0);
+        assert_eq!(fix.edit.new_text, "  \t// oxlint-disable-next-line no-console\n");
+        assert_eq!(fix.edit.range.start.line, 1);
+        assert_eq!(fix.edit.range.start.character, 0);
     }
 
     #[test]
@@ -577,9 +655,9 @@ mod test {
         let fix =
             super::disable_for_this_line("no-console", error_offset, section_offset, &rope, source);
 
-        assert_eq!(fix.code, "// oxlint-disable-next-line no-console\n");
-        assert_eq!(fix.range.start.line, 1);
-        assert_eq!(fix.range.start.character, 0);
+        assert_eq!(fix.edit.new_text, "// oxlint-disable-next-line no-console\n");
+        assert_eq!(fix.edit.range.start.line, 1);
+        assert_eq!(fix.edit.range.start.character, 0);
     }
 
     #[test]
@@ -592,9 +670,9 @@ mod test {
         let fix =
             super::disable_for_this_line("no-console", error_offset, section_offset, &rope, source);
 
-        assert_eq!(fix.code, "\n// oxlint-disable-next-line no-console\n");
-        assert_eq!(fix.range.start.line, 0);
-        assert_eq!(fix.range.start.character, 8);
+        assert_eq!(fix.edit.new_text, "\n// oxlint-disable-next-line no-console\n");
+        assert_eq!(fix.edit.range.start.line, 0);
+        assert_eq!(fix.edit.range.start.character, 8);
     }
 
     #[test]
@@ -607,9 +685,9 @@ mod test {
         let fix =
             super::disable_for_this_line("no-console", error_offset, section_offset, &rope, source);
 
-        assert_eq!(fix.code, "  // oxlint-disable-next-line no-console\n");
-        assert_eq!(fix.range.start.line, 3);
-        assert_eq!(fix.range.start.character, 0);
+        assert_eq!(fix.edit.new_text, "  // oxlint-disable-next-line no-console\n");
+        assert_eq!(fix.edit.range.start.line, 3);
+        assert_eq!(fix.edit.range.start.character, 0);
     }
 
     #[test]
@@ -622,9 +700,122 @@ mod test {
         let fix =
             super::disable_for_this_line("no-console", error_offset, section_offset, &rope, source);
 
-        assert_eq!(fix.code, "// oxlint-disable-next-line no-console\n");
-        assert_eq!(fix.range.start.line, 1);
-        assert_eq!(fix.range.start.character, 0);
+        assert_eq!(fix.edit.new_text, "// oxlint-disable-next-line no-console\n");
+        assert_eq!(fix.edit.range.start.line, 1);
+        assert_eq!(fix.edit.range.start.character, 0);
+    }
+
+    #[test]
+    fn disable_for_section_existing_directive() {
+        let source = "/* oxlint-disable no-debugger */\nconsole.log('hello');";
+        let rope = Rope::from_str(source);
+        let fix = super::disable_for_this_section("no-console", 0, &rope, source);
+
+        assert_eq!(fix.edit.new_text, ", no-console");
+        assert_eq!(fix.edit.range.start, Position::new(0, 29));
+
+        // after other comments, and with a description
+        let source = "// @ts-check\n// eslint-disable no-debugger, eqeqeq -- legacy code\nconsole.log('hello');";
+        let rope = Rope::from_str(source);
+        let fix = super::disable_for_this_section("no-console", 0, &rope, source);
+
+        assert_eq!(fix.edit.new_text, ", no-console");
+        assert_eq!(fix.edit.range.start, Position::new(1, 37));
+
+        // in a section of a framework file
+        let source = "<script>\n// oxlint-disable no-debugger\nconsole.log('hello');";
+        let rope = Rope::from_str(source);
+        let fix = super::disable_for_this_section("no-console", 8, &rope, source);
+
+        assert_eq!(fix.edit.new_text, ", no-console");
+        assert_eq!(fix.edit.range.start, Position::new(1, 29));
+    }
+
+    #[test]
+    fn disable_for_section_no_rule_directive() {
+        // directives disabling all rules or the next line, and directives after code are not changed
+        for source in [
+            "/* oxlint-disable */\nconsole.log('hello');",
+            "/* oxlint-disable -- reason */\nconsole.log('hello');",
+            "// oxlint-disable-next-line no-debugger\nconsole.log('hello');",
+            "console.log('hello');\n// oxlint-disable no-debugger",
+        ] {
+            let rope = Rope::from_str(source);
+            let fix = super::disable_for_this_section("no-console", 0, &rope, source);
+
+            assert_eq!(fix.edit.new_text, "// oxlint-disable no-console\n", "{source}");
+            assert_eq!(fix.edit.range.start, Position::new(0, 0), "{source}");
+        }
+    }
+
+    #[test]
+    fn suppressions_in_diagnostic_data() {
+        let source = "  console.log('hello');";
+        let rope = Rope::from_str(source);
+        let uri = Uri::from_str("file:///test.js").unwrap();
+        let message = Message::new(
+            OxcDiagnostic::warn("Unexpected console statement.")
+                .with_error_code("eslint", "no-console")
+                .with_label(LabeledSpan::new(None, 2, 11)),
+            PossibleFixes::None,
+        );
+
+        let report = message_to_lsp_diagnostic(&message, &uri, source, &rope);
+
+        assert_eq!(report.suppressions.len(), 2);
+        assert_eq!(report.suppressions[0].scope, SuppressionScope::Line);
+        assert_eq!(
+            report.suppressions[0].edit.new_text,
+            "  // oxlint-disable-next-line no-console\n"
+        );
+        assert_eq!(report.suppressions[1].scope, SuppressionScope::File);
+        let data: DiagnosticData =
+            serde_json::from_value(report.diagnostic.data.clone().unwrap()).unwrap();
+        assert_eq!(data.suppressions, report.suppressions);
+        assert_eq!(data.origin, None);
+        assert_eq!(
+            report.diagnostic.data.unwrap()["suppressions"][0]["scope"],
+            serde_json::json!("line")
+        );
+
+        // diagnostics without a rule can not be suppressed
+        let message = Message::new(
+            OxcDiagnostic::warn("Unused eslint-disable directive.")
+                .with_label(LabeledSpan::new(None, 2, 11)),
+            PossibleFixes::None,
+        );
+        let report = message_to_lsp_diagnostic(&message, &uri, source, &rope);
+        assert!(report.suppressions.is_empty());
+        assert!(report.diagnostic.data.is_none());
+    }
+
+    #[test]
+    fn origin_in_diagnostic_data() {
+        let source = "debugger;";
+        let rope = Rope::from_str(source);
+        let uri = Uri::from_str("file:///test.js").unwrap();
+        let mut message = Message::new(
+            OxcDiagnostic::warn("`debugger` statement is not allowed")
+                .with_error_code("eslint", "no-debugger")
+                .with_label(LabeledSpan::new(None, 0, 9)),
+            PossibleFixes::None,
+        );
+        message.origin = Some(RuleOrigin {
+            kind: RuleOriginKind::Override,
+            config_path: Some(PathBuf::from("/project/.oxlintrc.json")),
+            override_index: Some(1),
+        });
+
+        let report = message_to_lsp_diagnostic(&message, &uri, source, &rope);
+
+        assert_eq!(
+            report.diagnostic.data.unwrap()["origin"],
+            serde_json::json!({
+                "kind": "override",
+                "configPath": "/project/.oxlintrc.json",
+                "overrideIndex": 1
+            })
+        );
     }
 
     fn assert_position(source: &str, offset: u32, expected: (u32, u32)) {
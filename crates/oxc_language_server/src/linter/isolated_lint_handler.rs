@@ -10,15 +10,23 @@ use tower_lsp_server::{UriExt, lsp_types::Uri};
 
 use oxc_allocator::Allocator;
 use oxc_linter::{
-    AllowWarnDeny, ConfigStore, DisableDirectives, Fix, FixKind, LINTABLE_EXTENSIONS, LintOptions,
-    LintRunner, LintRunnerBuilder, LintServiceOptions, Linter, Message, PossibleFixes,
-    RuleCommentType, RuntimeFileSystem, read_to_arena_str, read_to_string,
+    AllowWarnDeny, CancellationToken, ConfigStore, DisableDirectives, Fix, FixKind,
+    LINTABLE_EXTENSIONS, LintOptions, LintRunner, LintRunnerBuilder, LintServiceOptions, Linter,
+    Message, PossibleFixes, ProjectFiles, RuleCommentType, RuleResultCache, RuntimeFileSystem,
+    read_to_arena_str, read_to_string,
 };
 
 use super::error_with_position::{
     DiagnosticReport, generate_inverted_diagnostics, message_to_lsp_diagnostic,
 };
 
+/// Maximum number of files whose diagnostics are kept, to skip linting files which did not change.
+const LINT_CACHE_CAPACITY: usize = 1000;
+
+/// Maximum number of files whose diagnostics are kept per rule, to only run the rules whose
+/// configuration changed on files which did not change.
+pub const RULE_RESULT_CACHE_CAPACITY: usize = 1000;
+
 /// smaller subset of LintServiceOptions, which is used by IsolatedLintHandler
 #[derive(Debug, Clone)]
 pub struct IsolatedLintHandlerOptions {
@@ -27,11 +35,16 @@ pub struct IsolatedLintHandlerOptions {
     pub fix_kind: FixKind,
     pub root_path: PathBuf,
     pub tsconfig_path: Option<PathBuf>,
+    /// Shared with the handlers created for the next configurations.
+    pub rule_result_cache: Arc<RuleResultCache>,
 }
 
 pub struct IsolatedLintHandler {
     runner: LintRunner,
     unused_directives_severity: Option<AllowWarnDeny>,
+    rule_result_cache: Arc<RuleResultCache>,
+    /// Project files read by rules, see [`LintContext::read_project_file`](oxc_linter::LintContext::read_project_file)
+    project_files: Arc<ProjectFiles>,
 }
 
 pub struct IsolatedLintHandlerFileSystem {
@@ -63,6 +76,34 @@ impl RuntimeFileSystem for IsolatedLintHandlerFileSystem {
     }
 }
 
+/// Reads the in-memory content of opened files, and other files from the file system.
+struct WorkspaceFileSystem<'a> {
+    read_content: &'a (dyn Fn(&Path) -> Option<String> + Sync),
+}
+
+impl WorkspaceFileSystem<'_> {
+    fn read_to_string(&self, path: &Path) -> Option<String> {
+        (self.read_content)(path).or_else(|| read_to_string(path).ok())
+    }
+}
+
+impl RuntimeFileSystem for WorkspaceFileSystem<'_> {
+    fn read_to_arena_str<'a>(
+        &'a self,
+        path: &Path,
+        allocator: &'a Allocator,
+    ) -> Result<&'a str, std::io::Error> {
+        match (self.read_content)(path) {
+            Some(content) => Ok(allocator.alloc_str(&content)),
+            None => read_to_arena_str(path, allocator),
+        }
+    }
+
+    fn write_file(&self, _path: &Path, _content: &str) -> Result<(), std::io::Error> {
+        panic!("writing file should not be allowed in Language Server");
+    }
+}
+
 impl IsolatedLintHandler {
     pub fn new(
         lint_options: LintOptions,
@@ -70,10 +111,14 @@ impl IsolatedLintHandler {
         options: &IsolatedLintHandlerOptions,
     ) -> Self {
         let config_store_clone = config_store.clone();
+        let project_files = Arc::new(ProjectFiles::new(options.root_path.clone()));
 
-        let linter = Linter::new(lint_options, config_store, None);
+        let linter = Linter::new(lint_options, config_store, None)
+            .with_rule_result_cache(Arc::clone(&options.rule_result_cache))
+            .with_project_files(Arc::clone(&project_files));
         let mut lint_service_options = LintServiceOptions::new(options.root_path.clone())
-            .with_cross_module(options.use_cross_module);
+            .with_cross_module(options.use_cross_module)
+            .with_lint_cache(LINT_CACHE_CAPACITY);
 
         if let Some(tsconfig_path) = &options.tsconfig_path
             && tsconfig_path.is_file()
@@ -90,7 +135,9 @@ impl IsolatedLintHandler {
             Ok(runner) => runner,
             Err(e) => {
                 warn!("Failed to initialize type-aware linting: {e}");
-                let linter = Linter::new(lint_options, config_store_clone, None);
+                let linter = Linter::new(lint_options, config_store_clone, None)
+                    .with_rule_result_cache(Arc::clone(&options.rule_result_cache))
+                    .with_project_files(Arc::clone(&project_files));
                 LintRunnerBuilder::new(lint_service_options, linter)
                     .with_type_aware(false)
                     .with_fix_kind(options.fix_kind)
@@ -99,10 +146,29 @@ impl IsolatedLintHandler {
             }
         };
 
-        Self { runner, unused_directives_severity: lint_options.report_unused_directive }
+        Self {
+            runner,
+            unused_directives_severity: lint_options.report_unused_directive,
+            rule_result_cache: Arc::clone(&options.rule_result_cache),
+            project_files,
+        }
+    }
+
+    pub fn rule_result_cache(&self) -> &Arc<RuleResultCache> {
+        &self.rule_result_cache
+    }
+
+    /// Read the project file at `path` again, the next time a rule reads it.
+    pub fn invalidate_project_file(&self, path: &Path) {
+        self.project_files.invalidate(path);
     }
 
-    pub fn run_single(&self, uri: &Uri, content: Option<&str>) -> Option<Vec<DiagnosticReport>> {
+    pub fn run_single(
+        &self,
+        uri: &Uri,
+        content: Option<&str>,
+        cancellation_token: &CancellationToken,
+    ) -> Option<Vec<DiagnosticReport>> {
         let path = uri.to_file_path()?;
 
         if !Self::should_lint_path(&path) {
@@ -112,20 +178,80 @@ impl IsolatedLintHandler {
         let source_text =
             if let Some(content) = content { content } else { &read_to_string(&path).ok()? };
 
-        let mut diagnostics = self.lint_path(&path, uri, source_text);
+        let mut diagnostics = self.lint_path(&path, uri, source_text, cancellation_token);
         diagnostics.append(&mut generate_inverted_diagnostics(&diagnostics, uri));
         Some(diagnostics)
     }
 
-    fn lint_path(&self, path: &Path, uri: &Uri, source_text: &str) -> Vec<DiagnosticReport> {
-        debug!("lint {}", path.display());
-        let rope = &Rope::from_str(source_text);
+    /// Lint `paths` in parallel, and call `on_file` with the diagnostics of each lintable file as
+    /// soon as it has been linted. `read_content` returns the in-memory content of opened files,
+    /// other files are read from the file system.
+    ///
+    /// Once `cancellation_token` is cancelled, linting stops as soon as possible and the
+    /// remaining files are not passed to `on_file`.
+    pub fn run_workspace(
+        &self,
+        paths: &[PathBuf],
+        read_content: &(dyn Fn(&Path) -> Option<String> + Sync),
+        cancellation_token: &CancellationToken,
+        on_file: &mut (dyn FnMut(Uri, Vec<DiagnosticReport>) + Send),
+    ) {
+        let paths = paths
+            .iter()
+            .filter(|path| Self::should_lint_path(path))
+            .map(|path| Arc::from(path.as_os_str()))
+            .collect::<Vec<_>>();
+        let fs = WorkspaceFileSystem { read_content };
+
+        self.runner.run_with_callback(paths, &fs, cancellation_token, |file| {
+            let Some(uri) = Uri::from_file_path(&file.path) else { return };
+            let Some(source_text) = fs.read_to_string(&file.path) else { return };
+            let mut messages = file.messages;
+            messages.extend(self.runner.run_type_aware_source(
+                &Arc::from(file.path.as_os_str()),
+                source_text.clone(),
+                cancellation_token,
+            ));
+            if cancellation_token.is_cancelled() {
+                return;
+            }
+            let mut diagnostics =
+                self.diagnostic_reports(&file.path, &uri, &source_text, &messages);
+            diagnostics.append(&mut generate_inverted_diagnostics(&diagnostics, &uri));
+            on_file(uri, diagnostics);
+        });
+    }
 
+    fn lint_path(
+        &self,
+        path: &Path,
+        uri: &Uri,
+        source_text: &str,
+        cancellation_token: &CancellationToken,
+    ) -> Vec<DiagnosticReport> {
+        debug!("lint {}", path.display());
         let fs = IsolatedLintHandlerFileSystem::new(path.to_path_buf(), Arc::from(source_text));
 
-        let mut messages: Vec<DiagnosticReport> = self
-            .runner
-            .run_source(&Arc::from(path.as_os_str()), source_text.to_string(), &fs)
+        let messages = self.runner.run_source(
+            &Arc::from(path.as_os_str()),
+            source_text.to_string(),
+            &fs,
+            cancellation_token,
+        );
+        self.diagnostic_reports(path, uri, source_text, &messages)
+    }
+
+    /// Convert the messages of the file at `path` to LSP diagnostics, and add its unused
+    /// directives if configured.
+    fn diagnostic_reports(
+        &self,
+        path: &Path,
+        uri: &Uri,
+        source_text: &str,
+        messages: &[Message],
+    ) -> Vec<DiagnosticReport> {
+        let rope = &Rope::from_str(source_text);
+        let mut diagnostics: Vec<DiagnosticReport> = messages
             .iter()
             .map(|message| message_to_lsp_diagnostic(message, uri, source_text, rope))
             .collect();
@@ -134,14 +260,14 @@ impl IsolatedLintHandler {
         if let Some(severity) = self.unused_directives_severity
             && let Some(directives) = self.runner.directives_coordinator().get(path)
         {
-            messages.extend(
+            diagnostics.extend(
                 create_unused_directives_messages(&directives, severity, source_text)
                     .iter()
                     .map(|message| message_to_lsp_diagnostic(message, uri, source_text, rope)),
             );
         }
 
-        messages
+        diagnostics
     }
 
     fn should_lint_path(path: &Path) -> bool {
@@ -207,6 +333,14 @@ fn create_unused_directives_messages(
         }
     }
 
+    // Report blanket disables for the whole file which only suppress a few rules
+    for file_disable in directives.collect_narrowable_file_disables() {
+        diagnostics.push(Message::new(
+            file_disable.diagnostic().with_severity(severity),
+            PossibleFixes::Single(file_disable.create_fix()),
+        ));
+    }
+
     // Report unused enable comments
     let unused_enable = directives.unused_enable_comments();
     for (rule_name, span) in unused_enable {
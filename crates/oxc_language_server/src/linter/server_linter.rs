@@ -1,6 +1,9 @@
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
 
 use ignore::gitignore::Gitignore;
 use log::{debug, warn};
@@ -16,8 +19,8 @@ use tower_lsp_server::{
 };
 
 use oxc_linter::{
-    AllowWarnDeny, Config, ConfigStore, ConfigStoreBuilder, ExternalPluginStore, FixKind,
-    LintIgnoreMatcher, LintOptions, Oxlintrc,
+    AllowWarnDeny, CancellationToken, Config, ConfigStore, ConfigStoreBuilder, ExternalPluginStore,
+    FixKind, LintIgnoreMatcher, LintOptions, Oxlintrc, RuleResultCache,
 };
 
 use crate::{
@@ -26,24 +29,48 @@ use crate::{
         LINT_CONFIG_FILE,
         code_actions::{
             CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC, apply_all_fix_code_action, apply_fix_code_actions,
-            fix_all_text_edit,
+            apply_suppression_code_actions, fix_all_text_edit,
         },
         commands::{FIX_ALL_COMMAND_ID, FixAllCommandArgs},
         config_walker::ConfigWalker,
         error_with_position::DiagnosticReport,
-        isolated_lint_handler::{IsolatedLintHandler, IsolatedLintHandlerOptions},
+        isolated_lint_handler::{
+            IsolatedLintHandler, IsolatedLintHandlerOptions, RULE_RESULT_CACHE_CAPACITY,
+        },
         options::{LintOptions as LSPLintOptions, Run, UnusedDisableDirectives},
     },
     tool::{Tool, ToolBuilder, ToolRestartChanges, ToolShutdownChanges},
     utils::normalize_path,
 };
 
+/// Names of the project files which rules read, see
+/// [`LintContext::read_project_file`](oxc_linter::LintContext::read_project_file). Changes to them
+/// are watched to read them again.
+const PROJECT_FILE_NAMES: [&str; 2] = ["package.json", "tsconfig.json"];
+
 pub struct ServerLinterBuilder;
 
 impl ServerLinterBuilder {
     /// # Panics
     /// Panics if the root URI cannot be converted to a file path.
     pub fn build(root_uri: &Uri, options: serde_json::Value) -> ServerLinter {
+        Self::build_with_rule_result_cache(
+            root_uri,
+            options,
+            Arc::new(RuleResultCache::new(RULE_RESULT_CACHE_CAPACITY)),
+        )
+    }
+
+    /// Build a linter which reuses the diagnostics of rules whose configuration did not change
+    /// from `rule_result_cache`, kept by the linter of the previous configuration.
+    ///
+    /// # Panics
+    /// Panics if the root URI cannot be converted to a file path.
+    pub fn build_with_rule_result_cache(
+        root_uri: &Uri,
+        options: serde_json::Value,
+        rule_result_cache: Arc<RuleResultCache>,
+    ) -> ServerLinter {
         let options = match serde_json::from_value::<LSPLintOptions>(options) {
             Ok(opts) => opts,
             Err(e) => {
@@ -85,16 +112,19 @@ impl ServerLinterBuilder {
         let use_nested_config = options.use_nested_configs();
         let fix_kind = FixKind::from(options.fix_kind.clone());
 
-        let use_cross_module = config_builder.plugins().has_import()
-            || (use_nested_config
-                && nested_configs.pin().values().any(|config| config.plugins().has_import()));
-
         extended_paths.extend(config_builder.extended_paths.clone());
+        for warning in &config_builder.warnings {
+            warn!("{warning}");
+        }
         let base_config = config_builder.build(&external_plugin_store).unwrap_or_else(|err| {
             warn!("Failed to build config: {err}");
             ConfigStoreBuilder::empty().build(&external_plugin_store).unwrap()
         });
 
+        let use_cross_module = base_config.all_plugins().has_import()
+            || (use_nested_config
+                && nested_configs.pin().values().any(|config| config.all_plugins().has_import()));
+
         let lint_options = LintOptions {
             fix: fix_kind,
             report_unused_directive: match options.unused_disable_directives {
@@ -102,6 +132,9 @@ impl ServerLinterBuilder {
                 UnusedDisableDirectives::Warn => Some(AllowWarnDeny::Warn),
                 UnusedDisableDirectives::Deny => Some(AllowWarnDeny::Deny),
             },
+            // All diagnostics of the open file are shown inline, so none are hidden
+            keep_warnings_over_threshold: true,
+            rule_origins: true,
             ..Default::default()
         };
         let config_store = ConfigStore::new(
@@ -130,6 +163,7 @@ impl ServerLinterBuilder {
                     let path = Path::new(path).to_path_buf();
                     if path.is_relative() { root_path.join(path) } else { path }
                 }),
+                rule_result_cache,
             },
         );
 
@@ -249,6 +283,9 @@ impl ServerLinterBuilder {
                 continue;
             };
             extended_paths.extend(config_store_builder.extended_paths.clone());
+            for warning in &config_store_builder.warnings {
+                warn!("{warning}");
+            }
             let config = config_store_builder.build(&external_plugin_store).unwrap_or_else(|err| {
                 warn!("Failed to build nested config for {}: {:?}", dir_path.display(), err);
                 ConfigStoreBuilder::empty().build(&external_plugin_store).unwrap()
@@ -277,7 +314,7 @@ impl ServerLinterBuilder {
             if !ignore_file_path
                 .file_name()
                 .and_then(std::ffi::OsStr::to_str)
-                .is_some_and(|v| [".eslintignore", ".gitignore"].contains(&v))
+                .is_some_and(|v| [".eslintignore", ".gitignore", ".oxlintignore"].contains(&v))
             {
                 continue;
             }
@@ -302,6 +339,9 @@ pub struct ServerLinter {
     gitignore_glob: Vec<Gitignore>,
     extended_paths: FxHashSet<PathBuf>,
     diagnostics: Arc<ConcurrentHashMap<String, Option<Vec<DiagnosticReport>>>>,
+    /// Cancellation tokens of the lint runs in progress, keyed by URI.
+    /// Starting a new run for a URI cancels the previous one, since its result would be stale.
+    in_flight_runs: ConcurrentHashMap<String, CancellationToken>,
 }
 
 impl Tool for ServerLinter {
@@ -310,6 +350,9 @@ impl Tool for ServerLinter {
     }
 
     fn shutdown(&self) -> ToolShutdownChanges {
+        for token in self.in_flight_runs.pin().values() {
+            token.cancel();
+        }
         ToolShutdownChanges {
             uris_to_clear_diagnostics: Some(self.get_cached_files_of_diagnostics()),
         }
@@ -353,7 +396,11 @@ impl Tool for ServerLinter {
 
         // get the cached files before refreshing the linter, and revalidate them after
         let cached_files = self.get_cached_files_of_diagnostics();
-        let new_linter = ServerLinterBuilder::build(root_uri, new_options_json.clone());
+        let new_linter = ServerLinterBuilder::build_with_rule_result_cache(
+            root_uri,
+            new_options_json.clone(),
+            Arc::clone(self.isolated_linter.rule_result_cache()),
+        );
         let diagnostics = Some(new_linter.revalidate_diagnostics(cached_files));
 
         let patterns = {
@@ -397,17 +444,37 @@ impl Tool for ServerLinter {
 
             watchers.push(normalize_path(pattern).to_string_lossy().to_string());
         }
+        watchers.extend(PROJECT_FILE_NAMES.iter().map(|file_name| format!("**/{file_name}")));
         watchers
     }
 
     fn handle_watched_file_change(
         &self,
-        _changed_uri: &Uri,
+        changed_uri: &Uri,
         root_uri: &Uri,
         options: serde_json::Value,
     ) -> ToolRestartChanges {
+        // Project files read by rules are read again, without restarting the linter
+        if let Some(path) = changed_uri.to_file_path()
+            && path
+                .file_name()
+                .is_some_and(|file_name| PROJECT_FILE_NAMES.iter().any(|name| file_name == *name))
+        {
+            self.isolated_linter.invalidate_project_file(&path);
+            let cached_files = self.get_cached_files_of_diagnostics();
+            return ToolRestartChanges {
+                tool: None,
+                diagnostic_reports: Some(self.revalidate_diagnostics(cached_files)),
+                watch_patterns: None,
+            };
+        }
+
         // TODO: Check if the changed file is actually a config file (including extended paths)
-        let new_linter = ServerLinterBuilder::build(root_uri, options);
+        let new_linter = ServerLinterBuilder::build_with_rule_result_cache(
+            root_uri,
+            options,
+            Arc::clone(self.isolated_linter.rule_result_cache()),
+        );
 
         // get the cached files before refreshing the linter, and revalidate them after
         let cached_files = self.get_cached_files_of_diagnostics();
@@ -511,6 +578,10 @@ impl Tool for ServerLinter {
                 code_actions_vec
                     .extend(fix_actions.into_iter().map(CodeActionOrCommand::CodeAction));
             }
+            code_actions_vec.extend(
+                apply_suppression_code_actions(&report.suppressions, uri)
+                    .map(CodeActionOrCommand::CodeAction),
+            );
         }
 
         code_actions_vec
@@ -550,6 +621,34 @@ impl Tool for ServerLinter {
         self.run_diagnostic(uri, content)
     }
 
+    /// Lint all files inside the workspace which are lintable and not ignored, in parallel.
+    /// Directories ignored by `.gitignore` files and `node_modules` are not visited.
+    fn run_workspace_diagnostic(
+        &self,
+        read_content: &(dyn Fn(&Uri) -> Option<String> + Sync),
+        cancelled: &Arc<AtomicBool>,
+        on_file: &mut (dyn FnMut(Uri, Vec<Diagnostic>) + Send),
+    ) {
+        let paths = ignore::WalkBuilder::new(&self.cwd)
+            .filter_entry(|entry| entry.file_name() != "node_modules")
+            .build()
+            .take_while(|_| !cancelled.load(Ordering::Relaxed))
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_some_and(|file_type| !file_type.is_dir()))
+            .map(ignore::DirEntry::into_path)
+            .filter(|path| Uri::from_file_path(path).is_some_and(|uri| !self.is_ignored(&uri)))
+            .collect::<Vec<_>>();
+
+        let read_content =
+            |path: &Path| Uri::from_file_path(path).and_then(|uri| read_content(&uri));
+        let token = CancellationToken::from_flag(Arc::clone(cancelled));
+        self.isolated_linter.run_workspace(&paths, &read_content, &token, &mut |uri, reports| {
+            let diagnostics = reports.iter().map(|report| report.diagnostic.clone()).collect();
+            self.diagnostics.pin().insert(uri.to_string(), Some(reports));
+            on_file(uri, diagnostics);
+        });
+    }
+
     fn remove_diagnostics(&self, uri: &Uri) {
         self.diagnostics.pin().remove(&uri.to_string());
     }
@@ -574,6 +673,7 @@ impl ServerLinter {
             gitignore_glob,
             extended_paths,
             diagnostics: Arc::new(ConcurrentHashMap::default()),
+            in_flight_runs: ConcurrentHashMap::default(),
         }
     }
 
@@ -622,15 +722,28 @@ impl ServerLinter {
         false
     }
 
-    /// Lint a single file, return `None` if the file is ignored.
+    /// Lint a single file, return `None` if the file is ignored,
+    /// or if the run was cancelled because a newer run for the same file started.
     fn run_file(&self, uri: &Uri, content: Option<&str>) -> Option<Vec<DiagnosticReport>> {
         if self.is_ignored(uri) {
             return None;
         }
 
-        let diagnostics = self.isolated_linter.run_single(uri, content);
+        let key = uri.to_string();
+        let token = CancellationToken::new();
+        if let Some(previous) = self.in_flight_runs.pin().insert(key.clone(), token.clone()) {
+            previous.cancel();
+        }
 
-        self.diagnostics.pin().insert(uri.to_string(), diagnostics.clone());
+        let diagnostics = self.isolated_linter.run_single(uri, content, &token);
+
+        // A newer run for the same URI may have replaced the token already
+        let _ = self.in_flight_runs.pin().remove_if(&key, |_, current| current.same_as(&token));
+        if token.is_cancelled() {
+            return None;
+        }
+
+        self.diagnostics.pin().insert(key, diagnostics.clone());
 
         diagnostics
     }
@@ -826,8 +939,10 @@ mod test_watchers {
             let patterns =
                 Tester::new("fixtures/linter/watchers/default", json!({})).get_watcher_patterns();
 
-            assert_eq!(patterns.len(), 1);
+            assert_eq!(patterns.len(), 3);
             assert_eq!(patterns[0], "**/.oxlintrc.json".to_string());
+            assert_eq!(patterns[1], "**/package.json".to_string());
+            assert_eq!(patterns[2], "**/tsconfig.json".to_string());
         }
 
         #[test]
@@ -840,7 +955,7 @@ mod test_watchers {
             )
             .get_watcher_patterns();
 
-            assert_eq!(patterns.len(), 1);
+            assert_eq!(patterns.len(), 3);
             assert_eq!(patterns[0], "configs/lint.json".to_string());
         }
 
@@ -849,8 +964,8 @@ mod test_watchers {
             let patterns = Tester::new("fixtures/linter/watchers/linter_extends", json!({}))
                 .get_watcher_patterns();
 
-            // The `.oxlintrc.json` extends `./lint.json -> 2 watchers
-            assert_eq!(patterns.len(), 2);
+            // The `.oxlintrc.json` extends `./lint.json -> 2 watchers, and the project files
+            assert_eq!(patterns.len(), 4);
             assert_eq!(patterns[0], "**/.oxlintrc.json".to_string());
             assert_eq!(patterns[1], "lint.json".to_string());
         }
@@ -865,7 +980,7 @@ mod test_watchers {
             )
             .get_watcher_patterns();
 
-            assert_eq!(patterns.len(), 2);
+            assert_eq!(patterns.len(), 4);
             assert_eq!(patterns[0], ".oxlintrc.json".to_string());
             assert_eq!(patterns[1], "lint.json".to_string());
         }
@@ -893,7 +1008,7 @@ mod test_watchers {
                     }));
 
             assert!(watch_patterns.is_some());
-            assert_eq!(watch_patterns.as_ref().unwrap().len(), 1);
+            assert_eq!(watch_patterns.as_ref().unwrap().len(), 3);
             assert_eq!(watch_patterns.unwrap()[0], "configs/lint.json".to_string());
         }
 
@@ -909,18 +1024,51 @@ mod test_watchers {
             assert!(watch_patterns.is_none());
         }
     }
+
+    mod handle_watched_file_change {
+        use crate::{ToolRestartChanges, linter::tester::Tester};
+        use serde_json::json;
+
+        #[test]
+        fn test_config_change() {
+            let ToolRestartChanges { tool, diagnostic_reports, .. } =
+                Tester::new("fixtures/linter/watchers/default", json!({}))
+                    .handle_watched_file_change(".oxlintrc.json");
+
+            assert!(tool.is_some());
+            assert!(diagnostic_reports.is_some());
+        }
+
+        #[test]
+        fn test_project_file_change() {
+            // the project file is read again, without restarting the linter
+            let ToolRestartChanges { tool, diagnostic_reports, watch_patterns } =
+                Tester::new("fixtures/linter/watchers/default", json!({}))
+                    .handle_watched_file_change("package.json");
+
+            assert!(tool.is_none());
+            assert!(diagnostic_reports.is_some());
+            assert!(watch_patterns.is_none());
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use std::path::{Path, PathBuf};
+    use std::{
+        path::{Path, PathBuf},
+        sync::{Arc, atomic::AtomicBool},
+    };
 
     use serde_json::json;
 
-    use crate::linter::{
-        options::LintOptions,
-        server_linter::ServerLinterBuilder,
-        tester::{Tester, get_file_path},
+    use crate::{
+        linter::{
+            options::LintOptions,
+            server_linter::ServerLinterBuilder,
+            tester::{Tester, get_file_path},
+        },
+        tool::Tool,
     };
 
     #[test]
@@ -966,6 +1114,13 @@ mod test {
             .test_and_snapshot_single_file("hello_world.js");
     }
 
+    #[test]
+    fn test_per_file_thresholds() {
+        // the warnings are kept, next to the error summarizing them
+        Tester::new("fixtures/linter/per_file_thresholds", json!({}))
+            .test_and_snapshot_single_file("generated.js");
+    }
+
     // Test case for https://github.com/oxc-project/oxc/issues/9958
     #[test]
     fn test_issue_9958() {
@@ -1043,6 +1198,17 @@ mod test {
         .test_and_snapshot_single_file("test.js");
     }
 
+    #[test]
+    fn test_report_narrowable_file_disable_directives() {
+        Tester::new(
+            "fixtures/linter/unused_disabled_directives",
+            json!({
+                "unusedDisableDirectives": "deny"
+            }),
+        )
+        .test_and_snapshot_single_file("file-disable.js");
+    }
+
     #[test]
     #[cfg(not(target_endian = "big"))]
     fn test_report_tsgolint_unused_directives() {
@@ -1065,6 +1231,28 @@ mod test {
         ]);
     }
 
+    #[test]
+    fn test_workspace_diagnostic() {
+        Tester::new("fixtures/linter/ignore_patterns", json!({})).test_and_snapshot_workspace();
+    }
+
+    #[test]
+    fn test_workspace_diagnostic_cancelled() {
+        let linter = ServerLinterBuilder::build(
+            &Tester::get_root_uri("fixtures/linter/ignore_patterns"),
+            json!({}),
+        );
+        let mut files = vec![];
+        linter.run_workspace_diagnostic(
+            &|_| None,
+            &Arc::new(AtomicBool::new(true)),
+            &mut |uri, _| {
+                files.push(uri);
+            },
+        );
+        assert!(files.is_empty());
+    }
+
     #[test]
     fn test_ts_alias() {
         Tester::new(
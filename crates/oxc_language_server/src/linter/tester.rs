@@ -1,4 +1,8 @@
-use std::{fmt::Write, path::PathBuf};
+use std::{
+    fmt::Write,
+    path::PathBuf,
+    sync::{Arc, atomic::AtomicBool},
+};
 
 use tower_lsp_server::{
     UriExt,
@@ -102,6 +106,9 @@ fn get_snapshot_for_code_action(code_action: &CodeAction) -> String {
 
     let mut result = String::new();
     let _ = writeln!(result, "Title: {}", code_action.title);
+    if let Some(kind) = &code_action.kind {
+        let _ = writeln!(result, "Kind: {}", kind.as_str());
+    }
     let _ = writeln!(
         result,
         "{}",
@@ -220,10 +227,66 @@ impl Tester<'_> {
         });
     }
 
+    /// Lint all files of the root directory, like an `oxc/workspaceDiagnostic` request,
+    /// and return the resulting diagnostics of each file in a custom snapshot format.
+    pub fn test_and_snapshot_workspace(&self) {
+        let linter = self.create_linter();
+        let mut reports = vec![];
+        linter.run_workspace_diagnostic(
+            &|_| None,
+            &Arc::new(AtomicBool::new(false)),
+            &mut |uri, diagnostics| {
+                reports.push((uri, diagnostics));
+            },
+        );
+
+        let root_path = get_file_path(self.relative_root_dir);
+        let mut files = reports
+            .into_iter()
+            .map(|(uri, diagnostics)| {
+                let path = uri.to_file_path().unwrap();
+                let relative_path = path.strip_prefix(&root_path).unwrap().to_string_lossy();
+                #[expect(clippy::disallowed_methods)]
+                let relative_path = relative_path.replace('\\', "/");
+                (relative_path, diagnostics)
+            })
+            .collect::<Vec<_>>();
+        files.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+        let mut snapshot_result = String::new();
+        for (relative_file_path, diagnostics) in files {
+            let _ = write!(
+                snapshot_result,
+                "########## \nfile: {}/{relative_file_path}\n----------\n{}\n",
+                self.relative_root_dir,
+                diagnostics.iter().map(get_snapshot_from_diagnostic).collect::<Vec<_>>().join("\n"),
+            );
+        }
+
+        #[expect(clippy::disallowed_methods)]
+        let snapshot_name = self.relative_root_dir.replace('/', "_");
+        let mut settings = insta::Settings::clone_current();
+        settings.set_prepend_module_to_snapshot(false);
+        settings.set_omit_expression(true);
+        settings.set_snapshot_suffix("workspace");
+        settings.bind(|| {
+            insta::assert_snapshot!(snapshot_name, snapshot_result);
+        });
+    }
+
     pub fn get_watcher_patterns(&self) -> Vec<String> {
         self.create_linter().get_watcher_patterns(self.options.clone())
     }
 
+    pub fn handle_watched_file_change(&self, relative_file_path: &str) -> ToolRestartChanges {
+        let uri = get_file_uri(&format!("{}/{relative_file_path}", self.relative_root_dir));
+        self.create_linter().handle_watched_file_change(
+            &uri,
+            &Self::get_root_uri(self.relative_root_dir),
+            self.options.clone(),
+        )
+    }
+
     pub fn handle_configuration_change(
         &self,
         new_options: serde_json::Value,
@@ -1,3 +1,5 @@
+use std::sync::{Arc, atomic::AtomicBool};
+
 use tower_lsp_server::{
     jsonrpc::ErrorCode,
     lsp_types::{
@@ -114,6 +116,19 @@ pub trait Tool: Send + Sync {
         None
     }
 
+    /// Run diagnostics on all files of the workspace, including files which are not opened.
+    /// `read_content` returns the in-memory content of an opened file, other files should be read from the file system.
+    /// `on_file` is called with the diagnostics of each file as soon as they are available.
+    /// The run stops early once `cancelled` is set.
+    /// Not all tools will implement diagnostics, so the default implementation does nothing.
+    fn run_workspace_diagnostic(
+        &self,
+        _read_content: &(dyn Fn(&Uri) -> Option<String> + Sync),
+        _cancelled: &Arc<AtomicBool>,
+        _on_file: &mut (dyn FnMut(Uri, Vec<Diagnostic>) + Send),
+    ) {
+    }
+
     /// Remove diagnostics associated with the given URI.
     fn remove_diagnostics(&self, _uri: &Uri) {
         // Default implementation does nothing.
@@ -1,3 +1,5 @@
+use std::sync::{Arc, atomic::AtomicBool};
+
 use log::debug;
 use serde_json::json;
 use tokio::sync::{Mutex, RwLock};
@@ -148,6 +150,21 @@ impl WorkspaceWorker {
         if found { Some(diagnostics) } else { None }
     }
 
+    /// Run different tools to collect diagnostics for all files of the workspace.
+    /// See [`Tool::run_workspace_diagnostic`].
+    ///
+    /// This blocks the current thread, so it must be called from a blocking task.
+    pub fn run_workspace_diagnostic(
+        &self,
+        read_content: &(dyn Fn(&Uri) -> Option<String> + Sync),
+        cancelled: &Arc<AtomicBool>,
+        on_file: &mut (dyn FnMut(Uri, Vec<Diagnostic>) + Send),
+    ) {
+        for tool in self.tools.blocking_read().iter() {
+            tool.run_workspace_diagnostic(read_content, cancelled, on_file);
+        }
+    }
+
     /// Format a file with the current formatter
     /// - If no file is not formattable or ignored, [`None`] is returned
     /// - If the file is formattable, but no changes are made, an empty vector is returned
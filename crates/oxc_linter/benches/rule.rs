@@ -0,0 +1,274 @@
+#![expect(clippy::print_stdout, clippy::print_stderr)]
+//! # Single rule benchmark
+//!
+//! Runs one rule against every lintable file in a corpus directory, and reports how long the rule
+//! takes per AST node and how many heap allocations it makes. Useful to check the performance of
+//! a new rule, or of a change to an existing rule, without running the whole linter.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! cargo bench -p oxc_linter --bench rule -- --rule eslint/no-unused-vars --corpus path/to/dir
+//! ```
+//!
+//! Options:
+//! - `--rule <plugin/rule>`: rule to benchmark. The plugin defaults to `eslint` if omitted.
+//! - `--corpus <path>`: file or directory to lint. Directories are searched recursively.
+//!   `cargo bench` runs from `crates/oxc_linter`, so prefer absolute paths.
+//! - `--iterations <n>`: number of times to lint each file. Defaults to 10.
+//!
+//! The time and allocations of linting with no rules enabled are measured too and subtracted,
+//! so the reported numbers are only for the rule itself.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    path::{Path, PathBuf},
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+use oxc_allocator::Allocator;
+use oxc_linter::{
+    ConfigStore, ConfigStoreBuilder, ContextSubHost, ExternalPluginStore, LintOptions, Linter,
+    ModuleRecord, Oxlintrc, read_to_string,
+};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+/// Wraps the system allocator to count heap allocations.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+// SAFETY: All calls are forwarded to `System`.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        // SAFETY: Caller upholds the contract of `GlobalAlloc::alloc`.
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: Caller upholds the contract of `GlobalAlloc::dealloc`.
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        // SAFETY: Caller upholds the contract of `GlobalAlloc::realloc`.
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+struct Options {
+    rule: String,
+    corpus: PathBuf,
+    iterations: u32,
+}
+
+impl Options {
+    fn parse() -> Result<Self, String> {
+        let mut rule = None;
+        let mut corpus = None;
+        let mut iterations = 10;
+
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--rule" => rule = args.next(),
+                "--corpus" => corpus = args.next().map(PathBuf::from),
+                "--iterations" => {
+                    iterations = args
+                        .next()
+                        .and_then(|n| n.parse().ok())
+                        .filter(|n| *n > 0)
+                        .ok_or("`--iterations` must be a positive number")?;
+                }
+                // Ignore arguments passed by `cargo bench`, such as `--bench`
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            rule: rule.ok_or("missing `--rule <plugin/rule>`")?,
+            corpus: corpus.ok_or("missing `--corpus <path>`")?,
+            iterations,
+        })
+    }
+}
+
+/// Time and heap allocations spent in [`Linter::run`].
+#[derive(Default)]
+struct Measurement {
+    duration: Duration,
+    allocations: usize,
+    diagnostics: usize,
+}
+
+fn build_linter(rule: Option<&str>) -> Result<Linter, String> {
+    let mut external_plugin_store = ExternalPluginStore::default();
+    let config = match rule {
+        Some(rule) => {
+            let (plugin, _) = rule.split_once('/').unwrap_or(("eslint", rule));
+            let oxlintrc = Oxlintrc::deserialize(serde_json::json!({
+                "plugins": [plugin],
+                "categories": {},
+                "rules": { rule: "deny" },
+            }))
+            .map_err(|e| e.to_string())?;
+            ConfigStoreBuilder::from_oxlintrc(true, oxlintrc, None, &mut external_plugin_store)
+                .map_err(|e| e.to_string())?
+        }
+        None => ConfigStoreBuilder::empty(),
+    }
+    .build(&external_plugin_store)
+    .map_err(|e| e.to_string())?;
+
+    let config_store = ConfigStore::new(config, FxHashMap::default(), external_plugin_store);
+    let expected_rules = usize::from(rule.is_some());
+    if config_store.number_of_rules(false) != Some(expected_rules) {
+        return Err(format!("unknown rule `{}`", rule.unwrap_or_default()));
+    }
+    Ok(Linter::new(LintOptions::default(), config_store, None))
+}
+
+fn collect_files(path: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    if path.is_file() {
+        if SourceType::from_path(path).is_ok() {
+            files.push(path.to_path_buf());
+        }
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(path)? {
+        let path = entry?.path();
+        if path.file_name().is_some_and(|name| name == "node_modules" || name == ".git") {
+            continue;
+        }
+        collect_files(&path, files)?;
+    }
+    Ok(())
+}
+
+/// Lint `source_text` once, measuring only the time spent running the linter.
+/// Parsing and semantic analysis are not included.
+fn measure(
+    linter: &Linter,
+    path: &Path,
+    source_text: &str,
+    allocator: &mut Allocator,
+) -> Measurement {
+    allocator.reset();
+    let source_type = SourceType::from_path(path).unwrap();
+    let parser_ret = Parser::new(allocator, source_text, source_type).parse();
+    let semantic = SemanticBuilder::new()
+        .with_scope_tree_child_ids(true)
+        .with_cfg(true)
+        .build(&parser_ret.program)
+        .semantic;
+    let module_record = Arc::new(ModuleRecord::new(path, &parser_ret.module_record, &semantic));
+    let sub_hosts = vec![ContextSubHost::new(semantic, module_record, 0)];
+
+    let allocations_before = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    let messages = linter.run(path, sub_hosts, allocator);
+    let duration = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations_before;
+
+    Measurement { duration, allocations, diagnostics: messages.len() }
+}
+
+#[expect(clippy::cast_precision_loss)]
+fn main() {
+    let options = match Options::parse() {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{err}");
+            eprintln!(
+                "Usage: cargo bench -p oxc_linter --bench rule -- --rule <plugin/rule> --corpus <path> [--iterations <n>]"
+            );
+            std::process::exit(1);
+        }
+    };
+
+    let (linter, baseline_linter) = match (build_linter(Some(&options.rule)), build_linter(None)) {
+        (Ok(linter), Ok(baseline_linter)) => (linter, baseline_linter),
+        (Err(err), _) | (_, Err(err)) => {
+            eprintln!("Failed to configure `{}`: {err}", options.rule);
+            std::process::exit(1);
+        }
+    };
+
+    let mut files = vec![];
+    if let Err(err) = collect_files(&options.corpus, &mut files) {
+        eprintln!("Failed to read corpus `{}`: {err}", options.corpus.display());
+        std::process::exit(1);
+    }
+    files.sort_unstable();
+
+    let mut allocator = Allocator::default();
+    let mut linted_files = 0;
+    let mut nodes = 0;
+    let mut rule = Measurement::default();
+    let mut baseline = Measurement::default();
+
+    for path in &files {
+        let Ok(source_text) = read_to_string(path) else {
+            eprintln!("Skipping `{}`: failed to read file", path.display());
+            continue;
+        };
+        let parser_ret =
+            Parser::new(&allocator, &source_text, SourceType::from_path(path).unwrap()).parse();
+        if parser_ret.panicked || !parser_ret.errors.is_empty() {
+            eprintln!("Skipping `{}`: failed to parse", path.display());
+            continue;
+        }
+        linted_files += 1;
+        nodes += SemanticBuilder::new().build(&parser_ret.program).semantic.nodes().len();
+
+        for iteration in 0..options.iterations {
+            let m = measure(&baseline_linter, path, &source_text, &mut allocator);
+            baseline.duration += m.duration;
+            baseline.allocations += m.allocations;
+
+            let m = measure(&linter, path, &source_text, &mut allocator);
+            rule.duration += m.duration;
+            rule.allocations += m.allocations;
+            if iteration == 0 {
+                rule.diagnostics += m.diagnostics;
+            }
+        }
+    }
+
+    if nodes == 0 {
+        eprintln!("No lintable files found in `{}`", options.corpus.display());
+        std::process::exit(1);
+    }
+
+    let iterations = f64::from(options.iterations);
+    let runs = nodes as f64 * iterations;
+    let rule_ns = rule.duration.saturating_sub(baseline.duration).as_nanos() as f64;
+    let rule_allocations = rule.allocations.saturating_sub(baseline.allocations) as f64;
+
+    println!("rule:         {}", options.rule);
+    println!("files:        {linted_files}");
+    println!("nodes:        {nodes}");
+    println!("iterations:   {}", options.iterations);
+    println!("diagnostics:  {}", rule.diagnostics);
+    println!("time:         {:.3} ns/node", rule_ns / runs);
+    println!("allocations:  {:.3} per 1000 nodes", rule_allocations / runs * 1000.0);
+    println!(
+        "(baseline without rules: {:.3} ns/node, {:.3} allocations per 1000 nodes)",
+        baseline.duration.as_nanos() as f64 / runs,
+        baseline.allocations as f64 / runs * 1000.0,
+    );
+}
@@ -0,0 +1,3 @@
+import './side-effects';
+
+export type Polyfilled = true;
@@ -0,0 +1,9 @@
+export interface Options {
+  debug: boolean;
+}
+
+export const DEFAULT_OPTIONS: Options = { debug: false };
+
+export function createOptions(debug: boolean): Options {
+  return { debug };
+}
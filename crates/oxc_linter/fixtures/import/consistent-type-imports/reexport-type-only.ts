@@ -0,0 +1 @@
+export type { Options } from './side-effects';
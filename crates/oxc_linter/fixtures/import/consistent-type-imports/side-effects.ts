@@ -0,0 +1,5 @@
+export interface Options {
+  debug: boolean;
+}
+
+globalThis.registry = new Map();
@@ -0,0 +1,131 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Instant,
+};
+
+/// Cooperative cancellation of an in-flight lint run.
+///
+/// Cancelling does not interrupt a rule which is already running. Instead, the runtime checks the
+/// token between files and script blocks, and periodically while running rules on the AST nodes,
+/// and stops `tsgolint`, so that a stale run (e.g. for a document which changed in the editor)
+/// finishes early and releases its allocators.
+///
+/// Clones share the same state, so cancelling any clone cancels the run.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    /// The token is also cancelled once this instant has passed.
+    deadline: Option<Instant>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A token which is cancelled once `cancelled` is set, e.g. by another component.
+    pub fn from_flag(cancelled: Arc<AtomicBool>) -> Self {
+        Self { cancelled, deadline: None }
+    }
+
+    /// A clone of this token which is additionally cancelled once `deadline` has passed.
+    /// Used to limit the time spent on a single file.
+    #[must_use]
+    pub fn with_deadline(&self, deadline: Instant) -> Self {
+        Self { cancelled: Arc::clone(&self.cancelled), deadline: Some(deadline) }
+    }
+
+    /// Request cancellation of every run using this token.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed) || self.is_past_deadline()
+    }
+
+    /// Returns `true` if the deadline of this token has passed.
+    pub fn is_past_deadline(&self) -> bool {
+        self.deadline.is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Returns `true` if `self` and `other` are clones of the same token.
+    pub fn same_as(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.cancelled, &other.cancelled)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        ffi::OsStr,
+        sync::Arc,
+        time::{Duration, Instant},
+    };
+
+    use rustc_hash::FxHashMap;
+
+    use super::CancellationToken;
+    use crate::{
+        AllowWarnDeny, ConfigStore, ConfigStoreBuilder, ExternalPluginStore, LintOptions,
+        LintService, LintServiceOptions, Linter, rules::RULES, tester::TesterFileSystem,
+    };
+
+    #[test]
+    fn cancel_is_shared_between_clones() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+        assert!(!token.is_cancelled());
+
+        clone.cancel();
+        assert!(token.is_cancelled());
+        assert!(token.same_as(&clone));
+        assert!(!token.same_as(&CancellationToken::new()));
+    }
+
+    #[test]
+    fn deadline_only_cancels_the_derived_token() {
+        let token = CancellationToken::new();
+        let expired = token.with_deadline(Instant::now());
+        assert!(expired.is_cancelled());
+        assert!(expired.is_past_deadline());
+        assert!(!token.is_cancelled());
+        assert!(expired.same_as(&token));
+
+        let pending = token.with_deadline(Instant::now() + Duration::from_secs(3600));
+        assert!(!pending.is_cancelled());
+        token.cancel();
+        assert!(pending.is_cancelled());
+        assert!(!pending.is_past_deadline());
+    }
+
+    #[test]
+    fn cancelled_run_returns_no_messages() {
+        let external_plugin_store = ExternalPluginStore::default();
+        let no_debugger = RULES.iter().find(|rule| rule.name() == "no-debugger").unwrap();
+        let config = ConfigStoreBuilder::empty()
+            .with_rule(no_debugger, AllowWarnDeny::Deny, None)
+            .build(&external_plugin_store)
+            .unwrap();
+        let linter = Linter::new(
+            LintOptions::default(),
+            ConfigStore::new(config, FxHashMap::default(), external_plugin_store),
+            None,
+        );
+        let cwd = std::env::current_dir().unwrap();
+        let path = cwd.join("cancelled.js");
+        let service = LintService::new(linter, LintServiceOptions::new(cwd));
+        let file_system = TesterFileSystem::new(path.clone(), "debugger;".to_string());
+        let paths = vec![Arc::<OsStr>::from(path.as_os_str())];
+
+        let messages = service.run_source(&file_system, paths.clone(), &CancellationToken::new());
+        assert_eq!(messages.len(), 1);
+
+        let token = CancellationToken::new();
+        token.cancel();
+        assert!(service.run_source(&file_system, paths, &token).is_empty());
+    }
+}
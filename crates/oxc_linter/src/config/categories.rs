@@ -5,14 +5,42 @@ use std::{
 
 use rustc_hash::FxHashMap;
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
-use crate::{AllowWarnDeny, LintFilter, RuleCategory};
+use crate::{
+    AllowWarnDeny, LintFilter, RuleCategory,
+    rules::{RULES, RuleEnum},
+};
+
+use super::{rules::parse_rule_key, validation::did_you_mean};
 
 /// Configure an entire category of rules all at once.
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize)]
 pub struct OxlintCategories(FxHashMap<RuleCategory, AllowWarnDeny>);
 
+impl<'de> Deserialize<'de> for OxlintCategories {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let categories = FxHashMap::<String, AllowWarnDeny>::deserialize(deserializer)?;
+        categories
+            .into_iter()
+            .map(|(name, severity)| {
+                let Ok(category) = RuleCategory::try_from(name.as_str()) else {
+                    let names = RuleCategory::ALL.iter().map(|category| category.as_str());
+                    let message = match did_you_mean(&name, names) {
+                        Some(suggestion) => {
+                            format!("Unknown category: '{name}'. Did you mean '{suggestion}'?")
+                        }
+                        None => format!("Unknown category: '{name}'."),
+                    };
+                    return Err(serde::de::Error::custom(message));
+                };
+                Ok((category, severity))
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
 impl Deref for OxlintCategories {
     type Target = FxHashMap<RuleCategory, AllowWarnDeny>;
 
@@ -28,6 +56,12 @@ impl DerefMut for OxlintCategories {
 }
 
 impl OxlintCategories {
+    /// Returns `true` if no categories are configured.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
     pub fn filters(&self) -> impl Iterator<Item = LintFilter> + '_ {
         self.iter().map(|(category, severity)| LintFilter::new(*severity, *category).unwrap())
     }
@@ -93,3 +127,96 @@ Example
         schema.into()
     }
 }
+
+/// Re-assign rules to a different category.
+///
+/// This changes which rules are enabled by the `categories` field and by category-based CLI
+/// filters (such as `-D style`). Rules are named the same way as in the `rules` field.
+///
+/// Example
+///
+/// ```json
+/// {
+///   "$schema": "./node_modules/oxlint/configuration_schema.json",
+///   "categories": {
+///     "pedantic": "error"
+///   },
+///   "categoriesOverride": {
+///     "unicorn/no-array-for-each": "style"
+///   }
+/// }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct OxlintCategoriesOverride(FxHashMap<String, RuleCategory>);
+
+impl OxlintCategoriesOverride {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Resolve rule names to builtin rules.
+    /// Names which do not match any builtin rule are ignored.
+    pub(crate) fn resolve(&self) -> FxHashMap<RuleEnum, RuleCategory> {
+        self.0
+            .iter()
+            .filter_map(|(name, category)| {
+                let (plugin_name, rule_name) = parse_rule_key(name);
+                RULES
+                    .iter()
+                    .find(|rule| rule.plugin_name() == plugin_name && rule.name() == rule_name)
+                    .map(|rule| (rule.clone(), *category))
+            })
+            .collect()
+    }
+
+    /// Merges two sets of overrides. Overrides in `self` take priority over `other`.
+    #[must_use]
+    pub(crate) fn merge(&self, other: &Self) -> Self {
+        let mut merged = other.0.clone();
+        merged.extend(self.0.iter().map(|(name, category)| (name.clone(), *category)));
+        Self(merged)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+
+    use super::{OxlintCategories, OxlintCategoriesOverride};
+    use crate::{AllowWarnDeny, RuleCategory};
+
+    #[test]
+    fn test_resolve_categories_override() {
+        let categories_override = OxlintCategoriesOverride::deserialize(&serde_json::json!({
+            "unicorn/no-array-for-each": "style",
+            "no-debugger": "pedantic",
+            "@typescript-eslint/no-explicit-any": "correctness",
+            "unknown/rule": "style"
+        }))
+        .unwrap();
+
+        let resolved = categories_override.resolve();
+        assert_eq!(resolved.len(), 3);
+        for (rule, category) in resolved {
+            let expected = match (rule.plugin_name(), rule.name()) {
+                ("unicorn", "no-array-for-each") => RuleCategory::Style,
+                ("eslint", "no-debugger") => RuleCategory::Pedantic,
+                ("typescript", "no-explicit-any") => RuleCategory::Correctness,
+                (plugin_name, rule_name) => panic!("unexpected rule {plugin_name}/{rule_name}"),
+            };
+            assert_eq!(category, expected);
+        }
+    }
+
+    #[test]
+    fn test_deserialize_categories() {
+        let categories =
+            OxlintCategories::deserialize(&serde_json::json!({ "correctness": "warn" })).unwrap();
+        assert_eq!(categories.get(&RuleCategory::Correctness), Some(&AllowWarnDeny::Warn));
+
+        let error = OxlintCategories::deserialize(&serde_json::json!({ "correctnes": "warn" }))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(error, "Unknown category: 'correctnes'. Did you mean 'correctness'?");
+    }
+}
@@ -1,19 +1,21 @@
 use std::{
-    fmt::{self, Debug, Display},
+    fmt::{self, Debug, Display, Write},
     path::{Path, PathBuf},
 };
 
 use itertools::Itertools;
-use oxc_resolver::{ResolveOptions, Resolver};
+use oxc_resolver::{Resolution, ResolveOptions, Resolver};
 use rustc_hash::{FxHashMap, FxHashSet};
 
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{CompactStr, format_compact_str};
 
 use crate::{
     AllowWarnDeny, ExternalPluginStore, LintConfig, LintFilter, LintFilterKind, Oxlintrc,
     RuleCategory, RuleEnum,
     config::{
-        ESLintRule, OxlintOverrides, OxlintRules, overrides::OxlintOverride, plugins::LintPlugins,
+        ESLintRule, OxlintOverrides, OxlintRules, RuleOptionsMap, overrides::OxlintOverride,
+        plugins::LintPlugins,
     },
     external_linter::ExternalLinter,
     external_plugin_store::{ExternalRuleId, ExternalRuleLookupError},
@@ -24,24 +26,35 @@ use super::{
     Config,
     categories::OxlintCategories,
     config_store::{ResolvedOxlintOverride, ResolvedOxlintOverrideRules, ResolvedOxlintOverrides},
+    overrides::GlobSet,
+    rules::is_rule_of_plugin,
 };
 
 #[must_use = "You dropped your builder without building a Linter! Did you mean to call .build()?"]
 pub struct ConfigStoreBuilder {
     pub(super) rules: FxHashMap<RuleEnum, AllowWarnDeny>,
+    /// The options of the rules configured with options, see [`RuleOptions`].
+    rule_options: RuleOptionsMap,
     pub(super) external_rules: FxHashMap<ExternalRuleId, AllowWarnDeny>,
     config: LintConfig,
     categories: OxlintCategories,
     overrides: OxlintOverrides,
 
+    /// Rules whose severity was last set by [`with_filters`](Self::with_filters), i.e. by `-A`,
+    /// `-W` or `-D` on the command line.
+    cli_filtered_rules: FxHashSet<RuleEnum>,
+
     // Collect all `extends` file paths for the language server.
     // The server will tell the clients to watch for the extends files.
     pub extended_paths: Vec<PathBuf>,
+
+    // Unknown keys, rules and environments found in the config file and its `extends` files.
+    pub warnings: Vec<OxcDiagnostic>,
 }
 
 impl Default for ConfigStoreBuilder {
     fn default() -> Self {
-        Self { rules: Self::warn_correctness(LintPlugins::default()), ..Self::empty() }
+        Self { rules: Self::warn_correctness(&LintConfig::default()), ..Self::empty() }
     }
 }
 
@@ -57,8 +70,19 @@ impl ConfigStoreBuilder {
         let categories: OxlintCategories = OxlintCategories::default();
         let overrides = OxlintOverrides::default();
         let extended_paths = Vec::new();
+        let warnings = Vec::new();
 
-        Self { rules, external_rules, config, categories, overrides, extended_paths }
+        Self {
+            rules,
+            rule_options: FxHashMap::default(),
+            external_rules,
+            config,
+            categories,
+            overrides,
+            cli_filtered_rules: FxHashSet::default(),
+            extended_paths,
+            warnings,
+        }
     }
 
     /// Warn on all rules in all plugins and categories, including those in `nursery`.
@@ -72,7 +96,18 @@ impl ConfigStoreBuilder {
         let rules = RULES.iter().map(|rule| (rule.clone(), AllowWarnDeny::Warn)).collect();
         let external_rules = FxHashMap::default();
         let extended_paths = Vec::new();
-        Self { rules, external_rules, config, categories, overrides, extended_paths }
+        let warnings = Vec::new();
+        Self {
+            rules,
+            rule_options: FxHashMap::default(),
+            external_rules,
+            config,
+            categories,
+            overrides,
+            cli_filtered_rules: FxHashSet::default(),
+            extended_paths,
+            warnings,
+        }
     }
 
     /// Create a [`ConfigStoreBuilder`] from a loaded or manually built [`Oxlintrc`].
@@ -93,6 +128,7 @@ impl ConfigStoreBuilder {
     /// # Errors
     ///
     /// Returns [`ConfigBuilderError::InvalidConfigFile`] if a referenced config file is not valid.
+    /// Returns [`ConfigBuilderError::ExtendsCycle`] if a config file (transitively) extends itself.
     pub fn from_oxlintrc(
         start_empty: bool,
         oxlintrc: Oxlintrc,
@@ -100,8 +136,11 @@ impl ConfigStoreBuilder {
         external_plugin_store: &mut ExternalPluginStore,
     ) -> Result<Self, ConfigBuilderError> {
         // TODO: this can be cached to avoid re-computing the same oxlintrc
+        // `chain` holds the canonical and the displayed paths of the configs being resolved,
+        // from the root config to `config`, to detect configs which extend themselves.
         fn resolve_oxlintrc_config(
             config: Oxlintrc,
+            chain: &mut Vec<(PathBuf, PathBuf)>,
         ) -> Result<(Oxlintrc, Vec<PathBuf>), ConfigBuilderError> {
             let path = config.path.clone();
             let root_path = path.parent();
@@ -126,6 +165,19 @@ impl ConfigStoreBuilder {
                     None => path,
                 };
 
+                let canonical_path = canonicalize(path);
+                if let Some(cycle_start) =
+                    chain.iter().position(|(chain_path, _)| *chain_path == canonical_path)
+                {
+                    let chain = chain[cycle_start..]
+                        .iter()
+                        .map(|(_, display_path)| display_path)
+                        .chain(std::iter::once(path))
+                        .map(|path| path.display().to_string())
+                        .collect();
+                    return Err(ConfigBuilderError::ExtendsCycle { chain });
+                }
+
                 let extends_oxlintrc = Oxlintrc::from_file(path).map_err(|e| {
                     ConfigBuilderError::InvalidConfigFile {
                         file: path.display().to_string(),
@@ -135,7 +187,10 @@ impl ConfigStoreBuilder {
 
                 extended_paths.push(path.clone());
 
-                let (extends, extends_paths) = resolve_oxlintrc_config(extends_oxlintrc)?;
+                chain.push((canonical_path, path.clone()));
+                let resolved = resolve_oxlintrc_config(extends_oxlintrc, chain);
+                chain.pop();
+                let (extends, extends_paths) = resolved?;
 
                 oxlintrc = oxlintrc.merge(&extends);
                 extended_paths.extend(extends_paths);
@@ -144,7 +199,12 @@ impl ConfigStoreBuilder {
             Ok((oxlintrc, extended_paths))
         }
 
-        let (oxlintrc, extended_paths) = resolve_oxlintrc_config(oxlintrc)?;
+        fn canonicalize(path: &Path) -> PathBuf {
+            path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+        }
+
+        let mut chain = vec![(canonicalize(&oxlintrc.path), oxlintrc.path.clone())];
+        let (oxlintrc, extended_paths) = resolve_oxlintrc_config(oxlintrc, &mut chain)?;
 
         // Collect external plugins from both base config and overrides
         let mut external_plugins: FxHashSet<(&PathBuf, &str)> = FxHashSet::default();
@@ -172,17 +232,14 @@ impl ConfigStoreBuilder {
                 });
             };
 
-            let resolver = Resolver::new(ResolveOptions {
-                condition_names: vec!["module-sync".into(), "node".into(), "import".into()],
-                ..Default::default()
-            });
+            let resolvers = external_plugin_resolvers();
 
             for (config_path, specifier) in &external_plugins {
                 Self::load_external_plugin(
                     config_path,
                     specifier,
                     external_linter,
-                    &resolver,
+                    &resolvers,
                     external_plugin_store,
                 )?;
             }
@@ -190,30 +247,38 @@ impl ConfigStoreBuilder {
 
         let plugins = oxlintrc.plugins.unwrap_or_default();
 
-        let rules =
-            if start_empty { FxHashMap::default() } else { Self::warn_correctness(plugins) };
-
-        let mut categories = oxlintrc.categories.clone();
-
-        if !start_empty {
-            categories.insert(RuleCategory::Correctness, AllowWarnDeny::Warn);
-        }
-
         let config = LintConfig {
             plugins,
             settings: oxlintrc.settings,
             env: oxlintrc.env,
             globals: oxlintrc.globals,
             path: Some(oxlintrc.path),
+            linter_options: oxlintrc.linter_options,
+            category_overrides: oxlintrc.categories_override.resolve(),
+            vendor_paths: GlobSet::new(&oxlintrc.vendor_paths),
+            per_file_thresholds: oxlintrc.per_file_thresholds,
+            parser_options: oxlintrc.parser_options,
         };
 
+        let rules =
+            if start_empty { FxHashMap::default() } else { Self::warn_correctness(&config) };
+
+        let mut categories = oxlintrc.categories.clone();
+
+        if !start_empty {
+            categories.insert(RuleCategory::Correctness, AllowWarnDeny::Warn);
+        }
+
         let mut builder = Self {
             rules,
+            rule_options: FxHashMap::default(),
             external_rules: FxHashMap::default(),
             config,
             categories,
             overrides: oxlintrc.overrides,
+            cli_filtered_rules: FxHashSet::default(),
             extended_paths,
+            warnings: oxlintrc.warnings,
         };
 
         for filter in oxlintrc.categories.filters() {
@@ -227,7 +292,9 @@ impl ConfigStoreBuilder {
                 .rules
                 .override_rules(
                     &mut builder.rules,
+                    &mut builder.rule_options,
                     &mut builder.external_rules,
+                    &FxHashMap::default(),
                     &all_rules,
                     external_plugin_store,
                 )
@@ -276,9 +343,22 @@ impl ConfigStoreBuilder {
         self.config.plugins
     }
 
+    /// Configure `rule` with `severity` and `options`, e.g. `[{ "allow": ["beforeAll"] }]`, like
+    /// `"jest/no-hooks": ["error", { "allow": ["beforeAll"] }]` in a configuration file does.
     #[cfg(test)]
-    pub(crate) fn with_rule(mut self, rule: RuleEnum, severity: AllowWarnDeny) -> Self {
-        self.rules.insert(rule, severity);
+    pub(crate) fn with_rule(
+        mut self,
+        rule: &RuleEnum,
+        severity: AllowWarnDeny,
+        options: Option<serde_json::Value>,
+    ) -> Self {
+        if let Some(options) = &options {
+            self.rule_options
+                .insert(rule.id(), std::sync::Arc::new(super::RuleOptions::new(options.clone())));
+        } else {
+            self.rule_options.remove(&rule.id());
+        }
+        self.rules.insert(rule.read_json(options.unwrap_or_default()), severity);
         self
     }
 
@@ -288,44 +368,67 @@ impl ConfigStoreBuilder {
         self
     }
 
+    /// Apply the `-A`, `-W` and `-D` filters of the command line. Diagnostics of the rules they
+    /// configure report them as their origin, see [`RuleOrigin`](crate::RuleOrigin).
     pub fn with_filters<'a, I: IntoIterator<Item = &'a LintFilter>>(mut self, filters: I) -> Self {
         for filter in filters {
-            self = self.with_filter(filter);
+            self.apply_filter(filter, true);
         }
         self
     }
 
     pub fn with_filter(mut self, filter: &LintFilter) -> Self {
+        self.apply_filter(filter, false);
+        self
+    }
+
+    fn apply_filter(&mut self, filter: &LintFilter, from_cli: bool) {
         let (severity, filter) = filter.into();
 
         match severity {
             AllowWarnDeny::Deny | AllowWarnDeny::Warn => match filter {
                 LintFilterKind::Category(category) => {
-                    self.upsert_where(severity, |r| r.category() == *category);
+                    self.upsert_where(severity, from_cli, |config, r| {
+                        config.rule_category(r) == *category
+                    });
                 }
                 LintFilterKind::Rule(plugin, rule) => {
                     let (plugin, rule) = super::rules::unalias_plugin_name(plugin, rule);
-                    self.upsert_where(severity, |r| r.plugin_name() == plugin && r.name() == rule);
+                    self.upsert_where(severity, from_cli, |_, r| {
+                        r.plugin_name() == plugin && r.name() == rule
+                    });
+                }
+                LintFilterKind::Generic(name) => {
+                    self.upsert_where(severity, from_cli, |_, r| r.name() == name);
+                }
+                LintFilterKind::Plugin(plugin) => {
+                    self.config.plugins |= *plugin;
+                    self.upsert_where(severity, from_cli, |config, r| {
+                        is_rule_of_plugin(r, *plugin)
+                            && config.rule_category(r) != RuleCategory::Nursery
+                    });
                 }
-                LintFilterKind::Generic(name) => self.upsert_where(severity, |r| r.name() == name),
                 LintFilterKind::All => {
-                    self.upsert_where(severity, |r| r.category() != RuleCategory::Nursery);
+                    self.upsert_where(severity, from_cli, |config, r| {
+                        config.rule_category(r) != RuleCategory::Nursery
+                    });
                 }
             },
             AllowWarnDeny::Allow => match filter {
                 LintFilterKind::Category(category) => {
-                    self.rules.retain(|rule, _| rule.category() != *category);
+                    self.rules.retain(|rule, _| self.config.rule_category(rule) != *category);
                 }
                 LintFilterKind::Rule(plugin, rule) => {
                     let (plugin, rule) = super::rules::unalias_plugin_name(plugin, rule);
                     self.rules.retain(|r, _| r.plugin_name() != plugin || r.name() != rule);
                 }
                 LintFilterKind::Generic(name) => self.rules.retain(|rule, _| rule.name() != name),
+                LintFilterKind::Plugin(plugin) => {
+                    self.rules.retain(|rule, _| !is_rule_of_plugin(rule, *plugin));
+                }
                 LintFilterKind::All => self.rules.clear(),
             },
         }
-
-        self
     }
 
     /// Warn/Deny a let of rules based on some predicate. Rules already in `self.rules` get
@@ -361,13 +464,14 @@ impl ConfigStoreBuilder {
         }
     }
 
-    fn upsert_where<F>(&mut self, severity: AllowWarnDeny, query: F)
+    fn upsert_where<F>(&mut self, severity: AllowWarnDeny, from_cli: bool, query: F)
     where
-        F: Fn(&&RuleEnum) -> bool,
+        F: Fn(&LintConfig, &RuleEnum) -> bool,
     {
         let all_rules = self.get_all_rules();
         // NOTE: we may want to warn users if they're configuring a rule that does not exist.
-        let rules_to_configure = all_rules.iter().filter(query);
+        let rules_to_configure =
+            all_rules.iter().filter(|rule| query(&self.config, rule)).collect::<Vec<_>>();
         for rule in rules_to_configure {
             // If the rule is already in the list, just update its severity.
             // Otherwise, add it to the map.
@@ -376,6 +480,12 @@ impl ConfigStoreBuilder {
                 *existing_rule = severity;
             } else {
                 self.rules.insert(rule.clone(), severity);
+                self.rule_options.remove(&rule.id());
+            }
+            if from_cli {
+                self.cli_filtered_rules.insert(rule.clone());
+            } else {
+                self.cli_filtered_rules.remove(rule);
             }
         }
     }
@@ -398,7 +508,7 @@ impl ConfigStoreBuilder {
         }
 
         let overrides = std::mem::take(&mut self.overrides);
-        let resolved_overrides = self
+        let (resolved_overrides, override_rule_options) = self
             .resolve_overrides(overrides, external_plugin_store)
             .map_err(ConfigBuilderError::ExternalRuleLookupError)?;
 
@@ -415,28 +525,50 @@ impl ConfigStoreBuilder {
         let mut external_rules: Vec<_> = self.external_rules.into_iter().collect();
         external_rules.sort_unstable_by_key(|(r, _)| *r);
 
-        Ok(Config::new(rules, external_rules, self.categories, self.config, resolved_overrides))
+        let cli_filtered_rules = rules
+            .iter()
+            .filter(|(rule, _)| self.cli_filtered_rules.contains(rule))
+            .map(|(rule, _)| rule.id())
+            .collect::<Vec<_>>();
+        Ok(Config::new(rules, external_rules, self.categories, self.config, resolved_overrides)
+            .with_cli_filtered_rules(cli_filtered_rules)
+            .with_rule_options(self.rule_options, override_rule_options))
     }
 
+    /// Resolve the rules of `overrides`, and the options of the rules each of them configures.
     fn resolve_overrides(
         &self,
         overrides: OxlintOverrides,
         external_plugin_store: &ExternalPluginStore,
-    ) -> Result<ResolvedOxlintOverrides, ExternalRuleLookupError> {
-        let resolved = overrides
+    ) -> Result<(ResolvedOxlintOverrides, Vec<RuleOptionsMap>), ExternalRuleLookupError> {
+        let (resolved, rule_options): (Vec<_>, Vec<_>) = overrides
             .into_iter()
             .map(|override_config| {
                 let mut builtin_rules = Vec::new();
                 let mut external_rules = Vec::new();
                 let mut rules_map = FxHashMap::default();
+                let mut rule_options = FxHashMap::default();
                 let mut external_rules_map = FxHashMap::default();
 
                 let all_rules = self.get_all_rules_for_plugins(override_config.plugins);
 
+                // Categories are applied first, so that the rules of this override take precedence
+                for (category, severity) in override_config.categories.iter() {
+                    for rule in
+                        all_rules.iter().filter(|rule| self.config.rule_category(rule) == *category)
+                    {
+                        // Keep the options of rules which are configured in the base config
+                        let rule = self.rules.get_key_value(rule).map_or(rule, |(rule, _)| rule);
+                        rules_map.insert(rule.clone(), *severity);
+                    }
+                }
+
                 // Resolve rules for this override
                 override_config.rules.override_rules(
                     &mut rules_map,
+                    &mut rule_options,
                     &mut external_rules_map,
+                    &self.rules,
                     &all_rules,
                     external_plugin_store,
                 )?;
@@ -445,21 +577,36 @@ impl ConfigStoreBuilder {
                 builtin_rules.extend(rules_map.into_iter());
                 external_rules.extend(external_rules_map.into_iter());
 
-                Ok(ResolvedOxlintOverride {
+                // Like external rules, processors are ignored in language server,
+                // which can't load JS plugins.
+                let processor = override_config
+                    .processor
+                    .as_deref()
+                    .filter(|_| external_plugin_store.is_enabled())
+                    .map(|name| external_plugin_store.lookup_processor_id(name))
+                    .transpose()?;
+
+                let resolved = ResolvedOxlintOverride {
                     files: override_config.files,
                     env: override_config.env,
                     globals: override_config.globals,
                     plugins: override_config.plugins,
                     rules: ResolvedOxlintOverrideRules { builtin_rules, external_rules },
-                })
+                    processor,
+                    parser_options: override_config.parser_options,
+                };
+                Ok((resolved, rule_options))
             })
-            .collect::<Result<Vec<_>, _>>()?;
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
 
-        Ok(ResolvedOxlintOverrides::new(resolved))
+        Ok((ResolvedOxlintOverrides::new(resolved), rule_options))
     }
 
-    /// Warn for all correctness rules in the given set of plugins.
-    fn warn_correctness(mut plugins: LintPlugins) -> FxHashMap<RuleEnum, AllowWarnDeny> {
+    /// Warn for all correctness rules in the plugins enabled by `config`.
+    fn warn_correctness(config: &LintConfig) -> FxHashMap<RuleEnum, AllowWarnDeny> {
+        let mut plugins = config.plugins;
         if plugins.contains(LintPlugins::VITEST) {
             plugins |= LintPlugins::JEST;
         }
@@ -468,7 +615,7 @@ impl ConfigStoreBuilder {
             .filter(|rule| {
                 // NOTE: this logic means there's no way to disable ESLint
                 // correctness rules. I think that's fine for now.
-                rule.category() == RuleCategory::Correctness
+                config.rule_category(rule) == RuleCategory::Correctness
                     && LintPlugins::try_from(rule.plugin_name())
                         .is_ok_and(|plugin_flag| plugins.contains(plugin_flag))
             })
@@ -476,9 +623,18 @@ impl ConfigStoreBuilder {
             .collect()
     }
 
+    /// Print `oxlintrc` with the rules of this builder.
+    ///
+    /// With `include_default_configs`, rules which are not configured with any options are printed
+    /// with the options they use by default.
+    ///
     /// # Panics
     /// This function will panic if the `oxlintrc` is not valid JSON.
-    pub fn resolve_final_config_file(&self, oxlintrc: Oxlintrc) -> String {
+    pub fn resolve_final_config_file(
+        &self,
+        oxlintrc: Oxlintrc,
+        include_default_configs: bool,
+    ) -> String {
         let mut oxlintrc = oxlintrc;
         let previous_rules = std::mem::take(&mut oxlintrc.rules);
 
@@ -498,7 +654,8 @@ impl ConfigStoreBuilder {
                 severity: *severity,
                 config: rule_name_to_rule
                     .get(&get_name(r.plugin_name(), r.name()))
-                    .and_then(|r| r.config.clone()),
+                    .and_then(|r| r.config.clone())
+                    .or_else(|| if include_default_configs { r.default_config() } else { None }),
             })
             .collect();
 
@@ -510,7 +667,7 @@ impl ConfigStoreBuilder {
         resolve_dir: &Path,
         plugin_specifier: &str,
         external_linter: &ExternalLinter,
-        resolver: &Resolver,
+        resolvers: &[Resolver],
         external_plugin_store: &mut ExternalPluginStore,
     ) -> Result<(), ConfigBuilderError> {
         use crate::PluginLoadResult;
@@ -524,12 +681,13 @@ impl ConfigStoreBuilder {
         }
 
         // Resolve the specifier relative to the config directory
-        let resolved = resolver.resolve(resolve_dir, plugin_specifier).map_err(|e| {
-            ConfigBuilderError::PluginLoadFailed {
-                plugin_specifier: plugin_specifier.to_string(),
-                error: e.to_string(),
-            }
-        })?;
+        let resolved =
+            resolve_external_plugin(resolvers, resolve_dir, plugin_specifier).map_err(|error| {
+                ConfigBuilderError::PluginLoadFailed {
+                    plugin_specifier: plugin_specifier.to_string(),
+                    error,
+                }
+            })?;
         // TODO: We should support paths which are not valid UTF-8. How?
         let plugin_path = resolved.full_path().to_str().unwrap().to_string();
 
@@ -537,8 +695,13 @@ impl ConfigStoreBuilder {
             return Ok(());
         }
 
-        // Extract package name from package.json if available
-        let package_name = resolved.package_json().and_then(|pkg| pkg.name().map(String::from));
+        // Extract package name from package.json if available,
+        // or from the specifier if the package has no `name` field
+        let package_name = resolved
+            .package_json()
+            .and_then(|pkg| pkg.name())
+            .or_else(|| bare_specifier_package_name(plugin_specifier))
+            .map(String::from);
 
         let result = {
             let plugin_path = plugin_path.clone();
@@ -551,7 +714,13 @@ impl ConfigStoreBuilder {
         }?;
 
         match result {
-            PluginLoadResult::Success { name, offset, rule_names } => {
+            PluginLoadResult::Success {
+                name,
+                offset,
+                rule_names,
+                processor_offset,
+                processor_names,
+            } => {
                 // Normalize plugin name (e.g., "eslint-plugin-foo" -> "foo", "@foo/eslint-plugin" -> "@foo")
                 use crate::config::plugins::normalize_plugin_name;
                 let normalized_name = normalize_plugin_name(&name).into_owned();
@@ -559,10 +728,15 @@ impl ConfigStoreBuilder {
                 if LintPlugins::try_from(normalized_name.as_str()).is_err() {
                     external_plugin_store.register_plugin(
                         plugin_path,
-                        normalized_name,
+                        normalized_name.clone(),
                         offset,
                         rule_names,
                     );
+                    external_plugin_store.register_processors(
+                        &normalized_name,
+                        processor_offset,
+                        processor_names,
+                    );
                     Ok(())
                 } else {
                     Err(ConfigBuilderError::ReservedExternalPluginName {
@@ -578,6 +752,73 @@ impl ConfigStoreBuilder {
     }
 }
 
+/// Resolvers for the specifiers of JS plugins, in the order they are tried.
+///
+/// Plugins are imported, but packages which only export CommonJS are resolved
+/// with the `require` conditions as a fallback.
+fn external_plugin_resolvers() -> [Resolver; 2] {
+    let import_resolver = Resolver::new(ResolveOptions {
+        condition_names: vec!["module-sync".into(), "node".into(), "import".into()],
+        ..Default::default()
+    });
+    let require_resolver = import_resolver.clone_with_options(ResolveOptions {
+        condition_names: vec!["node".into(), "require".into()],
+        ..Default::default()
+    });
+    [import_resolver, require_resolver]
+}
+
+/// Resolve the specifier of a JS plugin relative to `resolve_dir`.
+///
+/// Bare specifiers (e.g. `@org/oxlint-plugin-foo/rules`) are resolved with each of `resolvers` in
+/// turn, which differ in the conditions matched in the `exports` field of `package.json`.
+/// If none resolves the specifier, the error lists the conditions and error of each attempt.
+fn resolve_external_plugin(
+    resolvers: &[Resolver],
+    resolve_dir: &Path,
+    specifier: &str,
+) -> Result<Resolution, String> {
+    // Conditions only apply to packages, so paths are only resolved once.
+    let resolvers =
+        if bare_specifier_package_name(specifier).is_some() { resolvers } else { &resolvers[..1] };
+    if let [resolver] = resolvers {
+        return resolver.resolve(resolve_dir, specifier).map_err(|e| e.to_string());
+    }
+
+    let mut trace = format!("Cannot resolve '{specifier}' from '{}'", resolve_dir.display());
+    for resolver in resolvers {
+        match resolver.resolve(resolve_dir, specifier) {
+            Ok(resolution) => return Ok(resolution),
+            Err(e) => {
+                let conditions = resolver.options().condition_names.iter().format(", ");
+                write!(trace, "\n    with conditions [{conditions}]: {e}").unwrap();
+            }
+        }
+    }
+    Err(trace)
+}
+
+/// Name of the package a bare specifier imports from, e.g. `@org/oxlint-plugin-foo` for
+/// `@org/oxlint-plugin-foo/rules`. `None` for relative and absolute paths.
+fn bare_specifier_package_name(specifier: &str) -> Option<&str> {
+    if specifier.is_empty()
+        || specifier.starts_with(['.', '/', '#'])
+        || Path::new(specifier).is_absolute()
+        || specifier.contains(':')
+    {
+        return None;
+    }
+    let mut segments = specifier.match_indices('/').map(|(index, _)| index);
+    let end = if specifier.starts_with('@') {
+        // Scoped packages have a name like `@scope/name`, without a scope they are not valid.
+        segments.next()?;
+        segments.next()
+    } else {
+        segments.next()
+    };
+    Some(end.map_or(specifier, |end| &specifier[..end]))
+}
+
 fn get_name(plugin_name: &str, rule_name: &str) -> CompactStr {
     if plugin_name == "eslint" {
         CompactStr::from(rule_name)
@@ -618,6 +859,11 @@ pub enum ConfigBuilderError {
     ReservedExternalPluginName {
         plugin_name: String,
     },
+    /// A configuration file (transitively) extends itself.
+    /// `chain` lists the files of the cycle, starting and ending with the same file.
+    ExtendsCycle {
+        chain: Vec<String>,
+    },
 }
 
 impl Display for ConfigBuilderError {
@@ -655,6 +901,9 @@ impl Display for ConfigBuilderError {
                 )?;
                 Ok(())
             }
+            ConfigBuilderError::ExtendsCycle { chain } => {
+                write!(f, "config file extends itself: {}", chain.join(" -> "))
+            }
             ConfigBuilderError::ExternalRuleLookupError(e) => std::fmt::Display::fmt(&e, f),
         }
     }
@@ -765,6 +1014,27 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_filter_plugin() {
+        let builder = ConfigStoreBuilder::default()
+            .with_filter(&LintFilter::new(AllowWarnDeny::Deny, "react").unwrap());
+        assert!(builder.plugins().contains(LintPlugins::REACT));
+        let react_rules =
+            builder.rules.iter().filter(|(r, _)| r.plugin_name() == "react").collect::<Vec<_>>();
+        assert!(!react_rules.is_empty(), "Denying a plugin should enable its rules");
+        for (rule, severity) in react_rules {
+            assert_ne!(rule.category(), RuleCategory::Nursery);
+            assert_eq!(*severity, AllowWarnDeny::Deny);
+        }
+
+        let builder = builder.with_filter(&LintFilter::new(AllowWarnDeny::Allow, "react").unwrap());
+        assert!(!builder.rules.is_empty());
+        assert!(
+            !builder.rules.iter().any(|(r, _)| r.plugin_name() == "react"),
+            "Allowing a plugin should remove all of its rules"
+        );
+    }
+
     #[test]
     fn test_filter_allow_all_then_warn() {
         let builder = ConfigStoreBuilder::default()
@@ -964,6 +1234,134 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_categories_override() {
+        let oxlintrc: Oxlintrc = serde_json::from_str(
+            r#"
+        {
+            "categories": {
+                "suspicious": "deny"
+            },
+            "categoriesOverride": {
+                "no-debugger": "style",
+                "unicorn/no-array-for-each": "suspicious"
+            }
+        }
+        "#,
+        )
+        .unwrap();
+        let builder = {
+            let mut external_plugin_store = ExternalPluginStore::default();
+            ConfigStoreBuilder::from_oxlintrc(false, oxlintrc, None, &mut external_plugin_store)
+                .unwrap()
+        };
+        let severity_of = |plugin_name: &str, rule_name: &str| {
+            builder
+                .rules
+                .iter()
+                .find(|(r, _)| r.plugin_name() == plugin_name && r.name() == rule_name)
+                .map(|(_, severity)| *severity)
+        };
+
+        // `no-debugger` is no longer a correctness rule, so it is not enabled by default
+        assert_eq!(severity_of("eslint", "no-debugger"), None);
+        assert_eq!(severity_of("unicorn", "no-array-for-each"), Some(AllowWarnDeny::Deny));
+        assert_eq!(severity_of("eslint", "no-const-assign"), Some(AllowWarnDeny::Warn));
+    }
+
+    #[test]
+    fn test_override_categories() {
+        let config = config_store_from_str(
+            r#"
+        {
+            "rules": {
+                "eqeqeq": ["deny", "smart"]
+            },
+            "overrides": [
+                {
+                    "files": ["src/new-code/**"],
+                    "categories": {
+                        "pedantic": "warn"
+                    },
+                    "rules": {
+                        "max-depth": "off"
+                    }
+                }
+            ]
+        }
+        "#,
+        );
+        let severity_of = |path: &str, rule_name: &str| {
+            config
+                .apply_overrides(Path::new(path))
+                .rules
+                .iter()
+                .find(|(rule, _)| rule.name() == rule_name)
+                .map(|(rule, severity)| (format!("{rule:?}"), *severity))
+        };
+
+        // the category is enabled for the matched files only
+        assert!(severity_of("src/old-code/index.js", "max-lines").is_none());
+        assert_eq!(
+            severity_of("src/new-code/index.js", "max-lines").map(|(_, severity)| severity),
+            Some(AllowWarnDeny::Warn)
+        );
+        // rules of the base config keep their options
+        let (eqeqeq, severity) = severity_of("src/new-code/index.js", "eqeqeq").unwrap();
+        assert_eq!(severity, AllowWarnDeny::Warn);
+        assert!(eqeqeq.contains("Smart"), "{eqeqeq}");
+        assert_eq!(
+            severity_of("src/old-code/index.js", "eqeqeq").map(|(_, severity)| severity),
+            Some(AllowWarnDeny::Deny)
+        );
+        // rules of the same override take precedence over its categories
+        assert!(severity_of("src/new-code/index.js", "max-depth").is_none());
+    }
+
+    #[test]
+    fn test_severity_only_keeps_options() {
+        let config = config_store_from_str(
+            r#"
+        {
+            "extends": ["fixtures/extends_config/severities/options.json"],
+            "rules": {
+                "eqeqeq": 1,
+                "no-console": ["warn"]
+            },
+            "overrides": [
+                {
+                    "files": ["*.test.js"],
+                    "rules": {
+                        "eqeqeq": "Error",
+                        "no-console": [2.0]
+                    }
+                }
+            ]
+        }
+        "#,
+        );
+        let rule_of = |path: &str, rule_name: &str| {
+            config
+                .apply_overrides(Path::new(path))
+                .rules
+                .iter()
+                .find(|(rule, _)| rule.name() == rule_name)
+                .map(|(rule, severity)| (format!("{rule:?}"), *severity))
+                .unwrap()
+        };
+
+        for (path, severity) in
+            [("index.js", AllowWarnDeny::Warn), ("index.test.js", AllowWarnDeny::Deny)]
+        {
+            let (eqeqeq, eqeqeq_severity) = rule_of(path, "eqeqeq");
+            assert_eq!(eqeqeq_severity, severity);
+            assert!(eqeqeq.contains("Smart"), "{eqeqeq}");
+            let (no_console, no_console_severity) = rule_of(path, "no-console");
+            assert_eq!(no_console_severity, severity);
+            assert!(no_console.contains("info"), "{no_console}");
+        }
+    }
+
     #[test]
     fn test_extends_rules_single() {
         let base_config = config_store_from_path("fixtures/extends_config/rules_config.json");
@@ -1122,6 +1520,41 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_extends_cycle() {
+        let build = |path: &str| {
+            let mut external_plugin_store = ExternalPluginStore::default();
+            ConfigStoreBuilder::from_oxlintrc(
+                true,
+                Oxlintrc::from_file(&PathBuf::from(path)).unwrap(),
+                None,
+                &mut external_plugin_store,
+            )
+        };
+        let file_names = |chain: &[String]| {
+            chain
+                .iter()
+                .map(|path| Path::new(path).file_name().unwrap().to_string_lossy().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let err = build("fixtures/extends_config/cycle/a.json").unwrap_err();
+        let ConfigBuilderError::ExtendsCycle { chain } = &err else {
+            panic!("expected an extends cycle error, got {err:?}");
+        };
+        assert_eq!(file_names(chain), ["a.json", "b.json", "a.json"]);
+        assert!(err.to_string().starts_with("config file extends itself: "));
+
+        let err = build("fixtures/extends_config/cycle/self.json").unwrap_err();
+        let ConfigBuilderError::ExtendsCycle { chain } = &err else {
+            panic!("expected an extends cycle error, got {err:?}");
+        };
+        assert_eq!(file_names(chain), ["self.json", "self.json"]);
+
+        // extending the same config twice is not a cycle
+        assert!(build("fixtures/extends_config/cycle/diamond.json").is_ok());
+    }
+
     #[test]
     fn test_extends_plugins() {
         // Test 1: Default plugins when none are specified
@@ -1278,6 +1711,60 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_bare_specifier_package_name() {
+        assert_eq!(bare_specifier_package_name("oxlint-plugin-foo"), Some("oxlint-plugin-foo"));
+        assert_eq!(
+            bare_specifier_package_name("oxlint-plugin-foo/rules"),
+            Some("oxlint-plugin-foo")
+        );
+        assert_eq!(
+            bare_specifier_package_name("@org/oxlint-plugin-foo"),
+            Some("@org/oxlint-plugin-foo")
+        );
+        assert_eq!(
+            bare_specifier_package_name("@org/oxlint-plugin-foo/rules/index.js"),
+            Some("@org/oxlint-plugin-foo")
+        );
+        assert_eq!(bare_specifier_package_name("@org"), None);
+        assert_eq!(bare_specifier_package_name("./plugin.js"), None);
+        assert_eq!(bare_specifier_package_name("../plugins/plugin.js"), None);
+        assert_eq!(bare_specifier_package_name("/plugins/plugin.js"), None);
+        assert_eq!(bare_specifier_package_name("#plugin"), None);
+        assert_eq!(bare_specifier_package_name("node:fs"), None);
+    }
+
+    #[test]
+    fn test_resolve_external_plugin() {
+        let resolvers = external_plugin_resolvers();
+        let dir = std::env::current_dir().unwrap().join("fixtures/external_plugins");
+        let resolve = |specifier| resolve_external_plugin(&resolvers, &dir, specifier);
+
+        // Subpath exported with the `import` condition, next to a `package.json` without a name
+        let resolution = resolve("@scoped/eslint-plugin/rules").unwrap();
+        assert!(resolution.full_path().ends_with("@scoped/eslint-plugin/dist/rules.js"));
+        assert_eq!(
+            resolution.package_json().and_then(|pkg| pkg.name()),
+            Some("@scoped/eslint-plugin")
+        );
+
+        // Subpath only exported with the `require` condition
+        let resolution = resolve("@scoped/cjs-plugin/rules").unwrap();
+        assert!(resolution.full_path().ends_with("@scoped/cjs-plugin/rules.cjs"));
+
+        // Each attempt is reported when a subpath is not exported
+        let error = resolve("@scoped/eslint-plugin/missing").unwrap_err();
+        let lines = error.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("Cannot resolve '@scoped/eslint-plugin/missing' from '"));
+        assert!(lines[1].starts_with("    with conditions [module-sync, node, import]: "));
+        assert!(lines[2].starts_with("    with conditions [node, require]: "));
+        assert!(lines[1].contains("\"./missing\" is not exported"));
+
+        // Paths are resolved once, with the error of the resolver
+        assert_eq!(resolve("./missing.js").unwrap_err(), "Cannot find module './missing.js'");
+    }
+
     fn config_store_from_path(path: &str) -> Config {
         let mut external_plugin_store = ExternalPluginStore::default();
         ConfigStoreBuilder::from_oxlintrc(
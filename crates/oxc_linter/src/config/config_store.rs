@@ -1,19 +1,24 @@
 use std::{
+    collections::hash_map::Entry,
     path::{Path, PathBuf},
     sync::Arc,
 };
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxBuildHasher, FxHashMap};
+
+use oxc_diagnostics::NormalizedPath;
+use oxc_span::SourceType;
 
 use crate::{
     AllowWarnDeny,
-    external_plugin_store::{ExternalPluginStore, ExternalRuleId},
+    external_plugin_store::{ExternalPluginStore, ExternalProcessorId, ExternalRuleId},
+    frameworks::FrameworkOptions,
     rules::{RULES, RuleEnum},
 };
 
 use super::{
-    LintConfig, LintPlugins, OxlintEnv, OxlintGlobals, categories::OxlintCategories,
-    overrides::GlobSet,
+    LintConfig, LintPlugins, OxlintEnv, OxlintGlobals, OxlintParserOptions, RuleOptions,
+    RuleOptionsMap, RuleOrigin, RuleOriginKind, categories::OxlintCategories, overrides::GlobSet,
 };
 
 // TODO: support `categories` et. al. in overrides.
@@ -24,6 +29,160 @@ pub struct ResolvedLinterState {
     pub config: Arc<LintConfig>,
 
     pub external_rules: Arc<[(ExternalRuleId, AllowWarnDeny)]>,
+
+    /// `rules` filtered for each source type and framework options, shared by every file
+    /// resolved to this state.
+    pub(crate) rules_by_source: Arc<RulesBySource>,
+
+    /// What set the severity of rules, by rule id. Rules which are not in the map were set by
+    /// `rules` or `categories` of the configuration file.
+    rule_sources: Arc<FxHashMap<usize, RuleSource>>,
+    external_rule_sources: Arc<FxHashMap<ExternalRuleId, RuleSource>>,
+    /// The options of `rules`, by rule id, read by rules with [`LintContext::options`].
+    ///
+    /// [`LintContext::options`]: crate::LintContext::options
+    rule_options: Arc<RuleOptionsMap>,
+    /// Whether the configuration file is a nested configuration file, set by [`ConfigStore::resolve`].
+    nested: bool,
+    /// The processor of a JS plugin which extracts the JS/TS code of the file, set by the last
+    /// matching override which configures one.
+    pub processor: Option<ExternalProcessorId>,
+}
+
+/// What set the severity of a rule in a [`ResolvedLinterState`], see [`RuleOrigin`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RuleSource {
+    /// The override at this index
+    Override(usize),
+    CliFilter,
+}
+
+impl ResolvedLinterState {
+    fn new(
+        rules: Arc<[(RuleEnum, AllowWarnDeny)]>,
+        config: Arc<LintConfig>,
+        external_rules: Arc<[(ExternalRuleId, AllowWarnDeny)]>,
+    ) -> Self {
+        Self {
+            rules,
+            config,
+            external_rules,
+            rules_by_source: Arc::default(),
+            rule_sources: Arc::default(),
+            external_rule_sources: Arc::default(),
+            rule_options: Arc::default(),
+            nested: false,
+            processor: None,
+        }
+    }
+
+    /// Whether a rule of this state reads JSDoc comments, so they need to be parsed.
+    pub fn uses_jsdoc(&self) -> bool {
+        self.rules.iter().any(|(rule, _)| {
+            rule.plugin_name() == "jsdoc" || matches!(rule, RuleEnum::EslintNoEval(_))
+        })
+    }
+
+    /// The options the built-in rule with `rule_id` is configured with, if any.
+    pub fn rule_options(&self, rule_id: usize) -> Option<&Arc<RuleOptions>> {
+        self.rule_options.get(&rule_id)
+    }
+
+    /// The configuration which decided the severity of the built-in rule with `rule_id`.
+    pub fn rule_origin(&self, rule_id: usize) -> RuleOrigin {
+        self.origin(self.rule_sources.get(&rule_id).copied())
+    }
+
+    /// The configuration which decided the severity of the JS plugin rule with `rule_id`.
+    pub fn external_rule_origin(&self, rule_id: ExternalRuleId) -> RuleOrigin {
+        self.origin(self.external_rule_sources.get(&rule_id).copied())
+    }
+
+    fn origin(&self, source: Option<RuleSource>) -> RuleOrigin {
+        let config_path = self.config.path.clone();
+        match source {
+            None => RuleOrigin {
+                kind: if self.nested {
+                    RuleOriginKind::NestedConfig
+                } else {
+                    RuleOriginKind::RootConfig
+                },
+                config_path,
+                override_index: None,
+            },
+            Some(RuleSource::Override(index)) => RuleOrigin {
+                kind: RuleOriginKind::Override,
+                config_path,
+                override_index: Some(index),
+            },
+            Some(RuleSource::CliFilter) => RuleOrigin {
+                kind: RuleOriginKind::CliFilter,
+                config_path: None,
+                override_index: None,
+            },
+        }
+    }
+
+    /// Cap the severity of every rule at `max_severity`.
+    /// All rules are turned off if `max_severity` is [`AllowWarnDeny::Allow`].
+    fn with_max_severity(self, max_severity: AllowWarnDeny) -> Self {
+        match max_severity {
+            AllowWarnDeny::Deny => self,
+            AllowWarnDeny::Warn => Self {
+                rules: self
+                    .rules
+                    .iter()
+                    .map(|(rule, _)| (rule.clone(), AllowWarnDeny::Warn))
+                    .collect(),
+                external_rules: self
+                    .external_rules
+                    .iter()
+                    .map(|(rule_id, _)| (*rule_id, AllowWarnDeny::Warn))
+                    .collect(),
+                rules_by_source: Arc::default(),
+                ..self
+            },
+            AllowWarnDeny::Allow => Self::new(Arc::from([]), self.config, Arc::from([])),
+        }
+    }
+}
+
+/// Rules of a [`ResolvedLinterState`] which can run on a script block, by its source type and
+/// framework options.
+///
+/// Filtering with [`RuleEnum::should_run_on_source`] is done once per resolved configuration,
+/// instead of once per file. The map is lock-free, so files linted in parallel do not wait for
+/// each other.
+#[derive(Debug, Default)]
+pub struct RulesBySource(ConcurrentMap<(SourceType, FrameworkOptions), RulesWithSeverity>);
+
+type ConcurrentMap<K, V> = papaya::HashMap<K, V, FxBuildHasher>;
+
+type RulesWithSeverity = Arc<[(RuleEnum, AllowWarnDeny)]>;
+
+impl RulesBySource {
+    /// Get the `rules` which can run on script blocks with `source_type` and `framework_options`.
+    ///
+    /// Type-aware rules are always left out, they are run by `tsgolint`.
+    pub(crate) fn get_or_filter(
+        &self,
+        rules: &[(RuleEnum, AllowWarnDeny)],
+        source_type: SourceType,
+        framework_options: FrameworkOptions,
+    ) -> Arc<[(RuleEnum, AllowWarnDeny)]> {
+        let rules_by_source = self.0.pin();
+        let rules = rules_by_source.get_or_insert_with((source_type, framework_options), || {
+            rules
+                .iter()
+                .filter(|(rule, _)| {
+                    !rule.is_tsgolint_rule()
+                        && rule.should_run_on_source(source_type, framework_options)
+                })
+                .cloned()
+                .collect()
+        });
+        Arc::clone(rules)
+    }
 }
 
 #[derive(Debug, Default, Clone)]
@@ -50,6 +209,24 @@ pub struct ResolvedOxlintOverride {
     pub globals: Option<OxlintGlobals>,
     pub plugins: Option<LintPlugins>,
     pub rules: ResolvedOxlintOverrideRules,
+    pub processor: Option<ExternalProcessorId>,
+    pub parser_options: OxlintParserOptions,
+}
+
+impl ResolvedOxlintOverride {
+    /// Apply the `env` and `globals` of this override to those of the files it matches.
+    ///
+    /// Like in ESLint, the merge is additive: each environment and global variable configured by
+    /// the override replaces the value of the same key, and all other keys are kept. Environments
+    /// are disabled with `false`, and global variables with `"off"`.
+    fn apply_env_and_globals(&self, env: &mut OxlintEnv, globals: &mut OxlintGlobals) {
+        if let Some(override_env) = &self.env {
+            override_env.override_envs(env);
+        }
+        if let Some(override_globals) = &self.globals {
+            override_globals.override_globals(globals);
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -75,8 +252,20 @@ pub struct Config {
 
     /// An optional set of overrides to apply to the base state depending on the file being linted.
     pub(crate) overrides: ResolvedOxlintOverrides,
+
+    /// The options of the rules configured by each override, which replace those of the base
+    /// state.
+    override_rule_options: Vec<RuleOptionsMap>,
+
+    /// States resolved for files matching overrides, by the indices of the matching overrides and
+    /// whether the file is a vendor file. Files matching the same overrides share the same state,
+    /// and with it the rules filtered for each source type.
+    resolved_states: Arc<ConcurrentMap<ResolvedStateKey, ResolvedLinterState>>,
 }
 
+/// The indices of the overrides matching a file and whether it is a vendor file.
+type ResolvedStateKey = (Box<[usize]>, bool);
+
 impl Config {
     pub fn new(
         rules: Vec<(RuleEnum, AllowWarnDeny)>,
@@ -86,8 +275,8 @@ impl Config {
         overrides: ResolvedOxlintOverrides,
     ) -> Self {
         Config {
-            base: ResolvedLinterState {
-                rules: Arc::from(
+            base: ResolvedLinterState::new(
+                Arc::from(
                     rules
                         .iter()
                         .filter(|(_, severity)| severity.is_warn_deny())
@@ -95,22 +284,50 @@ impl Config {
                         .collect::<Vec<_>>()
                         .into_boxed_slice(),
                 ),
-                config: Arc::new(config),
-                external_rules: Arc::from({
+                Arc::new(config),
+                Arc::from({
                     external_rules.retain(|(_, sev)| sev.is_warn_deny());
                     external_rules.into_boxed_slice()
                 }),
-            },
+            ),
             base_rules: rules,
             categories,
             overrides,
+            override_rule_options: Vec::new(),
+            resolved_states: Arc::default(),
         }
     }
 
+    /// Report `rule_ids` as set by `-A`, `-W` or `-D` on the command line, see [`RuleOrigin`].
+    pub(crate) fn with_cli_filtered_rules(mut self, rule_ids: Vec<usize>) -> Self {
+        self.base.rule_sources =
+            Arc::new(rule_ids.into_iter().map(|id| (id, RuleSource::CliFilter)).collect());
+        self
+    }
+
+    /// Configure rules with the options of the configuration file and its overrides, by rule id.
+    pub(crate) fn with_rule_options(
+        mut self,
+        rule_options: RuleOptionsMap,
+        override_rule_options: Vec<RuleOptionsMap>,
+    ) -> Self {
+        self.base.rule_options = Arc::new(rule_options);
+        self.override_rule_options = override_rule_options;
+        self
+    }
+
     pub fn plugins(&self) -> LintPlugins {
         self.base.config.plugins
     }
 
+    /// Plugins enabled for some files, by the configuration or one of its overrides.
+    pub fn all_plugins(&self) -> LintPlugins {
+        self.overrides
+            .iter()
+            .filter_map(|override_config| override_config.plugins)
+            .fold(self.plugins(), |plugins, override_plugins| plugins | override_plugins)
+    }
+
     pub fn rules(&self) -> &Arc<[(RuleEnum, AllowWarnDeny)]> {
         &self.base.rules
     }
@@ -119,37 +336,82 @@ impl Config {
         self.base.rules.len()
     }
 
+    /// The rules of the base configuration, including those turned off with `"off"` in `rules`.
+    /// Overrides are not applied.
+    pub fn configured_rules(&self) -> &[(RuleEnum, AllowWarnDeny)] {
+        &self.base_rules
+    }
+
     pub fn apply_overrides(&self, path: &Path) -> ResolvedLinterState {
-        if self.overrides.is_empty() {
+        let vendor_paths = &self.base.config.vendor_paths;
+        if self.overrides.is_empty() && vendor_paths.is_empty() {
             return self.base.clone();
         }
 
-        let relative_path = self
-            .base
-            .config
-            .path
-            .as_ref()
-            .and_then(|config_path| {
-                config_path.parent().map(|parent| path.strip_prefix(parent).unwrap_or(path))
-            })
-            .unwrap_or(path);
+        // Globs are written with `/`, match them against a normalized path on every platform.
+        let path = match self.base.config.path.as_ref().and_then(|path| path.parent()) {
+            Some(config_dir) => NormalizedPath::relative_to(path, config_dir),
+            None => NormalizedPath::new(path),
+        };
+        let matching_overrides = self
+            .overrides
+            .iter()
+            .enumerate()
+            .filter(|(_, config)| config.files.is_match(path.as_str()))
+            .map(|(index, _)| index)
+            .collect::<Box<[usize]>>();
+        let is_vendor = vendor_paths.is_match(path.as_str());
+        if matching_overrides.is_empty() && !is_vendor {
+            return self.base.clone();
+        }
 
-        let path = relative_path.to_string_lossy();
-        let overrides_to_apply =
-            self.overrides.iter().filter(|config| config.files.is_match(path.as_ref()));
+        let resolved_states = self.resolved_states.pin();
+        let key = (matching_overrides, is_vendor);
+        if let Some(state) = resolved_states.get(&key) {
+            return state.clone();
+        }
+        // Files resolved in parallel may resolve the same state twice, only one is kept.
+        let state = self.apply_matching_overrides(&key.0);
+        let state = if is_vendor {
+            state.with_max_severity(self.base.config.linter_options.vendor_severity())
+        } else {
+            state
+        };
+        resolved_states.get_or_insert(key, state).clone()
+    }
 
-        let mut overrides_to_apply = overrides_to_apply.peekable();
+    /// The parser options of the base configuration, with those of the overrides matching `path`
+    /// applied in order.
+    pub fn parser_options(&self, path: &Path) -> OxlintParserOptions {
+        let base = self.base.config.parser_options;
+        if self.overrides.is_empty() {
+            return base;
+        }
+        let path = match self.base.config.path.as_ref().and_then(|path| path.parent()) {
+            Some(config_dir) => NormalizedPath::relative_to(path, config_dir),
+            None => NormalizedPath::new(path),
+        };
+        self.overrides
+            .iter()
+            .filter(|config| config.files.is_match(path.as_str()))
+            .fold(base, |options, config| config.parser_options.merge(options))
+    }
 
-        if overrides_to_apply.peek().is_none() {
+    /// Apply the overrides at `matching_overrides`, in order.
+    fn apply_matching_overrides(&self, matching_overrides: &[usize]) -> ResolvedLinterState {
+        if matching_overrides.is_empty() {
             return self.base.clone();
         }
 
+        let overrides_to_apply =
+            matching_overrides.iter().map(|&index| (index, &self.overrides.0[index]));
+
         let mut env = self.base.config.env.clone();
         let mut globals = self.base.config.globals.clone();
         let mut plugins = self.base.config.plugins;
         let settings = self.base.config.settings.clone();
 
-        for override_config in overrides_to_apply.clone() {
+        for (_, override_config) in overrides_to_apply.clone() {
             if let Some(override_plugins) = override_config.plugins {
                 plugins |= override_plugins;
             }
@@ -177,11 +439,15 @@ impl Config {
         let mut external_rules =
             self.base.external_rules.iter().copied().collect::<FxHashMap<_, _>>();
 
+        let mut rule_sources = (*self.base.rule_sources).clone();
+        let mut external_rule_sources = (*self.base.external_rule_sources).clone();
+        let mut rule_options = (*self.base.rule_options).clone();
+
         // Track which plugins have already had their category rules applied.
         // Start with the root plugins since they already have categories applied in base_rules.
         let mut configured_plugins = self.base.config.plugins;
 
-        for override_config in overrides_to_apply {
+        for (index, override_config) in overrides_to_apply {
             if let Some(override_plugins) = override_config.plugins
                 && override_plugins != plugins
             {
@@ -197,13 +463,17 @@ impl Config {
                         // Only apply categories to rules from unconfigured plugins
                         if unconfigured_plugins.contains(rule_plugin) {
                             self.categories
-                                .get(&rule.category())
+                                .get(&self.base.config.rule_category(rule))
                                 .map(|severity| (rule.clone(), severity))
                         } else {
                             None
                         }
                     }) {
-                        rules.entry(rule).or_insert(*severity);
+                        let rule_id = rule.id();
+                        if let Entry::Vacant(entry) = rules.entry(rule) {
+                            entry.insert(*severity);
+                            rule_sources.insert(rule_id, RuleSource::Override(index));
+                        }
                     }
                     // Mark these plugins as configured
                     configured_plugins |= unconfigured_plugins;
@@ -211,6 +481,7 @@ impl Config {
             }
 
             for (rule, severity) in &override_config.rules.builtin_rules {
+                rule_sources.insert(rule.id(), RuleSource::Override(index));
                 if *severity == AllowWarnDeny::Allow {
                     rules.remove(rule);
                 } else {
@@ -219,17 +490,18 @@ impl Config {
                 }
             }
 
-            for (external_rule_id, severity) in &override_config.rules.external_rules {
-                external_rules.insert(*external_rule_id, *severity);
+            if let Some(override_rule_options) = self.override_rule_options.get(index) {
+                rule_options.extend(
+                    override_rule_options.iter().map(|(id, options)| (*id, Arc::clone(options))),
+                );
             }
 
-            if let Some(override_env) = &override_config.env {
-                override_env.override_envs(&mut env);
+            for (external_rule_id, severity) in &override_config.rules.external_rules {
+                external_rules.insert(*external_rule_id, *severity);
+                external_rule_sources.insert(*external_rule_id, RuleSource::Override(index));
             }
 
-            if let Some(override_globals) = &override_config.globals {
-                override_globals.override_globals(&mut globals);
-            }
+            override_config.apply_env_and_globals(&mut env, &mut globals);
         }
 
         let config: Arc<LintConfig> = if plugins == self.base.config.plugins
@@ -248,18 +520,31 @@ impl Config {
             Arc::new(config)
         };
 
-        let rules =
+        // Sort the rules like the base rules, so they run and report in the same order on every run
+        // instead of the iteration order of the maps.
+        let mut rules =
             rules.into_iter().filter(|(_, severity)| severity.is_warn_deny()).collect::<Vec<_>>();
+        rules.sort_unstable_by_key(|(rule, _)| rule.id());
 
-        let external_rules = external_rules
+        let mut external_rules = external_rules
             .into_iter()
             .filter(|(_, severity)| severity.is_warn_deny())
             .collect::<Vec<_>>();
+        external_rules.sort_unstable_by_key(|(rule, _)| *rule);
+
+        let processor =
+            matching_overrides.iter().rev().find_map(|&index| self.overrides.0[index].processor);
 
         ResolvedLinterState {
-            rules: Arc::from(rules.into_boxed_slice()),
-            config,
-            external_rules: Arc::from(external_rules.into_boxed_slice()),
+            rule_sources: Arc::new(rule_sources),
+            external_rule_sources: Arc::new(external_rule_sources),
+            rule_options: Arc::new(rule_options),
+            processor,
+            ..ResolvedLinterState::new(
+                Arc::from(rules.into_boxed_slice()),
+                config,
+                Arc::from(external_rules.into_boxed_slice()),
+            )
         }
     }
 }
@@ -323,7 +608,16 @@ impl ConfigStore {
     // NOTE: This function is not crate visible because it is used in `oxlint` as well to resolve configs
     // for the `tsgolint` linter.
     pub fn resolve(&self, path: &Path) -> ResolvedLinterState {
-        Config::apply_overrides(self.get_related_config(path), path)
+        let config = self.get_related_config(path);
+        let mut state = config.apply_overrides(path);
+        // The nested configuration file in the root directory is the root configuration file.
+        state.nested = config.base.config.path != self.base.base.config.path;
+        state
+    }
+
+    /// The options to parse `path` with.
+    pub fn resolve_parser_options(&self, path: &Path) -> OxlintParserOptions {
+        self.get_related_config(path).parser_options(path)
     }
 
     fn get_nearest_config(&self, path: &Path) -> Option<&Config> {
@@ -342,31 +636,37 @@ impl ConfigStore {
     pub(crate) fn resolve_plugin_rule_names(
         &self,
         external_rule_id: ExternalRuleId,
-    ) -> (/* plugin name */ &str, /* rule name */ &str) {
+    ) -> (/* plugin name */ &'static str, /* rule name */ &'static str) {
         self.external_plugin_store.resolve_plugin_rule_names(external_rule_id)
     }
 }
 
 #[cfg(test)]
 mod test {
-    use std::{path::PathBuf, str::FromStr};
+    use std::{path::PathBuf, str::FromStr, sync::Arc};
 
     use rustc_hash::FxHashMap;
-    use serde_json::Value;
+    use serde_json::{Value, json};
+
+    use oxc_span::SourceType;
 
     use super::{ConfigStore, ResolvedOxlintOverrides};
     use crate::{
-        AllowWarnDeny, ExternalPluginStore, LintPlugins, RuleCategory, RuleEnum,
+        AllowWarnDeny, ConfigStoreBuilder, ExternalPluginStore, ExternalProcessorId, LintPlugins,
+        Oxlintrc, RuleCategory, RuleEnum,
         config::{
-            LintConfig, OxlintEnv, OxlintGlobals, OxlintSettings,
+            GlobalValue, LintConfig, OxlintEnv, OxlintGlobals, OxlintLinterOptions,
+            OxlintParserOptions, OxlintPerFileThresholds, OxlintSettings, RuleOrigin,
+            RuleOriginKind,
             categories::OxlintCategories,
             config_store::{Config, ResolvedOxlintOverride, ResolvedOxlintOverrideRules},
             overrides::GlobSet,
         },
+        frameworks::FrameworkOptions,
         rule::Rule,
         rules::{
-            EslintCurly, EslintNoUnusedVars, ReactJsxFilenameExtension, TypescriptNoExplicitAny,
-            TypescriptNoMisusedPromises,
+            EslintCurly, EslintNoUnusedVars, JestNoHooks, ReactJsxFilenameExtension,
+            TypescriptNoExplicitAny, TypescriptNoMisusedPromises,
         },
     };
 
@@ -391,6 +691,8 @@ mod test {
             plugins: None,
             globals: None,
             rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
+            processor: None,
+            parser_options: OxlintParserOptions::default(),
         }]);
         let store = ConfigStore::new(
             Config::new(
@@ -428,6 +730,8 @@ mod test {
             ),
             globals: None,
             rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
+            processor: None,
+            parser_options: OxlintParserOptions::default(),
         }]);
         let store = ConfigStore::new(
             Config::new(
@@ -464,6 +768,8 @@ mod test {
                 )],
                 external_rules: vec![],
             },
+            processor: None,
+            parser_options: OxlintParserOptions::default(),
         }]);
 
         let store = ConfigStore::new(
@@ -501,6 +807,8 @@ mod test {
                 )],
                 external_rules: vec![],
             },
+            processor: None,
+            parser_options: OxlintParserOptions::default(),
         }]);
 
         let store = ConfigStore::new(
@@ -521,6 +829,9 @@ mod test {
         assert_eq!(store.resolve("src/App.ts".as_ref()).rules.len(), 2);
         assert_eq!(store.resolve("src/foo/bar/baz/App.tsx".as_ref()).rules.len(), 2);
         assert_eq!(store.resolve("src/foo/bar/baz/App.spec.tsx".as_ref()).rules.len(), 2);
+        // Globs are written with `/`, but also match paths with Windows separators.
+        #[cfg(windows)]
+        assert_eq!(store.resolve(r"src\foo\bar\baz\App.tsx".as_ref()).rules.len(), 2);
     }
 
     #[test]
@@ -538,6 +849,8 @@ mod test {
                 )],
                 external_rules: vec![],
             },
+            processor: None,
+            parser_options: OxlintParserOptions::default(),
         }]);
 
         let store = ConfigStore::new(
@@ -575,6 +888,8 @@ mod test {
                     builtin_rules: vec![],
                     external_rules: vec![],
                 },
+                processor: None,
+                parser_options: OxlintParserOptions::default(),
             },
             ResolvedOxlintOverride {
                 env: None,
@@ -585,6 +900,8 @@ mod test {
                     builtin_rules: vec![],
                     external_rules: vec![],
                 },
+                processor: None,
+                parser_options: OxlintParserOptions::default(),
             },
         ]);
 
@@ -609,6 +926,50 @@ mod test {
         assert_eq!(app.plugins, LintPlugins::IMPORT | LintPlugins::REACT | LintPlugins::TYPESCRIPT);
     }
 
+    #[test]
+    fn test_resolve_processor() {
+        let processor_override =
+            |files: Vec<&str>, processor: Option<usize>| ResolvedOxlintOverride {
+                env: None,
+                files: GlobSet::new(files),
+                plugins: None,
+                globals: None,
+                rules: ResolvedOxlintOverrideRules {
+                    builtin_rules: vec![],
+                    external_rules: vec![],
+                },
+                processor: processor.map(ExternalProcessorId::from_usize),
+                parser_options: OxlintParserOptions::default(),
+            };
+        let overrides = ResolvedOxlintOverrides::new(vec![
+            processor_override(vec!["*.md"], Some(0)),
+            processor_override(vec!["docs/*.md"], Some(1)),
+            processor_override(vec!["docs/*"], None),
+        ]);
+        let store = ConfigStore::new(
+            Config::new(
+                vec![],
+                vec![],
+                OxlintCategories::default(),
+                LintConfig::default(),
+                overrides,
+            ),
+            FxHashMap::default(),
+            ExternalPluginStore::default(),
+        );
+
+        assert_eq!(store.resolve("index.js".as_ref()).processor, None);
+        assert_eq!(
+            store.resolve("README.md".as_ref()).processor,
+            Some(ExternalProcessorId::from_usize(0))
+        );
+        // the last matching override with a processor wins
+        assert_eq!(
+            store.resolve("docs/guide.md".as_ref()).processor,
+            Some(ExternalProcessorId::from_usize(1))
+        );
+    }
+
     #[test]
     fn test_add_env() {
         let base_config = LintConfig { plugins: LintPlugins::ESLINT, ..Default::default() };
@@ -618,6 +979,8 @@ mod test {
             plugins: None,
             globals: None,
             rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
+            processor: None,
+            parser_options: OxlintParserOptions::default(),
         }]);
 
         let store = ConfigStore::new(
@@ -641,6 +1004,8 @@ mod test {
             plugins: None,
             globals: None,
             rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
+            processor: None,
+            parser_options: OxlintParserOptions::default(),
         }]);
 
         let store = ConfigStore::new(
@@ -664,6 +1029,8 @@ mod test {
             plugins: None,
             globals: Some(from_json!({ "React": "readonly", "Secret": "writeable" })),
             rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
+            processor: None,
+            parser_options: OxlintParserOptions::default(),
         }]);
 
         let store = ConfigStore::new(
@@ -679,6 +1046,139 @@ mod test {
         assert!(app.globals.is_enabled("Secret"));
     }
 
+    #[test]
+    fn test_override_env_and_globals_are_additive() {
+        let base_config = LintConfig {
+            env: from_json!({ "browser": true }),
+            globals: from_json!({ "Foo": "readonly" }),
+            ..Default::default()
+        };
+        let overrides = ResolvedOxlintOverrides::new(vec![ResolvedOxlintOverride {
+            files: GlobSet::new(vec!["*.ts"]),
+            env: Some(from_json!({ "node": true })),
+            plugins: None,
+            globals: Some(from_json!({ "Bar": "writable" })),
+            rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
+            processor: None,
+            parser_options: OxlintParserOptions::default(),
+        }]);
+
+        let store = ConfigStore::new(
+            Config::new(vec![], vec![], OxlintCategories::default(), base_config, overrides),
+            FxHashMap::default(),
+            ExternalPluginStore::default(),
+        );
+
+        // the base `env` and `globals` are kept
+        let app = store.resolve("App.ts".as_ref()).config;
+        assert!(app.env.contains("browser"));
+        assert!(app.env.contains("node"));
+        assert!(app.globals.is_enabled("Foo"));
+        assert!(app.globals.is_enabled("Bar"));
+
+        let app = store.resolve("App.js".as_ref()).config;
+        assert!(app.env.contains("browser"));
+        assert!(!app.env.contains("node"));
+        assert!(app.globals.is_enabled("Foo"));
+        assert!(!app.globals.is_enabled("Bar"));
+    }
+
+    #[test]
+    fn test_later_override_env_and_globals_win_per_key() {
+        let base_config = LintConfig {
+            env: from_json!({ "browser": true, "node": true }),
+            globals: from_json!({ "Foo": "readonly", "Bar": "readonly" }),
+            ..Default::default()
+        };
+        let overrides = ResolvedOxlintOverrides::new(vec![
+            ResolvedOxlintOverride {
+                files: GlobSet::new(vec!["*.ts"]),
+                env: Some(from_json!({ "node": false, "jest": true })),
+                plugins: None,
+                globals: Some(from_json!({ "Foo": "off" })),
+                rules: ResolvedOxlintOverrideRules {
+                    builtin_rules: vec![],
+                    external_rules: vec![],
+                },
+                processor: None,
+                parser_options: OxlintParserOptions::default(),
+            },
+            ResolvedOxlintOverride {
+                files: GlobSet::new(vec!["src/*.ts"]),
+                env: Some(from_json!({ "jest": false })),
+                plugins: None,
+                globals: Some(from_json!({ "Foo": "writable" })),
+                rules: ResolvedOxlintOverrideRules {
+                    builtin_rules: vec![],
+                    external_rules: vec![],
+                },
+                processor: None,
+                parser_options: OxlintParserOptions::default(),
+            },
+        ]);
+
+        let store = ConfigStore::new(
+            Config::new(vec![], vec![], OxlintCategories::default(), base_config, overrides),
+            FxHashMap::default(),
+            ExternalPluginStore::default(),
+        );
+
+        let app = store.resolve("App.ts".as_ref()).config;
+        assert!(app.env.contains("browser"));
+        assert!(!app.env.contains("node"));
+        assert!(app.env.contains("jest"));
+        assert!(!app.globals.is_enabled("Foo"));
+        assert!(app.globals.is_enabled("Bar"));
+
+        // both overrides match, the last one wins for `jest` and `Foo`
+        let app = store.resolve("src/App.ts".as_ref()).config;
+        assert!(app.env.contains("browser"));
+        assert!(!app.env.contains("node"));
+        assert!(!app.env.contains("jest"));
+        assert_eq!(app.globals.get("Foo"), Some(&GlobalValue::Writeable));
+        assert_eq!(app.globals.get("Bar"), Some(&GlobalValue::Readonly));
+    }
+
+    #[test]
+    fn test_override_parser_options_are_merged_per_key() {
+        let base_config = LintConfig {
+            parser_options: from_json!({ "ecmaFeatures": { "jsx": false } }),
+            ..Default::default()
+        };
+        let override_parser_options = |files: &str, parser_options| ResolvedOxlintOverride {
+            files: GlobSet::new(vec![files]),
+            env: None,
+            plugins: None,
+            globals: None,
+            rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
+            processor: None,
+            parser_options,
+        };
+        let overrides = ResolvedOxlintOverrides::new(vec![
+            override_parser_options("legacy/**", from_json!({ "sourceType": "script" })),
+            override_parser_options("legacy/esm/**", from_json!({ "sourceType": "module" })),
+        ]);
+
+        let store = ConfigStore::new(
+            Config::new(vec![], vec![], OxlintCategories::default(), base_config, overrides),
+            FxHashMap::default(),
+            ExternalPluginStore::default(),
+        );
+
+        let js = SourceType::from_path("a.js").unwrap();
+        let app = store.resolve_parser_options("App.js".as_ref()).apply(js);
+        assert!(app.is_module());
+        assert!(!app.is_jsx());
+
+        let legacy = store.resolve_parser_options("legacy/App.js".as_ref()).apply(js);
+        assert!(legacy.is_script());
+        assert!(!legacy.is_jsx());
+
+        // both overrides match, the last one wins for `sourceType`
+        let esm = store.resolve_parser_options("legacy/esm/App.js".as_ref()).apply(js);
+        assert!(esm.is_module());
+    }
+
     #[test]
     fn test_external_rules_preserved_with_overrides() {
         // reproduction for https://github.com/oxc-project/oxc/issues/14504
@@ -702,6 +1202,8 @@ mod test {
             plugins: None,
             globals: None,
             rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
+            processor: None,
+            parser_options: OxlintParserOptions::default(),
         }]);
 
         let store = ConfigStore::new(
@@ -739,6 +1241,8 @@ mod test {
             plugins: None,
             globals: Some(from_json!({ "React": "off", "Secret": "off" })),
             rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
+            processor: None,
+            parser_options: OxlintParserOptions::default(),
         }]);
 
         let store = ConfigStore::new(
@@ -767,6 +1271,11 @@ mod test {
             settings: OxlintSettings::default(),
             globals: OxlintGlobals::default(),
             path: None,
+            linter_options: OxlintLinterOptions::default(),
+            category_overrides: FxHashMap::default(),
+            vendor_paths: GlobSet::default(),
+            per_file_thresholds: OxlintPerFileThresholds::default(),
+            parser_options: OxlintParserOptions::default(),
         };
 
         // Set up categories to enable restriction rules
@@ -785,6 +1294,8 @@ mod test {
                     builtin_rules: vec![],
                     external_rules: vec![],
                 },
+                processor: None,
+                parser_options: OxlintParserOptions::default(),
             },
             // Second override: react plugin for *.{ts,tsx} with jsx-filename-extension turned off
             ResolvedOxlintOverride {
@@ -799,6 +1310,8 @@ mod test {
                     )],
                     external_rules: vec![],
                 },
+                processor: None,
+                parser_options: OxlintParserOptions::default(),
             },
             // Third override: unicorn plugin for *.{ts,tsx,mts}
             ResolvedOxlintOverride {
@@ -810,6 +1323,8 @@ mod test {
                     builtin_rules: vec![],
                     external_rules: vec![],
                 },
+                processor: None,
+                parser_options: OxlintParserOptions::default(),
             },
         ]);
 
@@ -854,6 +1369,11 @@ mod test {
             settings: OxlintSettings::default(),
             globals: OxlintGlobals::default(),
             path: None,
+            linter_options: OxlintLinterOptions::default(),
+            category_overrides: FxHashMap::default(),
+            vendor_paths: GlobSet::default(),
+            per_file_thresholds: OxlintPerFileThresholds::default(),
+            parser_options: OxlintParserOptions::default(),
         };
 
         // Set up categories
@@ -867,6 +1387,8 @@ mod test {
             plugins: Some(LintPlugins::REACT),
             globals: None,
             rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
+            processor: None,
+            parser_options: OxlintParserOptions::default(),
         }]);
 
         let store = ConfigStore::new(
@@ -905,6 +1427,8 @@ mod test {
                 )],
                 external_rules: vec![],
             },
+            processor: None,
+            parser_options: OxlintParserOptions::default(),
         }]);
 
         let store = ConfigStore::new(
@@ -958,6 +1482,11 @@ mod test {
             settings: OxlintSettings::default(),
             globals: OxlintGlobals::default(),
             path: None,
+            linter_options: OxlintLinterOptions::default(),
+            category_overrides: FxHashMap::default(),
+            vendor_paths: GlobSet::default(),
+            per_file_thresholds: OxlintPerFileThresholds::default(),
+            parser_options: OxlintParserOptions::default(),
         };
 
         // Set up categories
@@ -977,6 +1506,8 @@ mod test {
             plugins: Some(LintPlugins::TYPESCRIPT),
             globals: None,
             rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
+            processor: None,
+            parser_options: OxlintParserOptions::default(),
         }]);
 
         let store = ConfigStore::new(
@@ -999,6 +1530,62 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_vendor_paths_cap_severity() {
+        let base_rules = vec![
+            (RuleEnum::EslintCurly(EslintCurly::default()), AllowWarnDeny::Deny),
+            (RuleEnum::EslintNoUnusedVars(EslintNoUnusedVars::default()), AllowWarnDeny::Warn),
+        ];
+        let store_with_vendor_severity = |vendor_severity| {
+            let base_config = LintConfig {
+                path: Some(PathBuf::from("/root/.oxlintrc.json")),
+                linter_options: OxlintLinterOptions {
+                    vendor_severity,
+                    ..OxlintLinterOptions::default()
+                },
+                vendor_paths: GlobSet::new(vec!["vendor/**"]),
+                per_file_thresholds: OxlintPerFileThresholds::default(),
+                parser_options: OxlintParserOptions::default(),
+                ..LintConfig::default()
+            };
+            ConfigStore::new(
+                Config::new(
+                    base_rules.clone(),
+                    vec![],
+                    OxlintCategories::default(),
+                    base_config,
+                    ResolvedOxlintOverrides::new(vec![]),
+                ),
+                FxHashMap::default(),
+                ExternalPluginStore::default(),
+            )
+        };
+
+        let store = store_with_vendor_severity(None);
+        let severities = |path: &str| {
+            store
+                .resolve(path.as_ref())
+                .rules
+                .iter()
+                .map(|(_, severity)| *severity)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(severities("/root/src/App.js"), vec![AllowWarnDeny::Deny, AllowWarnDeny::Warn]);
+        assert_eq!(
+            severities("/root/vendor/lib/jquery.js"),
+            vec![AllowWarnDeny::Warn, AllowWarnDeny::Warn]
+        );
+        // Globs are relative to the configuration file.
+        assert_eq!(
+            severities("/root/src/vendor/App.js"),
+            vec![AllowWarnDeny::Deny, AllowWarnDeny::Warn]
+        );
+
+        let store = store_with_vendor_severity(Some(AllowWarnDeny::Allow));
+        assert!(store.resolve("/root/vendor/jquery.js".as_ref()).rules.is_empty());
+        assert_eq!(store.resolve("/root/src/App.js".as_ref()).rules.len(), 2);
+    }
+
     #[test]
     fn test_number_of_rules() {
         let base_config = LintConfig::default();
@@ -1052,4 +1639,142 @@ mod test {
         assert_eq!(store_with_nested_configs.number_of_rules(false), None);
         assert_eq!(store_with_nested_configs.number_of_rules(true), None);
     }
+
+    #[test]
+    fn test_rules_filtered_by_source_are_shared() {
+        let base_rules = vec![
+            (RuleEnum::EslintCurly(EslintCurly::default()), AllowWarnDeny::Deny),
+            no_explicit_any(),
+            (
+                RuleEnum::TypescriptNoMisusedPromises(TypescriptNoMisusedPromises::default()),
+                AllowWarnDeny::Deny,
+            ),
+        ];
+        let overrides = ResolvedOxlintOverrides::new(vec![ResolvedOxlintOverride {
+            env: None,
+            files: GlobSet::new(vec!["*.test.ts"]),
+            plugins: None,
+            globals: None,
+            rules: ResolvedOxlintOverrideRules { builtin_rules: vec![], external_rules: vec![] },
+            processor: None,
+            parser_options: OxlintParserOptions::default(),
+        }]);
+        let store = ConfigStore::new(
+            Config::new(
+                base_rules,
+                vec![],
+                OxlintCategories::default(),
+                LintConfig::default(),
+                overrides,
+            ),
+            FxHashMap::default(),
+            ExternalPluginStore::default(),
+        );
+
+        let first = store.resolve("a.test.ts".as_ref());
+        let second = store.resolve("b.test.ts".as_ref());
+        assert!(Arc::ptr_eq(&first.rules_by_source, &second.rules_by_source));
+
+        // Type-aware rules are left out, and `typescript/no-explicit-any` only runs on TypeScript.
+        let js_rules = first.rules_by_source.get_or_filter(
+            &first.rules,
+            SourceType::mjs(),
+            FrameworkOptions::Default,
+        );
+        assert_eq!(js_rules.len(), 1);
+        let ts_rules = second.rules_by_source.get_or_filter(
+            &second.rules,
+            SourceType::ts(),
+            FrameworkOptions::Default,
+        );
+        assert_eq!(ts_rules.len(), 2);
+    }
+
+    #[test]
+    fn test_rule_origin() {
+        let no_unused_vars = RuleEnum::EslintNoUnusedVars(EslintNoUnusedVars::default());
+        let curly = RuleEnum::EslintCurly(EslintCurly::default());
+        let base_rules = vec![no_explicit_any(), (curly.clone(), AllowWarnDeny::Deny)];
+        let overrides = ResolvedOxlintOverrides::new(vec![ResolvedOxlintOverride {
+            env: None,
+            files: GlobSet::new(vec!["src/**"]),
+            plugins: None,
+            globals: None,
+            rules: ResolvedOxlintOverrideRules {
+                builtin_rules: vec![(no_unused_vars.clone(), AllowWarnDeny::Warn)],
+                external_rules: vec![],
+            },
+            processor: None,
+            parser_options: OxlintParserOptions::default(),
+        }]);
+        let config_path = PathBuf::from("/project/.oxlintrc.json");
+        let store = ConfigStore::new(
+            Config::new(
+                base_rules,
+                vec![],
+                OxlintCategories::default(),
+                LintConfig { path: Some(config_path.clone()), ..LintConfig::default() },
+                overrides,
+            )
+            .with_cli_filtered_rules(vec![curly.id()]),
+            FxHashMap::default(),
+            ExternalPluginStore::default(),
+        );
+
+        let resolved = store.resolve("/project/src/App.ts".as_ref());
+        assert_eq!(
+            resolved.rule_origin(no_explicit_any().0.id()),
+            RuleOrigin {
+                kind: RuleOriginKind::RootConfig,
+                config_path: Some(config_path.clone()),
+                override_index: None,
+            }
+        );
+        assert_eq!(
+            resolved.rule_origin(no_unused_vars.id()),
+            RuleOrigin {
+                kind: RuleOriginKind::Override,
+                config_path: Some(config_path),
+                override_index: Some(0),
+            }
+        );
+        assert_eq!(
+            resolved.rule_origin(curly.id()),
+            RuleOrigin { kind: RuleOriginKind::CliFilter, config_path: None, override_index: None }
+        );
+    }
+
+    #[test]
+    fn test_rule_options() {
+        let oxlintrc: Oxlintrc = from_json!({
+            "plugins": ["jest"],
+            "rules": { "jest/no-hooks": ["error", { "allow": ["beforeAll"] }] },
+            "overrides": [
+                {
+                    "files": ["*.test.js"],
+                    "rules": { "jest/no-hooks": ["error", { "allow": ["afterAll"] }] }
+                },
+                { "files": ["*.spec.js"], "rules": { "jest/no-hooks": "warn" } }
+            ]
+        });
+        let mut external_plugin_store = ExternalPluginStore::default();
+        let config =
+            ConfigStoreBuilder::from_oxlintrc(true, oxlintrc, None, &mut external_plugin_store)
+                .unwrap()
+                .build(&external_plugin_store)
+                .unwrap();
+        let store = ConfigStore::new(config, FxHashMap::default(), external_plugin_store);
+
+        let no_hooks = RuleEnum::JestNoHooks(JestNoHooks).id();
+        let options = |path: &str| {
+            store
+                .resolve(path.as_ref())
+                .rule_options(no_hooks)
+                .map(|options| options.value().clone())
+        };
+        assert_eq!(options("/project/a.js"), Some(json!([{ "allow": ["beforeAll"] }])));
+        assert_eq!(options("/project/a.test.js"), Some(json!([{ "allow": ["afterAll"] }])));
+        // Like ESLint, only changing the severity keeps the options.
+        assert_eq!(options("/project/a.spec.js"), Some(json!([{ "allow": ["beforeAll"] }])));
+    }
 }
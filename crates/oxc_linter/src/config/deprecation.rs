@@ -0,0 +1,222 @@
+use std::{fmt, path::Path};
+
+use serde_json::{Map, Value};
+
+use oxc_diagnostics::OxcDiagnostic;
+
+use super::plugins::normalize_plugin_name;
+
+/// The kind of a deprecated name in a configuration file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeprecationKind {
+    /// A plugin in `plugins`, e.g. `import-x`
+    PluginName,
+    /// The plugin prefix of a rule in `rules`, e.g. `import-x/no-cycle`
+    RuleName,
+    /// A value in `globals`, e.g. `readable`
+    GlobalValue,
+}
+
+impl DeprecationKind {
+    /// Machine-readable code of the diagnostic, e.g. `deprecated-plugin-name`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::PluginName => "deprecated-plugin-name",
+            Self::RuleName => "deprecated-rule-name",
+            Self::GlobalValue => "deprecated-global-value",
+        }
+    }
+}
+
+impl fmt::Display for DeprecationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::PluginName => "plugin name",
+            Self::RuleName => "rule name",
+            Self::GlobalValue => "global value",
+        })
+    }
+}
+
+/// A deprecated name which is still accepted, and the name replacing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeprecatedName {
+    pub kind: DeprecationKind,
+    pub name: String,
+    pub replacement: String,
+}
+
+impl DeprecatedName {
+    pub fn to_diagnostic(&self, path: &Path) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!(
+            "Deprecated {} `{}` in {}",
+            self.kind,
+            self.name,
+            path.display()
+        ))
+        .with_help(format!(
+            "Use `{}` instead, or run `oxlint --fix-config` to replace deprecated names.",
+            self.replacement
+        ))
+        .with_error_code("oxlint", self.kind.code())
+    }
+}
+
+/// Replace the deprecated names in the JSON of a configuration file, and return them.
+///
+/// Deprecated names mean the same as their replacements, so the configuration does not change.
+pub fn replace_deprecated_names(json: &mut Value) -> Vec<DeprecatedName> {
+    let mut deprecated = vec![];
+    let Some(config) = json.as_object_mut() else {
+        return deprecated;
+    };
+
+    replace_in_config(config, &mut deprecated);
+    let overrides = config.get_mut("overrides").and_then(Value::as_array_mut).into_iter().flatten();
+    for r#override in overrides.filter_map(Value::as_object_mut) {
+        replace_in_config(r#override, &mut deprecated);
+    }
+
+    deprecated
+}
+
+fn replace_in_config(config: &mut Map<String, Value>, deprecated: &mut Vec<DeprecatedName>) {
+    if let Some(plugins) = config.get_mut("plugins").and_then(Value::as_array_mut) {
+        for plugin in plugins {
+            let Some(name) = plugin.as_str() else {
+                continue;
+            };
+            if let Some(replacement) = plugin_replacement(name) {
+                deprecated.push(DeprecatedName {
+                    kind: DeprecationKind::PluginName,
+                    name: name.to_string(),
+                    replacement: replacement.to_string(),
+                });
+                *plugin = Value::from(replacement);
+            }
+        }
+    }
+
+    if let Some(rules) = config.get_mut("rules").and_then(Value::as_object_mut) {
+        // Rebuild the map to keep the order of the rules
+        *rules = std::mem::take(rules)
+            .into_iter()
+            .map(|(key, value)| {
+                let Some(replacement) = rule_replacement(&key) else {
+                    return (key, value);
+                };
+                deprecated.push(DeprecatedName {
+                    kind: DeprecationKind::RuleName,
+                    name: key,
+                    replacement: replacement.clone(),
+                });
+                (replacement, value)
+            })
+            .collect();
+    }
+
+    if let Some(globals) = config.get_mut("globals").and_then(Value::as_object_mut) {
+        for value in globals.values_mut() {
+            let Some(name) = value.as_str() else {
+                continue;
+            };
+            // Legacy spellings of ESLint
+            let replacement = match name {
+                "readable" => "readonly",
+                "writeable" => "writable",
+                _ => continue,
+            };
+            deprecated.push(DeprecatedName {
+                kind: DeprecationKind::GlobalValue,
+                name: name.to_string(),
+                replacement: replacement.to_string(),
+            });
+            *value = Value::from(replacement);
+        }
+    }
+}
+
+/// The plugin replacing a deprecated plugin, which is only accepted for backwards compatibility.
+fn plugin_replacement(name: &str) -> Option<&'static str> {
+    match normalize_plugin_name(name).as_ref() {
+        // import-x has the same rules as import
+        "import-x" => Some("import"),
+        // deepscan rules have been moved into oxc
+        "deepscan" => Some("oxc"),
+        _ => None,
+    }
+}
+
+fn rule_replacement(key: &str) -> Option<String> {
+    let (plugin, rule) = key.split_once('/')?;
+    plugin_replacement(plugin).map(|plugin| format!("{plugin}/{rule}"))
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use super::{DeprecationKind, replace_deprecated_names};
+
+    #[test]
+    fn test_replace_deprecated_names() {
+        let mut config = json!({
+            "plugins": ["import-x", "react", "eslint-plugin-deepscan"],
+            "rules": {
+                "no-debugger": "error",
+                "import-x/no-cycle": "error",
+                "eqeqeq": "warn",
+            },
+            "globals": { "foo": "readable", "bar": "writeable", "baz": "off" },
+            "overrides": [{ "files": ["*.ts"], "rules": { "deepscan/bad-array-method-on-arguments": "off" } }],
+        });
+        let deprecated = replace_deprecated_names(&mut config);
+        let replaced = deprecated
+            .iter()
+            .map(|d| (d.kind, d.name.as_str(), d.replacement.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            replaced,
+            [
+                (DeprecationKind::PluginName, "import-x", "import"),
+                (DeprecationKind::PluginName, "eslint-plugin-deepscan", "oxc"),
+                (DeprecationKind::RuleName, "import-x/no-cycle", "import/no-cycle"),
+                (DeprecationKind::GlobalValue, "readable", "readonly"),
+                (DeprecationKind::GlobalValue, "writeable", "writable"),
+                (
+                    DeprecationKind::RuleName,
+                    "deepscan/bad-array-method-on-arguments",
+                    "oxc/bad-array-method-on-arguments"
+                ),
+            ]
+        );
+
+        assert_eq!(
+            config,
+            json!({
+                "plugins": ["import", "react", "oxc"],
+                "rules": {
+                    "no-debugger": "error",
+                    "import/no-cycle": "error",
+                    "eqeqeq": "warn",
+                },
+                "globals": { "foo": "readonly", "bar": "writable", "baz": "off" },
+                "overrides": [{ "files": ["*.ts"], "rules": { "oxc/bad-array-method-on-arguments": "off" } }],
+            })
+        );
+        // the order of rules is kept
+        let rules = config["rules"].as_object().unwrap().keys().collect::<Vec<_>>();
+        assert_eq!(rules, ["no-debugger", "import/no-cycle", "eqeqeq"]);
+
+        let diagnostic = deprecated[0].to_diagnostic(Path::new(".oxlintrc.json"));
+        assert_eq!(diagnostic.message, "Deprecated plugin name `import-x` in .oxlintrc.json");
+        assert_eq!(
+            diagnostic.help.as_deref(),
+            Some("Use `import` instead, or run `oxlint --fix-config` to replace deprecated names.")
+        );
+
+        assert!(replace_deprecated_names(&mut config).is_empty());
+    }
+}
@@ -2,12 +2,14 @@ use std::path::{Path, PathBuf};
 
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
 
+use oxc_diagnostics::NormalizedPath;
+
 /// Holds ignore matchers for base and nested configs, for fast filtering in lint.rs
 
 #[derive(Debug)]
 pub struct LintIgnoreMatcher {
     base: Option<Gitignore>,
-    nested: Vec<(Option<Gitignore>, PathBuf)>,
+    nested: Vec<(Option<Gitignore>, NormalizedPath)>,
 }
 
 impl LintIgnoreMatcher {
@@ -19,7 +21,7 @@ impl LintIgnoreMatcher {
         mut nested: Vec<(Vec<String>, PathBuf)>,
     ) -> Self {
         let base_gi = {
-            let mut builder = GitignoreBuilder::new(base_root);
+            let mut builder = GitignoreBuilder::new(NormalizedPath::new(base_root));
             for pat in base_patterns {
                 let _ = builder.add_line(None, pat);
             }
@@ -35,6 +37,7 @@ impl LintIgnoreMatcher {
         let nested = nested
             .into_iter()
             .map(|(patterns, root)| {
+                let root = NormalizedPath::new(root);
                 if patterns.is_empty() {
                     (None, root)
                 } else {
@@ -52,6 +55,9 @@ impl LintIgnoreMatcher {
     /// Returns true if the path should be ignored by any config.
     /// Checks nested configs deepest-to-shallowest, so deepest config wins.
     pub fn should_ignore(&self, path: &Path) -> bool {
+        // Roots and paths may be written with different separators on Windows.
+        let path = NormalizedPath::new(path);
+        let path = path.as_path();
         // If a nested config matches, only use its ignore patterns (do not fall back to base)
         for (ignore, root) in &self.nested {
             if path.starts_with(root) {
@@ -100,4 +106,22 @@ mod tests {
         assert!(matcher.should_ignore(Path::new("/repo/file.js")));
         assert!(!matcher.should_ignore(Path::new("/repo/file.ts")));
     }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_windows_separators() {
+        let base_patterns = vec!["dist/**".to_string()];
+        let base_root = Path::new(r"C:\repo");
+        let nested = (vec!["*.ts".to_string()], PathBuf::from("C:/repo/packages/a"));
+
+        let matcher = LintIgnoreMatcher::new(&base_patterns, base_root, vec![nested]);
+
+        assert!(matcher.should_ignore(Path::new(r"C:\repo\dist\index.js")));
+        assert!(matcher.should_ignore(Path::new("C:/repo/dist/index.js")));
+        assert!(!matcher.should_ignore(Path::new(r"C:\repo\src\index.js")));
+
+        assert!(matcher.should_ignore(Path::new(r"C:\repo\packages\a\index.ts")));
+        assert!(matcher.should_ignore(Path::new(r"\\?\C:\repo\packages\a\index.ts")));
+        assert!(!matcher.should_ignore(Path::new(r"C:\repo\packages\a\index.js")));
+    }
 }
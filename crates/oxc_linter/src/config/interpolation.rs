@@ -0,0 +1,141 @@
+use std::fmt::Write as _;
+
+use serde_json::Value;
+
+const ENV_PREFIX: &str = "${env:";
+
+/// Expand `${env:NAME}` in the string values of the JSON of a configuration file, using `get_var`
+/// to look up environment variables. `$${env:NAME}` is kept as a literal `${env:NAME}`.
+///
+/// Keys, like rule names, are not expanded.
+///
+/// # Errors
+///
+/// If a variable is not set, or `${env:` is not closed.
+pub(super) fn expand_env_vars(
+    json: &mut Value,
+    get_var: &impl Fn(&str) -> Option<String>,
+) -> Result<(), String> {
+    expand_value(json, get_var, &mut String::new())
+}
+
+fn expand_value(
+    value: &mut Value,
+    get_var: &impl Fn(&str) -> Option<String>,
+    json_path: &mut String,
+) -> Result<(), String> {
+    let len = json_path.len();
+    match value {
+        Value::String(string) => {
+            if string.contains(ENV_PREFIX) {
+                *string = expand_string(string, get_var).map_err(|err| {
+                    if json_path.is_empty() { err } else { format!("{err} in `{json_path}`") }
+                })?;
+            }
+        }
+        Value::Array(array) => {
+            for (index, item) in array.iter_mut().enumerate() {
+                write!(json_path, "[{index}]").unwrap();
+                expand_value(item, get_var, json_path)?;
+                json_path.truncate(len);
+            }
+        }
+        Value::Object(object) => {
+            for (key, item) in object.iter_mut() {
+                if !json_path.is_empty() {
+                    json_path.push('.');
+                }
+                json_path.push_str(key);
+                expand_value(item, get_var, json_path)?;
+                json_path.truncate(len);
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+    Ok(())
+}
+
+fn expand_string(
+    string: &str,
+    get_var: &impl Fn(&str) -> Option<String>,
+) -> Result<String, String> {
+    let mut expanded = String::with_capacity(string.len());
+    let mut rest = string;
+    while let Some(start) = rest.find(ENV_PREFIX) {
+        // `$${env:NAME}` escapes the variable
+        if rest[..start].ends_with('$') {
+            expanded.push_str(&rest[..start - 1]);
+            expanded.push_str(ENV_PREFIX);
+            rest = &rest[start + ENV_PREFIX.len()..];
+            continue;
+        }
+        expanded.push_str(&rest[..start]);
+        let after_prefix = &rest[start + ENV_PREFIX.len()..];
+        let Some(end) = after_prefix.find('}') else {
+            return Err(format!("Unclosed `{ENV_PREFIX}` in \"{string}\""));
+        };
+        let name = &after_prefix[..end];
+        let value = get_var(name)
+            .ok_or_else(|| format!("Environment variable `{name}` is not set, but is used"))?;
+        expanded.push_str(&value);
+        rest = &after_prefix[end + 1..];
+    }
+    expanded.push_str(rest);
+    Ok(expanded)
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::expand_env_vars;
+
+    fn get_var(name: &str) -> Option<String> {
+        match name {
+            "CONFIG_DIR" => Some("/shared/oxlint".to_string()),
+            "MAX" => Some("3".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    #[expect(clippy::literal_string_with_formatting_args)]
+    fn test_expand_env_vars() {
+        let mut config = json!({
+            "extends": ["${env:CONFIG_DIR}/base.json"],
+            "ignorePatterns": ["dist", "$${env:CONFIG_DIR}"],
+            "rules": {
+                "max-params": ["error", { "max": 3 }],
+                "no-restricted-imports": ["error", { "paths": ["${env:CONFIG_DIR}", "lodash-${env:MAX}"] }],
+            },
+        });
+        expand_env_vars(&mut config, &get_var).unwrap();
+        assert_eq!(
+            config,
+            json!({
+                "extends": ["/shared/oxlint/base.json"],
+                "ignorePatterns": ["dist", "${env:CONFIG_DIR}"],
+                "rules": {
+                    "max-params": ["error", { "max": 3 }],
+                    "no-restricted-imports": ["error", { "paths": ["/shared/oxlint", "lodash-3"] }],
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn test_expand_env_vars_errors() {
+        let mut config =
+            json!({ "rules": { "no-console": ["error", { "allow": ["${env:MISSING}"] }] } });
+        assert_eq!(
+            expand_env_vars(&mut config, &get_var),
+            Err("Environment variable `MISSING` is not set, but is used in `rules.no-console[1].allow[0]`".to_string())
+        );
+
+        let mut config = json!({ "extends": ["${env:CONFIG_DIR"] });
+        assert_eq!(
+            expand_env_vars(&mut config, &get_var),
+            Err("Unclosed `${env:` in \"${env:CONFIG_DIR\" in `extends[0]`".to_string())
+        );
+    }
+}
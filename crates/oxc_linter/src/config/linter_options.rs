@@ -0,0 +1,105 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::AllowWarnDeny;
+
+/// Options which change how the linter itself behaves, rather than configuring individual rules.
+///
+/// Example
+///
+/// `.oxlintrc.json`
+///
+/// ```json
+/// {
+///   "$schema": "./node_modules/oxlint/configuration_schema.json",
+///   "linterOptions": {
+///     "reportUnusedInlineConfigs": "warn"
+///   }
+/// }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct OxlintLinterOptions {
+    /// Report inline config comments which have no effect, such as
+    /// `/* eslint-disable unknown-rule */`, or disable comments for rules which are not enabled.
+    ///
+    /// Equivalent to ESLint's `linterOptions.reportUnusedInlineConfigs`.
+    /// Accepts `"off"`, `"warn"` or `"error"`. Defaults to `"off"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report_unused_inline_configs: Option<AllowWarnDeny>,
+    /// The highest severity of diagnostics reported in files matching `vendorPaths`.
+    ///
+    /// Accepts `"off"`, `"warn"` or `"error"`. Defaults to `"warn"`.
+    /// `"off"` skips reporting diagnostics in vendored files, and `"error"` keeps the
+    /// severity of every rule unchanged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor_severity: Option<AllowWarnDeny>,
+}
+
+impl OxlintLinterOptions {
+    pub fn is_empty(&self) -> bool {
+        self.report_unused_inline_configs.is_none() && self.vendor_severity.is_none()
+    }
+
+    /// The highest severity of diagnostics reported in files matching `vendorPaths`.
+    pub(crate) fn vendor_severity(&self) -> AllowWarnDeny {
+        self.vendor_severity.unwrap_or(AllowWarnDeny::Warn)
+    }
+
+    /// Merges two sets of linter options. Options set in `self` take priority over `other`.
+    #[must_use]
+    pub(crate) fn merge(&self, other: &Self) -> Self {
+        Self {
+            report_unused_inline_configs: self
+                .report_unused_inline_configs
+                .or(other.report_unused_inline_configs),
+            vendor_severity: self.vendor_severity.or(other.vendor_severity),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+
+    use super::OxlintLinterOptions;
+    use crate::AllowWarnDeny;
+
+    #[test]
+    fn test_parse_linter_options() {
+        let options = OxlintLinterOptions::deserialize(&serde_json::json!({
+            "reportUnusedInlineConfigs": "error"
+        }))
+        .unwrap();
+        assert_eq!(options.report_unused_inline_configs, Some(AllowWarnDeny::Deny));
+
+        let options = OxlintLinterOptions::deserialize(&serde_json::json!({
+            "vendorSeverity": "off"
+        }))
+        .unwrap();
+        assert_eq!(options.vendor_severity, Some(AllowWarnDeny::Allow));
+        assert_eq!(options.vendor_severity(), AllowWarnDeny::Allow);
+        assert_eq!(OxlintLinterOptions::default().vendor_severity(), AllowWarnDeny::Warn);
+
+        let options = OxlintLinterOptions::deserialize(&serde_json::json!({})).unwrap();
+        assert!(options.is_empty());
+    }
+
+    #[test]
+    fn test_merge_linter_options() {
+        let warn = OxlintLinterOptions {
+            report_unused_inline_configs: Some(AllowWarnDeny::Warn),
+            ..OxlintLinterOptions::default()
+        };
+        let off = OxlintLinterOptions {
+            report_unused_inline_configs: Some(AllowWarnDeny::Allow),
+            vendor_severity: Some(AllowWarnDeny::Allow),
+        };
+        let unset = OxlintLinterOptions::default();
+
+        let merged = warn.merge(&off);
+        assert_eq!(merged.report_unused_inline_configs, Some(AllowWarnDeny::Warn));
+        assert_eq!(merged.vendor_severity, Some(AllowWarnDeny::Allow));
+        assert_eq!(unset.merge(&off), off);
+    }
+}
@@ -0,0 +1,221 @@
+use std::{fmt, path::Path};
+
+use serde_json::{Map, Value};
+
+use oxc_diagnostics::OxcDiagnostic;
+
+use super::deprecation::DeprecatedName;
+
+/// Current version of the configuration file format, set with `"$version"`.
+///
+/// Configuration files without `"$version"` have version 0. They are still read, by applying the
+/// migrations to the current version when the configuration is loaded.
+pub const CONFIG_VERSION: u32 = 1;
+
+/// Keys moved by the migration from version 0 to version 1, as `(from, to)`.
+///
+/// Only keys of released versions of the format belong here: keys which were never released are
+/// not in any configuration file. Version 1 only introduces `"$version"`.
+const MOVED_KEYS_V1: &[(&str, &str)] = &[];
+
+/// A change made when migrating a configuration file to the current version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigMigration {
+    /// A deprecated name replaced with the name replacing it.
+    Renamed(DeprecatedName),
+    /// A key moved to another object.
+    MovedKey(MovedKey),
+    /// `"$version"` updated to the current version.
+    Version { from: u32, to: u32 },
+}
+
+/// A key moved to another object, e.g. `vendorSeverity` to `linterOptions.vendorSeverity`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MovedKey {
+    pub from: &'static str,
+    pub to: &'static str,
+}
+
+impl MovedKey {
+    pub fn to_diagnostic(self, path: &Path) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!("Moved key `{}` in {}", self.from, path.display()))
+            .with_help(format!(
+                "Use `{}` instead, or run `oxlint --migrate-config` to migrate the configuration file.",
+                self.to
+            ))
+            .with_error_code("oxlint", "moved-config-key")
+    }
+}
+
+impl fmt::Display for ConfigMigration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Renamed(name) => write!(
+                f,
+                "Replaced {} `{}` with `{}` ({}).",
+                name.kind,
+                name.name,
+                name.replacement,
+                name.kind.code()
+            ),
+            Self::MovedKey(MovedKey { from, to }) => write!(f, "Moved `{from}` to `{to}`."),
+            Self::Version { from, to } => write!(f, "Updated `$version` from {from} to {to}."),
+        }
+    }
+}
+
+/// The `"$version"` of the JSON of a configuration file.
+///
+/// # Errors
+///
+/// If `"$version"` is not a non-negative integer, or is newer than [`CONFIG_VERSION`].
+pub fn config_version(json: &Value) -> Result<u32, String> {
+    let Some(version) = json.get("$version") else {
+        return Ok(0);
+    };
+    let version = version
+        .as_u64()
+        .and_then(|version| u32::try_from(version).ok())
+        .ok_or_else(|| format!("`$version` must be a non-negative integer, but is {version}."))?;
+    if version > CONFIG_VERSION {
+        return Err(format!(
+            "`$version` is {version}, but this version of oxlint only supports configuration files up to version {CONFIG_VERSION}. Please upgrade oxlint."
+        ));
+    }
+    Ok(version)
+}
+
+/// Migrate the JSON of a configuration file from its `"$version"` to [`CONFIG_VERSION`], and
+/// return the changes.
+///
+/// `"$version"` itself is not updated, see [`set_current_version`]. Deprecated names are replaced
+/// separately, as they are accepted regardless of the version.
+///
+/// # Errors
+///
+/// See [`config_version`].
+pub fn migrate_to_current_version(json: &mut Value) -> Result<Vec<MovedKey>, String> {
+    let version = config_version(json)?;
+    let mut moved = vec![];
+    let Some(config) = json.as_object_mut() else {
+        return Ok(moved);
+    };
+
+    if version < 1 {
+        move_keys(config, MOVED_KEYS_V1, &mut moved);
+    }
+
+    Ok(moved)
+}
+
+/// Set `"$version"` to [`CONFIG_VERSION`], after `"$schema"` if there is one.
+pub fn set_current_version(json: &mut Value) -> Option<ConfigMigration> {
+    let version = config_version(json).ok()?;
+    let config = json.as_object_mut()?;
+    if version == CONFIG_VERSION && config.contains_key("$version") {
+        return None;
+    }
+
+    // Rebuild the map to keep `$schema` first and `$version` next to it
+    let mut entries = std::mem::take(config).into_iter().collect::<Vec<_>>();
+    entries.retain(|(key, _)| key != "$version");
+    let index = usize::from(entries.first().is_some_and(|(key, _)| key == "$schema"));
+    entries.insert(index, ("$version".to_string(), Value::from(CONFIG_VERSION)));
+    *config = entries.into_iter().collect();
+
+    Some(ConfigMigration::Version { from: version, to: CONFIG_VERSION })
+}
+
+fn move_keys(
+    config: &mut Map<String, Value>,
+    moved_keys: &[(&'static str, &'static str)],
+    moved: &mut Vec<MovedKey>,
+) {
+    for &(from, to) in moved_keys {
+        let Some((object, key)) = to.split_once('.') else {
+            continue;
+        };
+        if !config.contains_key(from) || config.get(object).is_some_and(|v| !v.is_object()) {
+            continue;
+        }
+        let value = config.shift_remove(from).unwrap_or_default();
+        if let Some(target) =
+            config.entry(object).or_insert_with(|| Value::Object(Map::new())).as_object_mut()
+        {
+            // A value at the new place takes precedence.
+            target.entry(key).or_insert(value);
+        }
+        moved.push(MovedKey { from, to });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::{
+        CONFIG_VERSION, ConfigMigration, MovedKey, config_version, migrate_to_current_version,
+        move_keys, set_current_version,
+    };
+
+    #[test]
+    fn test_move_keys() {
+        let mut config = json!({
+            "rules": { "no-debugger": "error" },
+            "foo": "off",
+            "bar": "warn",
+            "options": { "bar": "error" },
+        });
+        let mut moved = vec![];
+        move_keys(
+            config.as_object_mut().unwrap(),
+            &[("bar", "options.bar"), ("foo", "options.foo"), ("baz", "options.baz")],
+            &mut moved,
+        );
+        assert_eq!(
+            moved,
+            [
+                MovedKey { from: "bar", to: "options.bar" },
+                MovedKey { from: "foo", to: "options.foo" },
+            ]
+        );
+        assert_eq!(
+            config,
+            json!({
+                "rules": { "no-debugger": "error" },
+                "options": { "bar": "error", "foo": "off" },
+            })
+        );
+    }
+
+    #[test]
+    fn test_migrate_to_current_version() {
+        // Keys which are only known from unreleased versions are left alone.
+        let mut config = json!({ "vendorSeverity": "off" });
+        assert!(migrate_to_current_version(&mut config).unwrap().is_empty());
+        assert_eq!(config, json!({ "vendorSeverity": "off" }));
+
+        let mut config = json!({ "$version": CONFIG_VERSION + 1 });
+        assert!(migrate_to_current_version(&mut config).is_err());
+    }
+
+    #[test]
+    fn test_config_version() {
+        assert_eq!(config_version(&json!({})), Ok(0));
+        assert_eq!(config_version(&json!({ "$version": 1 })), Ok(1));
+        assert!(config_version(&json!({ "$version": "1" })).is_err());
+        assert!(config_version(&json!({ "$version": CONFIG_VERSION + 1 })).is_err());
+    }
+
+    #[test]
+    fn test_set_current_version() {
+        let mut config = json!({ "$schema": "schema.json", "rules": {} });
+        assert_eq!(
+            set_current_version(&mut config),
+            Some(ConfigMigration::Version { from: 0, to: CONFIG_VERSION })
+        );
+        let keys = config.as_object().unwrap().keys().collect::<Vec<_>>();
+        assert_eq!(keys, ["$schema", "$version", "rules"]);
+        assert_eq!(set_current_version(&mut config), None);
+    }
+}
@@ -1,25 +1,50 @@
 use std::path::PathBuf;
 
+use rustc_hash::FxHashMap;
+
+use crate::{RuleCategory, config::overrides::GlobSet, rules::RuleEnum};
+
 mod categories;
 mod config_builder;
 mod config_store;
+mod deprecation;
 mod env;
 mod globals;
 mod ignore_matcher;
+mod interpolation;
+mod linter_options;
+mod migration;
+#[cfg(any(test, feature = "validate_config"))]
+mod options_schema;
+mod origin;
 mod overrides;
 mod oxlintrc;
+mod parser_options;
+mod per_file_thresholds;
 pub mod plugins;
+mod rule_options;
 mod rules;
 mod settings;
+mod validation;
+pub use categories::OxlintCategoriesOverride;
 pub use config_builder::{ConfigBuilderError, ConfigStoreBuilder};
 pub use config_store::{Config, ConfigStore, ResolvedLinterState};
+pub use deprecation::{DeprecatedName, DeprecationKind};
 pub use env::OxlintEnv;
 pub use globals::{GlobalValue, OxlintGlobals};
 pub use ignore_matcher::LintIgnoreMatcher;
+pub use linter_options::OxlintLinterOptions;
+pub use migration::{CONFIG_VERSION, ConfigMigration, MovedKey};
+#[cfg(test)]
+pub use options_schema::check_options;
+pub use origin::{RuleOrigin, RuleOriginKind, RuleOriginsMap};
 pub use overrides::OxlintOverrides;
 pub use oxlintrc::Oxlintrc;
+pub use parser_options::OxlintParserOptions;
+pub use per_file_thresholds::OxlintPerFileThresholds;
 pub use plugins::LintPlugins;
-pub use rules::{ESLintRule, OxlintRules};
+pub use rule_options::{RuleOptions, RuleOptionsMap};
+pub use rules::{ESLintRule, OxlintRules, diagnostic_codes, rule_of_diagnostic_code};
 pub use settings::{OxlintSettings, jsdoc::JSDocPluginSettings};
 
 #[derive(Debug, Default, Clone)]
@@ -32,6 +57,24 @@ pub struct LintConfig {
     pub(crate) globals: OxlintGlobals,
     /// Absolute path to the configuration file (may be `None` if there is no file).
     pub(crate) path: Option<PathBuf>,
+    /// Options which change how the linter itself behaves.
+    pub(crate) linter_options: OxlintLinterOptions,
+    /// Rules which have been re-assigned to a different category.
+    pub(crate) category_overrides: FxHashMap<RuleEnum, RuleCategory>,
+    /// Globs of vendored files, relative to the configuration file.
+    /// Diagnostics in these files are capped at `linter_options.vendor_severity()`.
+    pub(crate) vendor_paths: GlobSet,
+    /// Limits on the number of diagnostics reported in a file.
+    pub(crate) per_file_thresholds: OxlintPerFileThresholds,
+    /// Options which change how files are parsed.
+    pub(crate) parser_options: OxlintParserOptions,
+}
+
+impl LintConfig {
+    /// The category of `rule`, taking `categoriesOverride` into account.
+    pub(crate) fn rule_category(&self, rule: &RuleEnum) -> RuleCategory {
+        self.category_overrides.get(rule).copied().unwrap_or_else(|| rule.category())
+    }
 }
 
 impl From<Oxlintrc> for LintConfig {
@@ -42,6 +85,11 @@ impl From<Oxlintrc> for LintConfig {
             env: config.env,
             globals: config.globals,
             path: Some(config.path),
+            linter_options: config.linter_options,
+            category_overrides: config.categories_override.resolve(),
+            vendor_paths: GlobSet::new(&config.vendor_paths),
+            per_file_thresholds: config.per_file_thresholds,
+            parser_options: config.parser_options,
         }
     }
 }
@@ -119,7 +167,9 @@ mod test {
             .rules
             .override_rules(
                 &mut set,
+                &mut FxHashMap::default(),
                 &mut external_rules_for_override,
+                &FxHashMap::default(),
                 &RULES,
                 &external_linter_store,
             )
@@ -0,0 +1,464 @@
+use std::path::Path;
+
+use itertools::Itertools;
+use schemars::{
+    r#gen::SchemaGenerator,
+    schema::{InstanceType, Schema, SingleOrVec},
+};
+use serde_json::Value;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+
+use crate::rules::{RULES, RuleEnum};
+
+use super::{
+    rules::{parse_rule_key, transform_rule_and_plugin_name},
+    validation::did_you_mean,
+};
+
+/// Check the options of the built-in rules configured in the JSON of the configuration file at
+/// `path` against the schema of their `config` in `declare_oxc_lint!`, unless they are declared
+/// with `unchecked_config`.
+///
+/// Rules read the options they understand and ignore the others, so invalid options would
+/// otherwise silently fall back to the defaults. `source_text` is the text `json` was parsed from,
+/// with comments stripped, to point at the invalid options. It is `None` if the JSON was not
+/// read from a file.
+pub fn invalid_rule_options(
+    json: &Value,
+    path: &Path,
+    source_text: Option<&str>,
+) -> Vec<OxcDiagnostic> {
+    let mut diagnostics = vec![];
+    let Some(config) = json.as_object() else {
+        return diagnostics;
+    };
+
+    let mut generator = SchemaGenerator::default();
+    let mut check = |rules: Option<&Value>, pointer: &[JsonKey]| {
+        let Some(rules) = rules.and_then(Value::as_object) else {
+            return;
+        };
+        for (key, value) in rules {
+            // Options follow the severity, e.g. `["error", { "checkLoops": true }]`.
+            let Some(options) = value.as_array().and_then(|value| value.get(1..)) else {
+                continue;
+            };
+            let (plugin_name, rule_name) = parse_rule_key(key);
+            let (rule_name, plugin_name) = transform_rule_and_plugin_name(&rule_name, &plugin_name);
+            let Some(rule) = RULES
+                .iter()
+                .find(|rule| rule.plugin_name() == plugin_name && rule.name() == rule_name)
+            else {
+                continue;
+            };
+            let Err(reason) = check_options(rule, options, &mut generator) else {
+                continue;
+            };
+            let options_pointer =
+                [pointer, &[JsonKey::Field("rules"), JsonKey::Field(key), JsonKey::Index(1)]]
+                    .concat();
+            let span =
+                source_text.and_then(|source_text| find_value(source_text, &options_pointer));
+            let location = match source_text.zip(span) {
+                Some((source_text, span)) => {
+                    let (line, column) = line_column(source_text, span.start);
+                    format!("{}:{line}:{column}", path.display())
+                }
+                None => path.display().to_string(),
+            };
+            let diagnostic =
+                OxcDiagnostic::warn(format!("Invalid options for rule `{key}` in {location}"))
+                    .with_help(reason);
+            diagnostics.push(match span {
+                Some(span) => diagnostic.with_label(span),
+                None => diagnostic,
+            });
+        }
+    };
+
+    check(config.get("rules"), &[]);
+    let overrides = config.get("overrides").and_then(Value::as_array).into_iter().flatten();
+    for (index, r#override) in overrides.enumerate() {
+        check(r#override.get("rules"), &[JsonKey::Field("overrides"), JsonKey::Index(index)]);
+    }
+
+    diagnostics
+}
+
+/// A key of an object or an index of an array, leading to a value in a JSON document.
+#[derive(Debug, Clone, Copy)]
+enum JsonKey<'k> {
+    Field(&'k str),
+    Index(usize),
+}
+
+/// The span of the value at `pointer` in the JSON `source_text`, or `None` if there is no such
+/// value, e.g. because the configuration was migrated after it was read.
+fn find_value(source_text: &str, pointer: &[JsonKey]) -> Option<Span> {
+    let bytes = source_text.as_bytes();
+    let mut pos = skip_whitespace(bytes, 0);
+    for key in pointer {
+        match *key {
+            JsonKey::Field(field) => {
+                if bytes.get(pos) != Some(&b'{') {
+                    return None;
+                }
+                pos = skip_whitespace(bytes, pos + 1);
+                loop {
+                    let key_end = skip_value(bytes, pos)?;
+                    let key = serde_json::from_str::<String>(&source_text[pos..key_end]).ok()?;
+                    pos = skip_whitespace(bytes, key_end);
+                    if bytes.get(pos) != Some(&b':') {
+                        return None;
+                    }
+                    pos = skip_whitespace(bytes, pos + 1);
+                    if key == field {
+                        break;
+                    }
+                    pos = skip_separator(bytes, skip_value(bytes, pos)?)?;
+                }
+            }
+            JsonKey::Index(index) => {
+                if bytes.get(pos) != Some(&b'[') {
+                    return None;
+                }
+                pos = skip_whitespace(bytes, pos + 1);
+                for _ in 0..index {
+                    pos = skip_separator(bytes, skip_value(bytes, pos)?)?;
+                }
+            }
+        }
+    }
+    let end = skip_value(bytes, pos)?;
+    Some(Span::new(u32::try_from(pos).ok()?, u32::try_from(end).ok()?))
+}
+
+fn skip_whitespace(bytes: &[u8], mut pos: usize) -> usize {
+    while bytes.get(pos).is_some_and(u8::is_ascii_whitespace) {
+        pos += 1;
+    }
+    pos
+}
+
+/// Skip the `,` following a value and the whitespace around it. `None` at the end of the object
+/// or array.
+fn skip_separator(bytes: &[u8], pos: usize) -> Option<usize> {
+    let pos = skip_whitespace(bytes, pos);
+    (bytes.get(pos) == Some(&b',')).then(|| skip_whitespace(bytes, pos + 1))
+}
+
+/// The end of the value starting at `pos`.
+fn skip_value(bytes: &[u8], mut pos: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    loop {
+        match *bytes.get(pos)? {
+            b'"' => {
+                pos += 1;
+                loop {
+                    match *bytes.get(pos)? {
+                        b'\\' => pos += 2,
+                        b'"' => break,
+                        _ => pos += 1,
+                    }
+                }
+                pos += 1;
+            }
+            b'{' | b'[' => {
+                depth += 1;
+                pos += 1;
+            }
+            b'}' | b']' => {
+                depth = depth.checked_sub(1)?;
+                pos += 1;
+            }
+            // Numbers, `true`, `false` and `null`
+            _ if depth == 0 => {
+                while bytes
+                    .get(pos)
+                    .is_some_and(|byte| byte.is_ascii_alphanumeric() || b"+-.".contains(byte))
+                {
+                    pos += 1;
+                }
+                return Some(pos);
+            }
+            _ => pos += 1,
+        }
+        if depth == 0 {
+            return Some(pos);
+        }
+    }
+}
+
+/// The 1-based line and column of `offset` in `source_text`.
+fn line_column(source_text: &str, offset: u32) -> (usize, usize) {
+    let before = &source_text[..offset as usize];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    (before.matches('\n').count() + 1, before[line_start..].chars().count() + 1)
+}
+
+/// Check `options`, the options following the severity of `rule` in a configuration file, against
+/// the schema of its `config`, or describe why they do not match it. Rules declared without
+/// `config`, or with `unchecked_config`, accept any options.
+///
+/// Options of rules declared with `typed_config` must also deserialize as their `config`.
+pub fn check_options(
+    rule: &RuleEnum,
+    options: &[Value],
+    generator: &mut SchemaGenerator,
+) -> Result<(), String> {
+    check_schema(rule, options, generator)?;
+    rule.deserialize_config(options)
+}
+
+fn check_schema(
+    rule: &RuleEnum,
+    options: &[Value],
+    generator: &mut SchemaGenerator,
+) -> Result<(), String> {
+    if !rule.validates_config() {
+        return Ok(());
+    }
+    let (Some(schema), Some(first)) = (rule.config_schema(generator), options.first()) else {
+        return Ok(());
+    };
+    let validator = OptionsValidator { generator };
+    let schema = validator.resolve(&schema);
+    // Rules with several options, e.g. `["error", "always", { "ignore": [] }]`, have a schema for
+    // each of them.
+    let items = match schema {
+        Schema::Object(schema) => schema.array.as_ref().and_then(|array| match &array.items {
+            Some(SingleOrVec::Vec(items)) => Some(items),
+            _ => None,
+        }),
+        Schema::Bool(_) => None,
+    };
+    match items {
+        Some(items) => {
+            options.iter().zip(items).enumerate().try_for_each(|(i, (option, schema))| {
+                validator.check(schema, option, &format!("Option {}", i + 1))
+            })
+        }
+        None => validator.check(schema, first, "The options"),
+    }
+}
+
+/// Checks values against the schemas of rule options.
+///
+/// Only the parts of JSON schema generated for rule configurations are checked: types, enums,
+/// properties and items. Unknown properties are only reported when they are likely misspellings,
+/// since the schemas of some rules do not list every option they read.
+struct OptionsValidator<'a> {
+    generator: &'a SchemaGenerator,
+}
+
+impl OptionsValidator<'_> {
+    /// Follow the reference of `schema` to its definition, if any.
+    fn resolve<'s>(&'s self, schema: &'s Schema) -> &'s Schema {
+        self.generator.dereference(schema).unwrap_or(schema)
+    }
+
+    /// Check that `value` matches `schema`, or describe why it does not. `name` describes
+    /// `value` in the description, e.g. ``"`ignore[0]`"``.
+    fn check(&self, schema: &Schema, value: &Value, name: &str) -> Result<(), String> {
+        let schema = match self.resolve(schema) {
+            Schema::Bool(true) => return Ok(()),
+            Schema::Bool(false) => return Err(format!("{name} is not allowed.")),
+            Schema::Object(schema) => schema,
+        };
+
+        if let Some(subschemas) = &schema.subschemas {
+            for subschema in subschemas.all_of.iter().flatten() {
+                self.check(subschema, value, name)?;
+            }
+            for variants in [&subschemas.any_of, &subschemas.one_of].into_iter().flatten() {
+                if !variants.iter().any(|variant| self.check(variant, value, name).is_ok()) {
+                    return Err(match self.enum_values(variants) {
+                        Some(values) => format!("{name} must be one of {}.", values.join(", ")),
+                        None => format!("{name} does not match any of the accepted forms."),
+                    });
+                }
+            }
+        }
+
+        if let Some(values) = &schema.enum_values
+            && !values.contains(value)
+        {
+            return Err(format!("{name} must be one of {}.", values.iter().join(", ")));
+        }
+        if let Some(constant) = &schema.const_value
+            && constant != value
+        {
+            return Err(format!("{name} must be {constant}."));
+        }
+
+        if let Some(instance_type) = &schema.instance_type {
+            let types = match instance_type {
+                SingleOrVec::Single(instance_type) => std::slice::from_ref(&**instance_type),
+                SingleOrVec::Vec(types) => types.as_slice(),
+            };
+            if !types.iter().any(|instance_type| is_instance_of(value, *instance_type)) {
+                let types = types.iter().map(|instance_type| describe_type(*instance_type));
+                return Err(format!("{name} must be {}.", types.format(" or ")));
+            }
+        }
+
+        match value {
+            Value::Object(object) => {
+                let Some(validation) = &schema.object else {
+                    return Ok(());
+                };
+                for (key, value) in object {
+                    let property_name = if name.starts_with('`') {
+                        format!("`{}.{key}`", name.trim_matches('`'))
+                    } else {
+                        format!("`{key}`")
+                    };
+                    if let Some(property) = validation.properties.get(key) {
+                        self.check(property, value, &property_name)?;
+                    } else if let Some(additional) = &validation.additional_properties {
+                        self.check(additional, value, &property_name)?;
+                    } else if let Some(suggestion) =
+                        did_you_mean(key, validation.properties.keys().map(String::as_str))
+                    {
+                        return Err(format!(
+                            "Unknown option {property_name}. Did you mean `{suggestion}`?"
+                        ));
+                    }
+                }
+            }
+            Value::Array(array) => {
+                if let Some(SingleOrVec::Single(item)) =
+                    schema.array.as_ref().and_then(|array| array.items.as_ref())
+                {
+                    for (i, value) in array.iter().enumerate() {
+                        let item_name = format!("`{}[{i}]`", name.trim_matches('`'));
+                        self.check(item, value, &item_name)?;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// The values of `variants` if each of them is a single value, e.g. the variants of a unit
+    /// enum.
+    fn enum_values(&self, variants: &[Schema]) -> Option<Vec<String>> {
+        let mut values = vec![];
+        for variant in variants {
+            let Schema::Object(variant) = self.resolve(variant) else {
+                return None;
+            };
+            match (&variant.enum_values, &variant.const_value) {
+                (Some(enum_values), _) => values.extend(enum_values.iter().map(Value::to_string)),
+                (None, Some(constant)) => values.push(constant.to_string()),
+                (None, None) => return None,
+            }
+        }
+        Some(values)
+    }
+}
+
+fn is_instance_of(value: &Value, instance_type: InstanceType) -> bool {
+    match instance_type {
+        InstanceType::Null => value.is_null(),
+        InstanceType::Boolean => value.is_boolean(),
+        InstanceType::Object => value.is_object(),
+        InstanceType::Array => value.is_array(),
+        InstanceType::Number => value.is_number(),
+        InstanceType::String => value.is_string(),
+        InstanceType::Integer => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+    }
+}
+
+fn describe_type(instance_type: InstanceType) -> &'static str {
+    match instance_type {
+        InstanceType::Null => "null",
+        InstanceType::Boolean => "a boolean",
+        InstanceType::Object => "an object",
+        InstanceType::Array => "an array",
+        InstanceType::Number => "a number",
+        InstanceType::String => "a string",
+        InstanceType::Integer => "an integer",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use schemars::r#gen::SchemaGenerator;
+
+    use crate::rules::RULES;
+
+    use super::{check_options, invalid_rule_options};
+
+    #[test]
+    fn test_invalid_rule_options() {
+        let source_text = r#"{
+  "rules": {
+    "no-cond-assign": ["error", "always"],
+    "eslint/no-cond-assign": ["error", "never"],
+    "jest/max-nested-describe": ["warn", { "max": "3" }],
+    "jest/no-hooks": ["warn", { "alow": ["beforeEach"] }],
+    "jest/no-disabled-tests": "error",
+    "no-console": ["error", { "allow": "log" }],
+    "curly": ["error", "multi"]
+  },
+  "overrides": [
+    { "files": ["*.ts"], "rules": { "unicorn/switch-case-braces": ["error", 1] } },
+    { "files": ["*.test.js"], "rules": { "jest/max-nested-describe": ["warn", { "max": -1 }] } }
+  ]
+}"#;
+        let config = serde_json::from_str(source_text).unwrap();
+        let messages = |source_text| {
+            invalid_rule_options(&config, Path::new(".oxlintrc.json"), source_text)
+                .into_iter()
+                .map(|diagnostic| {
+                    let span = diagnostic.labels.as_ref().map(|labels| {
+                        let span = labels[0].inner();
+                        &source_text.unwrap()[span.offset()..span.offset() + span.len()]
+                    });
+                    let help = diagnostic.help.as_deref().unwrap_or("");
+                    format!("{} ({help}) {span:?}", diagnostic.message)
+                })
+                .collect::<Vec<_>>()
+        };
+        // Rules declared with `unchecked_config`, like `curly`, accept any options. The options of
+        // `jest/max-nested-describe` in the override match the schema, but do not deserialize.
+        assert_eq!(
+            messages(Some(source_text)),
+            [
+                r#"Invalid options for rule `eslint/no-cond-assign` in .oxlintrc.json:4:40 (The options must be one of "except-parens", "always".) Some("\"never\"")"#,
+                r#"Invalid options for rule `jest/max-nested-describe` in .oxlintrc.json:5:42 (`max` must be an integer.) Some("{ \"max\": \"3\" }")"#,
+                r#"Invalid options for rule `jest/no-hooks` in .oxlintrc.json:6:31 (Unknown option `alow`. Did you mean `allow`?) Some("{ \"alow\": [\"beforeEach\"] }")"#,
+                r#"Invalid options for rule `no-console` in .oxlintrc.json:8:29 (`allow` must be an array.) Some("{ \"allow\": \"log\" }")"#,
+                r#"Invalid options for rule `unicorn/switch-case-braces` in .oxlintrc.json:12:77 (The options must be one of "always", "avoid".) Some("1")"#,
+                r#"Invalid options for rule `jest/max-nested-describe` in .oxlintrc.json:13:79 (invalid value: integer `-1`, expected usize.) Some("{ \"max\": -1 }")"#,
+            ]
+        );
+        // Without the source text, e.g. for configurations passed as JSON values.
+        assert_eq!(
+            messages(None)[0],
+            r#"Invalid options for rule `eslint/no-cond-assign` in .oxlintrc.json (The options must be one of "except-parens", "always".) None"#
+        );
+    }
+
+    #[test]
+    fn test_default_configs_match_schema() {
+        let mut generator = SchemaGenerator::default();
+        for rule in RULES.iter() {
+            let Some(serde_json::Value::Array(options)) = rule.default_config() else {
+                continue;
+            };
+            if let Err(reason) = check_options(rule, &options, &mut generator) {
+                panic!("Default options of `{}/{}`: {reason}", rule.plugin_name(), rule.name());
+            }
+        }
+    }
+}
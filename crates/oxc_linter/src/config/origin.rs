@@ -0,0 +1,43 @@
+use std::{
+    collections::BTreeMap,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+/// The configuration which decided the severity of a rule for a file, reported with the
+/// diagnostics of the rule, e.g. `{"kind":"override","configPath":"packages/a/.oxlintrc.json","overrideIndex":0}`.
+///
+/// Computed by [`ConfigStore::resolve`](super::ConfigStore::resolve).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleOrigin {
+    pub kind: RuleOriginKind,
+    /// Path of the configuration file, `None` for the default configuration and for
+    /// [`RuleOriginKind::CliFilter`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config_path: Option<PathBuf>,
+    /// Index of the override in `overrides` of the configuration file, only for
+    /// [`RuleOriginKind::Override`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub override_index: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RuleOriginKind {
+    /// `rules` or `categories` of the root configuration file, or the default configuration
+    RootConfig,
+    /// `rules` or `categories` of a nested configuration file
+    NestedConfig,
+    /// An override of the root or a nested configuration file
+    Override,
+    /// `-A`, `-W` or `-D` on the command line
+    CliFilter,
+}
+
+/// Origins of the rules which reported diagnostics, keyed by the path of the linted file and the
+/// code of the rule, e.g. `eslint(no-debugger)`.
+pub type RuleOriginsMap = Arc<Mutex<FxHashMap<PathBuf, BTreeMap<String, RuleOrigin>>>>;
@@ -8,7 +8,10 @@ use rustc_hash::FxHashSet;
 use schemars::{JsonSchema, r#gen, schema::Schema};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::{LintPlugins, OxlintEnv, OxlintGlobals, config::OxlintRules};
+use crate::{
+    LintPlugins, OxlintEnv, OxlintGlobals,
+    config::{OxlintParserOptions, OxlintRules, categories::OxlintCategories},
+};
 
 // nominal wrapper required to add JsonSchema impl
 #[derive(Debug, Default, Clone, Deserialize, Serialize)]
@@ -84,9 +87,13 @@ pub struct OxlintOverride {
     pub files: GlobSet,
 
     /// Environments enable and disable collections of global variables.
+    ///
+    /// Environments of the base configuration which are not listed here are kept.
     pub env: Option<OxlintEnv>,
 
     /// Enabled or disabled specific global variables.
+    ///
+    /// Global variables of the base configuration which are not listed here are kept.
     pub globals: Option<OxlintGlobals>,
 
     /// Optionally change what plugins are enabled for this override. When
@@ -110,8 +117,44 @@ pub struct OxlintOverride {
         FxHashSet<(PathBuf /* config file directory */, String /* plugin specifier */)>,
     >,
 
+    /// Enable or disable categories of rules for the files matched by this override.
+    ///
+    /// Categories are applied before the `rules` of the same override, so a rule configured in
+    /// `rules` takes precedence over its category.
+    ///
+    /// ## Example
+    /// `{ "pedantic": "warn" }`
+    #[serde(default, skip_serializing_if = "OxlintCategories::is_empty")]
+    pub categories: OxlintCategories,
+
     #[serde(default)]
     pub rules: OxlintRules,
+
+    /// Processor of a JS plugin which extracts JS/TS code from the files matched by this override,
+    /// in the form `plugin/processor`.
+    ///
+    /// Files with an extension which oxlint cannot lint, such as `.md`, are only linted when a
+    /// processor is configured for them.
+    ///
+    /// Note: JS plugins are experimental and not subject to semver.
+    ///
+    /// ## Example
+    /// `"markdown/markdown"`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub processor: Option<String>,
+
+    /// Options which change how the files matched by this override are parsed.
+    ///
+    /// Options of the base configuration which are not set here are kept.
+    ///
+    /// ## Example
+    /// `{ "sourceType": "script" }`
+    #[serde(
+        rename = "parserOptions",
+        default,
+        skip_serializing_if = "OxlintParserOptions::is_empty"
+    )]
+    pub parser_options: OxlintParserOptions,
 }
 
 /// A set of glob patterns.
@@ -144,9 +187,30 @@ impl GlobSet {
         )
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
     pub fn is_match(&self, path: &str) -> bool {
         self.0.iter().any(|glob| fast_glob::glob_match(glob, path))
     }
+
+    /// Extensions of patterns which match files by extension, such as `md` for `**/*.md`
+    /// and `md` and `mdx` for `docs/*.{md,mdx}`.
+    pub(crate) fn extensions(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().flat_map(|glob| {
+            let file_name = glob.rsplit('/').next().unwrap_or_default();
+            let extension = file_name.strip_prefix("*.").unwrap_or_default();
+            let extensions = extension
+                .strip_prefix('{')
+                .and_then(|extensions| extensions.strip_suffix('}'))
+                .unwrap_or(extension);
+            extensions.split(',').filter(|extension| {
+                !extension.is_empty()
+                    && !extension.contains(['*', '?', '[', ']', '{', '}', '.', '!'])
+            })
+        })
+    }
 }
 
 fn deserialize_external_plugins_override<'de, D>(
@@ -182,6 +246,12 @@ mod test {
     use super::*;
     use serde_json::{from_value, json};
 
+    #[test]
+    fn test_globset_extensions() {
+        let glob_set = GlobSet::new(["*.md", "docs/*.{mdx,markdown}", "*.d.ts", "src/**", "*.*"]);
+        assert_eq!(glob_set.extensions().collect::<Vec<_>>(), ["md", "mdx", "markdown"]);
+    }
+
     #[test]
     fn test_globset() {
         let config: OxlintOverride = from_value(json!({
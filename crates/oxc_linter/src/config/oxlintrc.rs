@@ -1,4 +1,5 @@
 use std::{
+    collections::{BTreeMap, hash_map::Entry},
     ffi::OsStr,
     path::{Path, PathBuf},
 };
@@ -7,13 +8,24 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use schemars::{JsonSchema, schema_for};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{NormalizedPath, OxcDiagnostic};
 
 use crate::{LintPlugins, utils::read_to_string};
 
 use super::{
-    categories::OxlintCategories, env::OxlintEnv, globals::OxlintGlobals,
-    overrides::OxlintOverrides, rules::OxlintRules, settings::OxlintSettings,
+    categories::{OxlintCategories, OxlintCategoriesOverride},
+    deprecation::{DeprecatedName, replace_deprecated_names},
+    env::OxlintEnv,
+    globals::OxlintGlobals,
+    interpolation::expand_env_vars,
+    linter_options::OxlintLinterOptions,
+    migration::{ConfigMigration, migrate_to_current_version, set_current_version},
+    overrides::OxlintOverrides,
+    parser_options::OxlintParserOptions,
+    per_file_thresholds::OxlintPerFileThresholds,
+    rules::OxlintRules,
+    settings::OxlintSettings,
+    validation::unknown_config_names,
 };
 
 /// Oxlint Configuration File
@@ -28,6 +40,11 @@ use super::{
 ///
 /// :::
 ///
+/// Environment variables can be used in string values, like paths in `extends` and
+/// `ignorePatterns` or rule options, with `${env:NAME}`. They are expanded when the configuration
+/// is loaded, and it is an error if a variable is not set. Use `$${env:NAME}` for a literal
+/// `${env:NAME}`.
+///
 /// Example
 ///
 /// `.oxlintrc.json`
@@ -63,6 +80,24 @@ use super::{
 #[serde(default)]
 #[non_exhaustive]
 pub struct Oxlintrc {
+    /// Version of the configuration file format.
+    ///
+    /// Configuration files without a version are read as version 0. Run `oxlint --migrate-config`
+    /// to migrate a configuration file to the current version, e.g. moving keys and replacing
+    /// deprecated names.
+    ///
+    /// Example
+    ///
+    /// `.oxlintrc.json`
+    ///
+    /// ```json
+    /// {
+    ///   "$schema": "./node_modules/oxlint/configuration_schema.json",
+    ///   "$version": 1
+    /// }
+    /// ```
+    #[serde(rename = "$version", skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
     /// Enabled built-in plugins for Oxlint.
     /// You can view the list of available plugins on
     /// [the website](https://oxc.rs/docs/guide/usage/linter/plugins.html#supported-plugins).
@@ -84,6 +119,11 @@ pub struct Oxlintrc {
     #[schemars(with = "Option<FxHashSet<String>>")]
     pub external_plugins: Option<FxHashSet<(PathBuf, String)>>,
     pub categories: OxlintCategories,
+    #[serde(
+        rename = "categoriesOverride",
+        skip_serializing_if = "OxlintCategoriesOverride::is_empty"
+    )]
+    pub categories_override: OxlintCategoriesOverride,
     /// Example
     ///
     /// `.oxlintrc.json`
@@ -107,6 +147,9 @@ pub struct Oxlintrc {
     pub env: OxlintEnv,
     /// Enabled or disabled specific global variables.
     pub globals: OxlintGlobals,
+    /// Options which change how the linter itself behaves, rather than configuring individual rules.
+    #[serde(rename = "linterOptions", skip_serializing_if = "OxlintLinterOptions::is_empty")]
+    pub linter_options: OxlintLinterOptions,
     /// Add, remove, or otherwise reconfigure rules for specific files or groups of files.
     #[serde(skip_serializing_if = "OxlintOverrides::is_empty")]
     pub overrides: OxlintOverrides,
@@ -116,12 +159,89 @@ pub struct Oxlintrc {
     /// Globs to ignore during linting. These are resolved from the configuration file path.
     #[serde(rename = "ignorePatterns")]
     pub ignore_patterns: Vec<String>,
+    /// Globs of vendored or third-party code, such as `vendor/**`. These are resolved from the
+    /// configuration file path.
+    ///
+    /// Diagnostics in matching files are reported as warnings, regardless of the severity of the
+    /// rule, so vendored code stays visible without failing CI. Set
+    /// `linterOptions.vendorSeverity` to `"off"` to skip reporting them instead.
+    ///
+    /// Example
+    ///
+    /// `.oxlintrc.json`
+    ///
+    /// ```json
+    /// {
+    ///   "vendorPaths": ["vendor/**", "third_party/**"]
+    /// }
+    /// ```
+    #[serde(rename = "vendorPaths", skip_serializing_if = "Vec::is_empty")]
+    pub vendor_paths: Vec<String>,
+    /// Named sets of paths to lint, selected on the command line with `@name`. Each set is a list
+    /// of paths or globs, resolved from the configuration file path.
+    ///
+    /// Example
+    ///
+    /// `.oxlintrc.json`
+    ///
+    /// ```json
+    /// {
+    ///   "paths": {
+    ///     "app": ["src/**"],
+    ///     "scripts": ["tools/**", "scripts/build.js"]
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Running `oxlint @app @scripts` lints the files of both sets.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub paths: BTreeMap<String, Vec<String>>,
+    /// Limits on the number of diagnostics reported in a single file.
+    #[serde(
+        rename = "perFileThresholds",
+        skip_serializing_if = "OxlintPerFileThresholds::is_empty"
+    )]
+    pub per_file_thresholds: OxlintPerFileThresholds,
+    #[serde(rename = "parserOptions", skip_serializing_if = "OxlintParserOptions::is_empty")]
+    pub parser_options: OxlintParserOptions,
     /// Paths of configuration files that this configuration file extends (inherits from). The files
     /// are resolved relative to the location of the configuration file that contains the `extends`
     /// property. The configuration files are merged from the first to the last, with the last file
     /// overriding the previous ones.
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub extends: Vec<PathBuf>,
+    /// Report unknown keys, rules and environments in this configuration file as errors instead of
+    /// warnings.
+    ///
+    /// Example
+    ///
+    /// `.oxlintrc.json`
+    ///
+    /// ```json
+    /// {
+    ///   "strictConfig": true
+    /// }
+    /// ```
+    #[serde(rename = "strictConfig", skip_serializing_if = "std::ops::Not::not")]
+    pub strict_config: bool,
+    /// Ignore the `-A`, `-W` and `-D` options of the command line in this configuration file, so
+    /// that it fully controls its own rules. Only applies to nested configuration files.
+    ///
+    /// Example
+    ///
+    /// `vendor/.oxlintrc.json`
+    ///
+    /// ```json
+    /// {
+    ///   "ignoreCommandLineFilters": true
+    /// }
+    /// ```
+    #[serde(rename = "ignoreCommandLineFilters", skip_serializing_if = "std::ops::Not::not")]
+    pub ignore_command_line_filters: bool,
+    /// Unknown keys, rules and environments, and deprecated names found when reading the
+    /// configuration file.
+    #[serde(skip)]
+    pub warnings: Vec<OxcDiagnostic>,
 }
 
 impl Oxlintrc {
@@ -129,43 +249,82 @@ impl Oxlintrc {
     ///
     /// * Parse Failure
     pub fn from_file(path: &Path) -> Result<Self, OxcDiagnostic> {
-        let mut string = read_to_string(path).map_err(|e| {
-            OxcDiagnostic::error(format!(
-                "Failed to parse config {} with error {e:?}",
-                path.display()
-            ))
-        })?;
+        let (json, source_text) = Self::read_json(path)?;
+        Self::from_json(json, path, Some(&source_text))
+    }
 
+    /// Parse a configuration passed as a string, e.g. with `--config-inline`, as if it was read
+    /// from the configuration file at `path`. Relative paths in it, like those of `extends`, are
+    /// resolved from the directory of `path`.
+    ///
+    /// # Errors
+    ///
+    /// * Parse Failure
+    pub fn from_inline(json: &str, path: &Path) -> Result<Self, OxcDiagnostic> {
+        let mut string = json.to_string();
         // jsonc support
         json_strip_comments::strip(&mut string).map_err(|err| {
-            OxcDiagnostic::error(format!("Failed to parse jsonc file {}: {err:?}", path.display()))
+            OxcDiagnostic::error(format!("Failed to parse inline config: {err:?}"))
         })?;
-
         let json = serde_json::from_str::<serde_json::Value>(&string).map_err(|err| {
-            let ext = path.extension().and_then(OsStr::to_str);
-            let err = match ext {
-                // syntax error
-                Some(ext) if is_json_ext(ext) => err.to_string(),
-                Some(_) => "Only JSON configuration files are supported".to_string(),
-                None => {
-                    format!(
-                        "{err}, if the configuration is not a JSON file, please use JSON instead."
-                    )
-                }
-            };
-            OxcDiagnostic::error(format!(
-                "Failed to parse eslint config {}.\n{err}",
-                path.display()
-            ))
+            OxcDiagnostic::error(format!("Failed to parse inline config.\n{err}"))
         })?;
+        Self::from_json(json, path, Some(&string))
+    }
+
+    /// `source_text` is the text `json` was parsed from, with comments replaced by whitespace.
+    fn from_json(
+        mut json: serde_json::Value,
+        path: &Path,
+        #[cfg_attr(not(any(test, feature = "validate_config")), expect(unused_variables))]
+        source_text: Option<&str>,
+    ) -> Result<Self, OxcDiagnostic> {
+        expand_env_vars(&mut json, &|name| std::env::var(name).ok()).map_err(|err| {
+            OxcDiagnostic::error(format!("Failed to parse config {}: {err}.", path.display()))
+        })?;
+
+        // Configuration files of older versions are read by migrating them first.
+        let moved_keys = migrate_to_current_version(&mut json).map_err(|err| {
+            OxcDiagnostic::error(format!("Failed to parse config {}: {err}", path.display()))
+        })?;
+
+        let warnings = unknown_config_names(&json, path);
+        if !warnings.is_empty() && json.get("strictConfig") == Some(&serde_json::Value::Bool(true))
+        {
+            let mut message = format!(
+                "Failed to parse config {}, unknown names are not allowed with `strictConfig`.",
+                path.display()
+            );
+            for warning in &warnings {
+                message.push_str("\n  ");
+                message.push_str(&warning.message);
+                if let Some(help) = &warning.help {
+                    message.push_str(". ");
+                    message.push_str(help);
+                }
+            }
+            return Err(OxcDiagnostic::error(message));
+        }
+
+        // Deprecated names mean the same as their replacements, so replacing them does not change
+        // the configuration.
+        let deprecated = replace_deprecated_names(&mut json);
 
         let mut config = Self::deserialize(&json).map_err(|err| {
             OxcDiagnostic::error(format!("Failed to parse config with error {err:?}"))
         })?;
 
         config.path = path.to_path_buf();
+        config.warnings = warnings;
+        config.warnings.extend(moved_keys.iter().map(|key| key.to_diagnostic(path)));
+        config.warnings.extend(deprecated.iter().map(|name| name.to_diagnostic(path)));
+        #[cfg(any(test, feature = "validate_config"))]
+        config.warnings.extend(super::options_schema::invalid_rule_options(
+            &json,
+            path,
+            source_text,
+        ));
 
-        #[expect(clippy::missing_panics_doc)]
         let config_dir = config.path.parent().unwrap();
         if let Some(external_plugins) = &mut config.external_plugins {
             *external_plugins = std::mem::take(external_plugins)
@@ -186,6 +345,104 @@ impl Oxlintrc {
         Ok(config)
     }
 
+    /// Replace the deprecated names in the configuration file at `path`, and return them.
+    ///
+    /// The file is only written if it contains deprecated names. Comments are not kept.
+    ///
+    /// # Errors
+    ///
+    /// * Parse Failure
+    /// * Failure to write the file
+    pub fn fix_deprecated_names(path: &Path) -> Result<Vec<DeprecatedName>, OxcDiagnostic> {
+        Self::rewrite(path, |json| Ok(replace_deprecated_names(json)))
+    }
+
+    /// Migrate the configuration file at `path` to the current version of the format, and return
+    /// the changes in the order they were made.
+    ///
+    /// Keys are moved, deprecated names are replaced, and `"$version"` is set. The file is only
+    /// written if anything changed. Comments are not kept.
+    ///
+    /// # Errors
+    ///
+    /// * Parse Failure, or a version newer than the current version
+    /// * Failure to write the file
+    pub fn migrate(path: &Path) -> Result<Vec<ConfigMigration>, OxcDiagnostic> {
+        Self::rewrite(path, |json| {
+            let mut migrations = migrate_to_current_version(json)
+                .map_err(|err| {
+                    OxcDiagnostic::error(format!(
+                        "Failed to migrate config {}: {err}",
+                        path.display()
+                    ))
+                })?
+                .into_iter()
+                .map(ConfigMigration::MovedKey)
+                .collect::<Vec<_>>();
+            migrations
+                .extend(replace_deprecated_names(json).into_iter().map(ConfigMigration::Renamed));
+            migrations.extend(set_current_version(json));
+            Ok(migrations)
+        })
+    }
+
+    /// Apply `rewrite` to the JSON of the configuration file at `path`, and write the file back
+    /// if it returns any changes.
+    fn rewrite<T>(
+        path: &Path,
+        rewrite: impl FnOnce(&mut serde_json::Value) -> Result<Vec<T>, OxcDiagnostic>,
+    ) -> Result<Vec<T>, OxcDiagnostic> {
+        let (mut json, _) = Self::read_json(path)?;
+        let changes = rewrite(&mut json)?;
+        if !changes.is_empty() {
+            Self::write_json(path, &json)?;
+        }
+        Ok(changes)
+    }
+
+    fn write_json(path: &Path, json: &serde_json::Value) -> Result<(), OxcDiagnostic> {
+        let mut content = serde_json::to_string_pretty(json).unwrap();
+        content.push('\n');
+        std::fs::write(path, content).map_err(|err| {
+            OxcDiagnostic::error(format!("Failed to write config {}: {err}", path.display()))
+        })
+    }
+
+    /// Read the JSON of the configuration file at `path`, which may contain comments, and its
+    /// source text with the comments replaced by whitespace.
+    fn read_json(path: &Path) -> Result<(serde_json::Value, String), OxcDiagnostic> {
+        let mut string = read_to_string(path).map_err(|e| {
+            OxcDiagnostic::error(format!(
+                "Failed to parse config {} with error {e:?}",
+                path.display()
+            ))
+        })?;
+
+        // jsonc support
+        json_strip_comments::strip(&mut string).map_err(|err| {
+            OxcDiagnostic::error(format!("Failed to parse jsonc file {}: {err:?}", path.display()))
+        })?;
+
+        let json = serde_json::from_str::<serde_json::Value>(&string).map_err(|err| {
+            let ext = path.extension().and_then(OsStr::to_str);
+            let err = match ext {
+                // syntax error
+                Some(ext) if is_json_ext(ext) => err.to_string(),
+                Some(_) => "Only JSON configuration files are supported".to_string(),
+                None => {
+                    format!(
+                        "{err}, if the configuration is not a JSON file, please use JSON instead."
+                    )
+                }
+            };
+            OxcDiagnostic::error(format!(
+                "Failed to parse eslint config {}.\n{err}",
+                path.display()
+            ))
+        })?;
+        Ok((json, string))
+    }
+
     /// # Errors
     ///
     /// * Parse Failure
@@ -252,12 +509,41 @@ impl Oxlintrc {
         }
     }
 
+    /// Whether the `json` plugin is enabled by this configuration or one of its overrides, so
+    /// that JSON files must be collected in addition to the files with a lintable extension.
+    pub fn lints_json(&self) -> bool {
+        let has_json = |plugins: Option<LintPlugins>| {
+            plugins.is_some_and(|plugins| plugins.contains(LintPlugins::JSON))
+        };
+        has_json(self.plugins)
+            || self.overrides.iter().any(|r#override| has_json(r#override.plugins))
+    }
+
+    /// Extensions of the files which overrides with a `processor` match by extension,
+    /// e.g. `md` for `"files": ["**/*.md"]`.
+    ///
+    /// Those files can't be linted by oxlint itself, so they must be collected in addition to the
+    /// files with a lintable extension.
+    pub fn processed_extensions(&self) -> Vec<String> {
+        let mut extensions = self
+            .overrides
+            .iter()
+            .filter(|r#override| r#override.processor.is_some())
+            .flat_map(|r#override| r#override.files.extensions())
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        extensions.sort_unstable();
+        extensions.dedup();
+        extensions
+    }
+
     /// Merges two [Oxlintrc] files together
     /// [Self] takes priority over `other`
     #[must_use]
     pub fn merge(&self, other: &Oxlintrc) -> Oxlintrc {
         let mut categories = other.categories.clone();
         categories.extend(self.categories.iter());
+        let categories_override = self.categories_override.merge(&other.categories_override);
 
         let rules = self
             .rules
@@ -265,23 +551,42 @@ impl Oxlintrc {
             .iter()
             .chain(&other.rules.rules)
             .fold(FxHashMap::default(), |mut rules_set, rule| {
-                if rules_set.contains_key(&(&rule.plugin_name, &rule.rule_name)) {
-                    return rules_set;
+                match rules_set.entry((&rule.plugin_name, &rule.rule_name)) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(rule.clone());
+                    }
+                    // Like ESLint, a rule which only sets a severity keeps the options of the
+                    // extended config.
+                    Entry::Occupied(mut entry) => {
+                        let rule_of_self = entry.get_mut();
+                        if rule_of_self.config.is_none() {
+                            rule_of_self.config.clone_from(&rule.config);
+                        }
+                    }
                 }
-                rules_set.insert((&rule.plugin_name, &rule.rule_name), rule);
                 rules_set
             })
-            .values()
-            .map(|rule| (**rule).clone())
+            .into_values()
             .collect::<Vec<_>>();
 
         let settings = self.settings.clone();
-        let env = self.env.clone();
-        let globals = self.globals.clone();
+        // Environments and globals are merged per key, like the `env` and `globals` of overrides.
+        let mut env = other.env.clone();
+        self.env.override_envs(&mut env);
+        let mut globals = other.globals.clone();
+        self.globals.override_globals(&mut globals);
+        let linter_options = self.linter_options.merge(&other.linter_options);
 
         let mut overrides = other.overrides.clone();
         overrides.extend(self.overrides.clone());
 
+        let mut vendor_paths = rebase_globs(&other.vendor_paths, &other.path, &self.path);
+        vendor_paths.extend(self.vendor_paths.iter().cloned());
+
+        // Path sets of the same name are replaced, not merged.
+        let mut paths = other.paths.clone();
+        paths.extend(self.paths.iter().map(|(name, globs)| (name.clone(), globs.clone())));
+
         let plugins = match (self.plugins, other.plugins) {
             (Some(self_plugins), Some(other_plugins)) => Some(self_plugins | other_plugins),
             (Some(self_plugins), None) => Some(self_plugins),
@@ -298,21 +603,72 @@ impl Oxlintrc {
         };
 
         Oxlintrc {
+            version: self.version,
             plugins,
             external_plugins,
             categories,
+            categories_override,
             rules: OxlintRules::new(rules),
             settings,
             env,
             globals,
+            linter_options,
             overrides,
             path: self.path.clone(),
             ignore_patterns: self.ignore_patterns.clone(),
+            vendor_paths,
+            paths,
+            per_file_thresholds: self.per_file_thresholds.merge(other.per_file_thresholds),
+            parser_options: self.parser_options.merge(other.parser_options),
             extends: self.extends.clone(),
+            strict_config: self.strict_config || other.strict_config,
+            ignore_command_line_filters: self.ignore_command_line_filters
+                || other.ignore_command_line_filters,
+            warnings: self.warnings.iter().chain(&other.warnings).cloned().collect(),
         }
     }
 }
 
+/// Rebase `globs`, which are resolved from the directory of the configuration file `from`, so
+/// they match the same files when resolved from the directory of the configuration file `to`.
+fn rebase_globs(globs: &[String], from: &Path, to: &Path) -> Vec<String> {
+    let (Some(from_dir), Some(to_dir)) = (from.parent(), to.parent()) else {
+        return globs.to_vec();
+    };
+    if from_dir == to_dir {
+        return globs.to_vec();
+    }
+    // Patterns without `/` match files in any directory, see `GlobSet::new`.
+    let globs = globs
+        .iter()
+        .map(|glob| if glob.contains('/') { glob.clone() } else { format!("**/{glob}") });
+
+    // `from` is in a subdirectory, e.g. `packages/a/base.json` extended by `.oxlintrc.json`.
+    if let Ok(prefix) = from_dir.strip_prefix(to_dir) {
+        let prefix = NormalizedPath::new(prefix);
+        return globs.map(|glob| format!("{prefix}/{glob}")).collect();
+    }
+
+    // `from` is in a parent or unrelated directory. Paths of files in it are matched as
+    // absolute paths, while files in `to_dir` are matched relative to it.
+    let to_dir_prefix = to_dir.strip_prefix(from_dir).ok().map(NormalizedPath::new);
+    globs
+        .map(|glob| {
+            if glob.starts_with("**/") {
+                return glob;
+            }
+            if let Some(to_dir_prefix) = &to_dir_prefix
+                && let Some(rest) = glob
+                    .strip_prefix(to_dir_prefix.as_str())
+                    .and_then(|rest| rest.strip_prefix('/'))
+            {
+                return rest.to_string();
+            }
+            format!("{}/{glob}", NormalizedPath::new(from_dir))
+        })
+        .collect()
+}
+
 fn is_json_ext(ext: &str) -> bool {
     ext == "json" || ext == "jsonc"
 }
@@ -363,6 +719,33 @@ mod test {
         assert_eq!(config.extends, Vec::<PathBuf>::default());
     }
 
+    #[test]
+    fn test_oxlintrc_from_inline() {
+        let path = Path::new("/project/<config-inline>");
+        let config = Oxlintrc::from_inline(
+            r#"{ /* comment */ "plugins": ["react"], "rules": { "no-debugger": "error" } }"#,
+            path,
+        )
+        .unwrap();
+        assert_eq!(config.plugins, Some(LintPlugins::REACT));
+        assert_eq!(config.path, path);
+        assert!(!config.rules.is_empty());
+
+        let err = Oxlintrc::from_inline("{ \"rules\": ", path).unwrap_err();
+        assert!(err.to_string().starts_with("Failed to parse inline config."));
+
+        // Invalid options are located in the text with comments.
+        let config = Oxlintrc::from_inline(
+            "{\n  // comment\n  \"rules\": { \"no-console\": [\"error\", { \"allow\": 1 }] }\n}",
+            path,
+        )
+        .unwrap();
+        assert_eq!(
+            config.warnings[0].message,
+            "Invalid options for rule `no-console` in /project/<config-inline>:3:38"
+        );
+    }
+
     #[test]
     fn test_oxlintrc_de_plugins_empty_array() {
         let config: Oxlintrc = serde_json::from_value(json!({ "plugins": [] })).unwrap();
@@ -384,7 +767,7 @@ mod test {
             serde_json::from_str(r#"{ "plugins": ["typescript", "unicorn"] }"#).unwrap();
         assert_eq!(config.plugins, Some(LintPlugins::TYPESCRIPT | LintPlugins::UNICORN));
         let config: Oxlintrc =
-            serde_json::from_str(r#"{ "plugins": ["typescript", "unicorn", "react", "oxc", "import", "jsdoc", "jest", "vitest", "jsx-a11y", "nextjs", "react-perf", "promise", "node", "regex", "vue"] }"#).unwrap();
+            serde_json::from_str(r#"{ "plugins": ["typescript", "unicorn", "react", "oxc", "import", "jsdoc", "jest", "vitest", "jsx-a11y", "nextjs", "react-perf", "promise", "node", "regex", "vue", "json"] }"#).unwrap();
         assert_eq!(config.plugins, Some(LintPlugins::all()));
 
         let config: Oxlintrc =
@@ -410,4 +793,68 @@ mod test {
         let config: Oxlintrc = serde_json::from_str(r#"{"extends": []}"#).unwrap();
         assert_eq!(0, config.extends.len());
     }
+
+    #[test]
+    fn test_oxlintrc_merge_env_and_globals() {
+        let base: Oxlintrc = serde_json::from_value(json!({
+            "env": { "browser": true, "node": true },
+            "globals": { "Foo": "readonly", "Bar": "readonly" }
+        }))
+        .unwrap();
+        let config: Oxlintrc = serde_json::from_value(json!({
+            "env": { "node": false, "jest": true },
+            "globals": { "Bar": "off", "Baz": "writable" }
+        }))
+        .unwrap();
+
+        let merged = config.merge(&base);
+        assert!(merged.env.contains("browser"));
+        assert!(!merged.env.contains("node"));
+        assert!(merged.env.contains("jest"));
+        assert!(merged.globals.is_enabled("Foo"));
+        assert!(!merged.globals.is_enabled("Bar"));
+        assert!(merged.globals.is_enabled("Baz"));
+    }
+
+    #[test]
+    fn test_oxlintrc_merge_vendor_paths() {
+        let config_in = |dir: &str, vendor_paths: &[&str]| {
+            let mut config: Oxlintrc =
+                serde_json::from_value(json!({ "vendorPaths": vendor_paths })).unwrap();
+            config.path = PathBuf::from(dir).join(".oxlintrc.json");
+            config
+        };
+        let config = config_in("/project", &["vendor/**"]);
+
+        // Extended configs in the same directory match the same files.
+        let merged = config.merge(&config_in("/project", &["third_party/**"]));
+        assert_eq!(merged.vendor_paths, ["third_party/**", "vendor/**"]);
+
+        // Globs of extended configs in subdirectories only match files in those directories.
+        let merged = config.merge(&config_in("/project/packages/a", &["lib/**", "generated"]));
+        assert_eq!(
+            merged.vendor_paths,
+            ["packages/a/lib/**", "packages/a/**/generated", "vendor/**"]
+        );
+
+        // Globs of extended configs in parent directories are resolved from those directories.
+        let config = config_in("/project/packages/a", &[]);
+        let merged = config
+            .merge(&config_in("/project", &["packages/a/lib/**", "**/generated/**", "other/**"]));
+        assert_eq!(merged.vendor_paths, ["lib/**", "**/generated/**", "/project/other/**"]);
+    }
+
+    #[test]
+    fn test_oxlintrc_merge_paths() {
+        let base: Oxlintrc = serde_json::from_value(json!({
+            "paths": { "app": ["src/**"], "scripts": ["tools/**"] }
+        }))
+        .unwrap();
+        let config: Oxlintrc =
+            serde_json::from_value(json!({ "paths": { "app": ["app/**", "lib/**"] } })).unwrap();
+
+        let merged = config.merge(&base);
+        assert_eq!(merged.paths["app"], ["app/**", "lib/**"]);
+        assert_eq!(merged.paths["scripts"], ["tools/**"]);
+    }
 }
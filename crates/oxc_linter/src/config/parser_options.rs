@@ -0,0 +1,174 @@
+use oxc_span::SourceType;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Options which change how files are parsed.
+///
+/// By default, the source type of a file is derived from its extension, and JSX is enabled in all
+/// JavaScript files.
+///
+/// Example
+///
+/// `.oxlintrc.json`
+///
+/// ```json
+/// {
+///   "$schema": "./node_modules/oxlint/configuration_schema.json",
+///   "parserOptions": {
+///     "sourceType": "script",
+///     "ecmaFeatures": { "jsx": false }
+///   }
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct OxlintParserOptions {
+    /// Parse JavaScript and TypeScript files as scripts or as ES modules, regardless of their
+    /// extension.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<ParserSourceType>,
+
+    /// Additional language features.
+    #[serde(skip_serializing_if = "OxlintEcmaFeatures::is_empty")]
+    pub ecma_features: OxlintEcmaFeatures,
+}
+
+/// How the code of a file is parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ParserSourceType {
+    /// ES module: `import` and `export` are allowed, and the code is in strict mode.
+    Module,
+    /// Script, e.g. a file loaded with a `<script>` tag.
+    Script,
+    /// CommonJS module. Parsed as a script, with `return` allowed at the top level.
+    Commonjs,
+}
+
+/// Additional language features.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(default)]
+pub struct OxlintEcmaFeatures {
+    /// Parse JSX in JavaScript files. Defaults to `true`.
+    ///
+    /// TypeScript files are always parsed with JSX if they have the `.tsx` extension, and without
+    /// JSX otherwise, as the syntax of type assertions would be ambiguous.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jsx: Option<bool>,
+}
+
+impl OxlintEcmaFeatures {
+    #[expect(clippy::trivially_copy_pass_by_ref)] // Signature required by `skip_serializing_if`
+    pub fn is_empty(&self) -> bool {
+        self.jsx.is_none()
+    }
+}
+
+impl OxlintParserOptions {
+    #[expect(clippy::trivially_copy_pass_by_ref)] // Signature required by `skip_serializing_if`
+    pub fn is_empty(&self) -> bool {
+        self.source_type.is_none() && self.ecma_features.is_empty()
+    }
+
+    /// Merges two sets of parser options. Options set in `self` take priority over `other`.
+    #[must_use]
+    pub(crate) fn merge(self, other: Self) -> Self {
+        Self {
+            source_type: self.source_type.or(other.source_type),
+            ecma_features: OxlintEcmaFeatures {
+                jsx: self.ecma_features.jsx.or(other.ecma_features.jsx),
+            },
+        }
+    }
+
+    /// Apply these options to `source_type`, the source type derived from the extension of a file.
+    pub(crate) fn apply(self, mut source_type: SourceType) -> SourceType {
+        if source_type.is_typescript_definition() {
+            return source_type;
+        }
+        if let Some(parser_source_type) = self.source_type {
+            source_type = match parser_source_type {
+                ParserSourceType::Module => source_type.with_module(true),
+                ParserSourceType::Script | ParserSourceType::Commonjs => {
+                    source_type.with_script(true)
+                }
+            };
+        }
+        if source_type.is_javascript() {
+            source_type = source_type.with_jsx(self.ecma_features.jsx.unwrap_or(true));
+        }
+        source_type
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::SourceType;
+    use serde::Deserialize;
+
+    use super::{OxlintEcmaFeatures, OxlintParserOptions, ParserSourceType};
+
+    #[test]
+    fn test_parse_parser_options() {
+        let options = OxlintParserOptions::deserialize(&serde_json::json!({
+            "sourceType": "commonjs",
+            "ecmaFeatures": { "jsx": false }
+        }))
+        .unwrap();
+        assert_eq!(options.source_type, Some(ParserSourceType::Commonjs));
+        assert_eq!(options.ecma_features.jsx, Some(false));
+
+        let options = OxlintParserOptions::deserialize(&serde_json::json!({})).unwrap();
+        assert!(options.is_empty());
+
+        assert!(
+            OxlintParserOptions::deserialize(&serde_json::json!({ "sourceType": "esm" })).is_err()
+        );
+    }
+
+    #[test]
+    fn test_merge_parser_options() {
+        let base = OxlintParserOptions {
+            source_type: Some(ParserSourceType::Module),
+            ecma_features: OxlintEcmaFeatures { jsx: Some(false) },
+        };
+        let set = OxlintParserOptions {
+            source_type: Some(ParserSourceType::Script),
+            ..OxlintParserOptions::default()
+        };
+
+        assert_eq!(OxlintParserOptions::default().merge(base), base);
+        let merged = set.merge(base);
+        assert_eq!(merged.source_type, Some(ParserSourceType::Script));
+        assert_eq!(merged.ecma_features.jsx, Some(false));
+    }
+
+    #[test]
+    fn test_apply_parser_options() {
+        let js = SourceType::from_path("a.js").unwrap();
+        let mjs = SourceType::from_path("a.mjs").unwrap();
+        let ts = SourceType::from_path("a.ts").unwrap();
+        let tsx = SourceType::from_path("a.tsx").unwrap();
+        let dts = SourceType::from_path("a.d.ts").unwrap();
+
+        let default = OxlintParserOptions::default();
+        assert!(default.apply(js).is_jsx());
+        assert!(default.apply(mjs).is_module());
+        assert!(!default.apply(ts).is_jsx());
+
+        let script = OxlintParserOptions {
+            source_type: Some(ParserSourceType::Script),
+            ecma_features: OxlintEcmaFeatures { jsx: Some(false) },
+        };
+        assert!(script.apply(mjs).is_script());
+        assert!(!script.apply(js).is_jsx());
+        assert!(script.apply(tsx).is_jsx());
+        assert!(script.apply(dts).is_module());
+
+        let module = OxlintParserOptions {
+            source_type: Some(ParserSourceType::Module),
+            ..OxlintParserOptions::default()
+        };
+        assert!(module.apply(SourceType::from_path("a.cjs").unwrap()).is_module());
+    }
+}
@@ -0,0 +1,78 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Limits on the number of diagnostics reported in a single file.
+///
+/// Files exceeding a limit get a single error summarizing their diagnostics instead, which keeps
+/// the output usable when a generated or bundled file is linted by mistake.
+///
+/// Example
+///
+/// `.oxlintrc.json`
+///
+/// ```json
+/// {
+///   "$schema": "./node_modules/oxlint/configuration_schema.json",
+///   "perFileThresholds": {
+///     "max-warnings-per-file": 50
+///   }
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(default)]
+pub struct OxlintPerFileThresholds {
+    /// The maximum number of warnings reported in a file. The warnings of files with more
+    /// warnings, including their fixes, are replaced by a single error.
+    ///
+    /// Errors are always reported. Defaults to no limit.
+    #[serde(rename = "max-warnings-per-file", skip_serializing_if = "Option::is_none")]
+    pub max_warnings_per_file: Option<usize>,
+}
+
+impl OxlintPerFileThresholds {
+    pub fn is_empty(&self) -> bool {
+        self.max_warnings_per_file.is_none()
+    }
+
+    /// Merges two sets of thresholds. Thresholds set in `self` take priority over `other`.
+    #[must_use]
+    pub(crate) fn merge(self, other: Self) -> Self {
+        Self { max_warnings_per_file: self.max_warnings_per_file.or(other.max_warnings_per_file) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+
+    use super::OxlintPerFileThresholds;
+
+    #[test]
+    fn test_parse_per_file_thresholds() {
+        let thresholds = OxlintPerFileThresholds::deserialize(&serde_json::json!({
+            "max-warnings-per-file": 50
+        }))
+        .unwrap();
+        assert_eq!(thresholds.max_warnings_per_file, Some(50));
+
+        let thresholds = OxlintPerFileThresholds::deserialize(&serde_json::json!({})).unwrap();
+        assert!(thresholds.is_empty());
+
+        assert!(
+            OxlintPerFileThresholds::deserialize(&serde_json::json!({
+                "max-warnings-per-file": -1
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_merge_per_file_thresholds() {
+        let base = OxlintPerFileThresholds { max_warnings_per_file: Some(50) };
+        let unset = OxlintPerFileThresholds::default();
+        let set = OxlintPerFileThresholds { max_warnings_per_file: Some(10) };
+
+        assert_eq!(unset.merge(base).max_warnings_per_file, Some(50));
+        assert_eq!(set.merge(base).max_warnings_per_file, Some(10));
+    }
+}
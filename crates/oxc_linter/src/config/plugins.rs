@@ -4,6 +4,8 @@ use bitflags::bitflags;
 use schemars::{JsonSchema, r#gen::SchemaGenerator, schema::Schema};
 use serde::{Deserialize, Serialize, de::Deserializer, ser::Serializer};
 
+use super::validation;
+
 /// Normalizes plugin names by stripping common ESLint plugin prefixes and suffixes.
 ///
 /// This handles the various naming conventions used in the ESLint ecosystem:
@@ -82,6 +84,8 @@ bitflags! {
         const REGEX = 1 << 13;
         /// `eslint-plugin-vue`
         const VUE = 1 << 14;
+        /// Rules for JSON and JSONC files, e.g. `package.json` and `tsconfig.json`
+        const JSON = 1 << 15;
     }
 }
 
@@ -117,6 +121,19 @@ impl LintPlugins {
     pub fn has_import(self) -> bool {
         self.contains(LintPlugins::IMPORT)
     }
+
+    /// Plugins for projects depending on the npm package `name`, enabled in the configuration
+    /// created by `oxlint --init`.
+    pub fn for_dependency(name: &str) -> Self {
+        match name {
+            "react" | "react-dom" | "preact" => LintPlugins::REACT,
+            "next" => LintPlugins::REACT | LintPlugins::NEXTJS,
+            "jest" => LintPlugins::JEST,
+            "vitest" => LintPlugins::VITEST,
+            "vue" | "nuxt" => LintPlugins::VUE,
+            _ => LintPlugins::empty(),
+        }
+    }
 }
 
 impl TryFrom<&str> for LintPlugins {
@@ -147,6 +164,7 @@ impl TryFrom<&str> for LintPlugins {
             "node" => Ok(LintPlugins::NODE),
             "regex" => Ok(LintPlugins::REGEX),
             "vue" => Ok(LintPlugins::VUE),
+            "json" | "jsonc" => Ok(LintPlugins::JSON),
             // "eslint" is not really a plugin, so it's 'empty'. This has the added benefit of
             // making it the default value.
             "eslint" => Ok(LintPlugins::ESLINT),
@@ -173,6 +191,7 @@ impl From<LintPlugins> for &'static str {
             LintPlugins::NODE => "node",
             LintPlugins::REGEX => "regex",
             LintPlugins::VUE => "vue",
+            LintPlugins::JSON => "json",
             _ => "",
         }
     }
@@ -188,7 +207,13 @@ impl<'de> Deserialize<'de> for LintPlugins {
             if let Ok(plugin_flag) = LintPlugins::try_from(plugin.as_str()) {
                 lint_plugins |= plugin_flag;
             } else {
-                return Err(serde::de::Error::custom(format!("Unknown plugin: '{plugin}'.")));
+                let message = match validation::did_you_mean(plugin, validation::plugin_names()) {
+                    Some(suggestion) => {
+                        format!("Unknown plugin: '{plugin}'. Did you mean '{suggestion}'?")
+                    }
+                    None => format!("Unknown plugin: '{plugin}'."),
+                };
+                return Err(serde::de::Error::custom(message));
             }
         }
 
@@ -245,6 +270,7 @@ impl JsonSchema for LintPlugins {
             Node,
             Regex,
             Vue,
+            Json,
         }
 
         let enum_schema = r#gen.subschema_for::<LintPluginOptionsSchema>();
@@ -286,6 +312,7 @@ mod tests {
         assert_eq!(LintPlugins::try_from("react"), Ok(LintPlugins::REACT));
         assert_eq!(LintPlugins::try_from("typescript-eslint"), Ok(LintPlugins::TYPESCRIPT));
         assert_eq!(LintPlugins::try_from("deepscan"), Ok(LintPlugins::OXC));
+        assert_eq!(LintPlugins::try_from("jsonc"), Ok(LintPlugins::JSON));
         assert_eq!(LintPlugins::try_from("unknown"), Err(()));
     }
 
@@ -305,6 +332,15 @@ mod tests {
         assert!(plugins.has_import());
     }
 
+    #[test]
+    fn test_for_dependency() {
+        assert_eq!(LintPlugins::for_dependency("react"), LintPlugins::REACT);
+        assert_eq!(LintPlugins::for_dependency("next"), LintPlugins::REACT | LintPlugins::NEXTJS);
+        assert_eq!(LintPlugins::for_dependency("vitest"), LintPlugins::VITEST);
+        assert_eq!(LintPlugins::for_dependency("vue"), LintPlugins::VUE);
+        assert!(LintPlugins::for_dependency("lodash").is_empty());
+    }
+
     #[test]
     fn test_serialize_lint_plugins() {
         let plugins = LintPlugins::OXC | LintPlugins::REACT;
@@ -330,6 +366,10 @@ mod tests {
         assert!(result.is_err());
         let error = result.unwrap_err().to_string();
         assert_eq!(error, "Unknown plugin: 'not-a-real-plugin'.");
+
+        let result = serde_json::from_str::<LintPlugins>(r#"["unicon"]"#);
+        let error = result.unwrap_err().to_string();
+        assert_eq!(error, "Unknown plugin: 'unicon'. Did you mean 'unicorn'?");
     }
 
     #[test]
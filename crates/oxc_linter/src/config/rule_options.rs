@@ -0,0 +1,124 @@
+use std::{
+    any::Any,
+    sync::{Arc, LazyLock, OnceLock},
+};
+
+use rustc_hash::FxHashMap;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{rule::DefaultRuleConfig, rules::RULES};
+
+/// The options a rule is configured with, e.g. `[{ "allow": ["beforeAll"] }]` for
+/// `"jest/no-hooks": ["error", { "allow": ["beforeAll"] }]`.
+///
+/// Options are deserialized the first time a rule reads them with [`RuleOptions::get`], and
+/// shared by every file linted with the same configuration.
+#[derive(Debug, Default)]
+pub struct RuleOptions {
+    value: Value,
+    /// Deserialized options. A rule always reads its options as the same type.
+    typed: OnceLock<Box<dyn Any + Send + Sync>>,
+}
+
+impl RuleOptions {
+    pub fn new(value: Value) -> Self {
+        Self { value, typed: OnceLock::new() }
+    }
+
+    /// The options of the rule with id `rule_id` when it is configured without options, shared by
+    /// every configuration, so its default options are only created once.
+    pub fn none(rule_id: usize) -> &'static Self {
+        static NONE: LazyLock<Box<[RuleOptions]>> =
+            LazyLock::new(|| RULES.iter().map(|_| RuleOptions::default()).collect());
+        &NONE[rule_id]
+    }
+
+    /// The options as written in the configuration file, without the severity.
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    /// The first option deserialized as `T`, like [`DefaultRuleConfig`] does in
+    /// `from_configuration`. `T::default()` is returned if there are no options or they do not
+    /// deserialize, which is reported as an invalid option of the rule in the configuration file,
+    /// see [`RuleOptions::check`].
+    ///
+    /// # Panics
+    ///
+    /// If the options were already read as another type.
+    pub fn get<T>(&self) -> &T
+    where
+        T: DeserializeOwned + Default + Send + Sync + 'static,
+    {
+        self.typed
+            .get_or_init(|| {
+                Box::new(
+                    serde_json::from_value::<DefaultRuleConfig<T>>(self.value.clone())
+                        .unwrap_or_default()
+                        .into_inner(),
+                )
+            })
+            .downcast_ref()
+            .expect("a rule reads its options as a single type")
+    }
+}
+
+impl RuleOptions {
+    /// Check that `options`, the options following the severity in a configuration file,
+    /// deserialize as `T`, or describe why they do not.
+    ///
+    /// # Errors
+    ///
+    /// The error of deserializing the first option as `T`.
+    #[cfg(any(test, feature = "validate_config"))]
+    pub fn check<T: DeserializeOwned>(options: &[Value]) -> Result<(), String> {
+        match options.first() {
+            Some(option) => T::deserialize(option).map(drop).map_err(|err| format!("{err}.")),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The options of configured rules, by rule id. Rules which are not in the map have no options.
+pub type RuleOptionsMap = FxHashMap<usize, Arc<RuleOptions>>;
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::RuleOptions;
+
+    #[derive(Debug, Default, Deserialize, PartialEq)]
+    #[serde(default)]
+    struct Options {
+        max: usize,
+    }
+
+    #[test]
+    fn test_get() {
+        let options = RuleOptions::new(json!([{ "max": 3 }]));
+        let first = options.get::<Options>();
+        assert_eq!(*first, Options { max: 3 });
+        // Options are only deserialized once.
+        assert!(std::ptr::eq(first, options.get::<Options>()));
+    }
+
+    #[test]
+    fn test_get_default() {
+        assert_eq!(*RuleOptions::default().get::<Options>(), Options::default());
+        assert_eq!(*RuleOptions::new(json!([])).get::<Options>(), Options::default());
+        assert_eq!(*RuleOptions::new(json!([{ "max": "3" }])).get::<Options>(), Options::default());
+    }
+
+    #[test]
+    fn test_check() {
+        assert!(RuleOptions::check::<Options>(&[]).is_ok());
+        assert!(RuleOptions::check::<Options>(&[json!({ "max": 3 })]).is_ok());
+        assert_eq!(
+            RuleOptions::check::<Options>(&[json!({ "max": "3" })]),
+            Err(r#"invalid type: string "3", expected usize."#.to_string())
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use std::{borrow::Cow, fmt};
+use std::{borrow::Cow, fmt, sync::Arc};
 
 use itertools::Itertools;
 use rustc_hash::FxHashMap;
@@ -12,7 +12,9 @@ use serde::{
 use oxc_diagnostics::{Error, OxcDiagnostic};
 
 use crate::{
-    AllowWarnDeny, ExternalPluginStore, LintPlugins,
+    AllowWarnDeny, ExternalPluginStore, LintFilterKind, LintPlugins,
+    config::{RuleOptions, RuleOptionsMap},
+    context::plugin_name_to_prefix,
     external_plugin_store::{ExternalRuleId, ExternalRuleLookupError},
     rules::{RULES, RuleEnum},
     utils::{is_eslint_rule_adapted_to_typescript, is_jest_rule_adapted_to_vitest},
@@ -60,10 +62,17 @@ pub struct ESLintRule {
 }
 
 impl OxlintRules {
+    /// Apply these rules to `rules_for_override`.
+    ///
+    /// A rule which only sets a severity keeps the options it has in `rules_for_override`, or
+    /// else in `configured_rules`, e.g. the rules of the base config for an override. The options
+    /// of the other rules are recorded in `rule_options`.
     pub(crate) fn override_rules(
         &self,
         rules_for_override: &mut RuleSet,
+        rule_options: &mut RuleOptionsMap,
         external_rules_for_override: &mut FxHashMap<ExternalRuleId, AllowWarnDeny>,
+        configured_rules: &RuleSet,
         all_rules: &[RuleEnum],
         external_plugin_store: &ExternalPluginStore,
     ) -> Result<(), ExternalRuleLookupError> {
@@ -83,17 +92,37 @@ impl OxlintRules {
                     &rule_config.rule_name,
                     &rule_config.plugin_name,
                 );
-                let config = rule_config.config.clone().unwrap_or_default();
                 let severity = rule_config.severity;
 
                 if LintPlugins::try_from(plugin_name).is_ok() {
-                    let rule = rules_map.get(&plugin_name).copied().or_else(|| {
-                        all_rules
-                            .iter()
-                            .find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
-                    });
+                    let is_rule =
+                        |r: &&RuleEnum| r.name() == rule_name && r.plugin_name() == plugin_name;
+                    let configured_rule = rules_map
+                        .get(&plugin_name)
+                        .copied()
+                        .or_else(|| configured_rules.keys().find(is_rule));
+                    let rule = match (&rule_config.config, configured_rule) {
+                        // Like ESLint, only changing the severity, e.g. `"warn"` or `[1]`, keeps
+                        // the options the rule is already configured with.
+                        (None, Some(rule)) => Some(rule.clone()),
+                        (config, _) => rules_map
+                            .get(&plugin_name)
+                            .copied()
+                            .or_else(|| all_rules.iter().find(is_rule))
+                            .map(|rule| {
+                                if let Some(config) = config {
+                                    rule_options.insert(
+                                        rule.id(),
+                                        Arc::new(RuleOptions::new(config.clone())),
+                                    );
+                                } else {
+                                    rule_options.remove(&rule.id());
+                                }
+                                rule.read_json(config.clone().unwrap_or_default())
+                            }),
+                    };
                     if let Some(rule) = rule {
-                        rules_to_replace.push((rule.read_json(config), severity));
+                        rules_to_replace.push((rule, severity));
                     }
                 } else {
                     // If JS plugins are disabled (language server), assume plugin name refers to a JS plugin,
@@ -124,7 +153,50 @@ impl OxlintRules {
     }
 }
 
-fn transform_rule_and_plugin_name<'a>(
+/// The built-in rule which reports diagnostics with `code`, e.g. `eslint(no-debugger)`.
+pub fn rule_of_diagnostic_code(code: &str) -> Option<&'static RuleEnum> {
+    let (scope, rest) = code.split_once('(')?;
+    let name = rest.strip_suffix(')')?;
+    RULES
+        .iter()
+        .find(|rule| rule.name() == name && plugin_name_to_prefix(rule.plugin_name()) == scope)
+}
+
+/// Diagnostic codes (e.g. `eslint(no-debugger)`) of the rules matched by `filter`.
+///
+/// Categories match rules by their default category. A `<plugin>/<rule>` filter which does not
+/// match a built-in rule, e.g. a rule of a JS plugin, is returned as `plugin(rule)`.
+pub fn diagnostic_codes(filter: &LintFilterKind) -> Vec<String> {
+    let code =
+        |rule: &RuleEnum| format!("{}({})", plugin_name_to_prefix(rule.plugin_name()), rule.name());
+    match filter {
+        LintFilterKind::All => RULES.iter().map(code).collect(),
+        LintFilterKind::Category(category) => {
+            RULES.iter().filter(|r| r.category() == *category).map(code).collect()
+        }
+        LintFilterKind::Generic(name) => {
+            RULES.iter().filter(|r| r.name() == name).map(code).collect()
+        }
+        LintFilterKind::Plugin(plugin) => {
+            RULES.iter().filter(|r| is_rule_of_plugin(r, *plugin)).map(code).collect()
+        }
+        LintFilterKind::Rule(plugin, rule) => {
+            let (plugin, rule) = unalias_plugin_name(plugin, rule);
+            let (rule_name, plugin_name) = transform_rule_and_plugin_name(&rule, &plugin);
+            match RULES.iter().find(|r| r.name() == rule_name && r.plugin_name() == plugin_name) {
+                Some(rule) => vec![code(rule)],
+                None => vec![format!("{plugin}({rule})")],
+            }
+        }
+    }
+}
+
+/// Whether `rule` belongs to `plugin`, matched for `-A`/`-D` plugin filters.
+pub(super) fn is_rule_of_plugin(rule: &RuleEnum, plugin: LintPlugins) -> bool {
+    LintPlugins::try_from(rule.plugin_name()) == Ok(plugin)
+}
+
+pub(super) fn transform_rule_and_plugin_name<'a>(
     rule_name: &'a str,
     plugin_name: &'a str,
 ) -> (&'a str, &'a str) {
@@ -175,6 +247,11 @@ impl Serialize for OxlintRules {
             let key = rule.full_name();
             match rule.config.as_ref() {
                 // e.g. unicorn/some-rule: ["warn", { foo: "bar" }]
+                Some(serde_json::Value::Array(config)) => {
+                    let severity = serde_json::Value::from(rule.severity.as_str());
+                    let value = std::iter::once(&severity).chain(config).collect::<Vec<_>>();
+                    rules.serialize_entry(&key, &value)?;
+                }
                 Some(config) if !config.is_null() => {
                     let value = (rule.severity.as_str(), config);
                     rules.serialize_entry(&key, &value)?;
@@ -227,7 +304,7 @@ impl<'de> Deserialize<'de> for OxlintRules {
     }
 }
 
-fn parse_rule_key(name: &str) -> (String, String) {
+pub(super) fn parse_rule_key(name: &str) -> (String, String) {
     let Some((plugin_name, rule_name)) = name.split_once('/') else {
         return (
             RULES
@@ -330,11 +407,11 @@ mod test {
     use serde_json::{Value, json};
 
     use crate::{
-        AllowWarnDeny, ExternalPluginStore,
+        AllowWarnDeny, ExternalPluginStore, LintFilterKind,
         rules::{RULES, RuleEnum},
     };
 
-    use super::{OxlintRules, RuleSet};
+    use super::{OxlintRules, RuleSet, diagnostic_codes, rule_of_diagnostic_code};
 
     #[test]
     fn test_parse_rules() {
@@ -372,6 +449,32 @@ mod test {
         assert!(r4.config.is_none());
     }
 
+    #[test]
+    fn test_serialize_rules() {
+        let config = json!({
+            "no-console": "allow",
+            "eqeqeq": ["deny", "always", { "null": "ignore" }],
+        });
+        let rules = OxlintRules::deserialize(&config).unwrap();
+        assert_eq!(serde_json::to_value(&rules).unwrap(), config);
+
+        // numeric and aliased severities are written back with their canonical names
+        let rules = OxlintRules::deserialize(&json!({
+            "no-console": 0,
+            "no-debugger": ["Warn"],
+            "eqeqeq": [2, "always"],
+        }))
+        .unwrap();
+        assert_eq!(
+            serde_json::to_value(&rules).unwrap(),
+            json!({
+                "no-console": "allow",
+                "no-debugger": "warn",
+                "eqeqeq": ["deny", "always"],
+            })
+        );
+    }
+
     #[test]
     fn test_parse_rules_default() {
         let rules = OxlintRules::default();
@@ -383,7 +486,14 @@ mod test {
         let mut external_rules_for_override = FxHashMap::default();
         let external_linter_store = ExternalPluginStore::default();
         rules_config
-            .override_rules(rules, &mut external_rules_for_override, &RULES, &external_linter_store)
+            .override_rules(
+                rules,
+                &mut FxHashMap::default(),
+                &mut external_rules_for_override,
+                &RuleSet::default(),
+                &RULES,
+                &external_linter_store,
+            )
             .unwrap();
     }
 
@@ -517,4 +627,33 @@ mod test {
         assert_eq!(r2.plugin_name, "unicorn");
         assert!(r2.severity.is_warn_deny());
     }
+
+    #[test]
+    fn test_diagnostic_codes() {
+        let codes =
+            |filter: &'static str| diagnostic_codes(&LintFilterKind::try_from(filter).unwrap());
+
+        assert_eq!(codes("no-debugger"), vec!["eslint(no-debugger)"]);
+        assert_eq!(codes("eslint/no-debugger"), vec!["eslint(no-debugger)"]);
+        assert_eq!(codes("jsx-a11y/alt-text"), vec!["eslint-plugin-jsx-a11y(alt-text)"]);
+        assert_eq!(
+            codes("@typescript-eslint/no-explicit-any"),
+            vec!["typescript-eslint(no-explicit-any)"]
+        );
+        // ESLint rules adapted to TypeScript are reported under the `eslint` scope.
+        assert_eq!(codes("typescript/no-unused-vars"), vec!["eslint(no-unused-vars)"]);
+        // Unknown rules, e.g. from JS plugins, keep the plugin name as written.
+        assert_eq!(codes("my-plugin/my-rule"), vec!["my-plugin(my-rule)"]);
+        assert!(codes("correctness").contains(&"eslint(no-debugger)".to_string()));
+    }
+
+    #[test]
+    fn test_rule_of_diagnostic_code() {
+        let rule = rule_of_diagnostic_code("eslint-plugin-jsx-a11y(alt-text)").unwrap();
+        assert_eq!((rule.plugin_name(), rule.name()), ("jsx_a11y", "alt-text"));
+        let rule = rule_of_diagnostic_code("typescript-eslint(no-explicit-any)").unwrap();
+        assert_eq!((rule.plugin_name(), rule.name()), ("typescript", "no-explicit-any"));
+        assert!(rule_of_diagnostic_code("my-plugin(my-rule)").is_none());
+        assert!(rule_of_diagnostic_code("eslint").is_none());
+    }
 }
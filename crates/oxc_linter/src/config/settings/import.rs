@@ -0,0 +1,76 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Configure how the import plugin resolves import specifiers to modules.
+///
+/// Matches the options of `eslint-import-resolver-node` and `eslint-import-resolver-typescript`,
+/// which may also be nested in an entry per resolver:
+///
+/// ```json
+/// {
+///   "settings": {
+///     "import/resolver": {
+///       "typescript": {
+///         "conditionNames": ["development", "import", "module"],
+///         "mainFields": ["browser", "module", "main"]
+///       }
+///     }
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Default, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportResolverSettings {
+    /// Conditions matched against the `exports` and `imports` fields of `package.json`, in
+    /// addition to `default`.
+    ///
+    /// Default: `["module", "import"]`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition_names: Option<Vec<String>>,
+
+    /// Fields of `package.json` read, in order, to find the entry point of packages without an
+    /// `exports` field.
+    ///
+    /// Default: `["module", "main"]`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub main_fields: Option<Vec<String>>,
+}
+
+impl ImportResolverSettings {
+    pub fn is_empty(&self) -> bool {
+        self.condition_names.is_none() && self.main_fields.is_none()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ImportResolverSettingsJson {
+    #[serde(default, alias = "conditions")]
+    condition_names: Option<Vec<String>>,
+    #[serde(default)]
+    main_fields: Option<Vec<String>>,
+}
+
+impl<'de> Deserialize<'de> for ImportResolverSettings {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // ESLint configurations may name resolvers, e.g. `"import/resolver": "typescript"`,
+        // or configure several of them. Options which are not understood are ignored.
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let serde_json::Value::Object(map) = &value else {
+            return Ok(Self::default());
+        };
+        let read = |value: &serde_json::Value| {
+            serde_json::from_value::<ImportResolverSettingsJson>(value.clone()).ok().map(|json| {
+                Self { condition_names: json.condition_names, main_fields: json.main_fields }
+            })
+        };
+        Ok(std::iter::once(&value)
+            .chain(map.values().filter(|value| value.is_object()))
+            .filter_map(read)
+            .find(|settings| !settings.is_empty())
+            .unwrap_or_default())
+    }
+}
@@ -45,8 +45,9 @@ pub struct JSXA11yPluginSettings {
     #[serde(default)]
     pub components: FxHashMap<CompactStr, CompactStr>,
 
-    /// Map of attribute names to their DOM equivalents.
-    /// This is useful for non-React frameworks that use different attribute names.
+    /// Map of DOM attribute names to the props which set them, for components
+    /// or frameworks that use different prop names.
+    /// When an attribute is mapped, rules only look for the listed props.
     ///
     /// Example:
     ///
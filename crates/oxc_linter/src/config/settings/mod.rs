@@ -1,3 +1,4 @@
+mod import;
 pub mod jsdoc;
 mod jsx_a11y;
 mod next;
@@ -8,8 +9,8 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use self::{
-    jsdoc::JSDocPluginSettings, jsx_a11y::JSXA11yPluginSettings, next::NextPluginSettings,
-    react::ReactPluginSettings, vitest::VitestPluginSettings,
+    import::ImportResolverSettings, jsdoc::JSDocPluginSettings, jsx_a11y::JSXA11yPluginSettings,
+    next::NextPluginSettings, react::ReactPluginSettings, vitest::VitestPluginSettings,
 };
 
 /// # Oxlint Plugin Settings
@@ -58,6 +59,11 @@ pub struct OxlintSettings {
 
     #[serde(default)]
     pub vitest: VitestPluginSettings,
+
+    #[serde(default)]
+    #[serde(rename = "import/resolver")]
+    #[serde(skip_serializing_if = "ImportResolverSettings::is_empty")]
+    pub import_resolver: ImportResolverSettings,
 }
 
 #[derive(Deserialize, Default)]
@@ -78,6 +84,10 @@ struct WellKnownOxlintSettings {
 
     #[serde(default)]
     pub vitest: VitestPluginSettings,
+
+    #[serde(default)]
+    #[serde(rename = "import/resolver")]
+    pub import_resolver: ImportResolverSettings,
 }
 
 pub type OxlintSettingsJson = serde_json::Map<String, serde_json::Value>;
@@ -102,6 +112,7 @@ impl<'de> Deserialize<'de> for OxlintSettings {
             react: well_known_settings.react,
             jsdoc: well_known_settings.jsdoc,
             vitest: well_known_settings.vitest,
+            import_resolver: well_known_settings.import_resolver,
         })
     }
 }
@@ -126,6 +137,7 @@ impl OxlintSettings {
                         settings_to_override.react = well_known_settings.react;
                         settings_to_override.jsdoc = well_known_settings.jsdoc;
                         settings_to_override.vitest = well_known_settings.vitest;
+                        settings_to_override.import_resolver = well_known_settings.import_resolver;
                     }
                     Err(e) => {
                         panic!("Failed to parse override settings: {e:?}");
@@ -138,6 +150,7 @@ impl OxlintSettings {
                 settings_to_override.react = self.react.clone();
                 settings_to_override.jsdoc = self.jsdoc.clone();
                 settings_to_override.vitest = self.vitest.clone();
+                settings_to_override.import_resolver = self.import_resolver.clone();
             }
         }
     }
@@ -292,4 +305,41 @@ mod test {
         assert_eq!(raw_json["unknown-plugin"]["setting"], "value");
         assert_eq!(raw_json["globalSetting"], "value");
     }
+
+    #[test]
+    fn test_parse_import_resolver() {
+        let settings = OxlintSettings::deserialize(&serde_json::json!({
+            "import/resolver": {
+                "conditionNames": ["development", "import"],
+                "mainFields": ["browser", "main"]
+            }
+        }))
+        .unwrap();
+        assert_eq!(
+            settings.import_resolver.condition_names,
+            Some(vec!["development".to_string(), "import".to_string()])
+        );
+        assert_eq!(
+            settings.import_resolver.main_fields,
+            Some(vec!["browser".to_string(), "main".to_string()])
+        );
+
+        // options nested in the entry of a resolver
+        let settings = OxlintSettings::deserialize(&serde_json::json!({
+            "import/resolver": {
+                "node": { "extensions": [".js"] },
+                "typescript": { "conditionNames": ["browser"] }
+            }
+        }))
+        .unwrap();
+        assert_eq!(settings.import_resolver.condition_names, Some(vec!["browser".to_string()]));
+        assert!(settings.import_resolver.main_fields.is_none());
+
+        // names of resolvers are ignored
+        let settings = OxlintSettings::deserialize(&serde_json::json!({
+            "import/resolver": "typescript"
+        }))
+        .unwrap();
+        assert!(settings.import_resolver.condition_names.is_none());
+    }
 }
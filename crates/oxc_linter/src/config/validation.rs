@@ -0,0 +1,199 @@
+use std::path::Path;
+
+use javascript_globals::GLOBALS;
+use schemars::{JsonSchema, r#gen::SchemaGenerator};
+use serde_json::Value;
+
+use oxc_diagnostics::OxcDiagnostic;
+
+use crate::{
+    LintPlugins,
+    rules::{RULES, RuleEnum},
+};
+
+use super::{
+    Oxlintrc,
+    overrides::OxlintOverride,
+    rules::{parse_rule_key, transform_rule_and_plugin_name},
+};
+
+/// Check the JSON of the configuration file at `path` for keys, rules and environments which
+/// do not exist, and are likely misspelled.
+///
+/// Unknown plugins and categories are reported when deserializing the configuration.
+pub fn unknown_config_names(json: &Value, path: &Path) -> Vec<OxcDiagnostic> {
+    let mut diagnostics = vec![];
+    let Some(config) = json.as_object() else {
+        return diagnostics;
+    };
+
+    let mut keys = schema_properties::<Oxlintrc>();
+    keys.push("$schema".to_string());
+    check_keys(config.keys(), &keys, path, &mut diagnostics);
+    check_rules(config.get("rules"), path, &mut diagnostics);
+    check_rules(config.get("categoriesOverride"), path, &mut diagnostics);
+    check_envs(config.get("env"), path, &mut diagnostics);
+
+    let override_keys = schema_properties::<OxlintOverride>();
+    let overrides = config.get("overrides").and_then(Value::as_array).into_iter().flatten();
+    for r#override in overrides.filter_map(Value::as_object) {
+        check_keys(r#override.keys(), &override_keys, path, &mut diagnostics);
+        check_rules(r#override.get("rules"), path, &mut diagnostics);
+        check_envs(r#override.get("env"), path, &mut diagnostics);
+    }
+
+    diagnostics
+}
+
+/// Find the candidate `name` is most likely a misspelling of.
+pub fn did_you_mean<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Names of the plugins which can be used in `plugins`.
+pub fn plugin_names() -> impl Iterator<Item = &'static str> {
+    std::iter::once("eslint").chain(LintPlugins::all().iter().map(<&'static str>::from))
+}
+
+fn unknown_name(kind: &str, name: &str, suggestion: Option<&str>, path: &Path) -> OxcDiagnostic {
+    let diagnostic = OxcDiagnostic::warn(format!("Unknown {kind} `{name}` in {}", path.display()));
+    match suggestion {
+        Some(suggestion) => diagnostic.with_help(format!("Did you mean `{suggestion}`?")),
+        None => diagnostic,
+    }
+}
+
+fn schema_properties<T: JsonSchema>() -> Vec<String> {
+    let schema = SchemaGenerator::default().into_root_schema_for::<T>();
+    schema.schema.object.map(|object| object.properties.into_keys().collect()).unwrap_or_default()
+}
+
+fn check_keys<'a>(
+    keys: impl Iterator<Item = &'a String>,
+    known_keys: &[String],
+    path: &Path,
+    diagnostics: &mut Vec<OxcDiagnostic>,
+) {
+    for key in keys.filter(|key| !known_keys.contains(key)) {
+        let suggestion = did_you_mean(key, known_keys.iter().map(String::as_str));
+        diagnostics.push(unknown_name("key", key, suggestion, path));
+    }
+}
+
+fn check_rules(rules: Option<&Value>, path: &Path, diagnostics: &mut Vec<OxcDiagnostic>) {
+    let Some(rules) = rules.and_then(Value::as_object) else {
+        return;
+    };
+    for key in rules.keys() {
+        let (plugin_name, rule_name) = parse_rule_key(key);
+        let (rule_name, plugin_name) = transform_rule_and_plugin_name(&rule_name, &plugin_name);
+
+        if LintPlugins::try_from(plugin_name).is_err() {
+            // Rules of unknown plugins may belong to JS plugins, only report likely misspellings
+            // of built-in plugins.
+            if let Some(plugin_name) = did_you_mean(plugin_name, plugin_names()) {
+                let suggestion = format!("{plugin_name}/{rule_name}");
+                diagnostics.push(unknown_name("rule", key, Some(&suggestion), path));
+            }
+            continue;
+        }
+
+        if RULES.iter().any(|rule| rule.plugin_name() == plugin_name && rule.name() == rule_name) {
+            continue;
+        }
+        let suggestion = did_you_mean(
+            rule_name,
+            RULES.iter().filter(|rule| rule.plugin_name() == plugin_name).map(RuleEnum::name),
+        )
+        .map(|suggestion| match key.rsplit_once('/') {
+            Some((prefix, _)) => format!("{prefix}/{suggestion}"),
+            None => suggestion.to_string(),
+        });
+        diagnostics.push(unknown_name("rule", key, suggestion.as_deref(), path));
+    }
+}
+
+fn check_envs(envs: Option<&Value>, path: &Path, diagnostics: &mut Vec<OxcDiagnostic>) {
+    let Some(envs) = envs.and_then(Value::as_object) else {
+        return;
+    };
+    for env in envs.keys().filter(|env| !GLOBALS.contains_key(env.as_str())) {
+        let suggestion = did_you_mean(env, GLOBALS.keys().copied());
+        diagnostics.push(unknown_name("environment", env, suggestion, path));
+    }
+}
+
+/// Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = vec![0; b.len() + 1];
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use super::{did_you_mean, unknown_config_names};
+
+    #[test]
+    fn test_did_you_mean() {
+        assert_eq!(did_you_mean("unicon", ["unicorn", "react"]), Some("unicorn"));
+        assert_eq!(did_you_mean("correctnes", ["correctness", "perf"]), Some("correctness"));
+        assert_eq!(did_you_mean("foo", ["unicorn", "react"]), None);
+    }
+
+    #[test]
+    fn test_unknown_config_names() {
+        let config = json!({
+            "$schema": "./node_modules/oxlint/configuration_schema.json",
+            "rule": {},
+            "rules": {
+                "no-debugger": "error",
+                "no-debuger": "error",
+                "typescript/no-unused-vars": "error",
+                "unicon/filename-case": "error",
+                "my-plugin/my-rule": "error",
+            },
+            "env": { "brower": true, "node": true },
+            "overrides": [{ "files": ["*.ts"], "rulez": {}, "rules": { "eqeqe": "off" } }],
+        });
+        let messages = unknown_config_names(&config, Path::new(".oxlintrc.json"))
+            .into_iter()
+            .map(|diagnostic| {
+                format!("{} ({})", diagnostic.message, diagnostic.help.as_deref().unwrap_or(""))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            [
+                "Unknown key `rule` in .oxlintrc.json (Did you mean `rules`?)",
+                "Unknown rule `no-debuger` in .oxlintrc.json (Did you mean `no-debugger`?)",
+                "Unknown rule `unicon/filename-case` in .oxlintrc.json (Did you mean `unicorn/filename-case`?)",
+                "Unknown environment `brower` in .oxlintrc.json (Did you mean `browser`?)",
+                "Unknown key `rulez` in .oxlintrc.json (Did you mean `rules`?)",
+                "Unknown rule `eqeqe` in .oxlintrc.json (Did you mean `eqeqeq`?)",
+            ]
+        );
+    }
+}
@@ -1,25 +1,31 @@
 use std::{
     borrow::Cow,
-    cell::{Cell, RefCell},
+    cell::{Cell, OnceCell, RefCell},
     ffi::OsStr,
     path::Path,
     rc::Rc,
     sync::Arc,
 };
 
+use oxc_allocator::Allocator;
 use oxc_diagnostics::{OxcDiagnostic, Severity};
-use oxc_semantic::Semantic;
+use oxc_semantic::{NodeId, Semantic};
 use oxc_span::{SourceType, Span};
 
 use crate::{
     AllowWarnDeny, FrameworkFlags,
     config::{LintConfig, LintPlugins, OxlintSettings},
-    disable_directives::{DisableDirectives, DisableDirectivesBuilder, RuleCommentType},
+    disable_directives::{
+        DisableDirectives, DisableDirectivesBuilder, ExpiryDate, RuleCommentType, directive_name,
+    },
     fixer::{Fix, FixKind, Message, PossibleFixes},
-    frameworks::{self, FrameworkOptions},
+    frameworks::FrameworkOptions,
     module_record::ModuleRecord,
     options::LintOptions,
+    project_files::ProjectFiles,
     rules::RuleEnum,
+    type_facts::TypeFacts,
+    utils::{ParsedRegExp, PossibleJestNode, iter_possible_jest_call_node, parse_regexes},
 };
 
 use super::{LintContext, plugin_name_to_prefix};
@@ -38,6 +44,13 @@ pub struct ContextSubHost<'a> {
     pub(super) framework_options: FrameworkOptions,
     /// The source text offset of the sub host
     pub(super) source_text_offset: u32,
+    /// Type information computed by `tsgolint` for the file this script block belongs to.
+    pub(super) type_facts: Option<Arc<TypeFacts>>,
+    /// Regular expressions of the script block, parsed on first use.
+    regexes: OnceCell<Vec<ParsedRegExp<'a>>>,
+    /// Possible Jest and Vitest calls of the script block with the original name of their
+    /// import, found on first use.
+    jest_nodes: OnceCell<Vec<(NodeId, Option<&'a str>)>>,
 }
 
 impl<'a> ContextSubHost<'a> {
@@ -78,9 +91,20 @@ impl<'a> ContextSubHost<'a> {
             source_text_offset,
             disable_directives,
             framework_options: frameworks_options,
+            type_facts: None,
+            regexes: OnceCell::new(),
+            jest_nodes: OnceCell::new(),
         }
     }
 
+    /// Attach type information computed by `tsgolint` for the file being linted.
+    /// Spans of the facts are relative to the source text of the whole file.
+    #[must_use]
+    pub fn with_type_facts(mut self, type_facts: Option<Arc<TypeFacts>>) -> Self {
+        self.type_facts = type_facts;
+        self
+    }
+
     /// Shared reference to the [`Semantic`] analysis
     #[inline]
     pub fn semantic(&self) -> &Semantic<'a> {
@@ -102,6 +126,11 @@ impl<'a> ContextSubHost<'a> {
     pub fn framework_options(&self) -> FrameworkOptions {
         self.framework_options
     }
+
+    /// Shared reference to the [`TypeFacts`], if type information is available for this file.
+    pub fn type_facts(&self) -> Option<&TypeFacts> {
+        self.type_facts.as_deref()
+    }
 }
 
 /// Stores shared information about a file being linted.
@@ -149,6 +178,10 @@ pub struct ContextHost<'a> {
     pub(super) config: Arc<LintConfig>,
     /// Front-end frameworks that might be in use in the target file.
     pub(super) frameworks: FrameworkFlags,
+    /// Project files which rules can read, shared by all linted files.
+    pub(super) project_files: Arc<ProjectFiles>,
+    /// Allocator of the file being linted, used for the patterns of `RegExp` constructors.
+    allocator: &'a Allocator,
 }
 
 impl std::fmt::Debug for ContextHost<'_> {
@@ -165,6 +198,7 @@ impl<'a> ContextHost<'a> {
         sub_hosts: Vec<ContextSubHost<'a>>,
         options: LintOptions,
         config: Arc<LintConfig>,
+        allocator: &'a Allocator,
     ) -> Self {
         const DIAGNOSTICS_INITIAL_CAPACITY: usize = 512;
 
@@ -185,10 +219,18 @@ impl<'a> ContextHost<'a> {
             file_extension,
             config,
             frameworks: options.framework_hints,
+            project_files: Arc::default(),
+            allocator,
         }
         .sniff_for_frameworks()
     }
 
+    /// Share the cache of project files read by rules with other files.
+    pub fn with_project_files(mut self, project_files: Arc<ProjectFiles>) -> Self {
+        self.project_files = project_files;
+        self
+    }
+
     /// The current [`ContextSubHost`]
     pub fn current_sub_host(&self) -> &ContextSubHost<'a> {
         &self.sub_hosts[self.current_sub_host_index.get()]
@@ -199,6 +241,16 @@ impl<'a> ContextHost<'a> {
         &mut self.sub_hosts[self.current_sub_host_index.get()]
     }
 
+    /// Index of the current [`ContextSubHost`].
+    pub(crate) fn current_sub_host_index(&self) -> usize {
+        self.current_sub_host_index.get()
+    }
+
+    /// Source texts of all script blocks.
+    pub(crate) fn source_texts(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.sub_hosts.iter().map(|sub_host| sub_host.semantic.source_text())
+    }
+
     // Whether the current sub host is the first one.
     pub fn is_first_sub_host(&self) -> bool {
         self.current_sub_host_index.get() == 0
@@ -227,6 +279,37 @@ impl<'a> ContextHost<'a> {
         &self.current_sub_host().disable_directives
     }
 
+    /// Shared reference to the [`TypeFacts`] of the current script block, if any.
+    pub fn type_facts(&self) -> Option<&TypeFacts> {
+        self.current_sub_host().type_facts()
+    }
+
+    /// Regular expressions of the current script block. They are parsed on the first call and
+    /// shared by all rules.
+    pub fn regexes(&self) -> &[ParsedRegExp<'a>] {
+        let sub_host = self.current_sub_host();
+        sub_host.regexes.get_or_init(|| parse_regexes(&sub_host.semantic, self.allocator))
+    }
+
+    /// Possible Jest and Vitest calls of the current script block. They are found on the first
+    /// call and shared by all rules.
+    pub fn possible_jest_nodes(&self) -> impl Iterator<Item = PossibleJestNode<'a, '_>> {
+        let sub_host = self.current_sub_host();
+        let nodes = sub_host.semantic.nodes();
+        sub_host
+            .jest_nodes
+            .get_or_init(|| {
+                iter_possible_jest_call_node(&sub_host.semantic)
+                    .map(|jest_node| (jest_node.node.id(), jest_node.original))
+                    .collect()
+            })
+            .iter()
+            .map(|&(node_id, original)| PossibleJestNode {
+                node: nodes.get_node(node_id),
+                original,
+            })
+    }
+
     /// Path to the file being linted.
     ///
     /// When created from a [`LintService`](`crate::service::LintService`), this
@@ -263,19 +346,34 @@ impl<'a> ContextHost<'a> {
     /// by any rule to report issues.
     #[inline]
     pub(crate) fn push_diagnostic(&self, mut diagnostic: Message) {
-        if self.current_sub_host().source_text_offset != 0 {
-            diagnostic.move_offset(self.current_sub_host().source_text_offset);
+        let offset = self.current_sub_host().source_text_offset;
+        if offset != 0 {
+            diagnostic.move_offset(offset);
         }
+        // The fixer relies on the section offset to apply fixes to the right section.
+        diagnostic.section_offset = offset;
         self.diagnostics.borrow_mut().push(diagnostic);
     }
 
+    /// Add diagnostics reported by a rule when linting the same script block before, which
+    /// already have the offset of the script block.
+    pub(crate) fn extend_diagnostics(&self, diagnostics: Vec<Message>) {
+        self.diagnostics.borrow_mut().extend(diagnostics);
+    }
+
+    /// Diagnostics reported since there were `start` diagnostics.
+    pub(crate) fn diagnostics_since(&self, start: usize) -> Vec<Message> {
+        self.diagnostics.borrow()[start..].to_vec()
+    }
+
     // Append a list of diagnostics. Only used in report_unused_directives.
     fn append_diagnostics(&self, mut diagnostics: Vec<Message>) {
-        if self.current_sub_host().source_text_offset != 0 {
-            let offset = self.current_sub_host().source_text_offset;
-            for diagnostic in &mut diagnostics {
+        let offset = self.current_sub_host().source_text_offset;
+        for diagnostic in &mut diagnostics {
+            if offset != 0 {
                 diagnostic.move_offset(offset);
             }
+            diagnostic.section_offset = offset;
         }
         self.diagnostics.borrow_mut().extend(diagnostics);
     }
@@ -332,6 +430,14 @@ impl<'a> ContextHost<'a> {
             }
         }
 
+        // report blanket disables for the whole file which only suppress a few rules
+        for file_disable in self.disable_directives().collect_narrowable_file_disables() {
+            self.push_diagnostic(Message::new(
+                file_disable.diagnostic().with_severity(rule_severity),
+                PossibleFixes::Single(file_disable.create_fix()),
+            ));
+        }
+
         let unused_enable_comments = self.disable_directives().unused_enable_comments();
         let mut unused_directive_diagnostics: Vec<(Cow<str>, Span)> =
             Vec::with_capacity(unused_enable_comments.len());
@@ -365,6 +471,75 @@ impl<'a> ContextHost<'a> {
         );
     }
 
+    /// Report disable directives with an `expires:YYYY-MM-DD` date before `today` as errors,
+    /// so that temporary suppressions can not silently live forever.
+    pub fn report_expired_directives(&self, today: ExpiryDate) {
+        let source_text = self.semantic().source_text();
+        for expiry in self.disable_directives().collect_expired_comments(today) {
+            let directive = directive_name(expiry.comment_span.source_text(source_text));
+            let diagnostic = match expiry.expires {
+                Some(expires) => OxcDiagnostic::error(format!(
+                    "Expired {directive} directive (expired on {expires})."
+                ))
+                .with_help("Fix the reported problems and remove the directive, or extend its expiry date.")
+                .with_error_code("oxc", "expired-disable-directive"),
+                None => OxcDiagnostic::error(format!(
+                    "Invalid expiry date in {directive} directive, expected `expires:YYYY-MM-DD`."
+                ))
+                .with_error_code("oxc", "invalid-directive-expiry"),
+            };
+            self.push_diagnostic(Message::new(
+                diagnostic.with_label(expiry.span),
+                PossibleFixes::None,
+            ));
+        }
+    }
+
+    /// Report `eslint-disable` comments naming rules for which `is_rule_enabled` returns `false`.
+    /// Unlike [`ContextHost::report_unused_directives`], this does not depend on the lint result.
+    pub fn report_unused_inline_configs(
+        &self,
+        severity: Severity,
+        is_rule_enabled: impl Fn(&str) -> bool,
+    ) {
+        let unused_inline_configs =
+            self.disable_directives().collect_unused_inline_configs(is_rule_enabled);
+        let fix_message = "remove unused inline config";
+        let source_text = self.semantic().source_text();
+
+        for unused_inline_config in unused_inline_configs {
+            let span = unused_inline_config.span;
+            match &unused_inline_config.r#type {
+                RuleCommentType::All => {
+                    self.push_diagnostic(Message::new(
+                        OxcDiagnostic::error(
+                            "Unused inline config (none of the disabled rules are enabled).",
+                        )
+                        .with_label(span)
+                        .with_severity(severity),
+                        PossibleFixes::Single(Fix::delete(span).with_message(fix_message)),
+                    ));
+                }
+                RuleCommentType::Single(rules) => {
+                    for rule in rules {
+                        let message = format!(
+                            "Unused inline config ({} is not enabled, so disabling it has no effect).",
+                            rule.rule_name
+                        );
+                        let fix = rule.create_fix(source_text, span).with_message(fix_message);
+
+                        self.push_diagnostic(Message::new(
+                            OxcDiagnostic::error(message)
+                                .with_label(rule.name_span)
+                                .with_severity(severity),
+                            PossibleFixes::Single(fix),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
     /// Take ownership of all diagnostics collected during linting.
     pub fn take_diagnostics(&self) -> Vec<Message> {
         // NOTE: diagnostics are only ever borrowed here and in push_diagnostic, append_diagnostics.
@@ -388,12 +563,25 @@ impl<'a> ContextHost<'a> {
         self.sub_hosts.into_iter().next().map(|sub_host| sub_host.disable_directives)
     }
 
+    /// Take ownership of the semantic of the first sub host.
+    /// This consumes the `ContextHost`.
+    ///
+    /// # Panics
+    /// Panics if `sub_hosts` contains more than one sub host.
+    pub fn into_semantic(self) -> Option<Semantic<'a>> {
+        assert!(
+            self.sub_hosts.len() <= 1,
+            "into_semantic expects at most one sub host, but found {}",
+            self.sub_hosts.len()
+        );
+        self.sub_hosts.into_iter().next().map(|sub_host| sub_host.semantic)
+    }
+
     #[cfg(debug_assertions)]
     pub fn get_diagnostics(&self, cb: impl FnOnce(&mut Vec<Message>)) {
         cb(self.diagnostics.borrow_mut().as_mut());
     }
 
-    #[cfg(debug_assertions)]
     pub fn diagnostic_count(&self) -> usize {
         self.diagnostics.borrow().len()
     }
@@ -406,11 +594,13 @@ impl<'a> ContextHost<'a> {
         LintContext {
             parent: self,
             current_rule_name: rule_name,
+            current_rule_id: rule.id(),
             current_plugin_name: plugin_name,
             current_plugin_prefix: plugin_name_to_prefix(plugin_name),
             #[cfg(debug_assertions)]
             current_rule_fix_capabilities: rule.fix(),
             severity: severity.into(),
+            options: None,
         }
     }
 
@@ -420,11 +610,13 @@ impl<'a> ContextHost<'a> {
         LintContext {
             parent: Rc::clone(&self),
             current_rule_name: "",
+            current_rule_id: 0,
             current_plugin_name: "eslint",
             current_plugin_prefix: "eslint",
             #[cfg(debug_assertions)]
             current_rule_fix_capabilities: crate::rule::RuleFixMeta::None,
             severity: oxc_diagnostics::Severity::Warning,
+            options: None,
         }
     }
 
@@ -437,17 +629,12 @@ impl<'a> ContextHost<'a> {
     /// on top of those hints, providing a more granular understanding of the
     /// frameworks in use.
     fn sniff_for_frameworks(mut self) -> Self {
-        if self.plugins().has_test() {
-            // let mut test_flags = FrameworkFlags::empty();
-
-            let vitest_like = frameworks::has_vitest_imports(self.module_record());
-            let jest_like = frameworks::is_jestlike_file(&self.file_path)
-                || frameworks::has_jest_imports(self.module_record());
-
-            self.frameworks.set(FrameworkFlags::Vitest, vitest_like);
-            self.frameworks.set(FrameworkFlags::Jest, jest_like);
-        }
-
+        self.frameworks = FrameworkFlags::detect(
+            self.frameworks,
+            self.plugins(),
+            &self.file_path,
+            self.module_record(),
+        );
         self
     }
 
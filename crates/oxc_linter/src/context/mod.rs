@@ -1,6 +1,12 @@
 #![expect(rustdoc::private_intra_doc_links)] // useful for intellisense
 
-use std::{ffi::OsStr, ops::Deref, path::Path, rc::Rc};
+use std::{
+    ffi::OsStr,
+    ops::Deref,
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::Arc,
+};
 
 use javascript_globals::GLOBALS;
 
@@ -9,15 +15,18 @@ use oxc_cfg::ControlFlowGraph;
 use oxc_diagnostics::{OxcDiagnostic, Severity};
 use oxc_semantic::Semantic;
 use oxc_span::Span;
+use serde::de::DeserializeOwned;
 
 #[cfg(debug_assertions)]
 use crate::rule::RuleFixMeta;
 use crate::{
     AllowWarnDeny, FrameworkFlags, ModuleRecord, OxlintEnv, OxlintGlobals, OxlintSettings,
-    config::GlobalValue,
+    config::{GlobalValue, RuleOptions},
     disable_directives::DisableDirectives,
     fixer::{Fix, FixKind, Message, PossibleFixes, RuleFix, RuleFixer},
     frameworks::FrameworkOptions,
+    project_files::ProjectFile,
+    type_facts::TypeFact,
 };
 
 mod host;
@@ -41,6 +50,8 @@ pub struct LintContext<'a> {
     current_plugin_prefix: &'static str,
     /// Kebab-cased name of the current rule being linted. Example: `no-unused-vars`, `no-undef`.
     current_rule_name: &'static str,
+    /// Id of the current rule, for its shared default options.
+    current_rule_id: usize,
     /// Capabilities of the current rule to fix issues. Indicates whether:
     /// - Rule cannot be auto-fixed [`RuleFixMeta::None`]
     /// - Rule needs an auto-fix to be written still [`RuleFixMeta::FixPending`]
@@ -58,6 +69,8 @@ pub struct LintContext<'a> {
     /// }
     /// ```
     severity: Severity,
+    /// Options the current rule is configured with, read with [`LintContext::options`].
+    options: Option<Arc<RuleOptions>>,
 }
 
 impl<'a> Deref for LintContext<'a> {
@@ -93,6 +106,30 @@ impl<'a> LintContext<'a> {
         self
     }
 
+    /// Set the options the current rule is configured with.
+    #[inline]
+    pub fn with_options(mut self, options: Option<Arc<RuleOptions>>) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// The options the current rule is configured with, deserialized as `T` the first time a rule
+    /// reads them with this configuration. E.g. for
+    /// `"jest/no-hooks": ["error", { "allow": ["beforeAll"] }]`, `T` is deserialized from
+    /// `{ "allow": ["beforeAll"] }`.
+    ///
+    /// `T::default()` is returned if the rule is configured without options, or with options
+    /// which do not deserialize as `T`.
+    pub fn options<T>(&self) -> &T
+    where
+        T: DeserializeOwned + Default + Send + Sync + 'static,
+    {
+        match &self.options {
+            Some(options) => options.get(),
+            None => RuleOptions::none(self.current_rule_id).get(),
+        }
+    }
+
     /// Update the severity of diagnostics reported by the rule this context is
     /// associated with.
     #[inline]
@@ -115,6 +152,13 @@ impl<'a> LintContext<'a> {
         self.parent.module_record()
     }
 
+    /// Absolute path of the module imported with `specifier`, as found by the module resolver.
+    ///
+    /// Returns `None` if the specifier cannot be resolved, or if cross-module linting is disabled.
+    pub fn resolved_module_path(&self, specifier: &str) -> Option<&Path> {
+        self.module_record().resolved_modules.get(specifier).map(PathBuf::as_path)
+    }
+
     /// Get the control flow graph for the current program.
     #[inline]
     pub fn cfg(&self) -> &ControlFlowGraph {
@@ -129,6 +173,15 @@ impl<'a> LintContext<'a> {
         self.parent.disable_directives()
     }
 
+    /// Get the type information `tsgolint` computed for the node with the given [`Span`].
+    ///
+    /// Only available when type-aware linting is enabled and type facts were requested,
+    /// otherwise this always returns `None`.
+    pub fn type_fact(&self, span: Span) -> Option<&TypeFact> {
+        let offset = self.parent.current_sub_host().source_text_offset;
+        self.parent.type_facts()?.get(Span::new(span.start + offset, span.end + offset))
+    }
+
     /// Get a snippet of source text covered by the given [`Span`]. For details,
     /// see [`Span::source_text`].
     pub fn source_range(&self, span: Span) -> &'a str {
@@ -175,6 +228,16 @@ impl<'a> LintContext<'a> {
         self.parent.file_extension()
     }
 
+    /// The nearest file named `file_name` (e.g. `package.json` or `tsconfig.json`) in the
+    /// directory of the file being linted or its ancestors, parsed as JSON with comments.
+    ///
+    /// Only file names are accepted, paths like `../package.json` are not. Files are read once and
+    /// shared with the other linted files. Returns `None` if there is no such file, or if the
+    /// nearest one is not valid JSON.
+    pub fn read_project_file(&self, file_name: &str) -> Option<Arc<ProjectFile>> {
+        self.parent.project_files.find(&self.parent.file_path, file_name)
+    }
+
     /// Plugin settings
     #[inline]
     pub fn settings(&self) -> &OxlintSettings {
@@ -257,7 +320,11 @@ impl<'a> LintContext<'a> {
     /// Add a diagnostic message to the list of diagnostics. Outputs a diagnostic with the current rule
     /// name, severity, and a link to the rule's documentation URL.
     fn add_diagnostic(&self, mut message: Message) {
-        if self.parent.disable_directives().contains(self.current_rule_name, message.span) {
+        if self.parent.disable_directives().contains_rule(
+            self.current_plugin_name,
+            self.current_rule_name,
+            message.span,
+        ) {
             return;
         }
         message.error = message
@@ -506,7 +573,7 @@ impl<'a> LintContext<'a> {
 /// assert_eq!(plugin_name_to_prefix("react"), "eslint-plugin-react");
 /// ```
 #[inline]
-fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
+pub fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
     match plugin_name {
         "import" => "eslint-plugin-import",
         "jest" => "eslint-plugin-jest",
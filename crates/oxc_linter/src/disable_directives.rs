@@ -1,10 +1,15 @@
-use std::cell::RefCell;
+use std::{
+    cell::RefCell,
+    fmt,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use itertools::Itertools;
 use oxc_ast::Comment;
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
 use rust_lapper::{Interval, Lapper};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::fixer::Fix;
 
@@ -30,6 +35,14 @@ impl DisabledRule {
             }
         }
     }
+
+    /// Position of the directive in the source text, used to order directives covering each other
+    fn position(&self) -> u32 {
+        match self {
+            DisabledRule::All { comment_span, .. } => comment_span.start,
+            DisabledRule::Single { name_span, .. } => name_span.start,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -109,16 +122,188 @@ pub struct DisableRuleComment {
     pub r#type: RuleCommentType,
 }
 
+/// Maximum number of rules suppressed by a blanket `eslint-disable` for the whole file, for the
+/// directive to be narrowed to these rules.
+const MAX_NARROWED_RULES: usize = 3;
+
+/// The name of a built-in rule as it is written in a disable directive, e.g. `no-console`,
+/// `react/jsx-key` or `@typescript-eslint/no-explicit-any`.
+pub fn directive_rule_name(plugin_name: &str, rule_name: &str) -> String {
+    match plugin_name {
+        "eslint" => rule_name.to_string(),
+        "typescript" => format!("@typescript-eslint/{rule_name}"),
+        "jsx_a11y" => format!("jsx-a11y/{rule_name}"),
+        "react_perf" => format!("react-perf/{rule_name}"),
+        "nextjs" => format!("@next/next/{rule_name}"),
+        _ => format!("{plugin_name}/{rule_name}"),
+    }
+}
+
+/// A blanket `eslint-disable` comment, without rule names
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct BlanketDisable {
+    /// Span of the comment
+    span: Span,
+    /// End of `eslint-disable` in the comment, where rule names can be inserted
+    directive_end: u32,
+    /// The directive, i.e. `eslint-disable` or `oxlint-disable`
+    directive: String,
+}
+
+/// A blanket `eslint-disable` comment which is never followed by an `eslint-enable`, so it
+/// disables all rules for the rest of the file, but only suppresses a few rules.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FileDisableComment {
+    /// Span of the comment
+    pub span: Span,
+    /// End of `eslint-disable` in the comment, where rule names can be inserted
+    pub directive_end: u32,
+    /// The directive, i.e. `eslint-disable` or `oxlint-disable`
+    pub directive: String,
+    /// Names of the suppressed rules with the number of suppressed diagnostics,
+    /// most suppressed first
+    pub suppressed: Vec<(String, usize)>,
+}
+
+impl FileDisableComment {
+    /// Total number of suppressed diagnostics
+    pub fn suppressed_count(&self) -> usize {
+        self.suppressed.iter().map(|(_, count)| count).sum()
+    }
+
+    /// The suppressed rules, as they are listed in a directive, e.g. `no-console, no-debugger`
+    pub fn rule_names(&self) -> String {
+        self.suppressed.iter().map(|(rule_name, _)| rule_name.as_str()).join(", ")
+    }
+
+    /// Fix to only disable the suppressed rules, e.g. `eslint-disable no-console, no-debugger`
+    pub fn create_fix(&self) -> Fix {
+        Fix::new(format!(" {}", self.rule_names()), Span::empty(self.directive_end))
+            .with_message("disable only the rules which are suppressed")
+    }
+
+    /// The diagnostic reporting the comment, with the severity of unused directives.
+    pub fn diagnostic(&self) -> OxcDiagnostic {
+        OxcDiagnostic::warn(self.message())
+            .with_help(format!(
+                "Disable only these rules with `{} {}`.",
+                self.directive,
+                self.rule_names()
+            ))
+            .with_label(self.span)
+    }
+
+    /// The diagnostic message, e.g.
+    /// `eslint-disable directive for the whole file only suppresses 3 problems (no-console: 2, no-debugger: 1).`
+    pub fn message(&self) -> String {
+        let count = self.suppressed_count();
+        let problems = if count == 1 { "problem" } else { "problems" };
+        let counts = self
+            .suppressed
+            .iter()
+            .map(|(rule_name, count)| format!("{rule_name}: {count}"))
+            .join(", ");
+        format!(
+            "{} directive for the whole file only suppresses {count} {problems} ({counts}).",
+            self.directive
+        )
+    }
+}
+
+/// A calendar date, as written in `expires:YYYY-MM-DD` in the description of a disable directive.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub struct ExpiryDate {
+    year: u32,
+    month: u32,
+    day: u32,
+}
+
+impl ExpiryDate {
+    /// Parse a date in `YYYY-MM-DD` form. Returns `None` if the date does not exist.
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut parts = text.splitn(3, '-');
+        let mut next_part = |len: usize| {
+            parts
+                .next()
+                .filter(|part| part.len() == len && part.bytes().all(|b| b.is_ascii_digit()))
+                .and_then(|part| part.parse::<u32>().ok())
+        };
+        let (year, month, day) = (next_part(4)?, next_part(2)?, next_part(2)?);
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+            2 => 28,
+            _ => return None,
+        };
+        (1..=days_in_month).contains(&day).then_some(Self { year, month, day })
+    }
+
+    /// The current date in UTC.
+    #[expect(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    pub fn today() -> Self {
+        let secs = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        // Convert days since 1970-01-01 to a civil date.
+        // <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+        let days = (secs / 86_400) as i64 + 719_468;
+        let era = days / 146_097;
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (year_of_era + era * 400 + i64::from(month <= 2)) as u32;
+        Self { year, month, day }
+    }
+}
+
+impl fmt::Display for ExpiryDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// The directive of a disable comment, e.g. `oxlint-disable-next-line` for the text of
+/// `// oxlint-disable-next-line no-console`.
+pub fn directive_name(comment_text: &str) -> &str {
+    comment_text.split_whitespace().next().unwrap_or_default()
+}
+
+/// `expires:YYYY-MM-DD` in the description of a disable directive, e.g.
+/// `// oxlint-disable-next-line no-console -- expires:2025-12-31 remove after the migration`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DirectiveExpiry {
+    /// Span of `expires:YYYY-MM-DD`
+    pub span: Span,
+    /// Span of the directive comment
+    pub comment_span: Span,
+    /// `None` if the date is not a valid `YYYY-MM-DD` date.
+    pub expires: Option<ExpiryDate>,
+}
+
 #[derive(Debug, Clone)]
 pub struct DisableDirectives {
     /// All the disabled rules with their corresponding covering spans
     intervals: Lapper<u32, DisabledRule>,
     /// All comments that disable one or more specific rules
     disable_rule_comments: Box<[DisableRuleComment]>,
+    /// Rules re-enabled with `eslint-enable rule-name` inside of an `eslint-disable` region
+    reenabled_intervals: Lapper<u32, String>,
     /// Spans of unused enable directives
     unused_enable_comments: Box<[(Option<String>, Span)]>,
+    /// Disable directives for rules which were already disabled, so they have no effect
+    redundant_disables: Box<[DisabledRule]>,
     /// Spans of used enable directives, to filter out unused
     used_disable_comments: RefCell<Vec<DisabledRule>>,
+    /// Disable directives with an expiry date in their description
+    expiring_comments: Box<[DirectiveExpiry]>,
+    /// Blanket disable directives for the rest of the file, with the end of `eslint-disable`
+    file_disables: Box<[BlanketDisable]>,
+    /// Rule names and spans of the diagnostics suppressed by `file_disables`, by comment span.
+    /// A set, because rules may be run more than once on a file, e.g. in debug builds.
+    file_disable_suppressions: RefCell<FxHashMap<Span, FxHashSet<(String, Span)>>>,
 }
 
 impl DisableDirectives {
@@ -126,7 +311,20 @@ impl DisableDirectives {
         self.used_disable_comments.borrow_mut().push(disable_directive);
     }
 
+    /// Whether the diagnostic of the built-in rule `rule_name` of `plugin_name` at `span` is
+    /// disabled. Diagnostics suppressed by a blanket disable for the whole file are recorded with
+    /// the [full name](directive_rule_name) of the rule, to narrow the directive to it.
+    pub fn contains_rule(&self, plugin_name: &str, rule_name: &str, span: Span) -> bool {
+        self.contains_as(rule_name, || directive_rule_name(plugin_name, rule_name), span)
+    }
+
+    /// Whether the diagnostic of the rule `rule_name`, as it is written in a disable directive,
+    /// e.g. `my-plugin/my-rule` for a JS plugin, at `span` is disabled.
     pub fn contains(&self, rule_name: &str, span: Span) -> bool {
+        self.contains_as(rule_name, || rule_name.to_string(), span)
+    }
+
+    fn contains_as(&self, rule_name: &str, full_name: impl Fn() -> String, span: Span) -> bool {
         // For `eslint-disable-next-line` and `eslint-disable-line` directives, we only check
         // if the diagnostic's starting position falls within the disabled interval.
         // This prevents suppressing diagnostics for larger constructs (like functions) that
@@ -153,6 +351,13 @@ impl DisableDirectives {
                     return false;
                 }
 
+                // `eslint-enable rule-name` inside of an `eslint-disable` region
+                if matches!(interval.val, DisabledRule::All { is_next_line: false, .. })
+                    && self.is_reenabled(rule_name, span.start)
+                {
+                    return false;
+                }
+
                 // Check if the diagnostic span is covered by this interval
                 if interval.val.is_next_line() {
                     // For next-line directives, only check if the diagnostic starts within the interval
@@ -171,12 +376,27 @@ impl DisableDirectives {
             .collect::<Vec<DisabledRule>>();
 
         for disable in &matched_intervals {
+            if let DisabledRule::All { comment_span, is_next_line: false } = disable
+                && self.file_disables.iter().any(|file_disable| file_disable.span == *comment_span)
+            {
+                self.file_disable_suppressions
+                    .borrow_mut()
+                    .entry(*comment_span)
+                    .or_default()
+                    .insert((full_name(), span));
+            }
             self.mark_disable_directive_used(disable.clone());
         }
 
         !matched_intervals.is_empty()
     }
 
+    fn is_reenabled(&self, rule_name: &str, position: u32) -> bool {
+        self.reenabled_intervals
+            .find(position, position + 1)
+            .any(|interval| interval.val.contains(rule_name))
+    }
+
     pub fn disable_rule_comments(&self) -> &[DisableRuleComment] {
         &self.disable_rule_comments
     }
@@ -185,16 +405,28 @@ impl DisableDirectives {
         &self.unused_enable_comments
     }
 
+    /// Disable directives which expired before `today`, or whose expiry date is invalid.
+    pub fn collect_expired_comments(&self, today: ExpiryDate) -> Vec<&DirectiveExpiry> {
+        self.expiring_comments
+            .iter()
+            .filter(|expiry| expiry.expires.is_none_or(|expires| expires < today))
+            .collect()
+    }
+
     pub fn collect_unused_disable_comments(&self) -> Vec<DisableRuleComment> {
         let used = self.used_disable_comments.borrow();
 
         self.intervals
             .iter()
-            // 1. group intervals with the same interval.val.comment_span() together
-            .chunk_by(|interval| interval.val.comment_span())
+            .map(|interval| (&interval.val, used.contains(&interval.val)))
+            // redundant disable directives can never be used
+            .chain(self.redundant_disables.iter().map(|disabled_rule| (disabled_rule, false)))
+            // 1. group disabled rules with the same comment span together
+            .sorted_by_key(|(disabled_rule, _)| disabled_rule.comment_span().start)
+            .chunk_by(|(disabled_rule, _)| *disabled_rule.comment_span())
             .into_iter()
             // 2. iterate over all groups
-            // 3. check if the group has only one , ore all entries with the comment span are used with `used.contains(&interval.val))`
+            // 3. check if the group has only one , ore all entries with the comment span are used
             // 4. if all entries are used, map to RuleCommentType::All comment, otherwise map to RuleCommentType::Single comment.
             .filter_map(|(comment_span, group)| {
                 let group_vec: Vec<_> = group.collect();
@@ -205,11 +437,11 @@ impl DisableDirectives {
 
                 let rules: Vec<RuleCommentRule> = group_vec
                     .iter()
-                    .filter_map(|interval| {
-                        if used.contains(&interval.val) {
+                    .filter_map(|(disabled_rule, is_used)| {
+                        if *is_used {
                             return None;
                         }
-                        match &interval.val {
+                        match disabled_rule {
                             DisabledRule::Single { rule_name, name_span, .. } => {
                                 Some(RuleCommentRule {
                                     rule_name: rule_name.clone(),
@@ -218,7 +450,7 @@ impl DisableDirectives {
                             }
                             DisabledRule::All { .. } => Some(RuleCommentRule {
                                 rule_name: "all".to_string(),
-                                name_span: *comment_span,
+                                name_span: comment_span,
                             }),
                         }
                     })
@@ -230,18 +462,91 @@ impl DisableDirectives {
 
                 if rules.len() == group_vec.len() {
                     return Some(DisableRuleComment {
-                        span: *comment_span,
+                        span: comment_span,
                         r#type: RuleCommentType::All,
                     });
                 }
 
                 Some(DisableRuleComment {
-                    span: *comment_span,
+                    span: comment_span,
                     r#type: RuleCommentType::Single(rules),
                 })
             })
             .collect()
     }
+
+    /// Collect blanket disable comments for the rest of the file which suppress diagnostics of a
+    /// few rules, so they can be narrowed to these rules.
+    ///
+    /// Comments which do not suppress any diagnostic are not returned, they are reported by
+    /// [`DisableDirectives::collect_unused_disable_comments`].
+    pub fn collect_narrowable_file_disables(&self) -> Vec<FileDisableComment> {
+        let suppressions = self.file_disable_suppressions.borrow();
+        self.file_disables
+            .iter()
+            .filter_map(|file_disable| {
+                let suppressed = suppressions
+                    .get(&file_disable.span)?
+                    .iter()
+                    .counts_by(|(rule_name, _)| rule_name);
+                if suppressed.len() > MAX_NARROWED_RULES {
+                    return None;
+                }
+                let suppressed = suppressed
+                    .into_iter()
+                    .map(|(rule_name, count)| (rule_name.clone(), count))
+                    .sorted_by(|(a_name, a_count), (b_name, b_count)| {
+                        b_count.cmp(a_count).then_with(|| a_name.cmp(b_name))
+                    })
+                    .collect();
+                Some(FileDisableComment {
+                    span: file_disable.span,
+                    directive_end: file_disable.directive_end,
+                    directive: file_disable.directive.clone(),
+                    suppressed,
+                })
+            })
+            .collect()
+    }
+
+    /// Collect disable comments naming rules for which `is_rule_enabled` returns `false`.
+    /// Such comments can never suppress a diagnostic, regardless of the lint result.
+    ///
+    /// If every rule named by a comment is not enabled, the comment is returned as
+    /// [`RuleCommentType::All`], so the whole comment can be removed.
+    /// Otherwise, only the rules which are not enabled are returned.
+    /// Comments which disable all rules (e.g. `eslint-disable-next-line`) are never returned.
+    pub fn collect_unused_inline_configs(
+        &self,
+        is_rule_enabled: impl Fn(&str) -> bool,
+    ) -> Vec<DisableRuleComment> {
+        self.disable_rule_comments
+            .iter()
+            .filter_map(|comment| {
+                let RuleCommentType::Single(rules) = &comment.r#type else {
+                    return None;
+                };
+
+                let unused_rules = rules
+                    .iter()
+                    .filter(|rule| !is_rule_enabled(&rule.rule_name))
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                if unused_rules.is_empty() {
+                    return None;
+                }
+
+                let r#type = if unused_rules.len() == rules.len() {
+                    RuleCommentType::All
+                } else {
+                    RuleCommentType::Single(unused_rules)
+                };
+
+                Some(DisableRuleComment { span: comment.span, r#type })
+            })
+            .collect()
+    }
 }
 
 pub struct DisableDirectivesBuilder {
@@ -251,10 +556,22 @@ pub struct DisableDirectivesBuilder {
     disable_all_start: Option<(u32, Span)>,
     /// Start of `eslint-disable` or `oxlint-disable` rule_name`
     disable_start_map: FxHashMap<String, (u32, Span, Span)>,
+    /// Start of `eslint-enable rule_name` inside of an `eslint-disable` region
+    reenabled_start_map: FxHashMap<String, u32>,
+    /// Rules re-enabled inside of an `eslint-disable` region with their covering spans
+    reenabled_intervals: Lapper<u32, String>,
     /// All comments that disable one or more specific rules
     disable_rule_comments: Vec<DisableRuleComment>,
     /// Spans of unused enable directives
     unused_enable_comments: Vec<(Option<String>, Span)>,
+    /// Disable directives for rules which were already disabled
+    redundant_disables: Vec<DisabledRule>,
+    /// Disable directives with an expiry date in their description
+    expiring_comments: Vec<DirectiveExpiry>,
+    /// Blanket `eslint-disable` comments
+    blanket_disables: Vec<BlanketDisable>,
+    /// Blanket disable directives for the rest of the file
+    file_disables: Vec<BlanketDisable>,
 }
 
 impl DisableDirectivesBuilder {
@@ -263,8 +580,14 @@ impl DisableDirectivesBuilder {
             intervals: Lapper::new(vec![]),
             disable_all_start: None,
             disable_start_map: FxHashMap::default(),
+            reenabled_start_map: FxHashMap::default(),
+            reenabled_intervals: Lapper::new(vec![]),
             disable_rule_comments: vec![],
             unused_enable_comments: vec![],
+            redundant_disables: vec![],
+            expiring_comments: vec![],
+            blanket_disables: vec![],
+            file_disables: vec![],
         }
     }
 
@@ -274,8 +597,13 @@ impl DisableDirectivesBuilder {
         DisableDirectives {
             intervals: self.intervals,
             disable_rule_comments: self.disable_rule_comments.into_boxed_slice(),
+            reenabled_intervals: self.reenabled_intervals,
             unused_enable_comments: self.unused_enable_comments.into_boxed_slice(),
+            redundant_disables: self.redundant_disables.into_boxed_slice(),
             used_disable_comments: RefCell::new(Vec::new()),
+            expiring_comments: self.expiring_comments.into_boxed_slice(),
+            file_disables: self.file_disables.into_boxed_slice(),
+            file_disable_suppressions: RefCell::default(),
         }
     }
 
@@ -283,19 +611,52 @@ impl DisableDirectivesBuilder {
         self.intervals.insert(Interval { start, stop, val });
     }
 
+    /// End the `eslint-disable` region at `stop`.
+    fn close_disable_all(&mut self, stop: u32) {
+        if let Some((start, comment_span)) = self.disable_all_start.take() {
+            self.add_interval(start, stop, DisabledRule::All { comment_span, is_next_line: false });
+        }
+        self.close_reenabled_rules(stop);
+    }
+
+    /// End the `eslint-disable rule_name` region at `stop`.
+    fn close_disable_rule(&mut self, rule_name: &str, stop: u32) -> bool {
+        let Some((start, name_span, comment_span)) = self.disable_start_map.remove(rule_name)
+        else {
+            return false;
+        };
+        self.add_interval(
+            start,
+            stop,
+            DisabledRule::Single {
+                rule_name: rule_name.to_string(),
+                name_span,
+                comment_span,
+                is_next_line: false,
+            },
+        );
+        true
+    }
+
+    /// End all `eslint-enable rule_name` regions inside of the `eslint-disable` region at `stop`.
+    fn close_reenabled_rules(&mut self, stop: u32) {
+        for (rule_name, start) in self.reenabled_start_map.drain() {
+            self.reenabled_intervals.insert(Interval { start, stop, val: rule_name });
+        }
+    }
+
     #[expect(clippy::cast_possible_truncation)] // for `as u32`
     fn build_impl(&mut self, source_text: &str, comments: &[Comment]) {
         let source_len = source_text.len() as u32;
         // This algorithm iterates through the comments and builds all intervals
-        // for matching disable and enable pairs.
+        // for matching disable and enable pairs, attributed to the disable comment.
         // Wrongly ordered matching pairs are not taken into consideration.
-
-        // NOTE: oxlint apply directive's logic not exactly same to eslint
-        // only `disable-all` & `enable-all`, or `disable-rule` & `enable-rule` will add to intervals for disable directives
-        // `disable-all` & `enable-rule` -> lone `disable-all` (enable-rule find not disable-rule before itself)
+        //
+        // Like eslint, block directives are applied in order:
+        // - `enable-all` ends the regions of `disable-all` and of every `disable-rule`
+        // - `enable-rule` ends the region of `disable-rule`, and re-enables the rule inside of a `disable-all` region
+        // - `disable-rule` of an already disabled rule, and `disable-all` inside of a `disable-all` region, have no effect
         // https://github.com/eslint/eslint/blob/f67d5e875324a9d899598b11807a9c7624021432/lib/linter/apply-disable-directives.js#L308
-
-        // enable directive keep the same logic for checking unused
         let mut unused_enable_directives: Vec<(Option<String>, Span)> = vec![];
 
         for comment in comments {
@@ -307,11 +668,24 @@ impl DisableDirectivesBuilder {
             if let Some(text) =
                 text.strip_prefix("eslint-disable").or_else(|| text.strip_prefix("oxlint-disable"))
             {
+                self.add_expiry(text, rule_name_start + 14, comment_span);
                 rule_name_start += 14; // eslint-disable is 14 bytes
                 // `eslint-disable`
                 if text.trim().is_empty() {
+                    self.blanket_disables.push(BlanketDisable {
+                        span: comment_span,
+                        directive_end: rule_name_start,
+                        directive: directive_name(text_source).to_string(),
+                    });
                     if self.disable_all_start.is_none() {
                         self.disable_all_start = Some((comment_span.end, comment_span));
+                    } else if self.reenabled_start_map.is_empty() {
+                        self.redundant_disables
+                            .push(DisabledRule::All { comment_span, is_next_line: false });
+                    } else {
+                        // disables the re-enabled rules again
+                        self.close_disable_all(comment_span.start);
+                        self.disable_all_start = Some((comment_span.end, comment_span));
                     }
                     self.disable_rule_comments.push(DisableRuleComment {
                         span: comment_span,
@@ -426,11 +800,26 @@ impl DisableDirectivesBuilder {
                     // `eslint-disable rule-name1, rule-name2`
                     let mut rules = vec![];
                     Self::get_rule_names(text, rule_name_start, |rule_name, name_span| {
-                        self.disable_start_map.entry(rule_name.to_string()).or_insert((
-                            comment_span.end,
-                            name_span,
-                            comment_span,
-                        ));
+                        if self.disable_start_map.contains_key(rule_name) {
+                            self.redundant_disables.push(DisabledRule::Single {
+                                rule_name: rule_name.to_string(),
+                                name_span,
+                                comment_span,
+                                is_next_line: false,
+                            });
+                        } else {
+                            if let Some(start) = self.reenabled_start_map.remove(rule_name) {
+                                self.reenabled_intervals.insert(Interval {
+                                    start,
+                                    stop: comment_span.start,
+                                    val: rule_name.to_string(),
+                                });
+                            }
+                            self.disable_start_map.insert(
+                                rule_name.to_string(),
+                                (comment_span.end, name_span, comment_span),
+                            );
+                        }
                         rules.push(RuleCommentRule { rule_name: rule_name.to_string(), name_span });
                     });
                     self.disable_rule_comments.push(DisableRuleComment {
@@ -447,12 +836,14 @@ impl DisableDirectivesBuilder {
                 rule_name_start += 13; // eslint-enable is 13 bytes
                 // `eslint-enable`
                 if text.trim().is_empty() {
-                    if let Some((start, _)) = self.disable_all_start.take() {
-                        self.add_interval(
-                            start,
-                            comment_span.start,
-                            DisabledRule::All { comment_span, is_next_line: false },
-                        );
+                    let is_disabled =
+                        self.disable_all_start.is_some() || !self.disable_start_map.is_empty();
+                    if is_disabled {
+                        self.close_disable_all(comment_span.start);
+                        let rule_names = self.disable_start_map.keys().cloned().collect::<Vec<_>>();
+                        for rule_name in rule_names {
+                            self.close_disable_rule(&rule_name, comment_span.start);
+                        }
                     } else {
                         // collect as unused enable (see more at note comments in beginning of this method)
                         unused_enable_directives.push((None, comment_span));
@@ -460,18 +851,15 @@ impl DisableDirectivesBuilder {
                 } else {
                     // `eslint-enable rule-name1, rule-name2`
                     Self::get_rule_names(text, rule_name_start, |rule_name, name_span| {
-                        if let Some((start, _, _)) = self.disable_start_map.remove(rule_name) {
-                            self.add_interval(
-                                start,
-                                comment_span.start,
-                                DisabledRule::Single {
-                                    rule_name: rule_name.to_string(),
-                                    name_span,
-                                    comment_span,
-                                    is_next_line: false,
-                                },
-                            );
-                        } else {
+                        let closed_rule = self.close_disable_rule(rule_name, comment_span.start);
+                        // re-enable the rule inside of the `eslint-disable` region
+                        let reenabled = self.disable_all_start.is_some()
+                            && !self.reenabled_start_map.contains_key(rule_name);
+                        if reenabled {
+                            self.reenabled_start_map
+                                .insert(rule_name.to_string(), comment_span.start);
+                        }
+                        if !closed_rule && !reenabled {
                             // collect as unused enable (see more at note comments in beginning of this method)
                             unused_enable_directives.push((Some(rule_name.to_string()), name_span));
                         }
@@ -481,31 +869,100 @@ impl DisableDirectivesBuilder {
         }
 
         // Lone `eslint-disable`
-        if let Some((start, comment_span)) = self.disable_all_start {
-            self.add_interval(
-                start,
-                source_len,
-                DisabledRule::All { comment_span, is_next_line: false },
+        if let Some((_, comment_span)) = self.disable_all_start {
+            self.file_disables.extend(
+                self.blanket_disables
+                    .iter()
+                    .filter(|blanket_disable| blanket_disable.span == comment_span)
+                    .cloned(),
             );
         }
+        self.close_disable_all(source_len);
 
         // Lone `eslint-disable rule_name`
-        let disable_start_map = self.disable_start_map.drain().collect::<Vec<_>>();
-        for (rule_name, (start, name_span, comment_span)) in disable_start_map {
-            self.add_interval(
-                start,
-                source_len,
-                DisabledRule::Single {
-                    rule_name: rule_name.clone(),
-                    name_span,
-                    comment_span,
-                    is_next_line: false,
-                },
-            );
+        let rule_names = self.disable_start_map.keys().cloned().collect::<Vec<_>>();
+        for rule_name in rule_names {
+            self.close_disable_rule(&rule_name, source_len);
         }
 
         // Collect unused `enable` directives
         self.unused_enable_comments = unused_enable_directives;
+
+        self.collect_redundant_line_disables();
+    }
+
+    /// Move `eslint-disable-line` and `eslint-disable-next-line` directives which are covered by
+    /// an earlier directive for the same rule to the redundant directives, so they are reported
+    /// as unused. This catches identical directives for the same line, rules named twice in a
+    /// directive, and line directives inside of an `eslint-disable` region.
+    fn collect_redundant_line_disables(&mut self) {
+        let (redundant, intervals): (Vec<_>, Vec<_>) = self
+            .intervals
+            .iter()
+            .cloned()
+            .partition(|interval| interval.val.is_next_line() && self.is_covered(interval));
+        if redundant.is_empty() {
+            return;
+        }
+        self.redundant_disables.extend(redundant.into_iter().map(|interval| interval.val));
+        self.intervals = Lapper::new(intervals);
+    }
+
+    /// Whether an earlier directive disables the rule of `interval` for all of its span.
+    fn is_covered(&self, interval: &Interval<u32, DisabledRule>) -> bool {
+        let (start, stop) = (interval.start, interval.stop);
+        let is_reenabled = |rule_name: Option<&str>| {
+            self.reenabled_intervals
+                .find(start, stop)
+                .any(|reenabled| rule_name.is_none_or(|rule_name| reenabled.val == rule_name))
+        };
+        self.intervals.find(start, stop).any(|other| {
+            if other.start > start
+                || other.stop < stop
+                || other.val.position() >= interval.val.position()
+            {
+                return false;
+            }
+            match (&other.val, &interval.val) {
+                (DisabledRule::All { is_next_line, .. }, DisabledRule::All { .. }) => {
+                    *is_next_line || !is_reenabled(None)
+                }
+                (
+                    DisabledRule::All { is_next_line, .. },
+                    DisabledRule::Single { rule_name, .. },
+                ) => *is_next_line || !is_reenabled(Some(rule_name)),
+                (
+                    DisabledRule::Single { rule_name: other_name, .. },
+                    DisabledRule::Single { rule_name, .. },
+                ) => other_name == rule_name,
+                (DisabledRule::Single { .. }, DisabledRule::All { .. }) => false,
+            }
+        })
+    }
+
+    /// Collect `expires:YYYY-MM-DD` from the description of a disable directive.
+    /// `text` is the directive after `eslint-disable`, starting at `text_start`.
+    #[expect(clippy::cast_possible_truncation)] // for `as u32`
+    fn add_expiry(&mut self, text: &str, text_start: u32, comment_span: Span) {
+        let is_directive = text.is_empty()
+            || text.starts_with(char::is_whitespace)
+            || text.starts_with("-line")
+            || text.starts_with("-next-line");
+        let Some(description_start) = text.find("--").filter(|_| is_directive) else {
+            return;
+        };
+        let description = &text[description_start + 2..];
+        let Some(token) =
+            description.split_whitespace().find(|token| token.starts_with("expires:"))
+        else {
+            return;
+        };
+        let token_start = token.as_ptr() as usize - text.as_ptr() as usize;
+        self.expiring_comments.push(DirectiveExpiry {
+            span: Span::sized(text_start + token_start as u32, token.len() as u32),
+            comment_span,
+            expires: ExpiryDate::parse(&token["expires:".len()..]),
+        });
     }
 
     #[expect(clippy::cast_possible_truncation)] // for `as u32`
@@ -754,7 +1211,20 @@ no-debugger
                 /* {prefix}-disable , ,no-debugger, , */
                 debugger;
             "),
-            format!("debugger;//{prefix}-disable-line")
+            format!("debugger;//{prefix}-disable-line"),
+            // A rule re-enabled inside of a disable region can be disabled again
+            format!("
+                /* {prefix}-disable */
+                /* {prefix}-enable no-debugger */
+                /* {prefix}-disable no-debugger */
+                debugger;
+            "),
+            // Other rules stay disabled after re-enabling one rule
+            format!("
+                /* {prefix}-disable */
+                /* {prefix}-enable no-console */
+                debugger;
+            "),
         ];
 
         let fail = vec![
@@ -930,6 +1400,22 @@ semi*/
             debugger;
         "
             ),
+            // `enable-rule` re-enables the rule inside of a `disable-all` region
+            format!(
+                "
+            /* {prefix}-disable */
+            /* {prefix}-enable no-debugger */
+                debugger;
+            "
+            ),
+            // `enable-all` ends the regions of `disable-rule`
+            format!(
+                "
+            /* {prefix}-disable no-debugger */
+            /* {prefix}-enable */
+                debugger;
+            "
+            ),
         ];
 
         Tester::new(EslintNoDebugger::NAME, EslintNoDebugger::PLUGIN, pass, fail)
@@ -942,6 +1428,7 @@ semi*/
 ///
 /// This utility function generates `OxcDiagnostic` instances for:
 /// - Unused disable directives (no problems were reported)
+/// - Blanket disable directives for the whole file which only suppress a few rules
 /// - Unused enable directives (no matching disable directives)
 ///
 /// # Arguments
@@ -954,8 +1441,6 @@ pub fn create_unused_directives_diagnostics(
     directives: &DisableDirectives,
     severity: crate::AllowWarnDeny,
 ) -> Vec<oxc_diagnostics::OxcDiagnostic> {
-    use oxc_diagnostics::OxcDiagnostic;
-
     let mut diagnostics = Vec::new();
 
     let severity = if severity == crate::AllowWarnDeny::Deny {
@@ -994,6 +1479,11 @@ pub fn create_unused_directives_diagnostics(
         }
     }
 
+    // Report blanket disables for the whole file which only suppress a few rules
+    for file_disable in directives.collect_narrowable_file_disables() {
+        diagnostics.push(file_disable.diagnostic().with_severity(severity));
+    }
+
     // Report unused enable comments
     let unused_enable = directives.unused_enable_comments();
     for (rule_name, span) in unused_enable {
@@ -1021,7 +1511,7 @@ mod tests {
 
     use crate::disable_directives::{DisabledRule, RuleCommentRule, RuleCommentType};
 
-    use super::{DisableDirectives, DisableDirectivesBuilder};
+    use super::{DisableDirectives, DisableDirectivesBuilder, ExpiryDate};
 
     fn process_source<'a>(allocator: &'a Allocator, source_text: &'a str) -> Semantic<'a> {
         let source_type = SourceType::default();
@@ -1222,6 +1712,178 @@ mod tests {
         );
     }
 
+    #[test]
+    fn unused_disable_is_reported_at_disable_comment() {
+        test_directives(
+            |prefix| {
+                format!(
+                    r"
+                    /* {prefix}-disable no-debugger */
+                    console.log();
+                    /* {prefix}-enable no-debugger */
+                    "
+                )
+            },
+            |comments, directives| {
+                let unused = directives.collect_unused_disable_comments();
+
+                assert_eq!(unused.len(), 1);
+                assert_eq!(unused[0].span, comments[0].content_span());
+                assert!(directives.unused_enable_comments().is_empty());
+            },
+        );
+    }
+
+    #[test]
+    fn redundant_disable_rules() {
+        test_directives(
+            |prefix| {
+                format!(
+                    r"
+                    /* {prefix}-disable no-console */
+                    /* {prefix}-disable no-console, no-debugger */
+                    console.log(); debugger;
+                    /* {prefix}-enable */
+                    "
+                )
+            },
+            |comments, directives| {
+                let code_start = comments[1].content_span().end + 23;
+                assert!(directives.contains("no-console", Span::sized(code_start, 13)));
+                assert!(directives.contains("no-debugger", Span::sized(code_start + 15, 9)));
+
+                // the second `no-console` has no effect, as the rule is already disabled
+                let unused = directives.collect_unused_disable_comments();
+                assert_eq!(unused.len(), 1);
+                assert_eq!(unused[0].span, comments[1].content_span());
+                assert_eq!(
+                    unused[0].r#type,
+                    RuleCommentType::Single(vec![RuleCommentRule {
+                        rule_name: "no-console".to_string(),
+                        name_span: Span::sized(comments[1].content_span().start + 16, 10),
+                    }])
+                );
+                assert!(directives.unused_enable_comments().is_empty());
+            },
+        );
+    }
+
+    #[test]
+    fn duplicate_next_line_disables() {
+        test_directives(
+            |prefix| {
+                format!(
+                    r"
+                    /* {prefix}-disable-next-line no-console */ /* {prefix}-disable-next-line no-console */
+                    console.log();
+                    "
+                )
+            },
+            |comments, directives| {
+                let code_start = comments[1].content_span().end + 24;
+                assert!(directives.contains("no-console", Span::sized(code_start, 13)));
+
+                // the second comment has no effect, as the line is already disabled
+                let unused = directives.collect_unused_disable_comments();
+                assert_eq!(unused.len(), 1);
+                assert_eq!(unused[0].span, comments[1].content_span());
+                assert_eq!(unused[0].r#type, RuleCommentType::All);
+            },
+        );
+    }
+
+    #[test]
+    fn duplicate_rule_in_directive() {
+        test_directives(
+            |prefix| {
+                format!(
+                    r"
+                    // {prefix}-disable-next-line no-console, no-console
+                    console.log();
+                    "
+                )
+            },
+            |comments, directives| {
+                let code_start = comments[0].content_span().end + 21;
+                assert!(directives.contains("no-console", Span::sized(code_start, 13)));
+
+                let unused = directives.collect_unused_disable_comments();
+                assert_eq!(unused.len(), 1);
+                assert_eq!(
+                    unused[0].r#type,
+                    RuleCommentType::Single(vec![RuleCommentRule {
+                        rule_name: "no-console".to_string(),
+                        name_span: Span::sized(comments[0].content_span().start + 38, 10),
+                    }])
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn line_disable_in_disable_region() {
+        test_directives(
+            |prefix| {
+                format!(
+                    r"
+                    /* {prefix}-disable no-console */
+                    // {prefix}-disable-next-line no-console, no-debugger
+                    console.log(); debugger;
+                    /* {prefix}-enable */
+                    /* {prefix}-disable */
+                    /* {prefix}-enable no-debugger */
+                    debugger; // {prefix}-disable-line
+                    "
+                )
+            },
+            |comments, directives| {
+                let code_start = comments[1].content_span().end + 21;
+                assert!(directives.contains("no-console", Span::sized(code_start, 13)));
+                assert!(directives.contains("no-debugger", Span::sized(code_start + 15, 9)));
+                let last_debugger = Span::sized(comments[5].content_span().start - 12, 9);
+                assert!(directives.contains("no-debugger", last_debugger));
+
+                // `no-console` is already disabled by the `eslint-disable` region,
+                // the last comment still disables the re-enabled `no-debugger`
+                let unused = directives.collect_unused_disable_comments();
+                assert_eq!(unused.len(), 2);
+                assert_eq!(unused[0].span, comments[1].content_span());
+                assert_eq!(
+                    unused[0].r#type,
+                    RuleCommentType::Single(vec![RuleCommentRule {
+                        rule_name: "no-console".to_string(),
+                        name_span: Span::sized(comments[1].content_span().start + 26, 10),
+                    }])
+                );
+                assert_eq!(unused[1].span, comments[3].content_span());
+            },
+        );
+    }
+
+    #[test]
+    fn reenable_rule_in_disable_region() {
+        test_directives(
+            |prefix| {
+                format!(
+                    r"
+                    /* {prefix}-disable */
+                    console.log();
+                    /* {prefix}-enable no-console */
+                    console.log(); debugger;
+                    "
+                )
+            },
+            |comments, directives| {
+                let before = Span::sized(comments[0].content_span().end + 23, 13);
+                let after = Span::sized(comments[1].content_span().end + 23, 13);
+                assert!(directives.contains("no-console", before));
+                assert!(!directives.contains("no-console", after));
+                assert!(directives.contains("no-debugger", Span::sized(after.end + 2, 9)));
+                assert!(directives.unused_enable_comments().is_empty());
+            },
+        );
+    }
+
     #[test]
     fn next_line_span_of_line_comment() {
         test_directive_span("// eslint-disable-next-line max-params", 38, 38);
@@ -1325,4 +1987,196 @@ function test() {
             "eslint-disable-next-line should NOT suppress diagnostics on lines after the next line"
         );
     }
+
+    #[test]
+    fn unused_inline_configs() {
+        test_directives(
+            |prefix| {
+                format!(
+                    r"
+                    /* {prefix}-disable unknown-rule */
+                    // {prefix}-disable-next-line no-debugger, no-console
+                    debugger;
+                    // {prefix}-disable-next-line no-debugger
+                    debugger;
+                    // {prefix}-disable-next-line
+                    debugger;
+                    "
+                )
+            },
+            |comments, directives| {
+                let unused = directives.collect_unused_inline_configs(|name| name == "no-debugger");
+
+                assert_eq!(unused.len(), 2);
+
+                // every rule in the comment is unknown, so the whole comment is unused
+                assert_eq!(unused[0].span, comments[0].content_span());
+                assert_eq!(unused[0].r#type, RuleCommentType::All);
+
+                // only `no-console` is unused
+                assert_eq!(unused[1].span, comments[1].content_span());
+                let RuleCommentType::Single(rules) = &unused[1].r#type else {
+                    panic!("expected only some rules of the comment to be unused");
+                };
+                assert_eq!(rules.len(), 1);
+                assert_eq!(rules[0].rule_name, "no-console");
+            },
+        );
+    }
+
+    #[test]
+    fn narrowable_file_disables() {
+        let allocator = Allocator::default();
+        let build = |source_text: &'static str| {
+            let semantic = process_source(&allocator, source_text);
+            DisableDirectivesBuilder::new().build(semantic.source_text(), semantic.comments())
+        };
+        let span_of = |source_text: &str, text: &str, nth: usize| {
+            let (start, _) = source_text.match_indices(text).nth(nth).unwrap();
+            Span::sized(u32::try_from(start).unwrap(), u32::try_from(text.len()).unwrap())
+        };
+
+        let source_text = "/* eslint-disable */\nconsole.log();\nconsole.log();\ndebugger;\n";
+        let directives = build(source_text);
+        assert!(directives.contains("no-console", span_of(source_text, "console.log()", 0)));
+        assert!(directives.contains("no-console", span_of(source_text, "console.log()", 1)));
+        assert!(directives.contains("no-debugger", span_of(source_text, "debugger", 0)));
+
+        let file_disables = directives.collect_narrowable_file_disables();
+        assert_eq!(file_disables.len(), 1);
+        let file_disable = &file_disables[0];
+        assert_eq!(file_disable.span, Span::new(2, 18));
+        assert_eq!(
+            file_disable.suppressed,
+            [("no-console".to_string(), 2), ("no-debugger".to_string(), 1)]
+        );
+        assert_eq!(file_disable.suppressed_count(), 3);
+        assert_eq!(
+            file_disable.message(),
+            "eslint-disable directive for the whole file only suppresses 3 problems (no-console: 2, no-debugger: 1)."
+        );
+        let fix = file_disable.create_fix();
+        assert_eq!(fix.span, Span::empty(17));
+        assert_eq!(fix.content, " no-console, no-debugger");
+        assert_eq!(
+            file_disable.diagnostic().help.as_deref(),
+            Some("Disable only these rules with `eslint-disable no-console, no-debugger`.")
+        );
+        // used, so it is not reported as unused
+        assert!(directives.collect_unused_disable_comments().is_empty());
+
+        // rules of plugins are named with their plugin, as they are written in directives
+        let source_text = "/* eslint-disable */\nlink;\nlist;\nlet x;\n";
+        let directives = build(source_text);
+        assert!(directives.contains_rule("react", "jsx-key", span_of(source_text, "list", 0)));
+        assert!(directives.contains_rule(
+            "typescript",
+            "no-explicit-any",
+            span_of(source_text, "x", 0)
+        ));
+        assert!(directives.contains_rule(
+            "jsx_a11y",
+            "anchor-has-content",
+            span_of(source_text, "link", 0)
+        ));
+        let file_disables = directives.collect_narrowable_file_disables();
+        assert_eq!(
+            file_disables[0].suppressed,
+            [
+                ("@typescript-eslint/no-explicit-any".to_string(), 1),
+                ("jsx-a11y/anchor-has-content".to_string(), 1),
+                ("react/jsx-key".to_string(), 1),
+            ]
+        );
+        assert_eq!(
+            file_disables[0].create_fix().content,
+            " @typescript-eslint/no-explicit-any, jsx-a11y/anchor-has-content, react/jsx-key"
+        );
+        assert_eq!(
+            file_disables[0].diagnostic().help.as_deref(),
+            Some(
+                "Disable only these rules with `eslint-disable @typescript-eslint/no-explicit-any, jsx-a11y/anchor-has-content, react/jsx-key`."
+            )
+        );
+
+        // nothing is suppressed, it is reported as unused instead
+        let source_text = "/* oxlint-disable */\ndebugger;\n";
+        let directives = build(source_text);
+        assert!(directives.collect_narrowable_file_disables().is_empty());
+
+        // the directive is named as it is written
+        assert!(directives.contains("no-debugger", span_of(source_text, "debugger", 0)));
+        let file_disables = directives.collect_narrowable_file_disables();
+        assert_eq!(
+            file_disables[0].message(),
+            "oxlint-disable directive for the whole file only suppresses 1 problem (no-debugger: 1)."
+        );
+        assert_eq!(file_disables[0].create_fix().content, " no-debugger");
+
+        // not for the whole file
+        let source_text = "/* eslint-disable */\ndebugger;\n/* eslint-enable */\n";
+        let directives = build(source_text);
+        assert!(directives.contains("no-debugger", span_of(source_text, "debugger", 0)));
+        assert!(directives.collect_narrowable_file_disables().is_empty());
+
+        // too many rules to narrow the directive to
+        let source_text = "/* eslint-disable */\nw; x; y; z;\n";
+        let directives = build(source_text);
+        for rule_name in ["w", "x", "y", "z"] {
+            assert!(directives.contains(rule_name, span_of(source_text, rule_name, 0)));
+        }
+        assert!(directives.collect_narrowable_file_disables().is_empty());
+    }
+
+    #[test]
+    fn expiry_date() {
+        let date = ExpiryDate::parse("2024-02-29").unwrap();
+        assert_eq!(date.to_string(), "2024-02-29");
+        assert!(date < ExpiryDate::parse("2024-03-01").unwrap());
+        assert!(date > ExpiryDate::parse("2023-12-31").unwrap());
+
+        assert!(ExpiryDate::parse("2023-02-29").is_none());
+        assert!(ExpiryDate::parse("2025-13-01").is_none());
+        assert!(ExpiryDate::parse("2025-1-01").is_none());
+        assert!(ExpiryDate::parse("2025-01-01x").is_none());
+        assert!(ExpiryDate::parse("tomorrow").is_none());
+    }
+
+    #[test]
+    fn expired_directives() {
+        test_directives(
+            |prefix| {
+                format!(
+                    r"
+                    // {prefix}-disable-next-line no-debugger -- expires:2025-06-30 remove after the migration
+                    debugger;
+                    /* {prefix}-disable-line no-debugger -- reason expires:2025-07-01 */ debugger;
+                    // {prefix}-disable-next-line no-debugger -- expires:2025-06-31
+                    debugger;
+                    // {prefix}-disable-next-line no-debugger -- not expires:2000-01-01 yet
+                    debugger;
+                    // {prefix}-disable-next-line no-debugger expires:2000-01-01
+                    debugger;
+                    "
+                )
+            },
+            |comments, directives| {
+                let today = ExpiryDate::parse("2025-07-01").unwrap();
+                let expired = directives.collect_expired_comments(today);
+                // `expires:2025-07-01` expires after today, and the last comment has no description
+                assert_eq!(expired.len(), 3);
+                assert_eq!(expired[0].comment_span, comments[0].content_span());
+                assert_eq!(expired[0].expires, ExpiryDate::parse("2025-06-30"));
+                // `expires:2025-06-30`
+                assert_eq!(expired[0].span.size(), 18);
+                assert_eq!(expired[1].comment_span, comments[2].content_span());
+                assert_eq!(expired[1].expires, None);
+                assert_eq!(expired[2].comment_span, comments[3].content_span());
+
+                let expired =
+                    directives.collect_expired_comments(ExpiryDate::parse("2025-07-02").unwrap());
+                assert_eq!(expired.len(), 4);
+            },
+        );
+    }
 }
@@ -3,6 +3,9 @@ use std::{error::Error, fmt::Debug};
 use serde::Deserialize;
 
 use oxc_allocator::Allocator;
+use oxc_ast::ast::{IdentifierReference, Program};
+use oxc_ast_visit::Visit;
+use oxc_semantic::Scoping;
 
 pub type ExternalLinterLoadPluginCb = Box<
     dyn Fn(String, Option<String>) -> Result<PluginLoadResult, Box<dyn Error + Send + Sync>>
@@ -11,11 +14,48 @@ pub type ExternalLinterLoadPluginCb = Box<
 >;
 
 pub type ExternalLinterLintFileCb = Box<
-    dyn Fn(String, Vec<u32>, String, &Allocator) -> Result<Vec<LintFileResult>, String>
+    dyn for<'a> Fn(
+            String,
+            Vec<u32>,
+            String,
+            &'a Allocator,
+            &'a ExternalFileDataProvider<'a>,
+        ) -> Result<Vec<LintFileResult>, String>
         + Sync
         + Send,
 >;
 
+/// Computes the [`ExternalFileData`] which JS plugins request while linting a file.
+///
+/// The data is allocated in the `Allocator` of the file, so JS reads it from the buffer
+/// it already has, instead of it being copied.
+pub type ExternalFileDataProvider<'a> = dyn Fn(ExternalFileData) -> &'a [u32] + 'a;
+
+/// Data of the file being linted, which is only computed when a JS plugin requests it, as most
+/// rules do not need it.
+///
+/// Offsets are UTF-16 offsets into the source text of the file, as seen by JS.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum ExternalFileData {
+    /// Start offsets of the identifier references which do not resolve to any declared symbol,
+    /// in source order.
+    UnresolvedReferences = 0,
+}
+
+impl ExternalFileData {
+    /// Get the kind of data from the number JS sends for it.
+    pub fn from_raw(raw: u32) -> Option<Self> {
+        match raw {
+            0 => Some(Self::UnresolvedReferences),
+            _ => None,
+        }
+    }
+}
+
+pub type ExternalLinterPreprocessCb =
+    Box<dyn Fn(u32, String, String) -> Result<Vec<PreprocessSection>, String> + Sync + Send>;
+
 #[derive(Clone, Debug, Deserialize)]
 pub enum PluginLoadResult {
     #[serde(rename_all = "camelCase")]
@@ -23,6 +63,10 @@ pub enum PluginLoadResult {
         name: String,
         offset: usize,
         rule_names: Vec<String>,
+        #[serde(default)]
+        processor_offset: usize,
+        #[serde(default)]
+        processor_names: Vec<String>,
     },
     Failure(String),
 }
@@ -44,17 +88,32 @@ pub struct JsFix {
     pub text: String,
 }
 
+/// A section of JS/TS code extracted from a file by a processor of a JS plugin.
+///
+/// Offsets are UTF-16 offsets into the source text of the file, as seen by JS.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreprocessSection {
+    pub start: u32,
+    pub end: u32,
+    /// Extension which determines how the section is parsed, e.g. `ts` or `jsx`.
+    /// Defaults to `js`.
+    pub extension: Option<String>,
+}
+
 pub struct ExternalLinter {
     pub(crate) load_plugin: ExternalLinterLoadPluginCb,
     pub(crate) lint_file: ExternalLinterLintFileCb,
+    pub(crate) preprocess: ExternalLinterPreprocessCb,
 }
 
 impl ExternalLinter {
     pub fn new(
         load_plugin: ExternalLinterLoadPluginCb,
         lint_file: ExternalLinterLintFileCb,
+        preprocess: ExternalLinterPreprocessCb,
     ) -> Self {
-        Self { load_plugin, lint_file }
+        Self { load_plugin, lint_file, preprocess }
     }
 }
 
@@ -63,3 +122,71 @@ impl Debug for ExternalLinter {
         f.debug_struct("ExternalLinter").finish()
     }
 }
+
+/// Compute [`ExternalFileData`] for a `Program` whose spans have already been converted to UTF-16.
+pub fn compute_external_file_data(
+    kind: ExternalFileData,
+    program: &Program<'_>,
+    scoping: &Scoping,
+) -> Vec<u32> {
+    match kind {
+        ExternalFileData::UnresolvedReferences => {
+            let mut collector = UnresolvedReferencesCollector { scoping, offsets: vec![] };
+            collector.visit_program(program);
+            collector.offsets.sort_unstable();
+            collector.offsets
+        }
+    }
+}
+
+struct UnresolvedReferencesCollector<'s> {
+    scoping: &'s Scoping,
+    offsets: Vec<u32>,
+}
+
+impl<'a> Visit<'a> for UnresolvedReferencesCollector<'_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        if self.scoping.get_reference(reference_id).symbol_id().is_none() {
+            self.offsets.push(ident.span.start);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use super::{ExternalFileData, compute_external_file_data};
+
+    #[test]
+    fn test_unresolved_references() {
+        let allocator = Allocator::default();
+        let source_text = "let a = 1; a; b; function f(c) { return c + d; } console.log(a);";
+        let program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+        let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+
+        let starts =
+            compute_external_file_data(ExternalFileData::UnresolvedReferences, &program, &scoping);
+        let names = starts
+            .iter()
+            .map(|&start| {
+                let rest = &source_text[start as usize..];
+                &rest[..rest.find(|c: char| !c.is_ascii_alphanumeric()).unwrap()]
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["b", "d", "console"]);
+    }
+
+    #[test]
+    fn test_external_file_data_from_raw() {
+        assert_eq!(
+            ExternalFileData::from_raw(ExternalFileData::UnresolvedReferences as u32),
+            Some(ExternalFileData::UnresolvedReferences)
+        );
+        assert_eq!(ExternalFileData::from_raw(u32::MAX), None);
+    }
+}
@@ -4,6 +4,8 @@ use rustc_hash::{FxHashMap, FxHashSet};
 
 use oxc_index::{IndexVec, define_index_type};
 
+use crate::interner::intern;
+
 define_index_type! {
     pub struct ExternalPluginId = u32;
 }
@@ -12,6 +14,10 @@ define_index_type! {
     pub struct ExternalRuleId = u32;
 }
 
+define_index_type! {
+    pub struct ExternalProcessorId = u32;
+}
+
 #[derive(Debug)]
 pub struct ExternalPluginStore {
     registered_plugin_paths: FxHashSet<String>,
@@ -19,6 +25,8 @@ pub struct ExternalPluginStore {
     plugins: IndexVec<ExternalPluginId, ExternalPlugin>,
     plugin_names: FxHashMap<String, ExternalPluginId>,
     rules: IndexVec<ExternalRuleId, ExternalRule>,
+    /// Plugin which each processor belongs to.
+    processors: IndexVec<ExternalProcessorId, ExternalPluginId>,
 
     // `true` for `oxlint`, `false` for language server
     is_enabled: bool,
@@ -37,6 +45,7 @@ impl ExternalPluginStore {
             plugins: IndexVec::default(),
             plugin_names: FxHashMap::default(),
             rules: IndexVec::default(),
+            processors: IndexVec::default(),
             is_enabled,
         }
     }
@@ -71,9 +80,11 @@ impl ExternalPluginStore {
         let newly_inserted = self.registered_plugin_paths.insert(plugin_path);
         assert!(newly_inserted, "register_plugin: plugin already registered");
 
-        let plugin_id = self
-            .plugins
-            .push(ExternalPlugin { name: plugin_name.clone(), rules: FxHashMap::default() });
+        let plugin_id = self.plugins.push(ExternalPlugin {
+            name: intern(&plugin_name),
+            rules: FxHashMap::default(),
+            processors: FxHashMap::default(),
+        });
         self.plugin_names.insert(plugin_name, plugin_id);
 
         assert!(
@@ -84,11 +95,67 @@ impl ExternalPluginStore {
         );
 
         for rule_name in rule_names {
-            let rule_id = self.rules.push(ExternalRule { name: rule_name.clone(), plugin_id });
+            let rule_id = self.rules.push(ExternalRule { name: intern(&rule_name), plugin_id });
             self.plugins[plugin_id].rules.insert(rule_name, rule_id);
         }
     }
 
+    /// Register the processors of a plugin which was registered with [`Self::register_plugin`].
+    ///
+    /// # Panics
+    /// Panics if:
+    /// - No plugin named `plugin_name` is registered.
+    /// - `offset` does not equal the number of registered processors.
+    pub fn register_processors(
+        &mut self,
+        plugin_name: &str,
+        offset: usize,
+        processor_names: Vec<String>,
+    ) {
+        let plugin_id = *self
+            .plugin_names
+            .get(plugin_name)
+            .expect("register_processors: plugin is not registered");
+
+        assert!(
+            offset == self.processors.len(),
+            "register_processors: received offset {}, but processor table is currently {} long",
+            offset,
+            self.processors.len()
+        );
+
+        for processor_name in processor_names {
+            let processor_id = self.processors.push(plugin_id);
+            self.plugins[plugin_id].processors.insert(processor_name, processor_id);
+        }
+    }
+
+    /// Look up a processor by its name in config, e.g. `markdown/markdown`.
+    ///
+    /// # Errors
+    /// Returns an error if the name is not of the form `plugin/processor`,
+    /// or if the plugin or processor could not be found.
+    pub fn lookup_processor_id(
+        &self,
+        name: &str,
+    ) -> Result<ExternalProcessorId, ExternalRuleLookupError> {
+        // Plugin names may be scoped (`@foo/bar`), so split at the last `/`
+        let Some((plugin_name, processor_name)) = name.rsplit_once('/') else {
+            return Err(ExternalRuleLookupError::InvalidProcessorName { name: name.to_string() });
+        };
+
+        let plugin_id = *self.plugin_names.get(plugin_name).ok_or_else(|| {
+            ExternalRuleLookupError::PluginNotFound { plugin: plugin_name.to_string() }
+        })?;
+
+        self.plugins[plugin_id].processors.get(processor_name).copied().ok_or_else(|| {
+            ExternalRuleLookupError::ProcessorNotFound {
+                plugin: plugin_name.to_string(),
+                processor: processor_name.to_string(),
+            }
+        })
+    }
+
     /// # Errors
     /// Returns an error if the plugin, or rule could not be found
     pub fn lookup_rule_id(
@@ -108,13 +175,14 @@ impl ExternalPluginStore {
         })
     }
 
+    /// Names are interned, so they can be attached to diagnostics without allocating.
     pub fn resolve_plugin_rule_names(
         &self,
         external_rule_id: ExternalRuleId,
-    ) -> (/* plugin name */ &str, /* rule name */ &str) {
+    ) -> (/* plugin name */ &'static str, /* rule name */ &'static str) {
         let external_rule = &self.rules[external_rule_id];
         let plugin = &self.plugins[external_rule.plugin_id];
-        (&plugin.name, &external_rule.name)
+        (plugin.name, external_rule.name)
     }
 }
 
@@ -122,6 +190,8 @@ impl ExternalPluginStore {
 pub enum ExternalRuleLookupError {
     PluginNotFound { plugin: String },
     RuleNotFound { plugin: String, rule: String },
+    ProcessorNotFound { plugin: String, processor: String },
+    InvalidProcessorName { name: String },
 }
 
 impl fmt::Display for ExternalRuleLookupError {
@@ -133,6 +203,12 @@ impl fmt::Display for ExternalRuleLookupError {
             ExternalRuleLookupError::RuleNotFound { plugin, rule } => {
                 write!(f, "Rule '{rule}' not found in plugin '{plugin}'")
             }
+            ExternalRuleLookupError::ProcessorNotFound { plugin, processor } => {
+                write!(f, "Processor '{processor}' not found in plugin '{plugin}'")
+            }
+            ExternalRuleLookupError::InvalidProcessorName { name } => {
+                write!(f, "Processor '{name}' must be of the form 'plugin/processor'")
+            }
         }
     }
 }
@@ -141,12 +217,13 @@ impl std::error::Error for ExternalRuleLookupError {}
 
 #[derive(Debug)]
 struct ExternalPlugin {
-    name: String,
+    name: &'static str,
     rules: FxHashMap<String, ExternalRuleId>,
+    processors: FxHashMap<String, ExternalProcessorId>,
 }
 
 #[derive(Debug)]
 struct ExternalRule {
-    name: String,
+    name: &'static str,
     plugin_id: ExternalPluginId,
 }
@@ -252,8 +252,9 @@ impl RuleFix {
             }
             _ => self.message,
         };
-        let mut fix = self.fix.normalize_fixes(source_text);
+        let mut fix = self.fix.normalize_fixes(source_text).minimize(source_text);
         fix.message = message;
+        fix.kind = self.kind;
         fix
     }
 
@@ -294,6 +295,9 @@ pub struct Fix {
     /// editors via code actions.
     pub message: Option<Cow<'static, str>>,
     pub span: Span,
+    /// Whether this is a safe fix, a suggestion, or a dangerous fix or suggestion. Editors use it
+    /// to tell safe fixes apart from fixes which may change the meaning of the code.
+    pub kind: FixKind,
 }
 
 impl Default for Fix {
@@ -304,17 +308,17 @@ impl Default for Fix {
 
 impl Fix {
     pub const fn delete(span: Span) -> Self {
-        Self { content: Cow::Borrowed(""), message: None, span }
+        Self { content: Cow::Borrowed(""), message: None, span, kind: FixKind::SafeFix }
     }
 
     pub fn new<T: Into<Cow<'static, str>>>(content: T, span: Span) -> Self {
-        Self { content: content.into(), message: None, span }
+        Self { content: content.into(), message: None, span, kind: FixKind::SafeFix }
     }
 
     /// Creates a [`Fix`] that doesn't change the source code.
     #[inline]
     pub const fn empty() -> Self {
-        Self { content: Cow::Borrowed(""), message: None, span: SPAN }
+        Self { content: Cow::Borrowed(""), message: None, span: SPAN, kind: FixKind::SafeFix }
     }
 
     #[must_use]
@@ -322,6 +326,50 @@ impl Fix {
         self.message = Some(message.into());
         self
     }
+
+    #[must_use]
+    pub fn with_kind(mut self, kind: FixKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Shrink this fix to the text it changes, by trimming the text its content has in common
+    /// with `source_text` at the start and end of its span.
+    ///
+    /// Editors apply smaller edits without moving the cursors around them, e.g. replacing `==`
+    /// in `a == b` with `===` becomes inserting `=`.
+    /// The fix is returned as is if its span is not a valid range of `source_text`.
+    #[must_use]
+    pub fn minimize(mut self, source_text: &str) -> Self {
+        let Some(original) = source_text.get(self.span.start as usize..self.span.end as usize)
+        else {
+            return self;
+        };
+        let prefix_len = original
+            .chars()
+            .zip(self.content.chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(c, _)| c.len_utf8())
+            .sum::<usize>();
+        let suffix_len = original[prefix_len..]
+            .chars()
+            .rev()
+            .zip(self.content[prefix_len..].chars().rev())
+            .take_while(|(a, b)| a == b)
+            .map(|(c, _)| c.len_utf8())
+            .sum::<usize>();
+        if prefix_len == 0 && suffix_len == 0 {
+            return self;
+        }
+        let content = &self.content[prefix_len..self.content.len() - suffix_len];
+        self.content = Cow::Owned(content.to_string());
+        #[expect(clippy::cast_possible_truncation)]
+        {
+            self.span =
+                Span::new(self.span.start + prefix_len as u32, self.span.end - suffix_len as u32);
+        }
+        self
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -547,12 +595,14 @@ impl CompositeFix {
         let mut last_pos = start;
         let mut output = String::new();
         let mut merged_fix_message = None;
+        let mut merged_fix_kind = FixKind::None;
 
         for fix in fixes {
-            let Fix { content, span, message } = fix;
+            let Fix { content, span, message, kind } = fix;
             if let Some(message) = message {
                 merged_fix_message.get_or_insert(message);
             }
+            merged_fix_kind |= kind;
 
             // negative range or overlapping ranges is invalid
             if span.start > span.end {
@@ -578,7 +628,7 @@ impl CompositeFix {
 
         output.push_str(after);
 
-        let mut fix = Fix::new(output, Span::new(start, end));
+        let mut fix = Fix::new(output, Span::new(start, end)).with_kind(merged_fix_kind);
         if let Some(message) = merged_fix_message {
             fix = fix.with_message(message);
         }
@@ -664,6 +714,28 @@ mod test {
         assert!(!FixKind::None.can_apply(FixKind::DangerousFix));
     }
 
+    #[test]
+    fn test_minimize() {
+        let source_text = "a == b; 'héllo'";
+        let fix = Fix::new("a === b", Span::new(0, 6)).minimize(source_text);
+        assert_eq!(fix.span, Span::new(4, 4));
+        assert_eq!(fix.content, "=");
+
+        let fix = Fix::new("'hallo'", Span::new(8, 16)).minimize(source_text);
+        assert_eq!(fix.span, Span::new(10, 12));
+        assert_eq!(fix.content, "a");
+
+        let fix = Fix::new("b", Span::new(5, 6)).minimize(source_text);
+        assert_eq!(fix.span, Span::new(6, 6));
+        assert_eq!(fix.content, "");
+
+        let fix = Fix::new("c", Span::new(5, 6)).minimize(source_text);
+        assert_eq!(fix, Fix::new("c", Span::new(5, 6)));
+
+        let fix = Fix::new("c", Span::new(5, 100)).minimize(source_text);
+        assert_eq!(fix, Fix::new("c", Span::new(5, 100)));
+    }
+
     #[test]
     fn test_composite_push_on_none() {
         let f: CompositeFix = Fix::new("foo", Span::empty(4)).into();
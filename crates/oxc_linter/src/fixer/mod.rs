@@ -1,10 +1,18 @@
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use rustc_hash::FxHashMap;
+use serde::Serialize;
 
 use oxc_codegen::{Codegen, CodegenOptions};
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{OxcCode, OxcDiagnostic, Severity};
 use oxc_span::{GetSpan, SourceType, Span};
 
-use crate::LintContext;
+use crate::{LintContext, RuleOrigin};
 
 mod fix;
 pub use fix::{CompositeFix, Fix, FixKind, PossibleFixes, RuleFix};
@@ -215,8 +223,32 @@ pub struct FixResult<'a> {
     pub fixed: bool,
     pub fixed_code: Cow<'a, str>,
     pub messages: Vec<Message>,
+    /// Codes of the diagnostics whose fixes were applied, e.g. `eslint(no-debugger)`
+    pub fixed_codes: Vec<OxcCode>,
+}
+
+/// Fixes applied to a file, see [`FixedFilesMap`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct FixedFile {
+    /// Number of applied fixes, over all fix passes
+    pub fixes: usize,
+    /// Number of applied fixes by diagnostic code, e.g. `eslint(no-debugger)`
+    pub rules: BTreeMap<String, usize>,
+}
+
+impl FixedFile {
+    pub fn new(fixed_codes: &[OxcCode]) -> Self {
+        let mut rules = BTreeMap::new();
+        for code in fixed_codes {
+            *rules.entry(code.to_string()).or_default() += 1;
+        }
+        Self { fixes: fixed_codes.len(), rules }
+    }
 }
 
+/// Fixes applied to each file written by `--fix`, keyed by the path of the file.
+pub type FixedFilesMap = Arc<Mutex<FxHashMap<PathBuf, FixedFile>>>;
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Message {
     pub error: OxcDiagnostic,
@@ -224,6 +256,9 @@ pub struct Message {
     pub span: Span,
     fixed: bool,
     pub section_offset: u32,
+    /// The configuration which decided the severity of the rule reporting this message, `None`
+    /// for messages which are not reported by rules.
+    pub origin: Option<RuleOrigin>,
 }
 
 impl Message {
@@ -236,7 +271,7 @@ impl Message {
             .map(|span| Span::new(span.offset() as u32, (span.offset() + span.len()) as u32))
             .unwrap_or_default();
 
-        Self { error, span, fixes, fixed: false, section_offset: 0 }
+        Self { error, span, fixes, fixed: false, section_offset: 0, origin: None }
     }
 
     #[must_use]
@@ -287,6 +322,42 @@ impl GetSpan for Message {
     }
 }
 
+/// Fix stages of rules whose fixes depend on the fixes of rules of earlier stages, as
+/// `(scope, rule, stage)`. Rules which are not listed are in stage 0. See [`Fixer::with_fix_stages`].
+const FIX_STAGES: &[(&str, &str, u8)] = &[
+    // Fixes of other rules can remove the last usage of a variable or import,
+    // or the last usage of an import as a value.
+    ("eslint", "no-unused-vars", 1),
+    ("typescript-eslint", "consistent-type-imports", 1),
+    // How imports are written and ordered settles once unused imports were removed
+    // and type imports were marked.
+    ("eslint", "sort-imports", 2),
+    ("eslint-plugin-import", "consistent-type-specifier-style", 2),
+    ("typescript-eslint", "no-import-type-side-effects", 2),
+];
+
+/// The fix stage of the rule reporting a diagnostic with `code`, see [`FIX_STAGES`].
+fn fix_stage(code: &OxcCode) -> u8 {
+    FIX_STAGES
+        .iter()
+        .find(|(scope, rule, _)| {
+            code.scope.as_deref() == Some(*scope) && code.number.as_deref() == Some(*rule)
+        })
+        .map_or(0, |(_, _, stage)| *stage)
+}
+
+/// A part of a file which is linted on its own, e.g. a `<script>` block of a `.vue` file.
+///
+/// Fixes are applied to each section independently, relative to the start of the section.
+/// A fix which is not fully inside the section its message was reported in is never applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixSection {
+    /// Span of the section in the source text of the file.
+    pub span: Span,
+    /// Source type of the section, used to verify fixes produce valid code (in debug builds).
+    pub source_type: Option<SourceType>,
+}
+
 /// The fixer of the code.
 /// Note that our parser has handled the BOM, so we don't need to port the BOM test cases from `ESLint`.
 pub struct Fixer<'a> {
@@ -297,6 +368,15 @@ pub struct Fixer<'a> {
     // The behavior is oriented by `oxlint` where only one PossibleFixes is applied.
     fix_index: u8,
 
+    /// Sections of a file with multiple sources. Empty if the whole file is a single source.
+    sections: Vec<FixSection>,
+
+    /// Only fix messages with at least this severity. `None` fixes messages of any severity.
+    min_severity: Option<Severity>,
+
+    /// Only apply the fixes of the earliest fix stage, see [`Fixer::with_fix_stages`].
+    fix_stages: bool,
+
     #[cfg(debug_assertions)]
     source_type: Option<SourceType>,
 }
@@ -313,6 +393,9 @@ impl<'a> Fixer<'a> {
             source_text,
             messages,
             fix_index: 0,
+            sections: vec![],
+            min_severity: None,
+            fix_stages: false,
             #[cfg(debug_assertions)]
             source_type,
         }
@@ -324,6 +407,41 @@ impl<'a> Fixer<'a> {
         self
     }
 
+    /// Apply fixes to each of `sections` separately, instead of to the whole source text.
+    /// Messages are matched to a section by their [`Message::section_offset`].
+    #[must_use]
+    pub fn with_sections(mut self, mut sections: Vec<FixSection>) -> Self {
+        sections.sort_unstable_by_key(|section| section.span.start);
+        debug_assert!(
+            sections.windows(2).all(|pair| pair[0].span.end <= pair[1].span.start),
+            "Fix sections must not overlap: {sections:?}"
+        );
+        self.sections = sections;
+        self
+    }
+
+    /// Only apply fixes of messages with at least `min_severity`, e.g. only fix errors
+    /// and leave warnings as they are. Messages below it are kept unfixed.
+    #[must_use]
+    pub fn with_min_severity(mut self, min_severity: Option<Severity>) -> Self {
+        self.min_severity = min_severity;
+        self
+    }
+
+    /// Only apply the fixes of rules of the earliest [`FIX_STAGES`] with fixes, and keep the
+    /// messages of rules of later stages unfixed.
+    ///
+    /// Some rules report diagnostics depending on code which fixes of other rules change, e.g.
+    /// `no-unused-vars` reports imports whose last usage another fix removes. `--fix` lints the
+    /// fixed code again, so the fixes of these rules are applied in a later pass, once the fixes
+    /// they depend on were applied. Callers which do not lint the fixed code again should not
+    /// enable this, as the fixes of later stages would never be applied.
+    #[must_use]
+    pub fn with_fix_stages(mut self, fix_stages: bool) -> Self {
+        self.fix_stages = fix_stages;
+        self
+    }
+
     /// # Panics
     pub fn fix(mut self) -> FixResult<'a> {
         let source_text = self.source_text;
@@ -332,74 +450,166 @@ impl<'a> Fixer<'a> {
                 fixed: false,
                 fixed_code: Cow::Borrowed(source_text),
                 messages: self.messages,
+                fixed_codes: vec![],
             };
         }
 
-        self.messages.sort_unstable_by_key(|m| m.fixes.span());
-        let mut fixed = false;
-        let mut output = String::with_capacity(source_text.len());
-        let mut last_pos: u32 = 0;
+        let is_single_source = self.sections.is_empty();
+        let sections = if is_single_source {
+            #[cfg(debug_assertions)]
+            let source_type = self.source_type;
+            #[cfg(not(debug_assertions))]
+            let source_type = None;
+            #[expect(clippy::cast_possible_truncation)]
+            let span = Span::new(0, source_text.len() as u32);
+            vec![FixSection { span, source_type }]
+        } else {
+            std::mem::take(&mut self.sections)
+        };
+
+        // Sort by the span of the fix, and then of the diagnostic, so the same fixes are applied
+        // regardless of the order in which rules reported them.
+        self.messages.sort_by_key(|m| (m.fixes.span(), m.span));
 
-        // only keep messages that were not fixed
+        // Fixes to apply in each section, in order. Messages which cannot be fixed are kept.
+        let mut section_fixes: Vec<Vec<Message>> = sections.iter().map(|_| vec![]).collect();
         let mut filtered_messages = Vec::with_capacity(self.messages.len());
 
-        for mut m in self.messages {
-            let fix = match &m.fixes {
-                PossibleFixes::None => None,
-                PossibleFixes::Single(fix) => Some(fix),
-                // For multiple fixes, we take the first one as a representative fix.
-                // Applying all possible fixes at once is not possible in this context.
-                PossibleFixes::Multiple(multiple) => multiple.get(self.fix_index as usize),
-            };
-            let Some(Fix { content, span, .. }) = fix else {
-                filtered_messages.push(m);
-                continue;
-            };
-            let start = span.start;
-            let end = span.end;
-            debug_assert!(start <= end, "Negative range is invalid: {span:?}");
-            if start > end {
+        for m in self.messages {
+            if self.min_severity.is_some_and(|min_severity| m.error.severity < min_severity) {
                 filtered_messages.push(m);
                 continue;
             }
-            if start < last_pos {
+            let Some(fix) = Self::fix_to_apply(&m, self.fix_index) else {
                 filtered_messages.push(m);
                 continue;
+            };
+            debug_assert!(
+                fix.span.start <= fix.span.end,
+                "Negative range is invalid: {:?}",
+                fix.span
+            );
+            // In a file with multiple sources, a fix must stay inside the section it was reported in.
+            let section_index = if is_single_source {
+                Some(0)
+            } else {
+                sections.binary_search_by_key(&m.section_offset, |s| s.span.start).ok()
+            };
+            match section_index {
+                Some(index)
+                    if fix.span.start <= fix.span.end
+                        && sections[index].span.contains_inclusive(fix.span) =>
+                {
+                    section_fixes[index].push(m);
+                }
+                _ => filtered_messages.push(m),
             }
+        }
 
-            m.fixed = true;
-            fixed = true;
-            let offset = last_pos as usize;
-            output.push_str(&source_text[offset..start as usize]);
-            output.push_str(content);
-            last_pos = end;
+        // Fixes of later stages are applied in a later pass, after the fixes they depend on.
+        if self.fix_stages
+            && let Some(stage) =
+                section_fixes.iter().flatten().map(|m| fix_stage(&m.error.code)).min()
+        {
+            for messages in &mut section_fixes {
+                let (current, later) = std::mem::take(messages)
+                    .into_iter()
+                    .partition(|m| fix_stage(&m.error.code) == stage);
+                *messages = current;
+                filtered_messages.extend::<Vec<_>>(later);
+            }
         }
 
-        output.push_str(&source_text[last_pos as usize..]);
+        let mut fixed = false;
+        let mut fixed_codes = vec![];
+        let mut output = String::with_capacity(source_text.len());
+        let mut last_section_end = 0;
+
+        for (section, messages) in sections.iter().zip(section_fixes) {
+            let section_start = section.span.start;
+            let section_text = section.span.source_text(source_text);
+            // Source text between sections is never changed.
+            output.push_str(&source_text[last_section_end as usize..section_start as usize]);
+            #[cfg(debug_assertions)]
+            let section_output_start = output.len();
+            // Offset in `section_text` up to which the section has been copied to `output`.
+            let mut last_pos: u32 = 0;
+            let mut section_fixed = false;
+
+            for mut m in messages {
+                let fix = Self::fix_to_apply(&m, self.fix_index).unwrap();
+                let start = fix.span.start - section_start;
+                let end = fix.span.end - section_start;
+                if start < last_pos {
+                    filtered_messages.push(m);
+                    continue;
+                }
+
+                output.push_str(&section_text[last_pos as usize..start as usize]);
+                output.push_str(&fix.content);
+                last_pos = end;
+                m.fixed = true;
+                section_fixed = true;
+                fixed_codes.push(m.error.code.clone());
+            }
+
+            output.push_str(&section_text[last_pos as usize..]);
+            last_section_end = section.span.end;
+
+            if section_fixed {
+                fixed = true;
+                #[cfg(debug_assertions)]
+                if let Some(source_type) = section.source_type {
+                    Self::verify_fixed_code(
+                        section_text,
+                        &output[section_output_start..],
+                        source_type,
+                    );
+                }
+            }
+        }
+
+        output.push_str(&source_text[last_section_end as usize..]);
 
         filtered_messages.sort_unstable_by_key(GetSpan::span);
 
-        #[cfg(debug_assertions)]
-        if fixed && let Some(source_type) = self.source_type {
-            use oxc_allocator::Allocator;
-            use oxc_parser::{ParseOptions, Parser};
-
-            let allocator = Allocator::default();
-            let parse_result = Parser::new(&allocator, &output, source_type)
-                .with_options(ParseOptions {
-                    parse_regular_expression: true,
-                    allow_return_outside_function: true,
-                    ..ParseOptions::default()
-                })
-                .parse();
-            debug_assert!(
-                parse_result.errors.is_empty() && !parse_result.panicked,
-                "Linter fixer produced invalid syntax.\n\nInput code: \n```\n{source_text}\n```\n\nFixed code: \n```\n{output}\n```\n\nParse errors: {:?}",
-                parse_result.errors
-            );
+        FixResult {
+            fixed,
+            fixed_code: Cow::Owned(output),
+            messages: filtered_messages,
+            fixed_codes,
+        }
+    }
+
+    /// The fix of `m` which should be applied, if any.
+    fn fix_to_apply(m: &Message, fix_index: u8) -> Option<&Fix> {
+        match &m.fixes {
+            PossibleFixes::None => None,
+            PossibleFixes::Single(fix) => Some(fix),
+            // For multiple fixes, we take the first one as a representative fix.
+            // Applying all possible fixes at once is not possible in this context.
+            PossibleFixes::Multiple(multiple) => multiple.get(fix_index as usize),
         }
+    }
 
-        FixResult { fixed, fixed_code: Cow::Owned(output), messages: filtered_messages }
+    #[cfg(debug_assertions)]
+    fn verify_fixed_code(source_text: &str, output: &str, source_type: SourceType) {
+        use oxc_allocator::Allocator;
+        use oxc_parser::{ParseOptions, Parser};
+
+        let allocator = Allocator::default();
+        let parse_result = Parser::new(&allocator, output, source_type)
+            .with_options(ParseOptions {
+                parse_regular_expression: true,
+                allow_return_outside_function: true,
+                ..ParseOptions::default()
+            })
+            .parse();
+        debug_assert!(
+            parse_result.errors.is_empty() && !parse_result.panicked,
+            "Linter fixer produced invalid syntax.\n\nInput code: \n```\n{source_text}\n```\n\nFixed code: \n```\n{output}\n```\n\nParse errors: {:?}",
+            parse_result.errors
+        );
     }
 }
 
@@ -408,10 +618,12 @@ mod test {
     use std::borrow::Cow;
 
     use cow_utils::CowUtils;
-    use oxc_diagnostics::OxcDiagnostic;
+    use oxc_diagnostics::{OxcDiagnostic, Severity};
     use oxc_span::{SourceType, Span};
 
-    use super::{CompositeFix, Fix, FixResult, Fixer, Message, PossibleFixes};
+    use super::{
+        CompositeFix, Fix, FixKind, FixResult, FixSection, FixedFile, Fixer, Message, PossibleFixes,
+    };
 
     fn insert_at_end() -> OxcDiagnostic {
         OxcDiagnostic::warn("End")
@@ -466,23 +678,51 @@ mod test {
     }
 
     const TEST_CODE: &str = "var answer = 6 * 7;";
-    const INSERT_AT_END: Fix =
-        Fix { span: Span::new(19, 19), content: Cow::Borrowed("// end"), message: None };
-    const INSERT_AT_START: Fix =
-        Fix { span: Span::new(0, 0), content: Cow::Borrowed("// start"), message: None };
-    const INSERT_AT_MIDDLE: Fix =
-        Fix { span: Span::new(13, 13), content: Cow::Borrowed("5 *"), message: None };
-    const REPLACE_ID: Fix =
-        Fix { span: Span::new(4, 10), content: Cow::Borrowed("foo"), message: None };
-    const REPLACE_VAR: Fix =
-        Fix { span: Span::new(0, 3), content: Cow::Borrowed("let"), message: None };
-    const REPLACE_NUM: Fix =
-        Fix { span: Span::new(13, 14), content: Cow::Borrowed("5"), message: None };
+    const INSERT_AT_END: Fix = Fix {
+        span: Span::new(19, 19),
+        content: Cow::Borrowed("// end"),
+        message: None,
+        kind: FixKind::SafeFix,
+    };
+    const INSERT_AT_START: Fix = Fix {
+        span: Span::new(0, 0),
+        content: Cow::Borrowed("// start"),
+        message: None,
+        kind: FixKind::SafeFix,
+    };
+    const INSERT_AT_MIDDLE: Fix = Fix {
+        span: Span::new(13, 13),
+        content: Cow::Borrowed("5 *"),
+        message: None,
+        kind: FixKind::SafeFix,
+    };
+    const REPLACE_ID: Fix = Fix {
+        span: Span::new(4, 10),
+        content: Cow::Borrowed("foo"),
+        message: None,
+        kind: FixKind::SafeFix,
+    };
+    const REPLACE_VAR: Fix = Fix {
+        span: Span::new(0, 3),
+        content: Cow::Borrowed("let"),
+        message: None,
+        kind: FixKind::SafeFix,
+    };
+    const REPLACE_NUM: Fix = Fix {
+        span: Span::new(13, 14),
+        content: Cow::Borrowed("5"),
+        message: None,
+        kind: FixKind::SafeFix,
+    };
     const REMOVE_START: Fix = Fix::delete(Span::new(0, 4));
     const REMOVE_MIDDLE: Fix = Fix::delete(Span::new(5, 10));
     const REMOVE_END: Fix = Fix::delete(Span::new(14, 18));
-    const REVERSE_RANGE: Fix =
-        Fix { span: Span::new(3, 0), content: Cow::Borrowed(" "), message: None };
+    const REVERSE_RANGE: Fix = Fix {
+        span: Span::new(3, 0),
+        content: Cow::Borrowed(" "),
+        message: None,
+        kind: FixKind::SafeFix,
+    };
 
     fn get_fix_result(messages: Vec<Message>) -> FixResult<'static> {
         Fixer::new(TEST_CODE, messages, Some(SourceType::default())).fix()
@@ -826,6 +1066,135 @@ mod test {
         assert_fixes_merged(fixes, &Fix::new("baz\nqux", Span::new(0, 7)), source_text);
     }
 
+    #[test]
+    fn fix_only_at_or_above_min_severity() {
+        let source_text = "var a = 1; var b = 2;";
+        let messages = || {
+            vec![
+                create_message(
+                    OxcDiagnostic::error("error"),
+                    PossibleFixes::Single(Fix::new("let", Span::new(0, 3))),
+                ),
+                create_message(
+                    OxcDiagnostic::warn("warning"),
+                    PossibleFixes::Single(Fix::new("let", Span::new(11, 14))),
+                ),
+            ]
+        };
+
+        let result = Fixer::new(source_text, messages(), None)
+            .with_min_severity(Some(Severity::Error))
+            .fix();
+        assert_eq!(result.fixed_code, "let a = 1; var b = 2;");
+        assert_eq!(result.messages.len(), 1);
+        assert_eq!(result.messages[0].error.severity, Severity::Warning);
+
+        let result = Fixer::new(source_text, messages(), None)
+            .with_min_severity(Some(Severity::Warning))
+            .fix();
+        assert_eq!(result.fixed_code, "let a = 1; let b = 2;");
+        assert!(result.messages.is_empty());
+    }
+
+    #[test]
+    fn fix_stages() {
+        let source_text = "import a from 'a'; debugger; a;";
+        let messages = || {
+            vec![
+                create_message(
+                    OxcDiagnostic::warn("unused").with_error_code("eslint", "no-unused-vars"),
+                    PossibleFixes::Single(Fix::delete(Span::new(0, 19))),
+                ),
+                create_message(
+                    OxcDiagnostic::warn("debugger").with_error_code("eslint", "no-debugger"),
+                    PossibleFixes::Single(Fix::delete(Span::new(19, 29))),
+                ),
+            ]
+        };
+
+        // `no-unused-vars` depends on the fixes of other rules, so it is fixed in a later pass.
+        let result = Fixer::new(source_text, messages(), None).with_fix_stages(true).fix();
+        assert_eq!(result.fixed_code, "import a from 'a'; a;");
+        assert_eq!(result.messages.len(), 1);
+        assert!(!result.messages[0].fixes.is_empty());
+
+        // Fixes of later stages are applied once no fixes of earlier stages are left.
+        let result =
+            Fixer::new(source_text, messages()[..1].to_vec(), None).with_fix_stages(true).fix();
+        assert_eq!(result.fixed_code, "debugger; a;");
+
+        let result = Fixer::new(source_text, messages(), None).fix();
+        assert_eq!(result.fixed_code, "a;");
+    }
+
+    const SECTIONS_CODE: &str = "<script>debugger;</script>\n<script setup>var a = 1;</script>";
+
+    fn sections() -> Vec<FixSection> {
+        let ts = Some(SourceType::ts());
+        vec![
+            FixSection { span: Span::new(8, 17), source_type: ts },
+            FixSection { span: Span::new(41, 51), source_type: ts },
+        ]
+    }
+
+    fn section_message(section_offset: u32, fix: Fix) -> Message {
+        create_message(OxcDiagnostic::warn("section"), PossibleFixes::Single(fix))
+            .with_section_offset(section_offset)
+    }
+
+    #[test]
+    fn fix_all_sections() {
+        let messages = vec![
+            section_message(41, Fix::new("let", Span::new(41, 44))),
+            section_message(8, Fix::delete(Span::new(8, 17))),
+            section_message(41, Fix::new("2", Span::new(49, 50))),
+        ];
+        // Sections are sorted by the fixer, so the order they are given in does not matter.
+        let mut sections = sections();
+        sections.reverse();
+
+        let result = Fixer::new(SECTIONS_CODE, messages, None).with_sections(sections).fix();
+        assert!(result.fixed);
+        assert_eq!(result.fixed_code, "<script></script>\n<script setup>let a = 2;</script>");
+        assert!(result.messages.is_empty());
+    }
+
+    #[test]
+    fn fix_outside_of_section_is_not_applied() {
+        let messages = vec![
+            // Crosses the end of the first section
+            section_message(8, Fix::delete(Span::new(8, 26))),
+            // Is in the second section, but reported in the first section
+            section_message(8, Fix::new("let", Span::new(41, 44))),
+            // Reported in a section which does not exist
+            section_message(3, Fix::delete(Span::new(8, 17))),
+            section_message(41, Fix::new("2", Span::new(49, 50))),
+        ];
+
+        let result = Fixer::new(SECTIONS_CODE, messages, None).with_sections(sections()).fix();
+        assert!(result.fixed);
+        assert_eq!(
+            result.fixed_code,
+            "<script>debugger;</script>\n<script setup>var a = 2;</script>"
+        );
+        assert_eq!(result.messages.len(), 3);
+    }
+
+    #[test]
+    fn overlapping_fixes_in_section_apply_first() {
+        let messages = vec![
+            section_message(41, Fix::new("const b = 3;", Span::new(41, 51))),
+            section_message(41, Fix::new("let", Span::new(41, 44))),
+        ];
+
+        let result = Fixer::new(SECTIONS_CODE, messages, None).with_sections(sections()).fix();
+        assert_eq!(
+            result.fixed_code,
+            "<script>debugger;</script>\n<script setup>let a = 1;</script>"
+        );
+        assert_eq!(result.messages.len(), 1);
+    }
+
     // Test that debug assertion catches invalid syntax after fixes
     #[test]
     #[cfg_attr(debug_assertions, should_panic(expected = "Linter fixer produced invalid syntax."))]
@@ -863,4 +1232,29 @@ mod test {
         assert!(result.fixed);
         assert_eq!(result.fixed_code, "let answer = 42;");
     }
+
+    #[test]
+    fn fixed_codes() {
+        let messages = vec![
+            create_message(
+                insert_at_start().with_error_code("eslint", "start"),
+                PossibleFixes::Single(INSERT_AT_START),
+            ),
+            create_message(
+                insert_at_end().with_error_code("eslint", "end"),
+                PossibleFixes::Single(INSERT_AT_END),
+            ),
+            create_message(
+                no_fix(Span::default()).with_error_code("eslint", "none"),
+                PossibleFixes::None,
+            ),
+        ];
+        let result = get_fix_result(messages);
+        let fixed_file = FixedFile::new(&result.fixed_codes);
+        assert_eq!(fixed_file.fixes, 2);
+        assert_eq!(
+            fixed_file.rules.into_iter().collect::<Vec<_>>(),
+            [("eslint(end)".to_string(), 1), ("eslint(start)".to_string(), 1)]
+        );
+    }
 }
@@ -1,8 +1,16 @@
-use std::{hash, path::Path};
+use std::{
+    hash,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
 
 use bitflags::bitflags;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
 
-use crate::ModuleRecord;
+use oxc_span::SourceType;
+
+use crate::{ModuleRecord, config::LintPlugins};
 
 bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -69,6 +77,48 @@ impl FrameworkFlags {
     pub const fn is_jest(self) -> bool {
         self.contains(Self::Jest)
     }
+
+    /// Names of the frameworks, e.g. `["react", "jest"]`.
+    pub fn names(self) -> Vec<&'static str> {
+        [
+            (Self::React, "react"),
+            (Self::Preact, "preact"),
+            (Self::NextOnly, "next"),
+            (Self::Vue, "vue"),
+            (Self::NuxtOnly, "nuxt"),
+            (Self::Angular, "angular"),
+            (Self::Svelte, "svelte"),
+            (Self::SvelteKitOnly, "sveltekit"),
+            (Self::Astro, "astro"),
+            (Self::Jest, "jest"),
+            (Self::Vitest, "vitest"),
+            (Self::OtherTest, "other-test"),
+        ]
+        .into_iter()
+        .filter(|(flag, _)| self.contains(*flag))
+        .map(|(_, name)| name)
+        .collect()
+    }
+
+    /// Add the test frameworks a file uses to the `hints` obtained at the project level.
+    ///
+    /// Test frameworks are only detected when a test plugin is enabled.
+    pub(crate) fn detect(
+        hints: Self,
+        plugins: LintPlugins,
+        path: &Path,
+        module_record: &ModuleRecord,
+    ) -> Self {
+        let mut frameworks = hints;
+        if plugins.has_test() {
+            let vitest_like = has_vitest_imports(module_record);
+            let jest_like = is_jestlike_file(path) || has_jest_imports(module_record);
+
+            frameworks.set(Self::Vitest, vitest_like);
+            frameworks.set(Self::Jest, jest_like);
+        }
+        frameworks
+    }
 }
 
 /// <https://jestjs.io/docs/configuration#testmatch-arraystring>
@@ -96,9 +146,62 @@ pub fn has_jest_imports(module_record: &ModuleRecord) -> bool {
     module_record.import_entries.iter().any(|entry| entry.module_request.name() == "@jest/globals")
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 
 pub enum FrameworkOptions {
     Default,  // default
     VueSetup, // context is inside `<script setup>`
 }
+
+/// Frameworks and source types detected for a linted file, see [`Linter::file_metadata`](crate::Linter::file_metadata).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FileMetadata {
+    /// Names of the detected frameworks, see [`FrameworkFlags::names`]
+    pub frameworks: Vec<&'static str>,
+    /// One for each script block of the file
+    pub sections: Vec<SectionMetadata>,
+}
+
+/// File metadata keyed by the path of the linted file.
+pub type FileMetadataMap = Arc<Mutex<FxHashMap<PathBuf, FileMetadata>>>;
+
+/// Source type of a script block of a linted file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SectionMetadata {
+    /// `module` or `script`
+    pub source_type: &'static str,
+    /// `javascript`, `typescript` or `typescriptDefinition`
+    pub language: &'static str,
+    pub jsx: bool,
+    /// `script` or `script setup` for blocks of single file components (e.g. `.vue` files),
+    /// omitted for JavaScript and TypeScript files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub section: Option<&'static str>,
+}
+
+impl SectionMetadata {
+    pub(crate) fn new(
+        source_type: SourceType,
+        framework_options: FrameworkOptions,
+        is_partial: bool,
+    ) -> Self {
+        let language = if source_type.is_typescript_definition() {
+            "typescriptDefinition"
+        } else if source_type.is_typescript() {
+            "typescript"
+        } else {
+            "javascript"
+        };
+        let section = is_partial.then_some(match framework_options {
+            FrameworkOptions::Default => "script",
+            FrameworkOptions::VueSetup => "script setup",
+        });
+        Self {
+            source_type: if source_type.is_module() { "module" } else { "script" },
+            language,
+            jsx: source_type.is_jsx(),
+            section,
+        }
+    }
+}
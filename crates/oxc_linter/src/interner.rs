@@ -0,0 +1,62 @@
+//! Process-wide interner for strings which are attached to many diagnostics,
+//! such as plugin and rule names coming from JS plugins and `tsgolint`.
+//!
+//! [`OxcDiagnostic::with_error_code`](oxc_diagnostics::OxcDiagnostic::with_error_code) takes
+//! `Cow<'static, str>`. Builtin rules pass `&'static str`s, but names which are only known at runtime
+//! would otherwise have to be converted to a `String` for every diagnostic produced.
+//! Interning them once gives a `&'static str` which can be shared by all threads for free.
+//!
+//! Interned strings are leaked, so only intern values from a small, bounded set.
+//! Never intern file paths or diagnostic messages.
+
+use std::sync::{LazyLock, RwLock};
+
+use rustc_hash::FxHashSet;
+
+static INTERNER: LazyLock<RwLock<FxHashSet<&'static str>>> = LazyLock::new(RwLock::default);
+
+/// Intern `s`, returning a `&'static str` with the same contents.
+///
+/// Interning the same string multiple times, from any thread, always returns the same pointer.
+///
+/// # Panics
+/// Panics if the interner's lock is poisoned.
+pub fn intern(s: &str) -> &'static str {
+    if let Some(interned) = INTERNER.read().unwrap().get(s) {
+        return interned;
+    }
+
+    let mut interner = INTERNER.write().unwrap();
+    // Another thread may have interned `s` between releasing the read lock and acquiring the write lock
+    if let Some(interned) = interner.get(s) {
+        return interned;
+    }
+    let interned: &'static str = Box::leak(Box::from(s));
+    interner.insert(interned);
+    interned
+}
+
+#[cfg(test)]
+mod test {
+    use std::ptr;
+
+    use super::intern;
+
+    #[test]
+    fn interns_once() {
+        let a = intern("my-plugin");
+        let b = intern(&String::from("my-plugin"));
+        assert_eq!(a, "my-plugin");
+        assert!(ptr::eq(a, b));
+        assert!(!ptr::eq(a, intern("my-other-plugin")));
+    }
+
+    #[test]
+    fn interns_across_threads() {
+        let names = std::thread::scope(|scope| {
+            let handles = [(); 4].map(|()| scope.spawn(|| intern("no-floating-promises")));
+            handles.map(|handle| handle.join().unwrap())
+        });
+        assert!(names.windows(2).all(|pair| ptr::eq(pair[0], pair[1])));
+    }
+}
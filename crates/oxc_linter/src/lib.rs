@@ -1,10 +1,14 @@
 #![expect(clippy::self_named_module_files)] // for rules.rs
 
 use std::{
+    collections::BTreeMap,
+    hash::{Hash, Hasher},
     mem,
     path::Path,
     ptr::{self, NonNull},
     rc::Rc,
+    sync::Arc,
+    time::{Duration, Instant},
 };
 
 use oxc_allocator::Allocator;
@@ -12,11 +16,13 @@ use oxc_ast::{ast::Program, ast_kind::AST_TYPE_MAX};
 use oxc_ast_macros::ast;
 use oxc_ast_visit::utf8_to_utf16::Utf8ToUtf16;
 use oxc_data_structures::box_macros::boxed_array;
-use oxc_diagnostics::OxcDiagnostic;
-use oxc_semantic::AstNode;
-use oxc_span::Span;
+use oxc_diagnostics::{OxcCode, OxcDiagnostic, Severity};
+use oxc_semantic::{AstNode, Semantic};
+use oxc_span::{SourceType, Span};
+use rustc_hash::FxHasher;
 
 mod ast_util;
+mod cancellation;
 mod config;
 mod context;
 mod disable_directives;
@@ -25,12 +31,18 @@ mod external_plugin_store;
 mod fixer;
 mod frameworks;
 mod globals;
+mod interner;
 mod module_graph_visitor;
 mod module_record;
 mod options;
+mod project_files;
 mod rule;
+#[cfg(feature = "ruledocs")]
+mod rule_docs;
 mod service;
+mod timing;
 mod tsgolint;
+mod type_facts;
 mod utils;
 
 pub mod loader;
@@ -43,6 +55,8 @@ mod generated {
     mod rule_runner_impls;
 }
 
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
 #[cfg(test)]
 mod tester;
 
@@ -50,39 +64,55 @@ mod lint_runner;
 
 pub use crate::config::plugins::normalize_plugin_name;
 pub use crate::disable_directives::{
-    DisableDirectives, DisableRuleComment, RuleCommentRule, RuleCommentType,
-    create_unused_directives_diagnostics,
+    DirectiveExpiry, DisableDirectives, DisableRuleComment, ExpiryDate, FileDisableComment,
+    RuleCommentRule, RuleCommentType, create_unused_directives_diagnostics,
+};
+#[cfg(feature = "ruledocs")]
+pub use crate::rule_docs::{
+    RuleDocExample, RuleDocSection, RuleDocs, RuleMetadata, rules_metadata,
 };
 pub use crate::{
+    cancellation::CancellationToken,
     config::{
-        Config, ConfigBuilderError, ConfigStore, ConfigStoreBuilder, ESLintRule, LintIgnoreMatcher,
-        LintPlugins, Oxlintrc, ResolvedLinterState,
+        CONFIG_VERSION, Config, ConfigBuilderError, ConfigMigration, ConfigStore,
+        ConfigStoreBuilder, DeprecatedName, DeprecationKind, ESLintRule, LintIgnoreMatcher,
+        LintPlugins, MovedKey, OxlintCategoriesOverride, OxlintLinterOptions, OxlintParserOptions,
+        OxlintPerFileThresholds, Oxlintrc, ResolvedLinterState, RuleOrigin, RuleOriginKind,
+        RuleOriginsMap, diagnostic_codes, rule_of_diagnostic_code,
     },
     context::{ContextSubHost, LintContext},
     external_linter::{
-        ExternalLinter, ExternalLinterLintFileCb, ExternalLinterLoadPluginCb, JsFix,
-        LintFileResult, PluginLoadResult,
+        ExternalFileData, ExternalFileDataProvider, ExternalLinter, ExternalLinterLintFileCb,
+        ExternalLinterLoadPluginCb, ExternalLinterPreprocessCb, JsFix, LintFileResult,
+        PluginLoadResult, PreprocessSection,
     },
-    external_plugin_store::{ExternalPluginStore, ExternalRuleId},
-    fixer::{Fix, FixKind, Message, PossibleFixes},
-    frameworks::FrameworkFlags,
+    external_plugin_store::{ExternalPluginStore, ExternalProcessorId, ExternalRuleId},
+    fixer::{Fix, FixKind, FixedFile, FixedFilesMap, Message, PossibleFixes},
+    frameworks::{FileMetadata, FileMetadataMap, FrameworkFlags, SectionMetadata},
     lint_runner::{DirectivesStore, LintRunner, LintRunnerBuilder},
     loader::LINTABLE_EXTENSIONS,
     module_record::ModuleRecord,
     options::LintOptions,
     options::{AllowWarnDeny, InvalidFilterKind, LintFilter, LintFilterKind},
+    project_files::{ProjectFile, ProjectFiles},
     rule::{RuleCategory, RuleFixMeta, RuleMeta, RuleRunFunctionsImplemented, RuleRunner},
-    service::{LintService, LintServiceOptions, OsFileSystem, RuntimeFileSystem},
+    service::{
+        FileDiagnostics, FileLimits, FileStatus, FileStatusMap, LintService, LintServiceOptions,
+        OsFileSystem, PARSE_ERROR_RULE_NAME, RuleResultCache, RuntimeFileSystem, current_file,
+    },
+    timing::{RuleTiming, RuleTimingsMap},
     tsgolint::TsGoLintState,
+    type_facts::{TypeFact, TypeFacts, TypeFactsMap},
     utils::{read_to_arena_str, read_to_string},
 };
 use crate::{
-    config::{LintConfig, OxlintEnv, OxlintGlobals, OxlintSettings},
-    context::ContextHost,
+    config::{LintConfig, OxlintEnv, OxlintGlobals, OxlintSettings, RuleOptions},
+    context::{ContextHost, plugin_name_to_prefix},
+    external_linter::compute_external_file_data,
     fixer::{CompositeFix, Fixer},
-    loader::LINT_PARTIAL_LOADER_EXTENSIONS,
+    loader::{JavaScriptSource, LINT_PARTIAL_LOADER_EXTENSIONS},
     rules::RuleEnum,
-    utils::iter_possible_jest_call_node,
+    service::{ContentHash, RuleResultKey},
 };
 
 #[cfg(target_pointer_width = "64")]
@@ -100,6 +130,14 @@ pub struct Linter {
     options: LintOptions,
     config: ConfigStore,
     external_linter: Option<ExternalLinter>,
+    /// Project files read by rules, see [`LintContext::read_project_file`]
+    project_files: Arc<ProjectFiles>,
+    /// Time spent running each rule, see [`Linter::with_rule_timings`]
+    rule_timings: Option<RuleTimingsMap>,
+    /// Diagnostics of each rule on unchanged files, see [`Linter::with_rule_result_cache`]
+    rule_result_cache: Option<Arc<RuleResultCache>>,
+    /// Date to compare directive expiry dates with, see [`Linter::with_today`]
+    today: Option<ExpiryDate>,
 }
 
 impl Linter {
@@ -108,7 +146,24 @@ impl Linter {
         config: ConfigStore,
         external_linter: Option<ExternalLinter>,
     ) -> Self {
-        Self { options, config, external_linter }
+        Self {
+            options,
+            config,
+            external_linter,
+            project_files: Arc::default(),
+            rule_timings: None,
+            rule_result_cache: None,
+            today: None,
+        }
+    }
+
+    /// Share the project files read by rules with other linters, e.g. to
+    /// [invalidate](ProjectFiles::invalidate) them when they change, or to only look them up in a
+    /// project root, see [`ProjectFiles::new`].
+    #[must_use]
+    pub fn with_project_files(mut self, project_files: Arc<ProjectFiles>) -> Self {
+        self.project_files = project_files;
+        self
     }
 
     /// Set the kind of auto fixes to apply.
@@ -118,12 +173,65 @@ impl Linter {
         self
     }
 
+    /// Only apply auto fixes of diagnostics with at least `min_severity`.
+    #[must_use]
+    pub fn with_fix_min_severity(mut self, min_severity: Option<Severity>) -> Self {
+        self.options.fix_min_severity = min_severity;
+        self
+    }
+
     #[must_use]
     pub fn with_report_unused_directives(mut self, report_config: Option<AllowWarnDeny>) -> Self {
         self.options.report_unused_directive = report_config;
         self
     }
 
+    /// Set the origin of the diagnostics of rules, see [`LintOptions::rule_origins`].
+    #[must_use]
+    pub fn with_rule_origins(mut self, yes: bool) -> Self {
+        self.options.rule_origins = yes;
+        self
+    }
+
+    /// Measure the time spent running each rule into `map`, see [`RuleTiming`].
+    ///
+    /// Rules are run one after the other on every file, including large files whose nodes are
+    /// otherwise visited once for all rules, so linting is slower than without timings. JS plugin
+    /// rules are not measured.
+    #[must_use]
+    pub fn with_rule_timings(mut self, map: RuleTimingsMap) -> Self {
+        self.rule_timings = Some(map);
+        self
+    }
+
+    /// Reuse the diagnostics of rules which ran on the same source text with the same
+    /// configuration before, see [`RuleResultCache`].
+    ///
+    /// The cache can be shared with linters created for other configurations, so that changing
+    /// the configuration of one rule does not run every other rule on unchanged files again.
+    /// Results are not reused when unused disable directives or inline configuration comments
+    /// are reported, because reusing them does not mark the directives which disabled them as used.
+    #[must_use]
+    pub fn with_rule_result_cache(mut self, cache: Arc<RuleResultCache>) -> Self {
+        self.rule_result_cache = Some(cache);
+        self
+    }
+
+    /// Compare `expires:YYYY-MM-DD` dates of disable directives with `today` instead of the
+    /// current date.
+    #[must_use]
+    pub fn with_today(mut self, today: ExpiryDate) -> Self {
+        self.today = Some(today);
+        self
+    }
+
+    /// Only report syntax errors, without running any rules. See [`LintOptions::syntax_only`].
+    #[must_use]
+    pub fn with_syntax_only(mut self, yes: bool) -> Self {
+        self.options.syntax_only = yes;
+        self
+    }
+
     pub(crate) fn options(&self) -> &LintOptions {
         &self.options
     }
@@ -148,11 +256,109 @@ impl Linter {
         context_sub_hosts: Vec<ContextSubHost<'a>>,
         allocator: &'a Allocator,
     ) -> Vec<Message> {
-        self.run_with_disable_directives(path, context_sub_hosts, allocator).0
+        self.run_with_disable_directives(
+            path,
+            context_sub_hosts,
+            allocator,
+            &CancellationToken::new(),
+        )
+        .0
+    }
+
+    /// Run `rules` on a [`Semantic`] built by the caller, without parsing the file again.
+    ///
+    /// Each rule is configured with the options paired with it, e.g.
+    /// `Some(json!([{ "allow": ["warn"] }]))` for `no-console`, as if they followed its severity in
+    /// a configuration file. `None` runs the rule with its default options.
+    ///
+    /// Settings, globals and environments are read from the configuration of `path`, but only
+    /// `rules` are run, and their diagnostics are reported as errors. Disable directives in the
+    /// source text are respected. Rules which need other modules (e.g. `import/no-cycle`) find no
+    /// imported modules.
+    ///
+    /// `semantic` must be built with [`SemanticBuilder::with_cfg`](oxc_semantic::SemanticBuilder::with_cfg).
+    /// It is only borrowed mutably to move it into the linter during the run, and is left unchanged.
+    /// `allocator` must be the allocator `semantic` was built in.
+    ///
+    /// # Panics
+    /// Panics if `semantic` was built without a control flow graph.
+    pub fn run_rules_on_semantic<'a>(
+        &self,
+        rules: &[(RuleEnum, Option<serde_json::Value>)],
+        semantic: &mut Semantic<'a>,
+        allocator: &'a Allocator,
+        path: &Path,
+    ) -> Vec<Message> {
+        let config = self.config.resolve(path).config;
+        let mut module_record = ModuleRecord::default();
+        module_record.has_module_syntax = semantic.source_type().is_module();
+        module_record.resolved_absolute_path = path.to_path_buf();
+        let sub_host = ContextSubHost::new(mem::take(semantic), Arc::new(module_record), 0);
+        let ctx_host = Rc::new(
+            ContextHost::new(path, vec![sub_host], self.options, config, allocator)
+                .with_project_files(Arc::clone(&self.project_files)),
+        );
+
+        let contexts = rules
+            .iter()
+            .map(|(rule, options)| (rule.read_json(options.clone().unwrap_or_default()), options))
+            .filter(|(rule, _)| {
+                !rule.is_tsgolint_rule()
+                    && rule.should_run_on_source(
+                        *ctx_host.source_type(),
+                        ctx_host.frameworks_options(),
+                    )
+                    && rule.should_run(&ctx_host)
+            })
+            .map(|(rule, options)| {
+                let options = options.clone().map(|options| Arc::new(RuleOptions::new(options)));
+                let ctx = Rc::clone(&ctx_host).spawn(&rule, AllowWarnDeny::Deny);
+                (rule, ctx.with_options(options))
+            })
+            .collect::<Vec<_>>();
+
+        let semantic_ref = ctx_host.semantic();
+        let should_run_on_jest_node =
+            ctx_host.plugins().has_test() && ctx_host.frameworks().is_test();
+        for (rule, ctx) in &contexts {
+            rule.run_once(ctx);
+            for node in semantic_ref.nodes() {
+                rule.run(node, ctx);
+            }
+            if should_run_on_jest_node {
+                for jest_node in ctx_host.possible_jest_nodes() {
+                    rule.run_on_jest_node(&jest_node, ctx);
+                }
+            }
+            if rule.run_info().is_run_on_regex_implemented() {
+                for regex in ctx_host.regexes() {
+                    rule.run_on_regex(regex, ctx);
+                }
+            }
+            if rule.run_info().is_run_on_symbol_implemented() {
+                let scoping = semantic_ref.scoping();
+                let symbol_filter = rule.symbol_filter();
+                for symbol_id in scoping.symbol_ids() {
+                    if symbol_filter.matches(scoping, symbol_id) {
+                        rule.run_on_symbol(symbol_id, ctx);
+                    }
+                }
+            }
+        }
+        drop(contexts);
+
+        let diagnostics = ctx_host.take_diagnostics();
+        if let Some(original) = Rc::try_unwrap(ctx_host).ok().and_then(ContextHost::into_semantic) {
+            *semantic = original;
+        }
+        diagnostics
     }
 
     /// Same as `run` but also returns the disable directives for the file
     ///
+    /// `cancellation_token` is checked before linting each script block, while running rules, and
+    /// before running JS plugins. If it is cancelled, no diagnostics or disable directives are returned.
+    ///
     /// # Panics
     /// Panics in debug mode if running with and without optimizations produces different diagnostic counts.
     pub fn run_with_disable_directives<'a>(
@@ -160,27 +366,68 @@ impl Linter {
         path: &Path,
         context_sub_hosts: Vec<ContextSubHost<'a>>,
         allocator: &'a Allocator,
+        cancellation_token: &CancellationToken,
     ) -> (Vec<Message>, Option<DisableDirectives>) {
-        let ResolvedLinterState { rules, config, external_rules } = self.config.resolve(path);
+        // Syntax errors are reported by the caller while parsing.
+        if self.options.syntax_only {
+            return (Vec::new(), None);
+        }
 
-        let mut ctx_host = Rc::new(ContextHost::new(path, context_sub_hosts, self.options, config));
+        let state = self.config.resolve(path);
+        let ResolvedLinterState {
+            rules, config, external_rules, rules_by_source, processor, ..
+        } = state.clone();
+
+        let report_unused_inline_configs = config
+            .linter_options
+            .report_unused_inline_configs
+            .filter(|severity| severity.is_warn_deny());
+        let max_warnings_per_file = config.per_file_thresholds.max_warnings_per_file;
+        let today = self.today.unwrap_or_else(ExpiryDate::today);
+
+        let rule_result_cache = self.rule_result_cache.as_deref().filter(|_| {
+            report_unused_inline_configs.is_none()
+                && !self.options.report_unused_directive.is_some_and(AllowWarnDeny::is_warn_deny)
+        });
+        let config_hash = rule_result_cache.map(|_| self.config_hash(&config));
+
+        let mut ctx_host = Rc::new(
+            ContextHost::new(path, context_sub_hosts, self.options, config, allocator)
+                .with_project_files(Arc::clone(&self.project_files)),
+        );
+        let content_hash =
+            rule_result_cache.map(|_| ContentHash::of_sections(ctx_host.source_texts()));
+        // `rules` is shadowed by the rules to run on each script block below.
+        let enabled_rules = &*rules;
 
         #[cfg(debug_assertions)]
         let mut current_diagnostic_index = 0;
 
+        // Files extracted by a processor are made of sections too, like partial loader files.
         let is_partial_loader_file = ctx_host
             .file_extension()
-            .is_some_and(|ext| LINT_PARTIAL_LOADER_EXTENSIONS.iter().any(|e| e == &ext));
+            .is_some_and(|ext| LINT_PARTIAL_LOADER_EXTENSIONS.iter().any(|e| e == &ext))
+            || (processor.is_some() && self.external_linter.is_some());
+        // JSON files are only linted by the rules of the `json` plugin, which only lint JSON files.
+        let is_json_file = loader::is_json_path(path);
 
         loop {
+            if cancellation_token.is_cancelled() {
+                return (Vec::new(), None);
+            }
+
             let semantic = ctx_host.semantic();
-            let rules = rules
+            let source_rules = rules_by_source.get_or_filter(
+                &rules,
+                *semantic.source_type(),
+                ctx_host.frameworks_options(),
+            );
+            let mut rules = source_rules
                 .iter()
                 .filter(|(rule, _)| {
-                    if rule.is_tsgolint_rule() {
+                    if (rule.plugin_name() == "json") != is_json_file {
                         return false;
                     }
-
                     // If only the `run` function is implemented, we can skip running the file entirely if the current
                     // file does not contain any of the relevant AST node types.
                     if rule.run_info() == RuleRunFunctionsImplemented::Run
@@ -192,13 +439,55 @@ impl Linter {
 
                     rule.should_run(&ctx_host)
                 })
-                .map(|(rule, severity)| (rule, Rc::clone(&ctx_host).spawn(rule, *severity)))
                 .collect::<Vec<_>>();
 
+            // Rules which need other files can report different diagnostics for the same source text.
+            let section_rule_result_cache =
+                rule_result_cache.zip(content_hash).zip(config_hash).filter(|_| {
+                    ctx_host.module_record().loaded_modules().is_empty()
+                        && ctx_host.type_facts().is_none()
+                });
+            // Keys of the rules to run, and diagnostics of the rules which ran before.
+            let mut rule_result_keys = Vec::new();
+            let mut cached_diagnostics = Vec::new();
+            if let Some(((cache, content_hash), config_hash)) = section_rule_result_cache {
+                let section = ctx_host.current_sub_host_index();
+                let keys = rules
+                    .iter()
+                    .map(|(rule, severity)| {
+                        Self::rule_result_key(&state, rule, *severity, section, config_hash)
+                    })
+                    .collect::<Vec<_>>();
+                let mut cached = cache.get(path, content_hash, keys.iter().copied());
+                let mut keys = keys.into_iter();
+                rules.retain(|_| {
+                    let key = keys.next().expect("one key per rule");
+                    if let Some(diagnostics) = cached.remove(&key) {
+                        cached_diagnostics.extend(diagnostics);
+                        false
+                    } else {
+                        rule_result_keys.push(key);
+                        true
+                    }
+                });
+            }
+
+            let rules = rules
+                .into_iter()
+                .map(|(rule, severity)| {
+                    let options = state.rule_options(rule.id()).cloned();
+                    (rule, Rc::clone(&ctx_host).spawn(rule, *severity).with_options(options))
+                })
+                .collect::<Vec<_>>();
+            let section_diagnostic_index = ctx_host.diagnostic_count();
+
             let should_run_on_jest_node =
                 ctx_host.plugins().has_test() && ctx_host.frameworks().is_test();
 
-            let execute_rules = |with_runtime_optimization: bool| {
+            let mut rule_durations =
+                self.rule_timings.as_ref().map(|_| vec![Duration::ZERO; rules.len()]);
+
+            let mut execute_rules = |with_runtime_optimization: bool| {
                 // IMPORTANT: We have two branches here for performance reasons:
                 //
                 // 1) Branch where we iterate over each node, then each rule
@@ -217,7 +506,9 @@ impl Linter {
                 // don't thrash the cache too much. Feel free to tweak based on benchmarking.
                 //
                 // See https://github.com/oxc-project/oxc/pull/6600 for more context.
-                if semantic.nodes().len() > 200_000 {
+                //
+                // Timings are measured per rule, so rules are always in the outer loop then.
+                if semantic.nodes().len() > 200_000 && rule_durations.is_none() {
                     // TODO: It seems like there is probably a more intelligent way to preallocate space here. This will
                     // likely incur quite a few unnecessary reallocs currently. We theoretically could compute this at
                     // compile-time since we know all of the rules and their AST node type information ahead of time.
@@ -251,7 +542,10 @@ impl Linter {
                     }
 
                     // Run rules on nodes
-                    for node in semantic.nodes() {
+                    for (index, node) in semantic.nodes().iter().enumerate() {
+                        if index % 4096 == 0 && cancellation_token.is_cancelled() {
+                            break;
+                        }
                         for (rule, ctx) in &rules_by_ast_type[node.kind().ty() as usize] {
                             rule.run(node, ctx);
                         }
@@ -261,7 +555,7 @@ impl Linter {
                     }
 
                     if should_run_on_jest_node {
-                        for jest_node in iter_possible_jest_call_node(semantic) {
+                        for jest_node in ctx_host.possible_jest_nodes() {
                             for (rule, ctx) in &rules {
                                 if !with_runtime_optimization
                                     || rule.run_info().is_run_on_jest_node_implemented()
@@ -271,8 +565,45 @@ impl Linter {
                             }
                         }
                     }
-                } else {
+
                     for (rule, ctx) in &rules {
+                        if !with_runtime_optimization
+                            || rule.run_info().is_run_on_regex_implemented()
+                        {
+                            for regex in ctx_host.regexes() {
+                                rule.run_on_regex(regex, ctx);
+                            }
+                        }
+                    }
+
+                    // Run rules on symbols, skipping symbols not matching the filter of the rule
+                    let rules_on_symbols = rules
+                        .iter()
+                        .filter(|(rule, _)| {
+                            !with_runtime_optimization
+                                || rule.run_info().is_run_on_symbol_implemented()
+                        })
+                        .map(|(rule, ctx)| (*rule, ctx, rule.symbol_filter()))
+                        .collect::<Vec<_>>();
+                    if !rules_on_symbols.is_empty() {
+                        let scoping = semantic.scoping();
+                        for symbol_id in scoping.symbol_ids() {
+                            for (rule, ctx, symbol_filter) in &rules_on_symbols {
+                                if symbol_filter.matches(scoping, symbol_id) {
+                                    rule.run_on_symbol(symbol_id, ctx);
+                                }
+                            }
+                        }
+                    }
+                } else {
+                    for (index, (rule, ctx)) in rules.iter().enumerate() {
+                        if cancellation_token.is_cancelled() {
+                            break;
+                        }
+                        let start = with_runtime_optimization
+                            .then_some(rule_durations.as_ref())
+                            .flatten()
+                            .map(|_| Instant::now());
                         let run_info = rule.run_info();
                         if !with_runtime_optimization || run_info.is_run_once_implemented() {
                             rule.run_once(ctx);
@@ -299,16 +630,41 @@ impl Linter {
                             && (!with_runtime_optimization
                                 || run_info.is_run_on_jest_node_implemented())
                         {
-                            for jest_node in iter_possible_jest_call_node(semantic) {
+                            for jest_node in ctx_host.possible_jest_nodes() {
                                 rule.run_on_jest_node(&jest_node, ctx);
                             }
                         }
+
+                        if !with_runtime_optimization || run_info.is_run_on_regex_implemented() {
+                            for regex in ctx_host.regexes() {
+                                rule.run_on_regex(regex, ctx);
+                            }
+                        }
+
+                        if !with_runtime_optimization || run_info.is_run_on_symbol_implemented() {
+                            let scoping = semantic.scoping();
+                            let symbol_filter = rule.symbol_filter();
+                            for symbol_id in scoping.symbol_ids() {
+                                if symbol_filter.matches(scoping, symbol_id) {
+                                    rule.run_on_symbol(symbol_id, ctx);
+                                }
+                            }
+                        }
+
+                        if let (Some(start), Some(rule_durations)) = (start, &mut rule_durations) {
+                            rule_durations[index] += start.elapsed();
+                        }
                     }
                 }
             };
 
             execute_rules(true);
 
+            // Rules may have stopped early, so the diagnostics are incomplete
+            if cancellation_token.is_cancelled() {
+                return (Vec::new(), None);
+            }
+
             #[cfg(debug_assertions)]
             {
                 let diagnostics_after_optimized = ctx_host.diagnostic_count();
@@ -346,11 +702,57 @@ impl Linter {
                 });
             }
 
+            if let Some(((cache, content_hash), _)) = section_rule_result_cache {
+                let reported = ctx_host.diagnostics_since(section_diagnostic_index);
+                let results = rules.iter().zip(rule_result_keys).map(|((rule, _), key)| {
+                    let scope = plugin_name_to_prefix(rule.plugin_name());
+                    let diagnostics = reported
+                        .iter()
+                        .filter(|message| {
+                            message.error.code.scope.as_deref() == Some(scope)
+                                && message.error.code.number.as_deref() == Some(rule.name())
+                        })
+                        .cloned()
+                        .collect();
+                    (key, diagnostics)
+                });
+                cache.insert(path, content_hash, results);
+                ctx_host.extend_diagnostics(cached_diagnostics);
+            }
+
+            if let (Some(rule_timings), Some(rule_durations)) = (&self.rule_timings, rule_durations)
+            {
+                let nodes = semantic.nodes().len();
+                let mut rule_timings = rule_timings.lock().expect("rule_timings mutex poisoned");
+                for ((rule, _), duration) in rules.iter().zip(rule_durations) {
+                    let timing = rule_timings.entry((rule.plugin_name(), rule.name())).or_default();
+                    timing.duration += duration;
+                    timing.nodes += nodes;
+                }
+            }
+
+            if let Some(severity) = report_unused_inline_configs {
+                self.report_unused_inline_configs(
+                    &ctx_host,
+                    enabled_rules,
+                    &external_rules,
+                    severity,
+                );
+            }
+
             // Drop `rules` to release its `Rc` clones of `ctx_host`, ensuring `run_external_rules`
             // can mutably access `ctx_host` via `Rc::get_mut` without panicking due to multiple references.
             drop(rules);
 
-            self.run_external_rules(&external_rules, path, &mut ctx_host, allocator);
+            if cancellation_token.is_cancelled() {
+                return (Vec::new(), None);
+            }
+
+            if !is_json_file {
+                self.run_external_rules(&external_rules, path, &mut ctx_host, allocator);
+            }
+
+            ctx_host.report_expired_directives(today);
 
             // Report unused directives is now handled differently with type-aware linting
 
@@ -372,7 +774,13 @@ impl Linter {
             }
         }
 
-        let diagnostics = ctx_host.take_diagnostics();
+        let mut diagnostics = ctx_host.take_diagnostics();
+        if self.options.rule_origins {
+            self.set_rule_origins(&mut diagnostics, &state);
+        }
+        if let Some(max_warnings) = max_warnings_per_file {
+            self.apply_max_warnings_per_file(&mut diagnostics, max_warnings);
+        }
         let disable_directives = if is_partial_loader_file {
             None
         } else {
@@ -382,6 +790,246 @@ impl Linter {
         (diagnostics, disable_directives)
     }
 
+    /// Hash of the options of the linter and the configuration of a file, other than its rules,
+    /// which the diagnostics of every rule can depend on.
+    fn config_hash(&self, config: &LintConfig) -> u64 {
+        let LintOptions {
+            fix,
+            fix_min_severity,
+            framework_hints,
+            report_unused_directive,
+            syntax_only,
+            keep_warnings_over_threshold,
+            rule_origins,
+        } = self.options;
+        let mut hasher = FxHasher::default();
+        fix.hash(&mut hasher);
+        fix_min_severity.map(|severity| severity as u8).hash(&mut hasher);
+        framework_hints.bits().hash(&mut hasher);
+        report_unused_directive.hash(&mut hasher);
+        (syntax_only, keep_warnings_over_threshold, rule_origins).hash(&mut hasher);
+        config.plugins.hash(&mut hasher);
+        serde_json::to_string(&(
+            &config.settings,
+            &config.env,
+            &config.globals,
+            &config.parser_options,
+        ))
+        .expect("configuration serializes to JSON")
+        .hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Identifies the diagnostics of `rule` on `section` of a file, see [`RuleResultCache`].
+    ///
+    /// Rules are configured from their options in the configuration file, so the id of a rule and
+    /// its options identify its configuration.
+    fn rule_result_key(
+        state: &ResolvedLinterState,
+        rule: &RuleEnum,
+        severity: AllowWarnDeny,
+        section: usize,
+        config_hash: u64,
+    ) -> RuleResultKey {
+        let mut hasher = FxHasher::default();
+        config_hash.hash(&mut hasher);
+        state.rule_options(rule.id()).map(|options| options.value()).hash(&mut hasher);
+        severity.hash(&mut hasher);
+        RuleResultKey { section, rule_id: rule.id(), config_hash: hasher.finish() }
+    }
+
+    /// Set the origin of the diagnostics of rules, from the configuration `state` they ran with.
+    fn set_rule_origins(&self, diagnostics: &mut [Message], state: &ResolvedLinterState) {
+        let mut origins = BTreeMap::<OxcCode, Option<RuleOrigin>>::new();
+        for message in diagnostics {
+            let code = &message.error.code;
+            let (Some(scope), Some(number)) = (&code.scope, &code.number) else {
+                continue;
+            };
+            let origin = origins.entry(code.clone()).or_insert_with(|| {
+                if let Some((rule, _)) = state.rules.iter().find(|(rule, _)| {
+                    rule.name() == number.as_ref()
+                        && plugin_name_to_prefix(rule.plugin_name()) == scope.as_ref()
+                }) {
+                    return Some(state.rule_origin(rule.id()));
+                }
+                state
+                    .external_rules
+                    .iter()
+                    .find(|(rule_id, _)| {
+                        self.config.resolve_plugin_rule_names(*rule_id)
+                            == (scope.as_ref(), number.as_ref())
+                    })
+                    .map(|(rule_id, _)| state.external_rule_origin(*rule_id))
+            });
+            message.origin.clone_from(origin);
+        }
+    }
+
+    /// Replace the warnings in `diagnostics` with a single error if there are more than
+    /// `max_warnings`, see [`OxlintPerFileThresholds`](crate::config::OxlintPerFileThresholds).
+    /// The warnings are kept with [`LintOptions::keep_warnings_over_threshold`].
+    fn apply_max_warnings_per_file(&self, diagnostics: &mut Vec<Message>, max_warnings: usize) {
+        let is_warning = |message: &Message| message.error.severity == Severity::Warning;
+        let warnings = diagnostics.iter().filter(|message| is_warning(message)).count();
+        if warnings <= max_warnings {
+            return;
+        }
+        if !self.options.keep_warnings_over_threshold {
+            diagnostics.retain(|message| !is_warning(message));
+        }
+        diagnostics.push(Message::new(
+            OxcDiagnostic::error(format!(
+                "File has too many warnings ({warnings}). Maximum allowed is {max_warnings}."
+            ))
+            .with_help("Fix the warnings, ignore the file if it is generated, or raise `perFileThresholds.max-warnings-per-file`.")
+            .with_error_code("oxc", "max-warnings-per-file")
+            // Label the start of the file, so that the diagnostic is reported with the path of the file.
+            .with_label(Span::empty(0)),
+            PossibleFixes::None,
+        ));
+    }
+
+    /// Frameworks and source types detected for the file at `path`, which has the script blocks
+    /// of `context_sub_hosts`.
+    ///
+    /// These are the frameworks rules see with [`LintContext::frameworks`], e.g. test rules only
+    /// run on files with a test framework.
+    pub fn file_metadata(
+        &self,
+        path: &Path,
+        context_sub_hosts: &[ContextSubHost<'_>],
+    ) -> FileMetadata {
+        let ResolvedLinterState { config, processor, .. } = self.config.resolve(path);
+        let frameworks = context_sub_hosts.first().map_or(self.options.framework_hints, |host| {
+            FrameworkFlags::detect(
+                self.options.framework_hints,
+                config.plugins,
+                path,
+                host.module_record(),
+            )
+        });
+        let is_partial = path
+            .extension()
+            .is_some_and(|ext| LINT_PARTIAL_LOADER_EXTENSIONS.iter().any(|e| *e == ext))
+            || (processor.is_some() && self.external_linter.is_some());
+        let sections = context_sub_hosts
+            .iter()
+            .map(|host| {
+                SectionMetadata::new(
+                    *host.semantic().source_type(),
+                    host.framework_options(),
+                    is_partial,
+                )
+            })
+            .collect();
+        FileMetadata { frameworks: frameworks.names(), sections }
+    }
+
+    /// Report disable comments in the current script block which name rules that are not enabled
+    /// for this file, and so can never suppress a diagnostic.
+    fn report_unused_inline_configs(
+        &self,
+        ctx_host: &ContextHost<'_>,
+        rules: &[(RuleEnum, AllowWarnDeny)],
+        external_rules: &[(ExternalRuleId, AllowWarnDeny)],
+        severity: AllowWarnDeny,
+    ) {
+        let external_rule_names = external_rules
+            .iter()
+            .map(|(external_rule_id, _)| {
+                let (plugin_name, rule_name) =
+                    self.config.resolve_plugin_rule_names(*external_rule_id);
+                format!("{plugin_name}/{rule_name}")
+            })
+            .collect::<Vec<_>>();
+
+        ctx_host.report_unused_inline_configs(severity.into(), |name| {
+            // Ignore the plugin prefix of builtin rules,
+            // so `@typescript-eslint/no-explicit-any` is enabled by `no-explicit-any`.
+            let rule_name = name.rsplit_once('/').map_or(name, |(_, rule_name)| rule_name);
+            rules.iter().any(|(rule, _)| rule.name() == rule_name)
+                || external_rule_names.iter().any(|external_rule_name| external_rule_name == name)
+        });
+    }
+
+    /// Whether `path` is a JSON file and the `json` plugin is enabled for it.
+    pub(crate) fn lints_json(&self, path: &Path) -> bool {
+        loader::is_json_path(path)
+            && self.config.resolve(path).config.plugins.contains(LintPlugins::JSON)
+    }
+
+    /// The processor of a JS plugin configured for `path`, if JS plugins are enabled.
+    ///
+    /// Resolving the configuration of `path` is not free, so callers resolve the processor once
+    /// per file and pass it on.
+    pub(crate) fn processor(&self, path: &Path) -> Option<ExternalProcessorId> {
+        self.external_linter.as_ref()?;
+        self.config.resolve(path).processor
+    }
+
+    /// The options to parse `path` with.
+    pub(crate) fn parser_options(&self, path: &Path) -> OxlintParserOptions {
+        self.config.resolve_parser_options(path)
+    }
+
+    /// Extract the JS/TS sections of `source_text` with the processor `processor_id`.
+    ///
+    /// Sections are slices of `source_text`, so diagnostics and fixes are mapped back to the
+    /// original file the same way as for sections of files loaded by [`PartialLoader`](loader::PartialLoader).
+    ///
+    /// # Errors
+    /// Returns an error if the processor throws, or returns a section which is out of bounds.
+    pub(crate) fn preprocess<'a>(
+        &self,
+        processor_id: ExternalProcessorId,
+        path: &Path,
+        source_text: &'a str,
+    ) -> Result<Vec<JavaScriptSource<'a>>, OxcDiagnostic> {
+        // `processor` only returns a processor when `external_linter` exists
+        let external_linter = self.external_linter.as_ref().unwrap();
+
+        let sections = (external_linter.preprocess)(
+            processor_id.raw(),
+            path.to_string_lossy().into_owned(),
+            source_text.to_string(),
+        )
+        .map_err(|err| {
+            let path = path.to_string_lossy();
+            let message = format!("Error running JS plugin processor.\nFile path: {path}\n{err}");
+            OxcDiagnostic::error(message)
+        })?;
+
+        // JS reports UTF-16 offsets
+        let span_converter = Utf8ToUtf16::new(source_text);
+        sections
+            .into_iter()
+            .map(|section| {
+                let mut span = Span::new(section.start, section.end);
+                span_converter.convert_span_back(&mut span);
+                let (start, end) = (span.start as usize, span.end as usize);
+                if start > end
+                    || !source_text.is_char_boundary(start)
+                    || !source_text.is_char_boundary(end)
+                {
+                    let path = path.to_string_lossy();
+                    return Err(OxcDiagnostic::error(format!(
+                        "JS plugin processor returned an invalid section {}..{}.\nFile path: {path}",
+                        section.start, section.end
+                    )));
+                }
+
+                let extension = section.extension.as_deref().unwrap_or("js");
+                let source_type = SourceType::from_extension(extension).map_err(|_| {
+                    OxcDiagnostic::error(format!(
+                        "JS plugin processor returned a section with unsupported extension `{extension}`."
+                    ))
+                })?;
+                Ok(JavaScriptSource::partial(&source_text[start..end], source_type, span.start))
+            })
+            .collect()
+    }
+
     fn run_external_rules<'a>(
         &self,
         external_rules: &[(ExternalRuleId, AllowWarnDeny)],
@@ -396,7 +1044,7 @@ impl Linter {
         // `external_linter` always exists when `external_rules` is not empty
         let external_linter = self.external_linter.as_ref().unwrap();
 
-        let (program_offset, source_text, span_converter) = {
+        let (program, scoping, span_converter) = {
             // Extract `Semantic` from `ContextHost`, and get a mutable reference to `Program`.
             //
             // It's not possible to obtain a `&mut Program` while `Semantic` exists, because `Semantic`
@@ -410,7 +1058,8 @@ impl Linter {
             // So create a new pointer to `Program` which inherits `data_end_ptr`'s provenance,
             // which does allow mutation.
             //
-            // We then drop `Semantic`, after which no references to any AST nodes remain.
+            // We then drop `Semantic` (keeping only its `Scoping`, which contains no references
+            // to AST nodes), after which no references to any AST nodes remain.
             // We can then safety convert the pointer to `&mut Program`.
             //
             // `Program` was created in `allocator`, and that allocator is a `FixedSizeAllocator`,
@@ -425,7 +1074,7 @@ impl Linter {
             let program_addr = NonNull::from(semantic.nodes().program()).addr();
             let mut program_ptr =
                 allocator.data_end_ptr().cast::<Program<'a>>().with_addr(program_addr);
-            drop(semantic);
+            let scoping = semantic.into_scoping();
             // SAFETY: Now that we've dropped `Semantic`, no references to any AST nodes remain,
             // so can get a mutable reference to `Program` without aliasing violations.
             let program = unsafe { program_ptr.as_mut() };
@@ -435,11 +1084,12 @@ impl Linter {
             span_converter.convert_program(program);
             span_converter.convert_comments(&mut program.comments);
 
-            // Get offset of `Program` within buffer (bottom 32 bits of pointer)
-            let program_offset = ptr::from_ref(program) as u32;
-
-            (program_offset, program.source_text, span_converter)
+            (&*program, scoping, span_converter)
         };
+        let source_text = program.source_text;
+
+        // Get offset of `Program` within buffer (bottom 32 bits of pointer)
+        let program_offset = ptr::from_ref(program) as u32;
 
         // Write offset of `Program` in metadata at end of buffer
         let metadata = RawTransferMetadata::new(program_offset);
@@ -466,6 +1116,10 @@ impl Linter {
             external_rules.iter().map(|(rule_id, _)| rule_id.raw()).collect(),
             settings_json,
             allocator,
+            &|kind| {
+                let data = compute_external_file_data(kind, program, &scoping);
+                allocator.alloc_slice_copy(&data)
+            },
         );
         match result {
             Ok(diagnostics) => {
@@ -505,11 +1159,13 @@ impl Linter {
                         });
 
                         if is_single {
-                            PossibleFixes::Single(fixes.into_iter().next().unwrap())
+                            PossibleFixes::Single(
+                                fixes.into_iter().next().unwrap().minimize(source_text),
+                            )
                         } else {
                             let fixes = fixes.collect::<Vec<_>>();
                             match CompositeFix::merge_fixes_fallible(fixes, source_text) {
-                                Ok(fix) => PossibleFixes::Single(fix),
+                                Ok(fix) => PossibleFixes::Single(fix.minimize(source_text)),
                                 Err(err) => {
                                     let path = path.to_string_lossy();
                                     let message = format!(
@@ -530,7 +1186,7 @@ impl Linter {
                     ctx_host.push_diagnostic(Message::new(
                         OxcDiagnostic::error(diagnostic.message)
                             .with_label(span)
-                            .with_error_code(plugin_name.to_string(), rule_name.to_string())
+                            .with_error_code(plugin_name, rule_name)
                             .with_severity(severity.into()),
                         fix,
                     ));
@@ -575,11 +1231,23 @@ impl RawTransferMetadata {
 
 #[cfg(test)]
 mod test {
-    use std::fs;
+    use std::{fs, path::Path, sync::Arc};
 
     use project_root::get_project_root;
+    use rustc_hash::FxHashMap;
+    use serde_json::json;
+
+    use oxc_allocator::Allocator;
+    use oxc_diagnostics::{OxcDiagnostic, Severity};
+    use oxc_parser::{ParseOptions, Parser};
+    use oxc_semantic::{SemanticBuilder, SymbolFlags};
+    use oxc_span::SourceType;
 
-    use crate::Oxlintrc;
+    use crate::{
+        AllowWarnDeny, ConfigStore, ConfigStoreBuilder, ContextSubHost, ExpiryDate,
+        ExternalPluginStore, LintOptions, Linter, Message, ModuleRecord, Oxlintrc, PossibleFixes,
+        RuleResultCache, rule::RuleSymbolFilter, rules::RULES, service::ContentHash,
+    };
 
     #[test]
     fn test_schema_json() {
@@ -593,4 +1261,274 @@ mod test {
             insta::assert_snapshot!(json);
         });
     }
+
+    #[test]
+    fn test_run_rules_on_semantic() {
+        let external_plugin_store = ExternalPluginStore::default();
+        let config = ConfigStoreBuilder::empty().build(&external_plugin_store).unwrap();
+        let config_store = ConfigStore::new(config, FxHashMap::default(), external_plugin_store);
+        let linter = Linter::new(LintOptions::default(), config_store, None);
+
+        let source_text =
+            "debugger;\n// eslint-disable-next-line no-debugger\ndebugger;\neval('x');";
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        let mut semantic = SemanticBuilder::new().with_cfg(true).build(&ret.program).semantic;
+        let node_count = semantic.nodes().len();
+
+        let rule = |name| RULES.iter().find(|rule| rule.name() == name).unwrap().clone();
+        let rules = [(rule("no-debugger"), None)];
+        let messages =
+            linter.run_rules_on_semantic(&rules, &mut semantic, &allocator, Path::new("test.js"));
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].error.code.to_string(), "eslint(no-debugger)");
+        assert_eq!(messages[0].span.start, 0);
+        // The semantic is given back unchanged
+        assert_eq!(semantic.nodes().len(), node_count);
+        assert_eq!(semantic.source_text(), source_text);
+
+        // Rules run with the options paired with them
+        let source_text = "console.log('x');\nconsole.warn('x');";
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        let mut semantic = SemanticBuilder::new().with_cfg(true).build(&ret.program).semantic;
+        let rules = [(rule("no-console"), Some(json!([{ "allow": ["warn"] }])))];
+        let messages =
+            linter.run_rules_on_semantic(&rules, &mut semantic, &allocator, Path::new("test.js"));
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].error.code.to_string(), "eslint(no-console)");
+        assert_eq!(messages[0].span.start, 0);
+    }
+
+    #[test]
+    fn test_rule_result_cache() {
+        let cache = Arc::new(RuleResultCache::new(10));
+        let linter = |rules: serde_json::Value| {
+            let oxlintrc: Oxlintrc = serde_json::from_value(
+                json!({ "categories": { "correctness": "off" }, "rules": rules }),
+            )
+            .unwrap();
+            let mut external_plugin_store = ExternalPluginStore::default();
+            let config =
+                ConfigStoreBuilder::from_oxlintrc(true, oxlintrc, None, &mut external_plugin_store)
+                    .unwrap()
+                    .build(&external_plugin_store)
+                    .unwrap();
+            let config_store =
+                ConfigStore::new(config, FxHashMap::default(), external_plugin_store);
+            Linter::new(LintOptions::default(), config_store, None)
+                .with_rule_result_cache(Arc::clone(&cache))
+        };
+        let path = Path::new("test.js");
+        let source_text = "debugger;\neval('x');";
+        let lint = |linter: &Linter| {
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+            let semantic = SemanticBuilder::new().with_cfg(true).build(&ret.program).semantic;
+            let module_record = Arc::new(ModuleRecord::new(path, &ret.module_record, &semantic));
+            let mut messages =
+                linter.run(path, vec![ContextSubHost::new(semantic, module_record, 0)], &allocator);
+            messages.sort_by_key(|message| message.error.code.to_string());
+            messages
+                .into_iter()
+                .map(|message| (message.error.code.to_string(), message.error.severity))
+                .collect::<Vec<_>>()
+        };
+
+        let first = linter(json!({ "no-debugger": "warn", "no-eval": "warn" }));
+        assert_eq!(
+            lint(&first),
+            [
+                ("eslint(no-debugger)".to_string(), Severity::Warning),
+                ("eslint(no-eval)".to_string(), Severity::Warning),
+            ]
+        );
+
+        // Replace the diagnostics of `no-eval`, to see that they are reused by the next linter,
+        // which only changes the severity of `no-debugger`.
+        let second = linter(json!({ "no-debugger": "error", "no-eval": "warn" }));
+        let state = second.config.resolve(path);
+        let no_eval = state.rules.iter().find(|(rule, _)| rule.name() == "no-eval").unwrap();
+        let config_hash = second.config_hash(&state.config);
+        let key = Linter::rule_result_key(&state, &no_eval.0, AllowWarnDeny::Warn, 0, config_hash);
+        let cached = Message::new(
+            OxcDiagnostic::warn("cached").with_error_code("eslint", "no-eval-cached"),
+            PossibleFixes::None,
+        );
+        cache.insert(path, ContentHash::of_sections([source_text]), [(key, vec![cached])]);
+
+        assert_eq!(
+            lint(&second),
+            [
+                ("eslint(no-debugger)".to_string(), Severity::Error),
+                ("eslint(no-eval-cached)".to_string(), Severity::Warning),
+            ]
+        );
+
+        // Other options of `no-eval` run it again.
+        let third = linter(
+            json!({ "no-debugger": "error", "no-eval": ["warn", { "allowIndirect": true }] }),
+        );
+        assert_eq!(
+            lint(&third),
+            [
+                ("eslint(no-debugger)".to_string(), Severity::Error),
+                ("eslint(no-eval)".to_string(), Severity::Warning),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expired_directives() {
+        let external_plugin_store = ExternalPluginStore::default();
+        let config = ConfigStoreBuilder::empty().build(&external_plugin_store).unwrap();
+        let config_store = ConfigStore::new(config, FxHashMap::default(), external_plugin_store);
+        let linter = Linter::new(LintOptions::default(), config_store, None)
+            .with_today(ExpiryDate::parse("2025-07-01").unwrap());
+
+        let path = Path::new("test.js");
+        let source_text = "// oxlint-disable-next-line no-debugger -- expires:2025-06-30\n\
+            debugger;\n\
+            // eslint-disable-line no-eval -- expires:2025-07-01\n\
+            /* eslint-disable no-console -- expires:2025-02-30 */";
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        let semantic = SemanticBuilder::new().with_cfg(true).build(&ret.program).semantic;
+        let module_record = Arc::new(ModuleRecord::new(path, &ret.module_record, &semantic));
+        let messages =
+            linter.run(path, vec![ContextSubHost::new(semantic, module_record, 0)], &allocator);
+
+        let messages = messages
+            .iter()
+            .map(|message| (message.error.code.to_string(), message.error.message.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            [
+                (
+                    "oxc(expired-disable-directive)".to_string(),
+                    "Expired oxlint-disable-next-line directive (expired on 2025-06-30).".to_string()
+                ),
+                (
+                    "oxc(invalid-directive-expiry)".to_string(),
+                    "Invalid expiry date in eslint-disable directive, expected `expires:YYYY-MM-DD`."
+                        .to_string()
+                ),
+            ]
+        );
+    }
+
+    /// Lint `source_text` with only `rules` enabled, e.g. `{ "no-debugger": "error" }`, and return
+    /// the codes and start offsets of the diagnostics.
+    fn lint_with_rules(rules: &serde_json::Value, source_text: &str) -> Vec<(String, u32)> {
+        let oxlintrc: Oxlintrc = serde_json::from_value(
+            json!({ "categories": { "correctness": "off" }, "rules": rules }),
+        )
+        .unwrap();
+        let mut external_plugin_store = ExternalPluginStore::default();
+        let config =
+            ConfigStoreBuilder::from_oxlintrc(true, oxlintrc, None, &mut external_plugin_store)
+                .unwrap()
+                .build(&external_plugin_store)
+                .unwrap();
+        let config_store = ConfigStore::new(config, FxHashMap::default(), external_plugin_store);
+        let linter = Linter::new(LintOptions::default(), config_store, None);
+
+        let path = Path::new("test.js");
+        let allocator = Allocator::default();
+        // Like the runtime, parse the patterns of regex literals.
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs())
+            .with_options(ParseOptions {
+                parse_regular_expression: true,
+                ..ParseOptions::default()
+            })
+            .parse();
+        let semantic = SemanticBuilder::new().with_cfg(true).build(&ret.program).semantic;
+        let module_record = Arc::new(ModuleRecord::new(path, &ret.module_record, &semantic));
+        let mut messages = linter
+            .run(path, vec![ContextSubHost::new(semantic, module_record, 0)], &allocator)
+            .into_iter()
+            .map(|message| (message.error.code.to_string(), message.span.start))
+            .collect::<Vec<_>>();
+        messages.sort_by_key(|(_, start)| *start);
+        messages
+    }
+
+    #[test]
+    fn test_run_on_regex() {
+        let source_text = r#"/\x01/;
+new RegExp("\\x02");
+RegExp("\\x03", "u");
+globalThis.RegExp(`\\x04`);
+new RegExp("[\\x05");
+new RegExp("\\x06", "z");
+function f(RegExp) { new RegExp("\\x07"); }
+const pattern = "\\x08";
+new RegExp(pattern);
+/(\x09/;"#;
+        let start_of = |needle: &str| u32::try_from(source_text.find(needle).unwrap()).unwrap();
+
+        // Each regex literal and `RegExp` constructor with a literal pattern is checked once.
+        // Patterns and flags which do not parse, e.g. `/(\x09/`, shadowed `RegExp`s and
+        // patterns in variables are skipped.
+        assert_eq!(
+            lint_with_rules(&json!({ "no-control-regex": "error" }), source_text),
+            [
+                ("eslint(no-control-regex)".to_string(), 0),
+                ("eslint(no-control-regex)".to_string(), start_of(r#""\\x02""#)),
+                ("eslint(no-control-regex)".to_string(), start_of(r#""\\x03""#)),
+                ("eslint(no-control-regex)".to_string(), start_of("`")),
+            ]
+        );
+        // Rules without `run_on_regex` are not called on regexes.
+        assert!(lint_with_rules(&json!({ "no-debugger": "error" }), source_text).is_empty());
+    }
+
+    #[test]
+    fn test_rule_symbol_filter() {
+        let source_text = "class A {}\nclass B {}\nB;\nfunction f() {}\nlet x = 1;\nx;";
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        let scoping = semantic.scoping();
+        let matching = |filter: RuleSymbolFilter| {
+            scoping
+                .symbol_ids()
+                .filter(|symbol_id| filter.matches(scoping, *symbol_id))
+                .map(|symbol_id| scoping.symbol_name(symbol_id))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(matching(RuleSymbolFilter::ALL), ["A", "B", "f", "x"]);
+        let classes = RuleSymbolFilter { flags: SymbolFlags::Class, unused_only: false };
+        assert_eq!(matching(classes), ["A", "B"]);
+        let classes_or_functions = RuleSymbolFilter {
+            flags: SymbolFlags::Class.union(SymbolFlags::Function),
+            unused_only: false,
+        };
+        assert_eq!(matching(classes_or_functions), ["A", "B", "f"]);
+        let unused = RuleSymbolFilter { flags: SymbolFlags::all(), unused_only: true };
+        assert_eq!(matching(unused), ["A", "f"]);
+        let unused_classes = RuleSymbolFilter { flags: SymbolFlags::Class, unused_only: true };
+        assert_eq!(matching(unused_classes), ["A"]);
+    }
+
+    #[test]
+    fn test_run_on_symbol() {
+        let source_text = "class A {}\nA = 0;\nlet B = 0;\nB = 1;\nlet undefined = 1;";
+        let start_of = |needle: &str| u32::try_from(source_text.find(needle).unwrap()).unwrap();
+
+        // `no-class-assign` is only called on classes, `no-shadow-restricted-names` on all symbols.
+        assert_eq!(
+            lint_with_rules(
+                &json!({ "no-class-assign": "error", "no-shadow-restricted-names": "error" }),
+                source_text
+            ),
+            [
+                ("eslint(no-class-assign)".to_string(), start_of("A {}")),
+                ("eslint(no-shadow-restricted-names)".to_string(), start_of("undefined")),
+            ]
+        );
+    }
 }
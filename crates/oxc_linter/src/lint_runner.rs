@@ -6,12 +6,14 @@ use std::{
 
 use rustc_hash::FxHashMap;
 
+use oxc_allocator::AllocatorPoolStats;
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, OxcDiagnostic};
 use oxc_span::Span;
 
 use crate::{
-    AllowWarnDeny, DisableDirectives, FixKind, LintService, LintServiceOptions, Linter, Message,
-    OsFileSystem, PossibleFixes, TsGoLintState,
+    AllowWarnDeny, CancellationToken, DisableDirectives, FileDiagnostics, FileMetadataMap,
+    FileStatusMap, FixKind, FixedFilesMap, LintService, LintServiceOptions, Linter, Message,
+    OsFileSystem, PossibleFixes, RuleOriginsMap, TsGoLintState, TypeFactsMap,
 };
 
 /// Unified runner that orchestrates both regular (oxc) and type-aware (tsgolint) linting
@@ -23,8 +25,14 @@ pub struct LintRunner {
     type_aware_linter: Option<TsGoLintState>,
     /// Shared disable directives coordinator
     directives_store: DirectivesStore,
+    /// Type information shared with native rules, if requested
+    type_facts_map: Option<TypeFactsMap>,
     /// Current working directory
     cwd: PathBuf,
+    /// No diagnostic is displayed
+    silent: bool,
+    /// Warnings are not displayed
+    quiet: bool,
 }
 
 /// Manages disable directives across all linting engines.
@@ -56,10 +64,8 @@ impl DirectivesStore {
     pub fn should_disable(&self, path: &Path, rule: &str, span: Span) -> bool {
         let map = self.map.lock().expect("DirectivesStore mutex poisoned in should_disable");
         if let Some(directives) = map.get(path) {
-            // Check with various rule name formats
-            directives.contains(rule, span)
-                || directives.contains(&format!("typescript-eslint/{rule}"), span)
-                || directives.contains(&format!("@typescript-eslint/{rule}"), span)
+            // Also matches `typescript-eslint/rule` and `@typescript-eslint/rule`
+            directives.contains_rule("typescript", rule, span)
         } else {
             false
         }
@@ -83,11 +89,18 @@ impl DirectivesStore {
         self.map.lock().expect("DirectivesStore mutex poisoned in get").get(path).cloned()
     }
 
-    /// Report unused disable directives
+    /// Report unused disable directives.
+    /// Files are only read to attach their source text if the diagnostics `is_displayed`.
     ///
     /// # Panics
     /// Panics if the mutex is poisoned or if sending to the error channel fails.
-    pub fn report_unused(&self, severity: AllowWarnDeny, cwd: &Path, tx_error: &DiagnosticSender) {
+    pub fn report_unused(
+        &self,
+        severity: AllowWarnDeny,
+        cwd: &Path,
+        is_displayed: bool,
+        tx_error: &DiagnosticSender,
+    ) {
         use crate::create_unused_directives_diagnostics;
 
         let map = self.map.lock().expect("DirectivesStore mutex poisoned in report_unused");
@@ -95,7 +108,11 @@ impl DirectivesStore {
             let diagnostics = create_unused_directives_diagnostics(directives, severity);
 
             if !diagnostics.is_empty() {
-                let source_text = std::fs::read_to_string(path.as_path()).unwrap_or_default();
+                let source_text = if is_displayed {
+                    std::fs::read_to_string(path.as_path()).unwrap_or_default()
+                } else {
+                    String::new()
+                };
                 let wrapped = DiagnosticService::wrap_diagnostics(
                     cwd,
                     path.clone(),
@@ -127,8 +144,14 @@ pub struct LintRunnerBuilder {
     regular_linter: Linter,
     type_aware_enabled: bool,
     type_check: bool,
+    type_facts: bool,
+    file_metadata_map: Option<FileMetadataMap>,
+    fixed_files_map: Option<FixedFilesMap>,
+    rule_origins_map: Option<RuleOriginsMap>,
+    file_status_map: Option<FileStatusMap>,
     lint_service_options: LintServiceOptions,
     silent: bool,
+    quiet: bool,
     fix_kind: FixKind,
 }
 
@@ -138,8 +161,14 @@ impl LintRunnerBuilder {
             regular_linter: linter,
             type_aware_enabled: false,
             type_check: false,
+            type_facts: false,
+            file_metadata_map: None,
+            fixed_files_map: None,
+            rule_origins_map: None,
+            file_status_map: None,
             lint_service_options,
             silent: false,
+            quiet: false,
             fix_kind: FixKind::None,
         }
     }
@@ -156,12 +185,63 @@ impl LintRunnerBuilder {
         self
     }
 
+    /// Compute type information with `tsgolint` before linting, and make it available to native
+    /// rules via [`LintContext::type_fact`](crate::LintContext::type_fact).
+    /// Has no effect unless type-aware linting is enabled.
+    #[must_use]
+    pub fn with_type_facts(mut self, enabled: bool) -> Self {
+        self.type_facts = enabled;
+        self
+    }
+
+    /// Collect the frameworks and source types detected for each linted file into `map`.
+    /// See [`Linter::file_metadata`].
+    #[must_use]
+    pub fn with_file_metadata_map(mut self, map: Option<FileMetadataMap>) -> Self {
+        self.file_metadata_map = map;
+        self
+    }
+
+    /// Collect the fixes applied to each file written by `--fix` into `map`.
+    #[must_use]
+    pub fn with_fixed_files_map(mut self, map: Option<FixedFilesMap>) -> Self {
+        self.fixed_files_map = map;
+        self
+    }
+
+    /// Collect the origins of the rules reporting diagnostics in each file into `map`.
+    /// See [`RuleOrigin`](crate::RuleOrigin).
+    #[must_use]
+    pub fn with_rule_origins_map(mut self, map: Option<RuleOriginsMap>) -> Self {
+        self.rule_origins_map = map;
+        self
+    }
+
+    /// Collect the status of each file passed to [`LintRunner::lint_files`] into `map`.
+    /// See [`FileStatus`](crate::FileStatus).
+    #[must_use]
+    pub fn with_file_status_map(mut self, map: Option<FileStatusMap>) -> Self {
+        self.file_status_map = map;
+        self
+    }
+
+    /// No diagnostic is displayed, so they are sent without reading or copying source text,
+    /// only to be counted.
     #[must_use]
     pub fn with_silent(mut self, silent: bool) -> Self {
         self.silent = silent;
         self
     }
 
+    /// Warnings are not displayed, so they are sent without copying source text, only to be
+    /// counted. Must not be set when the severity of diagnostics may change after linting,
+    /// e.g. by the ownership of files.
+    #[must_use]
+    pub fn with_quiet(mut self, quiet: bool) -> Self {
+        self.quiet = quiet;
+        self
+    }
+
     #[must_use]
     pub fn with_fix_kind(mut self, fix_kind: FixKind) -> Self {
         self.fix_kind = fix_kind;
@@ -189,12 +269,35 @@ impl LintRunnerBuilder {
         let cwd = self.lint_service_options.cwd().to_path_buf();
         let mut lint_service = LintService::new(self.regular_linter, self.lint_service_options);
         lint_service.set_disable_directives_map(directives_coordinator.map());
+        lint_service.set_silent(self.silent);
+        lint_service.set_quiet(self.quiet);
+        if let Some(map) = self.file_metadata_map {
+            lint_service.set_file_metadata_map(map);
+        }
+        if let Some(map) = self.fixed_files_map {
+            lint_service.set_fixed_files_map(map);
+        }
+        if let Some(map) = self.rule_origins_map {
+            lint_service.set_rule_origins_map(map);
+        }
+        if let Some(map) = self.file_status_map {
+            lint_service.set_file_status_map(map);
+        }
+
+        let type_facts_map = (self.type_facts && type_aware_linter.is_some()).then(|| {
+            let map = TypeFactsMap::default();
+            lint_service.set_type_facts_map(Arc::clone(&map));
+            map
+        });
 
         Ok(LintRunner {
             lint_service,
             type_aware_linter,
             directives_store: directives_coordinator,
+            type_facts_map,
             cwd,
+            silent: self.silent,
+            quiet: self.quiet,
         })
     }
 }
@@ -208,12 +311,23 @@ impl LintRunner {
     /// Run both regular and type-aware linting on files
     /// # Errors
     /// Returns an error if type-aware linting fails.
+    ///
+    /// # Panics
+    /// Panics if the type facts mutex is poisoned.
     pub fn lint_files(
         mut self,
         files: &[Arc<OsStr>],
         tx_error: DiagnosticSender,
         file_system: Option<&(dyn crate::RuntimeFileSystem + Sync + Send)>,
     ) -> Result<Self, String> {
+        // Phase 0: Type facts for native rules which need type information
+        if let (Some(type_facts_map), Some(type_aware_linter)) =
+            (&self.type_facts_map, &self.type_aware_linter)
+        {
+            let type_facts = type_aware_linter.collect_type_facts(files)?;
+            type_facts_map.lock().expect("type_facts_map mutex poisoned").extend(type_facts);
+        }
+
         // Phase 1: Regular linting (collects disable directives)
         let default_fs = OsFileSystem;
         let fs: &(dyn crate::RuntimeFileSystem + Sync + Send) =
@@ -230,37 +344,67 @@ impl LintRunner {
         Ok(self)
     }
 
-    /// Run both regular and type-aware linting on files
-    /// # Errors
-    /// Returns an error if type-aware linting fails.
+    /// Run both regular and type-aware linting on a single file, returning the messages.
+    ///
+    /// Once `cancellation_token` is cancelled, linting stops as soon as possible and the
+    /// returned messages are incomplete, so they should be discarded.
     pub fn run_source(
         &self,
         file: &Arc<OsStr>,
         source_text: String,
         file_system: &(dyn crate::RuntimeFileSystem + Sync + Send),
+        cancellation_token: &CancellationToken,
     ) -> Vec<Message> {
-        let mut messages = self.lint_service.run_source(file_system, vec![Arc::clone(file)]);
-
-        if let Some(type_aware_linter) = &self.type_aware_linter {
-            let tsgo_messages =
-                match type_aware_linter.lint_source(file, source_text, self.directives_store.map())
-                {
-                    Ok(msgs) => msgs,
-                    Err(err) => {
-                        vec![Message::new(
-                            OxcDiagnostic::warn(format!(
-                                "Failed to run type-aware linting: `{err}`",
-                            )),
-                            PossibleFixes::None,
-                        )]
-                    }
-                };
-            messages.extend(tsgo_messages);
-        }
-
+        let mut messages =
+            self.lint_service.run_source(file_system, vec![Arc::clone(file)], cancellation_token);
+        messages.extend(self.run_type_aware_source(file, source_text, cancellation_token));
         messages
     }
 
+    /// Run regular linting on `files` in parallel, and call `callback` with the messages of each
+    /// file as soon as it has been linted. See [`LintService::run_with_callback`].
+    ///
+    /// Type-aware linting is not run, use [`LintRunner::run_type_aware_source`] on each file.
+    pub fn run_with_callback(
+        &self,
+        files: Vec<Arc<OsStr>>,
+        file_system: &(dyn crate::RuntimeFileSystem + Sync + Send),
+        cancellation_token: &CancellationToken,
+        callback: impl FnMut(FileDiagnostics) + Send,
+    ) {
+        self.lint_service.run_with_callback(file_system, files, cancellation_token, callback);
+    }
+
+    /// Run type-aware linting on a single file, returning the messages.
+    /// No messages are returned if type-aware linting is disabled, or the run was cancelled.
+    pub fn run_type_aware_source(
+        &self,
+        file: &Arc<OsStr>,
+        source_text: String,
+        cancellation_token: &CancellationToken,
+    ) -> Vec<Message> {
+        let Some(type_aware_linter) = &self.type_aware_linter else {
+            return vec![];
+        };
+        if cancellation_token.is_cancelled() {
+            return vec![];
+        }
+        match type_aware_linter.lint_source(
+            file,
+            source_text,
+            self.directives_store.map(),
+            cancellation_token,
+        ) {
+            Ok(msgs) => msgs,
+            Err(err) => {
+                vec![Message::new(
+                    OxcDiagnostic::warn(format!("Failed to run type-aware linting: `{err}`",)),
+                    PossibleFixes::None,
+                )]
+            }
+        }
+    }
+
     /// Report unused disable directives
     pub fn report_unused_directives(
         &self,
@@ -268,7 +412,8 @@ impl LintRunner {
         tx_error: &DiagnosticSender,
     ) {
         if let Some(severity) = severity {
-            self.directives_store.report_unused(severity, &self.cwd, tx_error);
+            let is_displayed = !(self.silent || (self.quiet && severity == AllowWarnDeny::Warn));
+            self.directives_store.report_unused(severity, &self.cwd, is_displayed, tx_error);
         }
     }
 
@@ -281,4 +426,9 @@ impl LintRunner {
     pub fn has_type_aware(&self) -> bool {
         self.type_aware_linter.is_some()
     }
+
+    /// Statistics of the memory arenas used to lint files, see [`LintService::allocator_pool_stats`].
+    pub fn allocator_pool_stats(&self) -> AllocatorPoolStats {
+        self.lint_service.allocator_pool_stats()
+    }
 }
@@ -0,0 +1,237 @@
+//! Loader for JSON and JSONC files, linted by the rules of the `json` plugin.
+//!
+//! A JSON document is parsed as a single JavaScript expression, so that rules walk the same AST as
+//! for JS files: a [`Program`] with one [`ExpressionStatement`](oxc_ast::ast::ExpressionStatement).
+//! Comments are only accepted in JSONC files (see [`is_jsonc_path`]). Trailing commas are accepted
+//! in all files, and reported by `json/no-trailing-commas` so that they can be fixed. Comments are
+//! not kept, so disable directives have no effect in JSON files.
+
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+use oxc_syntax::operator::UnaryOperator;
+
+/// Extensions of the files linted as JSON when the `json` plugin is enabled.
+pub const JSON_EXTENSIONS: &[&str] = &["json", "jsonc"];
+
+/// Whether `path` is linted as a JSON file.
+pub fn is_json_path(path: &Path) -> bool {
+    path.extension()
+        .and_then(std::ffi::OsStr::to_str)
+        .is_some_and(|ext| JSON_EXTENSIONS.contains(&ext))
+}
+
+/// Whether `path` is a JSONC file: a file with the `.jsonc` extension, or a `tsconfig.json`,
+/// `jsconfig.json` or `.oxlintrc.json` file, which are read by parsers accepting comments and
+/// trailing commas.
+pub fn is_jsonc_path(path: &Path) -> bool {
+    if path.extension().is_some_and(|ext| ext == "jsonc") {
+        return true;
+    }
+    path.file_name().and_then(|name| name.to_str()).is_some_and(|name| {
+        name == ".oxlintrc.json" || name.starts_with("tsconfig.") || name.starts_with("jsconfig.")
+    })
+}
+
+/// Parse the JSON document `source_text` into a program with a single expression statement.
+/// Comments are only accepted with `jsonc`.
+///
+/// # Errors
+/// Returns the syntax errors of `source_text`, including JavaScript syntax which is not valid
+/// JSON, like single quoted strings or unquoted property keys.
+pub fn parse_json<'a>(
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    source_type: SourceType,
+    jsonc: bool,
+) -> Result<Program<'a>, Vec<OxcDiagnostic>> {
+    let expression = Parser::new(allocator, source_text, source_type).parse_expression()?;
+
+    let mut errors = vec![];
+    check_value(&expression, source_text, &mut errors);
+    if !jsonc {
+        check_no_comments(source_text, &mut errors);
+    }
+    let end = expression.span().end as usize;
+    let rest = skip_trivia(&source_text[end..]);
+    if rest < source_text.len() - end {
+        #[expect(clippy::cast_possible_truncation)]
+        let start = (end + rest) as u32;
+        #[expect(clippy::cast_possible_truncation)]
+        let span = Span::new(start, source_text.len() as u32);
+        errors
+            .push(OxcDiagnostic::error("Unexpected content after the JSON value").with_label(span));
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let ast = AstBuilder::new(allocator);
+    let statement = ast.statement_expression(expression.span(), expression);
+    #[expect(clippy::cast_possible_truncation)]
+    let span = Span::new(0, source_text.len() as u32);
+    Ok(ast.program(span, source_type, source_text, ast.vec(), None, ast.vec(), ast.vec1(statement)))
+}
+
+/// The length of the whitespace and comments at the start of `text`.
+pub fn skip_trivia(text: &str) -> usize {
+    let mut rest = text;
+    loop {
+        let trimmed = rest.trim_start();
+        if let Some(comment) = trimmed.strip_prefix("//") {
+            rest = comment.find('\n').map_or("", |end| &comment[end..]);
+        } else if let Some(comment) = trimmed.strip_prefix("/*") {
+            rest = comment.find("*/").map_or("", |end| &comment[end + 2..]);
+        } else {
+            return text.len() - trimmed.len();
+        }
+    }
+}
+
+fn check_value(expression: &Expression, source_text: &str, errors: &mut Vec<OxcDiagnostic>) {
+    match expression {
+        Expression::ObjectExpression(object) => {
+            for property in &object.properties {
+                let ObjectPropertyKind::ObjectProperty(property) = property else {
+                    errors.push(invalid_json(property.span()));
+                    continue;
+                };
+                if property.computed || property.shorthand || property.method {
+                    errors.push(invalid_json(property.span));
+                    continue;
+                }
+                match &property.key {
+                    PropertyKey::StringLiteral(key) if is_double_quoted(key.span, source_text) => {}
+                    key => errors.push(
+                        OxcDiagnostic::error("Property keys must be double quoted strings in JSON")
+                            .with_label(key.span()),
+                    ),
+                }
+                check_value(&property.value, source_text, errors);
+            }
+        }
+        Expression::ArrayExpression(array) => {
+            for element in &array.elements {
+                match element.as_expression() {
+                    Some(element) => check_value(element, source_text, errors),
+                    None => errors.push(invalid_json(element.span())),
+                }
+            }
+        }
+        Expression::StringLiteral(string) => {
+            if !is_double_quoted(string.span, source_text) {
+                errors.push(
+                    OxcDiagnostic::error("Strings must be double quoted in JSON")
+                        .with_label(string.span),
+                );
+            }
+        }
+        Expression::NumericLiteral(_)
+        | Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_) => {}
+        Expression::UnaryExpression(unary)
+            if unary.operator == UnaryOperator::UnaryNegation
+                && matches!(unary.argument, Expression::NumericLiteral(_)) => {}
+        _ => errors.push(invalid_json(expression.span())),
+    }
+}
+
+/// Report the comments of `source_text`, which is a JSON value apart from its comments.
+fn check_no_comments(source_text: &str, errors: &mut Vec<OxcDiagnostic>) {
+    let bytes = source_text.as_bytes();
+    let mut in_string = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if in_string => i += 1,
+            b'"' => in_string = !in_string,
+            b'/' if !in_string => {
+                let rest = &source_text[i..];
+                let len = rest[..skip_trivia(rest)].trim_end().len();
+                if len > 0 {
+                    #[expect(clippy::cast_possible_truncation)]
+                    let span = Span::sized(i as u32, len as u32);
+                    errors.push(
+                        OxcDiagnostic::error("Comments are not allowed in JSON")
+                            .with_help("Use the `.jsonc` extension for JSON files with comments.")
+                            .with_label(span),
+                    );
+                    i += len;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+}
+
+fn is_double_quoted(span: Span, source_text: &str) -> bool {
+    source_text.as_bytes().get(span.start as usize) == Some(&b'"')
+}
+
+fn invalid_json(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Invalid JSON value").with_label(span)
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_span::SourceType;
+
+    use super::parse_json;
+
+    #[test]
+    fn parse() {
+        let allocator = Allocator::default();
+        let valid = [
+            r#"{ "a": [1, -2.5, "b", true, null, {}] }"#,
+            "[1, 2, 3,]\n",
+            r#""string""#,
+            r#"{ "url": "https://oxc.rs/*", "escaped": "\"//" }"#,
+        ];
+        for source_text in valid {
+            for jsonc in [false, true] {
+                assert!(
+                    parse_json(&allocator, source_text, SourceType::default(), jsonc).is_ok(),
+                    "{source_text}"
+                );
+            }
+        }
+
+        let with_comments = ["// comment\n[1, 2, /* comment */ 3,]\n", "[1] /* comment */"];
+        for source_text in with_comments {
+            assert!(
+                parse_json(&allocator, source_text, SourceType::default(), true).is_ok(),
+                "{source_text}"
+            );
+            assert_eq!(
+                parse_json(&allocator, source_text, SourceType::default(), false)
+                    .unwrap_err()
+                    .len(),
+                if source_text.starts_with("//") { 2 } else { 1 },
+                "{source_text}"
+            );
+        }
+
+        let invalid = [
+            "",
+            "{ a: 1 }",
+            "{ 'a': 1 }",
+            r#"{ "a": f() }"#,
+            "[1, , 2]",
+            "[1] [2]",
+            r#"{ "a": `b` }"#,
+        ];
+        for source_text in invalid {
+            assert!(
+                parse_json(&allocator, source_text, SourceType::default(), true).is_err(),
+                "{source_text}"
+            );
+        }
+    }
+}
@@ -2,8 +2,11 @@ use std::{error::Error, fmt, path::Path};
 
 use oxc_span::SourceType;
 
+mod json;
 mod partial_loader;
 mod source;
+pub(crate) use json::skip_trivia;
+pub use json::{JSON_EXTENSIONS, is_json_path, is_jsonc_path, parse_json};
 pub use partial_loader::{LINT_PARTIAL_LOADER_EXTENSIONS, LINTABLE_EXTENSIONS, PartialLoader};
 pub use source::JavaScriptSource;
 
@@ -8,7 +8,15 @@ use std::{
 
 use rustc_hash::FxHashMap;
 
-use oxc_semantic::Semantic;
+use oxc_ast::ast::{
+    Declaration, ExportDefaultDeclarationKind, Expression, IdentifierReference, Program, Statement,
+    TSModuleDeclarationBody,
+};
+use oxc_ecmascript::{
+    GlobalContext,
+    side_effects::{MayHaveSideEffects, MayHaveSideEffectsContext, PropertyReadSideEffects},
+};
+use oxc_semantic::{IsGlobalReference, Scoping, Semantic};
 use oxc_span::{CompactStr, Span};
 pub use oxc_syntax::module_record::RequestedModule;
 
@@ -24,6 +32,15 @@ pub struct ModuleRecord {
     /// This module has ESM syntax: `import` and `export`.
     pub has_module_syntax: bool,
 
+    /// Evaluating the top level of this module may have side effects, e.g. it calls functions,
+    /// assigns to globals or has a side-effect import such as `import './polyfill'`.
+    ///
+    /// Modules imported by this module are not taken into account.
+    ///
+    /// Only computed when cross-module linting is enabled (i.e. the import plugin is enabled),
+    /// since it is only used by modules importing this one.
+    pub has_side_effects: bool,
+
     /// Resolved absolute path to this module record
     pub resolved_absolute_path: PathBuf,
 
@@ -50,6 +67,13 @@ pub struct ModuleRecord {
     /// Use [ModuleRecord::get_loaded_module] to get a `ModuleRecord`.
     loaded_modules: RwLock<FxHashMap<CompactStr, Weak<ModuleRecord>>>,
 
+    /// Absolute paths of the requested modules, keyed by ModuleSpecifier, as found by the module
+    /// resolver. Unlike `loaded_modules`, it also contains modules which are not linted.
+    ///
+    /// Only populated when cross-module linting is enabled (i.e. the import plugin is enabled).
+    /// Specifiers which cannot be resolved are missing.
+    pub resolved_modules: FxHashMap<CompactStr, PathBuf>,
+
     /// `[[ImportEntries]]`
     ///
     /// A List of `ImportEntry` records derived from the code of this module
@@ -101,9 +125,11 @@ impl fmt::Debug for ModuleRecord {
         let loaded_modules = format!("{{ {loaded_modules} }}");
         f.debug_struct("ModuleRecord")
             .field("has_module_syntax", &self.has_module_syntax)
+            .field("has_side_effects", &self.has_side_effects)
             .field("resolved_absolute_path", &self.resolved_absolute_path)
             .field("requested_modules", &self.requested_modules)
             .field("loaded_modules", &loaded_modules)
+            .field("resolved_modules", &self.resolved_modules)
             .field("import_entries", &self.import_entries)
             .field("local_export_entries", &self.local_export_entries)
             .field("indirect_export_entries", &self.indirect_export_entries)
@@ -466,6 +492,7 @@ impl ModuleRecord {
     ) -> Self {
         Self {
             has_module_syntax: other.has_module_syntax,
+            has_side_effects: false,
             resolved_absolute_path: path.to_path_buf(),
             requested_modules: other
                 .requested_modules
@@ -510,6 +537,12 @@ impl ModuleRecord {
         }
     }
 
+    /// Compute [`ModuleRecord::has_side_effects`] from the top level statements of this module.
+    pub fn compute_side_effects(&mut self, semantic: &Semantic) {
+        self.has_side_effects =
+            program_has_side_effects(semantic.nodes().program(), semantic.scoping());
+    }
+
     /// # Panics
     ///
     /// * If the RwLock is poisoned (which only happens if a thread panicked while holding the lock).
@@ -571,3 +604,102 @@ impl ModuleRecord {
         })
     }
 }
+
+struct SideEffectsContext<'s> {
+    scoping: &'s Scoping,
+}
+
+impl<'a> GlobalContext<'a> for SideEffectsContext<'_> {
+    fn is_global_reference(&self, ident: &IdentifierReference<'a>) -> bool {
+        ident.is_global_reference(self.scoping)
+    }
+}
+
+impl MayHaveSideEffectsContext<'_> for SideEffectsContext<'_> {
+    fn annotations(&self) -> bool {
+        true
+    }
+
+    fn manual_pure_functions(&self, _callee: &Expression) -> bool {
+        false
+    }
+
+    fn property_read_side_effects(&self) -> PropertyReadSideEffects {
+        PropertyReadSideEffects::All
+    }
+
+    fn unknown_global_side_effects(&self) -> bool {
+        true
+    }
+}
+
+fn program_has_side_effects(program: &Program, scoping: &Scoping) -> bool {
+    let ctx = SideEffectsContext { scoping };
+    program.body.iter().any(|stmt| statement_has_side_effects(stmt, &ctx))
+}
+
+fn statement_has_side_effects(stmt: &Statement, ctx: &SideEffectsContext) -> bool {
+    match stmt {
+        // `import './polyfill'` is only there for its side effects.
+        Statement::ImportDeclaration(decl) => {
+            decl.import_kind.is_value() && decl.specifiers.is_none()
+        }
+        Statement::ExportNamedDeclaration(decl) => {
+            decl.declaration.as_ref().is_some_and(|decl| declaration_has_side_effects(decl, ctx))
+        }
+        Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(_)
+            | ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => false,
+            ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                class.may_have_side_effects(ctx)
+            }
+            kind => kind.as_expression().is_some_and(|expr| expr.may_have_side_effects(ctx)),
+        },
+        Statement::TSExportAssignment(assignment) => {
+            assignment.expression.may_have_side_effects(ctx)
+        }
+        Statement::ExportAllDeclaration(_) | Statement::TSNamespaceExportDeclaration(_) => false,
+        _ => match stmt.as_declaration() {
+            Some(decl) => declaration_has_side_effects(decl, ctx),
+            None => stmt.may_have_side_effects(ctx),
+        },
+    }
+}
+
+fn declaration_has_side_effects(decl: &Declaration, ctx: &SideEffectsContext) -> bool {
+    match decl {
+        Declaration::TSInterfaceDeclaration(_)
+        | Declaration::TSTypeAliasDeclaration(_)
+        | Declaration::TSGlobalDeclaration(_) => false,
+        Declaration::TSEnumDeclaration(decl) => {
+            !decl.declare
+                && decl
+                    .body
+                    .members
+                    .iter()
+                    .any(|member| member.initializer.may_have_side_effects(ctx))
+        }
+        Declaration::TSModuleDeclaration(decl) => {
+            !decl.declare && ts_module_body_has_side_effects(decl.body.as_ref(), ctx)
+        }
+        Declaration::TSImportEqualsDeclaration(decl) => decl.import_kind.is_value(),
+        Declaration::VariableDeclaration(decl) if decl.declare => false,
+        Declaration::ClassDeclaration(class) if class.declare => false,
+        _ => decl.may_have_side_effects(ctx),
+    }
+}
+
+fn ts_module_body_has_side_effects(
+    body: Option<&TSModuleDeclarationBody>,
+    ctx: &SideEffectsContext,
+) -> bool {
+    match body {
+        Some(TSModuleDeclarationBody::TSModuleBlock(block)) => {
+            block.body.iter().any(|stmt| statement_has_side_effects(stmt, ctx))
+        }
+        Some(TSModuleDeclarationBody::TSModuleDeclaration(decl)) => {
+            ts_module_body_has_side_effects(decl.body.as_ref(), ctx)
+        }
+        None => false,
+    }
+}
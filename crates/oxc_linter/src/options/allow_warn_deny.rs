@@ -1,16 +1,16 @@
 use std::{
-    borrow::Cow,
     convert::From,
     fmt::{self, Display},
 };
 
+use cow_utils::CowUtils;
 use schemars::{JsonSchema, schema::SchemaObject};
 use serde::{Deserialize, Serialize, de};
 use serde_json::{Number, Value};
 
 use oxc_diagnostics::{OxcDiagnostic, Severity};
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum AllowWarnDeny {
     Allow, // Off
@@ -47,7 +47,8 @@ impl TryFrom<&str> for AllowWarnDeny {
     type Error = OxcDiagnostic;
 
     fn try_from(s: &str) -> Result<Self, Self::Error> {
-        match s {
+        // Like ESLint, severities are case-insensitive, e.g. `"Error"`.
+        match s.cow_to_ascii_lowercase().as_ref() {
             "allow" | "off" => Ok(Self::Allow),
             "deny" | "error" => Ok(Self::Deny),
             "warn" => Ok(Self::Warn),
@@ -107,8 +108,12 @@ impl TryFrom<&Number> for AllowWarnDeny {
     type Error = OxcDiagnostic;
 
     fn try_from(value: &Number) -> Result<Self, Self::Error> {
-        let value = value.as_i64().ok_or_else(|| invalid_int_severity(value))?;
-        Self::try_from(value)
+        // `2.0` is the same number as `2` in JavaScript, so ESLint accepts it as well.
+        #[expect(clippy::cast_possible_truncation)]
+        let int = value.as_i64().or_else(|| {
+            value.as_f64().filter(|n| n.fract() == 0.0 && n.abs() <= 2.0).map(|n| n as i64)
+        });
+        Self::try_from(int.ok_or_else(|| invalid_int_severity(value))?)
     }
 }
 
@@ -117,32 +122,10 @@ impl<'de> Deserialize<'de> for AllowWarnDeny {
     where
         D: de::Deserializer<'de>,
     {
-        #[derive(Deserialize)]
-        #[serde(untagged)]
-        enum Repr<'a> {
-            Int(u8),
-            Str(Cow<'a, str>),
-        }
-
-        match Repr::deserialize(deserializer)? {
-            Repr::Int(0) => Ok(Self::Allow),
-            Repr::Int(1) => Ok(Self::Warn),
-            Repr::Int(2) => Ok(Self::Deny),
-            #[expect(clippy::cast_lossless)]
-            Repr::Int(other) => Err(de::Error::invalid_value(
-                de::Unexpected::Unsigned(other as u64),
-                &"`0`, `1` or `2`",
-            )),
-            Repr::Str(s) => match &*s {
-                "allow" | "off" => Ok(Self::Allow),
-                "warn" => Ok(Self::Warn),
-                "deny" | "error" => Ok(Self::Deny),
-                _ => Err(de::Error::invalid_value(
-                    de::Unexpected::Str(&s),
-                    &"`allow`, `off`, `warn`, `deny` or `error`",
-                )),
-            },
-        }
+        // Parsed like the severity of a rule, so that categories and linter options accept the
+        // same forms as rules.
+        let value = Value::deserialize(deserializer)?;
+        Self::try_from(&value).map_err(|err| de::Error::custom(&err.message))
     }
 }
 
@@ -225,6 +208,12 @@ mod test {
             (r#""error""#, AllowWarnDeny::Deny),
             (r#""deny""#, AllowWarnDeny::Deny),
             ("2", AllowWarnDeny::Deny),
+            // case-insensitive names and integral floats, as in ESLint
+            (r#""Off""#, AllowWarnDeny::Allow),
+            (r#""WARN""#, AllowWarnDeny::Warn),
+            (r#""Error""#, AllowWarnDeny::Deny),
+            ("0.0", AllowWarnDeny::Allow),
+            ("2.0", AllowWarnDeny::Deny),
         ];
 
         for (input, expected) in pass {
@@ -233,7 +222,7 @@ mod test {
             assert_eq!(actual, expected);
         }
 
-        let fail = [r#""foo""#, "-1", "3"];
+        let fail = [r#""foo""#, "-1", "3", "1.5", "3.0", "true", "null", r#"["error"]"#];
         for input in fail {
             assert!(serde_json::from_str::<AllowWarnDeny>(input).is_err());
         }
@@ -1,15 +1,16 @@
 use std::{borrow::Cow, fmt};
 
-use crate::RuleCategory;
+use crate::{LintPlugins, RuleCategory};
 
 use super::AllowWarnDeny;
 
 /// Enables, disables, and sets the severity of lint rules.
 ///
-/// Filters come in 3 forms:
+/// Filters come in 4 forms:
 /// 1. Filter by rule name and/or plugin: `no-const-assign`, `eslint/no-const-assign`
 /// 2. Filter an entire category: `correctness`
-/// 3. Some unknown filter. This is a fallback used when parsing a filter string,
+/// 3. Filter an entire plugin: `unicorn`
+/// 4. Some unknown filter. This is a fallback used when parsing a filter string,
 ///    and is interpreted uniquely by the linter.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct LintFilter {
@@ -85,6 +86,8 @@ pub enum LintFilterKind {
     Rule(Cow<'static, str>, Cow<'static, str>),
     /// e.g. `correctness`
     Category(RuleCategory),
+    /// e.g. `unicorn`
+    Plugin(LintPlugins),
     // TODO: plugin + category? e.g `-A react:correctness`
 }
 
@@ -146,7 +149,9 @@ impl LintFilterKind {
             match RuleCategory::try_from(filter.as_ref()) {
                 Ok(category) => Ok(LintFilterKind::Category(category)),
                 Err(()) => {
-                    if filter == "all" {
+                    if let Ok(plugin) = LintPlugins::try_from(filter.as_ref()) {
+                        Ok(LintFilterKind::Plugin(plugin))
+                    } else if filter == "all" {
                         Ok(LintFilterKind::All)
                     } else {
                         Ok(LintFilterKind::Generic(filter))
@@ -253,6 +258,10 @@ mod test {
             ("correctness", LintFilterKind::Category(RuleCategory::Correctness)),
             ("nursery", LintFilterKind::Category(RuleCategory::Nursery)),
             ("perf", LintFilterKind::Category(RuleCategory::Perf)),
+            // plugins
+            ("unicorn", LintFilterKind::Plugin(LintPlugins::UNICORN)),
+            ("react-hooks", LintFilterKind::Plugin(LintPlugins::REACT)),
+            ("eslint", LintFilterKind::Plugin(LintPlugins::ESLINT)),
             // misc
             ("no-const-assign", LintFilterKind::Generic("no-const-assign".into())),
             ("not-a-valid-filter", LintFilterKind::Generic("not-a-valid-filter".into())),
@@ -1,3 +1,5 @@
+use oxc_diagnostics::Severity;
+
 use crate::{FrameworkFlags, fixer::FixKind};
 
 mod allow_warn_deny;
@@ -11,6 +13,15 @@ pub use filter::{InvalidFilterKind, LintFilter, LintFilterKind};
 #[cfg_attr(test, derive(PartialEq, Eq))]
 pub struct LintOptions {
     pub fix: FixKind,
+    /// Only apply fixes of diagnostics with at least this severity. `None` fixes all diagnostics.
+    pub fix_min_severity: Option<Severity>,
     pub framework_hints: FrameworkFlags,
     pub report_unused_directive: Option<AllowWarnDeny>,
+    /// Only report syntax errors of the parser and semantic analysis, without running any rules.
+    pub syntax_only: bool,
+    /// Keep the warnings of files exceeding `perFileThresholds.max-warnings-per-file`, and report
+    /// the summarizing error next to them, e.g. in editors, which only show a single file.
+    pub keep_warnings_over_threshold: bool,
+    /// Set the [`RuleOrigin`](crate::RuleOrigin) of the diagnostics of rules.
+    pub rule_origins: bool,
 }
@@ -0,0 +1,183 @@
+use std::{
+    path::{Component, Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+
+use crate::utils::read_to_string;
+
+/// A project file, such as `package.json` or `tsconfig.json`, read by a rule with
+/// [`LintContext::read_project_file`](crate::LintContext::read_project_file).
+#[derive(Debug)]
+pub struct ProjectFile {
+    /// Path of the file
+    pub path: PathBuf,
+    /// Content of the file, parsed as JSON with comments
+    pub json: Value,
+}
+
+/// Project files which were read by rules, shared by all files linted by a [`Linter`](crate::Linter).
+///
+/// Rules can only read files by name, which are looked up in the directory of the linted file and
+/// its ancestors up to the root of the project. Each file is read and parsed once, until it is
+/// [invalidated](ProjectFiles::invalidate).
+#[derive(Debug, Default)]
+pub struct ProjectFiles {
+    /// The last directory which is searched, e.g. the workspace root. Without it, files are
+    /// looked up to the root of the file system.
+    root: Option<PathBuf>,
+    /// Files keyed by path, including files which do not exist, so that directories are only
+    /// checked once.
+    files: Mutex<FxHashMap<PathBuf, CachedFile>>,
+}
+
+#[derive(Debug, Clone)]
+enum CachedFile {
+    Missing,
+    Invalid,
+    Parsed(Arc<ProjectFile>),
+}
+
+impl ProjectFiles {
+    /// Project files of the files in `root`, which is the last directory searched.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: Some(root.into()), files: Mutex::default() }
+    }
+
+    /// The nearest file named `file_name` in the directory of `linted_path` or its ancestors,
+    /// up to the root of the project.
+    ///
+    /// Returns `None` if `file_name` is not a plain file name (e.g. `../secret.json`), if there is
+    /// no such file, or if the nearest one is not valid JSON.
+    pub fn find(&self, linted_path: &Path, file_name: &str) -> Option<Arc<ProjectFile>> {
+        if !is_file_name(file_name) {
+            return None;
+        }
+        let in_project = |dir: &&Path| self.root.as_ref().is_none_or(|root| dir.starts_with(root));
+        for dir in linted_path.parent()?.ancestors().take_while(in_project) {
+            match self.get(dir.join(file_name)) {
+                CachedFile::Missing => {}
+                CachedFile::Invalid => return None,
+                CachedFile::Parsed(file) => return Some(file),
+            }
+        }
+        None
+    }
+
+    /// Read the file at `path` again the next time it is looked up, e.g. after it was changed,
+    /// created or deleted.
+    ///
+    /// # Panics
+    /// Panics if the cache mutex is poisoned.
+    pub fn invalidate(&self, path: &Path) {
+        self.files.lock().expect("project files mutex poisoned").remove(path);
+    }
+
+    fn get(&self, path: PathBuf) -> CachedFile {
+        if let Some(file) = self.files.lock().expect("project files mutex poisoned").get(&path) {
+            return file.clone();
+        }
+        // Read without holding the lock, a file read by two threads at once is parsed twice.
+        let file = Self::read(&path);
+        self.files.lock().expect("project files mutex poisoned").entry(path).or_insert(file).clone()
+    }
+
+    fn read(path: &Path) -> CachedFile {
+        if !path.is_file() {
+            return CachedFile::Missing;
+        }
+        let Ok(mut text) = read_to_string(path) else {
+            return CachedFile::Invalid;
+        };
+        // jsonc support, e.g. for `tsconfig.json`
+        if json_strip_comments::strip(&mut text).is_err() {
+            return CachedFile::Invalid;
+        }
+        match serde_json::from_str(&text) {
+            Ok(json) => {
+                CachedFile::Parsed(Arc::new(ProjectFile { path: path.to_path_buf(), json }))
+            }
+            Err(_) => CachedFile::Invalid,
+        }
+    }
+}
+
+fn is_file_name(file_name: &str) -> bool {
+    let mut components = Path::new(file_name).components();
+    matches!(components.next(), Some(Component::Normal(_))) && components.next().is_none()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, path::Path};
+
+    use super::ProjectFiles;
+
+    #[test]
+    fn find_project_files() {
+        let fixtures = env::current_dir().unwrap().join("fixtures/project_files");
+        let project_files = ProjectFiles::default();
+
+        let app_file = fixtures.join("packages/app/src/index.js");
+        let package_json = project_files.find(&app_file, "package.json").unwrap();
+        assert_eq!(package_json.path, fixtures.join("packages/app/package.json"));
+        assert_eq!(package_json.json["name"], "app");
+
+        // `tsconfig.json` with comments is found in the root of the project
+        let tsconfig = project_files.find(&app_file, "tsconfig.json").unwrap();
+        assert_eq!(tsconfig.path, fixtures.join("tsconfig.json"));
+        assert_eq!(tsconfig.json["compilerOptions"]["strict"], true);
+
+        // files are parsed once
+        let root_file = fixtures.join("index.js");
+        let root_tsconfig = project_files.find(&root_file, "tsconfig.json").unwrap();
+        assert!(std::sync::Arc::ptr_eq(&tsconfig, &root_tsconfig));
+
+        // the nearest file is not valid JSON
+        let broken_file = fixtures.join("packages/broken/index.js");
+        assert!(project_files.find(&broken_file, "package.json").is_none());
+        assert_eq!(project_files.find(&root_file, "package.json").unwrap().json["name"], "root");
+
+        // only file names can be read
+        for file_name in ["", ".", "..", "../package.json", "app/package.json", "/package.json"] {
+            assert!(project_files.find(&app_file, file_name).is_none(), "{file_name}");
+        }
+        assert!(project_files.find(Path::new("index.js"), "missing.json").is_none());
+    }
+
+    #[test]
+    fn project_root() {
+        let fixtures = env::current_dir().unwrap().join("fixtures/project_files");
+        let project_files = ProjectFiles::new(fixtures.join("packages/app"));
+
+        let app_file = fixtures.join("packages/app/src/index.js");
+        assert_eq!(project_files.find(&app_file, "package.json").unwrap().json["name"], "app");
+        // `tsconfig.json` is outside of the project
+        assert!(project_files.find(&app_file, "tsconfig.json").is_none());
+        assert!(project_files.find(&fixtures.join("index.js"), "package.json").is_none());
+    }
+
+    #[test]
+    fn invalidate_project_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let linted_file = dir.path().join("index.js");
+        let package_json = dir.path().join("package.json");
+        let project_files = ProjectFiles::new(dir.path());
+
+        assert!(project_files.find(&linted_file, "package.json").is_none());
+        fs::write(&package_json, r#"{ "name": "before" }"#).unwrap();
+        // missing files are cached too
+        assert!(project_files.find(&linted_file, "package.json").is_none());
+        project_files.invalidate(&package_json);
+        assert_eq!(
+            project_files.find(&linted_file, "package.json").unwrap().json["name"],
+            "before"
+        );
+
+        fs::write(&package_json, r#"{ "name": "after" }"#).unwrap();
+        project_files.invalidate(&package_json);
+        assert_eq!(project_files.find(&linted_file, "package.json").unwrap().json["name"], "after");
+    }
+}
@@ -2,15 +2,20 @@
 use std::borrow::Cow;
 use std::{fmt, hash::Hash};
 
-use schemars::{JsonSchema, SchemaGenerator, schema::Schema};
+use bitflags::bitflags;
+use schemars::JsonSchema;
+#[cfg(any(test, feature = "ruledocs", feature = "validate_config"))]
+use schemars::{SchemaGenerator, schema::Schema};
 use serde::{Deserialize, Serialize};
 
-use oxc_semantic::AstTypesBitset;
+use oxc_semantic::{AstTypesBitset, Scoping, SymbolFlags, SymbolId};
+use oxc_span::SourceType;
 
 use crate::{
     AstNode, FixKind,
     context::{ContextHost, LintContext},
-    utils::PossibleJestNode,
+    frameworks::FrameworkOptions,
+    utils::{ParsedRegExp, PossibleJestNode},
 };
 
 pub trait Rule: Sized + Default + fmt::Debug {
@@ -58,6 +63,18 @@ pub trait Rule: Sized + Default + fmt::Debug {
     ) {
     }
 
+    /// Run on each regular expression of the file, i.e. regex literals and `RegExp` constructors
+    /// called with a string pattern. Patterns are parsed once per file and shared by all rules.
+    #[expect(unused_variables)]
+    #[inline]
+    fn run_on_regex<'a>(&self, regex: &ParsedRegExp<'a>, ctx: &LintContext<'a>) {}
+
+    /// Run on each symbol of the file matching [`RuleMeta::SYMBOL_FILTER`], which is set with
+    /// `symbols = Class | Function` and `unused_symbols` in `declare_oxc_lint!`.
+    #[expect(unused_variables)]
+    #[inline]
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {}
+
     /// Check if a rule should be run at all.
     ///
     /// You usually do not need to implement this function. If you do, use it to
@@ -70,6 +87,21 @@ pub trait Rule: Sized + Default + fmt::Debug {
     fn should_run(&self, ctx: &ContextHost) -> bool {
         true
     }
+
+    /// Check if a rule should run on script blocks with the given source type and framework options.
+    ///
+    /// Prefer this over [`Rule::should_run`] when the decision does not depend on anything else in
+    /// the file. It is only checked once per configuration, source type and framework options,
+    /// instead of once per file.
+    #[expect(unused_variables)]
+    #[inline]
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        framework_options: FrameworkOptions,
+    ) -> bool {
+        true
+    }
 }
 
 /// A wrapper type for deserializing ESLint-style rule configurations.
@@ -78,6 +110,9 @@ pub trait Rule: Sized + Default + fmt::Debug {
 /// the actual rule configuration. This type automatically extracts and deserializes
 /// that first element. If the array is empty, it uses the default value.
 ///
+/// Rules can also read their options while linting with [`LintContext::options`], which
+/// deserializes them like this type, once per configuration.
+///
 /// # Examples
 ///
 /// ```ignore
@@ -137,7 +172,7 @@ pub trait RuleRunner: Rule {
 
     /// What `Rule` functions are implemented by this `Rule`. For example, if a rule only
     /// implements `run_once`, then the linter can skip calling `run`, so
-    /// this value would be tagged as [`RuleRunFunctionsImplemented::RunOnce`].
+    /// this value would only contain [`RuleRunFunctionsImplemented::RunOnce`].
     const RUN_FUNCTIONS: RuleRunFunctionsImplemented = RuleRunFunctionsImplemented::Unknown;
 
     fn types_info(&self) -> Option<&'static AstTypesBitset> {
@@ -149,30 +184,66 @@ pub trait RuleRunner: Rule {
     }
 }
 
-/// Enum approximating a bitset of which `Rule` functions are implemented.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub enum RuleRunFunctionsImplemented {
-    /// Unknown which functions are implemented.
-    Unknown,
-    /// Only `run` is implemented
-    Run,
-    /// Only `run_once` is implemented
-    RunOnce,
-    /// Only `run_on_jest_node` is implemented
-    RunOnJestNode,
+bitflags! {
+    /// Bitset of which `Rule` functions are implemented.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct RuleRunFunctionsImplemented: u8 {
+        /// `run` is implemented
+        const Run = 1 << 0;
+        /// `run_once` is implemented
+        const RunOnce = 1 << 1;
+        /// `run_on_jest_node` is implemented
+        const RunOnJestNode = 1 << 2;
+        /// `run_on_regex` is implemented
+        const RunOnRegex = 1 << 3;
+        /// `run_on_symbol` is implemented
+        const RunOnSymbol = 1 << 4;
+        /// Unknown which functions are implemented, so all of them may be.
+        const Unknown = Self::Run.bits()
+            | Self::RunOnce.bits()
+            | Self::RunOnJestNode.bits()
+            | Self::RunOnRegex.bits()
+            | Self::RunOnSymbol.bits();
+    }
 }
 
 impl RuleRunFunctionsImplemented {
     pub fn is_run_implemented(self) -> bool {
-        matches!(self, Self::Run | Self::Unknown)
+        self.contains(Self::Run)
     }
 
     pub fn is_run_once_implemented(self) -> bool {
-        matches!(self, Self::RunOnce | Self::Unknown)
+        self.contains(Self::RunOnce)
     }
 
     pub fn is_run_on_jest_node_implemented(self) -> bool {
-        matches!(self, Self::RunOnJestNode | Self::Unknown)
+        self.contains(Self::RunOnJestNode)
+    }
+
+    pub fn is_run_on_regex_implemented(self) -> bool {
+        self.contains(Self::RunOnRegex)
+    }
+
+    pub fn is_run_on_symbol_implemented(self) -> bool {
+        self.contains(Self::RunOnSymbol)
+    }
+}
+
+/// Symbols a rule's [`Rule::run_on_symbol`] is called on.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RuleSymbolFilter {
+    /// Symbols with any of these flags, or all symbols if all flags are set.
+    pub flags: SymbolFlags,
+    /// Only symbols without any resolved references.
+    pub unused_only: bool,
+}
+
+impl RuleSymbolFilter {
+    pub const ALL: Self = Self { flags: SymbolFlags::all(), unused_only: false };
+
+    pub fn matches(self, scoping: &Scoping, symbol_id: SymbolId) -> bool {
+        (self.flags.is_all() || self.flags.intersects(scoping.symbol_flags(symbol_id)))
+            && (!self.unused_only || scoping.symbol_is_unused(symbol_id))
     }
 }
 
@@ -188,10 +259,38 @@ pub trait RuleMeta {
     /// What kind of auto-fixing can this rule do?
     const FIX: RuleFixMeta = RuleFixMeta::None;
 
+    /// Which symbols [`Rule::run_on_symbol`] is called on.
+    const SYMBOL_FILTER: RuleSymbolFilter = RuleSymbolFilter::ALL;
+
+    /// Whether the options of the rule in configuration files are checked against
+    /// [`config_schema`](RuleMeta::config_schema).
+    #[cfg(any(test, feature = "validate_config"))]
+    const VALIDATE_CONFIG: bool = false;
+
+    /// Check that `options`, the options following the severity in a configuration file,
+    /// deserialize as the type the rule reads them as with
+    /// [`LintContext::options`](crate::LintContext::options), or describe why they do not.
+    ///
+    /// # Errors
+    ///
+    /// The error of deserializing the options, for rules declared with `typed_config`.
+    #[cfg(any(test, feature = "validate_config"))]
+    #[expect(unused_variables)]
+    fn deserialize_config(options: &[serde_json::Value]) -> Result<(), String> {
+        Ok(())
+    }
+
     fn documentation() -> Option<&'static str> {
         None
     }
 
+    /// Options the rule uses when it is configured without any, in the form of the options
+    /// following the severity in a configuration file, e.g. `[{ "checkLoops": true }]`.
+    fn default_config() -> Option<serde_json::Value> {
+        None
+    }
+
+    #[cfg(any(test, feature = "ruledocs", feature = "validate_config"))]
     #[expect(unused_variables)]
     fn config_schema(generator: &mut SchemaGenerator) -> Option<Schema> {
         None
@@ -223,6 +322,16 @@ pub enum RuleCategory {
 }
 
 impl RuleCategory {
+    pub const ALL: [Self; 7] = [
+        Self::Correctness,
+        Self::Suspicious,
+        Self::Pedantic,
+        Self::Perf,
+        Self::Style,
+        Self::Restriction,
+        Self::Nursery,
+    ];
+
     pub fn description(self) -> &'static str {
         match self {
             Self::Correctness => "Code that is outright wrong or useless.",
@@ -488,6 +597,23 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_rule_run_functions_are_known() {
+        use super::RuleRunFunctionsImplemented;
+        use crate::rules::RULES;
+
+        // Rules with unknown run functions are called with every node and every symbol, so the
+        // codegen should recognize the run functions of each rule.
+        for rule in RULES.iter() {
+            assert_ne!(
+                rule.run_info(),
+                RuleRunFunctionsImplemented::Unknown,
+                "{}: run functions not recognized by `oxc_linter_codegen`",
+                rule.name()
+            );
+        }
+    }
+
     fn assert_rule_runs_on_node_types<R: RuleMeta + RuleRunner>(
         rule: &R,
         node_types: &[oxc_ast::AstType],
@@ -0,0 +1,290 @@
+//! Structured documentation of rules, parsed from the doc comments of `declare_oxc_lint!`.
+//!
+//! Used by the website generator, so that every consumer of the docs splits them into sections
+//! and examples the same way.
+
+use serde::{Serialize, Serializer};
+
+use crate::{RuleCategory, RuleFixMeta, rules::RULES};
+
+/// Metadata and documentation of a rule, see [`rules_metadata`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleMetadata {
+    pub name: &'static str,
+    pub plugin: &'static str,
+    pub category: RuleCategory,
+    /// Serialized as the description of the fix, or `null` if the rule has none.
+    #[serde(serialize_with = "serialize_fix")]
+    pub fix: RuleFixMeta,
+    pub is_tsgolint_rule: bool,
+    pub docs: RuleDocs,
+}
+
+/// The documentation of a rule, split into sections and code examples.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleDocs {
+    /// Sections in the order they are written, e.g. "What it does" and "Why is this bad?".
+    pub sections: Vec<RuleDocSection>,
+    /// Code blocks introduced by a paragraph about **incorrect** code.
+    pub incorrect_examples: Vec<RuleDocExample>,
+    /// Code blocks introduced by a paragraph about **correct** code.
+    pub correct_examples: Vec<RuleDocExample>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RuleDocSection {
+    /// Text of the heading starting the section, without the leading `#`s.
+    /// Empty for text before the first heading.
+    pub title: String,
+    /// Markdown of the section, including its subsections.
+    pub content: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RuleDocExample {
+    /// Language of the code block, e.g. `js` or `tsx`.
+    pub lang: String,
+    pub code: String,
+    /// The paragraph introducing the example, e.g. "Examples of **incorrect** code for this rule:".
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExampleKind {
+    Incorrect,
+    Correct,
+}
+
+/// A fenced code block which is being parsed.
+struct CodeBlock {
+    marker: String,
+    lang: String,
+    code: String,
+}
+
+impl CodeBlock {
+    fn is_closed_by(&self, line: &str) -> bool {
+        line.strip_prefix(self.marker.as_str()).is_some_and(|rest| rest.trim().is_empty())
+    }
+}
+
+/// Metadata and structured documentation of every rule.
+///
+/// Documentation is only embedded with the `ruledocs` feature, so this is only available with it.
+pub fn rules_metadata() -> Vec<RuleMetadata> {
+    RULES
+        .iter()
+        .map(|rule| RuleMetadata {
+            name: rule.name(),
+            plugin: rule.plugin_name(),
+            category: rule.category(),
+            fix: rule.fix(),
+            is_tsgolint_rule: rule.is_tsgolint_rule(),
+            docs: rule.documentation().map(RuleDocs::parse).unwrap_or_default(),
+        })
+        .collect()
+}
+
+impl RuleDocs {
+    /// Split the markdown of a rule's documentation at headings of level 1 to 3, and collect the
+    /// code blocks following a paragraph which mentions **incorrect** or **correct** code.
+    pub fn parse(markdown: &str) -> Self {
+        let mut docs = Self::default();
+        let mut section = RuleDocSection { title: String::new(), content: String::new() };
+        let mut code_block: Option<CodeBlock> = None;
+        let mut example: Option<(ExampleKind, String)> = None;
+
+        for line in markdown.lines() {
+            let trimmed = line.trim_start();
+
+            if let Some(block) = code_block.take_if(|block| block.is_closed_by(trimmed)) {
+                section.content.push_str(line);
+                section.content.push('\n');
+                if let Some((kind, description)) = &example {
+                    let example = RuleDocExample {
+                        lang: block.lang,
+                        code: block.code,
+                        description: description.clone(),
+                    };
+                    match kind {
+                        ExampleKind::Incorrect => docs.incorrect_examples.push(example),
+                        ExampleKind::Correct => docs.correct_examples.push(example),
+                    }
+                }
+                continue;
+            }
+
+            if let Some(block) = &mut code_block {
+                section.content.push_str(line);
+                section.content.push('\n');
+                block.code.push_str(line);
+                block.code.push('\n');
+                continue;
+            }
+
+            if let Some(title) = heading(line) {
+                docs.push_section(section);
+                section = RuleDocSection { title: title.to_string(), content: String::new() };
+                example = None;
+                continue;
+            }
+
+            if let Some(marker) = fence_marker(trimmed) {
+                let lang = trimmed[marker.len()..].split_whitespace().next().unwrap_or_default();
+                code_block = Some(CodeBlock {
+                    marker: marker.to_string(),
+                    lang: lang.to_string(),
+                    code: String::new(),
+                });
+            } else if let Some(kind) = example_kind(trimmed) {
+                example = Some((kind, trimmed.to_string()));
+            }
+            section.content.push_str(line);
+            section.content.push('\n');
+        }
+        docs.push_section(section);
+
+        docs
+    }
+
+    /// The content of the section with the given title, e.g. `"What it does"`.
+    pub fn section(&self, title: &str) -> Option<&str> {
+        self.sections.iter().find(|section| section.title == title).map(|s| s.content.as_str())
+    }
+
+    fn push_section(&mut self, mut section: RuleDocSection) {
+        section.content = section.content.trim_matches('\n').to_string();
+        if !section.title.is_empty() || !section.content.is_empty() {
+            self.sections.push(section);
+        }
+    }
+}
+
+/// The text of a heading of level 1 to 3, deeper headings belong to the current section.
+fn heading(line: &str) -> Option<&str> {
+    let level = line.bytes().take_while(|&b| b == b'#').count();
+    if !(1..=3).contains(&level) {
+        return None;
+    }
+    let rest = &line[level..];
+    rest.starts_with(' ').then(|| rest.trim())
+}
+
+/// The marker opening a fenced code block, e.g. ```` ``` ```` or `~~~`.
+fn fence_marker(line: &str) -> Option<&str> {
+    let fence = line.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+    let len = line.chars().take_while(|&c| c == fence).count();
+    (len >= 3).then(|| &line[..len])
+}
+
+/// Whether a paragraph introduces examples of incorrect or correct code.
+fn example_kind(line: &str) -> Option<ExampleKind> {
+    let mut kind = None;
+    for word in line.split(|c: char| !c.is_ascii_alphabetic()) {
+        if word.eq_ignore_ascii_case("incorrect") {
+            return Some(ExampleKind::Incorrect);
+        }
+        if word.eq_ignore_ascii_case("correct") {
+            kind = Some(ExampleKind::Correct);
+        }
+    }
+    kind
+}
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+fn serialize_fix<S: Serializer>(fix: &RuleFixMeta, serializer: S) -> Result<S::Ok, S::Error> {
+    match fix {
+        RuleFixMeta::None => serializer.serialize_none(),
+        fix => serializer.serialize_some(&fix.description()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{RuleDocs, rules_metadata};
+
+    #[test]
+    fn parse_sections_and_examples() {
+        let docs = RuleDocs::parse(
+            "### What it does
+
+Disallows `foo`.
+
+### Why is this bad?
+
+`foo` is confusing.
+
+### Examples
+
+Examples of **incorrect** code for this rule:
+```js
+foo();
+```
+
+```ts
+// an incorrect example in a second block
+foo<T>();
+```
+
+Examples of **correct** code for this rule:
+````md
+```js
+bar();
+```
+````
+
+### Options
+
+This rule correctly accepts no options:
+```json
+{}
+```
+",
+        );
+
+        let titles = docs.sections.iter().map(|s| s.title.as_str()).collect::<Vec<_>>();
+        assert_eq!(titles, ["What it does", "Why is this bad?", "Examples", "Options"]);
+        assert_eq!(docs.section("What it does"), Some("Disallows `foo`."));
+        assert!(docs.section("Options").unwrap().ends_with("```json\n{}\n```"));
+
+        let incorrect = docs
+            .incorrect_examples
+            .iter()
+            .map(|e| (e.lang.as_str(), e.code.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            incorrect,
+            [("js", "foo();\n"), ("ts", "// an incorrect example in a second block\nfoo<T>();\n")]
+        );
+        assert_eq!(
+            docs.incorrect_examples[0].description,
+            "Examples of **incorrect** code for this rule:"
+        );
+
+        let correct = docs
+            .correct_examples
+            .iter()
+            .map(|e| (e.lang.as_str(), e.code.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(correct, [("md", "```js\nbar();\n```\n")]);
+    }
+
+    #[test]
+    fn all_rules_have_structured_docs() {
+        let rules = rules_metadata();
+        assert!(!rules.is_empty());
+        for rule in &rules {
+            let name = format!("{}/{}", rule.plugin, rule.name);
+            assert!(!rule.docs.sections.is_empty(), "Rule '{name}' is missing documentation.");
+            let documentation = rule.docs.sections.iter().map(|s| s.content.as_str());
+            if documentation.clone().any(|s| s.contains("Examples of **incorrect** code")) {
+                assert!(!rule.docs.incorrect_examples.is_empty(), "{name}");
+            }
+            if documentation.clone().any(|s| s.contains("Examples of **correct** code")) {
+                assert!(!rule.docs.correct_examples.is_empty(), "{name}");
+            }
+        }
+    }
+}
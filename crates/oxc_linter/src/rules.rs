@@ -26,6 +26,7 @@ pub(crate) mod import {
     pub mod no_duplicates;
     pub mod no_dynamic_require;
     pub mod no_empty_named_blocks;
+    pub mod no_missing_extension;
     pub mod no_mutable_exports;
     pub mod no_named_as_default;
     pub mod no_named_as_default_member;
@@ -558,6 +559,7 @@ pub(crate) mod oxc {
     pub mod const_comparisons;
     pub mod double_comparisons;
     pub mod erasing_op;
+    pub mod max_cognitive_complexity;
     pub mod misrefactored_assign_op;
     pub mod missing_throw;
     pub mod no_accumulating_spread;
@@ -565,6 +567,7 @@ pub(crate) mod oxc {
     pub mod no_async_endpoint_handlers;
     pub mod no_barrel_file;
     pub mod no_const_enum;
+    pub mod no_large_file;
     pub mod no_map_spread;
     pub mod no_optional_chaining;
     pub mod no_rest_spread_properties;
@@ -653,6 +656,12 @@ pub(crate) mod node {
     pub mod no_process_env;
 }
 
+pub(crate) mod json {
+    pub mod no_duplicate_keys;
+    pub mod no_trailing_commas;
+    pub mod valid_package_json;
+}
+
 pub(crate) mod vue {
     pub mod define_emits_declaration;
     pub mod define_props_declaration;
@@ -850,6 +859,7 @@ oxc_macros::declare_all_lint_rules! {
     import::no_default_export,
     import::no_duplicates,
     import::no_dynamic_require,
+    import::no_missing_extension,
     import::no_named_as_default,
     import::no_named_as_default_member,
     import::no_self_import,
@@ -989,6 +999,7 @@ oxc_macros::declare_all_lint_rules! {
     oxc::const_comparisons,
     oxc::double_comparisons,
     oxc::erasing_op,
+    oxc::max_cognitive_complexity,
     oxc::misrefactored_assign_op,
     oxc::missing_throw,
     oxc::no_accumulating_spread,
@@ -996,6 +1007,7 @@ oxc_macros::declare_all_lint_rules! {
     oxc::no_async_endpoint_handlers,
     oxc::no_barrel_file,
     oxc::no_const_enum,
+    oxc::no_large_file,
     oxc::no_map_spread,
     oxc::no_optional_chaining,
     oxc::no_rest_spread_properties,
@@ -1268,6 +1280,9 @@ oxc_macros::declare_all_lint_rules! {
     unicorn::switch_case_braces,
     unicorn::text_encoding_identifier_case,
     unicorn::throw_new_error,
+    json::no_duplicate_keys,
+    json::no_trailing_commas,
+    json::valid_package_json,
     vitest::no_conditional_tests,
     vitest::no_import_node_test,
     vitest::prefer_to_be_falsy,
@@ -111,6 +111,7 @@ declare_oxc_lint!(
     eslint,
     restriction,
     config = ClassMethodsUseThisConfig,
+    unchecked_config,
 );
 
 impl Rule for ClassMethodsUseThis {
@@ -263,6 +263,7 @@ declare_oxc_lint!(
     style,
     fix,
     config = CurlyConfig,
+    unchecked_config,
 );
 
 impl Rule for Curly {
@@ -162,6 +162,7 @@ declare_oxc_lint!(
     pedantic,
     fix = conditional_fix_dangerous,
     config = Eqeqeq,
+    unchecked_config,
 );
 
 #[derive(Debug, Default, Clone, JsonSchema)]
@@ -191,7 +191,8 @@ declare_oxc_lint!(
     eslint,
     style,
     fix = pending,
-    config = FuncStyle
+    config = FuncStyle,
+    unchecked_config,
 );
 
 fn is_ancestor_export_name_decl<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
@@ -14,14 +14,10 @@ use oxc_cfg::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{SourceType, Span};
 use schemars::JsonSchema;
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, frameworks::FrameworkOptions, rule::Rule};
 
 fn getter_return_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Expected to always return a value in getter.")
@@ -109,9 +105,13 @@ impl Rule for GetterReturn {
         Self { allow_implicit }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
         // https://eslint.org/docs/latest/rules/getter-return#handled_by_typescript
-        !ctx.source_type().is_typescript()
+        !source_type.is_typescript()
     }
 }
 
@@ -185,6 +185,7 @@ declare_oxc_lint!(
     style,
     pending,
     config = GroupedAccessorPairs,
+    unchecked_config,
 );
 
 impl Rule for GroupedAccessorPairs {
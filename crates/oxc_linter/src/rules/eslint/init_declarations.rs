@@ -118,6 +118,7 @@ declare_oxc_lint!(
     eslint,
     style,
     config = InitDeclarations,
+    unchecked_config,
 );
 
 impl Rule for InitDeclarations {
@@ -10,6 +10,8 @@ use crate::{context::LintContext, rule::Rule};
 
 fn max_classes_per_file_diagnostic(total: usize, max: usize, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("File has too many classes ({total}). Maximum allowed is {max}",))
+        .with_data("count", total)
+        .with_data("max", max)
         .with_help("Reduce the number of classes in this file")
         .with_label(span)
 }
@@ -70,6 +72,7 @@ declare_oxc_lint!(
     eslint,
     pedantic,
     config = MaxClassesPerFileConfig,
+    unchecked_config,
 );
 
 impl Rule for MaxClassesPerFile {
@@ -11,6 +11,8 @@ use crate::{AstNode, ast_util::is_function_node, context::LintContext, rule::Rul
 
 fn max_depth_diagnostic(num: usize, max: usize, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("Blocks are nested too deeply ({num}). Maximum allowed is {max}."))
+        .with_data("count", num)
+        .with_data("max", max)
         .with_help("Consider refactoring your code.")
         .with_label(span)
 }
@@ -110,6 +112,7 @@ declare_oxc_lint!(
     eslint,
     pedantic,
     config = MaxDepth,
+    unchecked_config,
 );
 
 impl Rule for MaxDepth {
@@ -8,6 +8,8 @@ use crate::{context::LintContext, rule::Rule, utils::count_comment_lines};
 
 fn max_lines_diagnostic(count: usize, max: usize, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("File has too many lines ({count})."))
+        .with_data("count", count)
+        .with_data("max", max)
         .with_help(format!("Maximum allowed is {max}."))
         .with_label(span)
 }
@@ -56,6 +58,7 @@ declare_oxc_lint!(
     eslint,
     pedantic,
     config = MaxLinesConfig,
+    unchecked_config,
 );
 
 impl Rule for MaxLines {
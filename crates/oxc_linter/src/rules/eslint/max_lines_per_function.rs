@@ -25,6 +25,9 @@ fn max_lines_per_function_diagnostic(
     OxcDiagnostic::warn(format!(
         "The {name} has too many lines ({count}). Maximum allowed is {max}."
     ))
+    .with_data("name", name.to_string())
+    .with_data("count", count)
+    .with_data("max", max)
     .with_help("Consider splitting it into smaller functions.")
     .with_label(span)
 }
@@ -117,6 +120,7 @@ declare_oxc_lint!(
     eslint,
     pedantic,
     config = MaxLinesPerFunctionConfig,
+    unchecked_config,
 );
 
 impl Rule for MaxLinesPerFunction {
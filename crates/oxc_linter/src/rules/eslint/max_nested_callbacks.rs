@@ -15,6 +15,8 @@ use crate::{
 
 fn max_nested_callbacks_diagnostic(num: usize, max: usize, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("Too many nested callbacks ({num}). Maximum allowed is {max}."))
+        .with_data("count", num)
+        .with_data("max", max)
         .with_help("Reduce nesting with promises or refactoring your code.")
         .with_label(span)
 }
@@ -87,6 +89,7 @@ declare_oxc_lint!(
     eslint,
     pedantic,
     config = MaxNestedCallbacks,
+    unchecked_config,
 );
 
 impl Rule for MaxNestedCallbacks {
@@ -7,8 +7,26 @@ use serde_json::Value;
 
 use crate::{AstNode, context::LintContext, rule::Rule};
 
-fn max_params_diagnostic(message: &str, span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn(message.to_string())
+fn max_params_diagnostic(
+    kind: &str,
+    name: Option<&str>,
+    count: usize,
+    max: usize,
+    span: Span,
+) -> OxcDiagnostic {
+    let message = match name {
+        Some(name) => {
+            format!("{kind} '{name}' has too many parameters ({count}). Maximum allowed is {max}.")
+        }
+        None => format!("{kind} has too many parameters ({count}). Maximum allowed is {max}."),
+    };
+    let mut diagnostic = OxcDiagnostic::warn(message);
+    if let Some(name) = name {
+        diagnostic = diagnostic.with_data("name", name.to_string());
+    }
+    diagnostic
+        .with_data("count", count)
+        .with_data("max", max)
         .with_help(
             "This rule enforces a maximum number of parameters allowed in function definitions.",
         )
@@ -89,6 +107,7 @@ declare_oxc_lint!(
     eslint,
     style,
     config = MaxParamsConfig,
+    unchecked_config,
 );
 
 impl Rule for MaxParams {
@@ -134,33 +153,25 @@ impl Rule for MaxParams {
                 }
 
                 if real_len > self.max {
-                    if let Some(id) = &function.id {
-                        let function_name = id.name.as_str();
-                        let error_msg = format!(
-                            "Function '{}' has too many parameters ({}). Maximum allowed is {}.",
-                            function_name, real_len, self.max
-                        );
-                        let span = function.params.span;
-                        ctx.diagnostic(max_params_diagnostic(&error_msg, span));
-                    } else {
-                        let error_msg = format!(
-                            "Function has too many parameters ({}). Maximum allowed is {}.",
-                            real_len, self.max
-                        );
-                        let span = function.params.span;
-                        ctx.diagnostic(max_params_diagnostic(&error_msg, span));
-                    }
+                    let name = function.id.as_ref().map(|id| id.name.as_str());
+                    ctx.diagnostic(max_params_diagnostic(
+                        "Function",
+                        name,
+                        real_len,
+                        self.max,
+                        function.params.span,
+                    ));
                 }
             }
             AstKind::ArrowFunctionExpression(function) => {
                 if function.params.items.len() > self.max {
-                    let error_msg = format!(
-                        "Arrow function has too many parameters ({}). Maximum allowed is {}.",
+                    ctx.diagnostic(max_params_diagnostic(
+                        "Arrow function",
+                        None,
                         function.params.items.len(),
-                        self.max
-                    );
-                    let span = function.params.span;
-                    ctx.diagnostic(max_params_diagnostic(&error_msg, span));
+                        self.max,
+                        function.params.span,
+                    ));
                 }
             }
             _ => {}
@@ -1,7 +1,6 @@
-use oxc_ast::{AstKind, ast::BindingIdentifier};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_semantic::AstNode;
+use oxc_semantic::SymbolId;
 use oxc_span::Span;
 
 use crate::{context::LintContext, rule::Rule};
@@ -79,24 +78,13 @@ declare_oxc_lint!(
     /// ```
     NoClassAssign,
     eslint,
-    correctness
+    correctness,
+    symbols = Class
 );
 
 impl Rule for NoClassAssign {
-    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let AstKind::Class(class) = node.kind() else {
-            return;
-        };
-
-        let Some(symbol_id) = class.id.as_ref().map(BindingIdentifier::symbol_id) else {
-            return;
-        };
-
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
         let symbol_table = ctx.scoping();
-        // This should always be considered a class (since we got it from a class declaration),
-        // but we check in debug mode just to be sure.
-        debug_assert!(symbol_table.symbol_flags(symbol_id).is_class());
-
         for reference in symbol_table.get_resolved_references(symbol_id) {
             if reference.is_write() {
                 ctx.diagnostic(no_class_assign_diagnostic(
@@ -6,13 +6,9 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use schemars::JsonSchema;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use crate::{
-    AstNode,
-    context::LintContext,
-    rule::{DefaultRuleConfig, Rule},
-};
+use crate::{AstNode, context::LintContext, rule::Rule};
 
 fn no_cond_assign_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Expected a conditional expression and instead saw an assignment")
@@ -21,11 +17,9 @@ fn no_cond_assign_diagnostic(span: Span) -> OxcDiagnostic {
 }
 
 #[derive(Debug, Default, Clone)]
-pub struct NoCondAssign {
-    config: NoCondAssignConfig,
-}
+pub struct NoCondAssign;
 
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize, JsonSchema)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 enum NoCondAssignConfig {
     /// Allow assignments in conditional expressions only if they are
@@ -71,31 +65,26 @@ declare_oxc_lint!(
     eslint,
     correctness,
     config = NoCondAssignConfig,
+    default_config,
+    typed_config,
 );
 
 impl Rule for NoCondAssign {
-    fn from_configuration(value: serde_json::Value) -> Self {
-        let config = serde_json::from_value::<DefaultRuleConfig<NoCondAssignConfig>>(value)
-            .unwrap_or_default()
-            .into_inner();
-
-        Self { config }
-    }
-
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let config = *ctx.options::<NoCondAssignConfig>();
         match node.kind() {
-            AstKind::IfStatement(stmt) => self.check_expression(ctx, &stmt.test),
-            AstKind::WhileStatement(stmt) => self.check_expression(ctx, &stmt.test),
-            AstKind::DoWhileStatement(stmt) => self.check_expression(ctx, &stmt.test),
+            AstKind::IfStatement(stmt) => Self::check_expression(config, ctx, &stmt.test),
+            AstKind::WhileStatement(stmt) => Self::check_expression(config, ctx, &stmt.test),
+            AstKind::DoWhileStatement(stmt) => Self::check_expression(config, ctx, &stmt.test),
             AstKind::ForStatement(stmt) => {
                 if let Some(expr) = &stmt.test {
-                    self.check_expression(ctx, expr);
+                    Self::check_expression(config, ctx, expr);
                 }
             }
             AstKind::ConditionalExpression(expr) => {
-                self.check_expression(ctx, expr.test.get_inner_expression());
+                Self::check_expression(config, ctx, expr.test.get_inner_expression());
             }
-            AstKind::AssignmentExpression(expr) if self.config == NoCondAssignConfig::Always => {
+            AstKind::AssignmentExpression(expr) if config == NoCondAssignConfig::Always => {
                 let mut spans = vec![];
                 for ancestor in ctx.nodes().ancestors(node.id()) {
                     match ancestor.kind() {
@@ -148,9 +137,9 @@ impl NoCondAssign {
         ctx.diagnostic(no_cond_assign_diagnostic(operator_span));
     }
 
-    fn check_expression(&self, ctx: &LintContext<'_>, expr: &Expression<'_>) {
+    fn check_expression(config: NoCondAssignConfig, ctx: &LintContext<'_>, expr: &Expression<'_>) {
         let mut expr = expr;
-        if self.config == NoCondAssignConfig::Always {
+        if config == NoCondAssignConfig::Always {
             expr = expr.get_inner_expression();
         }
         if let Expression::AssignmentExpression(expr) = expr {
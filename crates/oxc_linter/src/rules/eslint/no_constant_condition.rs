@@ -104,6 +104,7 @@ declare_oxc_lint!(
     eslint,
     correctness,
     config = NoConstantCondition,
+    unchecked_config,
 );
 
 impl Rule for NoConstantCondition {
@@ -10,7 +10,7 @@ use oxc_regular_expression::{
 };
 use oxc_span::Span;
 
-use crate::{AstNode, context::LintContext, rule::Rule, utils::run_on_regex_node};
+use crate::{context::LintContext, rule::Rule, utils::ParsedRegExp};
 
 fn no_control_regex_diagnostic(control_chars: &[Character], span: Span) -> OxcDiagnostic {
     let count = control_chars.len();
@@ -127,10 +127,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoControlRegex {
-    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        run_on_regex_node(node, ctx, |pattern, span| {
-            check_pattern(ctx, pattern, span);
-        });
+    fn run_on_regex<'a>(&self, regex: &ParsedRegExp<'a>, ctx: &LintContext<'a>) {
+        check_pattern(ctx, regex.pattern, regex.span);
     }
 }
 
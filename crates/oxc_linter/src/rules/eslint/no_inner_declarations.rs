@@ -74,6 +74,7 @@ declare_oxc_lint!(
     eslint,
     pedantic,
     config = NoInnerDeclarations,
+    unchecked_config,
 );
 
 impl Rule for NoInnerDeclarations {
@@ -8,7 +8,7 @@ use oxc_regular_expression::{
 use oxc_span::Span;
 use schemars::JsonSchema;
 
-use crate::{AstNode, context::LintContext, rule::Rule, utils::run_on_regex_node};
+use crate::{context::LintContext, rule::Rule, utils::ParsedRegExp};
 
 fn surrogate_pair_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Unexpected surrogate pair in character class.").with_label(span)
@@ -154,52 +154,51 @@ impl Rule for NoMisleadingCharacterClass {
         Self { allow_escape }
     }
 
-    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        run_on_regex_node(node, ctx, |pattern, _span| {
-            let mut collector = CharacterSequenceCollector::new();
-            collector.visit_pattern(pattern);
+    fn run_on_regex<'a>(&self, regex: &ParsedRegExp<'a>, ctx: &LintContext<'a>) {
+        let pattern = regex.pattern;
+        let mut collector = CharacterSequenceCollector::new();
+        collector.visit_pattern(pattern);
 
-            // Restore: push any remaining sequence after visiting
-            if !collector.current_seq.is_empty() {
-                collector.sequences.push(std::mem::take(&mut collector.current_seq));
-            }
+        // Restore: push any remaining sequence after visiting
+        if !collector.current_seq.is_empty() {
+            collector.sequences.push(std::mem::take(&mut collector.current_seq));
+        }
 
-            for unfiltered_chars in &collector.sequences {
-                if self.allow_escape {
-                    let has_escape = unfiltered_chars.iter().any(|c| {
-                        !matches!(
-                            c.kind,
-                            CharacterKind::Symbol
-                                | CharacterKind::Identifier
-                                | CharacterKind::SingleEscape
-                        )
-                    });
-                    if has_escape {
-                        continue;
-                    }
+        for unfiltered_chars in &collector.sequences {
+            if self.allow_escape {
+                let has_escape = unfiltered_chars.iter().any(|c| {
+                    !matches!(
+                        c.kind,
+                        CharacterKind::Symbol
+                            | CharacterKind::Identifier
+                            | CharacterKind::SingleEscape
+                    )
+                });
+                if has_escape {
+                    continue;
                 }
+            }
 
-                // Always check for combining marks, regional indicator, ZWJ, and emoji modifier sequences
-                if combining_class_sequences(unfiltered_chars) {
-                    ctx.diagnostic(combining_class_diagnostic(pattern.span));
-                }
-                if regional_indicator_symbol_sequences(unfiltered_chars) {
-                    ctx.diagnostic(regional_indicator_diagnostic(pattern.span));
-                }
-                if zwj_sequences(unfiltered_chars) {
-                    ctx.diagnostic(zwj_diagnostic(pattern.span));
-                }
-                if emoji_modifier_sequences(unfiltered_chars) {
-                    ctx.diagnostic(emoji_modifiers_diagnostic(pattern.span));
-                }
-                if surrogate_pair_sequences(unfiltered_chars) {
-                    ctx.diagnostic(surrogate_pair_diagnostic(pattern.span));
-                }
-                if surrogate_pair_sequences_without_flag(unfiltered_chars) {
-                    ctx.diagnostic(surrogate_pair_diagnostic(pattern.span));
-                }
+            // Always check for combining marks, regional indicator, ZWJ, and emoji modifier sequences
+            if combining_class_sequences(unfiltered_chars) {
+                ctx.diagnostic(combining_class_diagnostic(pattern.span));
+            }
+            if regional_indicator_symbol_sequences(unfiltered_chars) {
+                ctx.diagnostic(regional_indicator_diagnostic(pattern.span));
+            }
+            if zwj_sequences(unfiltered_chars) {
+                ctx.diagnostic(zwj_diagnostic(pattern.span));
+            }
+            if emoji_modifier_sequences(unfiltered_chars) {
+                ctx.diagnostic(emoji_modifiers_diagnostic(pattern.span));
             }
-        });
+            if surrogate_pair_sequences(unfiltered_chars) {
+                ctx.diagnostic(surrogate_pair_diagnostic(pattern.span));
+            }
+            if surrogate_pair_sequences_without_flag(unfiltered_chars) {
+                ctx.diagnostic(surrogate_pair_diagnostic(pattern.span));
+            }
+        }
     }
 }
 
@@ -2,13 +2,10 @@ use javascript_globals::GLOBALS;
 
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{ModuleKind, Span};
+use oxc_span::{ModuleKind, SourceType, Span};
 use schemars::JsonSchema;
 
-use crate::{
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{context::LintContext, frameworks::FrameworkOptions, rule::Rule};
 
 fn no_redeclare_diagnostic(name: &str, decl_span: Span, re_decl_span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("'{name}' is already defined.")).with_labels([
@@ -63,6 +60,7 @@ declare_oxc_lint!(
     eslint,
     pedantic,
     config = NoRedeclare,
+    unchecked_config,
 );
 
 impl Rule for NoRedeclare {
@@ -124,9 +122,13 @@ impl Rule for NoRedeclare {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
         // Modules run in their own scope, and don't conflict with existing globals
-        ctx.source_type().module_kind() == ModuleKind::Script
+        source_type.module_kind() == ModuleKind::Script
     }
 }
 
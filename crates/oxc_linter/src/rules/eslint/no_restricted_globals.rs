@@ -60,6 +60,7 @@ declare_oxc_lint!(
     eslint,
     restriction,
     config = NoRestrictedGlobals,
+    unchecked_config,
 );
 
 impl Rule for NoRestrictedGlobals {
@@ -49,6 +49,7 @@ declare_oxc_lint!(
     style,
     pending, // TODO: add a suggestion
     config = NoReturnAssign,
+    unchecked_config,
 );
 
 fn is_sentinel_node(ast_kind: AstKind) -> bool {
@@ -2,6 +2,7 @@ use crate::{context::LintContext, rule::Rule};
 use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_semantic::SymbolId;
 use oxc_span::Span;
 use schemars::JsonSchema;
 use serde_json::Value;
@@ -96,38 +97,34 @@ impl Rule for NoShadowRestrictedNames {
         }))
     }
 
-    fn run_once(&self, ctx: &LintContext) {
-        for symbol_id in ctx.scoping().symbol_ids() {
-            let name = ctx.scoping().symbol_name(symbol_id);
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        let name = ctx.scoping().symbol_name(symbol_id);
 
-            if !(PRE_DEFINE_VAR.contains(&name)
-                || self.0.report_global_this && name == "globalThis")
-            {
-                continue;
-            }
+        if !(PRE_DEFINE_VAR.contains(&name) || self.0.report_global_this && name == "globalThis") {
+            return;
+        }
 
-            if name == "undefined" {
-                // Allow to declare `undefined` variable but not allow to assign value to it.
-                let node_id = ctx.scoping().symbol_declaration(symbol_id);
-                if let AstKind::VariableDeclarator(declarator) = ctx.nodes().kind(node_id)
-                    && declarator.init.is_none()
-                    && ctx
-                        .scoping()
-                        .get_resolved_references(symbol_id)
-                        .all(|reference| !reference.is_write())
-                {
-                    continue;
-                }
+        if name == "undefined" {
+            // Allow to declare `undefined` variable but not allow to assign value to it.
+            let node_id = ctx.scoping().symbol_declaration(symbol_id);
+            if let AstKind::VariableDeclarator(declarator) = ctx.nodes().kind(node_id)
+                && declarator.init.is_none()
+                && ctx
+                    .scoping()
+                    .get_resolved_references(symbol_id)
+                    .all(|reference| !reference.is_write())
+            {
+                return;
             }
+        }
 
-            let redeclarations = ctx.scoping().symbol_redeclarations(symbol_id);
-            if redeclarations.is_empty() {
-                let span = ctx.scoping().symbol_span(symbol_id);
-                ctx.diagnostic(no_shadow_restricted_names_diagnostic(name, span));
-            } else {
-                for rd in redeclarations {
-                    ctx.diagnostic(no_shadow_restricted_names_diagnostic(name, rd.span));
-                }
+        let redeclarations = ctx.scoping().symbol_redeclarations(symbol_id);
+        if redeclarations.is_empty() {
+            let span = ctx.scoping().symbol_span(symbol_id);
+            ctx.diagnostic(no_shadow_restricted_names_diagnostic(name, span));
+        } else {
+            for rd in redeclarations {
+                ctx.diagnostic(no_shadow_restricted_names_diagnostic(name, rd.span));
             }
         }
     }
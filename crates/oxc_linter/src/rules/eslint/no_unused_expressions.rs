@@ -58,6 +58,7 @@ declare_oxc_lint!(
     eslint,
     correctness,
     config = NoUnusedExpressionsConfig,
+    unchecked_config,
 );
 
 impl Rule for NoUnusedExpressions {
@@ -52,6 +52,7 @@ where
 
     OxcDiagnostic::warn(format!("{pronoun_singular} '{name}' is marked as ignored but is used."))
         .with_label(symbol.span().label(format!("'{name}' is declared here")))
+        .with_data("identifier", name.to_string())
         .with_help(format!(
             "Consider renaming this {}{help_suffix}",
             pronoun_singular.cow_to_ascii_lowercase()
@@ -82,6 +83,7 @@ where
         format!("{pronoun} '{name}' is {verb} but never used.{suffix}")
     })
     .with_label(symbol.span().label(format!("'{name}' is declared here")))
+    .with_data("identifier", name.to_string())
     .with_help(help)
 }
 
@@ -103,6 +105,7 @@ where
             symbol.span().label(format!("'{name}' is declared here")),
             assign_span.label("it was last assigned here"),
         ])
+        .with_data("identifier", name.to_string())
         .with_help("Did you mean to use this variable?")
 }
 
@@ -116,6 +119,7 @@ where
 
     OxcDiagnostic::warn(format!("Parameter '{name}' is declared but never used.{suffix}"))
         .with_label(symbol.span().label(format!("'{name}' is declared here")))
+        .with_data("identifier", name.to_string())
         .with_help("Consider removing this parameter.")
 }
 
@@ -126,5 +130,6 @@ pub fn imported(symbol: &Symbol<'_, '_>) -> OxcDiagnostic {
 
     OxcDiagnostic::warn(format!("{pronoun} '{name}' is imported but never used."))
         .with_label(symbol.span().label(format!("'{name}' is imported here")))
+        .with_data("identifier", name.to_string())
         .with_help("Consider removing this import.")
 }
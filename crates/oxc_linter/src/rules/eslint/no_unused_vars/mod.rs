@@ -190,7 +190,8 @@ declare_oxc_lint!(
     eslint,
     correctness,
     dangerous_suggestion,
-    config = NoUnusedVarsOptions
+    config = NoUnusedVarsOptions,
+    unchecked_config,
 );
 
 impl Deref for NoUnusedVars {
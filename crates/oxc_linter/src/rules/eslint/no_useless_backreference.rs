@@ -7,7 +7,7 @@ use oxc_regular_expression::{
 };
 use oxc_span::Span;
 
-use crate::{AstNode, context::LintContext, rule::Rule, utils::run_on_regex_node};
+use crate::{context::LintContext, rule::Rule, utils::ParsedRegExp};
 
 fn no_useless_backreference_diagnostic(
     span: Span,
@@ -66,24 +66,23 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUselessBackreference {
-    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        run_on_regex_node(node, ctx, |pattern, _span| {
-            let mut collector = RegexCollector::new();
-            collector.visit_pattern(pattern);
-
-            for bref in &collector.backrefs {
-                if let Some((problem, cap_group_span, bref_group_span)) =
-                    problems_for_backref(bref, &collector.nodes, &collector.groups)
-                {
-                    ctx.diagnostic(no_useless_backreference_diagnostic(
-                        bref.span,
-                        &problem,
-                        ctx.source_range(bref_group_span),
-                        ctx.source_range(cap_group_span),
-                    ));
-                }
+    fn run_on_regex<'a>(&self, regex: &ParsedRegExp<'a>, ctx: &LintContext<'a>) {
+        let pattern = regex.pattern;
+        let mut collector = RegexCollector::new();
+        collector.visit_pattern(pattern);
+
+        for bref in &collector.backrefs {
+            if let Some((problem, cap_group_span, bref_group_span)) =
+                problems_for_backref(bref, &collector.nodes, &collector.groups)
+            {
+                ctx.diagnostic(no_useless_backreference_diagnostic(
+                    bref.span,
+                    &problem,
+                    ctx.source_range(bref_group_span),
+                    ctx.source_range(cap_group_span),
+                ));
             }
-        });
+        }
     }
 }
 
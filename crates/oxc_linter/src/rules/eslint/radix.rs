@@ -65,6 +65,7 @@ declare_oxc_lint!(
     pedantic,
     conditional_fix_dangerous,
     config = Radix,
+    unchecked_config,
 );
 
 impl Rule for Radix {
@@ -49,6 +49,7 @@ declare_oxc_lint!(
     restriction,
     fix,
     config = UnicodeBom,
+    unchecked_config,
 );
 
 impl Rule for UnicodeBom {
@@ -92,6 +92,7 @@ declare_oxc_lint!(
     style,
     conditional_fix,
     config = ConsistentTypeSpecifierStyle,
+    unchecked_config,
 );
 
 impl Rule for ConsistentTypeSpecifierStyle {
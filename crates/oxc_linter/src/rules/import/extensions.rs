@@ -174,6 +174,7 @@ declare_oxc_lint!(
     import,
     restriction,
     config = ExtensionsConfig,
+    unchecked_config,
 );
 
 impl Rule for Extensions {
@@ -88,6 +88,7 @@ declare_oxc_lint!(
     style,
     pending,  // TODO: fixer
     config = First,
+    unchecked_config,
 );
 
 fn is_relative_path(path: &str) -> bool {
@@ -25,6 +25,7 @@ fn no_cycle_diagnostic(span: Span, paths: &str) -> OxcDiagnostic {
 #[serde(rename_all = "camelCase", default)]
 pub struct NoCycle {
     /// Maximum dependency depth to traverse
+    #[schemars(with = "Option<MaxDepth>")]
     max_depth: u32,
     /// Ignore type-only imports
     ignore_types: bool,
@@ -32,6 +33,26 @@ pub struct NoCycle {
     ignore_external: bool,
     /// Allow cyclic dependency if there is at least one dynamic import in the chain
     allow_unsafe_dynamic_cyclic_dependency: bool,
+    /// Report each cycle once, from the module of the cycle with the smallest path, instead of
+    /// from every module of the cycle. The cycle is not reported if this module is not linted,
+    /// e.g. when linting only changed files. Enabled by default, `reportOnce: false` reports the cycle from every module.
+    report_once: bool,
+}
+
+/// A positive integer, or `"∞"` for no limit.
+#[derive(JsonSchema)]
+#[serde(untagged)]
+#[expect(dead_code)]
+enum MaxDepth {
+    Depth(u32),
+    Unlimited(Unlimited),
+}
+
+#[derive(JsonSchema)]
+#[expect(dead_code)]
+enum Unlimited {
+    #[serde(rename = "∞")]
+    Infinity,
 }
 
 impl Default for NoCycle {
@@ -41,6 +62,7 @@ impl Default for NoCycle {
             ignore_types: true,
             ignore_external: false,
             allow_unsafe_dynamic_cyclic_dependency: false,
+            report_once: true,
         }
     }
 }
@@ -115,6 +137,10 @@ impl Rule for NoCycle {
                 .and_then(|v| v.get("allowUnsafeDynamicCyclicDependency"))
                 .and_then(serde_json::Value::as_bool)
                 .unwrap_or(default.allow_unsafe_dynamic_cyclic_dependency),
+            report_once: obj
+                .and_then(|v| v.get("reportOnce"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(default.report_once),
         }
     }
 
@@ -126,6 +152,7 @@ impl Rule for NoCycle {
 
         let mut stack = Vec::new();
         let ignore_types = self.ignore_types;
+        let report_once = self.report_once;
         let visitor_result = ModuleGraphVisitorBuilder::default()
             .max_depth(self.max_depth)
             .filter(move |(key, val): (&CompactStr, &Arc<ModuleRecord>), parent: &ModuleRecord| {
@@ -139,6 +166,12 @@ impl Rule for NoCycle {
                     return false;
                 }
 
+                // With `reportOnce`, a cycle is reported by its module with the smallest path only,
+                // instead of once per module.
+                if report_once && path < needle {
+                    return false;
+                }
+
                 if ignore_types {
                     let import_entries = parent
                         .import_entries
@@ -407,3 +440,49 @@ fn test() {
         .with_import_plugin(true)
         .test_and_snapshot();
 }
+
+#[test]
+fn test_report_once_per_cycle() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    // `a.js` -> `b.js` -> `c.js` -> `a.js` is reported by `a.js` only, unless `reportOnce` is
+    // disabled.
+    let pass = vec![
+        (r#"import { c } from "./c""#, None),
+        (r#"import { c } from "./c""#, Some(json!([{ "reportOnce": true }]))),
+    ];
+    let fail = vec![(r#"import { c } from "./c""#, Some(json!([{ "reportOnce": false }])))];
+    Tester::new(NoCycle::NAME, NoCycle::PLUGIN, pass, fail)
+        .change_rule_path("cycles/report-once/b.js")
+        .with_import_plugin(true)
+        .test();
+
+    let pass = vec![];
+    let fail = vec![
+        (r#"import { b } from "./b""#, None),
+        (r#"import { b } from "./b""#, Some(json!([{ "reportOnce": false }]))),
+    ];
+    Tester::new(NoCycle::NAME, NoCycle::PLUGIN, pass, fail)
+        .change_rule_path("cycles/report-once/a.js")
+        .with_import_plugin(true)
+        .with_snapshot_suffix("report_once")
+        .test_and_snapshot();
+}
+
+#[test]
+fn test_invalid_options() {
+    use schemars::SchemaGenerator;
+    use serde_json::json;
+
+    use crate::{config::check_options, rules::RuleEnum};
+
+    let rule = RuleEnum::ImportNoCycle(NoCycle::default());
+    let check = |options| check_options(&rule, &[options], &mut SchemaGenerator::default());
+    assert!(check(json!({ "maxDepth": 2, "reportOnce": false })).is_ok());
+    assert!(check(json!({ "maxDepth": "∞" })).is_ok());
+    assert!(check(json!({ "maxDepth": "2" })).is_err());
+    assert!(check(json!({ "reportOnce": 1 })).is_err());
+    assert!(check(json!({ "maxDeph": 2 })).is_err());
+}
@@ -0,0 +1,190 @@
+use std::path::Path;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+
+use crate::{context::LintContext, rule::Rule};
+
+fn no_missing_extension_diagnostic(span: Span, specifier: &str, fixed: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Missing file extension in import of `{specifier}`"))
+        .with_help(format!("Import `{fixed}` instead."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoMissingExtension;
+
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoMissingExtensionConfig {
+    /// Extensions (without the leading dot) which may be omitted, e.g. `["ts", "tsx"]` when a
+    /// bundler resolves TypeScript files without an extension.
+    ignore: Vec<CompactStr>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces explicit file extensions in relative imports and re-exports.
+    ///
+    /// The module resolver finds the file each specifier points to, and the autofix adds its
+    /// extension, or `/index.<ext>` for imports of a directory. Specifiers which cannot be
+    /// resolved are not reported.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// ES modules in Node.js and browsers resolve specifiers as written: `./foo` does not load
+    /// `./foo.js`, and `./dir` does not load `./dir/index.js`. Omitted extensions only work with
+    /// bundlers or CommonJS resolution.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import foo from './foo';
+    /// import { bar } from '../bar';
+    /// export * from './utils';
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import foo from './foo.js';
+    /// import { bar } from '../bar.js';
+    /// export * from './utils/index.js';
+    /// import lodash from 'lodash';
+    /// ```
+    NoMissingExtension,
+    import,
+    restriction,
+    fix,
+    config = NoMissingExtensionConfig,
+    typed_config,
+);
+
+impl Rule for NoMissingExtension {
+    fn run_once(&self, ctx: &LintContext) {
+        let module_record = ctx.module_record();
+        let ignore = &ctx.options::<NoMissingExtensionConfig>().ignore;
+
+        for (specifier, requested_modules) in &module_record.requested_modules {
+            if !is_relative_specifier(specifier) {
+                continue;
+            }
+            let Some(resolved_path) = ctx.resolved_module_path(specifier) else {
+                continue;
+            };
+            let Some((suffix, extension)) = missing_suffix(specifier, resolved_path) else {
+                continue;
+            };
+            if ignore.iter().any(|ignored| ignored == extension) {
+                continue;
+            }
+
+            let fixed = format!("{specifier}{suffix}");
+            // Type-only imports are removed when compiling, so the extension does not matter.
+            for requested_module in requested_modules.iter().filter(|module| !module.is_type) {
+                let span = requested_module.span;
+                // Keep the quotes of the string literal.
+                let inner = Span::new(span.start + 1, span.end - 1);
+                ctx.diagnostic_with_fix(
+                    no_missing_extension_diagnostic(span, specifier, &fixed),
+                    |fixer| fixer.replace(inner, fixed.clone()),
+                );
+            }
+        }
+    }
+}
+
+fn is_relative_specifier(specifier: &str) -> bool {
+    matches!(specifier, "." | "..") || specifier.starts_with("./") || specifier.starts_with("../")
+}
+
+/// Returns the suffix to append to `specifier` so that it names `resolved_path` exactly, together
+/// with the extension of `resolved_path`, or `None` if the specifier already has an extension.
+fn missing_suffix<'p>(specifier: &str, resolved_path: &'p Path) -> Option<(String, &'p str)> {
+    let extension = resolved_path.extension()?.to_str()?;
+    let file_name = resolved_path.file_name()?.to_str()?;
+    let is_index = resolved_path.file_stem().is_some_and(|stem| stem == "index");
+    let last_segment = specifier.rsplit('/').next().unwrap_or(specifier);
+
+    if last_segment == file_name {
+        return None;
+    }
+
+    // `./dir/`, `.` and `..` always name a directory.
+    if matches!(last_segment, "" | "." | "..") {
+        let separator = if last_segment.is_empty() { "" } else { "/" };
+        return is_index.then(|| (format!("{separator}index.{extension}"), extension));
+    }
+
+    if file_name.strip_prefix(last_segment).and_then(|rest| rest.strip_prefix('.'))
+        == Some(extension)
+    {
+        return Some((format!(".{extension}"), extension));
+    }
+
+    let is_directory = resolved_path
+        .parent()
+        .and_then(Path::file_name)
+        .is_some_and(|directory| directory == last_segment);
+    if is_directory && is_index {
+        return Some((format!("/index.{extension}"), extension));
+    }
+
+    // e.g. `./foo.js` resolved to `foo.ts` by an extension alias of `tsconfig.json`.
+    None
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        (r"import foo from './foo.js'", None),
+        (r"import './foo.js'", None),
+        (r"export * from './bar.js'", None),
+        (r"export { default } from './foo.js'", None),
+        (r"import data from './data.json'", None),
+        (r"import dotted from './file.with.dot.js'", None),
+        (r"import constants from './constants/index.js'", None),
+        (r"import lodash from 'lodash'", None),
+        (r"import fs from 'node:fs'", None),
+        // Unresolved specifiers are not reported.
+        (r"import missing from './does-not-exist'", None),
+        // CommonJS is not checked.
+        (r"const foo = require('./foo')", None),
+        (r"import foo from './foo'", Some(json!([{ "ignore": ["js"] }]))),
+    ];
+
+    let fail = vec![
+        (r"import foo from './foo'", None),
+        (r"import './foo'", None),
+        (r"export { default } from './foo'", None),
+        (r"export * from './bar'", None),
+        (r"import dotted from './file.with.dot'", None),
+        (r"import constants from './constants'", None),
+        (r"import constants from './constants/'", None),
+        (r"import a from './deep/a'", None),
+        (r"import foo from './foo'", Some(json!([{ "ignore": ["ts"] }]))),
+    ];
+
+    let fix = vec![
+        (r"import foo from './foo'", r"import foo from './foo.js'"),
+        (r#"import foo from "./foo""#, r#"import foo from "./foo.js""#),
+        (r"export * from './bar'", r"export * from './bar.js'"),
+        (r"import dotted from './file.with.dot'", r"import dotted from './file.with.dot.js'"),
+        (r"import constants from './constants'", r"import constants from './constants/index.js'"),
+        (r"import constants from './constants/'", r"import constants from './constants/index.js'"),
+    ];
+
+    Tester::new(NoMissingExtension::NAME, NoMissingExtension::PLUGIN, pass, fail)
+        .change_rule_path("index.js")
+        .with_import_plugin(true)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -98,6 +98,7 @@ declare_oxc_lint!(
     jest,
     correctness,
     config = ExpectExpectConfig,
+    unchecked_config,
 );
 
 impl Rule for ExpectExpect {
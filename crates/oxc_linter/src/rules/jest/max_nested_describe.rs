@@ -9,7 +9,7 @@ use oxc_span::Span;
 
 use crate::{
     context::LintContext,
-    rule::{DefaultRuleConfig, Rule},
+    rule::Rule,
     utils::{
         JestFnKind, JestGeneralFnKind, PossibleJestNode, collect_possible_jest_call_node,
         is_type_of_jest_fn_call,
@@ -22,15 +22,18 @@ fn exceeded_max_depth(current: usize, max: usize, span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
+#[derive(Debug, Default, Clone)]
+pub struct MaxNestedDescribe;
+
 #[derive(Debug, Clone, JsonSchema, Deserialize)]
 #[serde(rename_all = "camelCase", default)]
 #[schemars(default)]
-pub struct MaxNestedDescribe {
+pub struct MaxNestedDescribeConfig {
     /// Maximum allowed depth of nested describe calls.
     pub max: usize,
 }
 
-impl Default for MaxNestedDescribe {
+impl Default for MaxNestedDescribeConfig {
     fn default() -> Self {
         Self { max: 5 }
     }
@@ -123,30 +126,24 @@ declare_oxc_lint!(
     MaxNestedDescribe,
     jest,
     style,
-    config = MaxNestedDescribe
+    config = MaxNestedDescribeConfig,
+    typed_config,
 );
 
 impl Rule for MaxNestedDescribe {
-    fn from_configuration(value: serde_json::Value) -> Self {
-        serde_json::from_value::<DefaultRuleConfig<MaxNestedDescribe>>(value)
-            .unwrap_or_default()
-            .into_inner()
-    }
-
     fn run_once(&self, ctx: &LintContext) {
         let mut describes_hooks_depth: Vec<ScopeId> = vec![];
         let mut possibles_jest_nodes = collect_possible_jest_call_node(ctx);
         possibles_jest_nodes.sort_unstable_by_key(|n| n.node.id());
 
         for possible_jest_node in &possibles_jest_nodes {
-            self.run(possible_jest_node, &mut describes_hooks_depth, ctx);
+            Self::run(possible_jest_node, &mut describes_hooks_depth, ctx);
         }
     }
 }
 
 impl MaxNestedDescribe {
     fn run<'a>(
-        &self,
         possible_jest_node: &PossibleJestNode<'a, '_>,
         describes_hooks_depth: &mut Vec<ScopeId>,
         ctx: &LintContext<'a>,
@@ -167,12 +164,9 @@ impl MaxNestedDescribe {
             describes_hooks_depth.push(scope_id);
         }
 
-        if is_describe_call && describes_hooks_depth.len() > self.max {
-            ctx.diagnostic(exceeded_max_depth(
-                describes_hooks_depth.len(),
-                self.max,
-                call_expr.span,
-            ));
+        let max = ctx.options::<MaxNestedDescribeConfig>().max;
+        if is_describe_call && describes_hooks_depth.len() > max {
+            ctx.diagnostic(exceeded_max_depth(describes_hooks_depth.len(), max, call_expr.span));
         }
     }
 }
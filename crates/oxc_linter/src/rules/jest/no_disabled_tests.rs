@@ -1,3 +1,6 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
 use oxc_ast::{AstKind, ast::Expression};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -7,14 +10,25 @@ use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        JestFnKind, JestGeneralFnKind, ParsedGeneralJestFnCall, PossibleJestNode,
-        parse_general_jest_fn_call,
+        JestFnKind, JestGeneralFnKind, MOCHA_METHOD_NAMES, MOCHA_ONLY_METHOD_NAMES,
+        ParsedGeneralJestFnCall, PossibleJestNode, iter_mocha_fn_calls, parse_general_jest_fn_call,
     },
 };
 
 #[derive(Debug, Default, Clone)]
 pub struct NoDisabledTests;
 
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+#[schemars(default)]
+pub struct NoDisabledTestsConfig {
+    /// Also check the globals of mocha-style test APIs, such as `context`, `specify` and `suite`,
+    /// including in files which are not detected as Jest or Vitest test files.
+    mocha_globals: bool,
+    /// Also report tests which are planned but not written yet with `test.todo`.
+    report_todo: bool,
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -50,6 +64,18 @@ declare_oxc_lint!(
     /// });
     /// ```
     ///
+    /// Examples of **incorrect** code for this rule with `{ "mochaGlobals": true }`:
+    /// ```js
+    /// context.skip('foo', () => {});
+    /// xspecify('foo', () => {});
+    /// suite.skip('foo', () => {});
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "reportTodo": true }`:
+    /// ```js
+    /// test.todo('foo');
+    /// ```
+    ///
     /// This rule is compatible with [eslint-plugin-vitest](https://github.com/veritem/eslint-plugin-vitest/blob/v1.1.9/docs/rules/no-disabled-tests.md),
     /// to use it, add the following configuration to your `.eslintrc.json`:
     ///
@@ -62,7 +88,9 @@ declare_oxc_lint!(
     /// ```
     NoDisabledTests,
     jest,
-    correctness
+    correctness,
+    config = NoDisabledTestsConfig,
+    typed_config,
 );
 
 fn no_disabled_tests_diagnostic(x1: &'static str, x2: &'static str, span3: Span) -> OxcDiagnostic {
@@ -76,6 +104,7 @@ enum Message {
     DisabledSuiteWithX,
     DisabledTestWithSkip,
     DisabledTestWithX,
+    Todo,
 }
 
 impl Message {
@@ -87,6 +116,7 @@ impl Message {
             Self::DisabledSuiteWithX => ("Disabled test suite", "Remove x prefix"),
             Self::DisabledTestWithSkip => ("Disabled test", "Remove the appending `.skip`"),
             Self::DisabledTestWithX => ("Disabled test", "Remove x prefix"),
+            Self::Todo => ("Test is not implemented yet", "Implement the test"),
         }
     }
 }
@@ -97,20 +127,59 @@ impl Rule for NoDisabledTests {
         jest_node: &PossibleJestNode<'a, 'c>,
         ctx: &'c LintContext<'a>,
     ) {
-        run(jest_node, ctx);
+        Self::run(jest_node, ctx);
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        if !ctx.options::<NoDisabledTestsConfig>().mocha_globals {
+            return;
+        }
+        // Globals of Jest and Vitest are checked by `run_on_jest_node` in their test files.
+        let names: &[&str] =
+            if ctx.frameworks().is_test() { &MOCHA_ONLY_METHOD_NAMES } else { &MOCHA_METHOD_NAMES };
+        for call in iter_mocha_fn_calls(ctx.semantic(), names) {
+            let is_suite =
+                matches!(call.name.trim_start_matches('x'), "context" | "describe" | "suite");
+            let message = if call.name.starts_with('x') {
+                if is_suite { Message::DisabledSuiteWithX } else { Message::DisabledTestWithX }
+            } else if matches!(call.modifier, Some(("skip", _, _))) {
+                if is_suite {
+                    Message::DisabledSuiteWithSkip
+                } else {
+                    Message::DisabledTestWithSkip
+                }
+            } else {
+                continue;
+            };
+            let (error, help) = message.details();
+            ctx.diagnostic(no_disabled_tests_diagnostic(error, help, call.call_expr.callee.span()));
+        }
     }
 }
 
-fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>) {
-    let node = possible_jest_node.node;
-    if let AstKind::CallExpression(call_expr) = node.kind() {
+impl NoDisabledTests {
+    fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>) {
+        let node = possible_jest_node.node;
+        let AstKind::CallExpression(call_expr) = node.kind() else {
+            return;
+        };
         if let Some(jest_fn_call) = parse_general_jest_fn_call(call_expr, possible_jest_node, ctx) {
             let ParsedGeneralJestFnCall { kind, members, name, .. } = jest_fn_call;
-            // `test('foo')`
             let kind = match kind {
                 JestFnKind::Expect | JestFnKind::ExpectTypeOf | JestFnKind::Unknown => return,
                 JestFnKind::General(kind) => kind,
             };
+            // `test.todo('foo')`
+            if ctx.options::<NoDisabledTestsConfig>().report_todo
+                && matches!(kind, JestGeneralFnKind::Test)
+                && members.iter().any(|member| member.is_name_equal("todo"))
+            {
+                let (error, help) = Message::Todo.details();
+                ctx.diagnostic(no_disabled_tests_diagnostic(error, help, call_expr.callee.span()));
+                return;
+            }
+
+            // `test('foo')`
             if matches!(kind, JestGeneralFnKind::Test)
                 && call_expr.arguments.len() < 2
                 && members.iter().all(|member| member.is_name_unequal("todo"))
@@ -281,3 +350,51 @@ fn test() {
         .with_vitest_plugin(true)
         .test_and_snapshot();
 }
+
+#[test]
+fn test_options() {
+    use std::path::PathBuf;
+
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let mocha_globals = Some(json!([{ "mochaGlobals": true }]));
+    let report_todo = Some(json!([{ "reportTodo": true }]));
+    let source_file = || Some(PathBuf::from("src/foo.js"));
+
+    let pass = vec![
+        ("context.skip('foo', () => {})", None, None, None),
+        ("xspecify('foo', () => {})", None, None, None),
+        ("it.todo('foo')", None, None, None),
+        ("describe.skip('foo', () => {})", None, None, source_file()),
+        ("context('foo', () => {})", mocha_globals.clone(), None, None),
+        ("specify.only('foo', () => {})", mocha_globals.clone(), None, None),
+        ("xspecifySomething()", mocha_globals.clone(), None, None),
+        (
+            "const suite = { skip() {} }; suite.skip('foo', () => {})",
+            mocha_globals.clone(),
+            None,
+            None,
+        ),
+        ("it.todo('foo')", mocha_globals.clone(), None, None),
+        ("test.todo('foo')", report_todo.clone(), None, source_file()),
+    ];
+
+    let fail = vec![
+        ("context.skip('foo', () => {})", mocha_globals.clone(), None, None),
+        ("specify['skip']('foo', () => {})", mocha_globals.clone(), None, None),
+        ("xcontext('foo', () => {})", mocha_globals.clone(), None, None),
+        ("xspecify('foo', () => {})", mocha_globals.clone(), None, None),
+        ("suite.skip('foo', () => {})", mocha_globals.clone(), None, None),
+        ("describe.skip('foo', () => {})", mocha_globals.clone(), None, source_file()),
+        ("xit('foo', () => {})", mocha_globals, None, source_file()),
+        ("it.todo('foo')", report_todo.clone(), None, None),
+        ("test.todo('foo')", report_todo, None, None),
+    ];
+
+    Tester::new(NoDisabledTests::NAME, NoDisabledTests::PLUGIN, pass, fail)
+        .with_jest_plugin(true)
+        .with_snapshot_suffix("options")
+        .test_and_snapshot();
+}
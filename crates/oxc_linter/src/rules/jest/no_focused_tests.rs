@@ -1,3 +1,6 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
 use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -7,8 +10,9 @@ use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        JestFnKind, JestGeneralFnKind, MemberExpressionElement, ParsedGeneralJestFnCall,
-        PossibleJestNode, parse_general_jest_fn_call,
+        JestFnKind, JestGeneralFnKind, MOCHA_METHOD_NAMES, MOCHA_ONLY_METHOD_NAMES,
+        MemberExpressionElement, ParsedGeneralJestFnCall, PossibleJestNode, iter_mocha_fn_calls,
+        parse_general_jest_fn_call,
     },
 };
 
@@ -21,6 +25,15 @@ fn no_focused_tests_diagnostic(span: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct NoFocusedTests;
 
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+#[schemars(default)]
+pub struct NoFocusedTestsConfig {
+    /// Also check the globals of mocha-style test APIs, such as `context`, `specify` and `suite`,
+    /// including in files which are not detected as Jest or Vitest test files.
+    mocha_globals: bool,
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -52,6 +65,13 @@ declare_oxc_lint!(
     /// `();
     /// ```
     ///
+    /// Examples of **incorrect** code for this rule with `{ "mochaGlobals": true }`:
+    /// ```javascript
+    /// context.only('foo', () => {});
+    /// specify.only('foo', () => {});
+    /// suite.only('foo', () => {});
+    /// ```
+    ///
     /// This rule is compatible with [eslint-plugin-vitest](https://github.com/veritem/eslint-plugin-vitest/blob/v1.1.9/docs/rules/no-focused-tests.md),
     /// to use it, add the following configuration to your `.eslintrc.json`:
     ///
@@ -65,7 +85,9 @@ declare_oxc_lint!(
     NoFocusedTests,
     jest,
     correctness,
-    fix
+    fix,
+    config = NoFocusedTestsConfig,
+    typed_config,
 );
 
 impl Rule for NoFocusedTests {
@@ -76,6 +98,22 @@ impl Rule for NoFocusedTests {
     ) {
         run(jest_node, ctx);
     }
+
+    fn run_once(&self, ctx: &LintContext) {
+        if !ctx.options::<NoFocusedTestsConfig>().mocha_globals {
+            return;
+        }
+        // Globals of Jest and Vitest are checked by `run_on_jest_node` in their test files.
+        let names: &[&str] =
+            if ctx.frameworks().is_test() { &MOCHA_ONLY_METHOD_NAMES } else { &MOCHA_METHOD_NAMES };
+        for call in iter_mocha_fn_calls(ctx.semantic(), names) {
+            if let Some(("only", span, computed)) = call.modifier {
+                ctx.diagnostic_with_fix(no_focused_tests_diagnostic(span), |fixer| {
+                    fixer.delete_range(only_span(span, computed))
+                });
+            }
+        }
+    }
 }
 
 fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>) {
@@ -105,16 +143,19 @@ fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>)
 
     let only_node = members.iter().find(|member| member.is_name_equal("only"));
     if let Some(only_node) = only_node {
+        let computed = !matches!(only_node.element, MemberExpressionElement::IdentName(_));
         ctx.diagnostic_with_fix(no_focused_tests_diagnostic(only_node.span), |fixer| {
-            let mut span = only_node.span.expand_left(1);
-            if !matches!(only_node.element, MemberExpressionElement::IdentName(_)) {
-                span = span.expand_right(1);
-            }
-            fixer.delete_range(span)
+            fixer.delete_range(only_span(only_node.span, computed))
         });
     }
 }
 
+/// The span of `.only` or `['only']`, from the span of `only` or `'only'`.
+fn only_span(span: Span, computed: bool) -> Span {
+    let span = span.expand_left(1);
+    if computed { span.expand_right(1) } else { span }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -204,3 +245,49 @@ fn test() {
         .expect_fix(fix)
         .test_and_snapshot();
 }
+
+#[test]
+fn test_mocha_globals() {
+    use std::path::PathBuf;
+
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let mocha_globals = Some(json!([{ "mochaGlobals": true }]));
+    let source_file = || Some(PathBuf::from("src/foo.js"));
+
+    let pass = vec![
+        ("context.only('foo', () => {})", None, None, None),
+        ("describe.only('foo', () => {})", None, None, source_file()),
+        ("context('foo', () => {})", mocha_globals.clone(), None, None),
+        ("specify.skip('foo', () => {})", mocha_globals.clone(), None, None),
+        ("foo.only('bar')", mocha_globals.clone(), None, None),
+        (
+            "const context = { only() {} }; context.only('foo', () => {})",
+            mocha_globals.clone(),
+            None,
+            None,
+        ),
+    ];
+
+    let fail = vec![
+        ("context.only('foo', () => {})", mocha_globals.clone(), None, None),
+        ("specify['only']('foo', () => {})", mocha_globals.clone(), None, None),
+        ("suite.only('foo', () => {})", mocha_globals.clone(), None, None),
+        ("it.only('foo', () => {})", mocha_globals.clone(), None, None),
+        ("describe.only('foo', () => {})", mocha_globals.clone(), None, source_file()),
+        ("context.only('foo', () => {})", mocha_globals.clone(), None, source_file()),
+    ];
+
+    let fix = vec![
+        ("context.only('foo', () => {})", "context('foo', () => {})", mocha_globals.clone()),
+        ("specify['only']('foo', () => {})", "specify('foo', () => {})", mocha_globals),
+    ];
+
+    Tester::new(NoFocusedTests::NAME, NoFocusedTests::PLUGIN, pass, fail)
+        .with_jest_plugin(true)
+        .expect_fix(fix)
+        .with_snapshot_suffix("mocha")
+        .test_and_snapshot();
+}
@@ -8,7 +8,7 @@ use oxc_span::{CompactStr, GetSpan, Span};
 
 use crate::{
     context::LintContext,
-    rule::{DefaultRuleConfig, Rule},
+    rule::Rule,
     utils::{JestFnKind, JestGeneralFnKind, PossibleJestNode, is_type_of_jest_fn_call},
 };
 
@@ -17,7 +17,7 @@ fn unexpected_hook_diagonsitc(span: Span) -> OxcDiagnostic {
 }
 
 #[derive(Debug, Default, Clone)]
-pub struct NoHooks(Box<NoHooksConfig>);
+pub struct NoHooks;
 
 #[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
 #[serde(rename_all = "camelCase", default)]
@@ -26,14 +26,6 @@ pub struct NoHooksConfig {
     allow: Vec<CompactStr>,
 }
 
-impl std::ops::Deref for NoHooks {
-    type Target = NoHooksConfig;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -87,27 +79,21 @@ declare_oxc_lint!(
     jest,
     style,
     config = NoHooksConfig,
+    typed_config,
 );
 
 impl Rule for NoHooks {
-    fn from_configuration(value: serde_json::Value) -> Self {
-        let config = serde_json::from_value::<DefaultRuleConfig<NoHooksConfig>>(value)
-            .unwrap_or_default()
-            .into_inner();
-        Self(Box::new(config))
-    }
-
     fn run_on_jest_node<'a, 'c>(
         &self,
         jest_node: &PossibleJestNode<'a, 'c>,
         ctx: &'c LintContext<'a>,
     ) {
-        self.run(jest_node, ctx);
+        Self::run(jest_node, ctx);
     }
 }
 
 impl NoHooks {
-    fn run<'a>(&self, possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>) {
+    fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>) {
         let node = possible_jest_node.node;
         let AstKind::CallExpression(call_expr) = node.kind() else {
             return;
@@ -123,7 +109,7 @@ impl NoHooks {
 
         if let Expression::Identifier(ident) = &call_expr.callee {
             let name = CompactStr::from(ident.name.as_str());
-            if !self.allow.contains(&name) {
+            if !ctx.options::<NoHooksConfig>().allow.contains(&name) {
                 ctx.diagnostic(unexpected_hook_diagonsitc(call_expr.callee.span()));
             }
         }
@@ -200,5 +186,7 @@ fn test() {
 
     Tester::new(NoHooks::NAME, NoHooks::PLUGIN, pass, fail)
         .with_jest_plugin(true)
+        // `"allow": "undefined"` is not an array, so the rule falls back to its defaults.
+        .intentionally_allow_invalid_options()
         .test_and_snapshot();
 }
@@ -4,9 +4,11 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{SourceType, Span};
 
-use crate::{context::LintContext, rule::Rule, utils::PossibleJestNode};
+use crate::{
+    context::LintContext, frameworks::FrameworkOptions, rule::Rule, utils::PossibleJestNode,
+};
 
 fn add_type_parameter_to_module_mock_diagnostic(module_name: &str, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(
@@ -97,8 +99,12 @@ impl Rule for NoUntypedMockFactory {
         Self::run(jest_node, ctx);
     }
 
-    fn should_run(&self, ctx: &crate::context::ContextHost) -> bool {
-        ctx.source_type().is_typescript()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_typescript()
     }
 }
 
@@ -155,7 +155,8 @@ declare_oxc_lint!(
     jest,
     style,
     fix,
-    config = PreferLowercaseTitleConfig
+    config = PreferLowercaseTitleConfig,
+    unchecked_config,
 );
 
 impl Rule for PreferLowercaseTitle {
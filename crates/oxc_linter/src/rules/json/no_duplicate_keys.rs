@@ -0,0 +1,121 @@
+use std::collections::hash_map::Entry;
+
+use rustc_hash::FxHashMap;
+
+use oxc_ast::{AstKind, ast::ObjectPropertyKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_duplicate_keys_diagnostic(key: &str, first: Span, duplicate: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Duplicate key \"{key}\""))
+        .with_help("Only the last value is kept by JSON parsers, remove the other keys.")
+        .with_labels([
+            first.label(format!("\"{key}\" is first defined here")),
+            duplicate.label("and defined again here"),
+        ])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDuplicateKeys;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows duplicate keys in the objects of JSON files.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// JSON parsers silently keep the value of the last key, so the values of the other keys are
+    /// lost. This usually happens when merging changes, e.g. two `"scripts"` of a `package.json`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```json
+    /// {
+    ///   "name": "foo",
+    ///   "name": "bar"
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```json
+    /// {
+    ///   "name": "foo",
+    ///   "version": "1.0.0"
+    /// }
+    /// ```
+    NoDuplicateKeys,
+    json,
+    correctness,
+);
+
+impl Rule for NoDuplicateKeys {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ObjectExpression(object) = node.kind() else {
+            return;
+        };
+        // The span of the first occurrence of each key
+        let mut keys = FxHashMap::default();
+        for property in &object.properties {
+            let ObjectPropertyKind::ObjectProperty(property) = property else {
+                continue;
+            };
+            let Some(key) = property.key.static_name() else {
+                continue;
+            };
+            let span = property.key.span();
+            match keys.entry(key) {
+                Entry::Occupied(first) => {
+                    ctx.diagnostic(no_duplicate_keys_diagnostic(first.key(), *first.get(), span));
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(span);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"{ "a": 1, "b": 2 }"#, None, None, Some(PathBuf::from("test.json"))),
+        (
+            r#"{ "a": { "a": 1 }, "b": [{ "a": 1 }, { "a": 2 }] }"#,
+            None,
+            None,
+            Some(PathBuf::from("test.json")),
+        ),
+        (r#"{ "a": 1, "A": 2 }"#, None, None, Some(PathBuf::from("test.jsonc"))),
+        // JS files are not linted by the rules of the `json` plugin
+        ("({ a: 1, a: 2 })", None, None, Some(PathBuf::from("test.js"))),
+    ];
+
+    let fail = vec![
+        (r#"{ "a": 1, "a": 2 }"#, None, None, Some(PathBuf::from("test.json"))),
+        (
+            r#"{ "scripts": { "build": "tsc", "test": "vitest", "build": "vite build" } }"#,
+            None,
+            None,
+            Some(PathBuf::from("package.json")),
+        ),
+        (
+            "[{ \"a\": 1 }, { \"b\": 1, \"b\": 1, \"b\": 1 }]",
+            None,
+            None,
+            Some(PathBuf::from("test.jsonc")),
+        ),
+    ];
+
+    Tester::new(NoDuplicateKeys::NAME, NoDuplicateKeys::PLUGIN, pass, fail)
+        .change_rule_path_extension("json")
+        .test_and_snapshot();
+}
@@ -0,0 +1,164 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    loader::{is_jsonc_path, skip_trivia},
+    rule::{DefaultRuleConfig, Rule},
+};
+
+fn no_trailing_commas_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected trailing comma")
+        .with_help("Trailing commas are not valid JSON, remove it.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoTrailingCommas(NoTrailingCommasConfig);
+
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoTrailingCommasConfig {
+    /// Allow trailing commas in JSONC files: files with the `.jsonc` extension, and
+    /// `tsconfig.json`, `jsconfig.json` and `.oxlintrc.json` files, which are read by parsers
+    /// accepting them.
+    allow_in_jsonc: bool,
+}
+
+impl Default for NoTrailingCommasConfig {
+    fn default() -> Self {
+        Self { allow_in_jsonc: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows trailing commas in the objects and arrays of JSON files.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Trailing commas are not valid JSON, so `JSON.parse` and most tools reading JSON files,
+    /// like package managers, fail to read files with trailing commas.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```json
+    /// {
+    ///   "files": ["dist", "src",],
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```json
+    /// {
+    ///   "files": ["dist", "src"]
+    /// }
+    /// ```
+    NoTrailingCommas,
+    json,
+    correctness,
+    fix,
+    config = NoTrailingCommasConfig,
+);
+
+impl Rule for NoTrailingCommas {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(
+            serde_json::from_value::<DefaultRuleConfig<NoTrailingCommasConfig>>(value)
+                .unwrap_or_default()
+                .into_inner(),
+        )
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let last_element_end = match node.kind() {
+            AstKind::ObjectExpression(object) => object.properties.last().map(GetSpan::span),
+            AstKind::ArrayExpression(array) => array.elements.last().map(GetSpan::span),
+            _ => return,
+        };
+        let Some(last_element_end) = last_element_end.map(|span| span.end) else {
+            return;
+        };
+        // The text between the last element and the closing bracket
+        let rest = Span::new(last_element_end, node.span().end - 1).source_text(ctx.source_text());
+        let offset = skip_trivia(rest);
+        if !rest[offset..].starts_with(',') {
+            return;
+        }
+        #[expect(clippy::cast_possible_truncation)]
+        let comma = Span::sized(last_element_end + offset as u32, 1);
+        ctx.diagnostic_with_fix(no_trailing_commas_diagnostic(comma), |fixer| fixer.delete(&comma));
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        !(self.0.allow_in_jsonc && is_jsonc_path(ctx.file_path()))
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"{ "a": [1, 2], "b": {} }"#, None, None, Some(PathBuf::from("test.json"))),
+        (
+            "[\n  1, // one\n  2 // two\n]",
+            Some(json!([{ "allowInJsonc": false }])),
+            None,
+            Some(PathBuf::from("test.jsonc")),
+        ),
+        (r#"{ "a": ",", "b": [","] }"#, None, None, Some(PathBuf::from("test.json"))),
+        (r#"{ "a": [1, 2,], }"#, None, None, Some(PathBuf::from("test.jsonc"))),
+        (
+            r#"{ "compilerOptions": { "strict": true, }, }"#,
+            None,
+            None,
+            Some(PathBuf::from("tsconfig.json")),
+        ),
+        (
+            r#"{ "compilerOptions": { "strict": true, }, }"#,
+            None,
+            None,
+            Some(PathBuf::from("tsconfig.build.json")),
+        ),
+    ];
+
+    let fail = vec![
+        (r#"{ "a": 1, }"#, None, None, Some(PathBuf::from("test.json"))),
+        (r#"{ "files": ["dist", "src",] }"#, None, None, Some(PathBuf::from("package.json"))),
+        (
+            "[\n  1 /* one */ ,\n]",
+            Some(json!([{ "allowInJsonc": false }])),
+            None,
+            Some(PathBuf::from("test.jsonc")),
+        ),
+        (
+            r#"{ "a": [1, 2,], }"#,
+            Some(json!([{ "allowInJsonc": false }])),
+            None,
+            Some(PathBuf::from("test.jsonc")),
+        ),
+    ];
+
+    let fix = vec![
+        (r#"{ "a": 1, }"#, r#"{ "a": 1 }"#, None),
+        ("[\n  1,\n  2,\n]", "[\n  1,\n  2\n]", None),
+    ];
+
+    Tester::new(NoTrailingCommas::NAME, NoTrailingCommas::PLUGIN, pass, fail)
+        .change_rule_path_extension("json")
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
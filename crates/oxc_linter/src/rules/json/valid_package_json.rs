@@ -0,0 +1,257 @@
+use oxc_ast::ast::{ArrayExpressionElement, Expression, ObjectPropertyKind, Statement};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn invalid_field_diagnostic(field: &str, expected: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Invalid `{field}` field in package.json"))
+        .with_help(format!("`{field}` must be {expected}."))
+        .with_label(span)
+}
+
+fn not_an_object_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("package.json must contain an object").with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ValidPackageJson;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Checks the types of the well-known fields of `package.json` files, e.g. that each of the
+    /// `scripts` is a string and that `type` is either `"module"` or `"commonjs"`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Package managers and bundlers fail, or silently ignore fields, when a field of
+    /// `package.json` has an unexpected type.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```json
+    /// {
+    ///   "type": "esm",
+    ///   "private": "true",
+    ///   "scripts": { "build": ["tsc", "vite build"] }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```json
+    /// {
+    ///   "type": "module",
+    ///   "private": true,
+    ///   "scripts": { "build": "tsc && vite build" }
+    /// }
+    /// ```
+    ValidPackageJson,
+    json,
+    correctness,
+);
+
+/// The value expected for a field of `package.json`.
+#[derive(Debug, Clone, Copy)]
+enum Expected {
+    String,
+    Boolean,
+    /// One of the strings
+    OneOf(&'static [&'static str]),
+    /// An array of strings
+    StringArray,
+    /// An object whose values are strings, e.g. `scripts` or `dependencies`
+    StringMap,
+    /// A string, or an object whose values are strings, e.g. `bin`
+    StringOrStringMap,
+    /// A string, or an object, e.g. `repository`
+    StringOrObject,
+    Object,
+}
+
+impl Expected {
+    fn description(self) -> String {
+        match self {
+            Self::String => "a string".to_string(),
+            Self::Boolean => "a boolean".to_string(),
+            Self::OneOf(values) => format!(
+                "one of {}",
+                values.iter().map(|value| format!("\"{value}\"")).collect::<Vec<_>>().join(", ")
+            ),
+            Self::StringArray => "an array of strings".to_string(),
+            Self::StringMap => "an object whose values are strings".to_string(),
+            Self::StringOrStringMap => {
+                "a string, or an object whose values are strings".to_string()
+            }
+            Self::StringOrObject => "a string or an object".to_string(),
+            Self::Object => "an object".to_string(),
+        }
+    }
+}
+
+/// The fields of `package.json` which are checked, other fields may have any value.
+const PACKAGE_JSON_FIELDS: &[(&str, Expected)] = &[
+    ("name", Expected::String),
+    ("version", Expected::String),
+    ("description", Expected::String),
+    ("type", Expected::OneOf(&["module", "commonjs"])),
+    ("private", Expected::Boolean),
+    ("main", Expected::String),
+    ("module", Expected::String),
+    ("types", Expected::String),
+    ("typings", Expected::String),
+    ("license", Expected::String),
+    ("homepage", Expected::String),
+    ("packageManager", Expected::String),
+    ("keywords", Expected::StringArray),
+    ("files", Expected::StringArray),
+    ("bin", Expected::StringOrStringMap),
+    ("repository", Expected::StringOrObject),
+    ("scripts", Expected::StringMap),
+    ("engines", Expected::StringMap),
+    ("dependencies", Expected::StringMap),
+    ("devDependencies", Expected::StringMap),
+    ("peerDependencies", Expected::StringMap),
+    ("optionalDependencies", Expected::StringMap),
+    ("exports", Expected::StringOrObject),
+    ("publishConfig", Expected::Object),
+];
+
+impl Rule for ValidPackageJson {
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(Statement::ExpressionStatement(statement)) = ctx.nodes().program().body.first()
+        else {
+            return;
+        };
+        let Expression::ObjectExpression(package) = &statement.expression else {
+            ctx.diagnostic(not_an_object_diagnostic(statement.expression.span()));
+            return;
+        };
+        for property in &package.properties {
+            let ObjectPropertyKind::ObjectProperty(property) = property else {
+                continue;
+            };
+            let Some(field) = property.key.static_name() else {
+                continue;
+            };
+            let Some((field, expected)) =
+                PACKAGE_JSON_FIELDS.iter().find(|(name, _)| *name == field)
+            else {
+                continue;
+            };
+            if let Err(span) = check_value(&property.value, *expected) {
+                ctx.diagnostic(invalid_field_diagnostic(field, &expected.description(), span));
+            }
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.file_path().file_name().is_some_and(|name| name == "package.json")
+    }
+}
+
+/// Check that `value` is what is `expected`, or return the span of the invalid value.
+fn check_value(value: &Expression, expected: Expected) -> Result<(), Span> {
+    let is_valid = match expected {
+        Expected::String => matches!(value, Expression::StringLiteral(_)),
+        Expected::Boolean => matches!(value, Expression::BooleanLiteral(_)),
+        Expected::OneOf(values) => {
+            matches!(value, Expression::StringLiteral(string) if values.contains(&string.value.as_str()))
+        }
+        Expected::StringArray => {
+            let Expression::ArrayExpression(array) = value else {
+                return Err(value.span());
+            };
+            return array.elements.iter().try_for_each(|element| match element {
+                ArrayExpressionElement::StringLiteral(_) => Ok(()),
+                element => Err(element.span()),
+            });
+        }
+        Expected::StringMap => {
+            let Expression::ObjectExpression(object) = value else {
+                return Err(value.span());
+            };
+            return object.properties.iter().try_for_each(|property| match property {
+                ObjectPropertyKind::ObjectProperty(property) => {
+                    check_value(&property.value, Expected::String)
+                }
+                ObjectPropertyKind::SpreadProperty(spread) => Err(spread.span),
+            });
+        }
+        Expected::StringOrStringMap => {
+            return if matches!(value, Expression::StringLiteral(_)) {
+                Ok(())
+            } else {
+                check_value(value, Expected::StringMap)
+            };
+        }
+        Expected::StringOrObject => {
+            matches!(value, Expression::StringLiteral(_) | Expression::ObjectExpression(_))
+        }
+        Expected::Object => matches!(value, Expression::ObjectExpression(_)),
+    };
+    if is_valid { Ok(()) } else { Err(value.span()) }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            r#"{
+              "name": "foo",
+              "version": "1.0.0",
+              "type": "module",
+              "private": true,
+              "bin": "./cli.js",
+              "files": ["dist"],
+              "scripts": { "build": "tsc", "test": "vitest" },
+              "dependencies": { "react": "^19.0.0" },
+              "exports": { ".": { "import": "./index.js" } },
+              "unknown": [1, 2, 3]
+            }"#,
+            None,
+            None,
+            Some(PathBuf::from("package.json")),
+        ),
+        (r#"{ "bin": { "foo": "./cli.js" } }"#, None, None, Some(PathBuf::from("package.json"))),
+        (r#"{ "type": "commonjs" }"#, None, None, Some(PathBuf::from("packages/foo/package.json"))),
+        // Other JSON files are not checked
+        (r#"{ "scripts": { "build": 1 } }"#, None, None, Some(PathBuf::from("test.json"))),
+        ("[1]", None, None, Some(PathBuf::from("tsconfig.json"))),
+    ];
+
+    let fail = vec![
+        (r#"{ "name": 1 }"#, None, None, Some(PathBuf::from("package.json"))),
+        (r#"{ "type": "esm" }"#, None, None, Some(PathBuf::from("package.json"))),
+        (r#"{ "private": "true" }"#, None, None, Some(PathBuf::from("package.json"))),
+        (
+            r#"{ "scripts": { "build": "tsc", "test": ["vitest", "playwright test"] } }"#,
+            None,
+            None,
+            Some(PathBuf::from("package.json")),
+        ),
+        (
+            r#"{ "devDependencies": { "vite": 7 } }"#,
+            None,
+            None,
+            Some(PathBuf::from("package.json")),
+        ),
+        (r#"{ "files": "dist" }"#, None, None, Some(PathBuf::from("package.json"))),
+        (r#"{ "keywords": ["lint", 1] }"#, None, None, Some(PathBuf::from("package.json"))),
+        (r#"{ "bin": ["./cli.js"] }"#, None, None, Some(PathBuf::from("package.json"))),
+        ("[]", None, None, Some(PathBuf::from("package.json"))),
+    ];
+
+    Tester::new(ValidPackageJson::NAME, ValidPackageJson::PLUGIN, pass, fail)
+        .change_rule_path_extension("json")
+        .test_and_snapshot();
+}
@@ -12,8 +12,8 @@ use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        get_element_type, get_prop_value, get_string_literal_prop_value, has_jsx_prop_ignore_case,
-        object_has_accessible_child,
+        get_element_type, get_jsx_a11y_prop, get_prop_value, get_string_literal_prop_value,
+        has_jsx_prop_ignore_case, object_has_accessible_child,
     },
 };
 
@@ -250,7 +250,7 @@ fn aria_label_has_value<'a>(item: &'a JSXAttributeItem<'a>) -> bool {
 }
 
 fn img_rule<'a>(node: &'a JSXOpeningElement<'a>, ctx: &LintContext<'a>) {
-    if let Some(alt_prop) = has_jsx_prop_ignore_case(node, "alt") {
+    if let Some(alt_prop) = get_jsx_a11y_prop(ctx, node, "alt") {
         if !is_valid_alt_prop(alt_prop) {
             ctx.diagnostic(missing_alt_value(node.span));
         }
@@ -289,7 +289,7 @@ fn object_rule<'a>(
     let has_aria_labelledby =
         has_jsx_prop_ignore_case(node, "aria-labelledby").is_some_and(aria_label_has_value);
     let has_label = has_aria_label || has_aria_labelledby;
-    let has_title_attr = has_jsx_prop_ignore_case(node, "title")
+    let has_title_attr = get_jsx_a11y_prop(ctx, node, "title")
         .and_then(get_string_literal_prop_value)
         .is_some_and(|v| !v.is_empty());
 
@@ -308,7 +308,7 @@ fn area_rule<'a>(node: &'a JSXOpeningElement<'a>, ctx: &LintContext<'a>) {
     if has_label {
         return;
     }
-    has_jsx_prop_ignore_case(node, "alt").map_or_else(
+    get_jsx_a11y_prop(ctx, node, "alt").map_or_else(
         || {
             ctx.diagnostic(area(node.span));
         },
@@ -329,7 +329,7 @@ fn input_type_image_rule<'a>(node: &'a JSXOpeningElement<'a>, ctx: &LintContext<
     if has_label {
         return;
     }
-    has_jsx_prop_ignore_case(node, "alt").map_or_else(
+    get_jsx_a11y_prop(ctx, node, "alt").map_or_else(
         || {
             ctx.diagnostic(input_type_image(node.span));
         },
@@ -454,6 +454,14 @@ fn test() {
         (r#"<InputImage alt="" />"#, Some(config()), None),
         (r#"<InputImage alt="This is descriptive!" />"#, Some(config()), None),
         (r"<InputImage alt={altText} />", Some(config()), None),
+        (
+            r#"<Image altText="foo" />"#,
+            None,
+            Some(serde_json::json!({ "settings": { "jsx-a11y": {
+                "components": { "Image": "img" },
+                "attributes": { "alt": ["altText"] }
+            } } })),
+        ),
     ];
 
     let fail = vec![
@@ -530,6 +538,14 @@ fn test() {
         (r"<InputImage>Foo</InputImage>", Some(config()), None),
         (r"<InputImage {...this.props} />", Some(config()), None),
         (r#"<Input type="image" />"#, None, None),
+        (
+            r#"<Image alt="foo" />"#,
+            None,
+            Some(serde_json::json!({ "settings": { "jsx-a11y": {
+                "components": { "Image": "img" },
+                "attributes": { "alt": ["altText"] }
+            } } })),
+        ),
     ];
 
     Tester::new(AltText::NAME, AltText::PLUGIN, pass, fail)
@@ -14,8 +14,8 @@ use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        get_element_type, get_string_literal_prop_value, has_jsx_prop_ignore_case,
-        is_hidden_from_screen_reader,
+        decode_jsx_entities, get_element_type, get_jsx_a11y_prop, get_string_literal_prop_value,
+        has_jsx_prop_ignore_case, is_hidden_from_screen_reader,
     },
 };
 
@@ -173,15 +173,15 @@ fn get_accessible_text<'a, 'b>(
     if let Some(aria_label) = has_jsx_prop_ignore_case(&jsx_el.opening_element, "aria-label")
         && let Some(label_text) = get_string_literal_prop_value(aria_label)
     {
-        return Some(Cow::Borrowed(label_text));
+        return Some(decode_jsx_entities(label_text, 0).into_text());
     }
 
     let name = get_element_type(ctx, &jsx_el.opening_element);
     if name == "img"
-        && let Some(alt_text) = has_jsx_prop_ignore_case(&jsx_el.opening_element, "alt")
+        && let Some(alt_text) = get_jsx_a11y_prop(ctx, &jsx_el.opening_element, "alt")
         && let Some(text) = get_string_literal_prop_value(alt_text)
     {
-        return Some(Cow::Borrowed(text));
+        return Some(decode_jsx_entities(text, 0).into_text());
     }
 
     if is_hidden_from_screen_reader(ctx, &jsx_el.opening_element) {
@@ -193,7 +193,9 @@ fn get_accessible_text<'a, 'b>(
         .iter()
         .filter_map(|child| match child {
             JSXChild::Element(child_el) => get_accessible_text(child_el, ctx),
-            JSXChild::Text(text_el) => Some(Cow::Borrowed(text_el.value.as_str())),
+            JSXChild::Text(text_el) => {
+                Some(decode_jsx_entities(text_el.value.as_str(), text_el.span.start).into_text())
+            }
             _ => None,
         })
         .collect();
@@ -272,6 +274,8 @@ fn test() {
         ("<a>click here</a>;", None, None),
         ("<a>learn more</a>;", None, None),
         ("<a>learn      more</a>;", None, None),
+        ("<a>click&nbsp;here</a>;", None, None),
+        (r#"<a aria-label="learn&#32;more">documentation</a>;"#, None, None),
         ("<a>learn more.</a>;", None, None),
         ("<a>learn more?</a>;", None, None),
         ("<a>learn more,</a>;", None, None),
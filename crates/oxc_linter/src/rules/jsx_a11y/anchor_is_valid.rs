@@ -14,7 +14,7 @@ use crate::{
     AstNode,
     context::LintContext,
     rule::Rule,
-    utils::{get_element_type, has_jsx_prop_ignore_case},
+    utils::{get_element_type, get_jsx_a11y_prop, has_jsx_prop_ignore_case},
 };
 
 fn missing_href_attribute(span: Span) -> OxcDiagnostic {
@@ -142,7 +142,7 @@ impl Rule for AnchorIsValid {
             }
             // Don't eagerly get `span` here, to avoid that work unless rule fails
             let get_span = || jsx_el.opening_element.name.span();
-            if let Some(href_attr) = has_jsx_prop_ignore_case(&jsx_el.opening_element, "href") {
+            if let Some(href_attr) = get_jsx_a11y_prop(ctx, &jsx_el.opening_element, "href") {
                 let JSXAttributeItem::Attribute(attr) = href_attr else {
                     return;
                 };
@@ -12,7 +12,9 @@ use crate::{
     context::LintContext,
     globals::{HTML_TAG, VALID_ARIA_ROLES},
     rule::Rule,
-    utils::{get_element_type, get_prop_value, has_jsx_prop},
+    utils::{
+        decode_jsx_entities, get_element_type, get_prop_value, has_jsx_prop, jsx_string_value_span,
+    },
 };
 
 fn aria_role_diagnostic(span: Span, help_suffix: &str) -> OxcDiagnostic {
@@ -97,7 +99,8 @@ declare_oxc_lint!(
     AriaRole,
     jsx_a11y,
     correctness,
-    config = AriaRoleConfig
+    config = AriaRoleConfig,
+    unchecked_config,
 );
 
 impl Rule for AriaRole {
@@ -149,7 +152,9 @@ impl Rule for AriaRole {
                     }
                 }
                 Some(JSXAttributeValue::StringLiteral(str)) => {
-                    let words_str = String::from(str.value.as_str());
+                    let words_str =
+                        decode_jsx_entities(str.value.as_str(), jsx_string_value_span(str).start);
+                    let words_str = words_str.as_str();
                     let words = words_str.split_whitespace();
                     if words_str.trim().is_empty() {
                         ctx.diagnostic(aria_role_diagnostic(str.span, ""));
@@ -202,6 +207,7 @@ fn test() {
         ("<div />", None, None),
         ("<div></div>", None, None),
         ("<div role={role} />", None, None),
+        ("<div role='tabpanel&#x20;row' />", None, None),
         ("<div role={role || 'button'} />", None, None),
         ("<div role={role || 'foobar'} />", None, None),
         ("<div role='tabpanel row' />", None, None),
@@ -227,6 +233,7 @@ fn test() {
         ("<div role='Button'></div>", None, None),
         ("<div role=''></div>", None, None),
         ("<div role='tabpanel row foobar'></div>", None, None),
+        ("<div role='tabpanel&nbsp;foobar'></div>", None, None),
         ("<div role='tabpanel row range'></div>", None, None),
         ("<div role='doc-endnotes range'></div>", None, None),
         ("<div role />", None, None),
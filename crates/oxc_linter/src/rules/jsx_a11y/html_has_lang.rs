@@ -10,7 +10,7 @@ use crate::{
     AstNode,
     context::LintContext,
     rule::Rule,
-    utils::{get_element_type, get_prop_value, has_jsx_prop_ignore_case},
+    utils::{get_element_type, get_jsx_a11y_prop, get_prop_value},
 };
 
 fn missing_lang_prop(span: Span) -> OxcDiagnostic {
@@ -68,7 +68,7 @@ impl Rule for HtmlHasLang {
             return;
         }
 
-        has_jsx_prop_ignore_case(jsx_el, "lang").map_or_else(
+        get_jsx_a11y_prop(ctx, jsx_el, "lang").map_or_else(
             || ctx.diagnostic(missing_lang_prop(jsx_el.name.span())),
             |lang_prop| {
                 if !is_valid_lang_prop(lang_prop) {
@@ -113,6 +113,19 @@ fn test() {
         })
     }
 
+    fn attributes_settings() -> serde_json::Value {
+        serde_json::json!({
+            "settings": { "jsx-a11y": {
+                "components": {
+                    "HTMLTop": "html",
+                },
+                "attributes": {
+                    "lang": ["language"],
+                }
+            } }
+        })
+    }
+
     let pass = vec![
         (r"<div />;", None, None),
         (r#"<html lang="en" />"#, None, None),
@@ -124,6 +137,7 @@ fn test() {
         (r"<html lang />;", None, None),
         (r"<HTML />;", None, None),
         ("<HTMLTop lang='en' />", None, Some(settings())),
+        ("<HTMLTop language='en' />", None, Some(attributes_settings())),
     ];
 
     let fail = vec![
@@ -137,6 +151,7 @@ fn test() {
         (r"<html lang={``} />;", None, None),
         (r#"<html lang="" />;"#, None, None),
         ("<HTMLTop />", None, Some(settings())),
+        ("<HTMLTop lang='en' />", None, Some(attributes_settings())),
     ];
 
     Tester::new(HtmlHasLang::NAME, HtmlHasLang::PLUGIN, pass, fail)
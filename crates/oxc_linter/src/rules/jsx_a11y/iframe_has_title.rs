@@ -10,7 +10,7 @@ use crate::{
     AstNode,
     context::LintContext,
     rule::Rule,
-    utils::{get_element_type, get_prop_value, has_jsx_prop_ignore_case},
+    utils::{get_element_type, get_jsx_a11y_prop, get_prop_value},
 };
 
 fn iframe_has_title_diagnostic(span: Span) -> OxcDiagnostic {
@@ -70,7 +70,7 @@ impl Rule for IframeHasTitle {
         if name != "iframe" {
             return;
         }
-        let Some(alt_prop) = has_jsx_prop_ignore_case(jsx_el, "title") else {
+        let Some(alt_prop) = get_jsx_a11y_prop(ctx, jsx_el, "title") else {
             ctx.diagnostic(iframe_has_title_diagnostic(jsx_el.name.span()));
             return;
         };
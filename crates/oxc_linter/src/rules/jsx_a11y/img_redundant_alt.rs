@@ -15,9 +15,7 @@ use crate::{
     AstNode,
     context::LintContext,
     rule::Rule,
-    utils::{
-        get_element_type, get_prop_value, has_jsx_prop_ignore_case, is_hidden_from_screen_reader,
-    },
+    utils::{get_element_type, get_jsx_a11y_prop, get_prop_value, is_hidden_from_screen_reader},
 };
 
 fn img_redundant_alt_diagnostic(span: Span) -> OxcDiagnostic {
@@ -139,7 +137,7 @@ impl Rule for ImgRedundantAlt {
             return;
         }
 
-        let Some(alt_prop) = has_jsx_prop_ignore_case(jsx_el, "alt") else {
+        let Some(alt_prop) = get_jsx_a11y_prop(ctx, jsx_el, "alt") else {
             return;
         };
 
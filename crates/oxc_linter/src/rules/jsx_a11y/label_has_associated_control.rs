@@ -14,7 +14,10 @@ use crate::{
     AstNode,
     context::LintContext,
     rule::Rule,
-    utils::{get_element_type, get_jsx_attribute_name, has_jsx_prop, is_react_component_name},
+    utils::{
+        decode_jsx_entities, get_element_type, get_jsx_attribute_name, has_jsx_prop,
+        is_react_component_name, jsx_string_value_span,
+    },
 };
 
 fn label_has_associated_control_diagnostic(span: Span) -> OxcDiagnostic {
@@ -120,7 +123,8 @@ declare_oxc_lint!(
     LabelHasAssociatedControl,
     jsx_a11y,
     correctness,
-    config = LabelHasAssociatedControlConfig
+    config = LabelHasAssociatedControlConfig,
+    unchecked_config,
 );
 
 impl Rule for LabelHasAssociatedControl {
@@ -330,7 +334,10 @@ impl LabelHasAssociatedControl {
 
         match node {
             JSXChild::ExpressionContainer(_) => true,
-            JSXChild::Text(text) => !text.value.as_str().trim().is_empty(),
+            JSXChild::Text(text) => !decode_jsx_entities(text.value.as_str(), text.span.start)
+                .as_str()
+                .trim()
+                .is_empty(),
             JSXChild::Element(element) => {
                 let has_labelling_prop =
                     element.opening_element.attributes.iter().any(|attr| match attr {
@@ -340,7 +347,13 @@ impl LabelHasAssociatedControl {
                                     && attribute.value.as_ref().is_some_and(|attribute_value| {
                                         match attribute_value {
                                             JSXAttributeValue::StringLiteral(literal) => {
-                                                !literal.value.as_str().trim().is_empty()
+                                                !decode_jsx_entities(
+                                                    literal.value.as_str(),
+                                                    jsx_string_value_span(literal).start,
+                                                )
+                                                .as_str()
+                                                .trim()
+                                                .is_empty()
                                             }
                                             _ => true,
                                         }
@@ -971,6 +984,11 @@ fn test() {
     ];
 
     let fail = vec![
+        (
+            "<label>&nbsp;<input /></label>",
+            Some(serde_json::json!([{ "assert": "nesting" }])),
+            None,
+        ),
         (
             r#"<label htmlFor="js_id"><span><span><span>A label</span></span></span></label>"#,
             Some(serde_json::json!([{
@@ -11,7 +11,7 @@ use crate::{
     AstNode,
     context::LintContext,
     rule::Rule,
-    utils::{get_element_type, get_prop_value, has_jsx_prop_ignore_case},
+    utils::{get_element_type, get_jsx_a11y_prop, get_prop_value},
 };
 
 fn lang_diagnostic(span: Span) -> OxcDiagnostic {
@@ -69,7 +69,7 @@ impl Rule for Lang {
             return;
         }
 
-        has_jsx_prop_ignore_case(jsx_el, "lang").map_or_else(
+        get_jsx_a11y_prop(ctx, jsx_el, "lang").map_or_else(
             || ctx.diagnostic(lang_diagnostic(jsx_el.name.span())),
             |lang_prop| {
                 if !is_valid_lang_prop(lang_prop)
@@ -10,7 +10,7 @@ use crate::{
     AstNode,
     context::LintContext,
     rule::Rule,
-    utils::{get_element_type, has_jsx_prop_ignore_case, parse_jsx_value},
+    utils::{get_element_type, get_jsx_a11y_prop, has_jsx_prop_ignore_case, parse_jsx_value},
 };
 
 fn no_aria_hidden_on_focusable_diagnostic(span: Span) -> OxcDiagnostic {
@@ -104,7 +104,7 @@ fn is_focusable<'a>(ctx: &LintContext<'a>, element: &JSXOpeningElement<'a>) -> b
     }
 
     match tag_name.as_ref() {
-        "a" | "area" => has_jsx_prop_ignore_case(element, "href").is_some(),
+        "a" | "area" => get_jsx_a11y_prop(ctx, element, "href").is_some(),
         "button" | "input" | "select" | "textarea" => {
             has_jsx_prop_ignore_case(element, "disabled").is_none()
         }
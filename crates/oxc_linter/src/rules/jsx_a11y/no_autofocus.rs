@@ -57,6 +57,7 @@ declare_oxc_lint!(
     correctness,
     fix,
     config = NoAutofocus,
+    unchecked_config,
 );
 
 impl NoAutofocus {
@@ -10,7 +10,9 @@ use crate::{
     AstNode,
     context::LintContext,
     rule::Rule,
-    utils::{get_element_type, has_jsx_prop_ignore_case},
+    utils::{
+        decode_jsx_entities, get_element_type, has_jsx_prop_ignore_case, jsx_string_value_span,
+    },
 };
 
 fn no_redundant_roles_diagnostic(span: Span, element: &str, role: &str) -> OxcDiagnostic {
@@ -71,13 +73,25 @@ impl Rule for NoRedundantRoles {
         if let Some(JSXAttributeItem::Attribute(attr)) = has_jsx_prop_ignore_case(jsx_el, "role")
             && let Some(JSXAttributeValue::StringLiteral(role_values)) = &attr.value
         {
-            let roles = role_values.value.split_whitespace().collect::<Vec<_>>();
-            for role in &roles {
+            let value = decode_jsx_entities(
+                role_values.value.as_str(),
+                jsx_string_value_span(role_values).start,
+            );
+            let roles = value.as_str().split_whitespace().map(|role| (role, value.span_of(role)));
+            let roles = roles.collect::<Vec<_>>();
+            for (i, (role, _)) in roles.iter().enumerate() {
                 let exceptions = get_default_role_exception(&component);
                 if exceptions.is_some_and(|set| set.contains(role)) {
+                    // Only remove the redundant role, and the whitespace separating it from the
+                    // other roles, if there are any.
+                    let fix_span = match (i.checked_sub(1).map(|i| roles[i].1), roles.get(i + 1)) {
+                        (_, Some((_, next))) => Span::new(roles[i].1.start, next.start),
+                        (Some(prev), None) => Span::new(prev.end, roles[i].1.end),
+                        (None, None) => attr.span,
+                    };
                     ctx.diagnostic_with_fix(
                         no_redundant_roles_diagnostic(attr.span, &component, role),
-                        |fixer| fixer.delete_range(attr.span),
+                        |fixer| fixer.delete_range(fix_span),
                     );
                 }
             }
@@ -111,11 +125,17 @@ fn test() {
         ("<button role='button' />", None, None),
         ("<body role='document' />", None, None),
         ("<Button role='button' />", None, Some(settings())),
+        ("<button role='button presentation' />", None, None),
+        ("<nav role='none&nbsp;navigation' />", None, None),
     ];
 
     let fix = vec![
         ("<button role='button' />", "<button  />"),
         ("<body role='document' />", "<body  />"),
+        ("<button role='button presentation' />", "<button role='presentation' />"),
+        ("<button role='presentation  button' />", "<button role='presentation' />"),
+        ("<nav role='none&nbsp;navigation' />", "<nav role='none' />"),
+        ("<nav role='n&#x101;v navigation' />", "<nav role='n&#x101;v' />"),
     ];
 
     Tester::new(NoRedundantRoles::NAME, NoRedundantRoles::PLUGIN, pass, fail)
@@ -6,10 +6,11 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::AstNode;
-use oxc_span::Span;
+use oxc_span::{SourceType, Span};
 
 use crate::{
-    context::{ContextHost, LintContext},
+    context::LintContext,
+    frameworks::FrameworkOptions,
     rule::Rule,
     utils::{NEXT_POLYFILLED_FEATURES, find_url_query_value, get_next_script_import_local_name},
 };
@@ -67,8 +68,12 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUnwantedPolyfillio {
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_jsx()
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -0,0 +1,339 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        ArrowFunctionExpression, BreakStatement, CatchClause, ConditionalExpression,
+        ContinueStatement, DoWhileStatement, Expression, ForInStatement, ForOfStatement,
+        ForStatement, Function, FunctionBody, IfStatement, LogicalExpression, LogicalOperator,
+        Statement, SwitchStatement, WhileStatement,
+    },
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ScopeFlags;
+use oxc_span::Span;
+use schemars::JsonSchema;
+use serde_json::Value;
+
+use crate::{AstNode, ast_util::get_function_name_with_kind, context::LintContext, rule::Rule};
+
+fn max_cognitive_complexity_diagnostic(
+    name: &str,
+    complexity: usize,
+    max: usize,
+    span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "The {name} has a cognitive complexity of {complexity}. Maximum allowed is {max}."
+    ))
+    .with_help("Consider extracting parts of it into smaller functions, or flattening nested conditions with early returns.")
+    .with_label(span)
+}
+
+#[derive(Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MaxCognitiveComplexity {
+    /// Maximum cognitive complexity allowed per function.
+    max: usize,
+}
+
+impl Default for MaxCognitiveComplexity {
+    fn default() -> Self {
+        Self { max: 15 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce a maximum cognitive complexity of functions, which by default is 15.
+    ///
+    /// Cognitive complexity measures how hard the control flow of a function is to
+    /// understand. Each break in the linear flow adds 1: `if`, `else if`, `else`,
+    /// conditional expressions, `switch`, loops, `catch`, labeled `break` and
+    /// `continue`, and each sequence of like logical operators (`&&`, `||`, `??`).
+    /// Conditions, loops and `catch` nested inside of other ones add their depth
+    /// of nesting on top.
+    ///
+    /// Nested functions are not counted towards the complexity of the function
+    /// containing them, they are checked on their own.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Deeply nested and branching code is hard to read, test and change. Unlike
+    /// counting paths (cyclomatic complexity), cognitive complexity penalizes
+    /// nesting, so flat sequences of conditions stay cheap while nested ones do not.
+    ///
+    /// References:
+    ///
+    /// * <https://www.sonarsource.com/docs/CognitiveComplexity.pdf>
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "max": 3 }`:
+    /// ```javascript
+    /// function sumOfPrimes(max) {
+    ///     let total = 0;
+    ///     outer: for (let i = 1; i <= max; ++i) { // +1
+    ///         for (let j = 2; j < i; ++j) {       // +2 (nesting = 1)
+    ///             if (i % j === 0) {              // +3 (nesting = 2)
+    ///                 continue outer;             // +1
+    ///             }
+    ///         }
+    ///         total += i;
+    ///     }
+    ///     return total;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `{ "max": 3 }`:
+    /// ```javascript
+    /// function getWords(number) {
+    ///     switch (number) { // +1
+    ///         case 1:
+    ///             return "one";
+    ///         case 2:
+    ///             return "a couple";
+    ///         default:
+    ///             return "lots";
+    ///     }
+    /// }
+    /// ```
+    MaxCognitiveComplexity,
+    oxc,
+    pedantic,
+    config = MaxCognitiveComplexity,
+    unchecked_config,
+);
+
+impl Rule for MaxCognitiveComplexity {
+    fn from_configuration(value: Value) -> Self {
+        let config = value.get(0);
+        let max = config
+            .and_then(Value::as_u64)
+            .or_else(|| config.and_then(|config| config.get("max")).and_then(Value::as_u64))
+            .and_then(|max| usize::try_from(max).ok());
+        max.map_or_else(Self::default, |max| Self { max })
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::Function(function) => {
+                if let Some(body) = &function.body {
+                    let span = Span::new(function.span.start, function.params.span.end);
+                    self.check_function_body(body, span, node, ctx);
+                }
+            }
+            AstKind::ArrowFunctionExpression(arrow) => {
+                let span = Span::new(arrow.span.start, arrow.params.span.end);
+                self.check_function_body(&arrow.body, span, node, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl MaxCognitiveComplexity {
+    fn check_function_body<'a>(
+        &self,
+        body: &FunctionBody<'a>,
+        span: Span,
+        node: &AstNode<'a>,
+        ctx: &LintContext<'a>,
+    ) {
+        let mut counter = ComplexityCounter::default();
+        counter.visit_function_body(body);
+        if counter.complexity > self.max {
+            let name = get_function_name_with_kind(node, ctx.nodes().parent_node(node.id()));
+            ctx.diagnostic(max_cognitive_complexity_diagnostic(
+                &name,
+                counter.complexity,
+                self.max,
+                span,
+            ));
+        }
+    }
+}
+
+/// Computes the cognitive complexity of a function body.
+#[derive(Default)]
+struct ComplexityCounter {
+    complexity: usize,
+    nesting: usize,
+    /// Operator of the logical expression whose operand is being visited
+    logical_operator: Option<LogicalOperator>,
+}
+
+impl ComplexityCounter {
+    /// Add a structure which increases the nesting of its body, e.g. a loop.
+    fn visit_nested(&mut self, visit: impl FnOnce(&mut Self)) {
+        self.complexity += 1 + self.nesting;
+        self.nesting += 1;
+        visit(self);
+        self.nesting -= 1;
+    }
+
+    /// Visit an `if` statement after its increment, `else if` only adds 1 and no nesting.
+    fn visit_if_branches(&mut self, it: &IfStatement<'_>) {
+        self.visit_expression(&it.test);
+        self.nesting += 1;
+        self.visit_statement(&it.consequent);
+        self.nesting -= 1;
+        match &it.alternate {
+            Some(Statement::IfStatement(else_if)) => {
+                self.complexity += 1;
+                self.visit_if_branches(else_if);
+            }
+            Some(alternate) => {
+                self.complexity += 1;
+                self.nesting += 1;
+                self.visit_statement(alternate);
+                self.nesting -= 1;
+            }
+            None => {}
+        }
+    }
+}
+
+impl<'a> Visit<'a> for ComplexityCounter {
+    fn visit_if_statement(&mut self, it: &IfStatement<'a>) {
+        self.complexity += 1 + self.nesting;
+        self.visit_if_branches(it);
+    }
+
+    fn visit_conditional_expression(&mut self, it: &ConditionalExpression<'a>) {
+        self.visit_nested(|me| walk::walk_conditional_expression(me, it));
+    }
+
+    fn visit_switch_statement(&mut self, it: &SwitchStatement<'a>) {
+        self.visit_nested(|me| walk::walk_switch_statement(me, it));
+    }
+
+    fn visit_for_statement(&mut self, it: &ForStatement<'a>) {
+        self.visit_nested(|me| walk::walk_for_statement(me, it));
+    }
+
+    fn visit_for_in_statement(&mut self, it: &ForInStatement<'a>) {
+        self.visit_nested(|me| walk::walk_for_in_statement(me, it));
+    }
+
+    fn visit_for_of_statement(&mut self, it: &ForOfStatement<'a>) {
+        self.visit_nested(|me| walk::walk_for_of_statement(me, it));
+    }
+
+    fn visit_while_statement(&mut self, it: &WhileStatement<'a>) {
+        self.visit_nested(|me| walk::walk_while_statement(me, it));
+    }
+
+    fn visit_do_while_statement(&mut self, it: &DoWhileStatement<'a>) {
+        self.visit_nested(|me| walk::walk_do_while_statement(me, it));
+    }
+
+    fn visit_catch_clause(&mut self, it: &CatchClause<'a>) {
+        self.visit_nested(|me| walk::walk_catch_clause(me, it));
+    }
+
+    fn visit_break_statement(&mut self, it: &BreakStatement<'a>) {
+        if it.label.is_some() {
+            self.complexity += 1;
+        }
+    }
+
+    fn visit_continue_statement(&mut self, it: &ContinueStatement<'a>) {
+        if it.label.is_some() {
+            self.complexity += 1;
+        }
+    }
+
+    fn visit_logical_expression(&mut self, it: &LogicalExpression<'a>) {
+        // `a && b && c` is one sequence, `a && b || c` are two
+        if self.logical_operator != Some(it.operator) {
+            self.complexity += 1;
+        }
+        for operand in [&it.left, &it.right] {
+            let parent_operator = match operand.without_parentheses() {
+                Expression::LogicalExpression(_) => self.logical_operator.replace(it.operator),
+                _ => self.logical_operator.take(),
+            };
+            self.visit_expression(operand);
+            self.logical_operator = parent_operator;
+        }
+    }
+
+    // Nested functions are checked on their own.
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo() { return 1; }", None),
+        ("function foo(a) { if (a) { return 1; } }", Some(serde_json::json!([1]))),
+        (
+            "function foo(a, b, c) { if (a) {} else if (b) {} else if (c) {} else {} }",
+            Some(serde_json::json!([{ "max": 4 }])),
+        ),
+        ("function foo(a, b, c) { return a && b && c; }", Some(serde_json::json!([1]))),
+        ("function foo(a, b, c) { return (a && b) && c; }", Some(serde_json::json!([1]))),
+        (
+            "function foo(a) { switch (a) { case 1: return 1; case 2: return 2; default: return 3; } }",
+            Some(serde_json::json!([1])),
+        ),
+        // nested functions are not counted for the outer function
+        (
+            "function foo(a) { return a.map((b) => { if (b) { return 1; } }); }",
+            Some(serde_json::json!([1])),
+        ),
+        ("function foo(a) { for (const b of a) { break; } }", Some(serde_json::json!([1]))),
+    ];
+
+    let fail = vec![
+        ("function foo(a) { if (a) { return 1; } }", Some(serde_json::json!([0]))),
+        // 1 (if) + 1 (else if) + 1 (else) + 1 (else if) + 1 (else)
+        (
+            "function foo(a, b, c) { if (a) {} else if (b) {} else {} if (c) {} else {} }",
+            Some(serde_json::json!([{ "max": 4 }])),
+        ),
+        // 1 (for) + 2 (nested for) + 3 (nested if) + 1 (labeled continue)
+        (
+            "function sumOfPrimes(max) {
+                let total = 0;
+                outer: for (let i = 1; i <= max; ++i) {
+                    for (let j = 2; j < i; ++j) {
+                        if (i % j === 0) {
+                            continue outer;
+                        }
+                    }
+                    total += i;
+                }
+                return total;
+            }",
+            Some(serde_json::json!([{ "max": 6 }])),
+        ),
+        // 1 (&&) + 1 (||) + 1 (&&)
+        ("function foo(a, b, c, d) { return a && b || c && d; }", Some(serde_json::json!([2]))),
+        // 1 (ternary) + 2 (nested ternary)
+        ("const foo = (a, b) => a ? (b ? 1 : 2) : 3;", Some(serde_json::json!([2]))),
+        // 1 (try/catch) + 2 (if in catch)
+        (
+            "const foo = function () { try { bar(); } catch (e) { if (e) { throw e; } } };",
+            Some(serde_json::json!([2])),
+        ),
+        (
+            "class Foo { bar(a) { while (a) { do { a--; } while (a > 10); } } }",
+            Some(serde_json::json!([2])),
+        ),
+        // only the arrow function exceeds the maximum, its nesting starts at 0
+        (
+            "function foo(a) { if (a) { return () => { for (const b in a) { if (b) {} } }; } }",
+            Some(serde_json::json!([2])),
+        ),
+    ];
+
+    Tester::new(MaxCognitiveComplexity::NAME, MaxCognitiveComplexity::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
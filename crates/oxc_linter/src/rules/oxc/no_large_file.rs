@@ -0,0 +1,136 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use schemars::JsonSchema;
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule};
+
+fn no_large_file_diagnostic(size: usize, max: usize, unit: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("File is too large ({size} {unit})."))
+        .with_help(format!(
+            "Maximum allowed is {max} {unit}. Split the file into smaller modules, or ignore it if it is generated."
+        ))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoLargeFile(Box<NoLargeFileConfig>);
+
+#[derive(Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoLargeFileConfig {
+    /// Maximum number of lines allowed per file, including blank lines and comments.
+    max_lines: usize,
+    /// Maximum size of a file in bytes.
+    max_bytes: usize,
+}
+
+impl std::ops::Deref for NoLargeFile {
+    type Target = NoLargeFileConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for NoLargeFileConfig {
+    fn default() -> Self {
+        Self { max_lines: 2000, max_bytes: 100_000 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow files exceeding a budget of lines or bytes.
+    ///
+    /// The default budget is 2000 lines and 100,000 bytes.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Very large files are hard to navigate and review, slow down editors and
+    /// tools, and are often generated or bundled code which was committed by
+    /// accident. Unlike `eslint/max-lines`, which is a style guideline with a
+    /// low default, this rule is meant as a code-health budget which only
+    /// catches outliers.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "maxLines": 2 }`:
+    /// ```javascript
+    /// const a = 1;
+    /// const b = 2;
+    /// const c = 3;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `{ "maxLines": 2 }`:
+    /// ```javascript
+    /// const a = 1;
+    /// const b = 2;
+    /// ```
+    NoLargeFile,
+    oxc,
+    restriction,
+    config = NoLargeFileConfig,
+);
+
+impl Rule for NoLargeFile {
+    fn from_configuration(value: Value) -> Self {
+        let default = NoLargeFileConfig::default();
+        let config = value.get(0);
+        let get = |key: &str, default: usize| {
+            config
+                .and_then(|config| config.get(key))
+                .and_then(Value::as_u64)
+                .map_or(default, |v| usize::try_from(v).unwrap_or(default))
+        };
+        Self(Box::new(NoLargeFileConfig {
+            max_lines: get("maxLines", default.max_lines),
+            max_bytes: get("maxBytes", default.max_bytes),
+        }))
+    }
+
+    #[expect(clippy::cast_possible_truncation)]
+    fn run_once(&self, ctx: &LintContext) {
+        let source_text = ctx.source_text();
+        // Point to end of the file for `eslint-disable oxc/no-large-file` to work.
+        let span = Span::empty(source_text.len().saturating_sub(1) as u32);
+
+        // Intentionally counting newline bytes instead of using .lines() for performance
+        let newlines = source_text.bytes().filter(|ch| *ch == b'\n').count();
+        let lines = if source_text.ends_with('\n') { newlines } else { newlines + 1 };
+        if lines > self.max_lines {
+            ctx.diagnostic(no_large_file_diagnostic(lines, self.max_lines, "lines", span));
+            return;
+        }
+
+        let bytes = source_text.len();
+        if bytes > self.max_bytes {
+            ctx.diagnostic(no_large_file_diagnostic(bytes, self.max_bytes, "bytes", span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var x;", None),
+        ("var x;\nvar y;\n", Some(serde_json::json!([{ "maxLines": 2 }]))),
+        ("var x;\nvar y;", Some(serde_json::json!([{ "maxLines": 2, "maxBytes": 13 }]))),
+        (
+            "/* eslint-disable oxc/no-large-file */\nvar x;\nvar y;",
+            Some(serde_json::json!([{ "maxLines": 1 }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("var x;\nvar y;\nvar z;", Some(serde_json::json!([{ "maxLines": 2 }]))),
+        ("var x;\n\n// comment\n", Some(serde_json::json!([{ "maxLines": 2 }]))),
+        ("var x;\nvar y;", Some(serde_json::json!([{ "maxBytes": 12 }]))),
+    ];
+
+    Tester::new(NoLargeFile::NAME, NoLargeFile::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -81,6 +81,7 @@ declare_oxc_lint!(
     promise,
     restriction,
     config = CatchOrReturnConfig,
+    unchecked_config,
 );
 
 impl Rule for CatchOrReturn {
@@ -1,25 +1,19 @@
-use std::hash::Hash;
-
-use oxc_ast::{
-    AstKind,
-    ast::{BindingIdentifier, CallExpression, Expression},
-};
+use oxc_ast::{AstKind, ast::CallExpression};
 use oxc_ast_visit::Visit;
-use oxc_cfg::{
-    BlockNodeId, ControlFlowGraph, EdgeType, ErrorEdgeKind, InstructionKind,
-    graph::{
-        Direction,
-        visit::{Control, DfsEvent, EdgeRef, set_depth_first_search},
-    },
-};
+use oxc_cfg::{BlockNodeId, InstructionKind};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_semantic::{Scoping, SymbolId};
 use oxc_span::Span;
-use rustc_hash::{FxHashMap, FxHashSet};
+use rustc_hash::FxHashMap;
 
 use crate::{
-    AstNode, context::LintContext, rule::Rule, utils::get_promise_constructor_inline_executor,
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        ControlFlowPaths, PromiseResolveFinder, get_promise_constructor_inline_executor,
+        get_promise_executor_resolvers,
+    },
 };
 
 fn already_resolved_diagnostic(line: usize, span: Span) -> OxcDiagnostic {
@@ -85,12 +79,12 @@ impl Rule for NoMultipleResolved {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let Some(new_expr) = node.kind().as_new_expression() else { return };
         let Some(executor_expr) = get_promise_constructor_inline_executor(new_expr) else { return };
-        let (resolve_symbol_id, reject_symbol_id) = get_resolve_symbol_id(executor_expr);
-        let resolve_findler =
-            ResolveFinder::new(ctx.scoping(), resolve_symbol_id, reject_symbol_id);
-        let mut multiple_resolved_checker = MultipleResolvedChecker::new(ctx, resolve_findler);
+        let (resolve_symbol_id, reject_symbol_id) = get_promise_executor_resolvers(executor_expr);
+        let resolve_finder =
+            PromiseResolveFinder::new(ctx.scoping(), resolve_symbol_id, reject_symbol_id);
+        let mut multiple_resolved_checker = MultipleResolvedChecker::new(ctx, resolve_finder);
         let Some(inline_executor_cfg_id) =
-            next_new_function_node_id(ctx.cfg(), ctx.nodes().cfg_id(node.id()))
+            multiple_resolved_checker.paths.function_entry(ctx.nodes().cfg_id(node.id()))
         else {
             return;
         };
@@ -98,13 +92,6 @@ impl Rule for NoMultipleResolved {
     }
 }
 
-fn next_new_function_node_id(cfg: &ControlFlowGraph, block_id: BlockNodeId) -> Option<BlockNodeId> {
-    cfg.graph()
-        .edges_directed(block_id, Direction::Outgoing)
-        .find(|edge| matches!(edge.weight(), EdgeType::NewFunction))
-        .map(|edge| edge.target())
-}
-
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum ResolvedKind {
     /// resolve or reject has been called on some path
@@ -132,51 +119,29 @@ impl<'a> BlockResolvedInfo<'a> {
     }
 }
 
+#[derive(Default)]
 struct FunctionResolvedInfo<'a> {
     resolved_infos: FxHashMap<BlockNodeId, BlockResolvedInfo<'a>>,
-    // The block_ids contains all id of blocks in the current function, It's in topological order.
-    block_ids: Vec<BlockNodeId>,
     // The immediate dominator for each block
     dominators: FxHashMap<BlockNodeId, BlockNodeId>,
 }
 
-impl FunctionResolvedInfo<'_> {
-    fn new() -> Self {
-        Self {
-            resolved_infos: FxHashMap::default(),
-            block_ids: vec![],
-            dominators: FxHashMap::default(),
-        }
-    }
-}
-
-fn sets_intersection<T>(sets: &[&FxHashSet<T>]) -> FxHashSet<T>
-where
-    T: Eq + Hash + Copy,
-{
-    if let Some((&first, rest)) = sets.split_first() {
-        if rest.is_empty() {
-            first.clone()
-        } else {
-            rest.iter().fold(first.clone(), |acc, &set| {
-                acc.intersection(set).copied().collect::<FxHashSet<_>>()
-            })
-        }
-    } else {
-        FxHashSet::default()
-    }
-}
-
 struct MultipleResolvedChecker<'a, 'b: 'a> {
     ctx: &'a LintContext<'b>,
-    resolve_finder: ResolveFinder<'a>,
+    paths: ControlFlowPaths<'a>,
+    resolve_finder: PromiseResolveFinder<'a>,
     // Stack tracking resolved information for nested functions.
     func_resolved_info_stack: Vec<FunctionResolvedInfo<'a>>,
 }
 
 impl<'a, 'b> MultipleResolvedChecker<'a, 'b> {
-    fn new(ctx: &'a LintContext<'b>, resolve_finder: ResolveFinder<'a>) -> Self {
-        Self { ctx, resolve_finder, func_resolved_info_stack: vec![] }
+    fn new(ctx: &'a LintContext<'b>, resolve_finder: PromiseResolveFinder<'a>) -> Self {
+        Self {
+            ctx,
+            paths: ControlFlowPaths::new(ctx.cfg()),
+            resolve_finder,
+            func_resolved_info_stack: vec![],
+        }
     }
 
     #[inline]
@@ -189,34 +154,14 @@ impl<'a, 'b> MultipleResolvedChecker<'a, 'b> {
         self.func_resolved_info_stack.last_mut()
     }
 
-    #[inline]
-    fn push_func_resolved_info(&mut self, info: FunctionResolvedInfo<'a>) {
-        self.func_resolved_info_stack.push(info);
-    }
-
-    #[inline]
-    fn pop_func_resolved_info(&mut self) -> Option<FunctionResolvedInfo<'a>> {
-        self.func_resolved_info_stack.pop()
-    }
-
-    #[inline]
-    fn resolved_infos(&self) -> Option<&FxHashMap<BlockNodeId, BlockResolvedInfo<'a>>> {
-        Some(&self.current_func_resolved_info()?.resolved_infos)
-    }
-
     #[inline]
     fn block_resolved_info(&self, block_id: BlockNodeId) -> Option<&BlockResolvedInfo<'a>> {
-        self.resolved_infos()?.get(&block_id)
+        self.current_func_resolved_info()?.resolved_infos.get(&block_id)
     }
 
     fn insert_block_resolved_info(&mut self, block_id: BlockNodeId, info: BlockResolvedInfo<'a>) {
-        let Some(resolved_infos) = self.resolved_infos_mut() else { return };
-        resolved_infos.insert(block_id, info);
-    }
-
-    #[inline]
-    fn resolved_infos_mut(&mut self) -> Option<&mut FxHashMap<BlockNodeId, BlockResolvedInfo<'a>>> {
-        Some(&mut self.current_func_resolved_info_mut()?.resolved_infos)
+        let Some(func_resolved_info) = self.current_func_resolved_info_mut() else { return };
+        func_resolved_info.resolved_infos.insert(block_id, info);
     }
 
     #[inline]
@@ -224,192 +169,20 @@ impl<'a, 'b> MultipleResolvedChecker<'a, 'b> {
         &mut self,
         block_id: BlockNodeId,
     ) -> Option<&mut BlockResolvedInfo<'a>> {
-        self.resolved_infos_mut()?.get_mut(&block_id)
-    }
-
-    #[inline]
-    fn block_ids(&self) -> Option<&Vec<BlockNodeId>> {
-        Some(&self.current_func_resolved_info()?.block_ids)
-    }
-
-    #[inline]
-    fn set_block_ids(&mut self, block_ids: Vec<BlockNodeId>) {
-        if let Some(current_func_resolved_info) = self.current_func_resolved_info_mut() {
-            current_func_resolved_info.block_ids = block_ids;
-        }
-    }
-
-    #[inline]
-    fn dominators(&self) -> Option<&FxHashMap<BlockNodeId, BlockNodeId>> {
-        Some(&self.current_func_resolved_info()?.dominators)
+        self.current_func_resolved_info_mut()?.resolved_infos.get_mut(&block_id)
     }
 
     #[inline]
     fn block_dominator(&self, block_id: BlockNodeId) -> Option<BlockNodeId> {
-        self.dominators()?.get(&block_id).copied()
-    }
-
-    #[inline]
-    fn set_dominators(&mut self, dominators: FxHashMap<BlockNodeId, BlockNodeId>) {
-        if let Some(current_func_resolved_info) = self.current_func_resolved_info_mut() {
-            current_func_resolved_info.dominators = dominators;
-        }
-    }
-
-    fn compute_block_node_dominator(&self) -> FxHashMap<BlockNodeId, BlockNodeId> {
-        let Some(block_ids) = self.block_ids() else {
-            return FxHashMap::default();
-        };
-        let mut block_nodes_dominators: FxHashMap<BlockNodeId, FxHashSet<BlockNodeId>> = block_ids
-            .iter()
-            .map(|&block_id| (block_id, FxHashSet::from_iter([block_id])))
-            .collect();
-
-        for &block_id in block_ids {
-            let in_coming_block_ids = self.in_coming_block_ids(block_id);
-            let prev_block_nodes_dominators: Vec<_> = in_coming_block_ids
-                .iter()
-                .filter_map(|block_id| block_nodes_dominators.get(block_id))
-                .collect();
-            if prev_block_nodes_dominators.is_empty() {
-                continue;
-            }
-            let prev_dominators = sets_intersection(&prev_block_nodes_dominators);
-            block_nodes_dominators.entry(block_id).or_default().extend(prev_dominators);
-        }
-        let block_nodes_sort: FxHashMap<_, _> =
-            block_ids.iter().enumerate().map(|(i, &block_id)| (block_id, i)).collect();
-        let mut block_nodes_immediate_dominator: FxHashMap<BlockNodeId, BlockNodeId> =
-            FxHashMap::default();
-        for (block_node_id, dominators) in &block_nodes_dominators {
-            if dominators.len() <= 1 {
-                continue;
-            }
-            let immediate_dominator = dominators
-                .iter()
-                .filter(|&dominator_node_id| *dominator_node_id != *block_node_id)
-                .max_by_key(|&dominator_node_id| block_nodes_sort[dominator_node_id]);
-            if let Some(immediate_dominator) = immediate_dominator {
-                block_nodes_immediate_dominator.insert(*block_node_id, *immediate_dominator);
-            }
-        }
-        block_nodes_immediate_dominator
-    }
-
-    fn block_is_condition(&self, block_id: BlockNodeId) -> bool {
-        let instructions = self.ctx.cfg().basic_block(block_id).instructions();
-        !instructions.is_empty()
-            && instructions
-                .iter()
-                .all(|instruction| matches!(instruction.kind, InstructionKind::Condition))
-    }
-
-    fn backedge_next_target(&self, block_id: BlockNodeId) -> Option<BlockNodeId> {
-        let graph = self.ctx.cfg().graph();
-        let mut source = block_id;
-        'outer: loop {
-            let next_edges = graph.edges_directed(source, Direction::Outgoing);
-            for edge in next_edges {
-                match edge.weight() {
-                    EdgeType::Backedge if !self.block_is_condition(edge.source()) => {
-                        source = edge.target();
-                        continue 'outer;
-                    }
-                    EdgeType::Normal if source != block_id => {
-                        return Some(edge.target());
-                    }
-                    _ => {}
-                }
-            }
-            return None;
-        }
-    }
-
-    fn prev_backedge_source(&self, block_id: BlockNodeId) -> Option<BlockNodeId> {
-        let graph = self.ctx.cfg().graph();
-        let mut source: Option<BlockNodeId> = None;
-        loop {
-            let back_edge = graph
-                .edges_directed(source.unwrap_or(block_id), Direction::Incoming)
-                .find(|edge| matches!(edge.weight(), EdgeType::Backedge));
-            if let Some(back_edge) = back_edge
-                && !self.block_is_condition(back_edge.source())
-            {
-                source = Some(back_edge.source());
-                continue;
-            }
-            break;
-        }
-        source
-    }
-
-    fn out_going_block_ids(&self, block_id: BlockNodeId) -> Vec<BlockNodeId> {
-        let graph = self.ctx.cfg().graph();
-        let mut outgoming_block_ids: Vec<BlockNodeId> = Vec::new();
-        graph.edges_directed(block_id, Direction::Outgoing).for_each(|edge| match edge.weight() {
-            EdgeType::Normal
-            | EdgeType::Jump
-            | EdgeType::Join
-            | EdgeType::Error(ErrorEdgeKind::Explicit)
-            | EdgeType::Finalize => {
-                outgoming_block_ids.push(edge.target());
-            }
-            EdgeType::Backedge => {
-                if let Some(next_target) = self.backedge_next_target(edge.source()) {
-                    outgoming_block_ids.push(next_target);
-                }
-            }
-            _ => {}
-        });
-        outgoming_block_ids.reverse();
-        outgoming_block_ids
-    }
-
-    fn in_coming_block_ids(&self, block_id: BlockNodeId) -> Vec<BlockNodeId> {
-        let graph = self.ctx.cfg().graph();
-        let mut incoming_block_ids: Vec<BlockNodeId> = Vec::new();
-        graph.edges_directed(block_id, Direction::Incoming).for_each(|edge| match edge.weight() {
-            EdgeType::Normal => {
-                let source = edge.source();
-                if let Some(prev_source) = self.prev_backedge_source(source) {
-                    incoming_block_ids.push(prev_source);
-                }
-                incoming_block_ids.push(source);
-            }
-            EdgeType::Jump
-            | EdgeType::Join
-            | EdgeType::Error(ErrorEdgeKind::Explicit)
-            | EdgeType::Finalize => {
-                incoming_block_ids.push(edge.source());
-            }
-            EdgeType::Backedge => {
-                let source = edge.source();
-                if graph
-                    .edges_directed(block_id, Direction::Outgoing)
-                    .any(|edge| matches!(edge.weight(), EdgeType::Backedge))
-                {
-                    incoming_block_ids.push(source);
-                }
-            }
-            _ => {}
-        });
-        incoming_block_ids.reverse();
-        incoming_block_ids
-    }
-
-    fn is_catch_start_block(&self, block_id: BlockNodeId) -> bool {
-        let graph = self.ctx.cfg().graph();
-        graph
-            .edges_directed(block_id, Direction::Incoming)
-            .all(|edge| matches!(edge.weight(), EdgeType::Error(ErrorEdgeKind::Explicit)))
+        self.current_func_resolved_info()?.dominators.get(&block_id).copied()
     }
 
     fn prev_resolved_info(
         &self,
         block_id: BlockNodeId,
     ) -> (Option<&'a CallExpression<'a>>, ResolvedKind) {
-        let incoming_block_ids = self.in_coming_block_ids(block_id);
-        let is_catch_start_block = self.is_catch_start_block(block_id);
+        let incoming_block_ids = self.paths.predecessors(block_id);
+        let is_catch_start_block = self.paths.is_catch_entry(block_id);
         let mut certain_resolved_num = 0;
         let mut first_certain_resolved: Option<&CallExpression<'_>> = None;
         let mut first_potential_resolved: Option<&CallExpression<'_>> = None;
@@ -427,7 +200,7 @@ impl<'a, 'b> MultipleResolvedChecker<'a, 'b> {
                     }
                     ResolvedKind::Potential => {
                         if first_potential_resolved.is_none()
-                            && self.out_going_block_ids(block_id).len() == 1
+                            && self.paths.successors(block_id).len() == 1
                         {
                             first_potential_resolved = resolved_info.resolved;
                         }
@@ -463,55 +236,24 @@ impl<'a, 'b> MultipleResolvedChecker<'a, 'b> {
         }
     }
 
-    fn traverse_blocks(&mut self, start_block_id: BlockNodeId) {
-        let graph = self.ctx.cfg().graph();
-        let mut block_ids: Vec<BlockNodeId> = vec![];
-        set_depth_first_search::<_, _, _, Control<()>, _>(graph, Some(start_block_id), |event| {
-            match event {
-                DfsEvent::TreeEdge(a, b) => {
-                    let edges = graph.edges_connecting(a, b).collect::<Vec<_>>();
-                    for edge in &edges {
-                        if matches!(edge.weight(), EdgeType::NewFunction) {
-                            self.check(edge.target());
-                            return Control::Prune;
-                        }
-                    }
-                    if edges.iter().any(|edge| {
-                        matches!(edge.weight(), EdgeType::Backedge)
-                            && graph
-                                .edges_directed(edge.target(), Direction::Outgoing)
-                                .any(|edge| matches!(edge.weight(), EdgeType::Backedge))
-                    }) {
-                        return Control::Prune;
-                    }
-                    Control::Continue
-                }
-                DfsEvent::Finish(block_id, _) => {
-                    block_ids.push(block_id);
-                    Control::Continue
-                }
-                _ => Control::Continue,
-            }
-        });
-        block_ids.reverse();
-        self.set_block_ids(block_ids);
-    }
-
     fn check(&mut self, start_block_id: BlockNodeId) {
-        self.push_func_resolved_info(FunctionResolvedInfo::new());
-        self.traverse_blocks(start_block_id);
-        let block_ids: Vec<_> = self.block_ids().map_or(vec![], Clone::clone);
+        self.func_resolved_info_stack.push(FunctionResolvedInfo::default());
+        let paths = self.paths;
+        let block_ids = paths.function_blocks(start_block_id, |entry| self.check(entry));
         for &block_id in &block_ids {
             self.check_block(block_id);
         }
-        self.set_dominators(self.compute_block_node_dominator());
+        let dominators = paths.immediate_dominators(&block_ids);
+        if let Some(func_resolved_info) = self.current_func_resolved_info_mut() {
+            func_resolved_info.dominators = dominators;
+        }
         for &block_id in block_ids.iter().skip(1) {
             let (prev_resolved, prev_resolved_kind) = self.prev_resolved_info(block_id);
             if matches!(prev_resolved_kind, ResolvedKind::None) {
                 continue;
             }
             let Some(resolved_info) = self.block_resolved_info_mut(block_id) else {
-                self.pop_func_resolved_info();
+                self.func_resolved_info_stack.pop();
                 return;
             };
             if resolved_info.kind == ResolvedKind::Certain {
@@ -538,7 +280,7 @@ impl<'a, 'b> MultipleResolvedChecker<'a, 'b> {
                 resolved_info.kind = prev_resolved_kind;
             }
         }
-        self.pop_func_resolved_info();
+        self.func_resolved_info_stack.pop();
     }
 
     fn collect_block_resolved_calls(
@@ -546,7 +288,7 @@ impl<'a, 'b> MultipleResolvedChecker<'a, 'b> {
         block_id: BlockNodeId,
     ) -> Vec<&'a CallExpression<'a>> {
         let mut resolved = Vec::<&CallExpression>::new();
-        if self.is_catch_start_block(block_id) {
+        if self.paths.is_catch_entry(block_id) {
             self.resolve_finder.try_block_end();
         }
         self.ctx.cfg().basic_block(block_id).instructions().iter().for_each(|instruction| {
@@ -583,7 +325,7 @@ impl<'a, 'b> MultipleResolvedChecker<'a, 'b> {
         }) {
             true
         } else {
-            let in_coming_block_ids = self.in_coming_block_ids(block_id);
+            let in_coming_block_ids = self.paths.predecessors(block_id);
             !in_coming_block_ids.is_empty()
                 && in_coming_block_ids.iter().all(|block_id| {
                     self.block_resolved_info(*block_id)
@@ -619,141 +361,11 @@ impl<'a, 'b> MultipleResolvedChecker<'a, 'b> {
     }
 }
 
-fn get_resolve_symbol_id(expr: &Expression) -> (Option<SymbolId>, Option<SymbolId>) {
-    let params = match expr {
-        Expression::FunctionExpression(func_expr) => Some(func_expr.params.as_ref()),
-        Expression::ArrowFunctionExpression(arrow_func_expr) => {
-            Some(arrow_func_expr.params.as_ref())
-        }
-        _ => None,
-    };
-    let symbol_ids = params.map_or(vec![], |params| {
-        params
-            .items
-            .iter()
-            .map(|param| param.pattern.get_binding_identifier().map(BindingIdentifier::symbol_id))
-            .collect::<Vec<_>>()
-    });
-    let resolve_symbol_id = symbol_ids.first().copied().unwrap_or(None);
-    let reject_symbol_id = symbol_ids.get(1).copied().unwrap_or(None);
-    (resolve_symbol_id, reject_symbol_id)
-}
-
 #[inline]
 fn get_span_line(source_text: &str, span: Span) -> usize {
     source_text[..span.end as usize].lines().count()
 }
 
-#[derive(Debug)]
-struct ResolveFinder<'a> {
-    scoping: &'a Scoping,
-    resolve_symbol_id: Option<SymbolId>,
-    reject_symbol_id: Option<SymbolId>,
-    resolved: Vec<&'a CallExpression<'a>>,
-    // If it's a try block with a catch, then the try block's depth is 1
-    try_block_depth: i32,
-    last_throwable_expr_span: Option<Span>,
-}
-
-impl<'a> ResolveFinder<'a> {
-    fn new(
-        scoping: &'a Scoping,
-        resolve_symbol_id: Option<SymbolId>,
-        reject_symbol_id: Option<SymbolId>,
-    ) -> Self {
-        Self {
-            scoping,
-            resolve_symbol_id,
-            reject_symbol_id,
-            resolved: vec![],
-            try_block_depth: 0,
-            last_throwable_expr_span: None,
-        }
-    }
-
-    #[inline]
-    fn try_block_start(&mut self) {
-        self.try_block_depth += 1;
-    }
-
-    #[inline]
-    fn try_block_end(&mut self) {
-        self.try_block_depth -= 1;
-    }
-
-    #[inline]
-    fn in_try_block(&self) -> bool {
-        self.try_block_depth > 0
-    }
-
-    #[inline]
-    fn record_throwable_expr_span(&mut self, span: Span) {
-        if self.in_try_block() {
-            self.last_throwable_expr_span = Some(span);
-        }
-    }
-
-    #[inline]
-    fn take_resolved(&mut self) -> Vec<&'a CallExpression<'a>> {
-        self.resolved.drain(..).collect()
-    }
-
-    #[inline]
-    fn take_last_throwable_expr_span(&mut self) -> Option<Span> {
-        let span = self.last_throwable_expr_span;
-        self.last_throwable_expr_span = None;
-        span
-    }
-}
-
-impl<'a> Visit<'a> for ResolveFinder<'a> {
-    fn leave_node(&mut self, kind: AstKind<'a>) {
-        match kind {
-            AstKind::NewExpression(new_expr) => {
-                self.record_throwable_expr_span(new_expr.span);
-            }
-            AstKind::ImportExpression(import_expr) => {
-                self.record_throwable_expr_span(import_expr.span);
-            }
-            AstKind::YieldExpression(yield_expr) => {
-                self.record_throwable_expr_span(yield_expr.span);
-            }
-            AstKind::StaticMemberExpression(static_member_expr) => {
-                self.record_throwable_expr_span(static_member_expr.span);
-            }
-            AstKind::ComputedMemberExpression(computed_member_expr) => {
-                self.record_throwable_expr_span(computed_member_expr.span);
-            }
-            _ => {}
-        }
-    }
-    fn visit_call_expression(&mut self, call_expr: &CallExpression<'a>) {
-        match &call_expr.callee {
-            Expression::Identifier(ident) => {
-                let symbol_id = self.scoping.get_reference(ident.reference_id()).symbol_id();
-                if symbol_id == self.resolve_symbol_id || symbol_id == self.reject_symbol_id {
-                    self.resolved.push(self.alloc(call_expr));
-                } else {
-                    self.record_throwable_expr_span(call_expr.span);
-                }
-            }
-            _ => {
-                self.record_throwable_expr_span(call_expr.span);
-            }
-        }
-    }
-
-    fn visit_function(
-        &mut self,
-        _it: &oxc_ast::ast::Function<'a>,
-        _flags: oxc_semantic::ScopeFlags,
-    ) {
-    }
-
-    fn visit_arrow_function_expression(&mut self, _it: &oxc_ast::ast::ArrowFunctionExpression<'a>) {
-    }
-}
-
 #[test]
 fn test() {
     use crate::tester::Tester;
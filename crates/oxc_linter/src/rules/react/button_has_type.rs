@@ -7,12 +7,13 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{GetSpan, Span};
+use oxc_span::{GetSpan, SourceType, Span};
 use schemars::JsonSchema;
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
+    frameworks::FrameworkOptions,
     rule::Rule,
     utils::{get_prop_value, has_jsx_prop_ignore_case, is_create_element_call},
 };
@@ -169,8 +170,12 @@ impl Rule for ButtonHasType {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_jsx()
     }
 }
 
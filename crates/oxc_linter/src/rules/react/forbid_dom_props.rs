@@ -1,14 +1,12 @@
 use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{CompactStr, Span};
+use oxc_span::{CompactStr, SourceType, Span};
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde_json::Value;
 
 use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
+    AstNode, context::LintContext, frameworks::FrameworkOptions, rule::Rule,
     utils::is_react_component_name,
 };
 
@@ -186,8 +184,12 @@ impl Rule for ForbidDomProps {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx() && !self.forbid.is_empty()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_jsx() && !self.forbid.is_empty()
     }
 }
 
@@ -1,13 +1,14 @@
 use oxc_ast::{AstKind, ast::Argument};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{Atom, CompactStr, GetSpan, Span};
+use oxc_span::{Atom, CompactStr, GetSpan, SourceType, Span};
 use rustc_hash::FxHashMap;
 use serde_json::Value;
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
+    frameworks::FrameworkOptions,
     rule::Rule,
     utils::{get_element_type, is_react_function_call},
 };
@@ -170,8 +171,12 @@ impl Rule for ForbidElements {
         Self(Box::new(ForbidElementsConfig { forbid_elements }))
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx() && !self.forbid_elements.is_empty()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_jsx() && !self.forbid_elements.is_empty()
     }
 }
 
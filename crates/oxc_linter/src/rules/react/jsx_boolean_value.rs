@@ -4,16 +4,13 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{CompactStr, Span};
+use oxc_span::{CompactStr, SourceType, Span};
 use rustc_hash::FxHashSet;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-    utils::get_prop_value,
+    AstNode, context::LintContext, frameworks::FrameworkOptions, rule::Rule, utils::get_prop_value,
 };
 
 fn boolean_value_diagnostic(attr: &str, span: Span) -> OxcDiagnostic {
@@ -86,6 +83,7 @@ declare_oxc_lint!(
     style,
     fix,
     config = JsxBooleanValueConfig,
+    unchecked_config,
 );
 
 impl Rule for JsxBooleanValue {
@@ -171,8 +169,12 @@ impl Rule for JsxBooleanValue {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_jsx()
     }
 }
 
@@ -1,8 +1,4 @@
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, frameworks::FrameworkOptions, rule::Rule};
 use lazy_regex::{Lazy, Regex, lazy_regex};
 use oxc_allocator::{Allocator, Vec};
 
@@ -17,7 +13,7 @@ use oxc_codegen::CodegenOptions;
 use oxc_diagnostics::{Error, LabeledSpan, OxcDiagnostic};
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::NodeId;
-use oxc_span::{GetSpan as _, Span};
+use oxc_span::{GetSpan as _, SourceType, Span};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -304,6 +300,7 @@ declare_oxc_lint!(
     style,
     fix,
     config = JsxCurlyBracePresence,
+    unchecked_config,
 );
 
 impl Rule for JsxCurlyBracePresence {
@@ -364,8 +361,12 @@ impl Rule for JsxCurlyBracePresence {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_jsx()
     }
 }
 
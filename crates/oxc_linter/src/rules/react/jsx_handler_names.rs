@@ -104,6 +104,7 @@ declare_oxc_lint!(
     react,
     style,
     config = JsxHandlerNamesConfig,
+    unchecked_config,
 );
 
 fn build_event_handler_regex(handler_prefix: &str, handler_prop_prefix: &str) -> Option<Regex> {
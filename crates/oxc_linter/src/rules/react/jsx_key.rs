@@ -1,5 +1,3 @@
-use std::ops::Deref;
-
 use cow_utils::CowUtils;
 use rustc_hash::FxHashSet;
 use schemars::JsonSchema;
@@ -15,14 +13,11 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{GetSpan, Span};
+use oxc_span::{GetSpan, SourceType, Span};
 
 use crate::{
-    AstNode,
-    ast_util::is_node_within_call_argument,
-    context::{ContextHost, LintContext},
-    rule::{DefaultRuleConfig, Rule},
-    utils::default_true,
+    AstNode, ast_util::is_node_within_call_argument, context::LintContext,
+    frameworks::FrameworkOptions, rule::Rule, utils::default_true,
 };
 
 const TARGET_METHODS: [&str; 3] = ["flatMap", "from", "map"];
@@ -52,9 +47,8 @@ fn duplicate_key_prop(key_value: &str, span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
-#[derive(Debug, Default, Clone, JsonSchema)]
-#[schemars(transparent)]
-pub struct JsxKey(Box<JsxKeyConfig>);
+#[derive(Debug, Default, Clone)]
+pub struct JsxKey;
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase", default)]
@@ -71,14 +65,6 @@ pub struct JsxKeyConfig {
     pub check_fragment_shorthand: bool,
 }
 
-impl Deref for JsxKey {
-    type Target = JsxKeyConfig;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -104,35 +90,30 @@ declare_oxc_lint!(
     JsxKey,
     react,
     correctness,
-    config = JsxKey,
+    config = JsxKeyConfig,
+    typed_config,
 );
 
 impl Rule for JsxKey {
-    fn from_configuration(value: serde_json::Value) -> Self {
-        let config = serde_json::from_value::<DefaultRuleConfig<JsxKeyConfig>>(value)
-            .unwrap_or_default()
-            .into_inner();
-        Self(Box::new(config))
-    }
-
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let config = ctx.options::<JsxKeyConfig>();
         match node.kind() {
             AstKind::JSXElement(jsx_elem) => {
                 check_jsx_element(node, jsx_elem, ctx);
-                if self.check_key_must_before_spread {
+                if config.check_key_must_before_spread {
                     check_jsx_element_is_key_before_spread(jsx_elem, ctx);
                 }
-                if self.warn_on_duplicates {
+                if config.warn_on_duplicates {
                     check_duplicate_keys_in_children(jsx_elem, ctx);
                 }
             }
             AstKind::JSXFragment(jsx_frag) => {
-                if self.check_fragment_shorthand {
+                if config.check_fragment_shorthand {
                     check_jsx_fragment(node, jsx_frag, ctx);
                 }
             }
             AstKind::ArrayExpression(array_expr) => {
-                if self.warn_on_duplicates {
+                if config.warn_on_duplicates {
                     check_duplicate_keys_in_array(array_expr, ctx);
                 }
             }
@@ -141,8 +122,12 @@ impl Rule for JsxKey {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_jsx()
     }
 }
 
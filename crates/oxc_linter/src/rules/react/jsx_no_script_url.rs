@@ -6,13 +6,9 @@ use serde_json::Value;
 use oxc_ast::{AstKind, ast::JSXAttributeItem};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{CompactStr, GetSpan, Span};
+use oxc_span::{CompactStr, GetSpan, SourceType, Span};
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, frameworks::FrameworkOptions, rule::Rule};
 
 fn jsx_no_script_url_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("A future version of React will block javascript: URLs as a security precaution.")
@@ -71,6 +67,7 @@ declare_oxc_lint!(
     suspicious,
     pending,
     config = JsxNoScriptUrlConfig,
+    unchecked_config,
 );
 
 fn is_link_attribute(tag_name: &str, prop_value_literal: String, ctx: &LintContext) -> bool {
@@ -167,8 +164,12 @@ impl Rule for JsxNoScriptUrl {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_jsx()
     }
 }
 
@@ -1,7 +1,8 @@
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     fixer::{RuleFix, RuleFixer},
+    frameworks::FrameworkOptions,
     rule::Rule,
     utils::is_jsx_fragment,
 };
@@ -16,7 +17,7 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::NodeId;
-use oxc_span::{GetSpan, Span};
+use oxc_span::{GetSpan, SourceType, Span};
 use schemars::JsonSchema;
 
 fn needs_more_children(span: Span) -> OxcDiagnostic {
@@ -92,8 +93,12 @@ impl Rule for JsxNoUselessFragment {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_jsx()
     }
 }
 
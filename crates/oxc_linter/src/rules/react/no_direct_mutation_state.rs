@@ -1,12 +1,13 @@
 use oxc_ast::{AstKind, ast::MethodDefinitionKind};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{GetSpan, Span};
+use oxc_span::{GetSpan, SourceType, Span};
 
 use crate::{
     AstNode,
     ast_util::get_outer_member_expression,
-    context::{ContextHost, LintContext},
+    context::LintContext,
+    frameworks::FrameworkOptions,
     rule::Rule,
     utils::{is_es5_component, is_es6_component, is_state_member_expression},
 };
@@ -117,8 +118,12 @@ impl Rule for NoDirectMutationState {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_jsx()
     }
 }
 
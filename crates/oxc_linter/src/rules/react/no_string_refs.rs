@@ -7,13 +7,11 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{GetSpan, Span};
+use oxc_span::{GetSpan, SourceType, Span};
 use schemars::JsonSchema;
 
 use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
+    AstNode, context::LintContext, frameworks::FrameworkOptions, rule::Rule,
     utils::get_parent_component,
 };
 
@@ -142,8 +140,12 @@ impl Rule for NoStringRefs {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_jsx()
     }
 }
 
@@ -8,18 +8,15 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{GetSpan, Span};
+use oxc_span::{GetSpan, SourceType, Span};
 use phf::{Map, Set, phf_map, phf_set};
 use rustc_hash::{FxHashMap, FxHashSet};
 use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    globals::is_valid_aria_property,
-    rule::Rule,
-    utils::get_jsx_attribute_name,
+    AstNode, context::LintContext, frameworks::FrameworkOptions, globals::is_valid_aria_property,
+    rule::Rule, utils::get_jsx_attribute_name,
 };
 
 fn invalid_prop_on_tag(span: Span, prop: &str, tag: &str) -> OxcDiagnostic {
@@ -560,8 +557,12 @@ impl Rule for NoUnknownProperty {
             });
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_jsx()
     }
 }
 
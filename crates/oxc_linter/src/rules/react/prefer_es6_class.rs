@@ -1,14 +1,15 @@
 use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{GetSpan, Span};
+use oxc_span::{GetSpan, SourceType, Span};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
-    rule::{DefaultRuleConfig, Rule},
+    context::LintContext,
+    frameworks::FrameworkOptions,
+    rule::Rule,
     utils::{is_es5_component, is_es6_component},
 };
 
@@ -33,7 +34,7 @@ enum PreferES6ClassOptionType {
 }
 
 #[derive(Debug, Default, Clone)]
-pub struct PreferEs6Class(PreferES6ClassOptionType);
+pub struct PreferEs6Class;
 
 declare_oxc_lint!(
     /// ### What it does
@@ -59,26 +60,21 @@ declare_oxc_lint!(
     react,
     style,
     config = PreferES6ClassOptionType,
+    default_config,
+    typed_config,
 );
 
 impl Rule for PreferEs6Class {
-    fn from_configuration(value: serde_json::Value) -> Self {
-        Self(
-            serde_json::from_value::<DefaultRuleConfig<PreferES6ClassOptionType>>(value)
-                .unwrap_or_default()
-                .into_inner(),
-        )
-    }
-
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::CallExpression(call_expr)
-                if matches!(self.0, PreferES6ClassOptionType::Always) && is_es5_component(node) =>
+                if matches!(ctx.options(), PreferES6ClassOptionType::Always)
+                    && is_es5_component(node) =>
             {
                 ctx.diagnostic(expected_es6_class_diagnostic(call_expr.callee.span()));
             }
             AstKind::Class(class_expr)
-                if !matches!(self.0, PreferES6ClassOptionType::Always)
+                if !matches!(ctx.options(), PreferES6ClassOptionType::Always)
                     && is_es6_component(node) =>
             {
                 ctx.diagnostic(unexpected_es6_class_diagnostic(
@@ -89,8 +85,12 @@ impl Rule for PreferEs6Class {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_jsx()
     }
 }
 
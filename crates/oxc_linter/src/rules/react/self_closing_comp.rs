@@ -4,14 +4,11 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{SourceType, Span};
 use schemars::JsonSchema;
 
 use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    globals::HTML_TAG,
-    rule::Rule,
+    AstNode, context::LintContext, frameworks::FrameworkOptions, globals::HTML_TAG, rule::Rule,
 };
 
 fn self_closing_comp_diagnostic(span: Span) -> OxcDiagnostic {
@@ -139,8 +136,12 @@ impl Rule for SelfClosingComp {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_jsx()
     }
 }
 
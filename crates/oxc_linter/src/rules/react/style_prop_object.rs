@@ -7,15 +7,12 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{CompactStr, GetSpan, Span};
+use oxc_span::{CompactStr, GetSpan, SourceType, Span};
 use schemars::JsonSchema;
 
 use crate::{
-    AstNode,
-    ast_util::get_declaration_of_variable,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-    utils::is_create_element_call,
+    AstNode, ast_util::get_declaration_of_variable, context::LintContext,
+    frameworks::FrameworkOptions, rule::Rule, utils::is_create_element_call,
 };
 
 fn style_prop_object_diagnostic(span: Span) -> OxcDiagnostic {
@@ -232,8 +229,12 @@ impl Rule for StylePropObject {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_jsx()
     }
 }
 
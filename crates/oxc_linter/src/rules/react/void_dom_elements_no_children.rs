@@ -7,12 +7,10 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{SourceType, Span};
 
 use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
+    AstNode, context::LintContext, frameworks::FrameworkOptions, rule::Rule,
     utils::is_create_element_call,
 };
 
@@ -150,8 +148,12 @@ impl Rule for VoidDomElementsNoChildren {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_jsx()
     }
 }
 
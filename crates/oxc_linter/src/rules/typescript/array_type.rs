@@ -8,13 +8,12 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::AstNode;
-use oxc_span::Span;
+use oxc_span::{SourceType, Span};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    ast_util::outermost_paren_parent,
-    context::{ContextHost, LintContext},
+    ast_util::outermost_paren_parent, context::LintContext, frameworks::FrameworkOptions,
     rule::Rule,
 };
 
@@ -249,8 +248,12 @@ impl Rule for ArrayType {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_typescript()
     }
 }
 
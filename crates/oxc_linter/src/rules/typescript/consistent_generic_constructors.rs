@@ -4,11 +4,11 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{SourceType, Span};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{AstNode, context::LintContext, frameworks::FrameworkOptions, rule::Rule};
 
 fn consistent_generic_constructors_diagnostic_prefer_annotation(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(
@@ -89,7 +89,8 @@ declare_oxc_lint!(
     typescript,
     style,
     pending,
-    config = ConsistentGenericConstructorsConfig
+    config = ConsistentGenericConstructorsConfig,
+    unchecked_config,
 );
 
 impl Rule for ConsistentGenericConstructors {
@@ -128,8 +129,12 @@ impl Rule for ConsistentGenericConstructors {
         }))
     }
 
-    fn should_run(&self, ctx: &crate::rules::ContextHost) -> bool {
-        ctx.source_type().is_typescript()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_typescript()
     }
 }
 
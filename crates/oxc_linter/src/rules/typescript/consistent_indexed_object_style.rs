@@ -4,15 +4,11 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{SourceType, Span};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::{DefaultRuleConfig, Rule},
-};
+use crate::{AstNode, context::LintContext, frameworks::FrameworkOptions, rule::Rule};
 
 fn consistent_indexed_object_style_diagnostic(
     preferred: ConsistentIndexedObjectStyleConfig,
@@ -33,7 +29,7 @@ fn consistent_indexed_object_style_diagnostic(
 }
 
 #[derive(Debug, Clone, Default)]
-pub struct ConsistentIndexedObjectStyle(ConsistentIndexedObjectStyleConfig);
+pub struct ConsistentIndexedObjectStyle;
 
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
@@ -105,21 +101,15 @@ declare_oxc_lint!(
     style,
     conditional_fix,
     config = ConsistentIndexedObjectStyleConfig,
+    default_config,
+    typed_config,
 );
 
 impl Rule for ConsistentIndexedObjectStyle {
-    fn from_configuration(value: serde_json::Value) -> Self {
-        Self(
-            serde_json::from_value::<DefaultRuleConfig<ConsistentIndexedObjectStyleConfig>>(value)
-                .unwrap_or_default()
-                .into_inner(),
-        )
-    }
-
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let preferred_style = self.0;
+        let preferred_style = *ctx.options::<ConsistentIndexedObjectStyleConfig>();
 
-        if self.0 == ConsistentIndexedObjectStyleConfig::Record {
+        if preferred_style == ConsistentIndexedObjectStyleConfig::Record {
             match node.kind() {
                 AstKind::TSInterfaceDeclaration(inf) => {
                     if inf.body.body.len() > 1 {
@@ -273,8 +263,12 @@ impl Rule for ConsistentIndexedObjectStyle {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_typescript()
     }
 }
 
@@ -1,4 +1,4 @@
-use std::{borrow::Cow, error::Error, fmt::Write, ops::Deref};
+use std::{borrow::Cow, error::Error, fmt::Write, ops::Deref, path::PathBuf};
 
 use itertools::Itertools;
 use oxc_ast::{
@@ -11,13 +11,16 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::{Reference, SymbolId};
-use oxc_span::{GetSpan, Span};
+use oxc_span::{GetSpan, SourceType, Span};
+use rustc_hash::FxHashSet;
 use schemars::JsonSchema;
 
 use crate::{
     AstNode,
-    context::{ContextHost, LintContext},
+    context::LintContext,
     fixer::{RuleFix, RuleFixer},
+    frameworks::FrameworkOptions,
+    module_record::ModuleRecord,
     rule::Rule,
 };
 
@@ -33,6 +36,12 @@ fn type_over_value_diagnostic(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
+fn side_effects_help(source: &str) -> String {
+    format!(
+        "`{source}` has side effects which would not run after converting to `import type`. Add `import '{source}';` if they are needed."
+    )
+}
+
 fn some_imports_are_only_types_diagnostic(span: Span, type_imports: &str) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("Imports {type_imports} are only used as type.")).with_label(span)
 }
@@ -178,6 +187,22 @@ declare_oxc_lint!(
     /// const b = B;
     /// ```
     ///
+    /// #### Modules with side effects
+    ///
+    /// `import type` declarations are removed at runtime, so converting an import whose
+    /// imports are all only used as types stops the imported module from being evaluated.
+    /// When the import plugin is enabled, the imported module is inspected and the declaration
+    /// is reported without a fix if the module, or any module it imports, has side effects:
+    /// ```ts
+    /// // polyfill.ts
+    /// export interface Polyfilled {}
+    /// globalThis.structuredClone ??= clone;
+    ///
+    /// // index.ts: reported, but not fixed
+    /// import { Polyfilled } from './polyfill';
+    /// let p: Polyfilled;
+    /// ```
+    ///
     /// #### Examples with `"disallowTypeAnnotations": false`
     ///
     /// When set to `false`, allows `import()` type annotations:
@@ -350,7 +375,22 @@ impl Rule for ConsistentTypeImports {
                 // `import type {} from 'foo' assert { type: 'json' }` is invalid
                 // Import assertions cannot be used with type-only imports or exports.
                 if import_decl.with_clause.is_none() {
-                    ctx.diagnostic_with_fix(type_over_value_diagnostic(import_decl.span), fixer_fn);
+                    // `import type` is erased at runtime, so the imported module would no longer
+                    // be evaluated.
+                    let source = import_decl.source.value.as_str();
+                    if ctx.module_record().get_loaded_module(source).is_some_and(|module| {
+                        module_may_have_side_effects(&module, &mut FxHashSet::default())
+                    }) {
+                        ctx.diagnostic(
+                            type_over_value_diagnostic(import_decl.span)
+                                .with_help(side_effects_help(source)),
+                        );
+                    } else {
+                        ctx.diagnostic_with_fix(
+                            type_over_value_diagnostic(import_decl.span),
+                            fixer_fn,
+                        );
+                    }
                 }
                 return;
             }
@@ -362,8 +402,12 @@ impl Rule for ConsistentTypeImports {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_typescript()
     }
 }
 
@@ -401,6 +445,21 @@ fn is_only_has_type_references(symbol_id: SymbolId, ctx: &LintContext) -> bool {
     peekable_iter.all(Reference::is_type)
 }
 
+// Returns `true` if evaluating the module, or any module it imports at runtime, may have side
+// effects. Only modules which are linted are known, see [ModuleRecord::get_loaded_module].
+fn module_may_have_side_effects(module: &ModuleRecord, visited: &mut FxHashSet<PathBuf>) -> bool {
+    if !visited.insert(module.resolved_absolute_path.clone()) {
+        return false;
+    }
+    module.has_side_effects
+        || module
+            .requested_modules
+            .iter()
+            .filter(|(_, requests)| requests.iter().any(|request| !request.is_type))
+            .filter_map(|(specifier, _)| module.get_loaded_module(specifier))
+            .any(|module| module_may_have_side_effects(&module, visited))
+}
+
 struct FixOptions<'a, 'b> {
     fixer: RuleFixer<'b, 'a>,
     import_decl: &'b ImportDeclaration<'a>,
@@ -3466,3 +3525,49 @@ export class Foo extends Bar {}
         .expect_fix(fix)
         .test_and_snapshot();
 }
+
+#[test]
+fn test_side_effects() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "import { Options } from './side-effects'; const o: Options = { debug: true }; console.log(Options);",
+            None,
+        ),
+        ("import type { Options } from './side-effects'; let o: Options;", None),
+        ("import { type Options } from './side-effects'; let o: Options;", None),
+    ];
+
+    let fail = vec![
+        ("import { Options } from './pure'; let o: Options;", None),
+        ("import { Options } from './reexport-type-only'; let o: Options;", None),
+        ("import { Options } from './side-effects'; let o: Options;", None),
+        ("import { Options } from './reexport-side-effects'; let o: Options;", None),
+        ("import { Polyfilled } from './polyfill'; let p: Polyfilled;", None),
+        (
+            "import { Options, DEFAULT_OPTIONS } from './side-effects'; let o: Options = DEFAULT_OPTIONS;",
+            None,
+        ),
+    ];
+
+    let fix = vec![
+        (
+            "import { Options } from './pure'; let o: Options;",
+            "import type { Options } from './pure'; let o: Options;",
+            None,
+        ),
+        (
+            "import { Options } from './reexport-type-only'; let o: Options;",
+            "import type { Options } from './reexport-type-only'; let o: Options;",
+            None,
+        ),
+    ];
+
+    Tester::new(ConsistentTypeImports::NAME, ConsistentTypeImports::PLUGIN, pass, fail)
+        .change_rule_path("consistent-type-imports/index.ts")
+        .with_import_plugin(true)
+        .expect_fix(fix)
+        .with_snapshot_suffix("side_effects")
+        .test_and_snapshot();
+}
@@ -70,6 +70,7 @@ declare_oxc_lint!(
     pedantic,
     pending,
     config = NoConfusingVoidExpressionConfig,
+    default_config,
 );
 
 impl Rule for NoConfusingVoidExpression {
@@ -65,6 +65,7 @@ declare_oxc_lint!(
     correctness,
     pending,
     config = NoDuplicateTypeConstituentsConfig,
+    default_config,
 );
 
 impl Rule for NoDuplicateTypeConstituents {
@@ -84,6 +84,7 @@ declare_oxc_lint!(
     suspicious,
     dangerous_suggestion,
     config = NoExtraneousClass,
+    unchecked_config,
 );
 
 fn empty_class_diagnostic(span: Span, has_decorators: bool) -> OxcDiagnostic {
@@ -108,6 +108,7 @@ declare_oxc_lint!(
     correctness,
     pending,
     config = NoFloatingPromisesConfig,
+    default_config,
 );
 
 impl Rule for NoFloatingPromises {
@@ -136,6 +136,7 @@ declare_oxc_lint!(
     pedantic,
     pending,
     config = NoMisusedPromisesConfig,
+    default_config,
 );
 
 impl Rule for NoMisusedPromises {
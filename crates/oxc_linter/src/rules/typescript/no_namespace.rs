@@ -1,14 +1,10 @@
 use oxc_ast::{AstKind, ast::TSModuleDeclarationName};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{SourceType, Span};
 use schemars::JsonSchema;
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, frameworks::FrameworkOptions, rule::Rule};
 
 fn no_namespace_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("ES2015 module syntax is preferred over namespaces.")
@@ -158,11 +154,15 @@ impl Rule for NoNamespace {
         ctx.diagnostic(no_namespace_diagnostic(span));
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        if self.allow_definition_files && ctx.source_type().is_typescript_definition() {
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        if self.allow_definition_files && source_type.is_typescript_definition() {
             return false;
         }
-        ctx.source_type().is_typescript()
+        source_type.is_typescript()
     }
 }
 
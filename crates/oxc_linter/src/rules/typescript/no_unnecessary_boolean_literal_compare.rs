@@ -93,6 +93,7 @@ declare_oxc_lint!(
     suspicious,
     pending,
     config = NoUnnecessaryBooleanLiteralCompareConfig,
+    default_config,
 );
 
 impl Rule for NoUnnecessaryBooleanLiteralCompare {
@@ -63,6 +63,7 @@ declare_oxc_lint!(
     suspicious,
     pending,
     config = NoUnnecessaryTypeAssertionConfig,
+    default_config,
 );
 
 impl Rule for NoUnnecessaryTypeAssertion {
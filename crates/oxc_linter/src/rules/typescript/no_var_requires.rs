@@ -1,12 +1,10 @@
 use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{GetSpan, Span};
+use oxc_span::{GetSpan, SourceType, Span};
 
 use crate::{
-    AstNode,
-    ast_util::is_global_require_call,
-    context::{ContextHost, LintContext},
+    AstNode, ast_util::is_global_require_call, context::LintContext, frameworks::FrameworkOptions,
     rule::Rule,
 };
 
@@ -66,8 +64,12 @@ impl Rule for NoVarRequires {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_typescript()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        source_type.is_typescript()
     }
 }
 
@@ -85,6 +85,7 @@ declare_oxc_lint!(
     pedantic,
     pending,
     config = OnlyThrowErrorConfig,
+    default_config,
 );
 
 impl Rule for OnlyThrowError {
@@ -73,6 +73,7 @@ declare_oxc_lint!(
     pedantic,
     pending,
     config = PreferPromiseRejectErrorsConfig,
+    default_config,
 );
 
 impl Rule for PreferPromiseRejectErrors {
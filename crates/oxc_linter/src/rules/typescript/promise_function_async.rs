@@ -113,6 +113,7 @@ declare_oxc_lint!(
     restriction,
     pending,
     config = PromiseFunctionAsyncConfig,
+    default_config,
 );
 
 impl Rule for PromiseFunctionAsync {
@@ -102,6 +102,7 @@ declare_oxc_lint!(
     pedantic,
     pending,
     config = RestrictPlusOperandsConfig,
+    default_config,
 );
 
 impl Rule for RestrictPlusOperands {
@@ -129,6 +129,7 @@ declare_oxc_lint!(
     correctness,
     pending,
     config = RestrictTemplateExpressionsConfig,
+    default_config,
 );
 
 impl Rule for RestrictTemplateExpressions {
@@ -98,6 +98,7 @@ declare_oxc_lint!(
     pedantic,
     pending,
     config = ReturnAwaitOption,
+    default_config,
 );
 
 impl Rule for ReturnAwait {
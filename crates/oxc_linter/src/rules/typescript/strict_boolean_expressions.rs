@@ -130,6 +130,7 @@ declare_oxc_lint!(
     pedantic,
     pending,
     config = StrictBooleanExpressionsConfig,
+    default_config,
 );
 
 impl Rule for StrictBooleanExpressions {
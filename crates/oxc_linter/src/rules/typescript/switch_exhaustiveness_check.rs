@@ -123,6 +123,7 @@ declare_oxc_lint!(
     pedantic,
     pending,
     config = SwitchExhaustivenessCheckConfig,
+    default_config,
 );
 
 impl Rule for SwitchExhaustivenessCheck {
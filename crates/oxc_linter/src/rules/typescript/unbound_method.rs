@@ -98,6 +98,7 @@ declare_oxc_lint!(
     correctness,
     pending,
     config = UnboundMethodConfig,
+    default_config,
 );
 
 impl Rule for UnboundMethod {
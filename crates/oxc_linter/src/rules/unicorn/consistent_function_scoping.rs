@@ -5,13 +5,14 @@ use oxc_ast_visit::{Visit, walk};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::{ReferenceId, ScopeFlags, ScopeId, SymbolId};
-use oxc_span::{Atom, GetSpan, Span};
+use oxc_span::{Atom, GetSpan, SourceType, Span};
 use schemars::JsonSchema;
 
 use crate::{
     AstNode,
     ast_util::{get_function_like_declaration, is_node_call_like_argument, outermost_paren_parent},
     context::LintContext,
+    frameworks::FrameworkOptions,
     rule::Rule,
     utils::is_react_hook,
 };
@@ -302,9 +303,13 @@ impl Rule for ConsistentFunctionScoping {
         ));
     }
 
-    fn should_run(&self, ctx: &crate::context::ContextHost) -> bool {
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
         // .d.ts files are never run, so there are no perf considerations for them.
-        !ctx.source_type().is_typescript_definition()
+        !source_type.is_typescript_definition()
     }
 }
 
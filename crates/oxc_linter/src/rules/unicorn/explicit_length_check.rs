@@ -94,6 +94,7 @@ declare_oxc_lint!(
     pedantic,
     conditional_fix,
     config = ExplicitLengthCheck,
+    unchecked_config,
 );
 
 fn is_literal(expr: &Expression, value: f64) -> bool {
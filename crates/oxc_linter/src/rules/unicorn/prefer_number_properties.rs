@@ -79,6 +79,7 @@ declare_oxc_lint!(
     restriction,
     dangerous_fix,
     config = PreferNumberPropertiesConfig,
+    unchecked_config,
 );
 
 impl Rule for PreferNumberProperties {
@@ -5,12 +5,7 @@ use oxc_span::{GetSpan, Span};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    AstNode,
-    ast_util::get_preceding_indent_str,
-    context::LintContext,
-    rule::{DefaultRuleConfig, Rule},
-};
+use crate::{AstNode, ast_util::get_preceding_indent_str, context::LintContext, rule::Rule};
 
 fn switch_case_braces_diagnostic_empty_clause(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Unexpected braces in empty case clause.")
@@ -30,14 +25,8 @@ fn switch_case_braces_diagnostic_unnecessary_braces(span: Span) -> OxcDiagnostic
         .with_label(span)
 }
 
-#[derive(Debug, Clone)]
-pub struct SwitchCaseBraces(Box<SwitchCaseBracesConfig>);
-
-impl Default for SwitchCaseBraces {
-    fn default() -> Self {
-        Self(Box::new(SwitchCaseBracesConfig::Always))
-    }
-}
+#[derive(Debug, Default, Clone)]
+pub struct SwitchCaseBraces;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(rename_all = "kebab-case")]
@@ -92,17 +81,11 @@ declare_oxc_lint!(
     style,
     fix,
     config = SwitchCaseBracesConfig,
+    default_config,
+    typed_config,
 );
 
 impl Rule for SwitchCaseBraces {
-    fn from_configuration(value: serde_json::Value) -> Self {
-        Self(Box::new(
-            serde_json::from_value::<DefaultRuleConfig<SwitchCaseBracesConfig>>(value)
-                .unwrap_or_default()
-                .into_inner(),
-        ))
-    }
-
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::SwitchStatement(switch) = node.kind() else {
             return;
@@ -112,6 +95,7 @@ impl Rule for SwitchCaseBraces {
             return;
         }
 
+        let config = ctx.options::<SwitchCaseBracesConfig>();
         for case in &switch.cases {
             if case.consequent.is_empty() {
                 continue;
@@ -125,7 +109,7 @@ impl Rule for SwitchCaseBraces {
                         );
                         continue;
                     }
-                    if *self.0 == SwitchCaseBracesConfig::Avoid
+                    if *config == SwitchCaseBracesConfig::Avoid
                         && !block_stmt.body.iter().any(|stmt| {
                             matches!(
                                 stmt,
@@ -150,7 +134,7 @@ impl Rule for SwitchCaseBraces {
                 _ => true,
             };
 
-            if *self.0 == SwitchCaseBracesConfig::Always && missing_braces {
+            if *config == SwitchCaseBracesConfig::Always && missing_braces {
                 let test_end = case.test.as_ref().map_or(case.span.start, |t| t.span().end);
                 let colon_offset = ctx.find_next_token_from(test_end, ":").unwrap();
                 let colon_pos = test_end + colon_offset;
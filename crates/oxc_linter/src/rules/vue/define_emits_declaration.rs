@@ -4,16 +4,11 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{GetSpan, Span};
+use oxc_span::{GetSpan, SourceType, Span};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    frameworks::FrameworkOptions,
-    rule::{DefaultRuleConfig, Rule},
-};
+use crate::{AstNode, context::LintContext, frameworks::FrameworkOptions, rule::Rule};
 
 fn has_arg_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Use type based declaration instead of runtime declaration")
@@ -48,7 +43,7 @@ enum DeclarationStyle {
 }
 
 #[derive(Debug, Default, Clone)]
-pub struct DefineEmitsDeclaration(DeclarationStyle);
+pub struct DefineEmitsDeclaration;
 
 declare_oxc_lint!(
     /// ### What it does
@@ -125,17 +120,11 @@ declare_oxc_lint!(
     style,
     pending, // TODO: transform it to the other declaration (if possible)
     config = DeclarationStyle,
+    default_config,
+    typed_config,
 );
 
 impl Rule for DefineEmitsDeclaration {
-    fn from_configuration(value: serde_json::Value) -> Self {
-        Self(
-            serde_json::from_value::<DefaultRuleConfig<DeclarationStyle>>(value)
-                .unwrap_or_default()
-                .into_inner(),
-        )
-    }
-
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else { return };
 
@@ -148,7 +137,7 @@ impl Rule for DefineEmitsDeclaration {
             return;
         }
 
-        match self.0 {
+        match ctx.options::<DeclarationStyle>() {
             DeclarationStyle::TypeBased => {
                 if !call_expr.arguments.is_empty() {
                     ctx.diagnostic(has_arg_diagnostic(call_expr.span));
@@ -187,8 +176,12 @@ impl Rule for DefineEmitsDeclaration {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost<'_>) -> bool {
-        ctx.frameworks_options() == FrameworkOptions::VueSetup && ctx.source_type().is_typescript()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        framework_options: FrameworkOptions,
+    ) -> bool {
+        framework_options == FrameworkOptions::VueSetup && source_type.is_typescript()
     }
 }
 
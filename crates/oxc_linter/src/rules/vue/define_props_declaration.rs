@@ -1,16 +1,11 @@
 use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{SourceType, Span};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    frameworks::FrameworkOptions,
-    rule::{DefaultRuleConfig, Rule},
-};
+use crate::{AstNode, context::LintContext, frameworks::FrameworkOptions, rule::Rule};
 
 fn use_runtime_declaration_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Use runtime declaration instead of type-based declaration")
@@ -33,7 +28,7 @@ enum DeclarationStyle {
 }
 
 #[derive(Debug, Default, Clone)]
-pub struct DefinePropsDeclaration(DeclarationStyle);
+pub struct DefinePropsDeclaration;
 
 declare_oxc_lint!(
     /// ### What it does
@@ -80,17 +75,11 @@ declare_oxc_lint!(
     vue,
     style,
     config = DeclarationStyle,
+    default_config,
+    typed_config,
 );
 
 impl Rule for DefinePropsDeclaration {
-    fn from_configuration(value: serde_json::Value) -> Self {
-        Self(
-            serde_json::from_value::<DefaultRuleConfig<DeclarationStyle>>(value)
-                .unwrap_or_default()
-                .into_inner(),
-        )
-    }
-
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else { return };
 
@@ -103,7 +92,7 @@ impl Rule for DefinePropsDeclaration {
             return;
         }
 
-        match self.0 {
+        match ctx.options::<DeclarationStyle>() {
             DeclarationStyle::TypeBased => {
                 if !call_expr.arguments.is_empty() {
                     ctx.diagnostic(use_type_based_declaration_diagnostic(call_expr.span));
@@ -117,8 +106,12 @@ impl Rule for DefinePropsDeclaration {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost<'_>) -> bool {
-        ctx.frameworks_options() == FrameworkOptions::VueSetup && ctx.source_type().is_typescript()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        framework_options: FrameworkOptions,
+    ) -> bool {
+        framework_options == FrameworkOptions::VueSetup && source_type.is_typescript()
     }
 }
 
@@ -1,16 +1,11 @@
 use oxc_ast::{AstKind, ast::BindingPatternKind};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{SourceType, Span};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    AstNode,
-    context::{ContextHost, LintContext},
-    frameworks::FrameworkOptions,
-    rule::Rule,
-};
+use crate::{AstNode, context::LintContext, frameworks::FrameworkOptions, rule::Rule};
 
 fn prefer_destructuring_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Prefer destructuring from `defineProps` directly.").with_label(span)
@@ -86,6 +81,7 @@ declare_oxc_lint!(
     vue,
     style,
     config = DefinePropsDestructuring,
+    unchecked_config,
 );
 
 impl Rule for DefinePropsDestructuring {
@@ -133,8 +129,12 @@ impl Rule for DefinePropsDestructuring {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost<'_>) -> bool {
-        ctx.frameworks_options() == FrameworkOptions::VueSetup
+    fn should_run_on_source(
+        &self,
+        _source_type: SourceType,
+        framework_options: FrameworkOptions,
+    ) -> bool {
+        framework_options == FrameworkOptions::VueSetup
     }
 }
 
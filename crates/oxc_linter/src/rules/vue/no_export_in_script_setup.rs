@@ -1,12 +1,8 @@
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{SourceType, Span};
 
-use crate::{
-    context::{ContextHost, LintContext},
-    frameworks::FrameworkOptions,
-    rule::Rule,
-};
+use crate::{context::LintContext, frameworks::FrameworkOptions, rule::Rule};
 
 fn no_export_in_script_setup_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("<script setup>` cannot contain ES module exports.").with_label(span)
@@ -78,8 +74,12 @@ impl Rule for NoExportInScriptSetup {
         }
     }
 
-    fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.frameworks_options() == FrameworkOptions::VueSetup
+    fn should_run_on_source(
+        &self,
+        _source_type: SourceType,
+        framework_options: FrameworkOptions,
+    ) -> bool {
+        framework_options == FrameworkOptions::VueSetup
     }
 }
 
@@ -1,8 +1,8 @@
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{SourceType, Span};
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, frameworks::FrameworkOptions, rule::Rule};
 
 fn prefer_import_from_vue_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("enforce import from 'vue' instead of import from '@vue/*'")
@@ -82,8 +82,12 @@ impl Rule for PreferImportFromVue {
         }
     }
 
-    fn should_run(&self, ctx: &crate::context::ContextHost) -> bool {
-        !ctx.source_type().is_typescript_definition()
+    fn should_run_on_source(
+        &self,
+        source_type: SourceType,
+        _framework_options: FrameworkOptions,
+    ) -> bool {
+        !source_type.is_typescript_definition()
     }
 }
 
@@ -1,7 +1,7 @@
 use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{SourceType, Span};
 
 use crate::{
     context::LintContext,
@@ -178,8 +178,12 @@ impl Rule for ValidDefineEmits {
         }
     }
 
-    fn should_run(&self, ctx: &crate::context::ContextHost) -> bool {
-        ctx.frameworks_options() == FrameworkOptions::VueSetup
+    fn should_run_on_source(
+        &self,
+        _source_type: SourceType,
+        framework_options: FrameworkOptions,
+    ) -> bool {
+        framework_options == FrameworkOptions::VueSetup
     }
 }
 
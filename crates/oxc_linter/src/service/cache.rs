@@ -0,0 +1,724 @@
+use std::{
+    borrow::Cow,
+    ffi::OsStr,
+    fs,
+    hash::{Hash, Hasher},
+    io,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use indexmap::IndexMap;
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet, FxHasher};
+use serde::{Deserialize, Serialize};
+
+use oxc_allocator::Allocator;
+use oxc_diagnostics::{DiagnosticValue, LabeledSpan, OxcCode, OxcDiagnostic, Severity};
+use oxc_span::Span;
+
+use crate::{Fix, FixKind, Message, ModuleRecord, PossibleFixes, RuleOrigin};
+
+use super::RuntimeFileSystem;
+
+/// Hash of a source text, used to detect changed files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContentHash {
+    len: usize,
+    hash: u64,
+}
+
+impl ContentHash {
+    pub fn new(source_text: &str) -> Self {
+        Self::of_sections([source_text])
+    }
+
+    /// Hash of the source texts of all sections of a file, e.g. the `<script>` blocks of a Vue file.
+    pub fn of_sections<'s>(source_texts: impl IntoIterator<Item = &'s str>) -> Self {
+        let mut hasher = FxHasher::default();
+        let mut len = 0;
+        for source_text in source_texts {
+            source_text.hash(&mut hasher);
+            len += source_text.len();
+        }
+        Self { len, hash: hasher.finish() }
+    }
+
+    fn read(file_system: &(dyn RuntimeFileSystem + Sync + Send), path: &Path) -> Option<Self> {
+        // Not taken from the allocator pool, which may be exhausted by the threads linting.
+        let allocator = Allocator::default();
+        file_system.read_to_arena_str(path, &allocator).ok().map(Self::new)
+    }
+}
+
+struct CacheEntry {
+    content_hash: ContentHash,
+    /// Content hashes of all modules imported by the file, directly or transitively.
+    /// Cross-module rules can report different diagnostics when any of them change.
+    dependencies: Vec<(PathBuf, Option<ContentHash>)>,
+    messages: Vec<Message>,
+}
+
+/// A bounded, in-memory cache of the diagnostics of linted files, for long running processes
+/// such as the language server, which lint the same unchanged files again.
+///
+/// A file is not linted again if its source text and the source texts of all modules it imports
+/// are unchanged. The cache belongs to one [`Linter`](crate::Linter), so the configuration of a
+/// file can not change while its entry is alive. A linter created for a new configuration starts
+/// with an empty cache.
+pub struct LintCache {
+    capacity: usize,
+    /// Entries in least recently used order.
+    entries: Mutex<IndexMap<Arc<OsStr>, CacheEntry, FxBuildHasher>>,
+}
+
+impl LintCache {
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, entries: Mutex::new(IndexMap::with_hasher(FxBuildHasher)) }
+    }
+
+    /// Get the diagnostics of `path` from the last time it was linted, if neither the file nor
+    /// any of its dependencies changed since then. `content_hash` is the hash of the source text
+    /// of `path` read to lint it.
+    pub fn get(
+        &self,
+        file_system: &(dyn RuntimeFileSystem + Sync + Send),
+        path: &Arc<OsStr>,
+        content_hash: ContentHash,
+    ) -> Option<Vec<Message>> {
+        let dependencies = {
+            let entries = self.entries.lock().expect("lint cache mutex poisoned");
+            let entry = entries.get(path)?;
+            if entry.content_hash != content_hash {
+                return None;
+            }
+            entry.dependencies.clone()
+        };
+        for (dependency, hash) in &dependencies {
+            if ContentHash::read(file_system, dependency) != *hash {
+                return None;
+            }
+        }
+
+        let mut entries = self.entries.lock().expect("lint cache mutex poisoned");
+        let index = entries.get_index_of(path)?;
+        let last = entries.len() - 1;
+        entries.move_index(index, last);
+        Some(entries[last].messages.clone())
+    }
+
+    /// Store the diagnostics of `path`, linted from `source_text` with the modules imported by
+    /// `module_records`.
+    pub fn insert(
+        &self,
+        file_system: &(dyn RuntimeFileSystem + Sync + Send),
+        path: &Arc<OsStr>,
+        source_text: &str,
+        module_records: &[Arc<ModuleRecord>],
+        messages: &[Message],
+    ) {
+        if self.capacity == 0 {
+            return;
+        }
+        let dependencies = dependency_paths(module_records)
+            .into_iter()
+            .filter(|dependency| dependency.as_os_str() != &**path)
+            .map(|dependency| {
+                let hash = ContentHash::read(file_system, &dependency);
+                (dependency, hash)
+            })
+            .collect();
+        let entry = CacheEntry {
+            content_hash: ContentHash::new(source_text),
+            dependencies,
+            messages: messages.to_vec(),
+        };
+
+        let mut entries = self.entries.lock().expect("lint cache mutex poisoned");
+        entries.shift_remove(path);
+        if entries.len() >= self.capacity {
+            entries.shift_remove_index(0);
+        }
+        entries.insert(Arc::clone(path), entry);
+    }
+}
+
+/// Identifies the diagnostics reported by one rule on one section of a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RuleResultKey {
+    /// Index of the section in the file, `0` for files with a single section.
+    pub section: usize,
+    pub rule_id: usize,
+    /// Hash of the configuration the rule ran with: its options and severity, and the settings,
+    /// globals and environments of the file.
+    pub config_hash: u64,
+}
+
+#[derive(Debug)]
+struct RuleResultsEntry {
+    content_hash: ContentHash,
+    results: FxHashMap<RuleResultKey, Vec<Message>>,
+}
+
+/// A bounded cache of the diagnostics of each rule on linted files.
+///
+/// Unlike [`LintCache`], which caches the diagnostics of all rules of a file, results are kept
+/// per rule and configuration of the rule. The cache can be shared by the linters created for
+/// each new configuration with [`Linter::with_rule_result_cache`](crate::Linter::with_rule_result_cache),
+/// so changing the configuration of one rule only runs that rule again on unchanged files.
+///
+/// Results are only cached for files which import no resolved modules and have no type
+/// information, as rules can report different diagnostics when another file changes.
+///
+/// The language server keeps the cache in memory while it runs. `oxlint --cache` reads it from a
+/// file with [`RuleResultCache::read`] and writes it back with [`RuleResultCache::write`].
+#[derive(Debug)]
+pub struct RuleResultCache {
+    capacity: usize,
+    /// Entries in least recently used order.
+    entries: Mutex<IndexMap<Arc<OsStr>, RuleResultsEntry, FxBuildHasher>>,
+}
+
+impl RuleResultCache {
+    /// Create a cache for the results of at most `capacity` files.
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, entries: Mutex::new(IndexMap::with_hasher(FxBuildHasher)) }
+    }
+
+    /// Get the diagnostics of each of `keys` which was run on `path` with the same content.
+    ///
+    /// # Panics
+    /// Panics if another thread panicked while holding the lock of the cache.
+    pub fn get(
+        &self,
+        path: &Path,
+        content_hash: ContentHash,
+        keys: impl IntoIterator<Item = RuleResultKey>,
+    ) -> FxHashMap<RuleResultKey, Vec<Message>> {
+        let mut entries = self.entries.lock().expect("rule result cache mutex poisoned");
+        let Some(index) = entries.get_index_of(path.as_os_str()) else {
+            return FxHashMap::default();
+        };
+        let last = entries.len() - 1;
+        entries.move_index(index, last);
+        let entry = &entries[last];
+        if entry.content_hash != content_hash {
+            return FxHashMap::default();
+        }
+        keys.into_iter()
+            .filter_map(|key| entry.results.get(&key).map(|messages| (key, messages.clone())))
+            .collect()
+    }
+
+    /// Store the diagnostics of rules run on `path`. Results of other rules and configurations
+    /// are kept if the content of `path` is unchanged.
+    ///
+    /// # Panics
+    /// Panics if another thread panicked while holding the lock of the cache.
+    pub fn insert(
+        &self,
+        path: &Path,
+        content_hash: ContentHash,
+        results: impl IntoIterator<Item = (RuleResultKey, Vec<Message>)>,
+    ) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.entries.lock().expect("rule result cache mutex poisoned");
+        let mut entry = match entries.shift_remove(path.as_os_str()) {
+            Some(entry) if entry.content_hash == content_hash => entry,
+            _ => RuleResultsEntry { content_hash, results: FxHashMap::default() },
+        };
+        entry.results.extend(results);
+        if entries.len() >= self.capacity {
+            entries.shift_remove_index(0);
+        }
+        entries.insert(Arc::from(path.as_os_str()), entry);
+    }
+
+    /// Read a cache written by [`RuleResultCache::write`] to `path`, keeping the results of at
+    /// most `capacity` files.
+    ///
+    /// The cache is empty if `path` does not exist, can not be read, or was written by another
+    /// version of oxlint, whose rules and configuration hashes may differ.
+    ///
+    /// # Panics
+    /// Panics if another thread panicked while holding the lock of the cache.
+    pub fn read(path: &Path, capacity: usize) -> Self {
+        let cache = Self::new(capacity);
+        let Some(file) = fs::read_to_string(path)
+            .ok()
+            .and_then(|json| serde_json::from_str::<CacheFile>(&json).ok())
+            .filter(|file| file.version == CACHE_FILE_VERSION)
+        else {
+            return cache;
+        };
+        let skip = file.files.len().saturating_sub(capacity);
+        let mut entries = cache.entries.lock().expect("rule result cache mutex poisoned");
+        for file in file.files.into_iter().skip(skip) {
+            let results = file
+                .results
+                .into_iter()
+                .map(|result| {
+                    (
+                        result.key,
+                        result.messages.into_iter().map(CachedMessage::into_message).collect(),
+                    )
+                })
+                .collect();
+            entries.insert(
+                Arc::from(file.path.as_os_str()),
+                RuleResultsEntry { content_hash: file.content_hash, results },
+            );
+        }
+        drop(entries);
+        cache
+    }
+
+    /// Write the cache to `path`, creating its directory if needed, for
+    /// [`RuleResultCache::read`].
+    ///
+    /// # Errors
+    /// Returns an error if the file can not be written.
+    ///
+    /// # Panics
+    /// Panics if another thread panicked while holding the lock of the cache.
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        let entries = self.entries.lock().expect("rule result cache mutex poisoned");
+        let file = CacheFile {
+            version: Cow::Borrowed(CACHE_FILE_VERSION),
+            files: entries
+                .iter()
+                .map(|(path, entry)| CachedFile {
+                    path: PathBuf::from(path),
+                    content_hash: entry.content_hash,
+                    results: entry
+                        .results
+                        .iter()
+                        .map(|(key, messages)| CachedRuleResult {
+                            key: *key,
+                            messages: messages.iter().map(CachedMessage::from_message).collect(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        };
+        let json = serde_json::to_string(&file).map_err(io::Error::other)?;
+        drop(entries);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        // Write to a temporary file first, so that a concurrent run never reads half a cache.
+        let temporary_path = path.with_extension("tmp");
+        fs::write(&temporary_path, json)?;
+        fs::rename(temporary_path, path)
+    }
+}
+
+/// Version of the format of cache files, and of the rules which wrote them. Rule ids and hashes
+/// of configurations are only stable within one version of oxlint.
+const CACHE_FILE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// A [`RuleResultCache`] as it is written to a file, with files in least recently used order.
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    version: Cow<'static, str>,
+    files: Vec<CachedFile>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedFile {
+    path: PathBuf,
+    content_hash: ContentHash,
+    results: Vec<CachedRuleResult>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedRuleResult {
+    key: RuleResultKey,
+    messages: Vec<CachedMessage>,
+}
+
+/// A [`Message`] as it is written to a cache file.
+#[derive(Serialize, Deserialize)]
+struct CachedMessage {
+    message: String,
+    labels: Vec<CachedLabel>,
+    help: Option<String>,
+    severity: CachedSeverity,
+    scope: Option<String>,
+    number: Option<String>,
+    url: Option<String>,
+    data: Vec<(String, CachedValue)>,
+    fixes: CachedFixes,
+    span: (u32, u32),
+    section_offset: u32,
+    origin: Option<RuleOrigin>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedLabel {
+    label: Option<String>,
+    offset: usize,
+    len: usize,
+    primary: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+enum CachedSeverity {
+    Advice,
+    Warning,
+    Error,
+}
+
+#[derive(Serialize, Deserialize)]
+enum CachedValue {
+    String(String),
+    Number(i64),
+}
+
+#[derive(Serialize, Deserialize)]
+enum CachedFixes {
+    None,
+    Single(CachedFix),
+    Multiple(Vec<CachedFix>),
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedFix {
+    content: String,
+    message: Option<String>,
+    span: (u32, u32),
+    kind: u8,
+}
+
+impl CachedMessage {
+    fn from_message(message: &Message) -> Self {
+        let error = &message.error;
+        Self {
+            message: error.message.to_string(),
+            labels: error
+                .labels
+                .iter()
+                .flatten()
+                .map(|label| CachedLabel {
+                    label: label.label().map(str::to_string),
+                    offset: label.offset(),
+                    len: label.len(),
+                    primary: label.primary(),
+                })
+                .collect(),
+            help: error.help.as_deref().map(str::to_string),
+            severity: match error.severity {
+                Severity::Advice => CachedSeverity::Advice,
+                Severity::Warning => CachedSeverity::Warning,
+                Severity::Error => CachedSeverity::Error,
+            },
+            scope: error.code.scope.as_deref().map(str::to_string),
+            number: error.code.number.as_deref().map(str::to_string),
+            url: error.url.as_deref().map(str::to_string),
+            data: error
+                .data
+                .iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        DiagnosticValue::String(value) => CachedValue::String(value.to_string()),
+                        DiagnosticValue::Number(value) => CachedValue::Number(*value),
+                    };
+                    (key.to_string(), value)
+                })
+                .collect(),
+            fixes: match &message.fixes {
+                PossibleFixes::None => CachedFixes::None,
+                PossibleFixes::Single(fix) => CachedFixes::Single(CachedFix::from_fix(fix)),
+                PossibleFixes::Multiple(fixes) => {
+                    CachedFixes::Multiple(fixes.iter().map(CachedFix::from_fix).collect())
+                }
+            },
+            span: (message.span.start, message.span.end),
+            section_offset: message.section_offset,
+            origin: message.origin.clone(),
+        }
+    }
+
+    fn into_message(self) -> Message {
+        let mut error = OxcDiagnostic::warn(self.message);
+        if !self.labels.is_empty() {
+            error.labels = Some(
+                self.labels
+                    .into_iter()
+                    .map(|label| {
+                        let span = (label.offset, label.len);
+                        if label.primary {
+                            LabeledSpan::new_primary_with_span(label.label, span)
+                        } else {
+                            LabeledSpan::new_with_span(label.label, span)
+                        }
+                    })
+                    .collect(),
+            );
+        }
+        error.help = self.help.map(Cow::Owned);
+        error.severity = match self.severity {
+            CachedSeverity::Advice => Severity::Advice,
+            CachedSeverity::Warning => Severity::Warning,
+            CachedSeverity::Error => Severity::Error,
+        };
+        error.code =
+            OxcCode { scope: self.scope.map(Cow::Owned), number: self.number.map(Cow::Owned) };
+        error.url = self.url.map(Cow::Owned);
+        error.data = self
+            .data
+            .into_iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    CachedValue::String(value) => DiagnosticValue::String(Cow::Owned(value)),
+                    CachedValue::Number(value) => DiagnosticValue::Number(value),
+                };
+                (Cow::Owned(key), value)
+            })
+            .collect();
+        let fixes = match self.fixes {
+            CachedFixes::None => PossibleFixes::None,
+            CachedFixes::Single(fix) => PossibleFixes::Single(fix.into_fix()),
+            CachedFixes::Multiple(fixes) => {
+                PossibleFixes::Multiple(fixes.into_iter().map(CachedFix::into_fix).collect())
+            }
+        };
+        let mut message = Message::new(error, fixes).with_section_offset(self.section_offset);
+        message.span = Span::new(self.span.0, self.span.1);
+        message.origin = self.origin;
+        message
+    }
+}
+
+impl CachedFix {
+    fn from_fix(fix: &Fix) -> Self {
+        Self {
+            content: fix.content.to_string(),
+            message: fix.message.as_deref().map(str::to_string),
+            span: (fix.span.start, fix.span.end),
+            kind: fix.kind.bits(),
+        }
+    }
+
+    fn into_fix(self) -> Fix {
+        Fix {
+            content: Cow::Owned(self.content),
+            message: self.message.map(Cow::Owned),
+            span: Span::new(self.span.0, self.span.1),
+            kind: FixKind::from_bits_truncate(self.kind),
+        }
+    }
+}
+
+/// Paths of all modules imported by `module_records`, directly or transitively.
+fn dependency_paths(module_records: &[Arc<ModuleRecord>]) -> Vec<PathBuf> {
+    let mut visited = FxHashSet::default();
+    let mut stack = module_records.to_vec();
+    let mut paths = vec![];
+    while let Some(record) = stack.pop() {
+        for dependency in record.loaded_modules().values().filter_map(std::sync::Weak::upgrade) {
+            if visited.insert(dependency.resolved_absolute_path.clone()) {
+                paths.push(dependency.resolved_absolute_path.clone());
+                stack.push(dependency);
+            }
+        }
+    }
+    paths
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        ffi::OsStr,
+        path::{Path, PathBuf},
+        sync::{Arc, Mutex},
+    };
+
+    use rustc_hash::FxHashMap;
+
+    use oxc_allocator::Allocator;
+    use oxc_diagnostics::OxcDiagnostic;
+    use oxc_span::Span;
+
+    use super::{ContentHash, LintCache, RuleResultCache, RuleResultKey};
+    use crate::{Fix, Message, ModuleRecord, PossibleFixes, service::RuntimeFileSystem};
+
+    #[derive(Default)]
+    struct MemoryFileSystem(Mutex<FxHashMap<PathBuf, String>>);
+
+    impl MemoryFileSystem {
+        fn write(&self, path: &str, source_text: &str) {
+            self.0.lock().unwrap().insert(PathBuf::from(path), source_text.to_string());
+        }
+    }
+
+    impl RuntimeFileSystem for MemoryFileSystem {
+        fn read_to_arena_str<'a>(
+            &'a self,
+            path: &Path,
+            allocator: &'a Allocator,
+        ) -> Result<&'a str, std::io::Error> {
+            let files = self.0.lock().unwrap();
+            let source_text = files.get(path).ok_or(std::io::ErrorKind::NotFound)?;
+            Ok(allocator.alloc_str(source_text))
+        }
+
+        fn write_file(&self, _path: &Path, _content: &str) -> Result<(), std::io::Error> {
+            unreachable!()
+        }
+    }
+
+    /// Get the diagnostics of `path`, hashing its current source text.
+    fn get(
+        cache: &LintCache,
+        file_system: &MemoryFileSystem,
+        path: &Arc<OsStr>,
+    ) -> Option<Vec<Message>> {
+        let source_text = file_system.0.lock().unwrap()[Path::new(path)].clone();
+        cache.get(file_system, path, ContentHash::new(&source_text))
+    }
+
+    fn module_record(path: &str) -> Arc<ModuleRecord> {
+        let mut module_record = ModuleRecord::default();
+        module_record.resolved_absolute_path = PathBuf::from(path);
+        Arc::new(module_record)
+    }
+
+    fn messages(message: &str) -> Vec<Message> {
+        vec![Message::new(OxcDiagnostic::warn(message.to_string()), PossibleFixes::None)]
+    }
+
+    #[test]
+    fn reuse_unchanged_files() {
+        let file_system = MemoryFileSystem::default();
+        file_system.write("/a.js", "import './b.js'");
+        file_system.write("/b.js", "import './c.js'");
+        file_system.write("/c.js", "debugger");
+
+        let a = module_record("/a.js");
+        let b = module_record("/b.js");
+        let c = module_record("/c.js");
+        a.write_loaded_modules().insert("./b.js".into(), Arc::downgrade(&b));
+        b.write_loaded_modules().insert("./c.js".into(), Arc::downgrade(&c));
+
+        let cache = LintCache::new(10);
+        let path = Arc::<OsStr>::from(OsStr::new("/a.js"));
+        assert!(get(&cache, &file_system, &path).is_none());
+
+        cache.insert(&file_system, &path, "import './b.js'", &[a], &messages("a"));
+        assert_eq!(get(&cache, &file_system, &path), Some(messages("a")));
+
+        // Transitive dependency changed
+        file_system.write("/c.js", "");
+        assert!(get(&cache, &file_system, &path).is_none());
+        file_system.write("/c.js", "debugger");
+        assert!(get(&cache, &file_system, &path).is_some());
+
+        // File itself changed
+        file_system.write("/a.js", "import './c.js'");
+        assert!(get(&cache, &file_system, &path).is_none());
+    }
+
+    #[test]
+    fn evict_least_recently_used() {
+        let file_system = MemoryFileSystem::default();
+        let paths = ["/a.js", "/b.js", "/c.js"].map(|path| {
+            file_system.write(path, path);
+            Arc::<OsStr>::from(OsStr::new(path))
+        });
+
+        let cache = LintCache::new(2);
+        cache.insert(&file_system, &paths[0], "/a.js", &[], &messages("a"));
+        cache.insert(&file_system, &paths[1], "/b.js", &[], &messages("b"));
+        // `/a.js` is used more recently than `/b.js`
+        assert!(get(&cache, &file_system, &paths[0]).is_some());
+        cache.insert(&file_system, &paths[2], "/c.js", &[], &messages("c"));
+
+        assert!(get(&cache, &file_system, &paths[0]).is_some());
+        assert!(get(&cache, &file_system, &paths[1]).is_none());
+        assert!(get(&cache, &file_system, &paths[2]).is_some());
+    }
+
+    fn rule_result_key(rule_id: usize, config_hash: u64) -> RuleResultKey {
+        RuleResultKey { section: 0, rule_id, config_hash }
+    }
+
+    #[test]
+    fn reuse_rule_results_of_unchanged_files() {
+        let cache = RuleResultCache::new(10);
+        let path = Path::new("/a.js");
+        let content_hash = ContentHash::of_sections(["debugger"]);
+        let keys = [rule_result_key(0, 0), rule_result_key(0, 1), rule_result_key(1, 0)];
+
+        cache.insert(path, content_hash, [(keys[0], messages("a")), (keys[2], vec![])]);
+        // Results of other configurations of the same rule are kept.
+        cache.insert(path, content_hash, [(keys[1], messages("b"))]);
+        let results = cache.get(path, content_hash, keys);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[&keys[0]], messages("a"));
+        assert_eq!(results[&keys[1]], messages("b"));
+        assert!(results[&keys[2]].is_empty());
+
+        // File changed
+        let changed_hash = ContentHash::of_sections(["debugger;"]);
+        assert!(cache.get(path, changed_hash, keys).is_empty());
+        cache.insert(path, changed_hash, [(keys[2], messages("c"))]);
+        assert_eq!(cache.get(path, changed_hash, keys).len(), 1);
+        assert!(cache.get(path, content_hash, keys).is_empty());
+    }
+
+    #[test]
+    fn evict_least_recently_used_rule_results() {
+        let cache = RuleResultCache::new(2);
+        let key = rule_result_key(0, 0);
+        let content_hash = ContentHash::of_sections([""]);
+        let paths = ["/a.js", "/b.js", "/c.js"].map(Path::new);
+
+        cache.insert(paths[0], content_hash, [(key, messages("a"))]);
+        cache.insert(paths[1], content_hash, [(key, messages("b"))]);
+        // `/a.js` is used more recently than `/b.js`
+        assert_eq!(cache.get(paths[0], content_hash, [key]).len(), 1);
+        cache.insert(paths[2], content_hash, [(key, messages("c"))]);
+
+        assert_eq!(cache.get(paths[0], content_hash, [key]).len(), 1);
+        assert!(cache.get(paths[1], content_hash, [key]).is_empty());
+        assert_eq!(cache.get(paths[2], content_hash, [key]).len(), 1);
+    }
+
+    #[test]
+    fn read_written_rule_results() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("cache/.oxlintcache");
+        let path = Path::new("/a.js");
+        let content_hash = ContentHash::of_sections(["debugger"]);
+        let key = rule_result_key(3, 42);
+        let message = Message::new(
+            OxcDiagnostic::error("`debugger` statement is not allowed")
+                .with_help("Remove the debugger statement")
+                .with_label(Span::new(0, 8).label("here"))
+                .with_error_code("eslint", "no-debugger"),
+            PossibleFixes::Single(Fix::delete(Span::new(0, 8)).with_message("Remove")),
+        )
+        .with_section_offset(4);
+
+        let cache = RuleResultCache::new(10);
+        cache.insert(path, content_hash, [(key, vec![message.clone()])]);
+        cache.write(&cache_path).unwrap();
+
+        let cache = RuleResultCache::read(&cache_path, 10);
+        let results = cache.get(path, content_hash, [key]);
+        let read = &results[&key][0];
+        assert_eq!(read, &message);
+        assert_eq!(read.error.help, message.error.help);
+        assert_eq!(read.error.code, message.error.code);
+        assert_eq!(read.section_offset, 4);
+        assert!(cache.get(path, content_hash, [rule_result_key(3, 43)]).is_empty());
+
+        // Caches of other versions and invalid files are ignored.
+        std::fs::write(&cache_path, r#"{"version":"0.0.0","files":[]}"#).unwrap();
+        assert!(RuleResultCache::read(&cache_path, 10).get(path, content_hash, [key]).is_empty());
+        std::fs::write(&cache_path, "{").unwrap();
+        assert!(RuleResultCache::read(&cache_path, 10).get(path, content_hash, [key]).is_empty());
+    }
+}
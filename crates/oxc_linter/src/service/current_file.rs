@@ -0,0 +1,31 @@
+use std::{cell::RefCell, ffi::OsStr, path::PathBuf, sync::Arc};
+
+thread_local! {
+    static CURRENT_FILE: RefCell<Option<Arc<OsStr>>> = const { RefCell::new(None) };
+}
+
+/// The path of the file which the current thread is parsing or linting, if any.
+///
+/// Meant to be called from a panic hook, which runs on the panicking thread, to report the file
+/// which caused a crash.
+pub fn current_file() -> Option<PathBuf> {
+    CURRENT_FILE.with_borrow(|path| path.as_deref().map(PathBuf::from))
+}
+
+/// Marks the current thread as processing a file until dropped, see [`current_file`].
+///
+/// The previous file is restored on drop, because rayon may run another file's job on a thread
+/// while it waits.
+pub(super) struct CurrentFileGuard(Option<Arc<OsStr>>);
+
+impl CurrentFileGuard {
+    pub(super) fn new(path: &Arc<OsStr>) -> Self {
+        Self(CURRENT_FILE.replace(Some(Arc::clone(path))))
+    }
+}
+
+impl Drop for CurrentFileGuard {
+    fn drop(&mut self) {
+        CURRENT_FILE.set(self.0.take());
+    }
+}
@@ -0,0 +1,95 @@
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+
+/// Outcome of linting a file.
+///
+/// Lets wrappers of the linter verify that every file they passed was covered, instead of
+/// inferring it from the absence of diagnostics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FileStatus {
+    /// Rules were run on the whole file.
+    Linted,
+    /// The file matched an ignore file or ignore pattern.
+    SkippedIgnored,
+    /// The file cannot be linted, e.g. it has an unsupported extension, it is a Flow file,
+    /// or it exceeds the file limits.
+    SkippedUnsupported,
+    /// The file, or one of its sections, could not be read or parsed, so rules were not run on it.
+    ParseFailed,
+    /// Rules were run on the whole file, and fixes were written to it.
+    Fixed,
+}
+
+impl FileStatus {
+    /// All statuses, in the order they are displayed in.
+    pub const ALL: [Self; 5] = [
+        Self::Linted,
+        Self::SkippedIgnored,
+        Self::SkippedUnsupported,
+        Self::ParseFailed,
+        Self::Fixed,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Linted => "linted",
+            Self::SkippedIgnored => "skipped-ignored",
+            Self::SkippedUnsupported => "skipped-unsupported",
+            Self::ParseFailed => "parse-failed",
+            Self::Fixed => "fixed",
+        }
+    }
+
+    /// Status of a file with a section which was not linted, because of `diagnostics`.
+    ///
+    /// Syntax errors are always errors, so a section without errors was skipped deliberately,
+    /// e.g. a Flow file (no diagnostic) or a file exceeding the file limits (a warning).
+    pub(super) fn of_unlinted_section(diagnostics: &[OxcDiagnostic]) -> Self {
+        if diagnostics.iter().any(|diagnostic| diagnostic.severity == Severity::Error) {
+            Self::ParseFailed
+        } else {
+            Self::SkippedUnsupported
+        }
+    }
+}
+
+/// Status of each file passed to the linter, keyed by the path of the file.
+pub type FileStatusMap = Arc<Mutex<FxHashMap<PathBuf, FileStatus>>>;
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::OxcDiagnostic;
+
+    use super::FileStatus;
+
+    #[test]
+    fn of_unlinted_section() {
+        assert_eq!(FileStatus::of_unlinted_section(&[]), FileStatus::SkippedUnsupported);
+        assert_eq!(
+            FileStatus::of_unlinted_section(&[OxcDiagnostic::warn("File skipped")]),
+            FileStatus::SkippedUnsupported
+        );
+        assert_eq!(
+            FileStatus::of_unlinted_section(&[
+                OxcDiagnostic::warn("File skipped"),
+                OxcDiagnostic::error("Unexpected token")
+            ]),
+            FileStatus::ParseFailed
+        );
+    }
+
+    #[test]
+    fn serialize() {
+        for status in FileStatus::ALL {
+            assert_eq!(serde_json::to_string(&status).unwrap(), format!("\"{}\"", status.as_str()));
+        }
+    }
+}
@@ -0,0 +1,115 @@
+/// Number of modules to lint which bootstrap a group of the module graph, per thread, until the
+/// size of groups can be estimated from former groups.
+/// This size is empirical based on AFFiNE@97cc814a.
+const DEFAULT_MODULES_PER_THREAD: usize = 4;
+
+/// Bounds of the number of modules to lint per thread in a group.
+const MIN_MODULES_PER_THREAD: usize = 1;
+const MAX_MODULES_PER_THREAD: usize = 32;
+
+/// Bytes held per thread by a group of the default size with modules of typical size, i.e. arenas
+/// of 4 MiB. Groups of smaller modules are made bigger to reduce the overhead of scheduling groups,
+/// and groups of bigger modules or with more dependencies are made smaller to avoid memory spikes.
+const TARGET_BYTES_PER_THREAD: usize = DEFAULT_MODULES_PER_THREAD * 4 * 1024 * 1024;
+
+/// Chooses the size of each group of the module graph, from the average size of the modules to lint
+/// and the number of dependencies they pulled into former groups.
+///
+/// The size is fixed with the `OXLINT_GROUP_SIZE` environment variable.
+pub struct GroupSizer {
+    threads: usize,
+    fixed: Option<usize>,
+    /// Modules to lint, dependencies and bytes of modules to lint in former groups.
+    /// Halved after each group, so that recent groups weigh more.
+    modules_to_lint: usize,
+    dependencies: usize,
+    bytes: usize,
+}
+
+impl GroupSizer {
+    pub fn new(threads: usize, fixed: Option<usize>) -> Self {
+        Self {
+            threads: threads.max(1),
+            fixed: fixed.filter(|size| *size > 0),
+            modules_to_lint: 0,
+            dependencies: 0,
+            bytes: 0,
+        }
+    }
+
+    /// The group size set with `OXLINT_GROUP_SIZE`, if it is a positive integer.
+    pub fn fixed_from_env() -> Option<usize> {
+        std::env::var("OXLINT_GROUP_SIZE").ok()?.trim().parse().ok().filter(|size| *size > 0)
+    }
+
+    /// Number of modules to lint which bootstrap the next group.
+    pub fn next(&self) -> usize {
+        if let Some(fixed) = self.fixed {
+            return fixed;
+        }
+        if self.modules_to_lint == 0 {
+            return self.threads * DEFAULT_MODULES_PER_THREAD;
+        }
+        let bytes_per_module = (self.bytes / self.modules_to_lint).max(1);
+        // Dependencies are parsed along with the modules to lint, which wait until all of them are
+        // processed.
+        let modules_per_module_to_lint = 1 + self.dependencies / self.modules_to_lint;
+        let size = TARGET_BYTES_PER_THREAD / bytes_per_module / modules_per_module_to_lint;
+        self.threads * size.clamp(MIN_MODULES_PER_THREAD, MAX_MODULES_PER_THREAD)
+    }
+
+    /// Record a processed group, with the number of `modules_to_lint` bootstrapping it, the number of
+    /// `dependencies` they pulled in, and the `bytes` held by the modules to lint.
+    pub fn record(&mut self, modules_to_lint: usize, dependencies: usize, bytes: usize) {
+        self.modules_to_lint = self.modules_to_lint / 2 + modules_to_lint;
+        self.dependencies = self.dependencies / 2 + dependencies;
+        self.bytes = self.bytes / 2 + bytes;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GroupSizer;
+
+    const MIB: usize = 1024 * 1024;
+
+    #[test]
+    fn group_size() {
+        let mut sizer = GroupSizer::new(8, None);
+        assert_eq!(sizer.next(), 32);
+
+        // Modules of typical size without dependencies keep the default size.
+        sizer.record(32, 0, 32 * 4 * MIB);
+        assert_eq!(sizer.next(), 32);
+
+        // Tiny modules make groups bigger, up to the maximum.
+        let mut sizer = GroupSizer::new(8, None);
+        sizer.record(32, 0, 32 * 1024);
+        assert_eq!(sizer.next(), 8 * 32);
+
+        // A large fan-out makes groups smaller, down to one module per thread.
+        let mut sizer = GroupSizer::new(8, None);
+        sizer.record(32, 32 * 3, 32 * 4 * MIB);
+        assert_eq!(sizer.next(), 8);
+        sizer.record(32, 32 * 1000, 32 * 4 * MIB);
+        assert_eq!(sizer.next(), 8);
+
+        // Former groups weigh less than recent ones.
+        let mut sizer = GroupSizer::new(8, None);
+        sizer.record(32, 0, 32 * 16 * MIB);
+        assert_eq!(sizer.next(), 8);
+        sizer.record(32, 0, 32 * MIB);
+        assert_eq!(sizer.next(), 16);
+        sizer.record(32, 0, 32 * MIB);
+        assert_eq!(sizer.next(), 40);
+    }
+
+    #[test]
+    fn fixed_group_size() {
+        let mut sizer = GroupSizer::new(8, Some(3));
+        sizer.record(3, 3000, 3 * 1024);
+        assert_eq!(sizer.next(), 3);
+
+        assert_eq!(GroupSizer::new(8, Some(0)).next(), 32);
+    }
+}
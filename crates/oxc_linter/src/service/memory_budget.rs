@@ -0,0 +1,127 @@
+use std::sync::{
+    Condvar, Mutex,
+    atomic::{AtomicUsize, Ordering},
+};
+
+/// Bounds the memory held by parsed modules which wait to be linted, see
+/// [`LintServiceOptions::with_max_memory`](super::LintServiceOptions::with_max_memory).
+///
+/// Each parsed module reserves the capacity of its arena until it is linted. Files are not parsed
+/// while the reserved memory exceeds the budget, but wait for other modules to be linted.
+/// When the module graph is built, modules are parsed in groups, which are made smaller while
+/// memory is reserved.
+pub struct MemoryBudget {
+    limit: usize,
+    /// Bytes reserved by modules which are not linted yet
+    reserved: Mutex<usize>,
+    /// Notified when a reservation is released
+    released: Condvar,
+    /// Number and total bytes of all reservations so far, to estimate the size of further modules
+    reservation_count: AtomicUsize,
+    reservation_bytes: AtomicUsize,
+}
+
+/// Memory reserved by a module until it is linted, released on drop.
+pub struct MemoryReservation<'a> {
+    budget: &'a MemoryBudget,
+    bytes: usize,
+}
+
+impl MemoryBudget {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            reserved: Mutex::new(0),
+            released: Condvar::new(),
+            reservation_count: AtomicUsize::new(0),
+            reservation_bytes: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn is_exceeded(&self) -> bool {
+        *self.reserved.lock().unwrap() > self.limit
+    }
+
+    /// Block the current thread until the reserved memory is within the budget.
+    ///
+    /// Must not be called by a thread which holds a reservation itself, or by the only thread
+    /// which can release them.
+    pub fn wait(&self) {
+        let reserved = self.reserved.lock().unwrap();
+        drop(self.released.wait_while(reserved, |reserved| *reserved > self.limit).unwrap());
+    }
+
+    /// Number of modules, at most `max`, which are expected to fit into the remaining budget.
+    ///
+    /// At least 1 so that linting always makes progress, and `max` until the size of modules can
+    /// be estimated from former reservations.
+    pub fn modules_that_fit(&self, max: usize) -> usize {
+        let count = self.reservation_count.load(Ordering::Acquire);
+        if count == 0 {
+            return max;
+        }
+        let average = (self.reservation_bytes.load(Ordering::Acquire) / count).max(1);
+        let remaining = self.limit.saturating_sub(*self.reserved.lock().unwrap());
+        (remaining / average).clamp(1, max.max(1))
+    }
+
+    pub fn reserve(&self, bytes: usize) -> MemoryReservation<'_> {
+        *self.reserved.lock().unwrap() += bytes;
+        self.reservation_count.fetch_add(1, Ordering::AcqRel);
+        self.reservation_bytes.fetch_add(bytes, Ordering::AcqRel);
+        MemoryReservation { budget: self, bytes }
+    }
+}
+
+impl Drop for MemoryReservation<'_> {
+    fn drop(&mut self) {
+        *self.budget.reserved.lock().unwrap() -= self.bytes;
+        self.budget.released.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{sync::Arc, thread};
+
+    use super::MemoryBudget;
+
+    #[test]
+    fn memory_budget() {
+        let budget = MemoryBudget::new(1000);
+        assert_eq!(budget.modules_that_fit(16), 16);
+
+        let first = budget.reserve(300);
+        let second = budget.reserve(100);
+        assert!(!budget.is_exceeded());
+        // 600 bytes remaining, 200 bytes per module on average
+        assert_eq!(budget.modules_that_fit(16), 3);
+        assert_eq!(budget.modules_that_fit(2), 2);
+
+        let third = budget.reserve(700);
+        assert!(budget.is_exceeded());
+        assert_eq!(budget.modules_that_fit(16), 1);
+
+        drop(third);
+        drop(first);
+        assert!(!budget.is_exceeded());
+        drop(second);
+        // 1000 bytes remaining, 366 bytes per module on average
+        assert_eq!(budget.modules_that_fit(16), 2);
+    }
+
+    #[test]
+    fn wait_for_release() {
+        let budget = Arc::new(MemoryBudget::new(100));
+        let reservation = budget.reserve(200);
+        assert!(budget.is_exceeded());
+
+        let waiter = thread::spawn({
+            let budget = Arc::clone(&budget);
+            move || budget.wait()
+        });
+        drop(reservation);
+        waiter.join().unwrap();
+        assert!(!budget.is_exceeded());
+    }
+}
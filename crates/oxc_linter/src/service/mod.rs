@@ -2,25 +2,76 @@ use std::{
     ffi::OsStr,
     path::{Path, PathBuf},
     sync::{Arc, Mutex},
+    time::Duration,
 };
 
 use rustc_hash::FxHashMap;
 
+use oxc_allocator::AllocatorPoolStats;
 use oxc_diagnostics::DiagnosticSender;
 
-use crate::Linter;
+use crate::{Linter, Message};
 
+mod cache;
+mod current_file;
+mod file_status;
+mod group_size;
+mod memory_budget;
+mod prefetch;
+mod resolver;
 mod runtime;
+pub use cache::{ContentHash, RuleResultCache, RuleResultKey};
+pub use current_file::current_file;
+pub use file_status::{FileStatus, FileStatusMap};
 use runtime::Runtime;
-pub use runtime::{OsFileSystem, RuntimeFileSystem};
+pub use runtime::{OsFileSystem, PARSE_ERROR_RULE_NAME, RuntimeFileSystem};
 #[derive(Clone)]
 pub struct LintServiceOptions {
     /// Current working directory
     cwd: Box<Path>,
     /// TypeScript `tsconfig.json` path for reading path alias and project references
     tsconfig: Option<PathBuf>,
+    /// Find the nearest `tsconfig.json` of each file instead of using `tsconfig`
+    tsconfig_discovery: bool,
+    /// Conditions of `exports` and `imports` fields of `package.json` to resolve imports with
+    resolver_conditions: Option<Vec<String>>,
+    /// Fields of `package.json` pointing to the entry point of a package to resolve imports with
+    resolver_main_fields: Option<Vec<String>>,
 
     cross_module: bool,
+
+    /// Maximum number of files whose diagnostics are cached, `0` to disable the cache
+    lint_cache_capacity: usize,
+
+    file_limits: FileLimits,
+
+    /// Maximum number of bytes held by files waiting to be linted
+    max_memory: Option<usize>,
+
+    /// Read files on dedicated I/O threads ahead of parsing them
+    prefetch: bool,
+
+    /// Number of modules to lint bootstrapping each group of the module graph, instead of adapting it
+    group_size: Option<usize>,
+}
+
+/// Limits on the size of a file and the time spent linting it.
+///
+/// Files exceeding a limit are skipped and reported with a single diagnostic, so that pathological
+/// files such as accidentally included minified bundles do not blow the stack or hang the run.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FileLimits {
+    /// Maximum size of the source text in bytes, checked before parsing
+    pub size: Option<usize>,
+    /// Maximum number of lines of the source text, checked before parsing
+    pub lines: Option<usize>,
+    /// Maximum number of AST nodes
+    pub nodes: Option<usize>,
+    /// Maximum nesting depth of AST nodes
+    pub depth: Option<usize>,
+    /// Maximum time spent running rules on the file. Rules are stopped cooperatively, so a single
+    /// rule which is already running is not interrupted.
+    pub lint_time: Option<Duration>,
 }
 
 impl LintServiceOptions {
@@ -29,7 +80,19 @@ impl LintServiceOptions {
     where
         T: Into<Box<Path>>,
     {
-        Self { cwd: cwd.into(), tsconfig: None, cross_module: false }
+        Self {
+            cwd: cwd.into(),
+            tsconfig: None,
+            tsconfig_discovery: false,
+            resolver_conditions: None,
+            resolver_main_fields: None,
+            cross_module: false,
+            lint_cache_capacity: 0,
+            file_limits: FileLimits::default(),
+            max_memory: None,
+            prefetch: false,
+            group_size: None,
+        }
     }
 
     #[inline]
@@ -47,6 +110,44 @@ impl LintServiceOptions {
         self
     }
 
+    /// Resolve imports of each file with the nearest `tsconfig.json` found by walking up from the
+    /// file, including its project references, instead of a single `tsconfig.json`.
+    #[inline]
+    #[must_use]
+    pub fn with_tsconfig_discovery(mut self, yes: bool) -> Self {
+        self.tsconfig_discovery = yes;
+        self
+    }
+
+    /// Resolve imports with `conditions` matched against the `exports` and `imports` fields of
+    /// `package.json`, e.g. `["development", "browser", "import"]`.
+    ///
+    /// Defaults to `settings["import/resolver"].conditionNames` of the configuration of each file, or
+    /// `["module", "import"]`.
+    #[inline]
+    #[must_use]
+    pub fn with_resolver_conditions(mut self, conditions: Vec<String>) -> Self {
+        self.resolver_conditions = Some(conditions);
+        self
+    }
+
+    /// Resolve packages without an `exports` field to the entry point in the first of
+    /// `main_fields` found in their `package.json`, e.g. `["browser", "module", "main"]`.
+    ///
+    /// Defaults to `settings["import/resolver"].mainFields` of the configuration of each file, or
+    /// `["module", "main"]`.
+    #[inline]
+    #[must_use]
+    pub fn with_resolver_main_fields(mut self, main_fields: Vec<String>) -> Self {
+        self.resolver_main_fields = Some(main_fields);
+        self
+    }
+
+    /// Build the module graph of the linted files, for rules of the import plugin.
+    ///
+    /// Files are parsed in groups whose size adapts to the size of the files and the number of
+    /// their dependencies. The `OXLINT_GROUP_SIZE` environment variable sets a fixed number of
+    /// files to lint per group instead.
     #[inline]
     #[must_use]
     pub fn with_cross_module(mut self, cross_module: bool) -> Self {
@@ -54,6 +155,62 @@ impl LintServiceOptions {
         self
     }
 
+    /// Cache the diagnostics of up to `capacity` files linted with
+    /// [`LintService::run_source`], and reuse them while neither the files nor the modules they
+    /// import change. Meant for long running processes like the language server.
+    #[inline]
+    #[must_use]
+    pub fn with_lint_cache(mut self, capacity: usize) -> Self {
+        self.lint_cache_capacity = capacity;
+        self
+    }
+
+    /// Skip files which exceed `file_limits`, see [`FileLimits`].
+    #[inline]
+    #[must_use]
+    pub fn with_file_limits(mut self, file_limits: FileLimits) -> Self {
+        self.file_limits = file_limits;
+        self
+    }
+
+    /// Keep the memory used by parsed files waiting to be linted below about `bytes`.
+    ///
+    /// Files are not parsed while the budget is exceeded, but wait for parsed files to be linted.
+    /// With [`with_cross_module`](Self::with_cross_module), files are parsed in groups and kept in
+    /// memory until their imports are resolved. Groups get smaller, down to one file at a time, as
+    /// the budget fills up. Allocators larger than their share of the budget are freed after use
+    /// instead of being reused.
+    #[inline]
+    #[must_use]
+    pub fn with_max_memory(mut self, bytes: usize) -> Self {
+        self.max_memory = Some(bytes);
+        self
+    }
+
+    /// Read files on dedicated I/O threads, one per CPU thread, ahead of the threads parsing them,
+    /// so that parsing does not block on reads from a cold disk cache.
+    ///
+    /// Only applies to [`LintService::run`] with a file system which
+    /// [supports it](RuntimeFileSystem::supports_prefetch), like [`OsFileSystem`].
+    #[inline]
+    #[must_use]
+    pub fn with_prefetch(mut self, yes: bool) -> Self {
+        self.prefetch = yes;
+        self
+    }
+
+    /// Build the module graph in groups bootstrapped by `group_size` modules to lint, instead of
+    /// adapting the size of groups to the size of modules and the number of their dependencies.
+    ///
+    /// Takes precedence over the `OXLINT_GROUP_SIZE` environment variable. `None` or `0` adapts
+    /// the size of groups, unless `OXLINT_GROUP_SIZE` is set.
+    #[inline]
+    #[must_use]
+    pub fn with_group_size(mut self, group_size: Option<usize>) -> Self {
+        self.group_size = group_size.filter(|size| *size > 0);
+        self
+    }
+
     #[inline]
     pub fn cwd(&self) -> &Path {
         &self.cwd
@@ -64,6 +221,16 @@ pub struct LintService {
     runtime: Runtime,
 }
 
+/// The messages of one linted file, see [`LintService::run_with_callback`].
+#[derive(Debug)]
+pub struct FileDiagnostics {
+    /// Path of the linted file
+    pub path: PathBuf,
+    /// Diagnostics with their fixes. Spans are byte offsets into the source text of the whole file,
+    /// including for files with multiple script sections (e.g. `.vue` files).
+    pub messages: Vec<Message>,
+}
+
 impl LintService {
     pub fn new(linter: Linter, options: LintServiceOptions) -> Self {
         let runtime = Runtime::new(linter, options);
@@ -80,6 +247,11 @@ impl LintService {
         self.runtime.run(file_system, paths, tx_error);
     }
 
+    /// Statistics of the pool of allocators used to parse files, see `AllocatorPool::stats`.
+    pub fn allocator_pool_stats(&self) -> AllocatorPoolStats {
+        self.runtime.allocator_pool.stats()
+    }
+
     pub fn set_disable_directives_map(
         &mut self,
         map: Arc<Mutex<FxHashMap<PathBuf, crate::disable_directives::DisableDirectives>>>,
@@ -87,12 +259,70 @@ impl LintService {
         self.runtime.set_disable_directives_map(map);
     }
 
+    /// Collect the frameworks and source types detected for each linted file.
+    /// See [`Linter::file_metadata`](crate::Linter::file_metadata).
+    pub fn set_file_metadata_map(&mut self, map: crate::FileMetadataMap) {
+        self.runtime.set_file_metadata_map(map);
+    }
+
+    /// Collect the fixes applied to each file written by `--fix`.
+    pub fn set_fixed_files_map(&mut self, map: crate::FixedFilesMap) {
+        self.runtime.set_fixed_files_map(map);
+    }
+
+    /// Collect the status of each file passed to the linter.
+    /// See [`FileStatus`].
+    pub fn set_file_status_map(&mut self, map: FileStatusMap) {
+        self.runtime.set_file_status_map(map);
+    }
+
+    /// Collect the origins of the rules reporting diagnostics in each file.
+    /// See [`RuleOrigin`](crate::RuleOrigin).
+    pub fn set_rule_origins_map(&mut self, map: crate::RuleOriginsMap) {
+        self.runtime.set_rule_origins_map(map);
+    }
+
+    /// Share type information computed by `tsgolint` with native rules.
+    /// See [`LintContext::type_fact`](crate::LintContext::type_fact).
+    pub fn set_type_facts_map(&mut self, map: crate::TypeFactsMap) {
+        self.runtime.set_type_facts_map(map);
+    }
+
+    /// Do not attach the source text to diagnostics, as none are displayed.
+    /// They are still sent to the [`DiagnosticSender`], to be counted.
+    pub fn set_silent(&mut self, yes: bool) {
+        self.runtime.set_silent(yes);
+    }
+
+    /// Do not attach the source text to warnings, as they are not displayed.
+    /// They are still sent to the [`DiagnosticSender`], to be counted.
+    pub fn set_quiet(&mut self, yes: bool) {
+        self.runtime.set_quiet(yes);
+    }
+
+    /// Lint `paths` and return the messages, instead of sending them to a [`DiagnosticSender`].
+    /// Stops early, returning partial results, once `cancellation_token` is cancelled.
     pub fn run_source(
         &self,
         file_system: &(dyn RuntimeFileSystem + Sync + Send),
         paths: Vec<Arc<OsStr>>,
+        cancellation_token: &crate::CancellationToken,
     ) -> Vec<crate::Message> {
-        self.runtime.run_source(file_system, paths)
+        self.runtime.run_source(file_system, paths, cancellation_token)
+    }
+
+    /// Lint `paths` and call `callback` with the [`FileDiagnostics`] of each file as soon as it has
+    /// been linted, instead of sending [`oxc_diagnostics::Error`]s to a [`DiagnosticSender`].
+    /// Stops early once `cancellation_token` is cancelled, files which were not completely linted
+    /// are not passed to `callback`.
+    pub fn run_with_callback(
+        &self,
+        file_system: &(dyn RuntimeFileSystem + Sync + Send),
+        paths: Vec<Arc<OsStr>>,
+        cancellation_token: &crate::CancellationToken,
+        callback: impl FnMut(FileDiagnostics) + Send,
+    ) {
+        self.runtime.run_with_callback(file_system, paths, cancellation_token, callback);
     }
 
     /// For tests
@@ -107,3 +337,164 @@ impl LintService {
         self.runtime.run_test_source(file_system, paths, check_syntax_errors, tx_error)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{
+        ffi::OsStr,
+        path::Path,
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+    };
+
+    use rustc_hash::FxHashMap;
+
+    use oxc_allocator::Allocator;
+    use oxc_span::Span;
+
+    use super::{FileDiagnostics, FileLimits, LintService, LintServiceOptions, RuntimeFileSystem};
+    use crate::{
+        AllowWarnDeny, CancellationToken, ConfigStore, ConfigStoreBuilder, ExternalPluginStore,
+        FixKind, LintOptions, Linter, rules::RULES, tester::TesterFileSystem,
+    };
+
+    #[test]
+    fn run_with_callback() {
+        let external_plugin_store = ExternalPluginStore::default();
+        let no_debugger = RULES.iter().find(|rule| rule.name() == "no-debugger").unwrap();
+        let config = ConfigStoreBuilder::empty()
+            .with_rule(no_debugger, AllowWarnDeny::Deny, None)
+            .build(&external_plugin_store)
+            .unwrap();
+        let linter = Linter::new(
+            LintOptions { fix: FixKind::All, ..LintOptions::default() },
+            ConfigStore::new(config, FxHashMap::default(), external_plugin_store),
+            None,
+        );
+        let cwd = std::env::current_dir().unwrap();
+        let path = cwd.join("stream.vue");
+        let service = LintService::new(linter, LintServiceOptions::new(cwd));
+        let source_text = "<template></template>\n<script>\ndebugger;\n</script>\n";
+        let file_system = TesterFileSystem::new(path.clone(), source_text.to_string());
+        let paths = vec![Arc::<OsStr>::from(path.as_os_str())];
+
+        let mut files = Vec::<FileDiagnostics>::new();
+        service.run_with_callback(&file_system, paths.clone(), &CancellationToken::new(), |file| {
+            files.push(file);
+        });
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path, path);
+        assert_eq!(files[0].messages.len(), 1);
+        // spans refer to the whole file, not to the script section
+        let start = u32::try_from(source_text.find("debugger").unwrap()).unwrap();
+        assert_eq!(files[0].messages[0].span, Span::sized(start, 9));
+        assert!(!files[0].messages[0].fixes.is_empty());
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let mut called = false;
+        service.run_with_callback(&file_system, paths, &token, |_| called = true);
+        assert!(!called);
+    }
+
+    /// Counts the files read by the linter.
+    struct CountingFileSystem {
+        file_system: TesterFileSystem,
+        reads: AtomicUsize,
+    }
+
+    impl RuntimeFileSystem for CountingFileSystem {
+        fn read_to_arena_str<'a>(
+            &'a self,
+            path: &Path,
+            allocator: &'a Allocator,
+        ) -> Result<&'a str, std::io::Error> {
+            self.reads.fetch_add(1, Ordering::Relaxed);
+            self.file_system.read_to_arena_str(path, allocator)
+        }
+
+        fn write_file(&self, path: &Path, content: &str) -> Result<(), std::io::Error> {
+            self.file_system.write_file(path, content)
+        }
+    }
+
+    #[test]
+    fn lint_cache() {
+        let external_plugin_store = ExternalPluginStore::default();
+        let no_debugger = RULES.iter().find(|rule| rule.name() == "no-debugger").unwrap();
+        let config = ConfigStoreBuilder::empty()
+            .with_rule(no_debugger, AllowWarnDeny::Deny, None)
+            .build(&external_plugin_store)
+            .unwrap();
+        let linter = Linter::new(
+            LintOptions::default(),
+            ConfigStore::new(config, FxHashMap::default(), external_plugin_store),
+            None,
+        );
+        let cwd = std::env::current_dir().unwrap();
+        let path = cwd.join("cached.js");
+        let service = LintService::new(linter, LintServiceOptions::new(cwd).with_lint_cache(10));
+        let lint = |source_text: &str| {
+            let file_system = CountingFileSystem {
+                file_system: TesterFileSystem::new(path.clone(), source_text.to_string()),
+                reads: AtomicUsize::new(0),
+            };
+            let paths = vec![Arc::<OsStr>::from(path.as_os_str())];
+            let messages = service.run_source(&file_system, paths, &CancellationToken::new());
+            (messages.len(), file_system.reads.into_inner())
+        };
+
+        assert_eq!(lint("debugger;"), (1, 1));
+        // Taken from the cache, the file is only read once to check that it is unchanged
+        assert_eq!(lint("debugger;"), (1, 1));
+        assert_eq!(lint("debugger; debugger;"), (2, 1));
+    }
+
+    #[test]
+    fn file_depth_limit_before_parsing() {
+        let external_plugin_store = ExternalPluginStore::default();
+        let no_debugger = RULES.iter().find(|rule| rule.name() == "no-debugger").unwrap();
+        let config = ConfigStoreBuilder::empty()
+            .with_rule(no_debugger, AllowWarnDeny::Deny, None)
+            .build(&external_plugin_store)
+            .unwrap();
+        let linter = Linter::new(
+            LintOptions::default(),
+            ConfigStore::new(config, FxHashMap::default(), external_plugin_store),
+            None,
+        );
+        let cwd = std::env::current_dir().unwrap();
+        let path = cwd.join("nested.js");
+        let file_limits = FileLimits { depth: Some(100), ..FileLimits::default() };
+        let service =
+            LintService::new(linter, LintServiceOptions::new(cwd).with_file_limits(file_limits));
+        let lint = |source_text: String| {
+            let file_system = TesterFileSystem::new(path.clone(), source_text);
+            let paths = vec![Arc::<OsStr>::from(path.as_os_str())];
+            service
+                .run_source(&file_system, paths, &CancellationToken::new())
+                .into_iter()
+                .map(|message| message.error.message.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        // Nested deep enough to overflow the stack of the parser, so it must not be parsed at all
+        let depth = 100_000;
+        let messages = lint(format!("{}1{};\ndebugger;", "[".repeat(depth), "]".repeat(depth)));
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].ends_with("skipped: its AST is nested deeper than the limit of 100."));
+
+        let messages = lint(format!("x = ({}0{});\ndebugger;", "(".repeat(101), ")".repeat(101)));
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].contains("skipped"));
+
+        // Brackets in strings, template strings and comments are not counted
+        let brackets = "[".repeat(200);
+        let source_text = format!(
+            "let a = '{brackets}', b = \"{brackets}\", c = `{brackets}${{[[1]]}}{brackets}`;\n// {brackets}\n/* {brackets} */\ndebugger;"
+        );
+        assert_eq!(lint(source_text), vec!["`debugger` statement is not allowed".to_string()]);
+    }
+}
@@ -0,0 +1,234 @@
+use std::{
+    ffi::OsStr,
+    fs::File,
+    io::{self, Read},
+    path::Path,
+    sync::{Arc, Condvar, Mutex, MutexGuard},
+    thread,
+};
+
+use indexmap::IndexSet;
+use rustc_hash::{FxBuildHasher, FxHashMap};
+
+use oxc_allocator::Allocator;
+
+use super::RuntimeFileSystem;
+
+/// Number of files read ahead of the files taken by parsing threads, per I/O thread.
+const READ_AHEAD_PER_THREAD: usize = 16;
+
+/// Reads files on dedicated I/O threads ahead of the parsing threads, so that parsing threads do
+/// not block on cold reads, see `LintServiceOptions::with_prefetch`.
+///
+/// Files are read in the order they are expected to be parsed, at most a window of files ahead of
+/// the files already taken. Their bytes are read into buffers which are reused once they have been
+/// copied into the allocator of the parsing thread.
+pub(super) struct Prefetcher {
+    /// Paths in the order they are expected to be parsed
+    paths: IndexSet<Arc<OsStr>, FxBuildHasher>,
+    /// Maximum number of files read but not yet taken
+    window: usize,
+    state: Mutex<State>,
+    /// Notified when a file has been read, a file has been taken, or prefetching has finished
+    changed: Condvar,
+}
+
+#[derive(Default)]
+struct State {
+    /// Index into `paths` of the next file to read
+    next: usize,
+    /// Number of files of `paths` taken by parsing threads
+    taken: usize,
+    /// Files which are being read, are read, or were taken
+    files: FxHashMap<Arc<OsStr>, Entry>,
+    /// Buffers to read files into
+    buffers: Vec<Vec<u8>>,
+    /// Set once parsing has finished, to stop the I/O threads
+    done: bool,
+}
+
+enum Entry {
+    Reading,
+    Read(io::Result<Vec<u8>>),
+    Taken,
+}
+
+impl Prefetcher {
+    pub fn new(paths: Vec<Arc<OsStr>>, io_threads: usize) -> Self {
+        Self {
+            paths: paths.into_iter().collect(),
+            window: io_threads * READ_AHEAD_PER_THREAD,
+            state: Mutex::default(),
+            changed: Condvar::new(),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().expect("prefetcher mutex poisoned")
+    }
+
+    /// Run `f` while `io_threads` threads read files ahead of it.
+    pub fn run<R>(&self, io_threads: usize, f: impl FnOnce() -> R) -> R {
+        thread::scope(|scope| {
+            for _ in 0..io_threads {
+                scope.spawn(|| self.read_ahead());
+            }
+            // Stop the I/O threads even if `f` panics, or the scope would never be left.
+            let _finish = Finish(self);
+            f()
+        })
+    }
+
+    fn read_ahead(&self) {
+        let mut state = self.lock();
+        loop {
+            if state.done || state.next == self.paths.len() {
+                return;
+            }
+            if state.next >= state.taken + self.window {
+                state = self.changed.wait(state).expect("prefetcher mutex poisoned");
+                continue;
+            }
+            let path = &self.paths[state.next];
+            state.next += 1;
+            // Taken by a parsing thread before it was read
+            if state.files.contains_key(path) {
+                continue;
+            }
+            state.files.insert(Arc::clone(path), Entry::Reading);
+            let buffer = state.buffers.pop().unwrap_or_default();
+            drop(state);
+
+            let result = read_to_buffer(Path::new(path), buffer);
+
+            state = self.lock();
+            state.files.insert(Arc::clone(path), Entry::Read(result));
+            self.changed.notify_all();
+        }
+    }
+
+    /// Take the bytes of `path`, waiting for them if the file is being read.
+    ///
+    /// Returns `None` if `path` is not prefetched, or it was not read yet, in which case it will not
+    /// be read anymore and must be read by the caller.
+    fn take(&self, path: &Path) -> Option<io::Result<Vec<u8>>> {
+        let path = self.paths.get(path.as_os_str())?;
+        let mut state = self.lock();
+        loop {
+            match state.files.get(path) {
+                Some(Entry::Reading) => {
+                    state = self.changed.wait(state).expect("prefetcher mutex poisoned");
+                }
+                Some(Entry::Taken) => return None,
+                Some(Entry::Read(_)) | None => break,
+            }
+        }
+        let file = state.files.insert(Arc::clone(path), Entry::Taken);
+        state.taken += 1;
+        self.changed.notify_all();
+        match file {
+            Some(Entry::Read(result)) => Some(result),
+            _ => None,
+        }
+    }
+
+    /// Return a buffer taken with [`Prefetcher::take`] to be reused.
+    fn recycle(&self, buffer: Vec<u8>) {
+        let mut state = self.lock();
+        if state.buffers.len() < self.window {
+            state.buffers.push(buffer);
+        }
+    }
+}
+
+struct Finish<'a>(&'a Prefetcher);
+
+impl Drop for Finish<'_> {
+    fn drop(&mut self) {
+        self.0.lock().done = true;
+        self.0.changed.notify_all();
+    }
+}
+
+fn read_to_buffer(path: &Path, mut buffer: Vec<u8>) -> io::Result<Vec<u8>> {
+    buffer.clear();
+    File::open(path)?.read_to_end(&mut buffer)?;
+    Ok(buffer)
+}
+
+/// Reads files from a [`Prefetcher`], and falls back to `inner` for files which were not prefetched.
+pub(super) struct PrefetchFileSystem<'a> {
+    inner: &'a (dyn RuntimeFileSystem + Sync + Send),
+    prefetcher: &'a Prefetcher,
+}
+
+impl<'a> PrefetchFileSystem<'a> {
+    pub fn new(
+        inner: &'a (dyn RuntimeFileSystem + Sync + Send),
+        prefetcher: &'a Prefetcher,
+    ) -> Self {
+        Self { inner, prefetcher }
+    }
+}
+
+impl RuntimeFileSystem for PrefetchFileSystem<'_> {
+    fn read_to_arena_str<'a>(
+        &'a self,
+        path: &Path,
+        allocator: &'a Allocator,
+    ) -> Result<&'a str, io::Error> {
+        let Some(result) = self.prefetcher.take(path) else {
+            return self.inner.read_to_arena_str(path, allocator);
+        };
+        let buffer = result?;
+        let source_text = simdutf8::basic::from_utf8(&buffer).map(|text| allocator.alloc_str(text));
+        self.prefetcher.recycle(buffer);
+        source_text.map_err(|_| {
+            io::Error::new(io::ErrorKind::InvalidData, "stream did not contain valid UTF-8")
+        })
+    }
+
+    fn write_file(&self, path: &Path, content: &str) -> Result<(), io::Error> {
+        self.inner.write_file(path, content)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, ffi::OsStr, sync::Arc};
+
+    use oxc_allocator::Allocator;
+
+    use super::{PrefetchFileSystem, Prefetcher};
+    use crate::service::{OsFileSystem, RuntimeFileSystem};
+
+    #[test]
+    fn prefetch_files() {
+        let fixtures = env::current_dir().unwrap().join("fixtures/project_files");
+        let paths = ["tsconfig.json", "package.json", "missing.json"]
+            .map(|name| Arc::<OsStr>::from(fixtures.join(name).into_os_string()));
+        // A window of 1 file
+        let mut prefetcher = Prefetcher::new(paths.to_vec(), 1);
+        prefetcher.window = 1;
+        let file_system = PrefetchFileSystem::new(&OsFileSystem, &prefetcher);
+        let allocator = Allocator::default();
+
+        prefetcher.run(2, || {
+            // read out of order
+            let package_json =
+                file_system.read_to_arena_str(fixtures.join("package.json").as_ref(), &allocator);
+            assert!(package_json.unwrap().contains("\"root\""));
+            let tsconfig =
+                file_system.read_to_arena_str(fixtures.join("tsconfig.json").as_ref(), &allocator);
+            assert!(tsconfig.unwrap().contains("compilerOptions"));
+            let missing =
+                file_system.read_to_arena_str(fixtures.join("missing.json").as_ref(), &allocator);
+            assert!(missing.is_err());
+            // not prefetched
+            let app = file_system
+                .read_to_arena_str(fixtures.join("packages/app/package.json").as_ref(), &allocator);
+            assert!(app.unwrap().contains("\"app\""));
+        });
+        assert_eq!(prefetcher.lock().taken, 3);
+    }
+}
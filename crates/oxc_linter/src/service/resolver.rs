@@ -0,0 +1,206 @@
+use std::{
+    hash::BuildHasherDefault,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use rustc_hash::FxHasher;
+
+use oxc_resolver::{
+    Resolution, ResolveError, ResolveOptions, Resolver, TsconfigDiscovery, TsconfigOptions,
+    TsconfigReferences,
+};
+use oxc_span::VALID_EXTENSIONS;
+
+use crate::config::OxlintSettings;
+
+type PathMap<V> = papaya::HashMap<Arc<Path>, V, BuildHasherDefault<FxHasher>>;
+
+/// Options of the resolver which depend on the bundler of the project.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ResolverOptions {
+    /// Conditions of `exports` and `imports` fields of `package.json`
+    pub condition_names: Vec<String>,
+    /// Fields of `package.json` pointing to the entry point of a package
+    pub main_fields: Vec<String>,
+}
+
+impl Default for ResolverOptions {
+    fn default() -> Self {
+        Self {
+            condition_names: vec!["module".into(), "import".into()],
+            main_fields: vec!["module".into(), "main".into()],
+        }
+    }
+}
+
+/// Resolves the import specifiers of modules for cross-module rules, with the
+/// `settings["import/resolver"]` of the configuration of each module.
+pub struct ModuleResolvers {
+    /// Conditions which take precedence over the settings of all configurations.
+    condition_names: Option<Vec<String>>,
+    /// Main fields which take precedence over the settings of all configurations.
+    main_fields: Option<Vec<String>>,
+    tsconfig: Option<PathBuf>,
+    tsconfig_discovery: bool,
+    /// One resolver per distinct [`ResolverOptions`], created when the first module with these
+    /// options is resolved, so runs which resolve no imports don't pay for reading
+    /// `tsconfig.json` files.
+    resolvers: papaya::HashMap<ResolverOptions, ModuleResolver, BuildHasherDefault<FxHasher>>,
+}
+
+impl ModuleResolvers {
+    pub fn new(
+        condition_names: Option<Vec<String>>,
+        main_fields: Option<Vec<String>>,
+        tsconfig: Option<PathBuf>,
+        tsconfig_discovery: bool,
+    ) -> Self {
+        Self {
+            condition_names,
+            main_fields,
+            tsconfig,
+            tsconfig_discovery,
+            resolvers: papaya::HashMap::default(),
+        }
+    }
+
+    /// Call `f` with the resolver for modules whose configuration has `settings`.
+    pub fn with_resolver<R>(
+        &self,
+        settings: &OxlintSettings,
+        f: impl FnOnce(&ModuleResolver) -> R,
+    ) -> R {
+        let settings = &settings.import_resolver;
+        let default_options = ResolverOptions::default();
+        let options = ResolverOptions {
+            condition_names: self
+                .condition_names
+                .as_ref()
+                .or(settings.condition_names.as_ref())
+                .cloned()
+                .unwrap_or(default_options.condition_names),
+            main_fields: self
+                .main_fields
+                .as_ref()
+                .or(settings.main_fields.as_ref())
+                .cloned()
+                .unwrap_or(default_options.main_fields),
+        };
+        let resolvers = self.resolvers.pin();
+        let resolver = resolvers.get_or_insert_with(options.clone(), || {
+            if self.tsconfig_discovery {
+                ModuleResolver::discovery(options)
+            } else {
+                ModuleResolver::new(self.tsconfig.clone(), &options)
+            }
+        });
+        f(resolver)
+    }
+}
+
+/// Resolves the import specifiers of modules with a single [`ResolverOptions`].
+pub struct ModuleResolver {
+    /// Resolver for all modules, or for modules without a `tsconfig.json` in any of their ancestor
+    /// directories if `tsconfig.json` discovery is enabled.
+    default: Resolver,
+    /// Resolvers of discovered `tsconfig.json` files, `None` if discovery is disabled.
+    discovery: Option<TsconfigDiscoveryResolvers>,
+}
+
+impl ModuleResolver {
+    /// Create a resolver reading path aliases from `tsconfig_path`, if it exists.
+    pub fn new(tsconfig_path: Option<PathBuf>, options: &ResolverOptions) -> Self {
+        Self { default: new_resolver(tsconfig_path, options), discovery: None }
+    }
+
+    /// Create a resolver which finds the nearest `tsconfig.json` of each module, and resolves with
+    /// one resolver per `tsconfig.json`.
+    pub fn discovery(options: ResolverOptions) -> Self {
+        Self {
+            default: new_resolver(None, &options),
+            discovery: Some(TsconfigDiscoveryResolvers {
+                options,
+                nearest_tsconfigs: PathMap::default(),
+                resolvers: PathMap::default(),
+            }),
+        }
+    }
+
+    /// Resolve `specifier` imported by a module in `dir`.
+    pub fn resolve(&self, dir: &Path, specifier: &str) -> Result<Resolution, ResolveError> {
+        match &self.discovery {
+            Some(discovery) => discovery.resolve(dir, specifier, &self.default),
+            None => self.default.resolve(dir, specifier),
+        }
+    }
+}
+
+struct TsconfigDiscoveryResolvers {
+    /// Options shared by the resolvers of all `tsconfig.json` files.
+    options: ResolverOptions,
+    /// Path of the nearest `tsconfig.json` of each visited directory.
+    nearest_tsconfigs: PathMap<Option<Arc<Path>>>,
+    /// Resolvers keyed by the path of their `tsconfig.json`.
+    resolvers: PathMap<Resolver>,
+}
+
+impl TsconfigDiscoveryResolvers {
+    fn resolve(
+        &self,
+        dir: &Path,
+        specifier: &str,
+        default: &Resolver,
+    ) -> Result<Resolution, ResolveError> {
+        let Some(tsconfig_path) = self.nearest_tsconfig(dir) else {
+            return default.resolve(dir, specifier);
+        };
+        let resolvers = self.resolvers.pin();
+        // Each resolver needs its own cache, because a manually configured tsconfig is cached
+        // at the file system root.
+        let resolver = resolvers.get_or_insert_with(Arc::clone(&tsconfig_path), || {
+            new_resolver(Some(tsconfig_path.to_path_buf()), &self.options)
+        });
+        resolver.resolve(dir, specifier)
+    }
+
+    /// Find the nearest `tsconfig.json` by walking up from `dir`.
+    fn nearest_tsconfig(&self, dir: &Path) -> Option<Arc<Path>> {
+        let nearest_tsconfigs = self.nearest_tsconfigs.pin();
+        if let Some(tsconfig_path) = nearest_tsconfigs.get(dir) {
+            return tsconfig_path.clone();
+        }
+        let tsconfig_path = dir.join("tsconfig.json");
+        let tsconfig_path = if tsconfig_path.is_file() {
+            Some(Arc::from(tsconfig_path))
+        } else {
+            dir.parent().and_then(|parent| self.nearest_tsconfig(parent))
+        };
+        nearest_tsconfigs.insert(Arc::from(dir), tsconfig_path.clone());
+        tsconfig_path
+    }
+}
+
+fn new_resolver(tsconfig_path: Option<PathBuf>, options: &ResolverOptions) -> Resolver {
+    let tsconfig = tsconfig_path.and_then(|path| {
+        path.is_file().then_some(TsconfigDiscovery::Manual(TsconfigOptions {
+            config_file: path,
+            references: TsconfigReferences::Auto,
+        }))
+    });
+    let extension_alias = tsconfig.as_ref().map_or_else(Vec::new, |_| {
+        vec![
+            (".js".into(), vec![".js".into(), ".ts".into()]),
+            (".mjs".into(), vec![".mjs".into(), ".mts".into()]),
+            (".cjs".into(), vec![".cjs".into(), ".cts".into()]),
+        ]
+    });
+    Resolver::new(ResolveOptions {
+        extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
+        main_fields: options.main_fields.clone(),
+        condition_names: options.condition_names.clone(),
+        extension_alias,
+        tsconfig,
+        ..ResolveOptions::default()
+    })
+}
@@ -1,15 +1,17 @@
 use std::{
     borrow::Cow,
+    collections::BTreeMap,
     ffi::OsStr,
     fs,
     hash::BuildHasherDefault,
     mem::take,
     path::{Path, PathBuf},
     sync::{Arc, Mutex, mpsc},
+    time::Instant,
 };
 
 use indexmap::IndexSet;
-use rayon::iter::ParallelDrainRange;
+use rayon::iter::{ParallelBridge, ParallelDrainRange};
 use rayon::{
     Scope,
     iter::IntoParallelRefIterator,
@@ -17,25 +19,72 @@ use rayon::{
 };
 use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet, FxHasher};
 use self_cell::self_cell;
-use smallvec::SmallVec;
+use smallvec::{SmallVec, smallvec};
 
 use oxc_allocator::{Allocator, AllocatorGuard, AllocatorPool};
-use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic};
+use oxc_diagnostics::{
+    DiagnosticSender, DiagnosticService, Error, OxcCode, OxcDiagnostic, Severity,
+};
 use oxc_parser::{ParseOptions, Parser};
-use oxc_resolver::Resolver;
-use oxc_semantic::{Semantic, SemanticBuilder};
-use oxc_span::{CompactStr, SourceType, VALID_EXTENSIONS};
+use oxc_semantic::{NodeId, Semantic, SemanticBuilder};
+use oxc_span::{CompactStr, SourceType, Span};
 
 use crate::{
-    Fixer, Linter, Message, PossibleFixes,
+    CancellationToken, ExternalProcessorId, FileMetadataMap, Fixer, Linter, Message, PossibleFixes,
+    ResolvedLinterState, RuleOriginsMap,
     context::ContextSubHost,
     disable_directives::DisableDirectives,
-    loader::{JavaScriptSource, LINT_PARTIAL_LOADER_EXTENSIONS, PartialLoader},
+    fixer::{FixSection, FixedFile, FixedFilesMap},
+    loader::{
+        JavaScriptSource, LINT_PARTIAL_LOADER_EXTENSIONS, PartialLoader, is_json_path,
+        is_jsonc_path, parse_json,
+    },
     module_record::ModuleRecord,
+    type_facts::{TypeFacts, TypeFactsMap},
     utils::read_to_arena_str,
 };
 
-use super::LintServiceOptions;
+use super::{
+    FileDiagnostics, FileLimits, FileStatus, FileStatusMap, LintServiceOptions,
+    cache::{ContentHash, LintCache},
+    current_file::CurrentFileGuard,
+    group_size::GroupSizer,
+    memory_budget::{MemoryBudget, MemoryReservation},
+    prefetch::{PrefetchFileSystem, Prefetcher},
+    resolver::ModuleResolvers,
+};
+
+/// Maximum number of times a file is linted and fixed in a single `--fix` run.
+/// Same as ESLint.
+const MAX_FIX_PASSES: usize = 10;
+
+/// Name of the pseudo rule syntax errors are reported with, as `oxc(parse-error)`.
+///
+/// It cannot be configured or disabled, but lets every output format give syntax errors a rule id.
+pub const PARSE_ERROR_RULE_NAME: &str = "parse-error";
+
+/// Report syntax errors with the [`PARSE_ERROR_RULE_NAME`] pseudo rule.
+///
+/// Other error codes, such as TypeScript's, are replaced so that syntax errors always have the
+/// same rule id, and kept as the `parserCode` data of the diagnostic. Help messages and labels,
+/// e.g. which token was expected, are kept.
+fn parse_errors(errors: Vec<OxcDiagnostic>) -> Vec<OxcDiagnostic> {
+    errors
+        .into_iter()
+        .map(|error| {
+            let parser_code = error.code.is_some().then(|| error.code.to_string());
+            let mut error = match parser_code {
+                Some(parser_code) => error.with_data("parserCode", parser_code),
+                None => error,
+            };
+            error.code = OxcCode {
+                scope: Some(Cow::Borrowed("oxc")),
+                number: Some(Cow::Borrowed(PARSE_ERROR_RULE_NAME)),
+            };
+            error
+        })
+        .collect()
+}
 
 type ModulesByPath =
     papaya::HashMap<Arc<OsStr>, SmallVec<[Arc<ModuleRecord>; 1]>, BuildHasherDefault<FxHasher>>;
@@ -43,9 +92,11 @@ type ModulesByPath =
 pub struct Runtime {
     cwd: Box<Path>,
     pub(super) linter: Linter,
-    resolver: Option<Resolver>,
+    /// Resolves imports of files whose configuration enables the import plugin. `None` if no
+    /// configuration enables it.
+    resolver: Option<ModuleResolvers>,
 
-    allocator_pool: AllocatorPool,
+    pub(super) allocator_pool: AllocatorPool,
 
     /// The module graph keyed by module paths. It is looked up when populating `loaded_modules`.
     /// The values are module records of sections (check the docs of `ProcessedModule.section_module_records`)
@@ -57,6 +108,31 @@ pub struct Runtime {
     modules_by_path: ModulesByPath,
     /// Collected disable directives from linted files
     disable_directives_map: Arc<Mutex<FxHashMap<PathBuf, DisableDirectives>>>,
+    /// Collected frameworks and source types of linted files, only when requested
+    file_metadata_map: Option<FileMetadataMap>,
+    /// Collected fixes applied to written files, only when requested
+    fixed_files_map: Option<FixedFilesMap>,
+    /// Collected statuses of the files to lint, only when requested
+    file_status_map: Option<FileStatusMap>,
+    /// Collected origins of the rules reporting diagnostics, only when requested
+    rule_origins_map: Option<RuleOriginsMap>,
+    /// Type information computed by `tsgolint` before linting, attached to each linted file
+    type_facts_map: TypeFactsMap,
+    /// Diagnostics of files linted by `run_source`, reused while the files are unchanged
+    lint_cache: Option<LintCache>,
+    /// Files exceeding these limits are skipped
+    file_limits: FileLimits,
+    /// Bounds the memory held by modules waiting to be linted, see `LintServiceOptions::with_max_memory`
+    memory_budget: Option<MemoryBudget>,
+    /// Size of groups of the module graph set with [`LintServiceOptions::with_group_size`] or
+    /// `OXLINT_GROUP_SIZE`, instead of adapting it
+    fixed_group_size: Option<usize>,
+    /// Read files on dedicated I/O threads ahead of parsing them, see `LintServiceOptions::with_prefetch`
+    prefetch: bool,
+    /// No diagnostic is displayed, they are only counted
+    silent: bool,
+    /// Warnings are not displayed, they are only counted
+    quiet: bool,
 }
 
 /// Output of `Runtime::process_path`
@@ -85,6 +161,10 @@ struct ProcessedModule<'alloc_pool> {
     /// Note that `content` is `Some` even if parsing is unsuccessful as long as the source to lint is valid utf-8.
     /// It is designed this way to cover the case where some but not all the sections fail to parse.
     content: Option<ModuleContent<'alloc_pool>>,
+
+    /// Diagnostics of a module to lint taken from the lint cache, as its source text and the
+    /// source texts of its dependencies are unchanged. Its sections are not parsed again.
+    cached_messages: Option<Vec<Message>>,
 }
 
 struct ResolvedModuleRequest {
@@ -130,6 +210,10 @@ struct ModuleToLint<'alloc_pool> {
     path: Arc<OsStr>,
     section_module_records: SmallVec<[Result<Arc<ModuleRecord>, Vec<OxcDiagnostic>>; 1]>,
     content: ModuleContent<'alloc_pool>,
+    /// See [`ProcessedModule::cached_messages`]
+    cached_messages: Option<Vec<Message>>,
+    /// Released when the module is dropped after linting
+    memory: Option<MemoryReservation<'alloc_pool>>,
 }
 impl<'alloc_pool> ModuleToLint<'alloc_pool> {
     fn from_processed_module(
@@ -144,8 +228,51 @@ impl<'alloc_pool> ModuleToLint<'alloc_pool> {
                 .map(|record_result| record_result.map(|ok| ok.module_record))
                 .collect(),
             content,
+            cached_messages: processed_module.cached_messages,
+            memory: None,
+        })
+    }
+}
+
+/// Result of [`Runtime::fix_until_stable`]
+struct FixPasses {
+    /// Source text with all fixes applied
+    fixed_code: String,
+    /// Messages remaining after the last pass
+    messages: Vec<Message>,
+    /// Source text which the spans of `messages` refer to
+    messages_source_text: String,
+    /// Codes of the diagnostics whose fixes were applied, over all passes
+    fixed_codes: Vec<OxcCode>,
+}
+
+/// The sections of a file, so that fixes are applied to each section separately.
+/// e.g. the `<script>` blocks of a `.vue` file, or the frontmatter and scripts of an `.astro` file.
+fn fix_sections(section_contents: &[SectionContent<'_>]) -> Vec<FixSection> {
+    section_contents
+        .iter()
+        .map(|section| {
+            #[expect(clippy::cast_possible_truncation)]
+            let len = section.source.source_text.len() as u32;
+            FixSection {
+                span: Span::sized(section.source.start, len),
+                source_type: Some(section.source.source_type),
+            }
         })
+        .collect()
+}
+
+/// The extension of `path` if it is a file which can be linted, either as a whole or through a
+/// [`PartialLoader`].
+fn lintable_extension(path: &Path) -> Option<&str> {
+    let ext = path.extension().and_then(OsStr::to_str)?;
+    if SourceType::from_path(path)
+        .as_ref()
+        .is_err_and(|_| !LINT_PARTIAL_LOADER_EXTENSIONS.contains(&ext))
+    {
+        return None;
     }
+    Some(ext)
 }
 
 /// A simple trait for the `Runtime` to load and save file from a filesystem
@@ -167,6 +294,12 @@ pub trait RuntimeFileSystem {
     /// # Errors
     /// When the program does not have write permission for the file system
     fn write_file(&self, path: &Path, content: &str) -> Result<(), std::io::Error>;
+
+    /// Whether files can be read ahead of parsing them, see [`LintServiceOptions::with_prefetch`].
+    /// Only file systems reading from disk benefit from it.
+    fn supports_prefetch(&self) -> bool {
+        false
+    }
 }
 
 pub struct OsFileSystem;
@@ -183,6 +316,10 @@ impl RuntimeFileSystem for OsFileSystem {
     fn write_file(&self, path: &Path, content: &str) -> Result<(), std::io::Error> {
         fs::write(path, content)
     }
+
+    fn supports_prefetch(&self) -> bool {
+        true
+    }
 }
 
 impl Runtime {
@@ -211,14 +348,22 @@ impl Runtime {
 
         // If an external linter is used (JS plugins), we must use fixed-size allocators,
         // for compatibility with raw transfer
-        let allocator_pool = if linter.has_external_linter() {
+        let mut allocator_pool = if linter.has_external_linter() {
             AllocatorPool::new_fixed_size(thread_count)
         } else {
             AllocatorPool::new(thread_count)
         };
+        if let Some(max_memory) = options.max_memory {
+            allocator_pool = allocator_pool.with_max_retained_capacity(max_memory / thread_count);
+        }
 
         let resolver = options.cross_module.then(|| {
-            Self::get_resolver(options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json"))))
+            ModuleResolvers::new(
+                options.resolver_conditions,
+                options.resolver_main_fields,
+                options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json"))),
+                options.tsconfig_discovery,
+            )
         });
 
         Self {
@@ -231,7 +376,125 @@ impl Runtime {
                 .resize_mode(papaya::ResizeMode::Blocking)
                 .build(),
             disable_directives_map: Arc::new(Mutex::new(FxHashMap::default())),
+            file_metadata_map: None,
+            fixed_files_map: None,
+            file_status_map: None,
+            rule_origins_map: None,
+            type_facts_map: Arc::new(Mutex::new(FxHashMap::default())),
+            lint_cache: (options.lint_cache_capacity > 0)
+                .then(|| LintCache::new(options.lint_cache_capacity)),
+            file_limits: options.file_limits,
+            memory_budget: options.max_memory.map(MemoryBudget::new),
+            fixed_group_size: options.group_size.or_else(GroupSizer::fixed_from_env),
+            prefetch: options.prefetch,
+            silent: false,
+            quiet: false,
+        }
+    }
+
+    /// The cancellation token for linting a single file, which is also cancelled once the file
+    /// takes longer than [`FileLimits::lint_time`].
+    fn file_cancellation_token(&self, cancellation_token: &CancellationToken) -> CancellationToken {
+        match self.file_limits.lint_time {
+            Some(max_lint_time) => cancellation_token.with_deadline(Instant::now() + max_lint_time),
+            None => cancellation_token.clone(),
+        }
+    }
+
+    /// The message replacing the messages of a file which took longer than
+    /// [`FileLimits::lint_time`], if it did.
+    fn lint_time_exceeded(
+        &self,
+        path: &Path,
+        file_cancellation_token: &CancellationToken,
+    ) -> Option<Message> {
+        let max_lint_time = self.file_limits.lint_time?;
+        file_cancellation_token.is_past_deadline().then(|| {
+            Message::new(
+                file_limit_exceeded(
+                    path,
+                    &format!(
+                        "linting it took longer than the limit of {}ms",
+                        max_lint_time.as_millis()
+                    ),
+                ),
+                PossibleFixes::None,
+            )
+        })
+    }
+
+    /// Check the source text of a file against [`FileLimits::size`], [`FileLimits::lines`] and
+    /// [`FileLimits::depth`], so that files exceeding them are not parsed at all.
+    ///
+    /// The depth is estimated from the nesting of brackets, which is a lower bound of the AST depth.
+    /// The exact depth is checked after parsing in [`Runtime::check_file_limits`].
+    fn check_source_limits(&self, path: &Path, source_text: &str) -> Result<(), OxcDiagnostic> {
+        let FileLimits { size: max_size, lines: max_lines, depth: max_depth, .. } =
+            self.file_limits;
+
+        if let Some(max_size) = max_size
+            && source_text.len() > max_size
+        {
+            return Err(file_limit_exceeded(
+                path,
+                &format!("it has {} bytes, more than the limit of {max_size}", source_text.len()),
+            ));
+        }
+
+        if let Some(max_lines) = max_lines
+            && source_text.lines().nth(max_lines).is_some()
+        {
+            return Err(file_limit_exceeded(
+                path,
+                &format!("it has more lines than the limit of {max_lines}"),
+            ));
+        }
+
+        if let Some(max_depth) = max_depth
+            && bracket_depth_exceeds(source_text, max_depth)
+        {
+            return Err(file_limit_exceeded(
+                path,
+                &format!("its AST is nested deeper than the limit of {max_depth}"),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Check the AST of a source section against [`FileLimits::nodes`] and [`FileLimits::depth`].
+    fn check_file_limits(&self, path: &Path, semantic: &Semantic) -> Result<(), OxcDiagnostic> {
+        let FileLimits { nodes: max_nodes, depth: max_depth, .. } = self.file_limits;
+        let nodes = semantic.nodes();
+
+        if let Some(max_nodes) = max_nodes
+            && nodes.len() > max_nodes
+        {
+            return Err(file_limit_exceeded(
+                path,
+                &format!("it has {} AST nodes, more than the limit of {max_nodes}", nodes.len()),
+            ));
+        }
+
+        if let Some(max_depth) = max_depth {
+            // Parents are created before their children, so the depth of the parent of each node
+            // is known when the node is visited.
+            let mut depths = Vec::<usize>::with_capacity(nodes.len());
+            for node in nodes.iter() {
+                let id = node.id();
+                let depth =
+                    if id == NodeId::ROOT { 0 } else { depths[nodes.parent_id(id).index()] + 1 };
+                if depth > max_depth {
+                    return Err(file_limit_exceeded(
+                        path,
+                        &format!("its AST is nested deeper than the limit of {max_depth}"),
+                    ));
+                }
+                depths.push(depth);
+            }
         }
+
+        Ok(())
     }
 
     pub fn set_disable_directives_map(
@@ -241,51 +504,131 @@ impl Runtime {
         self.disable_directives_map = map;
     }
 
-    fn get_resolver(tsconfig_path: Option<PathBuf>) -> Resolver {
-        use oxc_resolver::{
-            ResolveOptions, TsconfigDiscovery, TsconfigOptions, TsconfigReferences,
+    pub fn set_file_metadata_map(&mut self, map: FileMetadataMap) {
+        self.file_metadata_map = Some(map);
+    }
+
+    pub fn set_fixed_files_map(&mut self, map: FixedFilesMap) {
+        self.fixed_files_map = Some(map);
+    }
+
+    pub fn set_file_status_map(&mut self, map: FileStatusMap) {
+        self.file_status_map = Some(map);
+    }
+
+    pub fn set_rule_origins_map(&mut self, map: RuleOriginsMap) {
+        self.rule_origins_map = Some(map);
+    }
+
+    pub fn set_type_facts_map(&mut self, map: TypeFactsMap) {
+        self.type_facts_map = map;
+    }
+
+    pub fn set_silent(&mut self, yes: bool) {
+        self.silent = yes;
+    }
+
+    pub fn set_quiet(&mut self, yes: bool) {
+        self.quiet = yes;
+    }
+
+    /// Wrap `diagnostics` with the source text of the file at `path`, see
+    /// [`DiagnosticService::wrap_diagnostics`].
+    ///
+    /// When none of the diagnostics are displayed, they are only counted, so the source text is
+    /// not copied.
+    fn wrap_diagnostics(
+        &self,
+        path: &Path,
+        source_text: &str,
+        diagnostics: Vec<OxcDiagnostic>,
+    ) -> Vec<Error> {
+        let is_hidden = |diagnostic: &OxcDiagnostic| {
+            self.silent || (self.quiet && diagnostic.severity == Severity::Warning)
         };
-        let tsconfig = tsconfig_path.and_then(|path| {
-            path.is_file().then_some(TsconfigDiscovery::Manual(TsconfigOptions {
-                config_file: path,
-                references: TsconfigReferences::Auto,
-            }))
-        });
-        let extension_alias = tsconfig.as_ref().map_or_else(Vec::new, |_| {
-            vec![
-                (".js".into(), vec![".js".into(), ".ts".into()]),
-                (".mjs".into(), vec![".mjs".into(), ".mts".into()]),
-                (".cjs".into(), vec![".cjs".into(), ".cts".into()]),
-            ]
-        });
-        Resolver::new(ResolveOptions {
-            extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
-            main_fields: vec!["module".into(), "main".into()],
-            condition_names: vec!["module".into(), "import".into()],
-            extension_alias,
-            tsconfig,
-            ..ResolveOptions::default()
+        let source_text = if diagnostics.iter().all(is_hidden) { "" } else { source_text };
+        DiagnosticService::wrap_diagnostics(&self.cwd, path, source_text, diagnostics)
+    }
+
+    fn store_file_metadata(&self, path: &Path, context_sub_hosts: &[ContextSubHost<'_>]) {
+        if let Some(file_metadata_map) = &self.file_metadata_map {
+            let metadata = self.linter.file_metadata(path, context_sub_hosts);
+            file_metadata_map
+                .lock()
+                .expect("file_metadata_map mutex poisoned")
+                .insert(path.to_path_buf(), metadata);
+        }
+    }
+
+    fn store_file_status(&self, path: &Path, status: FileStatus) {
+        if let Some(file_status_map) = &self.file_status_map {
+            file_status_map
+                .lock()
+                .expect("file_status_map mutex poisoned")
+                .insert(path.to_path_buf(), status);
+        }
+    }
+
+    fn store_rule_origins(&self, path: &Path, messages: &[Message]) {
+        if let Some(rule_origins_map) = &self.rule_origins_map {
+            let origins = messages
+                .iter()
+                .filter_map(|message| {
+                    Some((message.error.code.to_string(), message.origin.clone()?))
+                })
+                .collect::<BTreeMap<_, _>>();
+            if !origins.is_empty() {
+                rule_origins_map
+                    .lock()
+                    .expect("rule_origins_map mutex poisoned")
+                    .insert(path.to_path_buf(), origins);
+            }
+        }
+    }
+
+    fn type_facts(&self, path: &Path) -> Option<Arc<TypeFacts>> {
+        self.type_facts_map.lock().expect("type_facts_map mutex poisoned").get(path).cloned()
+    }
+
+    /// The extension of `path`, if it can be linted: either it is JS/TS, it is loaded with the
+    /// [`PartialLoader`], a processor of a JS plugin is configured for it, or it is a JSON file
+    /// and the `json` plugin is enabled.
+    ///
+    /// `processor` is the processor of `path`, resolved once per file with [`Linter::processor`].
+    fn lintable_extension<'p>(
+        &self,
+        path: &'p Path,
+        processor: Option<ExternalProcessorId>,
+    ) -> Option<&'p str> {
+        lintable_extension(path).or_else(|| {
+            if processor.is_none() && !self.linter.lints_json(path) {
+                return None;
+            }
+            path.extension().and_then(OsStr::to_str)
         })
     }
 
     fn get_source_type_and_text<'a>(
+        &self,
         file_system: &'a (dyn RuntimeFileSystem + Sync + Send),
         path: &Path,
         ext: &str,
+        processor: Option<ExternalProcessorId>,
         allocator: &'a Allocator,
     ) -> Option<Result<(SourceType, &'a str), Error>> {
         let source_type = SourceType::from_path(path);
-        let not_supported_yet =
-            source_type.as_ref().is_err_and(|_| !LINT_PARTIAL_LOADER_EXTENSIONS.contains(&ext));
+        let not_supported_yet = source_type.as_ref().is_err_and(|_| {
+            !LINT_PARTIAL_LOADER_EXTENSIONS.contains(&ext)
+                && processor.is_none()
+                && !self.linter.lints_json(path)
+        });
         if not_supported_yet {
             return None;
         }
 
-        let mut source_type = source_type.unwrap_or_default();
-        // Treat JS and JSX files to maximize chance of parsing files.
-        if source_type.is_javascript() {
-            source_type = source_type.with_jsx(true);
-        }
+        // JSX is enabled in JS files to maximize chance of parsing files, unless disabled by the
+        // `parserOptions` of the configuration.
+        let source_type = self.linter.parser_options(path).apply(source_type.unwrap_or_default());
 
         let file_result = file_system.read_to_arena_str(path, allocator).map_err(|e| {
             Error::new(OxcDiagnostic::error(format!(
@@ -299,10 +642,32 @@ impl Runtime {
         })
     }
 
+    /// The lintable files of `paths` in the order they are read in, deeper paths first if imports
+    /// are resolved (see `resolve_modules`).
+    fn read_order(&self, paths: &IndexSet<Arc<OsStr>, FxBuildHasher>) -> Vec<Arc<OsStr>> {
+        let mut paths: Vec<_> = paths
+            .iter()
+            .filter(|path| {
+                let path = Path::new(path);
+                self.lintable_extension(path, self.linter.processor(path)).is_some()
+            })
+            .cloned()
+            .collect();
+        if self.resolver.is_some() {
+            paths.par_sort_unstable_by(|a, b| Path::new(b).cmp(Path::new(a)));
+        }
+        paths
+    }
+
     /// Prepare entry modules for linting.
     ///
     /// `on_module_to_lint` is called for each entry modules in `paths` when it's ready for linting,
     /// which means all its dependencies are resolved if import plugin is enabled.
+    ///
+    /// Once `cancellation_token` is cancelled, no more modules are read or parsed, and modules which
+    /// are ready but not yet linted are dropped without calling `on_module_to_lint`.
+    ///
+    /// With `ordered`, entry modules are read in the order of [`Runtime::read_order`].
     fn resolve_modules<'a>(
         &'a self,
         file_system: &'a (dyn RuntimeFileSystem + Sync + Send),
@@ -310,19 +675,45 @@ impl Runtime {
         scope: &Scope<'a>,
         check_syntax_errors: bool,
         tx_error: Option<&'a DiagnosticSender>,
+        cancellation_token: &'a CancellationToken,
+        ordered: bool,
         on_module_to_lint: impl Fn(&'a Self, ModuleToLint) + Send + Sync + Clone + 'a,
     ) {
         if self.resolver.is_none() {
-            paths.par_iter().for_each(|path| {
-                let output =
-                    self.process_path(file_system, paths, path, check_syntax_errors, tx_error);
-                let Some(entry) =
+            let process_path = |path: &Arc<OsStr>| {
+                // Modules are linted on the thread which parsed them, so a thread waiting here
+                // holds no memory of the budget itself.
+                if let Some(budget) = &self.memory_budget {
+                    budget.wait();
+                }
+                let output = self.process_path(
+                    file_system,
+                    paths,
+                    path,
+                    check_syntax_errors,
+                    tx_error,
+                    cancellation_token,
+                );
+                if cancellation_token.is_cancelled() {
+                    return;
+                }
+                let Some(mut entry) =
                     ModuleToLint::from_processed_module(output.path, output.processed_module)
                 else {
                     return;
                 };
+                if let Some(budget) = &self.memory_budget {
+                    entry.memory = Some(budget.reserve(entry.content.borrow_owner().capacity()));
+                }
                 on_module_to_lint(self, entry);
-            });
+            };
+            // Splitting `paths` between threads would make them read from far apart parts of it,
+            // instead of the files read ahead by the prefetcher.
+            if ordered {
+                paths.iter().par_bridge().for_each(process_path);
+            } else {
+                paths.par_iter().for_each(process_path);
+            }
             return;
         }
         // The goal of code below is to construct the module graph bootstrapped by the entry modules (`paths`),
@@ -353,19 +744,18 @@ impl Runtime {
         // This heuristic is not always true, but it works well enough for real world codebases.
 
         // Create a sorted copy of paths for processing
-        let mut sorted_paths: Vec<_> = paths.iter().cloned().collect();
-        sorted_paths.par_sort_unstable_by(|a, b| Path::new(b).cmp(Path::new(a)));
+        let sorted_paths = self.read_order(paths);
 
         // The general idea is processing `sorted_paths` and their dependencies in groups. We start from a group of modules
         // in `sorted_paths` that is small enough to hold in memory but big enough to make use of the rayon thread pool.
         // We build the module graph from one group, run lint on them, drop sources and semantics but keep the module
         // graph, and then move on to the next group.
-        // This size is empirical based on AFFiNE@97cc814a.
-        let group_size = rayon::current_num_threads() * 4;
+        // The size of groups adapts to the size of modules and the number of their dependencies, see `GroupSizer`.
+        let mut group_sizer = GroupSizer::new(rayon::current_num_threads(), self.fixed_group_size);
 
         // Stores modules that belongs to `self.paths` in current group.
         // They are passed to `on_module_to_lint` at the end of each group.
-        let mut modules_to_lint: Vec<ModuleToLint> = Vec::with_capacity(group_size);
+        let mut modules_to_lint: Vec<ModuleToLint> = Vec::with_capacity(group_sizer.next());
 
         // Set self to immutable reference so it can be shared among spawned tasks.
         let me: &Self = self;
@@ -392,9 +782,29 @@ impl Runtime {
         let mut group_start = 0usize;
 
         // The group loop. Each iteration of this loop processes a group of modules.
-        while group_start < sorted_paths.len() {
+        while group_start < sorted_paths.len() && !cancellation_token.is_cancelled() {
             // How many modules are queued but not processed in this group.
             let mut pending_module_count = 0;
+            // Modules to lint which bootstrap this group, dependencies they pull in and bytes they hold,
+            // recorded in `group_sizer` at the end of this group.
+            let mut bootstrap_module_count = 0;
+            let mut dependency_count = 0;
+            let mut group_bytes = 0;
+
+            // With a memory budget, wait for modules of former groups to be linted while it's exceeded,
+            // and shrink the group to the number of modules expected to fit into the rest of it.
+            let group_size = if let Some(budget) = &self.memory_budget {
+                while budget.is_exceeded() && !cancellation_token.is_cancelled() {
+                    // Lint modules queued on this thread, which may be the only one. Otherwise all
+                    // modules are already being linted by other threads, which release their memory.
+                    if rayon::yield_now() != Some(rayon::Yield::Executed) {
+                        budget.wait();
+                    }
+                }
+                budget.modules_that_fit(group_sizer.next())
+            } else {
+                group_sizer.next()
+            };
 
             // Bootstrap the group by processing modules to be linted.
             while pending_module_count < group_size && group_start < sorted_paths.len() {
@@ -404,6 +814,7 @@ impl Runtime {
                 // Check if this module to be linted is already processed as a dependency in former groups
                 if encountered_paths.insert(Arc::clone(path)) {
                     pending_module_count += 1;
+                    bootstrap_module_count += 1;
                     let path = Arc::clone(path);
                     let tx_process_output = tx_process_output.clone();
                     scope.spawn(move |_| {
@@ -414,6 +825,7 @@ impl Runtime {
                                 &path,
                                 check_syntax_errors,
                                 tx_error,
+                                cancellation_token,
                             ))
                             .unwrap();
                     });
@@ -454,11 +866,13 @@ impl Runtime {
                                             &dep_path,
                                             check_syntax_errors,
                                             tx_error,
+                                            cancellation_token,
                                         ))
                                         .unwrap();
                                 }
                             });
                             pending_module_count += 1;
+                            dependency_count += 1;
                         }
                     }
                 }
@@ -492,12 +906,18 @@ impl Runtime {
 
                 // This module has `content` which means it's one of `self.paths`.
                 // Store it to `modules_to_lint`
-                if let Some(entry_module) =
+                if let Some(mut entry_module) =
                     ModuleToLint::from_processed_module(path, processed_module)
                 {
+                    let bytes = entry_module.content.borrow_owner().capacity();
+                    group_bytes += bytes;
+                    if let Some(budget) = &me.memory_budget {
+                        entry_module.memory = Some(budget.reserve(bytes));
+                    }
                     modules_to_lint.push(entry_module);
                 }
             } // while pending_module_count > 0
+            group_sizer.record(bootstrap_module_count, dependency_count, group_bytes);
 
             // Now all dependencies in this group are processed.
             // Writing to `loaded_modules` based on `module_paths_and_resolved_requests`
@@ -526,6 +946,12 @@ impl Runtime {
                     }
                 }
             });
+
+            // Drop the contents of modules which will not be linted anymore, releasing their allocators.
+            if cancellation_token.is_cancelled() {
+                modules_to_lint.clear();
+            }
+
             #[expect(clippy::iter_with_drain)]
             for entry in modules_to_lint.drain(..) {
                 let on_entry = on_module_to_lint.clone();
@@ -544,112 +970,307 @@ impl Runtime {
     ) {
         self.modules_by_path.pin().reserve(paths.len());
         let paths_set: IndexSet<Arc<OsStr>, FxBuildHasher> = paths.into_iter().collect();
+        // CLI runs are never cancelled
+        let cancellation_token = &CancellationToken::new();
+
+        let io_threads = rayon::current_num_threads();
+        let prefetcher = (self.prefetch && file_system.supports_prefetch())
+            .then(|| Prefetcher::new(self.read_order(&paths_set), io_threads));
+        let prefetch_file_system =
+            prefetcher.as_ref().map(|prefetcher| PrefetchFileSystem::new(file_system, prefetcher));
+        let file_system: &(dyn RuntimeFileSystem + Sync + Send) = match &prefetch_file_system {
+            Some(prefetch_file_system) => prefetch_file_system,
+            None => file_system,
+        };
 
-        rayon::scope(|scope| {
-            self.resolve_modules(
-                file_system,
-                &paths_set,
-                scope,
-                true,
-                Some(tx_error),
-                move |me, mut module_to_lint| {
-                    module_to_lint.content.with_dependent_mut(|allocator_guard, dep| {
-                        // If there are fixes, we will accumulate all of them and write to the file at the end.
-                        // This means we do not write multiple times to the same file if there are multiple sources
-                        // in the same file (for example, multiple scripts in an `.astro` file).
-                        let mut new_source_text = Cow::from(dep.source_text);
+        let lint = || {
+            rayon::scope(|scope| {
+                self.resolve_modules(
+                    file_system,
+                    &paths_set,
+                    scope,
+                    true,
+                    Some(tx_error),
+                    cancellation_token,
+                    prefetcher.is_some(),
+                    move |me, mut module_to_lint| {
+                        let _current_file = CurrentFileGuard::new(&module_to_lint.path);
+                        module_to_lint.content.with_dependent_mut(|allocator_guard, dep| {
+                            // If there are fixes, we will accumulate all of them and write to the file at the end.
+                            // This means we do not write multiple times to the same file if there are multiple sources
+                            // in the same file (for example, multiple scripts in an `.astro` file).
+                            let mut new_source_text = Cow::from(dep.source_text);
+
+                            let path = Path::new(&module_to_lint.path);
+                            // A file is only linted completely if all of its sections were parsed
+                            let unlinted_status = module_to_lint
+                                .section_module_records
+                                .iter()
+                                .filter_map(|record_result| record_result.as_ref().err())
+                                .map(|messages| FileStatus::of_unlinted_section(messages))
+                                .max();
+                            let type_facts = me.type_facts(path);
+                            let fix_sections = if me.linter.options().fix.is_some() {
+                                fix_sections(&dep.section_contents)
+                            } else {
+                                Vec::new()
+                            };
+
+                            assert_eq!(
+                                module_to_lint.section_module_records.len(),
+                                dep.section_contents.len()
+                            );
 
-                        let path = Path::new(&module_to_lint.path);
+                            let context_sub_hosts: Vec<ContextSubHost<'_>> = module_to_lint
+                                .section_module_records
+                                .into_iter()
+                                .zip(dep.section_contents.drain(..))
+                                .filter_map(|(record_result, section)| match record_result {
+                                    // Semantic is built without a control flow graph, see `process_source_section`.
+                                    Ok(_) if me.linter.options().syntax_only => None,
+                                    Ok(module_record) => Some(
+                                        ContextSubHost::new_with_framework_options(
+                                            section.semantic.unwrap(),
+                                            Arc::clone(&module_record),
+                                            section.source.start,
+                                            section.source.framework_options,
+                                        )
+                                        .with_type_facts(type_facts.clone()),
+                                    ),
+                                    Err(messages) => {
+                                        if !messages.is_empty() {
+                                            let diagnostics = me.wrap_diagnostics(
+                                                path,
+                                                dep.source_text,
+                                                messages,
+                                            );
+                                            tx_error.send(diagnostics).unwrap();
+                                        }
+                                        None
+                                    }
+                                })
+                                .collect();
+
+                            if context_sub_hosts.is_empty() {
+                                me.store_file_status(
+                                    path,
+                                    unlinted_status.unwrap_or(FileStatus::Linted),
+                                );
+                                return;
+                            }
 
-                        assert_eq!(
-                            module_to_lint.section_module_records.len(),
-                            dep.section_contents.len()
-                        );
+                            me.store_file_metadata(path, &context_sub_hosts);
+                            let file_cancellation_token =
+                                me.file_cancellation_token(cancellation_token);
+                            let (mut messages, disable_directives) =
+                                me.linter.run_with_disable_directives(
+                                    path,
+                                    context_sub_hosts,
+                                    allocator_guard,
+                                    &file_cancellation_token,
+                                );
+                            if let Some(message) =
+                                me.lint_time_exceeded(path, &file_cancellation_token)
+                            {
+                                messages = vec![message];
+                            }
 
-                        let context_sub_hosts: Vec<ContextSubHost<'_>> = module_to_lint
-                            .section_module_records
-                            .into_iter()
-                            .zip(dep.section_contents.drain(..))
-                            .filter_map(|(record_result, section)| match record_result {
-                                Ok(module_record) => {
-                                    Some(ContextSubHost::new_with_framework_options(
-                                        section.semantic.unwrap(),
-                                        Arc::clone(&module_record),
-                                        section.source.start,
-                                        section.source.framework_options,
-                                    ))
+                            // Store the disable directives for this file
+                            if let Some(disable_directives) = disable_directives {
+                                me.disable_directives_map
+                                    .lock()
+                                    .expect("disable_directives_map mutex poisoned")
+                                    .insert(path.to_path_buf(), disable_directives);
+                            }
+
+                            // Source text which the spans of `messages` refer to
+                            let mut messages_source_text = Cow::from(dep.source_text);
+                            let mut fixed_codes = vec![];
+
+                            if me.linter.options().fix.is_some() {
+                                let fix_result = Fixer::new(dep.source_text, messages, None)
+                                    .with_sections(fix_sections)
+                                    .with_min_severity(me.linter.options().fix_min_severity)
+                                    .with_fix_stages(true)
+                                    .fix();
+                                messages = fix_result.messages;
+                                if fix_result.fixed {
+                                    let fix_passes = me.fix_until_stable(
+                                        path,
+                                        fix_result.fixed_code.into_owned(),
+                                        messages,
+                                        dep.source_text.to_string(),
+                                        fix_result.fixed_codes,
+                                        &file_cancellation_token,
+                                    );
+                                    new_source_text = Cow::Owned(fix_passes.fixed_code);
+                                    messages = fix_passes.messages;
+                                    messages_source_text =
+                                        Cow::Owned(fix_passes.messages_source_text);
+                                    fixed_codes = fix_passes.fixed_codes;
                                 }
-                                Err(messages) => {
-                                    if !messages.is_empty() {
-                                        let diagnostics = DiagnosticService::wrap_diagnostics(
-                                            &me.cwd,
-                                            path,
-                                            dep.source_text,
-                                            messages,
-                                        );
-                                        tx_error.send(diagnostics).unwrap();
-                                    }
-                                    None
+                            }
+
+                            if !messages.is_empty() {
+                                me.store_rule_origins(path, &messages);
+                                let errors = messages.into_iter().map(Into::into).collect();
+                                let diagnostics =
+                                    me.wrap_diagnostics(path, &messages_source_text, errors);
+                                tx_error.send(diagnostics).unwrap();
+                            }
+
+                            // If the new source text is owned, that means it was modified,
+                            // so we write the new source text to the file.
+                            if let Cow::Owned(new_source_text) = &new_source_text {
+                                file_system.write_file(path, new_source_text).unwrap();
+                                if let Some(fixed_files_map) = &me.fixed_files_map {
+                                    fixed_files_map
+                                        .lock()
+                                        .expect("fixed_files_map mutex poisoned")
+                                        .insert(path.to_path_buf(), FixedFile::new(&fixed_codes));
                                 }
-                            })
-                            .collect();
+                            }
 
-                        if context_sub_hosts.is_empty() {
-                            return;
-                        }
+                            let status = unlinted_status.unwrap_or(match new_source_text {
+                                Cow::Owned(_) => FileStatus::Fixed,
+                                Cow::Borrowed(_) => FileStatus::Linted,
+                            });
+                            me.store_file_status(path, status);
+                        });
+                    },
+                );
+            });
+        };
+        match &prefetcher {
+            Some(prefetcher) => prefetcher.run(io_threads, lint),
+            None => lint(),
+        }
+    }
 
-                        let (mut messages, disable_directives) = me
-                            .linter
-                            .run_with_disable_directives(path, context_sub_hosts, allocator_guard);
+    /// Re-lint source text which had fixes applied, and apply any further fixes, until no more
+    /// fixes can be applied or [`MAX_FIX_PASSES`] is reached.
+    ///
+    /// Some fixes only become possible once another fix has been applied. For example, removing the
+    /// last usage of an import makes the import unused. Without this, users would need to run
+    /// `--fix` repeatedly for the file to converge. Fixes of rules depending on the fixes of other
+    /// rules are deferred to the passes after them, see [`Fixer::with_fix_stages`], except in the
+    /// last pass.
+    ///
+    /// `messages` are the messages remaining after the first pass, and `messages_source_text` is the
+    /// source text they refer to. `fixed_codes` are the codes of the fixes applied in the first pass.
+    /// `file_cancellation_token` is the token of the first pass, so that [`FileLimits::lint_time`]
+    /// limits the time of all passes. If it is exceeded, the fixes of the previous passes are kept
+    /// and the file is reported like in the first pass.
+    fn fix_until_stable(
+        &self,
+        path: &Path,
+        mut fixed_code: String,
+        mut messages: Vec<Message>,
+        mut messages_source_text: String,
+        mut fixed_codes: Vec<OxcCode>,
+        file_cancellation_token: &CancellationToken,
+    ) -> FixPasses {
+        let ext = path.extension().and_then(OsStr::to_str).unwrap_or_default();
+        // Source type used to parse the file again after fixes were applied.
+        let source_type = SourceType::from_path(path)
+            .ok()
+            .map(|source_type| self.linter.parser_options(path).apply(source_type));
+        let processor = self.linter.processor(path);
+
+        // The first pass has already been done by the caller
+        for pass in 1..MAX_FIX_PASSES {
+            let allocator_guard = self.allocator_pool.get();
+            let allocator = &*allocator_guard;
+            let source_text = allocator.alloc_str(&fixed_code);
 
-                        // Store the disable directives for this file
-                        if let Some(disable_directives) = disable_directives {
-                            me.disable_directives_map
-                                .lock()
-                                .expect("disable_directives_map mutex poisoned")
-                                .insert(path.to_path_buf(), disable_directives);
-                        }
+            let mut section_contents = SmallVec::new();
+            let records = self.process_source(
+                path,
+                ext,
+                processor,
+                true,
+                source_type.unwrap_or_default(),
+                source_text,
+                allocator,
+                Some(&mut section_contents),
+            );
 
-                        if me.linter.options().fix.is_some() {
-                            let fix_result = Fixer::new(
-                                dep.source_text,
-                                messages,
-                                SourceType::from_path(path).ok().map(|st| {
-                                    if st.is_javascript() { st.with_jsx(true) } else { st }
-                                }),
-                            )
-                            .fix();
-                            if fix_result.fixed {
-                                // write to file, replacing only the changed part
-                                let start = 0;
-                                let end = start + dep.source_text.len();
-                                new_source_text
-                                    .to_mut()
-                                    .replace_range(start..end, &fix_result.fixed_code);
-                            }
-                            messages = fix_result.messages;
-                        }
+            // Either a fix produced code which does not parse, or a section of the file already
+            // failed to parse (and was reported) in the first pass. Keep the result of the previous pass.
+            if records.iter().any(Result::is_err) {
+                break;
+            }
 
-                        if !messages.is_empty() {
-                            let errors = messages.into_iter().map(Into::into).collect();
-                            let diagnostics = DiagnosticService::wrap_diagnostics(
-                                &me.cwd,
-                                path,
-                                dep.source_text,
-                                errors,
-                            );
-                            tx_error.send(diagnostics).unwrap();
-                        }
+            let fix_sections = fix_sections(&section_contents);
+            let context_sub_hosts = records
+                .into_iter()
+                .zip(section_contents)
+                .filter_map(|(record_result, section)| {
+                    let record = record_result.ok()?;
+                    self.load_known_modules(&record);
+                    // Type facts are not attached, since their spans refer to the code before it was fixed.
+                    Some(ContextSubHost::new_with_framework_options(
+                        section.semantic.unwrap(),
+                        record.module_record,
+                        section.source.start,
+                        section.source.framework_options,
+                    ))
+                })
+                .collect::<Vec<_>>();
 
-                        // If the new source text is owned, that means it was modified,
-                        // so we write the new source text to the file.
-                        if let Cow::Owned(new_source_text) = &new_source_text {
-                            file_system.write_file(path, new_source_text).unwrap();
-                        }
-                    });
-                },
+            let (pass_messages, disable_directives) = self.linter.run_with_disable_directives(
+                path,
+                context_sub_hosts,
+                allocator,
+                file_cancellation_token,
             );
-        });
+            if let Some(message) = self.lint_time_exceeded(path, file_cancellation_token) {
+                messages = vec![message];
+                messages_source_text.clone_from(&fixed_code);
+                break;
+            }
+
+            if let Some(disable_directives) = disable_directives {
+                self.disable_directives_map
+                    .lock()
+                    .expect("disable_directives_map mutex poisoned")
+                    .insert(path.to_path_buf(), disable_directives);
+            }
+
+            let fix_result = Fixer::new(source_text, pass_messages, None)
+                .with_sections(fix_sections)
+                .with_min_severity(self.linter.options().fix_min_severity)
+                .with_fix_stages(pass + 1 < MAX_FIX_PASSES)
+                .fix();
+            messages = fix_result.messages;
+            messages_source_text.clone_from(&fixed_code);
+
+            if !fix_result.fixed {
+                break;
+            }
+            fixed_code = fix_result.fixed_code.into_owned();
+            fixed_codes.extend(fix_result.fixed_codes);
+        }
+
+        FixPasses { fixed_code, messages, messages_source_text, fixed_codes }
+    }
+
+    /// Populate `loaded_modules` of a module record created while re-linting a fixed file,
+    /// from modules which were already processed when constructing the module graph.
+    fn load_known_modules(&self, record: &ResolvedModuleRecord) {
+        if record.resolved_module_requests.is_empty() {
+            return;
+        }
+        let modules_by_path = self.modules_by_path.pin();
+        let mut loaded_modules = record.module_record.write_loaded_modules();
+        for request in &record.resolved_module_requests {
+            if let Some(dep_module_record) = modules_by_path
+                .get(&request.resolved_requested_path)
+                .and_then(|records| records.last())
+            {
+                loaded_modules.insert(request.specifier.clone(), Arc::downgrade(dep_module_record));
+            }
+        }
     }
 
     // language_server: the language server needs line and character position
@@ -659,13 +1280,30 @@ impl Runtime {
         &self,
         file_system: &(dyn RuntimeFileSystem + Sync + Send),
         paths: Vec<Arc<OsStr>>,
+        cancellation_token: &CancellationToken,
     ) -> Vec<Message> {
+        let mut messages = Vec::<Message>::new();
+        self.run_with_callback(file_system, paths, cancellation_token, |file_diagnostics| {
+            messages.extend(file_diagnostics.messages);
+        });
+        messages
+    }
+
+    /// Lint `paths` and call `callback` with the messages of each file once it has been linted,
+    /// including files whose messages are taken from the lint cache.
+    pub(super) fn run_with_callback(
+        &self,
+        file_system: &(dyn RuntimeFileSystem + Sync + Send),
+        paths: Vec<Arc<OsStr>>,
+        cancellation_token: &CancellationToken,
+        callback: impl FnMut(FileDiagnostics) + Send,
+    ) {
         use std::sync::Mutex;
 
         self.modules_by_path.pin().reserve(paths.len());
         let paths_set: IndexSet<Arc<OsStr>, FxBuildHasher> = paths.into_iter().collect();
 
-        let messages = Mutex::new(Vec::<Message>::new());
+        let callback = Mutex::new(callback);
         rayon::scope(|scope| {
             self.resolve_modules(
                 file_system,
@@ -673,66 +1311,113 @@ impl Runtime {
                 scope,
                 true,
                 None,
+                cancellation_token,
+                false,
                 |me, mut module_to_lint| {
+                    if let Some(messages) = module_to_lint.cached_messages.take() {
+                        (callback.lock().unwrap())(FileDiagnostics {
+                            path: PathBuf::from(&*module_to_lint.path),
+                            messages,
+                        });
+                        return;
+                    }
+                    let _current_file = CurrentFileGuard::new(&module_to_lint.path);
                     module_to_lint.content.with_dependent_mut(
-                    |allocator_guard, ModuleContentDependent { source_text: _, section_contents }| {
+                    |allocator_guard, ModuleContentDependent { source_text, section_contents }| {
                         assert_eq!(
                             module_to_lint.section_module_records.len(),
                             section_contents.len()
                         );
 
+                        let path = Path::new(&module_to_lint.path);
+                        let type_facts = me.type_facts(path);
+
+                        // Collected per file, to store them in the lint cache.
+                        let mut file_messages = Vec::<Message>::new();
+                        let module_records = module_to_lint
+                            .section_module_records
+                            .iter()
+                            .filter_map(|record_result| record_result.as_ref().ok().map(Arc::clone))
+                            .collect::<Vec<_>>();
+
                         let context_sub_hosts: Vec<ContextSubHost<'_>> = module_to_lint
                             .section_module_records
                             .into_iter()
                             .zip(section_contents.drain(..))
                             .filter_map(|(record_result, section)| match record_result {
-                                Ok(module_record) => {
-                                    Some(ContextSubHost::new_with_framework_options(
+                                // Semantic is built without a control flow graph, see `process_source_section`.
+                                Ok(_) if me.linter.options().syntax_only => None,
+                                Ok(module_record) => Some(
+                                    ContextSubHost::new_with_framework_options(
                                         section.semantic.unwrap(),
                                         Arc::clone(&module_record),
                                         section.source.start,
                                         section.source.framework_options,
-                                    ))
-                                }
+                                    )
+                                    .with_type_facts(type_facts.clone()),
+                                ),
                                 Err(diagnostics) => {
-                                    if !diagnostics.is_empty() {
-                                        messages.lock().unwrap().extend(
-                                            diagnostics.into_iter().map(|diagnostic| {
-                                                Message::new(diagnostic, PossibleFixes::None)
-                                            }),
-                                        );
-                                    }
+                                    file_messages.extend(diagnostics.into_iter().map(
+                                        |diagnostic| Message::new(diagnostic, PossibleFixes::None),
+                                    ));
                                     None
                                 }
                             })
                             .collect();
 
-                        if context_sub_hosts.is_empty() {
-                            return;
+                        if !context_sub_hosts.is_empty() {
+                            me.store_file_metadata(path, &context_sub_hosts);
+                            let file_cancellation_token =
+                                me.file_cancellation_token(cancellation_token);
+                            let (mut section_messages, disable_directives) = me
+                                .linter
+                                .run_with_disable_directives(
+                                    path,
+                                    context_sub_hosts,
+                                    allocator_guard,
+                                    &file_cancellation_token,
+                                );
+                            if let Some(message) = me.lint_time_exceeded(path, &file_cancellation_token)
+                            {
+                                section_messages = vec![message];
+                            }
+
+                            if let Some(disable_directives) = disable_directives {
+                                me.disable_directives_map
+                                    .lock()
+                                    .expect("disable_directives_map mutex poisoned")
+                                    .insert(path.to_path_buf(), disable_directives);
+                            }
+
+                            file_messages.extend(section_messages);
                         }
 
-                        let path = Path::new(&module_to_lint.path);
-                        let (section_messages, disable_directives) = me
-                            .linter
-                            .run_with_disable_directives(path, context_sub_hosts, allocator_guard);
-
-                        if let Some(disable_directives) = disable_directives {
-                            me.disable_directives_map
-                                .lock()
-                                .expect("disable_directives_map mutex poisoned")
-                                .insert(path.to_path_buf(), disable_directives);
+                        // Type facts can change when any file in the project changes.
+                        if let Some(lint_cache) = &me.lint_cache
+                            && type_facts.is_none()
+                            && !cancellation_token.is_cancelled()
+                        {
+                            lint_cache.insert(
+                                file_system,
+                                &module_to_lint.path,
+                                source_text,
+                                &module_records,
+                                &file_messages,
+                            );
                         }
 
-                        messages.lock().unwrap().extend(
-                            section_messages
-                        );
+                        // Messages of a cancelled run are incomplete.
+                        if !cancellation_token.is_cancelled() {
+                            (callback.lock().unwrap())(FileDiagnostics {
+                                path: path.to_path_buf(),
+                                messages: file_messages,
+                            });
+                        }
                     },
                 );
                 },
             );
         });
-
-        messages.into_inner().unwrap()
     }
 
     #[cfg(test)]
@@ -749,8 +1434,9 @@ impl Runtime {
         let paths_set: IndexSet<Arc<OsStr>, FxBuildHasher> = paths.into_iter().collect();
 
         let messages = Mutex::new(Vec::<Message>::new());
+        let cancellation_token = &CancellationToken::new();
         rayon::scope(|scope| {
-            self.resolve_modules(file_system, &paths_set, scope, check_syntax_errors, Some(tx_error), |me, mut module| {
+            self.resolve_modules(file_system, &paths_set, scope, check_syntax_errors, Some(tx_error), cancellation_token, false, |me, mut module| {
                 module.content.with_dependent_mut(
                     |allocator_guard, ModuleContentDependent { source_text: _, section_contents }| {
                         assert_eq!(module.section_module_records.len(), section_contents.len());
@@ -760,6 +1446,7 @@ impl Runtime {
                             .into_iter()
                             .zip(section_contents.drain(..))
                             .filter_map(|(record_result, section)| match record_result {
+                                Ok(_) if me.linter.options().syntax_only => None,
                                 Ok(module_record) => Some(ContextSubHost::new_with_framework_options(
                                     section.semantic.unwrap(),
                                     Arc::clone(&module_record),
@@ -807,7 +1494,15 @@ impl Runtime {
         path: &Arc<OsStr>,
         check_syntax_errors: bool,
         tx_error: Option<&DiagnosticSender>,
+        cancellation_token: &CancellationToken,
     ) -> ModuleProcessOutput<'a> {
+        if cancellation_token.is_cancelled() {
+            return ModuleProcessOutput {
+                path: Arc::clone(path),
+                processed_module: ProcessedModule::default(),
+            };
+        }
+        let _current_file = CurrentFileGuard::new(path);
         let processed_module = self
             .process_path_to_module(file_system, paths, path, check_syntax_errors, tx_error)
             .unwrap_or_default();
@@ -822,33 +1517,39 @@ impl Runtime {
         check_syntax_errors: bool,
         tx_error: Option<&DiagnosticSender>,
     ) -> Option<ProcessedModule<'a>> {
-        let ext = Path::new(path).extension().and_then(OsStr::to_str)?;
-
-        if SourceType::from_path(Path::new(path))
-            .as_ref()
-            .is_err_and(|_| !LINT_PARTIAL_LOADER_EXTENSIONS.contains(&ext))
-        {
+        let processor = self.linter.processor(Path::new(path));
+        let Some(ext) = self.lintable_extension(Path::new(path), processor) else {
+            if paths.contains(path) {
+                self.store_file_status(Path::new(path), FileStatus::SkippedUnsupported);
+            }
             return None;
-        }
+        };
 
         let allocator_guard = self.allocator_pool.get();
 
         if paths.contains(path) {
             let mut records =
                 SmallVec::<[Result<ResolvedModuleRecord, Vec<OxcDiagnostic>>; 1]>::new();
+            let mut cached_messages = None;
 
             let module_content = ModuleContent::try_new(allocator_guard, |allocator_guard| {
                 let allocator = &**allocator_guard;
 
-                let Some(stt) =
-                    Self::get_source_type_and_text(file_system, Path::new(path), ext, allocator)
-                else {
+                let Some(stt) = self.get_source_type_and_text(
+                    file_system,
+                    Path::new(path),
+                    ext,
+                    processor,
+                    allocator,
+                ) else {
+                    self.store_file_status(Path::new(path), FileStatus::SkippedUnsupported);
                     return Err(());
                 };
 
                 let (source_type, source_text) = match stt {
                     Ok(v) => v,
                     Err(e) => {
+                        self.store_file_status(Path::new(path), FileStatus::ParseFailed);
                         if let Some(tx_error) = tx_error {
                             tx_error.send(vec![e]).unwrap();
                         }
@@ -857,25 +1558,42 @@ impl Runtime {
                 };
 
                 let mut section_contents = SmallVec::new();
-                records = self.process_source(
-                    Path::new(path),
-                    ext,
-                    check_syntax_errors,
-                    source_type,
-                    source_text,
-                    allocator,
-                    Some(&mut section_contents),
-                );
+                cached_messages = self.lint_cache.as_ref().and_then(|lint_cache| {
+                    lint_cache.get(file_system, path, ContentHash::new(source_text))
+                });
+                // A cached module is only processed for the module graph, like a dependency.
+                if cached_messages.is_none() || self.resolver.is_some() {
+                    records = self.process_source(
+                        Path::new(path),
+                        ext,
+                        processor,
+                        check_syntax_errors,
+                        source_type,
+                        source_text,
+                        allocator,
+                        cached_messages.is_none().then_some(&mut section_contents),
+                    );
+                }
 
                 Ok(ModuleContentDependent { source_text, section_contents })
             });
             let module_content = module_content.ok()?;
 
-            Some(ProcessedModule { section_module_records: records, content: Some(module_content) })
+            Some(ProcessedModule {
+                section_module_records: records,
+                content: Some(module_content),
+                cached_messages,
+            })
         } else {
             let allocator = &*allocator_guard;
 
-            let stt = Self::get_source_type_and_text(file_system, Path::new(path), ext, allocator)?;
+            let stt = self.get_source_type_and_text(
+                file_system,
+                Path::new(path),
+                ext,
+                processor,
+                allocator,
+            )?;
 
             let (source_type, source_text) = match stt {
                 Ok(v) => v,
@@ -890,6 +1608,7 @@ impl Runtime {
             let records = self.process_source(
                 Path::new(path),
                 ext,
+                processor,
                 check_syntax_errors,
                 source_type,
                 source_text,
@@ -897,7 +1616,11 @@ impl Runtime {
                 None,
             );
 
-            Some(ProcessedModule { section_module_records: records, content: None })
+            Some(ProcessedModule {
+                section_module_records: records,
+                content: None,
+                cached_messages: None,
+            })
         }
     }
 
@@ -906,14 +1629,32 @@ impl Runtime {
         &self,
         path: &Path,
         ext: &str,
+        processor: Option<ExternalProcessorId>,
         check_syntax_errors: bool,
         source_type: SourceType,
         source_text: &'a str,
         allocator: &'a Allocator,
         mut out_sections: Option<&mut SectionContents<'a>>,
     ) -> SmallVec<[Result<ResolvedModuleRecord, Vec<OxcDiagnostic>>; 1]> {
-        let section_sources = PartialLoader::parse(ext, source_text)
-            .unwrap_or_else(|| vec![JavaScriptSource::partial(source_text, source_type, 0)]);
+        let section_sources = if let Err(diagnostic) = self.check_source_limits(path, source_text) {
+            Err(diagnostic)
+        } else if let Some(processor_id) = processor {
+            self.linter.preprocess(processor_id, path, source_text)
+        } else {
+            Ok(PartialLoader::parse(ext, source_text)
+                .unwrap_or_else(|| vec![JavaScriptSource::partial(source_text, source_type, 0)]))
+        };
+        let section_sources = match section_sources {
+            Ok(section_sources) => section_sources,
+            Err(diagnostic) => {
+                // Keep records and sections the same length, the file has no lintable section.
+                if let Some(sections) = &mut out_sections {
+                    let source = JavaScriptSource::partial(&source_text[..0], source_type, 0);
+                    sections.push(SectionContent { source, semantic: None });
+                }
+                return smallvec![Err(vec![diagnostic])];
+            }
+        };
 
         let mut section_module_records = SmallVec::<
             [Result<ResolvedModuleRecord, Vec<OxcDiagnostic>>; 1],
@@ -968,51 +1709,153 @@ impl Runtime {
         source_type: SourceType,
         check_syntax_errors: bool,
     ) -> Result<(ResolvedModuleRecord, Semantic<'a>), Vec<OxcDiagnostic>> {
-        let ret = Parser::new(allocator, source_text, source_type)
-            .with_options(ParseOptions {
-                parse_regular_expression: true,
-                allow_return_outside_function: true,
-                ..ParseOptions::default()
-            })
-            .parse();
+        let (program, parser_module_record, irregular_whitespaces) = if is_json_path(path) {
+            let program = parse_json(allocator, source_text, source_type, is_jsonc_path(path))
+                .map_err(parse_errors)?;
+            (program, oxc_syntax::module_record::ModuleRecord::new(allocator), Box::default())
+        } else {
+            let ret = Parser::new(allocator, source_text, source_type)
+                .with_options(ParseOptions {
+                    parse_regular_expression: true,
+                    allow_return_outside_function: true,
+                    ..ParseOptions::default()
+                })
+                .parse();
 
-        if !ret.errors.is_empty() {
-            return Err(if ret.is_flow_language { vec![] } else { ret.errors });
-        }
+            if !ret.errors.is_empty() {
+                return Err(if ret.is_flow_language { vec![] } else { parse_errors(ret.errors) });
+            }
+            (ret.program, ret.module_record, ret.irregular_whitespaces)
+        };
+
+        // Rules are not run when only syntax errors are reported, so they need neither a control
+        // flow graph nor resolved imports.
+        let syntax_only = self.linter.options().syntax_only;
+        // Subsystems only used by some plugins are set up for files whose configuration uses
+        // them, e.g. imports are only resolved for files the import plugin is enabled for.
+        let state = (!syntax_only).then(|| self.linter.config.resolve(path));
 
         let semantic_ret = SemanticBuilder::new()
-            .with_cfg(true)
-            .with_scope_tree_child_ids(true)
+            .with_cfg(!syntax_only)
+            .with_jsdoc(state.as_ref().is_some_and(ResolvedLinterState::uses_jsdoc))
+            .with_scope_tree_child_ids(!syntax_only)
             .with_check_syntax_error(check_syntax_errors)
-            .build(allocator.alloc(ret.program));
+            .build(allocator.alloc(program));
 
         if !semantic_ret.errors.is_empty() {
-            return Err(semantic_ret.errors);
+            return Err(parse_errors(semantic_ret.errors));
         }
 
         let mut semantic = semantic_ret.semantic;
-        semantic.set_irregular_whitespaces(ret.irregular_whitespaces);
+        semantic.set_irregular_whitespaces(irregular_whitespaces);
+
+        self.check_file_limits(path, &semantic).map_err(|diagnostic| vec![diagnostic])?;
 
-        let module_record = Arc::new(ModuleRecord::new(path, &ret.module_record, &semantic));
+        let mut module_record = ModuleRecord::new(path, &parser_module_record, &semantic);
 
         let mut resolved_module_requests: Vec<ResolvedModuleRequest> = vec![];
 
         // If import plugin is enabled.
-        if let Some(resolver) = &self.resolver {
+        if let Some(resolvers) = &self.resolver
+            && let Some(state) = state.filter(|state| state.config.plugins.has_import())
+        {
+            // Only modules importing this module need to know whether it has side effects.
+            module_record.compute_side_effects(&semantic);
             // Retrieve all dependent modules from this module.
             let dir = path.parent().unwrap();
+            module_record.resolved_modules =
+                resolvers.with_resolver(&state.config.settings, |resolver| {
+                    module_record
+                        .requested_modules
+                        .keys()
+                        .filter_map(|specifier| {
+                            let resolution = resolver.resolve(dir, specifier).ok()?;
+                            Some((specifier.clone(), resolution.into_path_buf()))
+                        })
+                        .collect()
+                });
             resolved_module_requests = module_record
-                .requested_modules
-                .keys()
-                .filter_map(|specifier| {
-                    let resolution = resolver.resolve(dir, specifier).ok()?;
-                    Some(ResolvedModuleRequest {
-                        specifier: specifier.clone(),
-                        resolved_requested_path: Arc::<OsStr>::from(resolution.path().as_os_str()),
-                    })
+                .resolved_modules
+                .iter()
+                .map(|(specifier, resolved_path)| ResolvedModuleRequest {
+                    specifier: specifier.clone(),
+                    resolved_requested_path: Arc::<OsStr>::from(resolved_path.as_os_str()),
                 })
                 .collect();
         }
+        let module_record = Arc::new(module_record);
         Ok((ResolvedModuleRecord { module_record, resolved_module_requests }, semantic))
     }
 }
+
+/// Whether brackets in `source_text` are nested deeper than `max_depth`.
+///
+/// Brackets in strings, template strings and comments are skipped, regular expressions are not
+/// recognized. A pattern such as `/[(]/` adds to the depth, which only matters for files which are
+/// close to the limit anyway.
+fn bracket_depth_exceeds(source_text: &str, max_depth: usize) -> bool {
+    let bytes = source_text.as_bytes();
+    let mut depth = 0usize;
+    // For each open `{`, whether it is a `${` which returns to a template string when closed
+    let mut braces = Vec::<bool>::new();
+    let mut in_template = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        let byte = bytes[i];
+        i += 1;
+        if in_template {
+            match byte {
+                b'\\' => i += 1,
+                b'`' => in_template = false,
+                b'$' if bytes.get(i) == Some(&b'{') => {
+                    i += 1;
+                    in_template = false;
+                    braces.push(true);
+                    depth += 1;
+                }
+                _ => {}
+            }
+        } else {
+            match byte {
+                b'(' | b'[' | b'{' => {
+                    if byte == b'{' {
+                        braces.push(false);
+                    }
+                    depth += 1;
+                }
+                b')' | b']' | b'}' => {
+                    if byte == b'}' && braces.pop() == Some(true) {
+                        in_template = true;
+                    }
+                    depth = depth.saturating_sub(1);
+                }
+                b'`' => in_template = true,
+                b'\'' | b'"' => {
+                    while i < bytes.len() && bytes[i] != byte && bytes[i] != b'\n' {
+                        i += if bytes[i] == b'\\' { 2 } else { 1 };
+                    }
+                    i += 1;
+                }
+                b'/' if bytes.get(i) == Some(&b'/') => {
+                    while i < bytes.len() && bytes[i] != b'\n' {
+                        i += 1;
+                    }
+                }
+                b'/' if bytes.get(i) == Some(&b'*') => {
+                    i = source_text[i + 1..].find("*/").map_or(bytes.len(), |end| i + 1 + end + 2);
+                }
+                _ => {}
+            }
+            if depth > max_depth {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// A diagnostic for a file which is skipped, because it exceeds one of the [`FileLimits`].
+fn file_limit_exceeded(path: &Path, reason: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("File {} skipped: {reason}.", path.display()))
+        .with_help("This is likely a generated or minified file. Add it to `ignorePatterns`, or raise the limit.")
+}
@@ -0,0 +1,219 @@
+//! Utilities for testing tools embedding the linter, enabled with the `test-util` feature.
+
+use std::{
+    ffi::OsStr,
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::Allocator;
+
+use crate::{
+    CancellationToken, Config, ConfigStore, ConfigStoreBuilder, ExternalPluginStore,
+    FileDiagnostics, LintIgnoreMatcher, LintOptions, LintService, LintServiceOptions, Linter,
+    Oxlintrc, RuntimeFileSystem, loader::Loader,
+};
+
+const CONFIG_FILE_NAME: &str = ".oxlintrc.json";
+
+/// A project defined in memory, linted with the same pipeline as `oxlint` without touching the
+/// file system.
+///
+/// `.oxlintrc.json` files are used the same way as nested configuration files of `oxlint`: each
+/// applies to the files in its directory and subdirectories, and the one at the root is also the
+/// base configuration. All other files which can be linted are linted.
+///
+/// Configurations cannot extend other configuration files, and imports are not resolved.
+///
+/// ```
+/// use oxc_linter::test_util::VirtualWorkspace;
+///
+/// let results = VirtualWorkspace::new()
+///     .with_file(".oxlintrc.json", r#"{ "rules": { "no-var": "error" } }"#)
+///     .with_file("src/index.js", "var a = 1;")
+///     .lint()
+///     .unwrap();
+/// assert_eq!(results[0].path.to_str(), Some("src/index.js"));
+/// ```
+#[derive(Debug, Default)]
+pub struct VirtualWorkspace {
+    /// Contents keyed by paths relative to the root of the workspace
+    files: FxHashMap<PathBuf, String>,
+}
+
+impl VirtualWorkspace {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a file at `path`, relative to the root of the workspace.
+    #[must_use]
+    pub fn with_file(mut self, path: impl AsRef<Path>, source_text: impl Into<String>) -> Self {
+        self.files.insert(path.as_ref().to_path_buf(), source_text.into());
+        self
+    }
+
+    /// Lint all files of the workspace, and return the diagnostics of each file with diagnostics,
+    /// in order of their paths. Paths are relative to the root of the workspace.
+    ///
+    /// # Errors
+    ///
+    /// If a configuration file is not valid.
+    ///
+    /// # Panics
+    ///
+    /// If a configuration file has no parent directory.
+    pub fn lint(&self) -> Result<Vec<FileDiagnostics>, String> {
+        let root = Self::root();
+        let mut external_plugin_store = ExternalPluginStore::default();
+
+        let mut base_config = None;
+        let mut nested_configs = FxHashMap::<PathBuf, Config>::default();
+        let mut nested_ignore_patterns = vec![];
+        for (path, source_text) in &self.files {
+            if path.file_name() != Some(OsStr::new(CONFIG_FILE_NAME)) {
+                continue;
+            }
+            let config_path = root.join(path);
+            let oxlintrc = Oxlintrc::from_inline(source_text, &config_path)
+                .map_err(|err| format!("{}: {}", path.display(), err.message))?;
+            let dir = config_path.parent().unwrap().to_path_buf();
+            nested_ignore_patterns.push((oxlintrc.ignore_patterns.clone(), dir.clone()));
+            let config = ConfigStoreBuilder::from_oxlintrc(
+                false,
+                oxlintrc,
+                None,
+                &mut external_plugin_store,
+            )
+            .and_then(|builder| builder.build(&external_plugin_store))
+            .map_err(|err| format!("{}: {err}", path.display()))?;
+            if dir == root {
+                base_config = Some(config.clone());
+            }
+            nested_configs.insert(dir, config);
+        }
+
+        let base_config = match base_config {
+            Some(config) => config,
+            None => ConfigStoreBuilder::default()
+                .build(&external_plugin_store)
+                .map_err(|err| err.to_string())?,
+        };
+        let ignore_matcher = LintIgnoreMatcher::new(&[], &root, nested_ignore_patterns);
+
+        let mut paths = self
+            .files
+            .keys()
+            .map(|path| root.join(path))
+            .filter(|path| Loader::can_load(path) && !ignore_matcher.should_ignore(path))
+            .map(|path| Arc::<OsStr>::from(path.into_os_string()))
+            .collect::<Vec<_>>();
+        paths.sort_unstable();
+
+        let config_store = ConfigStore::new(base_config, nested_configs, external_plugin_store);
+        let linter = Linter::new(LintOptions::default(), config_store, None);
+        let service = LintService::new(linter, LintServiceOptions::new(root.clone()));
+
+        let file_system = VirtualFileSystem { root: root.clone(), files: &self.files };
+        let mut results = Vec::new();
+        service.run_with_callback(&file_system, paths, &CancellationToken::new(), |mut file| {
+            if file.messages.is_empty() {
+                return;
+            }
+            file.path = file.path.strip_prefix(&root).map(Path::to_path_buf).unwrap_or(file.path);
+            file.messages.sort_by_key(|message| message.span);
+            results.push(file);
+        });
+
+        results.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+        Ok(results)
+    }
+
+    /// Root of the workspace. It does not exist on disk, paths below it are only looked up in
+    /// memory.
+    fn root() -> PathBuf {
+        if cfg!(windows) { PathBuf::from("C:\\virtual") } else { PathBuf::from("/virtual") }
+    }
+}
+
+struct VirtualFileSystem<'w> {
+    root: PathBuf,
+    files: &'w FxHashMap<PathBuf, String>,
+}
+
+impl RuntimeFileSystem for VirtualFileSystem<'_> {
+    fn read_to_arena_str<'a>(
+        &'a self,
+        path: &Path,
+        allocator: &'a Allocator,
+    ) -> Result<&'a str, io::Error> {
+        let source_text = path
+            .strip_prefix(&self.root)
+            .ok()
+            .and_then(|path| self.files.get(path))
+            .ok_or(io::ErrorKind::NotFound)?;
+        Ok(allocator.alloc_str(source_text))
+    }
+
+    fn write_file(&self, path: &Path, _content: &str) -> Result<(), io::Error> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("Cannot write {} in a virtual workspace", path.display()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::VirtualWorkspace;
+
+    #[test]
+    fn nested_configs() {
+        let results = VirtualWorkspace::new()
+            .with_file(".oxlintrc.json", r#"{ "rules": { "no-var": "error" } }"#)
+            .with_file("a.js", "var a = 1; debugger; console.log(a);")
+            .with_file(
+                "packages/b/.oxlintrc.json",
+                r#"{ "rules": { "no-debugger": "off", "eqeqeq": "error" }, "ignorePatterns": ["dist"] }"#,
+            )
+            .with_file("packages/b/b.js", "var b = 1; if (b == 1) { debugger; }")
+            .with_file("packages/b/dist/c.js", "debugger;")
+            .with_file("README.md", "debugger;")
+            .lint()
+            .unwrap();
+
+        let codes = results
+            .iter()
+            .map(|file| {
+                let codes = file
+                    .messages
+                    .iter()
+                    .map(|message| message.error.code.to_string())
+                    .collect::<Vec<_>>();
+                (file.path.as_path(), codes)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            [
+                (
+                    Path::new("a.js"),
+                    vec!["eslint(no-var)".to_string(), "eslint(no-debugger)".to_string()]
+                ),
+                // Nested configurations do not inherit from their parents, as in `oxlint`.
+                (Path::new("packages/b/b.js"), vec!["eslint(eqeqeq)".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_config() {
+        let result = VirtualWorkspace::new().with_file(".oxlintrc.json", "{ rules: }").lint();
+        assert!(result.is_err_and(|err| err.starts_with(".oxlintrc.json: ")));
+    }
+}
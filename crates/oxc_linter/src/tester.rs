@@ -9,6 +9,7 @@ use std::{
 use cow_utils::CowUtils;
 use oxc_span::SourceType;
 use rustc_hash::FxHashMap;
+use schemars::SchemaGenerator;
 use serde::Deserialize;
 use serde_json::{Value, json};
 
@@ -18,6 +19,7 @@ use oxc_diagnostics::{GraphicalReportHandler, GraphicalTheme, NamedSource};
 use crate::{
     AllowWarnDeny, ConfigStore, ConfigStoreBuilder, LintPlugins, LintService, LintServiceOptions,
     Linter, Oxlintrc, RuleEnum,
+    config::check_options,
     external_plugin_store::ExternalPluginStore,
     fixer::{FixKind, Fixer},
     options::LintOptions,
@@ -187,7 +189,7 @@ where
     }
 }
 
-struct TesterFileSystem {
+pub struct TesterFileSystem {
     path_to_lint: PathBuf,
     source_text: String,
 }
@@ -236,6 +238,9 @@ pub struct Tester {
     snapshot_suffix: Option<&'static str>,
     current_working_directory: Box<Path>,
     plugins: LintPlugins,
+    /// Options of test cases must match the schema of the rule, since options which do not are
+    /// reported in configuration files.
+    allow_invalid_options: bool,
 }
 
 impl Tester {
@@ -263,6 +268,7 @@ impl Tester {
             snapshot_suffix: None,
             current_working_directory,
             plugins: LintPlugins::default(),
+            allow_invalid_options: false,
         }
     }
 
@@ -389,6 +395,16 @@ impl Tester {
         self
     }
 
+    /// Intentionally allow test cases with options which do not match the schema of the rule.
+    ///
+    /// This should only be used for test cases checking that the rule falls back to its defaults
+    /// for invalid options.
+    #[must_use]
+    pub fn intentionally_allow_invalid_options(mut self) -> Self {
+        self.allow_invalid_options = true;
+        self
+    }
+
     pub fn test(&mut self) {
         self.test_pass();
         self.test_fail();
@@ -502,7 +518,15 @@ impl Tester {
         fix_kind: ExpectFixKind,
         fix_index: u8,
     ) -> TestResult {
-        let rule = self.find_rule().read_json(rule_config.unwrap_or_default());
+        // Options not matching the schema of the rule are reported in configuration files, so
+        // test cases must match it.
+        if !self.allow_invalid_options
+            && let Some(Value::Array(options)) = &rule_config
+            && let Err(reason) =
+                check_options(self.find_rule(), options, &mut SchemaGenerator::default())
+        {
+            panic!("Options {} do not match the schema of the rule: {reason}", json!(options));
+        }
         let mut external_plugin_store = ExternalPluginStore::default();
         let linter = Linter::new(
             self.lint_options,
@@ -524,7 +548,7 @@ impl Tester {
                                 panic!("invalid plugin name: {}", self.plugin_name)
                             }),
                     )
-                    .with_rule(rule, AllowWarnDeny::Warn)
+                    .with_rule(self.find_rule(), AllowWarnDeny::Warn, rule_config)
                     .build(&external_plugin_store)
                     .unwrap(),
                 FxHashMap::default(),
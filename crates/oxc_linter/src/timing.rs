@@ -0,0 +1,32 @@
+//! Time spent running each rule, collected with [`Linter::with_rule_timings`](crate::Linter::with_rule_timings).
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use rustc_hash::FxHashMap;
+
+/// Timings keyed by the plugin name and the name of the rule, e.g. `("eslint", "no-debugger")`.
+pub type RuleTimingsMap = Arc<Mutex<FxHashMap<(&'static str, &'static str), RuleTiming>>>;
+
+/// Time spent running a rule over all linted files.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RuleTiming {
+    /// Total time spent running the rule
+    pub duration: Duration,
+    /// Total number of AST nodes of the files the rule ran on
+    pub nodes: usize,
+}
+
+impl RuleTiming {
+    /// Milliseconds spent per 1000 AST nodes, to compare timings of runs on different files.
+    pub fn ms_per_1k_nodes(&self) -> f64 {
+        if self.nodes == 0 {
+            return 0.0;
+        }
+        #[expect(clippy::cast_precision_loss)]
+        let nodes = self.nodes as f64;
+        self.duration.as_secs_f64() * 1000.0 / (nodes / 1000.0)
+    }
+}
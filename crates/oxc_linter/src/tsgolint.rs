@@ -14,7 +14,11 @@ use oxc_span::{SourceType, Span};
 
 use super::{AllowWarnDeny, ConfigStore, DisableDirectives, ResolvedLinterState, read_to_string};
 
-use crate::{CompositeFix, FixKind, Message, PossibleFixes};
+use crate::{
+    CancellationToken, CompositeFix, FixKind, Message, PossibleFixes,
+    interner::intern,
+    type_facts::{TypeFact, TypeFacts},
+};
 
 /// State required to initialize the `tsgolint` linter.
 #[derive(Debug, Clone)]
@@ -189,6 +193,8 @@ impl TsGoLintState {
                         Ok(TsGoLintMessage::Error(err)) => {
                             return Err(err.error);
                         }
+                        // Type facts are only reported when requested by `collect_type_facts`
+                        Ok(TsGoLintMessage::TypeFacts(_)) => {}
                         Ok(TsGoLintMessage::Diagnostic(tsgolint_diagnostic)) => {
                             match tsgolint_diagnostic {
                                 TsGoLintDiagnostic::Rule(tsgolint_diagnostic) => {
@@ -340,11 +346,14 @@ impl TsGoLintState {
     ///
     /// # Errors
     /// A human-readable error message indicating why the linting failed.
+    ///
+    /// If `cancellation_token` is cancelled, the `tsgolint` process is killed and no messages are returned.
     pub fn lint_source(
         &self,
         path: &Arc<OsStr>,
         source_text: String,
         disable_directives_map: Arc<Mutex<FxHashMap<PathBuf, DisableDirectives>>>,
+        cancellation_token: &CancellationToken,
     ) -> Result<Vec<Message>, String> {
         let mut resolved_configs: FxHashMap<PathBuf, ResolvedLinterState> = FxHashMap::default();
         let mut source_overrides = FxHashMap::default();
@@ -359,6 +368,7 @@ impl TsGoLintState {
 
         let fix = self.fix;
         let fix_suggestions = self.fix_suggestions;
+        let cancellation_token = cancellation_token.clone();
         let handler = std::thread::spawn(move || {
             let mut cmd = std::process::Command::new(&executable_path);
             cmd.arg("headless")
@@ -414,6 +424,8 @@ impl TsGoLintState {
                         Ok(TsGoLintMessage::Error(err)) => {
                             return Err(err.error);
                         }
+                        // Type facts are only reported when requested by `collect_type_facts`
+                        Ok(TsGoLintMessage::TypeFacts(_)) => {}
                         Ok(TsGoLintMessage::Diagnostic(tsgolint_diagnostic)) => {
                             match tsgolint_diagnostic {
                                 TsGoLintDiagnostic::Rule(tsgolint_diagnostic) => {
@@ -424,15 +436,15 @@ impl TsGoLintState {
                                         continue;
                                     };
 
-                                    let severity =
+                                    let rule =
                                         resolved_config.rules.iter().find_map(|(rule, status)| {
                                             if rule.name() == tsgolint_diagnostic.rule {
-                                                Some(*status)
+                                                Some((rule.id(), *status))
                                             } else {
                                                 None
                                             }
                                         });
-                                    let Some(severity) = severity else {
+                                    let Some((rule_id, severity)) = rule else {
                                         // If the severity is not found, we should not report the diagnostic
                                         continue;
                                     };
@@ -455,6 +467,7 @@ impl TsGoLintState {
                                     } else {
                                         Severity::Warning
                                     };
+                                    message.origin = Some(resolved_config.rule_origin(rule_id));
 
                                     result.push(message);
                                 }
@@ -473,9 +486,14 @@ impl TsGoLintState {
             });
 
             // Wait for process to complete and stdout processing to finish
-            let exit_status = child.wait().expect("Failed to wait for tsgolint process");
+            let exit_status = wait_or_kill(&mut child, &cancellation_token)
+                .expect("Failed to wait for tsgolint process");
             let stdout_result = stdout_handler.join();
 
+            if cancellation_token.is_cancelled() {
+                return Ok(vec![]);
+            }
+
             if !exit_status.success() {
                 let err_msg = stdout_result.ok().and_then(Result::err).unwrap_or_default();
                 return Err(format!(
@@ -500,6 +518,102 @@ impl TsGoLintState {
         }
     }
 
+    /// Run `tsgolint` to compute type information for the AST nodes of `paths`, without running
+    /// any type-aware rules. The returned facts can be attached to native rules via
+    /// [`LintService::set_type_facts_map`](crate::LintService::set_type_facts_map), so rules which
+    /// need both syntactic context and type information can be implemented in Rust.
+    ///
+    /// # Panics
+    /// - when `stdin` of subprocess cannot be opened
+    /// - when `stdout` of subprocess cannot be opened
+    /// - when `tsgolint` process cannot be awaited
+    ///
+    /// # Errors
+    /// A human-readable error message indicating why computing the type facts failed.
+    pub fn collect_type_facts(
+        &self,
+        paths: &[Arc<OsStr>],
+    ) -> Result<FxHashMap<PathBuf, Arc<TypeFacts>>, String> {
+        let mut resolved_configs: FxHashMap<PathBuf, ResolvedLinterState> = FxHashMap::default();
+
+        let mut json_input = self.json_input(paths, None, &mut resolved_configs);
+        if json_input.configs.is_empty() {
+            return Ok(FxHashMap::default());
+        }
+        // Only type information is needed, so do not run any rules.
+        json_input.configs = vec![Config {
+            file_paths: json_input
+                .configs
+                .into_iter()
+                .flat_map(|config| config.file_paths)
+                .collect(),
+            rules: vec![],
+        }];
+        json_input.report_syntactic = false;
+        json_input.report_semantic = false;
+        json_input.report_type_facts = true;
+
+        let mut cmd = std::process::Command::new(&self.executable_path);
+        cmd.arg("headless")
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(stderr());
+
+        let mut child = cmd.spawn().map_err(|e| {
+            format!(
+                "Failed to spawn tsgolint from path `{}`, with error: {e}",
+                self.executable_path.display()
+            )
+        })?;
+
+        let mut stdin = child.stdin.take().expect("Failed to open tsgolint stdin");
+        let json = serde_json::to_string(&json_input).expect("Failed to serialize JSON");
+        if let Err(e) = stdin.write_all(json.as_bytes()) {
+            // If the child closed stdin early, avoid crashing on SIGPIPE/BrokenPipe.
+            if e.kind() != ErrorKind::BrokenPipe {
+                return Err(format!("Failed to write to tsgolint stdin: {e}"));
+            }
+        }
+        // Explicitly drop stdin to send EOF to the child.
+        drop(stdin);
+
+        let stdout = child.stdout.take().expect("Failed to open tsgolint stdout");
+
+        let mut type_facts = FxHashMap::default();
+        let mut stream_result = Ok(());
+        for msg in TsGoLintMessageStream::new(stdout) {
+            match msg {
+                Ok(TsGoLintMessage::TypeFacts(facts)) => {
+                    type_facts.insert(facts.file_path, Arc::new(facts.facts));
+                }
+                Ok(TsGoLintMessage::Error(err)) => {
+                    stream_result = Err(err.error);
+                    break;
+                }
+                // No rules are run, so only internal diagnostics can be reported here.
+                // They are reported again when linting, so ignore them.
+                Ok(TsGoLintMessage::Diagnostic(_)) => {}
+                Err(e) => {
+                    stream_result = Err(e);
+                    break;
+                }
+            }
+        }
+
+        let exit_status = child.wait().expect("Failed to wait for tsgolint process");
+        if !exit_status.success() {
+            return Err(match stream_result {
+                Err(err) => {
+                    format!("Error running tsgolint: exit status: {exit_status}, error: {err}")
+                }
+                Ok(()) => format!("Error running tsgolint: exit status: {exit_status}"),
+            });
+        }
+        stream_result.map_err(|err| format!("Error running tsgolint: {err}"))?;
+
+        Ok(type_facts)
+    }
+
     /// Create a JSON input for STDIN of tsgolint in this format:
     ///
     /// ```json
@@ -563,6 +677,7 @@ impl TsGoLintState {
             source_overrides,
             report_syntactic: self.type_check,
             report_semantic: self.type_check,
+            report_type_facts: false,
         }
     }
 }
@@ -590,6 +705,8 @@ pub struct Payload {
     pub source_overrides: Option<FxHashMap<String, String>>,
     pub report_syntactic: bool,
     pub report_semantic: bool,
+    /// Report type information for AST nodes, instead of only diagnostics.
+    pub report_type_facts: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -677,6 +794,22 @@ struct TsGoLintDiagnosticPayload {
     pub suggestions: Vec<Suggestion>,
 }
 
+/// Represents the type facts payload from `tsgolint`, reported once per file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TsGoLintTypeFactsPayload {
+    pub file_path: String,
+    pub facts: Vec<TypeFactPayload>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TypeFactPayload {
+    pub range: Range,
+    #[serde(rename = "type")]
+    pub type_text: String,
+    #[serde(default)]
+    pub is_thenable: bool,
+}
+
 /// Represents the error payload from `tsgolint`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct TsGoLintErrorPayload {
@@ -687,6 +820,7 @@ struct TsGoLintErrorPayload {
 pub enum TsGoLintMessage {
     Diagnostic(TsGoLintDiagnostic),
     Error(TsGoLintError),
+    TypeFacts(TsGoLintTypeFacts),
 }
 
 #[derive(Debug, Clone)]
@@ -717,6 +851,12 @@ pub struct TsGoLintError {
     pub error: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct TsGoLintTypeFacts {
+    pub file_path: PathBuf,
+    pub facts: TypeFacts,
+}
+
 impl From<TsGoLintDiagnostic> for OxcDiagnostic {
     fn from(val: TsGoLintDiagnostic) -> Self {
         match val {
@@ -730,7 +870,7 @@ impl From<TsGoLintRuleDiagnostic> for OxcDiagnostic {
     fn from(val: TsGoLintRuleDiagnostic) -> Self {
         let mut d = OxcDiagnostic::warn(val.message.description)
             .with_label(val.span)
-            .with_error_code("typescript-eslint", val.rule);
+            .with_error_code("typescript-eslint", intern(&val.rule));
         if let Some(help) = val.message.help {
             d = d.with_help(help);
         }
@@ -769,6 +909,7 @@ impl Message {
                     content: Cow::Owned(fix.text),
                     span: Span::new(fix.range.pos, fix.range.end),
                     message: None,
+                    kind: FixKind::SafeFix,
                 })
                 .collect();
 
@@ -794,6 +935,7 @@ impl Message {
                         content: Cow::Owned(fix.text),
                         span: Span::new(fix.range.pos, fix.range.end),
                         message: Some(Cow::Owned(message)),
+                        kind: FixKind::Suggestion,
                     }
                 })
                 .collect();
@@ -844,6 +986,7 @@ pub struct Suggestion {
 pub enum MessageType {
     Error = 0,
     Diagnostic = 1,
+    TypeFacts = 2,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -864,6 +1007,7 @@ impl TryFrom<u8> for MessageType {
         match value {
             0 => Ok(Self::Error),
             1 => Ok(Self::Diagnostic),
+            2 => Ok(Self::TypeFacts),
             _ => Err(InvalidMessageType(value)),
         }
     }
@@ -927,6 +1071,7 @@ enum TsGoLintMessageParseError {
     InvalidMessageType(InvalidMessageType),
     InvalidErrorPayload(serde_json::Error),
     InvalidDiagnosticPayload(serde_json::Error),
+    InvalidTypeFactsPayload(serde_json::Error),
 }
 
 impl std::fmt::Display for TsGoLintMessageParseError {
@@ -940,6 +1085,9 @@ impl std::fmt::Display for TsGoLintMessageParseError {
             TsGoLintMessageParseError::InvalidDiagnosticPayload(e) => {
                 write!(f, "Failed to parse tsgolint diagnostic payload: {e}")
             }
+            TsGoLintMessageParseError::InvalidTypeFactsPayload(e) => {
+                write!(f, "Failed to parse tsgolint type facts payload: {e}")
+            }
         }
     }
 }
@@ -952,10 +1100,8 @@ fn should_skip_diagnostic(
     let span = tsgolint_diagnostic.span;
 
     if let Some(directives) = disable_directives_map.get(path) {
-        directives.contains(&tsgolint_diagnostic.rule, span)
-            || directives.contains(&format!("typescript-eslint/{}", tsgolint_diagnostic.rule), span)
-            || directives
-                .contains(&format!("@typescript-eslint/{}", tsgolint_diagnostic.rule), span)
+        // Also matches `typescript-eslint/rule` and `@typescript-eslint/rule`
+        directives.contains_rule("typescript", &tsgolint_diagnostic.rule, span)
     } else {
         debug_assert!(
             false,
@@ -1033,6 +1179,44 @@ fn parse_single_message(
                 }
             }))
         }
+        MessageType::TypeFacts => {
+            let type_facts_payload = serde_json::from_str::<TsGoLintTypeFactsPayload>(&payload_str)
+                .map_err(TsGoLintMessageParseError::InvalidTypeFactsPayload)?;
+
+            Ok(TsGoLintMessage::TypeFacts(TsGoLintTypeFacts {
+                file_path: PathBuf::from(type_facts_payload.file_path),
+                facts: TypeFacts::new(
+                    type_facts_payload
+                        .facts
+                        .into_iter()
+                        .map(|fact| TypeFact {
+                            span: Span::new(fact.range.pos, fact.range.end),
+                            type_text: fact.type_text,
+                            is_thenable: fact.is_thenable,
+                        })
+                        .collect(),
+                ),
+            }))
+        }
+    }
+}
+
+/// Wait for the `tsgolint` process to exit, killing it if `cancellation_token` is cancelled first.
+/// Killing the process closes its stdout, which also ends any stream reading from it.
+fn wait_or_kill(
+    child: &mut std::process::Child,
+    cancellation_token: &CancellationToken,
+) -> std::io::Result<std::process::ExitStatus> {
+    loop {
+        if let Some(exit_status) = child.try_wait()? {
+            return Ok(exit_status);
+        }
+        if cancellation_token.is_cancelled() {
+            // Fails if the process exited in the meantime, which is fine.
+            let _ = child.kill();
+            return child.wait();
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
     }
 }
 
@@ -1112,7 +1296,7 @@ mod test {
     use oxc_span::Span;
 
     use crate::{
-        fixer::{Message, PossibleFixes},
+        fixer::{FixKind, Message, PossibleFixes},
         tsgolint::{Fix, Range, RuleMessage, Suggestion, TsGoLintRuleDiagnostic},
     };
 
@@ -1174,6 +1358,7 @@ mod test {
                 content: "fixedhello".into(),
                 span: Span::new(0, 10),
                 message: None,
+                kind: FixKind::SafeFix,
             })
         );
     }
@@ -1222,11 +1407,13 @@ mod test {
                     content: "hello".into(),
                     span: Span::new(0, 5),
                     message: Some("Suggestion 1".into()),
+                    kind: FixKind::Suggestion,
                 },
                 crate::fixer::Fix {
                     content: "helloworld".into(),
                     span: Span::new(0, 10),
                     message: Some("Suggestion 2".into()),
+                    kind: FixKind::Suggestion,
                 },
             ])
         );
@@ -1260,11 +1447,17 @@ mod test {
         assert_eq!(
             message.fixes,
             PossibleFixes::Multiple(vec![
-                crate::fixer::Fix { content: "fixed".into(), span: Span::new(0, 5), message: None },
+                crate::fixer::Fix {
+                    content: "fixed".into(),
+                    span: Span::new(0, 5),
+                    message: None,
+                    kind: FixKind::SafeFix,
+                },
                 crate::fixer::Fix {
                     content: "Suggestion 1".into(),
                     span: Span::new(0, 5),
                     message: Some("Suggestion 1".into()),
+                    kind: FixKind::Suggestion,
                 },
             ])
         );
@@ -1344,6 +1537,89 @@ mod test {
         assert_eq!(payload.suggestions.len(), 0);
     }
 
+    #[test]
+    fn test_parse_type_facts_message() {
+        use super::{TsGoLintMessage, parse_single_message};
+
+        let payload = br#"{
+            "file_path": "/project/test.ts",
+            "facts": [
+                {"range": {"pos": 20, "end": 27}, "type": "Promise<void>", "is_thenable": true},
+                {"range": {"pos": 0, "end": 3}, "type": "number"}
+            ]
+        }"#;
+        let mut bytes = u32::try_from(payload.len()).unwrap().to_le_bytes().to_vec();
+        bytes.push(2);
+        bytes.extend_from_slice(payload);
+
+        let Ok(TsGoLintMessage::TypeFacts(type_facts)) =
+            parse_single_message(&mut std::io::Cursor::new(bytes.as_slice()))
+        else {
+            panic!("expected a type facts message");
+        };
+
+        assert_eq!(type_facts.file_path, std::path::PathBuf::from("/project/test.ts"));
+        assert_eq!(type_facts.facts.len(), 2);
+        let promise = type_facts.facts.get(Span::new(20, 27)).unwrap();
+        assert_eq!(promise.type_text, "Promise<void>");
+        assert!(promise.is_thenable);
+        assert!(!type_facts.facts.get(Span::new(0, 3)).unwrap().is_thenable);
+    }
+
+    /// Run `collect_type_facts` against a stand-in for `tsgolint`, which records its input and
+    /// replies with the type facts of `test.ts`.
+    #[test]
+    #[cfg(unix)]
+    fn test_collect_type_facts() {
+        use std::{ffi::OsStr, fs, os::unix::fs::PermissionsExt, sync::Arc};
+
+        use rustc_hash::FxHashMap;
+
+        use super::TsGoLintState;
+        use crate::{ConfigStore, ConfigStoreBuilder, ExternalPluginStore};
+
+        let dir = tempfile::tempdir().unwrap();
+        let file_path = dir.path().join("test.ts");
+        let payload = format!(
+            r#"{{"file_path": {}, "facts": [{{"range": {{"pos": 6, "end": 13}}, "type": "Promise<void>", "is_thenable": true}}]}}"#,
+            serde_json::to_string(&file_path.to_string_lossy()).unwrap()
+        );
+        let mut message = u32::try_from(payload.len()).unwrap().to_le_bytes().to_vec();
+        message.push(2);
+        message.extend_from_slice(payload.as_bytes());
+        fs::write(dir.path().join("reply.bin"), message).unwrap();
+
+        let bin = dir.path().join("node_modules/.bin");
+        fs::create_dir_all(&bin).unwrap();
+        let executable = bin.join("tsgolint");
+        fs::write(
+            &executable,
+            format!(
+                "#!/bin/sh\ncat > '{dir}/input.json'\ncat '{dir}/reply.bin'\n",
+                dir = dir.path().display()
+            ),
+        )
+        .unwrap();
+        fs::set_permissions(&executable, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let external_plugin_store = ExternalPluginStore::default();
+        let config = ConfigStoreBuilder::empty().build(&external_plugin_store).unwrap();
+        let config_store = ConfigStore::new(config, FxHashMap::default(), external_plugin_store);
+        let state = TsGoLintState::try_new(dir.path(), config_store, FixKind::None).unwrap();
+
+        let paths = [Arc::<OsStr>::from(file_path.as_os_str())];
+        let type_facts = state.collect_type_facts(&paths).unwrap();
+
+        let input: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(dir.path().join("input.json")).unwrap())
+                .unwrap();
+        assert_eq!(input["report_type_facts"], true);
+        assert_eq!(input["configs"][0]["rules"], serde_json::json!([]));
+        let fact = type_facts[&file_path].get(Span::new(6, 13)).unwrap();
+        assert_eq!(fact.type_text, "Promise<void>");
+        assert!(fact.is_thenable);
+    }
+
     #[test]
     fn test_btreeset_preserves_rules_with_different_options() {
         use super::Rule;
@@ -0,0 +1,139 @@
+//! Type information about AST nodes, computed by `tsgolint` and made available to native rules.
+//!
+//! Type-aware rules which only need type information run inside `tsgolint`. Rules which also need
+//! syntactic context (scopes, CFG, comments, etc.) run natively, and can read the facts `tsgolint`
+//! reported for a node via [`LintContext::type_fact`](crate::LintContext::type_fact).
+
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use rustc_hash::FxHashMap;
+
+use oxc_span::Span;
+
+/// Type facts keyed by the absolute path of the file they were computed for.
+pub type TypeFactsMap = Arc<Mutex<FxHashMap<PathBuf, Arc<TypeFacts>>>>;
+
+/// Type information about a single AST node, identified by its span.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeFact {
+    /// Span of the node in the file's source text.
+    pub span: Span,
+    /// The type of the node, as printed by the TypeScript type checker. e.g. `Promise<void>`
+    pub type_text: String,
+    /// `true` if the type of the node is thenable (e.g. a `Promise`).
+    pub is_thenable: bool,
+}
+
+/// All type facts for a single file, sorted by span for fast lookup.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TypeFacts {
+    facts: Vec<TypeFact>,
+}
+
+impl TypeFacts {
+    pub fn new(mut facts: Vec<TypeFact>) -> Self {
+        facts.sort_unstable_by_key(|fact| (fact.span.start, fact.span.end));
+        Self { facts }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.facts.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.facts.len()
+    }
+
+    /// Get the fact for the node with exactly this span.
+    pub fn get(&self, span: Span) -> Option<&TypeFact> {
+        self.facts
+            .binary_search_by_key(&(span.start, span.end), |fact| (fact.span.start, fact.span.end))
+            .ok()
+            .map(|index| &self.facts[index])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &TypeFact> {
+        self.facts.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{rc::Rc, sync::Arc};
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::AstKind;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{GetSpan, SourceType, Span};
+
+    use super::{TypeFact, TypeFacts};
+    use crate::{ContextHost, ContextSubHost, ModuleRecord, options::LintOptions};
+
+    fn fact(start: u32, end: u32, type_text: &str) -> TypeFact {
+        TypeFact {
+            span: Span::new(start, end),
+            type_text: type_text.to_string(),
+            is_thenable: false,
+        }
+    }
+
+    #[test]
+    fn test_get_type_fact() {
+        let facts = TypeFacts::new(vec![
+            fact(10, 20, "number"),
+            fact(0, 30, "Promise<void>"),
+            fact(0, 5, "string"),
+        ]);
+
+        assert_eq!(facts.len(), 3);
+        assert_eq!(facts.get(Span::new(0, 30)).unwrap().type_text, "Promise<void>");
+        assert_eq!(facts.get(Span::new(0, 5)).unwrap().type_text, "string");
+        assert_eq!(facts.get(Span::new(10, 20)).unwrap().type_text, "number");
+        assert!(facts.get(Span::new(10, 21)).is_none());
+        assert!(TypeFacts::default().get(Span::new(0, 5)).is_none());
+    }
+
+    #[test]
+    fn test_lint_context_type_fact() {
+        // A script block starting at offset 8 of the file, e.g. `<script>` in a Vue file.
+        let source_text = "const p = f();";
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new().with_cfg(true).build(program).semantic;
+        // Facts have spans in the source text of the whole file.
+        let type_facts = TypeFacts::new(vec![TypeFact {
+            span: Span::new(18, 21),
+            type_text: "Promise<void>".to_string(),
+            is_thenable: true,
+        }]);
+        let sub_host = ContextSubHost::new(semantic, Arc::new(ModuleRecord::default()), 8)
+            .with_type_facts(Some(Arc::new(type_facts)));
+        let ctx = Rc::new(ContextHost::new(
+            "test.vue",
+            vec![sub_host],
+            LintOptions::default(),
+            Arc::default(),
+            &allocator,
+        ))
+        .spawn_for_test();
+
+        // Rules read facts with the spans of the nodes in the script block.
+        let call_expr = ctx
+            .nodes()
+            .iter()
+            .find_map(|node| match node.kind() {
+                AstKind::CallExpression(call_expr) => Some(call_expr.span()),
+                _ => None,
+            })
+            .unwrap();
+        let fact = ctx.type_fact(call_expr).unwrap();
+        assert_eq!(fact.type_text, "Promise<void>");
+        assert!(fact.is_thenable);
+        assert!(ctx.type_fact(Span::new(6, 7)).is_none());
+    }
+}
@@ -0,0 +1,235 @@
+use std::hash::Hash;
+
+use oxc_cfg::{
+    BlockNodeId, ControlFlowGraph, EdgeType, ErrorEdgeKind, InstructionKind,
+    graph::{
+        Direction,
+        visit::{Control, DfsEvent, EdgeRef, set_depth_first_search},
+    },
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// Path analysis of the basic blocks of a function in the [`ControlFlowGraph`].
+///
+/// Loops are followed once: the blocks after a loop succeed the blocks before it, instead of the
+/// blocks of the loop body succeeding each other forever. This way, the blocks of a function
+/// can be visited in topological order, e.g. to track which calls were made on every path
+/// reaching a block, like `resolve` and `reject` calls in the executor of a `Promise`.
+#[derive(Clone, Copy)]
+pub struct ControlFlowPaths<'c> {
+    cfg: &'c ControlFlowGraph,
+}
+
+impl<'c> ControlFlowPaths<'c> {
+    pub fn new(cfg: &'c ControlFlowGraph) -> Self {
+        Self { cfg }
+    }
+
+    /// The entry block of the function created in `block_id`, e.g. the callback in
+    /// `foo(() => {})`.
+    pub fn function_entry(self, block_id: BlockNodeId) -> Option<BlockNodeId> {
+        self.cfg
+            .graph()
+            .edges_directed(block_id, Direction::Outgoing)
+            .find(|edge| matches!(edge.weight(), EdgeType::NewFunction))
+            .map(|edge| edge.target())
+    }
+
+    /// The blocks of the function starting at `entry`, in topological order.
+    /// `on_function` is called with the entry block of each function created in them, whose
+    /// blocks are not included.
+    pub fn function_blocks(
+        self,
+        entry: BlockNodeId,
+        mut on_function: impl FnMut(BlockNodeId),
+    ) -> Vec<BlockNodeId> {
+        let graph = self.cfg.graph();
+        let mut block_ids = vec![];
+        set_depth_first_search::<_, _, _, Control<()>, _>(
+            graph,
+            Some(entry),
+            |event| match event {
+                DfsEvent::TreeEdge(a, b) => {
+                    let edges = graph.edges_connecting(a, b).collect::<Vec<_>>();
+                    if let Some(edge) =
+                        edges.iter().find(|edge| matches!(edge.weight(), EdgeType::NewFunction))
+                    {
+                        on_function(edge.target());
+                        return Control::Prune;
+                    }
+                    if edges.iter().any(|edge| {
+                        matches!(edge.weight(), EdgeType::Backedge)
+                            && graph
+                                .edges_directed(edge.target(), Direction::Outgoing)
+                                .any(|edge| matches!(edge.weight(), EdgeType::Backedge))
+                    }) {
+                        return Control::Prune;
+                    }
+                    Control::Continue
+                }
+                DfsEvent::Finish(block_id, _) => {
+                    block_ids.push(block_id);
+                    Control::Continue
+                }
+                _ => Control::Continue,
+            },
+        );
+        block_ids.reverse();
+        block_ids
+    }
+
+    /// Whether `block_id` is the start of a `catch` clause, only reached by exceptions.
+    pub fn is_catch_entry(self, block_id: BlockNodeId) -> bool {
+        self.cfg
+            .graph()
+            .edges_directed(block_id, Direction::Incoming)
+            .all(|edge| matches!(edge.weight(), EdgeType::Error(ErrorEdgeKind::Explicit)))
+    }
+
+    /// The blocks executed right after `block_id`, skipping over loops.
+    pub fn successors(self, block_id: BlockNodeId) -> Vec<BlockNodeId> {
+        let mut successors = vec![];
+        for edge in self.cfg.graph().edges_directed(block_id, Direction::Outgoing) {
+            match edge.weight() {
+                EdgeType::Normal
+                | EdgeType::Jump
+                | EdgeType::Join
+                | EdgeType::Error(ErrorEdgeKind::Explicit)
+                | EdgeType::Finalize => successors.push(edge.target()),
+                EdgeType::Backedge => {
+                    if let Some(next_target) = self.backedge_next_target(edge.source()) {
+                        successors.push(next_target);
+                    }
+                }
+                _ => {}
+            }
+        }
+        successors.reverse();
+        successors
+    }
+
+    /// The blocks executed right before `block_id`, skipping over loops.
+    pub fn predecessors(self, block_id: BlockNodeId) -> Vec<BlockNodeId> {
+        let graph = self.cfg.graph();
+        let mut predecessors = vec![];
+        for edge in graph.edges_directed(block_id, Direction::Incoming) {
+            match edge.weight() {
+                EdgeType::Normal => {
+                    let source = edge.source();
+                    if let Some(prev_source) = self.prev_backedge_source(source) {
+                        predecessors.push(prev_source);
+                    }
+                    predecessors.push(source);
+                }
+                EdgeType::Jump
+                | EdgeType::Join
+                | EdgeType::Error(ErrorEdgeKind::Explicit)
+                | EdgeType::Finalize => predecessors.push(edge.source()),
+                EdgeType::Backedge => {
+                    if graph
+                        .edges_directed(block_id, Direction::Outgoing)
+                        .any(|edge| matches!(edge.weight(), EdgeType::Backedge))
+                    {
+                        predecessors.push(edge.source());
+                    }
+                }
+                _ => {}
+            }
+        }
+        predecessors.reverse();
+        predecessors
+    }
+
+    /// The immediate dominator of each of `block_ids`, which are in topological order: the
+    /// last block other than itself which every path to the block goes through.
+    pub fn immediate_dominators(
+        self,
+        block_ids: &[BlockNodeId],
+    ) -> FxHashMap<BlockNodeId, BlockNodeId> {
+        let mut dominators: FxHashMap<BlockNodeId, FxHashSet<BlockNodeId>> = block_ids
+            .iter()
+            .map(|&block_id| (block_id, FxHashSet::from_iter([block_id])))
+            .collect();
+
+        for &block_id in block_ids {
+            let predecessors = self.predecessors(block_id);
+            let predecessor_dominators: Vec<_> =
+                predecessors.iter().filter_map(|block_id| dominators.get(block_id)).collect();
+            if predecessor_dominators.is_empty() {
+                continue;
+            }
+            let common_dominators = sets_intersection(&predecessor_dominators);
+            dominators.entry(block_id).or_default().extend(common_dominators);
+        }
+
+        let order: FxHashMap<_, _> =
+            block_ids.iter().enumerate().map(|(i, &block_id)| (block_id, i)).collect();
+        dominators
+            .iter()
+            .filter_map(|(&block_id, dominators)| {
+                dominators
+                    .iter()
+                    .filter(|&&dominator| dominator != block_id)
+                    .max_by_key(|&dominator| order[dominator])
+                    .map(|&dominator| (block_id, dominator))
+            })
+            .collect()
+    }
+
+    fn block_is_condition(self, block_id: BlockNodeId) -> bool {
+        let instructions = self.cfg.basic_block(block_id).instructions();
+        !instructions.is_empty()
+            && instructions
+                .iter()
+                .all(|instruction| matches!(instruction.kind, InstructionKind::Condition))
+    }
+
+    fn backedge_next_target(self, block_id: BlockNodeId) -> Option<BlockNodeId> {
+        let graph = self.cfg.graph();
+        let mut source = block_id;
+        'outer: loop {
+            for edge in graph.edges_directed(source, Direction::Outgoing) {
+                match edge.weight() {
+                    EdgeType::Backedge if !self.block_is_condition(edge.source()) => {
+                        source = edge.target();
+                        continue 'outer;
+                    }
+                    EdgeType::Normal if source != block_id => {
+                        return Some(edge.target());
+                    }
+                    _ => {}
+                }
+            }
+            return None;
+        }
+    }
+
+    fn prev_backedge_source(self, block_id: BlockNodeId) -> Option<BlockNodeId> {
+        let graph = self.cfg.graph();
+        let mut source: Option<BlockNodeId> = None;
+        loop {
+            let back_edge = graph
+                .edges_directed(source.unwrap_or(block_id), Direction::Incoming)
+                .find(|edge| matches!(edge.weight(), EdgeType::Backedge));
+            if let Some(back_edge) = back_edge
+                && !self.block_is_condition(back_edge.source())
+            {
+                source = Some(back_edge.source());
+                continue;
+            }
+            break;
+        }
+        source
+    }
+}
+
+fn sets_intersection<T>(sets: &[&FxHashSet<T>]) -> FxHashSet<T>
+where
+    T: Eq + Hash + Copy,
+{
+    if let Some((&first, rest)) = sets.split_first() {
+        rest.iter().fold(first.clone(), |acc, &set| acc.intersection(set).copied().collect())
+    } else {
+        FxHashSet::default()
+    }
+}
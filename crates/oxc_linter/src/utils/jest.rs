@@ -9,7 +9,7 @@ use oxc_ast::{
     },
 };
 use oxc_semantic::{AstNode, ReferenceId, Semantic, SymbolId};
-use oxc_span::CompactStr;
+use oxc_span::{CompactStr, GetSpan, Span};
 
 use crate::LintContext;
 pub use crate::utils::jest::parse_jest_fn::{
@@ -41,6 +41,25 @@ const JEST_METHOD_NAMES: [&str; 18] = [
     "xtest",
 ];
 
+/// Globals of mocha-style test APIs, which are also used by Jasmine and by Node's test runner.
+pub const MOCHA_METHOD_NAMES: [&str; 10] = [
+    "context",
+    "describe",
+    "it",
+    "specify",
+    "suite",
+    "test",
+    "xcontext",
+    "xdescribe",
+    "xit",
+    "xspecify",
+];
+
+/// Globals of mocha-style test APIs which are neither Jest nor Vitest globals, and therefore not
+/// visited by [`iter_possible_jest_call_node`].
+pub const MOCHA_ONLY_METHOD_NAMES: [&str; 5] =
+    ["context", "specify", "suite", "xcontext", "xspecify"];
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum JestFnKind {
     Expect,
@@ -261,6 +280,60 @@ fn collect_ids_referenced_to_global<'c>(
         .flat_map(|(_, reference_ids)| reference_ids.iter().copied())
 }
 
+/// A call of a mocha-style test function, e.g. `context.only('foo', () => {})`.
+pub struct MochaFnCall<'a> {
+    pub call_expr: &'a CallExpression<'a>,
+    /// Name of the called global, e.g. `context`
+    pub name: &'a str,
+    /// The modifier of the call, e.g. `only`, with the span of its name and whether it is
+    /// computed, as in `context['only']()`
+    pub modifier: Option<(&'a str, Span, bool)>,
+}
+
+/// Iterate over the calls of the mocha-style globals in `names`, e.g. `it('foo', () => {})` and
+/// `describe.skip('foo', () => {})`. Only one modifier is supported, as in mocha.
+pub fn iter_mocha_fn_calls<'a, 'c>(
+    semantic: &'c Semantic<'a>,
+    names: &'c [&'static str],
+) -> impl Iterator<Item = MochaFnCall<'a>> + 'c {
+    semantic
+        .scoping()
+        .root_unresolved_references()
+        .iter()
+        .filter(|(name, _)| names.contains(name))
+        .flat_map(|(_, reference_ids)| reference_ids.iter().copied())
+        .filter_map(move |reference_id| {
+            let nodes = semantic.nodes();
+            let id = semantic.scoping().get_reference(reference_id).node_id();
+            let AstKind::IdentifierReference(ident) = nodes.kind(id) else {
+                return None;
+            };
+            let parent = nodes.parent_node(id);
+            let (callee_span, modifier) = match parent.kind() {
+                AstKind::StaticMemberExpression(member) => (
+                    member.span,
+                    Some((member.property.name.as_str(), member.property.span, false)),
+                ),
+                AstKind::ComputedMemberExpression(member) => {
+                    let Expression::StringLiteral(property) = &member.expression else {
+                        return None;
+                    };
+                    (member.span, Some((property.value.as_str(), property.span, true)))
+                }
+                _ => (ident.span, None),
+            };
+            let call_node =
+                if modifier.is_some() { nodes.parent_node(parent.id()) } else { parent };
+            let AstKind::CallExpression(call_expr) = call_node.kind() else {
+                return None;
+            };
+            if call_expr.callee.span() != callee_span {
+                return None;
+            }
+            Some(MochaFnCall { call_expr, name: ident.name.as_str(), modifier })
+        })
+}
+
 /// join name of the expression. e.g.
 /// `expect(foo).toBe(bar)`  -> "expect.toBe"
 /// `new Foo().bar` -> "Foo.bar"
@@ -333,6 +406,7 @@ mod test {
                 vec![ContextSubHost::new(semantic, Arc::new(ModuleRecord::default()), 0)],
                 LintOptions::default(),
                 Arc::default(),
+                &allocator,
             ))
             .spawn_for_test()
         };
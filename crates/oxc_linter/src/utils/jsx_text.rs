@@ -0,0 +1,203 @@
+//! Helpers for mapping positions inside JSX text and JSX string attributes back to source spans.
+//!
+//! The parser keeps JSX text and JSX string attribute values exactly as written, so HTML entities
+//! such as `&nbsp;` or `&#x27;` are not decoded. Rules which compare against the text a user would
+//! see should decode it with [`decode_jsx_entities`], and use [`DecodedJsxText::span_of`] to get
+//! the source span of a part of the decoded text. All offsets are in bytes, so multi-byte
+//! characters and entities never shift a fix or diagnostic label.
+
+use std::{borrow::Cow, ops::Range};
+
+use oxc_ast::ast::StringLiteral;
+use oxc_span::Span;
+use oxc_syntax::xml_entities::XML_ENTITIES;
+
+/// Span of the value of a JSX string attribute, without the quotes.
+///
+/// ```text
+/// <div role="button" />
+///            ^^^^^^
+/// ```
+pub fn jsx_string_value_span(lit: &StringLiteral) -> Span {
+    Span::new(lit.span.start + 1, lit.span.end - 1)
+}
+
+/// Span of `part`, which must be a sub-slice of `text`, given that `text` starts at `text_start`
+/// in the source. e.g. a word yielded by `text.split_whitespace()`.
+///
+/// # Panics
+/// Panics if `part` is not a sub-slice of `text`.
+pub fn span_of_substr(text: &str, text_start: u32, part: &str) -> Span {
+    let offset = (part.as_ptr() as usize)
+        .checked_sub(text.as_ptr() as usize)
+        .filter(|offset| offset + part.len() <= text.len())
+        .expect("`part` must be a sub-slice of `text`");
+    span_of_range(text_start, offset..offset + part.len())
+}
+
+#[expect(clippy::cast_possible_truncation)]
+fn span_of_range(start: u32, range: Range<usize>) -> Span {
+    Span::new(start + range.start as u32, start + range.end as u32)
+}
+
+/// An HTML entity which was decoded, as byte ranges relative to the start of the text.
+#[derive(Debug, Clone)]
+struct DecodedEntity {
+    decoded: Range<usize>,
+    source: Range<usize>,
+}
+
+/// JSX text with HTML entities decoded, which can map offsets in the decoded text back to the
+/// source. Create it with [`decode_jsx_entities`].
+#[derive(Debug, Clone)]
+pub struct DecodedJsxText<'a> {
+    text: Cow<'a, str>,
+    start: u32,
+    entities: Vec<DecodedEntity>,
+}
+
+impl<'a> DecodedJsxText<'a> {
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn into_text(self) -> Cow<'a, str> {
+        self.text
+    }
+
+    /// Source span of the byte range `range` of the decoded text.
+    /// A range which starts or ends inside a decoded entity is widened to cover the whole entity.
+    pub fn source_span(&self, range: Range<usize>) -> Span {
+        let start = self.source_offset(range.start, false);
+        let end = self.source_offset(range.end, true);
+        span_of_range(self.start, start..end)
+    }
+
+    /// Source span of `part`, which must be a sub-slice of [`DecodedJsxText::as_str`].
+    ///
+    /// # Panics
+    /// Panics if `part` is not a sub-slice of the decoded text.
+    pub fn span_of(&self, part: &str) -> Span {
+        let span = span_of_substr(self.as_str(), 0, part);
+        self.source_span(span.start as usize..span.end as usize)
+    }
+
+    fn source_offset(&self, offset: usize, is_end: bool) -> usize {
+        // Last entity which starts before `offset`. For the end of a range, an entity starting
+        // exactly at `offset` is not part of the range.
+        let index = self.entities.partition_point(|entity| {
+            if is_end { entity.decoded.start < offset } else { entity.decoded.start <= offset }
+        });
+        let Some(entity) = index.checked_sub(1).map(|index| &self.entities[index]) else {
+            return offset;
+        };
+        if offset < entity.decoded.end {
+            if is_end { entity.source.end } else { entity.source.start }
+        } else {
+            entity.source.end + (offset - entity.decoded.end)
+        }
+    }
+}
+
+/// Decode the HTML entities in `raw`, which starts at `start` in the source.
+///
+/// Named entities (e.g. `&amp;`), decimal (`&#39;`) and hexadecimal (`&#x27;`) references are
+/// decoded, in the same way as the JSX transform. Anything else is kept as written.
+///
+/// ```text
+/// decode_jsx_entities("a&nbsp;b", 10).as_str() // => "a\u{a0}b"
+/// ```
+pub fn decode_jsx_entities(raw: &str, start: u32) -> DecodedJsxText<'_> {
+    let mut decoded: Option<String> = None;
+    let mut entities = vec![];
+    let mut copied = 0;
+    let mut search_from = 0;
+
+    while let Some(amp) = raw[search_from..].find('&').map(|i| i + search_from) {
+        search_from = amp + 1;
+        let Some(semi) = raw[amp + 1..].find(';').map(|i| i + amp + 1) else {
+            break;
+        };
+        let Some(c) = decode_entity(&raw[amp + 1..semi]) else {
+            continue;
+        };
+
+        let text = decoded.get_or_insert_with(|| String::with_capacity(raw.len()));
+        text.push_str(&raw[copied..amp]);
+        let decoded_start = text.len();
+        text.push(c);
+        entities.push(DecodedEntity { decoded: decoded_start..text.len(), source: amp..semi + 1 });
+        copied = semi + 1;
+        search_from = semi + 1;
+    }
+
+    let text = match decoded {
+        Some(mut text) => {
+            text.push_str(&raw[copied..]);
+            Cow::Owned(text)
+        }
+        None => Cow::Borrowed(raw),
+    };
+    DecodedJsxText { text, start, entities }
+}
+
+/// Decode the name of a single entity, without the leading `&` and trailing `;`.
+fn decode_entity(name: &str) -> Option<char> {
+    if let Some(number) = name.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+        char::from_u32(code)
+    } else {
+        XML_ENTITIES.get(name).copied()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+
+    use oxc_span::Span;
+
+    use super::{decode_jsx_entities, span_of_substr};
+
+    #[test]
+    fn test_decode_jsx_entities() {
+        let text = decode_jsx_entities("plain text", 0);
+        assert_eq!(text.as_str(), "plain text");
+        assert!(matches!(text.into_text(), Cow::Borrowed(_)));
+
+        let text = decode_jsx_entities("a&nbsp;b &amp; c&#39;s &#x1F600; &unknown; & d", 0);
+        assert_eq!(text.as_str(), "a\u{a0}b & c's \u{1F600} &unknown; & d");
+
+        assert_eq!(decode_jsx_entities("&&amp;", 0).as_str(), "&&");
+        assert_eq!(decode_jsx_entities("&amp", 0).as_str(), "&amp");
+    }
+
+    #[test]
+    fn test_decoded_source_span() {
+        let raw = "click&nbsp;here &amp; más";
+        let text = decode_jsx_entities(raw, 100);
+        let words = text.as_str().split_whitespace().collect::<Vec<_>>();
+        assert_eq!(words, vec!["click", "here", "&", "más"]);
+
+        assert_eq!(text.span_of(words[0]), Span::new(100, 105));
+        assert_eq!(text.span_of(words[1]), Span::new(111, 115));
+        assert_eq!(text.span_of(words[2]), Span::new(116, 121));
+        assert_eq!(text.span_of(words[3]), Span::new(122, 126));
+        assert_eq!(text.span_of(text.as_str()), Span::new(100, 126));
+
+        // Ranges which partly cover an entity are widened to the whole entity.
+        assert_eq!(text.source_span(5..6), Span::new(105, 111));
+        assert_eq!(text.source_span(6..7), Span::new(105, 111));
+        assert_eq!(text.source_span(7..7), Span::new(111, 111));
+    }
+
+    #[test]
+    fn test_span_of_substr() {
+        let text = "  menuitem   button ";
+        let spans = text.split_whitespace().map(|word| span_of_substr(text, 10, word));
+        assert_eq!(spans.collect::<Vec<_>>(), vec![Span::new(12, 20), Span::new(23, 29)]);
+    }
+}
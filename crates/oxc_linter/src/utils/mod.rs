@@ -9,11 +9,13 @@ use std::{
 
 use oxc_allocator::Allocator;
 
+mod cfg;
 mod comment;
 mod config;
 mod express;
 mod jest;
 mod jsdoc;
+mod jsx_text;
 mod nextjs;
 mod promise;
 mod react;
@@ -26,8 +28,9 @@ mod vitest;
 mod vue;
 
 pub use self::{
-    comment::*, config::*, express::*, jest::*, jsdoc::*, nextjs::*, promise::*, react::*,
-    react_perf::*, regex::*, typescript::*, unicorn::*, url::*, vitest::*, vue::*,
+    cfg::*, comment::*, config::*, express::*, jest::*, jsdoc::*, jsx_text::*, nextjs::*,
+    promise::*, react::*, react_perf::*, regex::*, typescript::*, unicorn::*, url::*, vitest::*,
+    vue::*,
 };
 
 /// List of Jest rules that have Vitest equivalents.
@@ -1,4 +1,13 @@
-use oxc_ast::ast::{CallExpression, Expression, NewExpression};
+use oxc_ast::{
+    AstKind,
+    ast::{
+        ArrowFunctionExpression, BindingIdentifier, CallExpression, Expression, Function,
+        NewExpression,
+    },
+};
+use oxc_ast_visit::Visit;
+use oxc_semantic::{ScopeFlags, Scoping, SymbolId};
+use oxc_span::Span;
 
 // https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise
 pub const PROMISE_STATIC_METHODS: [&str; 7] =
@@ -38,3 +47,141 @@ pub fn get_promise_constructor_inline_executor<'a>(
         .as_expression()
         .and_then(|expr| if expr.is_function() { Some(expr) } else { None })
 }
+
+/// The symbols of the `resolve` and `reject` parameters of the executor of a `Promise`, see
+/// [`get_promise_constructor_inline_executor`].
+pub fn get_promise_executor_resolvers(
+    executor: &Expression,
+) -> (Option<SymbolId>, Option<SymbolId>) {
+    let params = match executor {
+        Expression::FunctionExpression(func_expr) => Some(func_expr.params.as_ref()),
+        Expression::ArrowFunctionExpression(arrow_func_expr) => {
+            Some(arrow_func_expr.params.as_ref())
+        }
+        _ => None,
+    };
+    let symbol_ids = params.map_or(vec![], |params| {
+        params
+            .items
+            .iter()
+            .map(|param| param.pattern.get_binding_identifier().map(BindingIdentifier::symbol_id))
+            .collect::<Vec<_>>()
+    });
+    let resolve_symbol_id = symbol_ids.first().copied().unwrap_or(None);
+    let reject_symbol_id = symbol_ids.get(1).copied().unwrap_or(None);
+    (resolve_symbol_id, reject_symbol_id)
+}
+
+/// Finds the calls of `resolve` and `reject` of a `Promise` executor in the statements it visits,
+/// without visiting nested functions. Visit the statements of the basic blocks of the executor
+/// in [`ControlFlowPaths::function_blocks`](super::ControlFlowPaths::function_blocks) order to
+/// track on which paths the `Promise` is settled.
+///
+/// Inside `try` blocks with a `catch` clause, it also tracks the last expression which may throw,
+/// as a `resolve` call followed by a throwing expression may be followed by the `catch` clause.
+#[derive(Debug)]
+pub struct PromiseResolveFinder<'a> {
+    scoping: &'a Scoping,
+    resolve_symbol_id: Option<SymbolId>,
+    reject_symbol_id: Option<SymbolId>,
+    resolved: Vec<&'a CallExpression<'a>>,
+    // If it's a try block with a catch, then the try block's depth is 1
+    try_block_depth: i32,
+    last_throwable_expr_span: Option<Span>,
+}
+
+impl<'a> PromiseResolveFinder<'a> {
+    pub fn new(
+        scoping: &'a Scoping,
+        resolve_symbol_id: Option<SymbolId>,
+        reject_symbol_id: Option<SymbolId>,
+    ) -> Self {
+        Self {
+            scoping,
+            resolve_symbol_id,
+            reject_symbol_id,
+            resolved: vec![],
+            try_block_depth: 0,
+            last_throwable_expr_span: None,
+        }
+    }
+
+    /// Call on entering a `try` block with a `catch` clause.
+    #[inline]
+    pub fn try_block_start(&mut self) {
+        self.try_block_depth += 1;
+    }
+
+    /// Call on entering its `catch` clause.
+    #[inline]
+    pub fn try_block_end(&mut self) {
+        self.try_block_depth -= 1;
+    }
+
+    #[inline]
+    pub fn in_try_block(&self) -> bool {
+        self.try_block_depth > 0
+    }
+
+    #[inline]
+    fn record_throwable_expr_span(&mut self, span: Span) {
+        if self.in_try_block() {
+            self.last_throwable_expr_span = Some(span);
+        }
+    }
+
+    /// The `resolve` and `reject` calls found since the last call, in source order.
+    #[inline]
+    pub fn take_resolved(&mut self) -> Vec<&'a CallExpression<'a>> {
+        self.resolved.drain(..).collect()
+    }
+
+    /// The last expression which may throw in a `try` block found since the last call.
+    #[inline]
+    pub fn take_last_throwable_expr_span(&mut self) -> Option<Span> {
+        self.last_throwable_expr_span.take()
+    }
+}
+
+impl<'a> Visit<'a> for PromiseResolveFinder<'a> {
+    fn leave_node(&mut self, kind: AstKind<'a>) {
+        match kind {
+            AstKind::NewExpression(new_expr) => {
+                self.record_throwable_expr_span(new_expr.span);
+            }
+            AstKind::ImportExpression(import_expr) => {
+                self.record_throwable_expr_span(import_expr.span);
+            }
+            AstKind::YieldExpression(yield_expr) => {
+                self.record_throwable_expr_span(yield_expr.span);
+            }
+            AstKind::StaticMemberExpression(static_member_expr) => {
+                self.record_throwable_expr_span(static_member_expr.span);
+            }
+            AstKind::ComputedMemberExpression(computed_member_expr) => {
+                self.record_throwable_expr_span(computed_member_expr.span);
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_call_expression(&mut self, call_expr: &CallExpression<'a>) {
+        match &call_expr.callee {
+            Expression::Identifier(ident) => {
+                let symbol_id = self.scoping.get_reference(ident.reference_id()).symbol_id();
+                if symbol_id == self.resolve_symbol_id || symbol_id == self.reject_symbol_id {
+                    self.resolved.push(self.alloc(call_expr));
+                } else {
+                    self.record_throwable_expr_span(call_expr.span);
+                }
+            }
+            _ => {
+                self.record_throwable_expr_span(call_expr.span);
+            }
+        }
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+}
@@ -44,6 +44,28 @@ pub fn has_jsx_prop_ignore_case<'a, 'b>(
     })
 }
 
+/// Find the prop of `node` for the DOM attribute `attribute_name`.
+///
+/// If `settings["jsx-a11y"].attributes` maps the attribute to prop names, e.g.
+/// `{ "for": ["htmlFor", "for"] }`, the first of these props is returned.
+/// Otherwise, the prop named `attribute_name` is returned, ignoring case.
+pub fn get_jsx_a11y_prop<'a, 'b>(
+    ctx: &LintContext<'a>,
+    node: &'b JSXOpeningElement<'a>,
+    attribute_name: &str,
+) -> Option<&'b JSXAttributeItem<'a>> {
+    match ctx.settings().jsx_a11y.attributes.get(attribute_name) {
+        Some(prop_names) => prop_names.iter().find_map(|prop_name| {
+            node.attributes
+                .iter()
+                .find(|attr| attr.as_attribute().is_some_and(|attr| attr.is_identifier(prop_name)))
+        }),
+        None => node.attributes.iter().find(|attr| {
+            attr.as_attribute().is_some_and(|attr| attr.is_identifier_ignore_case(attribute_name))
+        }),
+    }
+}
+
 pub fn get_prop_value<'a, 'b>(item: &'b JSXAttributeItem<'a>) -> Option<&'b JSXAttributeValue<'a>> {
     item.as_attribute().and_then(|item| item.value.as_ref())
 }
@@ -4,14 +4,48 @@ use oxc_ast::{
     ast::{Argument, Expression},
 };
 use oxc_regular_expression::{ConstructorParser, Options, ast::Pattern};
-use oxc_semantic::IsGlobalReference;
+use oxc_semantic::{IsGlobalReference, NodeId, Scoping, Semantic};
 use oxc_span::Span;
 
-use crate::{AstNode, context::LintContext};
+use crate::AstNode;
 
-pub fn run_on_regex_node<'a, 'b, M>(node: &'a AstNode<'b>, ctx: &'a LintContext<'b>, cb: M)
-where
-    M: FnOnce(&Pattern<'_>, Span),
+/// A regular expression of the linted file, either a regex literal or a `RegExp` constructor
+/// called with a string pattern.
+///
+/// Regular expressions are parsed once per file and shared by all rules implementing
+/// [`Rule::run_on_regex`](crate::rule::Rule::run_on_regex).
+#[derive(Debug, Clone, Copy)]
+pub struct ParsedRegExp<'a> {
+    /// The parsed pattern.
+    pub pattern: &'a Pattern<'a>,
+    /// Span of the regex literal, or of the pattern argument of the `RegExp` constructor.
+    pub span: Span,
+    /// The regex literal, or the `new RegExp()` / `RegExp()` expression.
+    pub node_id: NodeId,
+}
+
+/// Parse all regular expressions of `semantic`, allocating the patterns of `RegExp` constructors
+/// in `allocator`. Patterns which fail to parse are skipped.
+pub fn parse_regexes<'a>(
+    semantic: &Semantic<'a>,
+    allocator: &'a Allocator,
+) -> Vec<ParsedRegExp<'a>> {
+    let mut regexes = vec![];
+    for node in semantic.nodes() {
+        run_on_regex_node(node, semantic, allocator, |pattern, span| {
+            regexes.push(ParsedRegExp { pattern, span, node_id: node.id() });
+        });
+    }
+    regexes
+}
+
+fn run_on_regex_node<'a, M>(
+    node: &AstNode<'a>,
+    semantic: &Semantic<'a>,
+    allocator: &'a Allocator,
+    cb: M,
+) where
+    M: FnOnce(&'a Pattern<'a>, Span),
 {
     match node.kind() {
         AstKind::RegExpLiteral(reg) => {
@@ -19,88 +53,88 @@ where
                 cb(pat, reg.span);
             }
         }
-        AstKind::NewExpression(expr) if is_regexp_callee(&expr.callee, ctx) => {
-            run_on_arguments(expr.arguments.first(), expr.arguments.get(1), ctx, cb);
+        AstKind::NewExpression(expr) if is_regexp_callee(&expr.callee, semantic.scoping()) => {
+            run_on_arguments(
+                expr.arguments.first(),
+                expr.arguments.get(1),
+                semantic.source_text(),
+                allocator,
+                cb,
+            );
         }
 
         // RegExp()
-        AstKind::CallExpression(expr) if is_regexp_callee(&expr.callee, ctx) => {
-            run_on_arguments(expr.arguments.first(), expr.arguments.get(1), ctx, cb);
+        AstKind::CallExpression(expr) if is_regexp_callee(&expr.callee, semantic.scoping()) => {
+            run_on_arguments(
+                expr.arguments.first(),
+                expr.arguments.get(1),
+                semantic.source_text(),
+                allocator,
+                cb,
+            );
         }
         _ => {}
     }
 }
 
-fn run_on_arguments<M>(arg1: Option<&Argument>, arg2: Option<&Argument>, ctx: &LintContext, cb: M)
-where
-    M: FnOnce(&Pattern<'_>, Span),
+fn run_on_arguments<'a, M>(
+    arg1: Option<&Argument>,
+    arg2: Option<&Argument>,
+    source_text: &'a str,
+    allocator: &'a Allocator,
+    cb: M,
+) where
+    M: FnOnce(&'a Pattern<'a>, Span),
 {
     let arg1 = arg1.and_then(Argument::as_expression).map(Expression::get_inner_expression);
     let arg2 = arg2.and_then(Argument::as_expression).map(Expression::get_inner_expression);
     // note: improvements required for strings used via identifier references
     // Missing or non-string arguments will be runtime errors, but are not covered by this rule.
-    match (arg1, arg2) {
+    let (pattern_span, flags_span) = match (arg1, arg2) {
         (Some(Expression::StringLiteral(pattern)), Some(Expression::StringLiteral(flags))) => {
-            let allocator = Allocator::default();
-            if let Some(pat) = parse_regex(&allocator, pattern.span, Some(flags.span), ctx) {
-                cb(&pat, pattern.span);
-            }
+            (pattern.span, Some(flags.span))
         }
         (Some(Expression::StringLiteral(pattern)), Some(Expression::TemplateLiteral(flags))) => {
             if !flags.is_no_substitution_template() {
                 return;
             }
-            let allocator = Allocator::default();
-            if let Some(pat) = parse_regex(&allocator, pattern.span, Some(flags.span), ctx) {
-                cb(&pat, pattern.span);
-            }
-        }
-        (Some(Expression::StringLiteral(pattern)), _) => {
-            let allocator = Allocator::default();
-            if let Some(pat) = parse_regex(&allocator, pattern.span, None, ctx) {
-                cb(&pat, pattern.span);
-            }
+            (pattern.span, Some(flags.span))
         }
+        (Some(Expression::StringLiteral(pattern)), _) => (pattern.span, None),
         (Some(Expression::TemplateLiteral(pattern)), Some(Expression::TemplateLiteral(flags))) => {
             if !pattern.is_no_substitution_template() || !flags.is_no_substitution_template() {
                 return;
             }
-            let allocator = Allocator::default();
-            if let Some(pat) = parse_regex(&allocator, pattern.span, Some(flags.span), ctx) {
-                cb(&pat, pattern.span);
-            }
+            (pattern.span, Some(flags.span))
         }
         (Some(Expression::TemplateLiteral(pattern)), Some(Expression::StringLiteral(flags))) => {
             if !pattern.is_no_substitution_template() {
                 return;
             }
-            let allocator = Allocator::default();
-            if let Some(pat) = parse_regex(&allocator, pattern.span, Some(flags.span), ctx) {
-                cb(&pat, pattern.span);
-            }
+            (pattern.span, Some(flags.span))
         }
         (Some(Expression::TemplateLiteral(pattern)), _) => {
             if !pattern.is_no_substitution_template() {
                 return;
             }
-            let allocator = Allocator::default();
-            if let Some(pat) = parse_regex(&allocator, pattern.span, None, ctx) {
-                cb(&pat, pattern.span);
-            }
+            (pattern.span, None)
         }
-        _ => {}
+        _ => return,
+    };
+    if let Some(pat) = parse_regex(allocator, pattern_span, flags_span, source_text) {
+        cb(allocator.alloc(pat), pattern_span);
     }
 }
 
 // Accepts both RegExp and globalThis.RegExp
-fn is_regexp_callee<'a>(callee: &'a Expression<'a>, ctx: &'a LintContext<'_>) -> bool {
-    if callee.is_global_reference_name("RegExp", ctx.semantic().scoping()) {
+fn is_regexp_callee(callee: &Expression<'_>, scoping: &Scoping) -> bool {
+    if callee.is_global_reference_name("RegExp", scoping) {
         return true;
     }
     // Check for globalThis.RegExp (StaticMemberExpression)
     if let Expression::StaticMemberExpression(member) = callee
         && let Expression::Identifier(obj) = &member.object
-        && obj.is_global_reference_name("globalThis", ctx.semantic().scoping())
+        && obj.is_global_reference_name("globalThis", scoping)
         && member.property.name == "RegExp"
     {
         return true;
@@ -112,12 +146,12 @@ fn parse_regex<'a>(
     allocator: &'a Allocator,
     pattern_span: Span,
     flags_span: Option<Span>,
-    ctx: &'a LintContext<'_>,
+    source_text: &'a str,
 ) -> Option<Pattern<'a>> {
-    let flags_text = flags_span.map(|span| span.source_text(ctx.source_text()));
+    let flags_text = flags_span.map(|span| span.source_text(source_text));
     let parser = ConstructorParser::new(
         allocator,
-        pattern_span.source_text(ctx.source_text()),
+        pattern_span.source_text(source_text),
         flags_text,
         Options {
             pattern_span_offset: pattern_span.start,
@@ -50,6 +50,23 @@ fn test_rules_with_custom_configuration_have_schema() {
 
     let exception_set: FxHashSet<&str> = exceptions.iter().copied().collect();
 
+    // Rules which read their options while linting with `LintContext::options`, instead of
+    // storing them, so they do not show in their debug output.
+    let reads_options_while_linting: &[&str] = &[
+        "eslint/no-cond-assign",
+        "import/no-missing-extension",
+        "jest/max-nested-describe",
+        "jest/no-disabled-tests",
+        "jest/no-focused-tests",
+        "jest/no-hooks",
+        "react/jsx-key",
+        "react/prefer-es6-class",
+        "typescript/consistent-indexed-object-style",
+        "unicorn/switch-case-braces",
+        "vue/define-emits-declaration",
+        "vue/define-props-declaration",
+    ];
+
     // Get the full rule list programmatically
     let mut generator = r#gen::SchemaGenerator::new(r#gen::SchemaSettings::default());
     let table = RuleTable::new(Some(&mut generator));
@@ -107,7 +124,8 @@ fn test_rules_with_custom_configuration_have_schema() {
         // source.
         let default_rule = rule.clone();
         let rule_debug = format!("{default_rule:?}");
-        let rule_has_config_options = config_regex.is_match(&rule_debug);
+        let rule_has_config_options = config_regex.is_match(&rule_debug)
+            || reads_options_while_linting.contains(&full_rule_name.as_str());
 
         // Skip rules with config options that already have schemas.
         if rules_with_schemas.contains(&full_rule_name) && rule_has_config_options {
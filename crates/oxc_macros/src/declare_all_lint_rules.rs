@@ -78,11 +78,13 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
 
         use crate::{
             context::{ContextHost, LintContext},
-            rule::{Rule, RuleCategory, RuleFixMeta, RuleMeta, RuleRunner, RuleRunFunctionsImplemented},
-            utils::PossibleJestNode,
+            frameworks::FrameworkOptions,
+            rule::{Rule, RuleCategory, RuleFixMeta, RuleMeta, RuleRunner, RuleRunFunctionsImplemented, RuleSymbolFilter},
+            utils::{ParsedRegExp, PossibleJestNode},
             AstNode
         };
         use oxc_semantic::{AstTypesBitset, SymbolId};
+        use oxc_span::SourceType;
 
         #[derive(Debug, Clone)]
         #[expect(clippy::enum_variant_names)]
@@ -116,6 +118,39 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            /// Options this [`Rule`] uses when it is configured without any.
+            pub fn default_config(&self) -> Option<serde_json::Value> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::default_config()),*
+                }
+            }
+
+            /// Whether the options of this [`Rule`] are checked against its
+            /// [`config_schema`](Self::config_schema).
+            #[cfg(any(test, feature = "validate_config"))]
+            pub fn validates_config(&self) -> bool {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::VALIDATE_CONFIG),*
+                }
+            }
+
+            /// Check that `options` deserialize as the options this [`Rule`] reads with
+            /// `ctx.options`, if it is declared with `typed_config`.
+            #[cfg(any(test, feature = "validate_config"))]
+            pub fn deserialize_config(&self, options: &[serde_json::Value]) -> Result<(), String> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::deserialize_config(options)),*
+                }
+            }
+
+            /// The schema of the options of this [`Rule`], declared with `config = ...`.
+            #[cfg(any(test, feature = "ruledocs", feature = "validate_config"))]
+            pub fn config_schema(&self, generator: &mut schemars::SchemaGenerator) -> Option<schemars::schema::Schema> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::config_schema(generator)),*
+                }
+            }
+
             #[cfg(feature = "ruledocs")]
             pub fn documentation(&self) -> Option<&'static str> {
                 match self {
@@ -172,12 +207,41 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            pub(super) fn run_on_regex<'a>(&self, regex: &ParsedRegExp<'a>, ctx: &LintContext<'a>) {
+                match self {
+                    #(Self::#struct_names(rule) => rule.run_on_regex(regex, ctx)),*
+                }
+            }
+
+            pub(super) fn run_on_symbol<'a>(&self, symbol_id: SymbolId, ctx: &LintContext<'a>) {
+                match self {
+                    #(Self::#struct_names(rule) => rule.run_on_symbol(symbol_id, ctx)),*
+                }
+            }
+
+            /// Symbols this [`Rule`]'s `run_on_symbol` is called on.
+            pub fn symbol_filter(&self) -> RuleSymbolFilter {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::SYMBOL_FILTER),*
+                }
+            }
+
             pub(super) fn should_run(&self, ctx: &ContextHost) -> bool {
                 match self {
                     #(Self::#struct_names(rule) => rule.should_run(ctx)),*
                 }
             }
 
+            pub(crate) fn should_run_on_source(
+                &self,
+                source_type: SourceType,
+                framework_options: FrameworkOptions,
+            ) -> bool {
+                match self {
+                    #(Self::#struct_names(rule) => rule.should_run_on_source(source_type, framework_options)),*
+                }
+            }
+
             pub fn is_tsgolint_rule(&self) -> bool {
                 match self {
                     #(Self::#struct_names(rule) => #struct_names::IS_TSGOLINT_RULE),*
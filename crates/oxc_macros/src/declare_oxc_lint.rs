@@ -22,6 +22,20 @@ pub struct LintRuleMeta {
     /// This is the name of a struct/enum/whatever implementing
     /// schemars::JsonSchema
     config: Option<Ident>,
+    /// Whether the default of `config` is exposed by `RuleMeta::default_config`.
+    /// `config` must implement `Default` and `serde::Serialize`.
+    default_config: bool,
+    /// Whether configured options are not checked against the schema of `config`, because they are
+    /// not deserialized into `config`, e.g. when they are read from a string or a number.
+    unchecked_config: bool,
+    /// Whether the rule reads its options with `ctx.options::<config>()`, so configured options
+    /// must deserialize as `config`. `config` must implement `Default` and `serde::Deserialize`.
+    typed_config: bool,
+    /// `SymbolFlags` of the symbols `run_on_symbol` is called on, e.g. `Class | Function`.
+    /// Empty for all symbols.
+    symbols: Vec<Ident>,
+    /// Whether `run_on_symbol` is only called on symbols without references.
+    unused_symbols: bool,
 }
 
 impl Parse for LintRuleMeta {
@@ -80,6 +94,11 @@ impl Parse for LintRuleMeta {
         // Do not provide a default value here so that it can be set there instead.
         let mut fix: Option<Ident> = None;
         let mut config: Option<Ident> = None;
+        let mut default_config = false;
+        let mut unchecked_config = false;
+        let mut typed_config = false;
+        let mut symbols: Vec<Ident> = vec![];
+        let mut unused_symbols = false;
 
         // remaining options are `key = value` pairs, with the exception of
         // fix kinds. Those can be short-handed to just the fix kind
@@ -105,6 +124,31 @@ impl Parse for LintRuleMeta {
                     input.parse::<Token!(=)>()?;
                     config.replace(input.parse()?);
                 }
+                // default_config, serialize the default of `config`
+                "default_config" => {
+                    default_config = true;
+                }
+                // unchecked_config, do not check configured options against the schema of `config`
+                "unchecked_config" => {
+                    unchecked_config = true;
+                }
+                // typed_config, options are read with `ctx.options::<config>()`
+                "typed_config" => {
+                    typed_config = true;
+                }
+                // symbols = Class | Function, the `SymbolFlags` passed to `run_on_symbol`
+                "symbols" => {
+                    input.parse::<Token!(=)>()?;
+                    symbols.push(input.parse()?);
+                    while input.peek(Token!(|)) {
+                        input.parse::<Token!(|)>()?;
+                        symbols.push(input.parse()?);
+                    }
+                }
+                // unused_symbols, only pass symbols without references to `run_on_symbol`
+                "unused_symbols" => {
+                    unused_symbols = true;
+                }
                 _ => {
                     if input.peek(Token!(=)) || fix.is_some() {
                         panic!("invalid key: {key}");
@@ -116,6 +160,16 @@ impl Parse for LintRuleMeta {
             }
         }
 
+        if default_config && config.is_none() {
+            return Err(Error::new(input.span(), "`default_config` requires `config = ...`"));
+        }
+        if unchecked_config && config.is_none() {
+            return Err(Error::new(input.span(), "`unchecked_config` requires `config = ...`"));
+        }
+        if typed_config && config.is_none() {
+            return Err(Error::new(input.span(), "`typed_config` requires `config = ...`"));
+        }
+
         let remaining = input.parse::<proc_macro2::TokenStream>()?;
         if !remaining.is_empty() {
             return Err(Error::new_spanned(
@@ -134,6 +188,11 @@ impl Parse for LintRuleMeta {
             documentation,
             used_in_test: false,
             config,
+            default_config,
+            unchecked_config,
+            typed_config,
+            symbols,
+            unused_symbols,
         })
     }
 }
@@ -153,6 +212,11 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
         documentation,
         used_in_test,
         config,
+        default_config,
+        unchecked_config,
+        typed_config,
+        symbols,
+        unused_symbols,
     } = metadata;
 
     let canonical_name = rule_name_converter().convert(name.to_string());
@@ -175,6 +239,18 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
         }
     });
 
+    let symbol_filter = (!symbols.is_empty() || unused_symbols).then(|| {
+        let flags = symbols
+            .iter()
+            .map(|flag| quote! { oxc_semantic::SymbolFlags::#flag })
+            .reduce(|acc, flag| quote! { #acc.union(#flag) })
+            .unwrap_or_else(|| quote! { oxc_semantic::SymbolFlags::all() });
+        quote! {
+            const SYMBOL_FILTER: crate::rule::RuleSymbolFilter =
+                crate::rule::RuleSymbolFilter { flags: #flags, unused_only: #unused_symbols };
+        }
+    });
+
     let import_statement = if used_in_test {
         None
     } else {
@@ -194,24 +270,42 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
         }
     });
 
-    #[cfg(not(feature = "ruledocs"))]
-    let config_schema: Option<proc_macro2::TokenStream> = {
-        let _ = config;
-        None
-    };
-    #[cfg(feature = "ruledocs")]
-    let config_schema = match config {
-        Some(config) => Some(quote! {
+    let default_config = config.as_ref().filter(|_| default_config).map(|config| {
+        quote! {
+            fn default_config() -> Option<serde_json::Value> {
+                serde_json::to_value([#config::default()]).ok()
+            }
+        }
+    });
+
+    // The schema of `config` documents the options of the rule, and configured options are
+    // checked against it unless the rule is declared with `unchecked_config`.
+    let validate_config = (config.is_some() && !unchecked_config).then(|| {
+        quote! {
+            #[cfg(any(test, feature = "validate_config"))]
+            const VALIDATE_CONFIG: bool = true;
+        }
+    });
+
+    // Options which do not deserialize as `config` would fall back to its default in
+    // `ctx.options`, so they are reported in configuration files.
+    let deserialize_config = config.as_ref().filter(|_| typed_config).map(|config| {
+        quote! {
+            #[cfg(any(test, feature = "validate_config"))]
+            fn deserialize_config(options: &[serde_json::Value]) -> Result<(), String> {
+                crate::config::RuleOptions::check::<#config>(options)
+            }
+        }
+    });
+
+    let config_schema = config.map(|config| {
+        quote! {
+            #[cfg(any(test, feature = "ruledocs", feature = "validate_config"))]
             fn config_schema(generator: &mut schemars::SchemaGenerator) -> Option<schemars::schema::Schema> {
                 Some(generator.subschema_for::<#config>())
             }
-        }),
-        None => Some(quote! {
-            fn config_schema(_generator: &mut schemars::SchemaGenerator) -> Option<schemars::schema::Schema> {
-                None
-            }
-        }),
-    };
+        }
+    });
 
     let output = quote! {
         #import_statement
@@ -227,8 +321,16 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
 
             #fix
 
+            #symbol_filter
+
             #docs
 
+            #default_config
+
+            #validate_config
+
+            #deserialize_config
+
             #config_schema
         }
     };
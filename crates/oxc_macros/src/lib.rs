@@ -50,6 +50,12 @@ mod declare_oxc_lint;
 ///
 /// `pending` and `none` are special cases that do not follow this pattern.
 ///
+/// ## Symbols
+///
+/// Rules implementing `run_on_symbol` can limit the symbols it is called on:
+/// - `symbols = Class | Function`: only symbols with any of these `SymbolFlags`
+/// - `unused_symbols`: only symbols without any references
+///
 /// ## Integration markers
 /// You can optionally add an integration marker immediately after the rule's struct
 /// name in parentheses. Currently the only supported marker is `tsgolint`:
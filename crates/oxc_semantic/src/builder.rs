@@ -92,6 +92,8 @@ pub struct SemanticBuilder<'a> {
     unused_labels: UnusedLabels<'a>,
     #[cfg(feature = "linter")]
     jsdoc: JSDocBuilder<'a>,
+    #[cfg(feature = "linter")]
+    build_jsdoc: bool,
     stats: Option<Stats>,
     excess_capacity: f64,
 
@@ -146,6 +148,8 @@ impl<'a> SemanticBuilder<'a> {
             unused_labels: UnusedLabels::default(),
             #[cfg(feature = "linter")]
             jsdoc: JSDocBuilder::default(),
+            #[cfg(feature = "linter")]
+            build_jsdoc: true,
             stats: None,
             excess_capacity: 0.0,
             check_syntax_error: false,
@@ -187,6 +191,16 @@ impl<'a> SemanticBuilder<'a> {
         self
     }
 
+    /// Enable or disable parsing JSDoc comments for [`Semantic::jsdoc`].
+    ///
+    /// By default, this is `true`.
+    #[must_use]
+    #[cfg(feature = "linter")]
+    pub fn with_jsdoc(mut self, yes: bool) -> Self {
+        self.build_jsdoc = yes;
+        self
+    }
+
     #[must_use]
     pub fn with_scope_tree_child_ids(mut self, yes: bool) -> Self {
         self.scoping.scope_build_child_ids = yes;
@@ -231,7 +245,7 @@ impl<'a> SemanticBuilder<'a> {
         self.source_text = program.source_text;
         self.source_type = program.source_type;
         #[cfg(feature = "linter")]
-        {
+        if self.build_jsdoc {
             self.jsdoc = JSDocBuilder::new(self.source_text, &program.comments);
         }
 
@@ -1,4 +1,11 @@
-use std::{path::Path, sync::Arc};
+use std::{
+    env,
+    ffi::OsStr,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, mpsc},
+};
 
 use rustc_hash::FxHashMap;
 
@@ -6,7 +13,7 @@ use oxc_allocator::Allocator;
 use oxc_benchmark::{BenchmarkId, Criterion, criterion_group, criterion_main};
 use oxc_linter::{
     ConfigStore, ConfigStoreBuilder, ContextSubHost, ExternalPluginStore, FixKind, LintOptions,
-    Linter, ModuleRecord,
+    LintService, LintServiceOptions, Linter, ModuleRecord, OsFileSystem,
 };
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
@@ -60,5 +67,131 @@ fn bench_linter(criterion: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(linter, bench_linter);
+/// Number of copies of each test file linted by `bench_lint_service`
+const LINT_SERVICE_COPIES: usize = 20;
+
+/// Lint files on disk with and without `--prefetch`.
+///
+/// Files are read from a warm page cache, so this measures the overhead of prefetching rather
+/// than the time saved on slow disks.
+fn bench_lint_service(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("lint_service");
+
+    let dir = env::temp_dir().join("oxc_bench_lint_service");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let mut paths = Vec::<Arc<OsStr>>::new();
+    for file in TestFiles::minimal().files() {
+        for i in 0..LINT_SERVICE_COPIES {
+            let path: PathBuf = dir.join(format!("{i}-{}", file.file_name));
+            fs::write(&path, &file.source_text).unwrap();
+            paths.push(path.into_os_string().into());
+        }
+    }
+
+    for prefetch in [false, true] {
+        let id = BenchmarkId::from_parameter(if prefetch { "prefetch" } else { "default" });
+        group.bench_function(id, |b| {
+            let external_plugin_store = ExternalPluginStore::default();
+            let lint_config = ConfigStoreBuilder::default().build(&external_plugin_store).unwrap();
+            let linter = Linter::new(
+                LintOptions::default(),
+                ConfigStore::new(lint_config, FxHashMap::default(), external_plugin_store),
+                None,
+            );
+            let options = LintServiceOptions::new(dir.clone()).with_prefetch(prefetch);
+            let service = LintService::new(linter, options);
+            let (tx_error, rx_error) = mpsc::channel();
+
+            b.iter(|| {
+                service.run(&OsFileSystem, paths.clone(), &tx_error);
+                while rx_error.try_recv().is_ok() {}
+            });
+        });
+    }
+    group.finish();
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+/// Number of modules of each module graph linted by `bench_module_graph`
+const MODULE_GRAPH_SIZE: usize = 2000;
+
+/// Build and lint module graphs whose shapes challenge the size of groups the module graph is
+/// built in: one module importing all others (large fan-out), and chains of tiny modules.
+///
+/// Each graph is built with adaptive group sizes, and with the fixed size of 4 modules per thread
+/// used before groups adapted to the size of modules.
+fn bench_module_graph(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("module_graph");
+
+    let root = env::temp_dir().join("oxc_bench_module_graph");
+    let _ = fs::remove_dir_all(&root);
+
+    let fan_out = (0..MODULE_GRAPH_SIZE)
+        .map(|i| {
+            let source_text = if i == 0 {
+                (1..MODULE_GRAPH_SIZE).fold(String::new(), |mut source_text, j| {
+                    let _ = writeln!(source_text, "import './{j}.js';");
+                    source_text
+                })
+            } else {
+                format!("export const a{i} = {i};\n")
+            };
+            (format!("{i}.js"), source_text)
+        })
+        .collect::<Vec<_>>();
+    let tiny_files = (0..MODULE_GRAPH_SIZE)
+        .map(|i| {
+            let source_text =
+                if i % 10 == 9 { String::new() } else { format!("import './{}.js';\n", i + 1) };
+            (format!("{i}.js"), source_text)
+        })
+        .collect::<Vec<_>>();
+
+    // The default number of threads of the lint service
+    let threads = std::thread::available_parallelism().map_or(1, usize::from);
+    let fixed_group_size = threads * 4;
+
+    for (name, files) in [("fan_out", fan_out), ("tiny_files", tiny_files)] {
+        let dir = root.join(name);
+        fs::create_dir_all(&dir).unwrap();
+        let mut paths = Vec::<Arc<OsStr>>::new();
+        for (file_name, source_text) in files {
+            let path = dir.join(file_name);
+            fs::write(&path, source_text).unwrap();
+            paths.push(path.into_os_string().into());
+        }
+
+        for (group_size_name, group_size) in [("adaptive", None), ("fixed", Some(fixed_group_size))]
+        {
+            let id = BenchmarkId::new(name, group_size_name);
+            group.bench_function(id, |b| {
+                let external_plugin_store = ExternalPluginStore::default();
+                let lint_config =
+                    ConfigStoreBuilder::default().build(&external_plugin_store).unwrap();
+                let linter = Linter::new(
+                    LintOptions::default(),
+                    ConfigStore::new(lint_config, FxHashMap::default(), external_plugin_store),
+                    None,
+                );
+                let options = LintServiceOptions::new(dir.clone())
+                    .with_cross_module(true)
+                    .with_group_size(group_size);
+                let service = LintService::new(linter, options);
+                let (tx_error, rx_error) = mpsc::channel();
+
+                b.iter(|| {
+                    service.run(&OsFileSystem, paths.clone(), &tx_error);
+                    while rx_error.try_recv().is_ok() {}
+                });
+            });
+        }
+    }
+    group.finish();
+
+    let _ = fs::remove_dir_all(&root);
+}
+
+criterion_group!(linter, bench_linter, bench_lint_service, bench_module_graph);
 criterion_main!(linter);
@@ -0,0 +1,6 @@
+/// ESLint rule test cases for which oxlint intentionally reports a different number of
+/// diagnostics, as `(rule name, test case id)`, e.g. `("no-unused-vars", "invalid/12")`.
+///
+/// Add a comment with the reason of each divergence. Known divergences are not counted as
+/// failures, and entries which pass again are listed in the report so that they can be removed.
+pub const KNOWN_DIVERGENCES: &[(&str, &str)] = &[];
@@ -0,0 +1,259 @@
+#![expect(clippy::print_stdout)]
+
+mod known_divergences;
+pub mod options;
+mod spec;
+
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use oxc_allocator::Allocator;
+use oxc_linter::{
+    ConfigStore, ConfigStoreBuilder, ContextSubHost, ExternalPluginStore, LintOptions, Linter,
+    Message, ModuleRecord, Oxlintrc,
+    rules::{RULES, RuleEnum},
+};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+
+use crate::{
+    known_divergences::KNOWN_DIVERGENCES,
+    options::TestRunnerOptions,
+    spec::{TestInput, parse_rule_tests},
+};
+
+#[test]
+#[cfg(any(coverage, coverage_nightly))]
+fn test() {
+    TestRunner::new(TestRunnerOptions::default()).run();
+}
+
+fn root() -> PathBuf {
+    oxc_tasks_common::project_root().join("tasks").join("eslint_conformance")
+}
+
+fn fixtures_root() -> PathBuf {
+    root().join("eslint").join("tests").join("lib").join("rules")
+}
+
+fn snap_root() -> PathBuf {
+    root().join("snapshots")
+}
+
+/// Result of the test cases of one rule.
+#[derive(Default)]
+struct RuleReport {
+    rule: &'static str,
+    /// Whether ESLint has a test file for the rule.
+    has_fixture: bool,
+    passed: usize,
+    /// `(test case id, reason)`
+    failed: Vec<(String, String)>,
+    known_divergences: usize,
+    /// Known divergences which pass now, and should be removed from the allow-list.
+    stale_divergences: Vec<String>,
+    /// Test cases which can not be run, e.g. because they use a custom parser.
+    skipped: usize,
+}
+
+pub struct TestRunner {
+    options: TestRunnerOptions,
+}
+
+impl TestRunner {
+    pub fn new(options: TestRunnerOptions) -> Self {
+        Self { options }
+    }
+
+    /// # Panics
+    ///
+    /// If ESLint's test fixtures are not cloned.
+    pub fn run(&self) {
+        assert!(
+            fixtures_root().exists(),
+            "ESLint's rule tests are missing in {}, run `just submodules` to clone them",
+            fixtures_root().display()
+        );
+
+        let mut rules = RULES
+            .iter()
+            .filter(|rule| rule.plugin_name() == "eslint")
+            .map(RuleEnum::name)
+            .filter(|rule| {
+                self.options.filter.as_deref().is_none_or(|filter| rule.contains(filter))
+            })
+            .collect::<Vec<_>>();
+        rules.sort_unstable();
+
+        let reports = rules.into_iter().map(|rule| self.test_rule(rule)).collect::<Vec<_>>();
+
+        // If filter is set, only print the results for debugging
+        if self.options.filter.is_some() {
+            for report in &reports {
+                for (id, reason) in &report.failed {
+                    println!("{} {id}: {reason}", report.rule);
+                }
+            }
+            println!("{}", Self::summary(&reports));
+            return;
+        }
+
+        let snapshot = Self::snapshot(&reports);
+        println!("{}", Self::summary(&reports));
+        std::fs::create_dir_all(snap_root()).unwrap();
+        std::fs::write(snap_root().join("eslint.snap.md"), snapshot).unwrap();
+    }
+
+    fn summary(reports: &[RuleReport]) -> String {
+        let passed = reports.iter().map(|report| report.passed).sum::<usize>();
+        let failed = reports.iter().map(|report| report.failed.len()).sum::<usize>();
+        let known = reports.iter().map(|report| report.known_divergences).sum::<usize>();
+        let skipped = reports.iter().map(|report| report.skipped).sum::<usize>();
+        let total = passed + failed + known;
+        #[expect(clippy::cast_precision_loss)]
+        let percentage = if total == 0 { 0.0 } else { passed as f64 / total as f64 * 100.0 };
+        format!(
+            "eslint compatibility: {passed}/{total} ({percentage:.2}%)\nKnown divergences: {known}, skipped test cases: {skipped}"
+        )
+    }
+
+    fn snapshot(reports: &[RuleReport]) -> String {
+        let mut snapshot = Self::summary(reports);
+        snapshot.push_str("\n\n# Rules\n\n");
+        snapshot.push_str("| Rule | Passed | Failed | Known divergences | Skipped |\n");
+        snapshot.push_str("| :--- | :----: | :----: | :---------------: | :-----: |\n");
+        for report in reports.iter().filter(|report| report.has_fixture) {
+            writeln!(
+                snapshot,
+                "| {} | {} | {} | {} | {} |",
+                report.rule,
+                report.passed,
+                report.failed.len(),
+                report.known_divergences,
+                report.skipped
+            )
+            .unwrap();
+        }
+
+        snapshot.push_str("\n# Failed\n");
+        for report in reports.iter().filter(|report| !report.failed.is_empty()) {
+            writeln!(snapshot, "\n## {}\n", report.rule).unwrap();
+            for (id, reason) in &report.failed {
+                writeln!(snapshot, "- {id}: {reason}").unwrap();
+            }
+        }
+
+        let stale = reports
+            .iter()
+            .flat_map(|report| report.stale_divergences.iter().map(|id| (report.rule, id)))
+            .collect::<Vec<_>>();
+        if !stale.is_empty() {
+            snapshot.push_str("\n# Known divergences which pass\n\n");
+            for (rule, id) in stale {
+                writeln!(snapshot, "- {rule} {id}").unwrap();
+            }
+        }
+
+        let missing = reports.iter().filter(|report| !report.has_fixture).collect::<Vec<_>>();
+        if !missing.is_empty() {
+            snapshot.push_str("\n# Rules without ESLint tests\n\n");
+            for report in missing {
+                writeln!(snapshot, "- {}", report.rule).unwrap();
+            }
+        }
+        snapshot
+    }
+
+    fn test_rule(&self, rule: &'static str) -> RuleReport {
+        let mut report = RuleReport { rule, ..RuleReport::default() };
+        let Ok(source_text) = std::fs::read_to_string(fixtures_root().join(format!("{rule}.js")))
+        else {
+            return report;
+        };
+        report.has_fixture = true;
+
+        let cases = match parse_rule_tests(&source_text) {
+            Ok(cases) => cases,
+            Err(error) => {
+                report.failed.push(("*".to_string(), format!("failed to parse tests: {error}")));
+                return report;
+            }
+        };
+
+        for case in cases {
+            let Ok(input) = &case.input else {
+                report.skipped += 1;
+                continue;
+            };
+            let result = Self::lint(rule, input).map(|messages| {
+                if self.options.debug && messages.len() != case.expected_errors {
+                    println!("{rule} {}:\n{}\n", case.id, input.code);
+                    for message in &messages {
+                        println!("  {}", message.error.message);
+                    }
+                }
+                messages.len()
+            });
+            let is_known_divergence = KNOWN_DIVERGENCES.contains(&(rule, case.id.as_str()));
+            match result {
+                Ok(found) if found == case.expected_errors => {
+                    report.passed += 1;
+                    if is_known_divergence {
+                        report.stale_divergences.push(case.id);
+                    }
+                }
+                _ if is_known_divergence => report.known_divergences += 1,
+                Ok(found) => report.failed.push((
+                    case.id,
+                    format!("expected {} diagnostics, found {found}", case.expected_errors),
+                )),
+                Err(error) => report.failed.push((case.id, error)),
+            }
+        }
+        report
+    }
+
+    /// Lint a test case with only `rule` enabled.
+    fn lint(rule: &str, input: &TestInput) -> Result<Vec<Message>, String> {
+        let mut rule_config = vec![Value::from("error")];
+        rule_config.extend(input.options.iter().cloned());
+        let oxlintrc = Oxlintrc::deserialize(json!({
+            "plugins": ["eslint"],
+            "categories": {},
+            "globals": input.globals,
+            "rules": { rule: rule_config },
+        }))
+        .map_err(|error| format!("invalid configuration: {error}"))?;
+
+        let mut external_plugin_store = ExternalPluginStore::default();
+        let config =
+            ConfigStoreBuilder::from_oxlintrc(true, oxlintrc, None, &mut external_plugin_store)
+                .map_err(|error| format!("invalid configuration: {error}"))?
+                .build(&external_plugin_store)
+                .map_err(|error| format!("invalid configuration: {error}"))?;
+        let config_store = ConfigStore::new(config, FxHashMap::default(), external_plugin_store);
+        let linter = Linter::new(LintOptions::default(), config_store, None);
+
+        let allocator = Allocator::default();
+        let path = Path::new(input.filename.as_deref().unwrap_or("test.js"));
+        let ret = Parser::new(&allocator, &input.code, input.source_type).parse();
+        if let Some(error) = ret.errors.first() {
+            return Err(format!("parse error: {}", error.message));
+        }
+        let semantic = SemanticBuilder::new()
+            .with_scope_tree_child_ids(true)
+            .with_cfg(true)
+            .build(&ret.program)
+            .semantic;
+        let module_record = Arc::new(ModuleRecord::new(path, &ret.module_record, &semantic));
+        let sub_hosts = vec![ContextSubHost::new(semantic, module_record, 0)];
+        Ok(linter.run(path, sub_hosts, &allocator))
+    }
+}
@@ -0,0 +1,16 @@
+use pico_args::Arguments;
+
+use oxc_eslint_conformance::{TestRunner, options::TestRunnerOptions};
+
+/// This CLI runs in 2 modes:
+/// - `cargo run`: Run the tests of all rules and generate the conformance report
+/// - `cargo run -- --filter <rule>`: Debug specific rules, not generating the report
+fn main() {
+    let mut args = Arguments::from_env();
+    let options = TestRunnerOptions {
+        debug: args.contains("--debug"),
+        filter: args.opt_value_from_str("--filter").unwrap(),
+    };
+
+    TestRunner::new(options).run();
+}
@@ -0,0 +1,7 @@
+#[derive(Default, Clone)]
+pub struct TestRunnerOptions {
+    /// Print the code and diagnostics of failed test cases.
+    pub debug: bool,
+    /// Only run the rules whose name contains this string, without writing the report.
+    pub filter: Option<String>,
+}
@@ -0,0 +1,437 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    ArrayExpression, ArrayExpressionElement, CallExpression, Expression, NewExpression,
+    ObjectExpression, ObjectPropertyKind, UnaryOperator,
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use serde_json::{Map, Value};
+
+/// A test case of ESLint's `RuleTester`.
+pub struct TestCase {
+    /// Position of the case in the test file, e.g. `valid/3` or `invalid/12`.
+    pub id: String,
+    /// Number of diagnostics ESLint reports, `0` for valid cases.
+    pub expected_errors: usize,
+    /// The test case, or the reason why it can not be run by the conformance suite.
+    pub input: Result<TestInput, &'static str>,
+}
+
+pub struct TestInput {
+    pub code: String,
+    /// Rule options, without the severity.
+    pub options: Vec<Value>,
+    pub source_type: SourceType,
+    pub globals: Map<String, Value>,
+    pub filename: Option<String>,
+}
+
+/// Collect the test cases of every `ruleTester.run(name, rule, { valid, invalid })` call in an
+/// ESLint rule test file.
+///
+/// # Errors
+///
+/// If the test file can not be parsed.
+pub fn parse_rule_tests(source_text: &str) -> Result<Vec<TestCase>, String> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::cjs()).parse();
+    if let Some(error) = ret.errors.first() {
+        return Err(error.message.to_string());
+    }
+
+    let mut collector = TestCaseCollector::default();
+    collector.visit_program(&ret.program);
+    Ok(collector.cases)
+}
+
+/// `languageOptions` of a test case, or of `new RuleTester()`.
+#[derive(Default, Clone)]
+struct LanguageOptions {
+    source_type: Option<String>,
+    jsx: Option<bool>,
+    globals: Map<String, Value>,
+    unsupported: Option<&'static str>,
+}
+
+impl LanguageOptions {
+    fn parse(expr: &Expression<'_>) -> Self {
+        let mut options = Self::default();
+        let Expression::ObjectExpression(obj) = expr else {
+            options.unsupported = Some("`languageOptions` is not a static object");
+            return options;
+        };
+        for (key, value) in static_properties(obj) {
+            let Some(value) = value else {
+                options.unsupported = Some("`languageOptions` is not a static object");
+                continue;
+            };
+            match key.as_str() {
+                "sourceType" => options.source_type = static_string(value),
+                "parser" => options.unsupported = Some("custom parser"),
+                "globals" => match to_json(value) {
+                    Some(Value::Object(globals)) => options.globals = globals,
+                    _ => options.unsupported = Some("`globals` are not static JSON"),
+                },
+                "parserOptions" => {
+                    if let Some(Value::Object(parser_options)) = to_json(value)
+                        && let Some(Value::Bool(jsx)) = parser_options
+                            .get("ecmaFeatures")
+                            .and_then(|features| features.get("jsx"))
+                    {
+                        options.jsx = Some(*jsx);
+                    }
+                }
+                _ => {}
+            }
+        }
+        options
+    }
+
+    /// `self`, with the options which are not set taken from `defaults`.
+    fn or(self, defaults: &Self) -> Self {
+        let mut globals = defaults.globals.clone();
+        globals.extend(self.globals);
+        Self {
+            source_type: self.source_type.or_else(|| defaults.source_type.clone()),
+            jsx: self.jsx.or(defaults.jsx),
+            globals,
+            unsupported: self.unsupported.or(defaults.unsupported),
+        }
+    }
+
+    fn source_type(&self, filename: Option<&str>) -> SourceType {
+        let source_type = filename
+            .and_then(|filename| SourceType::from_path(filename).ok())
+            .unwrap_or_else(SourceType::mjs);
+        // ESLint parses files as modules unless `sourceType` says otherwise.
+        let source_type = match self.source_type.as_deref() {
+            Some("script" | "commonjs") => source_type.with_script(true),
+            _ => source_type,
+        };
+        match self.jsx {
+            Some(jsx) if source_type.is_javascript() => source_type.with_jsx(jsx),
+            _ => source_type,
+        }
+    }
+}
+
+#[derive(Default)]
+struct TestCaseCollector {
+    cases: Vec<TestCase>,
+    valid_count: usize,
+    invalid_count: usize,
+    /// Options passed to `new RuleTester()`, used by all following test cases.
+    defaults: LanguageOptions,
+}
+
+impl TestCaseCollector {
+    fn add_cases(&mut self, array: &ArrayExpression<'_>, is_valid: bool) {
+        for element in &array.elements {
+            let count = if is_valid { &mut self.valid_count } else { &mut self.invalid_count };
+            let id = format!("{}/{count}", if is_valid { "valid" } else { "invalid" });
+            *count += 1;
+
+            let case = match element {
+                ArrayExpressionElement::SpreadElement(_) => TestCase {
+                    id,
+                    expected_errors: 0,
+                    input: Err("test cases are not a static array"),
+                },
+                ArrayExpressionElement::ObjectExpression(obj) => {
+                    self.parse_case_object(id, obj, is_valid)
+                }
+                element => {
+                    let input = element
+                        .as_expression()
+                        .and_then(static_code)
+                        .ok_or("code is not a static string")
+                        .and_then(|code| {
+                            self.input(code, vec![], LanguageOptions::default(), None)
+                        });
+                    TestCase { id, expected_errors: 0, input }
+                }
+            };
+            self.cases.push(case);
+        }
+    }
+
+    fn parse_case_object(
+        &self,
+        id: String,
+        obj: &ObjectExpression<'_>,
+        is_valid: bool,
+    ) -> TestCase {
+        let mut code = None;
+        let mut options = vec![];
+        let mut expected_errors = None;
+        let mut language_options = LanguageOptions::default();
+        let mut filename = None;
+        let mut unsupported = None;
+
+        for (key, value) in static_properties(obj) {
+            let Some(value) = value else {
+                unsupported = Some("test case is not a static object");
+                continue;
+            };
+            match key.as_str() {
+                "code" => code = static_code(value),
+                "options" => match to_json(value) {
+                    Some(Value::Array(values)) => options = values,
+                    _ => unsupported = Some("options are not static JSON"),
+                },
+                "errors" => {
+                    expected_errors = match value {
+                        Expression::NumericLiteral(_) => to_json(value)
+                            .and_then(|n| n.as_u64())
+                            .and_then(|n| usize::try_from(n).ok()),
+                        Expression::ArrayExpression(errors) => Some(errors.elements.len()),
+                        _ => None,
+                    };
+                }
+                "languageOptions" => language_options = LanguageOptions::parse(value),
+                "filename" => filename = static_string(value),
+                _ => {}
+            }
+        }
+
+        let expected_errors = if is_valid { Some(0) } else { expected_errors };
+        let input = match (code, expected_errors) {
+            (None, _) => Err("code is not a static string"),
+            (_, None) => Err("errors are not statically known"),
+            (Some(code), Some(_)) => match unsupported {
+                Some(reason) => Err(reason),
+                None => self.input(code, options, language_options, filename),
+            },
+        };
+        TestCase { id, expected_errors: expected_errors.unwrap_or_default(), input }
+    }
+
+    fn input(
+        &self,
+        code: String,
+        options: Vec<Value>,
+        language_options: LanguageOptions,
+        filename: Option<String>,
+    ) -> Result<TestInput, &'static str> {
+        let language_options = language_options.or(&self.defaults);
+        if let Some(reason) = language_options.unsupported {
+            return Err(reason);
+        }
+        Ok(TestInput {
+            code,
+            options,
+            source_type: language_options.source_type(filename.as_deref()),
+            globals: language_options.globals,
+            filename,
+        })
+    }
+}
+
+impl<'a> Visit<'a> for TestCaseCollector {
+    // `new RuleTester({ languageOptions: { ... } })`
+    fn visit_new_expression(&mut self, expr: &NewExpression<'a>) {
+        if expr.callee.get_identifier_reference().is_some_and(|ident| ident.name == "RuleTester")
+            && let Some(Expression::ObjectExpression(config)) =
+                expr.arguments.first().and_then(|arg| arg.as_expression())
+        {
+            self.defaults = static_properties(config)
+                .find(|(key, _)| key == "languageOptions")
+                .and_then(|(_, value)| value)
+                .map(LanguageOptions::parse)
+                .unwrap_or_default();
+        }
+        walk::walk_new_expression(self, expr);
+    }
+
+    // `ruleTester.run("rule-name", rule, { valid: [...], invalid: [...] })`
+    fn visit_call_expression(&mut self, expr: &CallExpression<'a>) {
+        let is_run = expr
+            .callee
+            .get_member_expr()
+            .is_some_and(|member| member.static_property_name() == Some("run"));
+        if is_run
+            && expr.arguments.len() == 3
+            && let Some(Expression::ObjectExpression(tests)) = expr.arguments[2].as_expression()
+        {
+            for (key, value) in static_properties(tests) {
+                if let Some(Expression::ArrayExpression(array)) = value {
+                    match key.as_str() {
+                        "valid" => self.add_cases(array, true),
+                        "invalid" => self.add_cases(array, false),
+                        _ => {}
+                    }
+                }
+            }
+            return;
+        }
+        walk::walk_call_expression(self, expr);
+    }
+}
+
+/// Properties of an object literal with a static key. The value is `None` for spread elements.
+fn static_properties<'b, 'a: 'b>(
+    obj: &'b ObjectExpression<'a>,
+) -> impl Iterator<Item = (String, Option<&'b Expression<'a>>)> {
+    obj.properties.iter().filter_map(|prop| match prop {
+        ObjectPropertyKind::ObjectProperty(prop) => {
+            prop.key.static_name().map(|key| (key.into_owned(), Some(&prop.value)))
+        }
+        ObjectPropertyKind::SpreadProperty(_) => Some((String::new(), None)),
+    })
+}
+
+fn static_string(expr: &Expression<'_>) -> Option<String> {
+    match expr {
+        Expression::StringLiteral(lit) => Some(lit.value.to_string()),
+        Expression::TemplateLiteral(lit) => lit.single_quasi().map(|s| s.to_string()),
+        _ => None,
+    }
+}
+
+/// The source text of a test case. Supports string literals, and templates without substitutions
+/// which are optionally tagged with ESLint's `unIndent` helper.
+fn static_code(expr: &Expression<'_>) -> Option<String> {
+    match expr {
+        Expression::TaggedTemplateExpression(tagged)
+            if tagged.tag.get_identifier_reference().is_some_and(|tag| tag.name == "unIndent") =>
+        {
+            tagged.quasi.single_quasi().map(|text| un_indent(&text))
+        }
+        expr => static_string(expr),
+    }
+}
+
+/// Same as `unIndent` in ESLint's `tests/_utils`: removes the leading and trailing line break, and
+/// the common indentation of all lines.
+fn un_indent(text: &str) -> String {
+    let text = text.strip_prefix('\n').unwrap_or(text);
+    let text = match text.rfind('\n') {
+        Some(last_line) if text[last_line..].trim().is_empty() => &text[..last_line],
+        _ => text,
+    };
+    let min_indent = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start_matches(' ').len())
+        .min()
+        .unwrap_or(0);
+    text.split('\n').map(|line| line.get(min_indent..).unwrap_or("")).collect::<Vec<_>>().join("\n")
+}
+
+/// Convert a literal expression to JSON, or `None` if it is not static.
+fn to_json(expr: &Expression<'_>) -> Option<Value> {
+    match expr {
+        Expression::NullLiteral(_) => Some(Value::Null),
+        Expression::BooleanLiteral(lit) => Some(Value::Bool(lit.value)),
+        Expression::NumericLiteral(lit) => Some(number_to_json(lit.value)),
+        Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::UnaryNegation => {
+            match &unary.argument {
+                Expression::NumericLiteral(lit) => Some(number_to_json(-lit.value)),
+                _ => None,
+            }
+        }
+        Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => {
+            static_string(expr).map(Value::String)
+        }
+        Expression::ArrayExpression(array) => array
+            .elements
+            .iter()
+            .map(|element| element.as_expression().and_then(to_json))
+            .collect::<Option<Vec<_>>>()
+            .map(Value::Array),
+        Expression::ObjectExpression(obj) => static_properties(obj)
+            .map(|(key, value)| Some((key, to_json(value?)?)))
+            .collect::<Option<Map<_, _>>>()
+            .map(Value::Object),
+        Expression::ParenthesizedExpression(expr) => to_json(&expr.expression),
+        _ => None,
+    }
+}
+
+#[expect(clippy::cast_possible_truncation)]
+fn number_to_json(value: f64) -> Value {
+    if value.fract() == 0.0 && value.abs() < 2f64.powi(53) {
+        Value::from(value as i64)
+    } else {
+        Value::from(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::{parse_rule_tests, un_indent};
+
+    #[test]
+    fn collect_test_cases() {
+        let source_text = r#"
+            const rule = require("../../../lib/rules/no-foo");
+            const { RuleTester } = require("../../../lib/rule-tester");
+            const ruleTester = new RuleTester({ languageOptions: { sourceType: "script" } });
+
+            ruleTester.run("no-foo", rule, {
+                valid: [
+                    "bar",
+                    `baz`,
+                    { code: "qux", options: [{ allow: ["foo"], max: -1 }] },
+                    { code: "<Foo />", languageOptions: { parserOptions: { ecmaFeatures: { jsx: true } } } },
+                    { code: "foo", languageOptions: { parser: require("custom-parser") } },
+                    ...extraCases,
+                ],
+                invalid: [
+                    { code: "foo", errors: 2 },
+                    { code: "foo; foo", errors: [{ messageId: "foo" }] },
+                    { code: "import foo from 'foo'", languageOptions: { sourceType: "module" }, errors: [{}] },
+                    { code: foo(), errors: 1 },
+                    { code: "foo", errors: messages },
+                ],
+            });
+        "#;
+        let cases = parse_rule_tests(source_text).unwrap();
+        let ids = cases.iter().map(|case| case.id.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            ids,
+            vec![
+                "valid/0",
+                "valid/1",
+                "valid/2",
+                "valid/3",
+                "valid/4",
+                "valid/5",
+                "invalid/0",
+                "invalid/1",
+                "invalid/2",
+                "invalid/3",
+                "invalid/4",
+            ]
+        );
+
+        let input = cases[1].input.as_ref().unwrap();
+        assert_eq!(input.code, "baz");
+        assert!(input.source_type.is_script());
+
+        let input = cases[2].input.as_ref().unwrap();
+        assert_eq!(input.options, vec![json!({ "allow": ["foo"], "max": -1 })]);
+
+        let input = cases[3].input.as_ref().unwrap();
+        assert!(input.source_type.is_jsx());
+
+        assert_eq!(cases[4].input.as_ref().err(), Some(&"custom parser"));
+        assert_eq!(cases[5].input.as_ref().err(), Some(&"test cases are not a static array"));
+
+        assert_eq!(cases[6].expected_errors, 2);
+        assert_eq!(cases[7].expected_errors, 1);
+        let input = cases[8].input.as_ref().unwrap();
+        assert!(input.source_type.is_module());
+        assert_eq!(cases[9].input.as_ref().err(), Some(&"code is not a static string"));
+        assert_eq!(cases[10].input.as_ref().err(), Some(&"errors are not statically known"));
+    }
+
+    #[test]
+    fn test_un_indent() {
+        assert_eq!(un_indent("\n    if (a) {\n        b();\n    }\n"), "if (a) {\n    b();\n}");
+        assert_eq!(un_indent("\n  a\n\n  b\n  "), "a\n\nb");
+    }
+}
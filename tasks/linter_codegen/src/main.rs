@@ -8,7 +8,7 @@ use crate::{
     member_expression_kinds::get_member_expression_kinds,
     node_type_set::NodeTypeSet,
     rules::{RuleEntry, find_rule_source_file, get_all_rules},
-    utils::{find_impl_function, find_rule_impl_block},
+    utils::{find_impl_function, find_rule_impl_block, find_trait_impl_block},
 };
 use rustc_hash::FxHashSet;
 use std::{
@@ -61,7 +61,7 @@ pub fn generate_rule_runner_impls() -> io::Result<()> {
     for rule in &rule_entries {
         // Try to open the rule source file and use syn to detect node types
         let mut detected_types: NodeTypeSet = NodeTypeSet::new();
-        let mut rule_run_info: FxHashSet<String> = FxHashSet::default();
+        let mut rule_run_info: Option<FxHashSet<String>> = None;
 
         if let Some(src_path) = find_rule_source_file(&root, rule)
             && let Ok(src_contents) = fs::read_to_string(&src_path)
@@ -71,7 +71,7 @@ pub fn generate_rule_runner_impls() -> io::Result<()> {
                 detected_types.extend(node_types);
             }
 
-            rule_run_info.extend(detect_rule_run_implementations(&file, rule));
+            rule_run_info = detect_rule_run_implementations(&file, rule);
         }
 
         let node_types_init = if detected_types.is_empty() {
@@ -80,18 +80,7 @@ pub fn generate_rule_runner_impls() -> io::Result<()> {
             format!("Some(&{})", detected_types.to_ast_type_bitset_string())
         };
 
-        let rule_run_info_init = if rule_run_info.len() == 1 {
-            match rule_run_info.iter().next().map(String::as_str) {
-                Some("run") => "RuleRunFunctionsImplemented::Run".to_string(),
-                Some("run_once") => "RuleRunFunctionsImplemented::RunOnce".to_string(),
-                Some("run_on_jest_node") => {
-                    "RuleRunFunctionsImplemented::RunOnJestNode".to_string()
-                }
-                _ => "RuleRunFunctionsImplemented::Unknown".to_string(),
-            }
-        } else {
-            "RuleRunFunctionsImplemented::Unknown".to_string()
-        };
+        let rule_run_info_init = rule_run_functions_init(rule_run_info.as_ref());
 
         write!(
             out,
@@ -168,20 +157,36 @@ fn detect_top_level_node_types(
     None
 }
 
+/// Traits with a blanket `Rule` impl, and the run functions implemented by that impl.
+const BLANKET_RULE_IMPLS: &[(&str, &[&str])] = &[
+    // `crates/oxc_linter/src/utils/react_perf.rs`
+    ("ReactPerfRule", &["run"]),
+];
+
 /// Detect which `run` functions are implemented for a given rule. Returns a set of the function names
 /// that are implemented, and an empty set otherwise.
-fn detect_rule_run_implementations(file: &File, rule: &RuleEntry) -> FxHashSet<String> {
+fn detect_rule_run_implementations(file: &File, rule: &RuleEntry) -> Option<FxHashSet<String>> {
     let mut set = FxHashSet::default();
 
     let Some(rule_impl) = find_rule_impl_block(file, &rule.rule_struct_name()) else {
-        return FxHashSet::default();
+        // Rules implementing a trait with a blanket `Rule` impl run the functions of that impl.
+        let (_, run_funcs) = BLANKET_RULE_IMPLS.iter().find(|(trait_name, _)| {
+            find_trait_impl_block(file, &rule.rule_struct_name(), trait_name).is_some()
+        })?;
+        return Some(run_funcs.iter().map(ToString::to_string).collect());
     };
 
     // In order to be very conservative about only generating correct info, we will consider *all*
     // functions that are implemented in the rule impl. Then, we will only remove a few known functions
     // that do not affect rule run behavior. This way, if we ever add more ways of running a rule, it should
     // be forwards compatible even if we do not change the linter codegen.
-    let ignore_funcs = FxHashSet::from_iter(["from_configuration", "should_run"]);
+    let ignore_funcs = FxHashSet::from_iter([
+        "from_configuration",
+        "to_configuration",
+        "schema",
+        "should_run",
+        "should_run_on_source",
+    ]);
 
     // Get names of all implemented functions in rule impl
     let implemented_funcs = rule_impl
@@ -196,7 +201,38 @@ fn detect_rule_run_implementations(file: &File, rule: &RuleEntry) -> FxHashSet<S
         set.insert(func);
     }
 
-    set
+    Some(set)
+}
+
+/// Generates the `RuleRunFunctionsImplemented` value for the detected run functions. If the rule
+/// impl was not found or any function is not recognized, all run functions are assumed to be implemented.
+fn rule_run_functions_init(rule_run_info: Option<&FxHashSet<String>>) -> String {
+    let Some(rule_run_info) = rule_run_info else {
+        return "RuleRunFunctionsImplemented::Unknown".to_string();
+    };
+    if rule_run_info.is_empty() {
+        return "RuleRunFunctionsImplemented::empty()".to_string();
+    }
+    let Some(mut flags) = rule_run_info
+        .iter()
+        .map(|func| match func.as_str() {
+            "run" => Some("Run"),
+            "run_once" => Some("RunOnce"),
+            "run_on_jest_node" => Some("RunOnJestNode"),
+            "run_on_regex" => Some("RunOnRegex"),
+            "run_on_symbol" => Some("RunOnSymbol"),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()
+    else {
+        return "RuleRunFunctionsImplemented::Unknown".to_string();
+    };
+    flags.sort_unstable();
+    flags
+        .iter()
+        .map(|flag| format!("RuleRunFunctionsImplemented::{flag}"))
+        .reduce(|acc, flag| format!("{acc}.union({flag})"))
+        .unwrap()
 }
 
 /// Result of attempting to collect node type variants.
@@ -26,6 +26,15 @@ pub fn astkind_variant_from_path(path: &syn::Path) -> Option<String> {
 pub fn find_rule_impl_block<'a>(
     file: &'a File,
     rule_struct_name: &str,
+) -> Option<&'a syn::ItemImpl> {
+    find_trait_impl_block(file, rule_struct_name, "Rule")
+}
+
+/// Find the `impl <trait_name> for <struct_name>` block in `file`.
+pub fn find_trait_impl_block<'a>(
+    file: &'a File,
+    struct_name: &str,
+    trait_name: &str,
 ) -> Option<&'a syn::ItemImpl> {
     for item in &file.items {
         let syn::Item::Impl(imp) = item else { continue };
@@ -33,8 +42,8 @@ pub fn find_rule_impl_block<'a>(
             syn::Type::Path(p) => p.path.get_ident(),
             _ => None,
         };
-        if ident.is_some_and(|id| id == rule_struct_name)
-            && imp.trait_.as_ref().is_some_and(|(_, path, _)| path.is_ident("Rule"))
+        if ident.is_some_and(|id| id == struct_name)
+            && imp.trait_.as_ref().is_some_and(|(_, path, _)| path.is_ident(trait_name))
         {
             return Some(imp);
         }
@@ -11,7 +11,7 @@ use std::{
 
 use doc_page::Context;
 use html::HtmlWriter;
-use oxc_linter::{Oxlintrc, table::RuleTable};
+use oxc_linter::{Oxlintrc, rules_metadata, table::RuleTable};
 use pico_args::Arguments;
 use schemars::{SchemaGenerator, r#gen::SchemaSettings};
 use table::render_rules_table;
@@ -23,6 +23,7 @@ Arguments:
     -t,--table <path>     Path to file where rule markdown table will be saved.
     -r,--rule-docs <path> Path to directory where rule doc pages will be saved.
                           A directory will be created if one doesn't exist.
+    --metadata <path>     Path to file where the structured docs of all rules will be saved as JSON.
     --git-ref <ref>       Git commit, branch, or tag to be used in the generated links.
                           If not supplied, `main` will be used.
     -h,--help             Show this help message.
@@ -47,6 +48,7 @@ pub fn print_rules(mut args: Arguments) {
     let git_ref: Option<String> = args.opt_value_from_str("--git-ref").unwrap();
     let table_path = args.opt_value_from_str::<_, PathBuf>(["-t", "--table"]).unwrap();
     let rules_dir = args.opt_value_from_str::<_, PathBuf>(["-r", "--rule-docs"]).unwrap();
+    let metadata_path = args.opt_value_from_str::<_, PathBuf>("--metadata").unwrap();
 
     let prefix =
         rules_dir.as_ref().and_then(|p| p.as_os_str().to_str()).map_or(Cow::Borrowed(""), |p| {
@@ -70,6 +72,12 @@ pub fn print_rules(mut args: Arguments) {
         fs::write(table_path, rules_table).unwrap();
     }
 
+    if let Some(metadata_path) = metadata_path {
+        eprintln!("Writing rules metadata...");
+        let metadata = serde_json::to_string_pretty(&rules_metadata()).unwrap();
+        fs::write(pwd.join(metadata_path), metadata).unwrap();
+    }
+
     if let Some(rules_dir) = &rules_dir {
         eprintln!("Rendering rule doc pages...");
         let rules_dir = pwd.join(rules_dir);